@@ -0,0 +1,32 @@
+use starfoundry_lib_eve_client::ENV_FIXTURE_DIR;
+use tokio::net::TcpListener;
+
+const ENV_APP_ADDRESS: &str = "STARFOUNDRY_EVE_API_STUB_APP_ADDRESS";
+
+#[derive(Debug)]
+pub struct Config {
+    pub app_address: TcpListener,
+    pub fixture_dir: String,
+}
+
+impl Config {
+    pub async fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let app_address = std::env::var(ENV_APP_ADDRESS)
+            .unwrap_or("0.0.0.0:9992".into());
+        let app_address = match TcpListener::bind(app_address).await {
+            Ok(x) => x,
+            Err(e) => {
+                tracing::error!("Error validating config {ENV_APP_ADDRESS}. Error: {}", e);
+                return Err("Error while parsing address".into());
+            }
+        };
+
+        let fixture_dir = std::env::var(ENV_FIXTURE_DIR)
+            .unwrap_or("fixtures".into());
+
+        Ok(Self {
+            app_address,
+            fixture_dir,
+        })
+    }
+}
@@ -0,0 +1,74 @@
+mod config;
+
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{Method, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use starfoundry_lib_eve_client::{EveApiError, EveFixtureStore};
+use tracing_subscriber::EnvFilter;
+use url::Url;
+
+use crate::config::Config;
+
+/// Local stand-in for the EVE-API, serving fixtures that were recorded using
+/// the `record` fixture mode of the EVE API client.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenvy::dotenv().ok();
+
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .init();
+
+    let config = Config::load().await?;
+    let store = EveFixtureStore::new(config.fixture_dir);
+
+    tracing::info!("Starting app server on {}", config.app_address.local_addr()?);
+
+    let app = Router::new()
+        .fallback(fixture)
+        .with_state(store);
+    axum::serve(config.app_address, app).await?;
+
+    Ok(())
+}
+
+/// Answers every request with the matching fixture.
+/// The character behind an access token is not known, so the first recorded
+/// fixture for the method, path and query is used.
+async fn fixture(
+    State(store): State<EveFixtureStore>,
+    method:       Method,
+    uri:          Uri,
+) -> Response {
+    let url = match Url::parse("http://localhost")
+        .and_then(|x| x.join(&uri.to_string())) {
+
+        Ok(x) => x,
+        Err(e) => {
+            return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+        }
+    };
+
+    match store.find(&method, &url, None) {
+        Ok(Some(x)) => {
+            let mut builder = Response::builder()
+                .status(x.status);
+            for (key, value) in x.headers.iter() {
+                builder = builder.header(key, value);
+            }
+
+            builder
+                .body(Body::from(x.body()))
+                .unwrap_or_else(|e| {
+                    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+                })
+        },
+        Ok(None) => {
+            tracing::warn!("No fixture for {method} {uri}");
+            EveApiError::FixtureNotFound(method.to_string(), url).into_response()
+        },
+        Err(e) => e.into_response(),
+    }
+}
@@ -0,0 +1,81 @@
+-- determines if a matching rule grants or revokes access to a domain
+DO
+$$
+BEGIN
+    IF NOT EXISTS (
+        SELECT *
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'access_rule_effect'
+    ) THEN
+        CREATE TYPE ACCESS_RULE_EFFECT AS ENUM (
+            'ALLOW',
+            'DENY'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+-- determines against what a rule is matched
+DO
+$$
+BEGIN
+    IF NOT EXISTS (
+        SELECT *
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'access_rule_subject'
+    ) THEN
+        CREATE TYPE ACCESS_RULE_SUBJECT AS ENUM (
+            'CHARACTER',
+            'CORPORATION',
+            'ALLIANCE',
+            -- minimum standing the entity has set towards the character,
+            -- its corporation or alliance
+            'STANDING'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+CREATE TABLE IF NOT EXISTS access_rule (
+    id              UUID                NOT NULL DEFAULT uuidv7(),
+    domain          VARCHAR             NOT NULL,
+
+    effect          ACCESS_RULE_EFFECT  NOT NULL,
+    subject         ACCESS_RULE_SUBJECT NOT NULL,
+    -- either a character, corporation or alliance id
+    -- for STANDING rules the entity that has set the standings
+    entity_id       INTEGER             NOT NULL,
+    -- only set for STANDING rules
+    min_standing    REAL,
+
+    description     VARCHAR,
+
+    created_at      TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id)
+);
+CREATE INDEX IF NOT EXISTS access_rule_domain ON access_rule (domain);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON access_rule
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+-- last result of the access evaluation for a character in a domain
+CREATE TABLE IF NOT EXISTS access_evaluation (
+    character_id    INTEGER     NOT NULL,
+    domain          VARCHAR     NOT NULL,
+
+    allowed         BOOLEAN     NOT NULL,
+    -- rule that lead to the decision, NULL if no rule matched
+    rule_id         UUID,
+
+    evaluated_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (character_id, domain)
+);
@@ -0,0 +1,29 @@
+-- personal api tokens, that can be used by scripts and third-party tools
+-- instead of logging in
+CREATE TABLE IF NOT EXISTS api_token (
+    id              UUID        NOT NULL DEFAULT uuidv7(),
+    character_id    INTEGER     NOT NULL,
+    -- domain the token was created for
+    domain          VARCHAR     NOT NULL,
+
+    name            VARCHAR     NOT NULL,
+    -- sha256 of the token, the token itself is only shown once
+    token_hash      VARCHAR     NOT NULL,
+    -- first characters of the token, so that the user can identify it
+    token_prefix    VARCHAR     NOT NULL,
+    -- format: `<route>:<read|write>`
+    scopes          VARCHAR[]   NOT NULL,
+
+    expires_at      TIMESTAMPTZ,
+    last_used_at    TIMESTAMPTZ,
+
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id),
+    UNIQUE (token_hash)
+);
+CREATE INDEX IF NOT EXISTS api_token_character ON api_token (character_id, domain);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON api_token
+    EXECUTE FUNCTION trigger_set_updated_at();
@@ -0,0 +1,16 @@
+-- domains that were already seeded from the deprecated whitelist, the
+-- whitelist is only imported once per domain
+CREATE TABLE IF NOT EXISTS access_rule_seed (
+    domain      VARCHAR     NOT NULL,
+
+    seeded_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (domain)
+);
+
+-- domains that already have rules were either seeded before or are managed
+-- through the API
+INSERT INTO access_rule_seed (domain)
+SELECT DISTINCT domain
+FROM access_rule
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,49 @@
+mod create;
+mod delete;
+mod error;
+mod evaluate;
+mod list;
+mod update;
+
+pub mod service;
+
+use axum::middleware;
+use starfoundry_lib_gateway::assert_admin;
+use starfoundry_lib_types::starfoundry_uuid;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use crate::state::AppState;
+
+pub use self::error::*;
+
+/// Exposes all routes that are under `/access-rules`
+/// 
+/// All routes are only available for admins of the requesting domain.
+/// 
+pub fn routes() -> OpenApiRouter<AppState> {
+    let create = OpenApiRouter::new()
+        .routes(routes!(create::api));
+
+    let delete = OpenApiRouter::new()
+        .routes(routes!(delete::api));
+
+    let evaluate = OpenApiRouter::new()
+        .routes(routes!(evaluate::api));
+
+    let list = OpenApiRouter::new()
+        .routes(routes!(list::api));
+
+    let update = OpenApiRouter::new()
+        .routes(routes!(update::api));
+
+    OpenApiRouter::new()
+        .merge(create)
+        .merge(delete)
+        .merge(evaluate)
+        .merge(list)
+        .merge(update)
+        .route_layer(middleware::from_fn(assert_admin))
+}
+
+starfoundry_uuid!(AccessRuleUuid, "AccessRuleUuid");
@@ -0,0 +1,61 @@
+use axum::extract::State;
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::access::{AccessRuleUuid, Result};
+use crate::access::service::{create_rule, AccessRuleRequest};
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, Unauthorized};
+use crate::state::AppState;
+
+/// Create Access Rule
+/// 
+/// - Alternative route: `/latest/access-rules`
+/// - Alternative route: `/v1/access-rules`
+/// 
+/// ---
+/// 
+/// Creates a new access rule for the requesting domain.
+/// 
+/// The rule is applied on the next login of a character and during the
+/// periodic evaluation, use `/access-rules/evaluate` to apply it immediately.
+/// 
+/// Requires the requesting character to be an admin of the domain.
+/// 
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "Access Rules",
+    request_body = AccessRuleRequest,
+    responses(
+        (
+            body = AccessRuleUuid,
+            description = "Id of the new rule",
+            status = CREATED,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+    Json(rule):   Json<AccessRuleRequest>,
+) -> Result<impl IntoResponse> {
+    let id = create_rule(
+        &state.postgres,
+        &identity.host()?,
+        rule,
+    ).await?;
+
+    Ok(
+        (
+            StatusCode::CREATED,
+            Json(id),
+        )
+        .into_response()
+    )
+}
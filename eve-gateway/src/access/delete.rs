@@ -0,0 +1,57 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::access::{AccessRuleUuid, Result};
+use crate::access::service::delete_rule;
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::state::AppState;
+
+/// Delete Access Rule
+/// 
+/// - Alternative route: `/latest/access-rules/{AccessRuleUuid}`
+/// - Alternative route: `/v1/access-rules/{AccessRuleUuid}`
+/// 
+/// ---
+/// 
+/// Deletes an access rule of the requesting domain.
+/// 
+/// Requires the requesting character to be an admin of the domain.
+/// 
+#[utoipa::path(
+    delete,
+    path = "/{AccessRuleUuid}",
+    tag = "Access Rules",
+    params(
+        AccessRuleUuid,
+    ),
+    responses(
+        (
+            description = "The rule was deleted",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:      ExtractIdentity,
+    State(state):  State<AppState>,
+    Path(rule_id): Path<AccessRuleUuid>,
+) -> Result<impl IntoResponse> {
+    delete_rule(
+        &state.postgres,
+        &identity.host()?,
+        rule_id,
+    ).await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+        )
+        .into_response()
+    )
+}
@@ -0,0 +1,95 @@
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use starfoundry_lib_gateway::boxed_from;
+use thiserror::Error;
+
+use crate::access::AccessRuleUuid;
+use crate::api_docs::ErrorResponse;
+use crate::character::CharacterError;
+
+pub type Result<T, E = AccessError> = std::result::Result<T, E>;
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum AccessError {
+    #[error("error while listing access rules, error: '{0}'")]
+    ListRules(sqlx::Error),
+    #[error("error while creating access rule, error: '{0}'")]
+    CreateRule(sqlx::Error),
+    #[error("error while updating access rule '{1}', error: '{0}'")]
+    UpdateRule(sqlx::Error, AccessRuleUuid),
+    #[error("error while deleting access rule '{1}', error: '{0}'")]
+    DeleteRule(sqlx::Error, AccessRuleUuid),
+    #[error("the access rule '{0}' could not be found")]
+    NotFound(AccessRuleUuid),
+    #[error("invalid access rule, '{0}'")]
+    ValidationError(String),
+    #[error("error while seeding access rules of '{1}', error: '{0}'")]
+    SeedRules(sqlx::Error, String),
+
+    #[error("error while fetching standings, error: '{0}'")]
+    FetchStandings(sqlx::Error),
+    #[error("error while fetching characters of the domain, error: '{0}'")]
+    FetchCharacters(sqlx::Error),
+    #[error("error while fetching the access evaluation, error: '{0}'")]
+    FetchEvaluation(sqlx::Error),
+    #[error("error while storing the access evaluation, error: '{0}'")]
+    InsertEvaluation(sqlx::Error),
+
+    #[error("character error, error: '{0}'")]
+    CharacterError(Box<CharacterError>),
+    #[error("gateway error, error: '{0:?}'")]
+    GatewayError(Box<starfoundry_lib_gateway::Error>),
+}
+
+impl IntoResponse for AccessError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(_) => {
+                tracing::warn!("{}", self.to_string());
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(
+                        ErrorResponse {
+                            error: "NOT_FOUND".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+            Self::ValidationError(_) => {
+                tracing::warn!("{}", self.to_string());
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(
+                        ErrorResponse {
+                            error: "VALIDATION".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+            Self::GatewayError(e) => {
+                starfoundry_lib_gateway::Error::into_response(*e)
+            },
+
+            _ => {
+                tracing::error!("{}", self.to_string());
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(
+                        ErrorResponse {
+                            error: "UNKNOWN".into(),
+                            description: "An unknown error occurred, please try again later.".into(),
+                        }
+                    )
+                ).into_response()
+            },
+        }
+        .into_response()
+    }
+}
+
+boxed_from!(AccessError::CharacterError, CharacterError);
+boxed_from!(AccessError::GatewayError, starfoundry_lib_gateway::Error);
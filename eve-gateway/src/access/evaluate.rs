@@ -0,0 +1,65 @@
+use axum::extract::State;
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::access::Result;
+use crate::access::service::{evaluate_domain, AccessDecision};
+use crate::api_docs::{Forbidden, InternalServerError, Unauthorized};
+use crate::state::AppState;
+
+/// Evaluate Access Rules
+/// 
+/// - Alternative route: `/latest/access-rules/evaluate`
+/// - Alternative route: `/v1/access-rules/evaluate`
+/// 
+/// ---
+/// 
+/// Re-evaluates the access rules for every character that has logged into the
+/// requesting domain.
+/// Characters that lost their access can no longer request new access tokens.
+/// 
+/// Requires the requesting character to be an admin of the domain.
+/// 
+#[utoipa::path(
+    post,
+    path = "/evaluate",
+    tag = "Access Rules",
+    responses(
+        (
+            body = Vec<AccessDecision>,
+            description = "Result of the evaluation for every character",
+            status = OK,
+        ),
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse> {
+    let domain = identity.host()?;
+    let admins = state
+        .auth_domains
+        .get(&domain)
+        .map(|x| x.admins.clone())
+        .unwrap_or_default();
+
+    let entries = evaluate_domain(
+        &state.postgres,
+        state.eve_api_metric,
+        &domain,
+        &admins,
+    ).await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(entries),
+        )
+        .into_response()
+    )
+}
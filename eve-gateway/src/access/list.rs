@@ -0,0 +1,54 @@
+use axum::extract::State;
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::access::Result;
+use crate::access::service::{list_rules, AccessRule};
+use crate::api_docs::{Forbidden, InternalServerError, Unauthorized};
+use crate::state::AppState;
+
+/// List Access Rules
+/// 
+/// - Alternative route: `/latest/access-rules`
+/// - Alternative route: `/v1/access-rules`
+/// 
+/// ---
+/// 
+/// Lists all access rules of the requesting domain.
+/// 
+/// Requires the requesting character to be an admin of the domain.
+/// 
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "Access Rules",
+    responses(
+        (
+            body = Vec<AccessRule>,
+            description = "All access rules of the domain",
+            status = OK,
+        ),
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse> {
+    let entries = list_rules(
+        &state.postgres,
+        &identity.host()?,
+    ).await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(entries),
+        )
+        .into_response()
+    )
+}
@@ -0,0 +1,15 @@
+mod access_rule;
+mod create;
+mod delete;
+mod evaluate;
+mod list;
+mod seed;
+mod update;
+
+pub use self::access_rule::*;
+pub use self::create::*;
+pub use self::delete::*;
+pub use self::evaluate::*;
+pub use self::list::*;
+pub use self::seed::*;
+pub use self::update::*;
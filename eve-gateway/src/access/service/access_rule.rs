@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::access::{AccessError, AccessRuleUuid, Result};
+
+/// Rule that grants or revokes access to a domain
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "id": "019a0a3b-6a3c-7e43-9b7c-3bd1e0d1c6a1",
+        "effect": "ALLOW",
+        "subject": "STANDING",
+        "entity_id": 99003214,
+        "min_standing": 5.0,
+        "description": "Blue to the alliance"
+    })
+)]
+pub struct AccessRule {
+    pub id:             AccessRuleUuid,
+    pub effect:         AccessRuleEffect,
+    pub subject:        AccessRuleSubject,
+    /// Either a character, corporation or alliance id.
+    /// For [AccessRuleSubject::Standing] the entity that has set the standings
+    pub entity_id:      i32,
+    /// Only set for [AccessRuleSubject::Standing]
+    pub min_standing:   Option<f32>,
+    pub description:    Option<String>,
+}
+
+/// Body for creating and updating an [AccessRule]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "effect": "DENY",
+        "subject": "CORPORATION",
+        "entity_id": 98000001,
+        "description": "Known spies"
+    })
+)]
+pub struct AccessRuleRequest {
+    pub effect:         AccessRuleEffect,
+    pub subject:        AccessRuleSubject,
+    pub entity_id:      i32,
+    #[serde(default)]
+    pub min_standing:   Option<f32>,
+    #[serde(default)]
+    pub description:    Option<String>,
+}
+
+impl AccessRuleRequest {
+    pub fn validate(&self) -> Result<()> {
+        match self.subject {
+            AccessRuleSubject::Standing => {
+                match self.min_standing {
+                    Some(x) if (-10f32..=10f32).contains(&x) => Ok(()),
+                    Some(_) => Err(AccessError::ValidationError("Field 'min_standing' must be between -10 and 10".into())),
+                    None    => Err(AccessError::ValidationError("Field 'min_standing' must be set for standing rules".into())),
+                }
+            },
+            AccessRuleSubject::Character   |
+            AccessRuleSubject::Corporation |
+            AccessRuleSubject::Alliance    => {
+                if self.min_standing.is_some() {
+                    Err(AccessError::ValidationError("Field 'min_standing' is only allowed for standing rules".into()))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "ACCESS_RULE_EFFECT")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccessRuleEffect {
+    /// Characters matching the rule are allowed to login
+    Allow,
+    /// Characters matching the rule are never allowed to login, deny rules
+    /// always win over allow rules
+    Deny,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "ACCESS_RULE_SUBJECT")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccessRuleSubject {
+    /// Matches the character itself
+    Character,
+    /// Matches all characters in the corporation
+    Corporation,
+    /// Matches all characters in the alliance
+    Alliance,
+    /// Matches all characters the entity has set a standing of at least
+    /// `min_standing` for
+    Standing,
+}
@@ -0,0 +1,37 @@
+use sqlx::PgPool;
+
+use crate::access::{AccessError, AccessRuleUuid, Result};
+use crate::access::service::AccessRuleRequest;
+
+/// Creates a new rule for the given domain
+pub async fn create_rule(
+    pool:   &PgPool,
+    domain: &str,
+    rule:   AccessRuleRequest,
+) -> Result<AccessRuleUuid> {
+    rule.validate()?;
+
+    sqlx::query!("
+            INSERT INTO access_rule (
+                domain,
+                effect,
+                subject,
+                entity_id,
+                min_standing,
+                description
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id
+        ",
+            domain,
+            rule.effect as _,
+            rule.subject as _,
+            rule.entity_id,
+            rule.min_standing,
+            rule.description,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|x| x.id.into())
+        .map_err(AccessError::CreateRule)
+}
@@ -0,0 +1,28 @@
+use sqlx::PgPool;
+
+use crate::access::{AccessError, AccessRuleUuid, Result};
+
+/// Deletes a rule of the given domain
+pub async fn delete_rule(
+    pool:    &PgPool,
+    domain:  &str,
+    rule_id: AccessRuleUuid,
+) -> Result<()> {
+    let result = sqlx::query!("
+            DELETE FROM access_rule
+            WHERE id = $1
+            AND domain = $2
+        ",
+            *rule_id,
+            domain,
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| AccessError::DeleteRule(e, rule_id))?;
+
+    if result.rows_affected() == 0 {
+        Err(AccessError::NotFound(rule_id))
+    } else {
+        Ok(())
+    }
+}
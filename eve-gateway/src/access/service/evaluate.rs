@@ -0,0 +1,439 @@
+use serde::Serialize;
+use sqlx::PgPool;
+use starfoundry_lib_eve_client::EveApiClientMetric;
+use starfoundry_lib_eve_gateway::CharacterInfo;
+use starfoundry_lib_types::CharacterId;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use utoipa::ToSchema;
+
+use crate::access::{AccessError, AccessRuleUuid, Result};
+use crate::access::service::{list_rules, AccessRule, AccessRuleEffect, AccessRuleSubject};
+use crate::character::service::{fetch_character, refresh_character_in_db};
+use crate::state::AppState;
+
+/// Affiliations older than this are refreshed before a periodic evaluation
+const AFFILIATION_MAX_AGE_MINUTES: i32 = 60;
+
+/// Result of evaluating the access rules for a character
+#[derive(Clone, Debug, PartialEq, Serialize, ToSchema)]
+pub struct AccessDecision {
+    pub character_id: CharacterId,
+    pub allowed:      bool,
+    /// Rule that lead to the decision, [None] if no rule matched
+    pub rule_id:      Option<AccessRuleUuid>,
+}
+
+/// Everything that is required for matching a character against the rules
+#[derive(Clone, Debug)]
+pub struct AccessSubject {
+    pub character_info: CharacterInfo,
+    /// Standing of every entity referenced by a standing rule towards the
+    /// character, key is the entity_id of the rule
+    pub standings:      HashMap<i32, f32>,
+}
+
+/// Decides if the subject is allowed to access the domain.
+///
+/// - a matching `DENY` rule always revokes access
+/// - a matching `ALLOW` rule grants access
+/// - if there are no `ALLOW` rules at all, everybody is allowed
+///
+#[must_use]
+pub fn decide(
+    rules:   &[AccessRule],
+    subject: &AccessSubject,
+) -> AccessDecision {
+    let character_id = subject.character_info.character_id;
+    let matches = |effect: AccessRuleEffect| {
+        rules
+            .iter()
+            .filter(|x| x.effect == effect)
+            .find(|x| rule_matches(x, subject))
+            .map(|x| x.id)
+    };
+
+    if let Some(x) = matches(AccessRuleEffect::Deny) {
+        return AccessDecision {
+            character_id,
+            allowed: false,
+            rule_id: Some(x),
+        };
+    }
+
+    if let Some(x) = matches(AccessRuleEffect::Allow) {
+        return AccessDecision {
+            character_id,
+            allowed: true,
+            rule_id: Some(x),
+        };
+    }
+
+    AccessDecision {
+        character_id,
+        allowed: !rules.iter().any(|x| x.effect == AccessRuleEffect::Allow),
+        rule_id: None,
+    }
+}
+
+fn rule_matches(
+    rule:    &AccessRule,
+    subject: &AccessSubject,
+) -> bool {
+    let character_info = &subject.character_info;
+
+    match rule.subject {
+        AccessRuleSubject::Character   => *character_info.character_id == rule.entity_id,
+        AccessRuleSubject::Corporation => *character_info.corporation_id == rule.entity_id,
+        AccessRuleSubject::Alliance    => character_info.alliance_id.map(|x| *x) == Some(rule.entity_id),
+        AccessRuleSubject::Standing    => {
+            match (subject.standings.get(&rule.entity_id), rule.min_standing) {
+                (Some(standing), Some(min_standing)) => *standing >= min_standing,
+                _                                    => false,
+            }
+        },
+    }
+}
+
+/// Evaluates the rules of the domain for a single character and stores the
+/// result.
+/// Admins of the domain are always allowed, to prevent locking them out.
+///
+pub async fn evaluate_character(
+    pool:           &PgPool,
+    domain:         &str,
+    admins:         &[CharacterId],
+    character_info: CharacterInfo,
+) -> Result<AccessDecision> {
+    let rules = list_rules(pool, domain).await?;
+    evaluate_with_rules(pool, domain, admins, &rules, character_info).await
+}
+
+/// Evaluates all main characters that have logged into the domain.
+/// The affiliation of the characters is refreshed if it is outdated, so that
+/// characters that left a corporation or alliance lose their access.
+///
+pub async fn evaluate_domain(
+    pool:   &PgPool,
+    metric: Arc<EveApiClientMetric>,
+    domain: &str,
+    admins: &[CharacterId],
+) -> Result<Vec<AccessDecision>> {
+    let rules = list_rules(pool, domain).await?;
+
+    let characters = sqlx::query!("
+            SELECT
+                ec.character_id,
+                c.updated_at < NOW() - make_interval(mins => $2) AS outdated
+            FROM eve_credential ec
+            LEFT JOIN character c ON c.character_id = ec.character_id
+            WHERE ec.domain = $1
+            AND ec.character_main IS NULL
+        ",
+            domain,
+            AFFILIATION_MAX_AGE_MINUTES,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(AccessError::FetchCharacters)?;
+
+    let mut decisions = Vec::new();
+    for character in characters {
+        let character_id: CharacterId = character.character_id.into();
+
+        let character_info = if character.outdated.unwrap_or(true) {
+            refresh_character_in_db(pool, metric.clone(), character_id).await
+        } else {
+            fetch_character(pool, metric.clone(), character_id).await
+        };
+        let character_info = match character_info {
+            Ok(x) => x,
+            Err(e) => {
+                tracing::warn!("could not load affiliation for {character_id}, skipping, error: {e}");
+                continue;
+            }
+        };
+
+        let decision = evaluate_with_rules(
+            pool,
+            domain,
+            admins,
+            &rules,
+            character_info,
+        ).await?;
+        decisions.push(decision);
+    }
+
+    Ok(decisions)
+}
+
+/// Checks if the last evaluation revoked the access of the character for the
+/// domain.
+/// Characters that were never evaluated are not denied, they are evaluated
+/// during their next login.
+///
+pub async fn is_access_denied(
+    pool:         &PgPool,
+    domain:       &str,
+    character_id: CharacterId,
+) -> Result<bool> {
+    sqlx::query!("
+            SELECT allowed
+            FROM access_evaluation
+            WHERE character_id = $1
+            AND domain = $2
+        ",
+            *character_id,
+            domain,
+        )
+        .fetch_optional(pool)
+        .await
+        .map(|x| x.map(|x| !x.allowed).unwrap_or_default())
+        .map_err(AccessError::FetchEvaluation)
+}
+
+/// Runs the evaluation for all configured domains in the given interval.
+/// Never returns.
+///
+pub async fn evaluate_periodically(
+    state:    AppState,
+    interval: Duration,
+) {
+    let mut interval = tokio::time::interval(interval);
+
+    loop {
+        interval.tick().await;
+
+        for (domain, config) in state.auth_domains.iter() {
+            match evaluate_domain(
+                &state.postgres,
+                state.eve_api_metric.clone(),
+                domain,
+                &config.admins,
+            ).await {
+                Ok(x) => {
+                    let denied = x.iter().filter(|x| !x.allowed).count();
+                    tracing::info!("evaluated access for {} characters in '{domain}', {denied} denied", x.len());
+                },
+                Err(e) => tracing::error!("error while evaluating access for '{domain}', error: {e}"),
+            }
+        }
+    }
+}
+
+async fn evaluate_with_rules(
+    pool:           &PgPool,
+    domain:         &str,
+    admins:         &[CharacterId],
+    rules:          &[AccessRule],
+    character_info: CharacterInfo,
+) -> Result<AccessDecision> {
+    let decision = if admins.contains(&character_info.character_id) {
+        AccessDecision {
+            character_id: character_info.character_id,
+            allowed:      true,
+            rule_id:      None,
+        }
+    } else {
+        let standings = fetch_standings(pool, rules, &character_info).await?;
+        decide(rules, &AccessSubject {
+            character_info,
+            standings,
+        })
+    };
+
+    sqlx::query!("
+            INSERT INTO access_evaluation (
+                character_id,
+                domain,
+                allowed,
+                rule_id
+            )
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (character_id, domain)
+            DO UPDATE SET
+                allowed      = EXCLUDED.allowed,
+                rule_id      = EXCLUDED.rule_id,
+                evaluated_at = NOW()
+        ",
+            *decision.character_id,
+            domain,
+            decision.allowed,
+            decision.rule_id.map(|x| *x),
+        )
+        .execute(pool)
+        .await
+        .map_err(AccessError::InsertEvaluation)?;
+
+    Ok(decision)
+}
+
+/// Fetches the standings of all entities referenced by standing rules towards
+/// the character.
+/// The most specific standing wins, a standing towards the character is
+/// preferred over one towards the corporation, which is preferred over one
+/// towards the alliance.
+///
+async fn fetch_standings(
+    pool:           &PgPool,
+    rules:          &[AccessRule],
+    character_info: &CharacterInfo,
+) -> Result<HashMap<i32, f32>> {
+    let entity_ids = rules
+        .iter()
+        .filter(|x| x.subject == AccessRuleSubject::Standing)
+        .map(|x| x.entity_id)
+        .collect::<Vec<_>>();
+
+    if entity_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    // the standings are stored by the character that synced them, the source
+    // determines if they are from the character, its corporation or alliance
+    sqlx::query!(r#"
+            SELECT DISTINCT ON (entity_id)
+                entity_id AS "entity_id!",
+                standing AS "standing!"
+            FROM (
+                SELECT
+                    CASE s.source
+                        WHEN 'alliance'    THEN c.alliance_id
+                        WHEN 'corporation' THEN c.corporation_id
+                        ELSE s.owner_id
+                    END AS entity_id,
+                    s.standing,
+                    CASE s.contact_id
+                        WHEN $1 THEN 0
+                        WHEN $2 THEN 1
+                        ELSE 2
+                    END AS priority
+                FROM standing s
+                JOIN character c ON c.character_id = s.owner_id
+                WHERE s.contact_id = $1
+                OR s.contact_id = $2
+                OR s.contact_id = $3
+            ) standings
+            WHERE entity_id = ANY($4)
+            ORDER BY entity_id, priority
+        "#,
+            *character_info.character_id,
+            *character_info.corporation_id,
+            character_info.alliance_id.map(|x| *x),
+            &entity_ids,
+        )
+        .fetch_all(pool)
+        .await
+        .map(|entries| {
+            entries
+                .into_iter()
+                .map(|x| (x.entity_id, x.standing))
+                .collect::<HashMap<_, _>>()
+        })
+        .map_err(AccessError::FetchStandings)
+}
+
+#[cfg(test)]
+mod evaluate_test {
+    use starfoundry_lib_eve_gateway::CharacterInfo;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    use crate::access::service::{decide, AccessRule, AccessRuleEffect, AccessRuleSubject, AccessSubject};
+
+    fn rule(
+        effect:       AccessRuleEffect,
+        subject:      AccessRuleSubject,
+        entity_id:    i32,
+        min_standing: Option<f32>,
+    ) -> AccessRule {
+        AccessRule {
+            id:           Uuid::now_v7().into(),
+            effect,
+            subject,
+            entity_id,
+            min_standing,
+            description:  None,
+        }
+    }
+
+    fn subject(
+        standings: HashMap<i32, f32>,
+    ) -> AccessSubject {
+        AccessSubject {
+            character_info: CharacterInfo {
+                character_name:   "Character".into(),
+                character_id:     1.into(),
+                corporation_name: "Corporation".into(),
+                corporation_id:   2.into(),
+                alliance_name:    Some("Alliance".into()),
+                alliance_id:      Some(3.into()),
+            },
+            standings,
+        }
+    }
+
+    #[test]
+    fn no_rules_allows_everybody() {
+        let decision = decide(&[], &subject(HashMap::new()));
+        assert!(decision.allowed);
+        assert!(decision.rule_id.is_none());
+    }
+
+    #[test]
+    fn only_deny_rules_allows_everybody_else() {
+        let rules = vec![
+            rule(AccessRuleEffect::Deny, AccessRuleSubject::Corporation, 100, None),
+        ];
+        assert!(decide(&rules, &subject(HashMap::new())).allowed);
+    }
+
+    #[test]
+    fn allow_by_alliance() {
+        let rules = vec![
+            rule(AccessRuleEffect::Allow, AccessRuleSubject::Corporation, 100, None),
+            rule(AccessRuleEffect::Allow, AccessRuleSubject::Alliance, 3, None),
+        ];
+
+        let decision = decide(&rules, &subject(HashMap::new()));
+        assert!(decision.allowed);
+        assert_eq!(decision.rule_id, rules.get(1).map(|x| x.id));
+    }
+
+    #[test]
+    fn not_matching_any_allow_rule() {
+        let rules = vec![
+            rule(AccessRuleEffect::Allow, AccessRuleSubject::Alliance, 100, None),
+        ];
+
+        let decision = decide(&rules, &subject(HashMap::new()));
+        assert!(!decision.allowed);
+        assert!(decision.rule_id.is_none());
+    }
+
+    #[test]
+    fn deny_wins_over_allow() {
+        let rules = vec![
+            rule(AccessRuleEffect::Allow, AccessRuleSubject::Alliance, 3, None),
+            rule(AccessRuleEffect::Deny, AccessRuleSubject::Character, 1, None),
+        ];
+
+        let decision = decide(&rules, &subject(HashMap::new()));
+        assert!(!decision.allowed);
+        assert_eq!(decision.rule_id, rules.get(1).map(|x| x.id));
+    }
+
+    #[test]
+    fn minimum_standing() {
+        let rules = vec![
+            rule(AccessRuleEffect::Allow, AccessRuleSubject::Standing, 500, Some(5f32)),
+        ];
+
+        assert!(decide(&rules, &subject(HashMap::from([(500, 10f32)]))).allowed);
+        assert!(decide(&rules, &subject(HashMap::from([(500, 5f32)]))).allowed);
+        assert!(!decide(&rules, &subject(HashMap::from([(500, 0f32)]))).allowed);
+        assert!(!decide(&rules, &subject(HashMap::from([(501, 10f32)]))).allowed);
+        assert!(!decide(&rules, &subject(HashMap::new())).allowed);
+    }
+}
@@ -0,0 +1,41 @@
+use sqlx::PgPool;
+
+use crate::access::{AccessError, Result};
+use crate::access::service::{AccessRule, AccessRuleEffect, AccessRuleSubject};
+
+/// Lists all rules of the given domain
+pub async fn list_rules(
+    pool:   &PgPool,
+    domain: &str,
+) -> Result<Vec<AccessRule>> {
+    sqlx::query!(r#"
+            SELECT
+                id,
+                effect AS "effect!: AccessRuleEffect",
+                subject AS "subject!: AccessRuleSubject",
+                entity_id,
+                min_standing,
+                description
+            FROM access_rule
+            WHERE domain = $1
+            ORDER BY created_at
+        "#,
+            domain,
+        )
+        .fetch_all(pool)
+        .await
+        .map(|entries| {
+            entries
+                .into_iter()
+                .map(|x| AccessRule {
+                    id:           x.id.into(),
+                    effect:       x.effect,
+                    subject:      x.subject,
+                    entity_id:    x.entity_id,
+                    min_standing: x.min_standing,
+                    description:  x.description,
+                })
+                .collect::<Vec<_>>()
+        })
+        .map_err(AccessError::ListRules)
+}
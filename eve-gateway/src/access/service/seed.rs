@@ -0,0 +1,88 @@
+use sqlx::PgPool;
+use std::collections::HashMap;
+
+use crate::access::{AccessError, Result};
+use crate::access::service::{create_rule, list_rules, AccessRuleEffect, AccessRuleRequest, AccessRuleSubject};
+use crate::config::ConfigFileDomain;
+
+/// Migrates the deprecated `whitelist` from the config file into access rules.
+///
+/// Every domain is only seeded once, afterwards the rules are managed through
+/// the API, even if all of them are deleted.
+/// Domains that already have rules are marked as seeded without importing
+/// the whitelist.
+///
+pub async fn seed_from_whitelist(
+    pool:    &PgPool,
+    domains: &HashMap<String, ConfigFileDomain>,
+) -> Result<()> {
+    for (domain, config) in domains.iter() {
+        if config.whitelist.is_empty() {
+            continue;
+        }
+
+        let seeded = sqlx::query!("
+                SELECT domain
+                FROM access_rule_seed
+                WHERE domain = $1
+            ",
+                domain,
+            )
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| AccessError::SeedRules(e, domain.clone()))?
+            .is_some();
+        if seeded {
+            continue;
+        }
+
+        if list_rules(pool, domain).await?.is_empty() {
+            tracing::info!("seeding access rules for '{domain}' from the whitelist");
+            for entity_id in config.whitelist.iter() {
+                let entity_id = if let Ok(x) = i32::try_from(*entity_id) {
+                    x
+                } else {
+                    tracing::warn!("'{entity_id}' is not a valid eve id, skipping");
+                    continue;
+                };
+
+                create_rule(pool, domain, AccessRuleRequest {
+                    effect:       AccessRuleEffect::Allow,
+                    subject:      subject_from_id(entity_id),
+                    entity_id:    entity_id,
+                    min_standing: None,
+                    description:  Some("Imported from whitelist".into()),
+                }).await?;
+            }
+        }
+
+        sqlx::query!("
+                INSERT INTO access_rule_seed (domain)
+                VALUES ($1)
+                ON CONFLICT DO NOTHING
+            ",
+                domain,
+            )
+            .execute(pool)
+            .await
+            .map_err(|e| AccessError::SeedRules(e, domain.clone()))?;
+    }
+
+    Ok(())
+}
+
+/// The whitelist did not differentiate between characters, corporations and
+/// alliances, so the subject is guessed from the documented id ranges.
+///
+/// <https://developers.eveonline.com/docs/guides/id-ranges/>
+///
+fn subject_from_id(
+    entity_id: i32,
+) -> AccessRuleSubject {
+    match entity_id {
+        1_000_000..=1_999_999   |
+        98_000_000..=98_999_999 => AccessRuleSubject::Corporation,
+        99_000_000..=99_999_999 => AccessRuleSubject::Alliance,
+        _                       => AccessRuleSubject::Character,
+    }
+}
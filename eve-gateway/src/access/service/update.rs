@@ -0,0 +1,43 @@
+use sqlx::PgPool;
+
+use crate::access::{AccessError, AccessRuleUuid, Result};
+use crate::access::service::AccessRuleRequest;
+
+/// Updates a rule of the given domain
+pub async fn update_rule(
+    pool:    &PgPool,
+    domain:  &str,
+    rule_id: AccessRuleUuid,
+    rule:    AccessRuleRequest,
+) -> Result<()> {
+    rule.validate()?;
+
+    let result = sqlx::query!("
+            UPDATE access_rule
+            SET
+                effect       = $3,
+                subject      = $4,
+                entity_id    = $5,
+                min_standing = $6,
+                description  = $7
+            WHERE id = $1
+            AND domain = $2
+        ",
+            *rule_id,
+            domain,
+            rule.effect as _,
+            rule.subject as _,
+            rule.entity_id,
+            rule.min_standing,
+            rule.description,
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| AccessError::UpdateRule(e, rule_id))?;
+
+    if result.rows_affected() == 0 {
+        Err(AccessError::NotFound(rule_id))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::access::{AccessRuleUuid, Result};
+use crate::access::service::{update_rule, AccessRuleRequest};
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::state::AppState;
+
+/// Update Access Rule
+/// 
+/// - Alternative route: `/latest/access-rules/{AccessRuleUuid}`
+/// - Alternative route: `/v1/access-rules/{AccessRuleUuid}`
+/// 
+/// ---
+/// 
+/// Updates an existing access rule of the requesting domain.
+/// 
+/// Requires the requesting character to be an admin of the domain.
+/// 
+#[utoipa::path(
+    put,
+    path = "/{AccessRuleUuid}",
+    tag = "Access Rules",
+    request_body = AccessRuleRequest,
+    params(
+        AccessRuleUuid,
+    ),
+    responses(
+        (
+            description = "The rule was updated",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:      ExtractIdentity,
+    State(state):  State<AppState>,
+    Path(rule_id): Path<AccessRuleUuid>,
+    Json(rule):    Json<AccessRuleRequest>,
+) -> Result<impl IntoResponse> {
+    update_rule(
+        &state.postgres,
+        &identity.host()?,
+        rule_id,
+        rule,
+    ).await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+        )
+        .into_response()
+    )
+}
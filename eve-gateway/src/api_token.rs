@@ -0,0 +1,37 @@
+mod create;
+mod delete;
+mod error;
+mod list;
+
+pub mod service;
+
+use starfoundry_lib_types::starfoundry_uuid;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use crate::state::AppState;
+
+pub use self::error::*;
+
+/// Exposes all routes that are under `/api-tokens`
+/// 
+/// The routes only manage the tokens of the requesting character, exchanging
+/// a token is done using `/auth/api-token`.
+/// 
+pub fn routes() -> OpenApiRouter<AppState> {
+    let create = OpenApiRouter::new()
+        .routes(routes!(create::api));
+
+    let delete = OpenApiRouter::new()
+        .routes(routes!(delete::api));
+
+    let list = OpenApiRouter::new()
+        .routes(routes!(list::api));
+
+    OpenApiRouter::new()
+        .merge(create)
+        .merge(delete)
+        .merge(list)
+}
+
+starfoundry_uuid!(ApiTokenUuid, "ApiTokenUuid");
@@ -0,0 +1,62 @@
+use axum::extract::State;
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::api_token::Result;
+use crate::api_token::service::{create_token, CreateApiToken, CreatedApiToken};
+use crate::api_docs::{BadRequest, InternalServerError, Unauthorized};
+use crate::state::AppState;
+
+/// Create Api Token
+/// 
+/// - Alternative route: `/latest/api-tokens`
+/// - Alternative route: `/v1/api-tokens`
+/// 
+/// ---
+/// 
+/// Creates a new personal api token for the requesting character.
+/// 
+/// The token can be used in the `AUTHORIZATION` header instead of an access
+/// token. Requests are only allowed for the routes in the given scopes, for
+/// example `projects:read` only allows `GET` requests to `/projects`.
+/// 
+/// The token is only returned once.
+/// 
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "Api Tokens",
+    request_body = CreateApiToken,
+    responses(
+        (
+            body = CreatedApiToken,
+            description = "The new token",
+            status = CREATED,
+        ),
+        BadRequest,
+        Unauthorized,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+    Json(token):  Json<CreateApiToken>,
+) -> Result<impl IntoResponse> {
+    let token = create_token(
+        &state.postgres,
+        identity.character_id,
+        &identity.host()?,
+        token,
+    ).await?;
+
+    Ok(
+        (
+            StatusCode::CREATED,
+            Json(token),
+        )
+        .into_response()
+    )
+}
@@ -0,0 +1,57 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::api_token::{ApiTokenUuid, Result};
+use crate::api_token::service::delete_token;
+use crate::api_docs::{InternalServerError, NotFound, Unauthorized};
+use crate::state::AppState;
+
+/// Delete Api Token
+/// 
+/// - Alternative route: `/latest/api-tokens/{ApiTokenUuid}`
+/// - Alternative route: `/v1/api-tokens/{ApiTokenUuid}`
+/// 
+/// ---
+/// 
+/// Revokes a personal api token of the requesting character.
+/// 
+/// Already exchanged access tokens stay valid for at most one minute.
+/// 
+#[utoipa::path(
+    delete,
+    path = "/{ApiTokenUuid}",
+    tag = "Api Tokens",
+    params(
+        ApiTokenUuid,
+    ),
+    responses(
+        (
+            description = "The token was deleted",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        NotFound,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:       ExtractIdentity,
+    State(state):   State<AppState>,
+    Path(token_id): Path<ApiTokenUuid>,
+) -> Result<impl IntoResponse> {
+    delete_token(
+        &state.postgres,
+        identity.character_id,
+        &identity.host()?,
+        token_id,
+    ).await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+        )
+        .into_response()
+    )
+}
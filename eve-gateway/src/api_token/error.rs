@@ -0,0 +1,80 @@
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use starfoundry_lib_gateway::boxed_from;
+use thiserror::Error;
+
+use crate::api_docs::ErrorResponse;
+use crate::api_token::ApiTokenUuid;
+
+pub type Result<T, E = ApiTokenError> = std::result::Result<T, E>;
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ApiTokenError {
+    #[error("error while listing api tokens, error: '{0}'")]
+    ListTokens(sqlx::Error),
+    #[error("error while creating api token, error: '{0}'")]
+    CreateToken(sqlx::Error),
+    #[error("error while deleting api token '{1}', error: '{0}'")]
+    DeleteToken(sqlx::Error, ApiTokenUuid),
+    #[error("error while exchanging api token, error: '{0}'")]
+    ExchangeToken(sqlx::Error),
+    #[error("the api token '{0}' could not be found")]
+    NotFound(ApiTokenUuid),
+    #[error("invalid api token, '{0}'")]
+    ValidationError(String),
+
+    #[error("gateway error, error: '{0:?}'")]
+    GatewayError(Box<starfoundry_lib_gateway::Error>),
+}
+
+impl IntoResponse for ApiTokenError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(_) => {
+                tracing::warn!("{}", self.to_string());
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(
+                        ErrorResponse {
+                            error: "NOT_FOUND".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+            Self::ValidationError(_) => {
+                tracing::warn!("{}", self.to_string());
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(
+                        ErrorResponse {
+                            error: "VALIDATION".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+            Self::GatewayError(e) => {
+                starfoundry_lib_gateway::Error::into_response(*e)
+            },
+
+            _ => {
+                tracing::error!("{}", self.to_string());
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(
+                        ErrorResponse {
+                            error: "UNKNOWN".into(),
+                            description: "An unknown error occurred, please try again later.".into(),
+                        }
+                    )
+                ).into_response()
+            },
+        }
+        .into_response()
+    }
+}
+
+boxed_from!(ApiTokenError::GatewayError, starfoundry_lib_gateway::Error);
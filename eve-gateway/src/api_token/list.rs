@@ -0,0 +1,52 @@
+use axum::extract::State;
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::api_token::Result;
+use crate::api_token::service::{list_tokens, ApiToken};
+use crate::api_docs::{InternalServerError, Unauthorized};
+use crate::state::AppState;
+
+/// List Api Tokens
+/// 
+/// - Alternative route: `/latest/api-tokens`
+/// - Alternative route: `/v1/api-tokens`
+/// 
+/// ---
+/// 
+/// Lists all personal api tokens of the requesting character.
+/// 
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "Api Tokens",
+    responses(
+        (
+            body = Vec<ApiToken>,
+            description = "All tokens of the character",
+            status = OK,
+        ),
+        Unauthorized,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse> {
+    let entries = list_tokens(
+        &state.postgres,
+        identity.character_id,
+        &identity.host()?,
+    ).await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(entries),
+        )
+        .into_response()
+    )
+}
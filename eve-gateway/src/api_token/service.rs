@@ -0,0 +1,11 @@
+mod api_token;
+mod create;
+mod delete;
+mod exchange;
+mod list;
+
+pub use self::api_token::*;
+pub use self::create::*;
+pub use self::delete::*;
+pub use self::exchange::*;
+pub use self::list::*;
@@ -0,0 +1,130 @@
+use base64::prelude::*;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use starfoundry_lib_gateway::{ApiTokenScope, API_TOKEN_PREFIX};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::api_token::{ApiTokenError, ApiTokenUuid, Result};
+
+/// Number of characters of the token that are stored in plain text
+const TOKEN_PREFIX_LENGTH: usize = 8;
+
+/// Personal api token, the token itself is only returned once after creating it
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "id": "019a0a3b-6a3c-7e43-9b7c-3bd1e0d1c6a1",
+        "name": "Market spreadsheet",
+        "token_prefix": "sfp_3f9a",
+        "scopes": ["projects:read"],
+        "expires_at": "2027-01-01T00:00:00Z",
+        "last_used_at": null,
+        "created_at": "2026-10-19T12:00:00Z"
+    })
+)]
+pub struct ApiToken {
+    pub id:           ApiTokenUuid,
+    pub name:         String,
+    /// First characters of the token, to identify it
+    pub token_prefix: String,
+    #[schema(value_type = Vec<String>)]
+    pub scopes:       Vec<ApiTokenScope>,
+    pub expires_at:   Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at:   DateTime<Utc>,
+}
+
+/// Body for creating a new [ApiToken]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "name": "Market spreadsheet",
+        "scopes": ["projects:read", "projects:write"],
+        "expires_at": "2027-01-01T00:00:00Z"
+    })
+)]
+pub struct CreateApiToken {
+    pub name:       String,
+    /// Format: `<route>:<read|write>`, `write` includes `read`
+    #[schema(value_type = Vec<String>)]
+    pub scopes:     Vec<ApiTokenScope>,
+    /// If not set, the token is valid until it is deleted
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl CreateApiToken {
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(ApiTokenError::ValidationError("Field 'name' must be set".into()));
+        }
+
+        if self.scopes.is_empty() {
+            return Err(ApiTokenError::ValidationError("Field 'scopes' must contain at least one scope".into()));
+        }
+
+        if let Some(x) = self.expires_at &&
+            x <= Utc::now() {
+            return Err(ApiTokenError::ValidationError("Field 'expires_at' must be in the future".into()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Response after creating a new [ApiToken]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "id": "019a0a3b-6a3c-7e43-9b7c-3bd1e0d1c6a1",
+        "token": "sfp_3f9a0c1e5b7d4a2f8e6c0b9a7d5e3f1a2b4c6d8e0f1a3b5c7d9e1f3a5b7c9d1"
+    })
+)]
+pub struct CreatedApiToken {
+    pub id:    ApiTokenUuid,
+    /// The token is only returned once, store it in a secure place
+    pub token: String,
+}
+
+/// Generates a new random token
+#[must_use]
+pub fn generate_token() -> String {
+    format!(
+        "{API_TOKEN_PREFIX}{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple(),
+    )
+}
+
+/// Hash of the token that is stored in the database
+#[must_use]
+pub fn hash_token(
+    token: &str,
+) -> String {
+    let hash = Sha256::digest(token.as_bytes());
+    BASE64_STANDARD.encode(hash)
+}
+
+/// Prefix of the token that is stored in plain text
+#[must_use]
+pub fn token_prefix(
+    token: &str,
+) -> String {
+    token
+        .chars()
+        .take(TOKEN_PREFIX_LENGTH)
+        .collect()
+}
+
+/// Converts the stored scopes, invalid scopes are dropped
+#[must_use]
+pub fn parse_scopes(
+    scopes: Vec<String>,
+) -> Vec<ApiTokenScope> {
+    scopes
+        .into_iter()
+        .filter_map(|x| ApiTokenScope::try_from(x).ok())
+        .collect::<Vec<_>>()
+}
@@ -0,0 +1,51 @@
+use sqlx::PgPool;
+use starfoundry_lib_types::CharacterId;
+
+use crate::api_token::{ApiTokenError, Result};
+use crate::api_token::service::{generate_token, hash_token, token_prefix, CreateApiToken, CreatedApiToken};
+
+/// Creates a new token for the character in the given domain
+pub async fn create_token(
+    pool:         &PgPool,
+    character_id: CharacterId,
+    domain:       &str,
+    token:        CreateApiToken,
+) -> Result<CreatedApiToken> {
+    token.validate()?;
+
+    let plain_token = generate_token();
+    let scopes = token
+        .scopes
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>();
+
+    sqlx::query!("
+            INSERT INTO api_token (
+                character_id,
+                domain,
+                name,
+                token_hash,
+                token_prefix,
+                scopes,
+                expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id
+        ",
+            *character_id,
+            domain,
+            token.name.trim(),
+            hash_token(&plain_token),
+            token_prefix(&plain_token),
+            &scopes,
+            token.expires_at,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|x| CreatedApiToken {
+            id:    x.id.into(),
+            token: plain_token,
+        })
+        .map_err(ApiTokenError::CreateToken)
+}
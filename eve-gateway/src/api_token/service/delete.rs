@@ -0,0 +1,33 @@
+use sqlx::PgPool;
+use starfoundry_lib_types::CharacterId;
+
+use crate::api_token::{ApiTokenError, ApiTokenUuid, Result};
+
+/// Deletes a token of the character, the token can no longer be used
+/// afterwards
+pub async fn delete_token(
+    pool:         &PgPool,
+    character_id: CharacterId,
+    domain:       &str,
+    token_id:     ApiTokenUuid,
+) -> Result<()> {
+    let result = sqlx::query!("
+            DELETE FROM api_token
+            WHERE id = $1
+            AND character_id = $2
+            AND domain = $3
+        ",
+            *token_id,
+            *character_id,
+            domain,
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| ApiTokenError::DeleteToken(e, token_id))?;
+
+    if result.rows_affected() == 0 {
+        Err(ApiTokenError::NotFound(token_id))
+    } else {
+        Ok(())
+    }
+}
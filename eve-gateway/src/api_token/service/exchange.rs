@@ -0,0 +1,68 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::CharacterInfo;
+use starfoundry_lib_gateway::ApiTokenScope;
+
+use crate::api_token::{ApiTokenError, Result};
+use crate::api_token::service::{hash_token, parse_scopes};
+
+/// Identity behind a valid api token
+#[derive(Debug)]
+pub struct ApiTokenIdentity {
+    pub character_info: CharacterInfo,
+    pub scopes:         Vec<ApiTokenScope>,
+}
+
+/// Resolves the identity of the token.
+///
+/// Returns [None] if the token does not exist, is expired or was not created
+/// for the given domain.
+///
+pub async fn exchange_token(
+    pool:   &PgPool,
+    domain: &str,
+    token:  &str,
+) -> Result<Option<ApiTokenIdentity>> {
+    let entry = sqlx::query!("
+            UPDATE api_token at
+            SET last_used_at = NOW()
+            FROM character c
+            WHERE at.character_id = c.character_id
+            AND at.token_hash = $1
+            AND at.domain = $2
+            AND (at.expires_at IS NULL OR at.expires_at > NOW())
+            RETURNING
+                at.scopes,
+                c.character_id,
+                c.character_name,
+                c.corporation_id,
+                c.corporation_name,
+                c.alliance_id,
+                c.alliance_name
+        ",
+            hash_token(token),
+            domain,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(ApiTokenError::ExchangeToken)?;
+
+    let entry = if let Some(x) = entry {
+        x
+    } else {
+        return Ok(None);
+    };
+
+    Ok(Some(ApiTokenIdentity {
+        character_info: CharacterInfo {
+            character_name:   entry.character_name,
+            character_id:     entry.character_id.into(),
+
+            corporation_name: entry.corporation_name,
+            corporation_id:   entry.corporation_id.into(),
+
+            alliance_name:    entry.alliance_name,
+            alliance_id:      entry.alliance_id.map(Into::into),
+        },
+        scopes: parse_scopes(entry.scopes),
+    }))
+}
@@ -0,0 +1,47 @@
+use sqlx::PgPool;
+use starfoundry_lib_types::CharacterId;
+
+use crate::api_token::{ApiTokenError, Result};
+use crate::api_token::service::{parse_scopes, ApiToken};
+
+/// Lists all tokens of the character in the given domain
+pub async fn list_tokens(
+    pool:         &PgPool,
+    character_id: CharacterId,
+    domain:       &str,
+) -> Result<Vec<ApiToken>> {
+    sqlx::query!("
+            SELECT
+                id,
+                name,
+                token_prefix,
+                scopes,
+                expires_at,
+                last_used_at,
+                created_at
+            FROM api_token
+            WHERE character_id = $1
+            AND domain = $2
+            ORDER BY created_at
+        ",
+            *character_id,
+            domain,
+        )
+        .fetch_all(pool)
+        .await
+        .map(|entries| {
+            entries
+                .into_iter()
+                .map(|x| ApiToken {
+                    id:           x.id.into(),
+                    name:         x.name,
+                    token_prefix: x.token_prefix,
+                    scopes:       parse_scopes(x.scopes),
+                    expires_at:   x.expires_at,
+                    last_used_at: x.last_used_at,
+                    created_at:   x.created_at,
+                })
+                .collect::<Vec<_>>()
+        })
+        .map_err(ApiTokenError::ListTokens)
+}
@@ -1,3 +1,4 @@
+pub mod eve_list_asset_stock;
 pub mod eve_resolve_character_asset;
 pub mod eve_resolve_corporation_asset;
 
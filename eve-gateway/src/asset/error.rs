@@ -14,6 +14,8 @@ pub type Result<T, E = AssetError> = std::result::Result<T, E>;
 pub enum  AssetError {
     #[error("error while listing blueprints, error: '{0}'")]
     ListBlueprints(sqlx::Error),
+    #[error("error while listing asset stock, error: '{0}'")]
+    ListStock(sqlx::Error),
 
     #[error("eve api error, error: '{0:?}'")]
     EveApiError(Box<EveApiError>),
@@ -0,0 +1,63 @@
+use axum::extract::State;
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_eve_gateway::{AssetStock, AssetStockFilter};
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::api_docs::{InternalServerError, Unauthorized};
+use crate::asset::error::Result;
+use crate::asset::service::list_stock;
+use crate::state::AppState;
+
+/// List Asset Stock
+/// 
+/// - Alternative route: `/latest/eve/assets/stock`
+/// - Alternative route: `/v1/eve/assets/stock`
+/// 
+/// ---
+/// 
+/// Lists the synced assets of the character and its corporation, that match
+/// the given filter.
+/// Corporation assets are only listed if the character granted the scope
+/// `esi-assets.read_corporation_assets.v1`.
+/// The assets are synced once an hour.
+/// 
+#[utoipa::path(
+    post,
+    path = "/assets/stock",
+    tag = "Assets",
+    request_body = AssetStockFilter,
+    responses(
+        (
+            body = Vec<AssetStock>,
+            description = "Assets matching the filter",
+            status = OK,
+        ),
+        Unauthorized,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:       ExtractIdentity,
+    State(state):   State<AppState>,
+    Json(filter):   Json<AssetStockFilter>,
+) -> Result<impl IntoResponse> {
+    let stock = list_stock(
+            &state.postgres,
+            state.eve_api_metric,
+            identity.host()?,
+            identity.character_id,
+            identity.corporation_id,
+            filter,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(stock),
+        )
+        .into_response()
+    )
+}
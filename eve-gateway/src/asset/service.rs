@@ -1,3 +1,5 @@
 mod list_blueprints;
+mod list_stock;
 
 pub use self::list_blueprints::*;
+pub use self::list_stock::*;
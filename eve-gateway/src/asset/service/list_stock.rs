@@ -0,0 +1,251 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_client::{EveApiClient, EveApiClientMetric};
+use starfoundry_lib_eve_gateway::{AssetStock, AssetStockFilter, ResolvedItem};
+use starfoundry_lib_types::{CharacterId, CorporationId, ItemId, LocationId, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::asset::{AssetError, Result};
+use crate::utils::{api_client_auth, has_scope};
+
+const CHARACTER_SCOPE: &str = "esi-assets.read_assets.v1";
+const CORPORATION_SCOPE: &str = "esi-assets.read_corporation_assets.v1";
+
+/// Maximum number of ids the EVE API accepts for resolving names
+const MAX_RESOLVE_IDS: usize = 1_000;
+
+/// Lists all synced assets of the character and its corporation that are
+/// located in one of the given locations.
+///
+/// Corporation assets are only included if the character granted the
+/// corporation asset scope, otherwise only the assets of the character are
+/// listed.
+///
+/// Assets in corporation offices get the hangar they are in as location flag.
+/// Assembled items, like ships or containers, are not counted as stock,
+/// unless `include_assembled` is set and they are directly in a hangar.
+/// Container names are only resolved if they are part of the filter.
+///
+pub async fn list_stock(
+    pool:           &PgPool,
+    metric:         Arc<EveApiClientMetric>,
+    host:           String,
+    character_id:   CharacterId,
+    corporation_id: CorporationId,
+    filter:         AssetStockFilter,
+) -> Result<Vec<AssetStock>> {
+    if filter.location_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let location_ids = filter
+        .location_ids
+        .iter()
+        .map(|x| **x)
+        .collect::<Vec<_>>();
+
+    let mut owner_ids = vec![*character_id];
+    if has_scope(
+            pool,
+            host.clone(),
+            character_id,
+            vec![CORPORATION_SCOPE.into()],
+        )
+        .await
+        .map_err(AssetError::ListStock)? {
+
+        owner_ids.push(*corporation_id);
+    }
+
+    let entries = sqlx::query!(r#"
+            WITH RECURSIVE tree AS (
+                SELECT
+                    owner_id,
+                    item_id,
+                    type_id,
+                    quantity,
+                    is_singleton,
+                    location_id AS root_location_id,
+                    TRIM(BOTH '"' FROM location_flag)::VARCHAR AS location_flag,
+                    NULL::BIGINT AS container_id
+                FROM asset
+                WHERE owner_id = ANY($1)
+                AND location_id = ANY($2)
+
+                UNION ALL
+
+                SELECT
+                    a.owner_id,
+                    a.item_id,
+                    a.type_id,
+                    a.quantity,
+                    a.is_singleton,
+                    t.root_location_id,
+                    CASE
+                        WHEN t.location_flag = 'OfficeFolder'
+                        THEN TRIM(BOTH '"' FROM a.location_flag)::VARCHAR
+                        ELSE t.location_flag
+                    END,
+                    CASE
+                        WHEN t.location_flag = 'OfficeFolder'
+                        THEN NULL::BIGINT
+                        ELSE a.location_id
+                    END
+                FROM asset a
+                JOIN tree t ON t.item_id = a.location_id
+            )
+            SELECT
+                owner_id AS "owner_id!",
+                type_id::INTEGER AS "type_id!",
+                quantity AS "quantity!",
+                root_location_id AS "location_id!",
+                location_flag AS "location_flag!",
+                container_id
+            FROM tree
+            WHERE (NOT is_singleton OR ($4 AND container_id IS NULL))
+            AND (CARDINALITY($3::VARCHAR[]) = 0 OR location_flag = ANY($3))
+        "#,
+            &owner_ids,
+            &location_ids,
+            &filter.location_flags,
+            filter.include_assembled,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(AssetError::ListStock)?;
+
+    let container_names = if filter.container_names.is_empty() {
+        HashMap::new()
+    } else {
+        let mut character_containers = Vec::new();
+        let mut corporation_containers = Vec::new();
+        for entry in entries.iter() {
+            let container_id = if let Some(x) = entry.container_id {
+                ItemId(x)
+            } else {
+                continue;
+            };
+
+            if entry.owner_id == *character_id {
+                character_containers.push(container_id);
+            } else {
+                corporation_containers.push(container_id);
+            }
+        }
+
+        let mut names = resolve_names(
+                pool,
+                metric.clone(),
+                host.clone(),
+                character_id,
+                CHARACTER_SCOPE,
+                format!("latest/characters/{character_id}/assets/names"),
+                character_containers,
+            )
+            .await?;
+        names.extend(
+            resolve_names(
+                pool,
+                metric,
+                host,
+                character_id,
+                CORPORATION_SCOPE,
+                format!("latest/corporations/{corporation_id}/assets/names"),
+                corporation_containers,
+            )
+            .await?
+        );
+        names
+    };
+
+    let mut stock: HashMap<(TypeId, LocationId, String, Option<String>), i64> = HashMap::new();
+    for entry in entries {
+        let container_name = entry
+            .container_id
+            .and_then(|x| container_names.get(&ItemId(x)))
+            .cloned();
+
+        if !filter.container_names.is_empty() {
+            let is_match = container_name
+                .as_ref()
+                .map(|x| filter.container_names.contains(x))
+                .unwrap_or_default();
+            if !is_match {
+                continue;
+            }
+        }
+
+        stock
+            .entry((
+                TypeId(entry.type_id),
+                LocationId(entry.location_id),
+                entry.location_flag,
+                container_name,
+            ))
+            .and_modify(|x| *x += entry.quantity as i64)
+            .or_insert(entry.quantity as i64);
+    }
+
+    let mut stock = stock
+        .into_iter()
+        .map(|((type_id, location_id, location_flag, container_name), quantity)| AssetStock {
+            type_id,
+            quantity,
+            location_id,
+            location_flag,
+            container_name,
+        })
+        .collect::<Vec<_>>();
+    stock.sort_by_key(|x| (x.type_id, x.location_id));
+
+    Ok(stock)
+}
+
+/// Resolves the names of the given containers, if the character doesn't have
+/// the required scope, no names are returned
+async fn resolve_names(
+    pool:         &PgPool,
+    metric:       Arc<EveApiClientMetric>,
+    host:         String,
+    character_id: CharacterId,
+    scope:        &str,
+    path:         String,
+    mut item_ids: Vec<ItemId>,
+) -> Result<HashMap<ItemId, String>> {
+    item_ids.sort();
+    item_ids.dedup();
+
+    if item_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let api_client: EveApiClient = if let Some(x) = api_client_auth(
+            pool,
+            metric,
+            host,
+            character_id,
+            vec![
+                scope.into(),
+            ],
+        )
+        .await? {
+
+        x
+    } else {
+        return Ok(HashMap::new());
+    };
+
+    let mut names = HashMap::new();
+    for chunk in item_ids.chunks(MAX_RESOLVE_IDS) {
+        let resolved = api_client
+            .post::<_, Vec<ResolvedItem>>(&path, chunk)
+            .await?;
+        names.extend(
+            resolved
+                .into_iter()
+                .map(|x| (x.item_id, x.name))
+        );
+    }
+
+    Ok(names)
+}
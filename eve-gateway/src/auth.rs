@@ -1,3 +1,4 @@
+mod api_token;
 mod callback;
 mod extractor;
 mod history;
@@ -10,6 +11,7 @@ mod token;
 
 pub mod error;
 
+pub use self::api_token::*;
 pub use self::callback::*;
 pub use self::extractor::*;
 pub use self::jwks::*;
@@ -27,6 +29,7 @@ use crate::state::AppState;
 /// returns all routes under the path `/auth`
 pub fn routes() -> OpenApiRouter<AppState> {
     OpenApiRouter::new()
+        .routes(routes!(api_token))
         .routes(routes!(callback))
         .routes(routes!(login))
         .routes(routes!(login_character))
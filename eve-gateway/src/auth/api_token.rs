@@ -0,0 +1,111 @@
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::header::HOST;
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::ToSchema;
+
+use crate::access::service::is_access_denied;
+use crate::api_docs::Unauthorized;
+use crate::api_token::service::exchange_token;
+use crate::auth::{AccessToken, JwtToken};
+use crate::auth::error::Result;
+use crate::state::AppState;
+
+/// Exchange Api Token
+/// 
+/// - Alternative route: `/latest/auth/api-token`
+/// - Alternative route: `/v1/auth/api-token`
+/// 
+/// ---
+/// 
+/// Exchanges a personal api token for a short lived access token.
+/// 
+/// The access token only allows requests to the routes the api token was
+/// created with, and is valid for one minute.
+/// 
+/// The gateway does the exchange automatically, if an api token is given in
+/// the `AUTHORIZATION` header.
+/// 
+#[utoipa::path(
+    post,
+    path = "/api-token",
+    tag = "Auth",
+    request_body = ApiTokenExchange,
+    responses(
+        (
+            status = OK,
+            description = "Returns the access token",
+            content_type = "application/json",
+            body = AccessToken,
+        ),
+        Unauthorized,
+    ),
+)]
+pub async fn api_token(
+    State(state): State<AppState>,
+    header:       HeaderMap,
+    Json(body):   Json<ApiTokenExchange>,
+) -> Result<impl IntoResponse> {
+    let host = if let Some(x) = header.get(HOST) {
+        x.to_str().unwrap_or_default()
+    } else {
+        tracing::error!("{HOST} header not present");
+        return Ok((
+            StatusCode::BAD_REQUEST,
+        ).into_response())
+    };
+
+    if !state.auth_domains.contains_key(host) {
+        tracing::error!("'{host}' is not in the list of valid domains");
+        return Ok((
+            StatusCode::BAD_REQUEST,
+        ).into_response())
+    }
+
+    let identity = if let Some(x) = exchange_token(
+        &state.postgres,
+        host,
+        &body.token,
+    ).await? {
+        x
+    } else {
+        return Ok((
+            StatusCode::UNAUTHORIZED
+        ).into_response());
+    };
+
+    let character_id = identity.character_info.character_id;
+    // the access may have been revoked since the token was created
+    if is_access_denied(
+        &state.postgres,
+        host,
+        character_id,
+    ).await? {
+        return Ok((
+            StatusCode::UNAUTHORIZED
+        ).into_response());
+    }
+
+    let access_token = JwtToken::new_api_token_access_token(
+        character_id,
+        identity.character_info,
+        identity.scopes,
+        host.into(),
+    )?;
+
+    Ok((
+        StatusCode::OK,
+        Json(json!({
+            "access_token": access_token
+        }))
+    ).into_response())
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ApiTokenExchange {
+    /// Personal api token, starts with `sfp_`
+    pub token: String,
+}
@@ -9,6 +9,7 @@ use std::collections::HashMap;
 use std::str::FromStr;
 use uuid::Uuid;
 
+use crate::access::service::evaluate_character;
 use crate::api_docs::{BadRequest, InternalServerError};
 use crate::auth::error::{AuthError, Result};
 use crate::auth::history::insert_into_history;
@@ -48,7 +49,6 @@ const QUERY_PARAM_STATE: &str = "state";
         InternalServerError,
     ),
 )]
-// TODO: add whitelist check
 pub async fn callback(
     State(state):        State<AppState>,
     Query(query_params): Query<HashMap<String, String>>,
@@ -169,10 +169,14 @@ pub async fn callback(
         character_id
     ).await?;
 
-    if auth_domain.whitelist.contains(&(*character_id as i64)) ||
-        auth_domain.whitelist.contains(&(*character_info.corporation_id as i64)) ||
-        auth_domain.whitelist.contains(&(*character_info.alliance_id.unwrap_or(0i32.into()) as i64)) {
-    } else {
+    let access = evaluate_character(
+        &state.postgres,
+        &domain,
+        &auth_domain.admins,
+        character_info,
+    ).await?;
+
+    if !access.allowed {
         return Ok((
             StatusCode::FORBIDDEN,
             Json(serde_json::json!({
@@ -5,6 +5,8 @@ use starfoundry_lib_eve_client::EveApiError;
 use starfoundry_lib_gateway::boxed_from;
 use thiserror::Error;
 
+use crate::access::AccessError;
+use crate::api_token::ApiTokenError;
 use crate::api_docs::ErrorResponse;
 use crate::character::CharacterError;
 
@@ -32,6 +34,10 @@ pub enum AuthError {
     #[error("error while updating login, error: '{0}'")]
     UpdateLogin(sqlx::Error),
 
+    #[error("access error, error: '{0}'")]
+    AccessError(Box<AccessError>),
+    #[error("api token error, error: '{0}'")]
+    ApiTokenError(Box<ApiTokenError>),
     #[error("character error, error: '{0}'")]
     CharacterError(Box<CharacterError>),
     #[error("error performing eve api call, error: '{0}'")]
@@ -129,5 +135,7 @@ impl IntoResponse for AuthError {
     }
 }
 
+boxed_from!(AuthError::AccessError, AccessError);
+boxed_from!(AuthError::ApiTokenError, ApiTokenError);
 boxed_from!(AuthError::CharacterError, CharacterError);
 boxed_from!(AuthError::EveApiError, EveApiError);
@@ -4,6 +4,7 @@ use chrono::{Duration, Utc};
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation, decode, encode};
 use serde::{Deserialize, Serialize};
 use starfoundry_lib_eve_gateway::CharacterInfo;
+use starfoundry_lib_gateway::ApiTokenScope;
 use starfoundry_lib_types::CharacterId;
 
 use crate::auth::error::{AuthError, Result};
@@ -12,9 +13,11 @@ pub const ENV_JWT_ECDSA_PRIVATE: &str  = "STARFOUNDRY_EVE_GATEWAY_JWT_ECDSA_PRIV
 pub const ENV_JWT_ECDSA_PUBLIC: &str   = "STARFOUNDRY_EVE_GATEWAY_JWT_ECDSA_PUBLIC";
 pub const ENV_JWT_ISSUER_DOMAIN: &str  = "STARFOUNDRY_EVE_GATEWAY_JWT_ISSUER_DOMAIN";
 
-const ACCESS_TOKEN_EXP: Duration  = Duration::minutes(15);
-const REFRESH_TOKEN_EXP: Duration = Duration::days(1);
-const JWT_KID: &str               = "starfoundry-eve-gateway";
+const ACCESS_TOKEN_EXP: Duration           = Duration::minutes(15);
+/// kept short, so that deleted api tokens can no longer be used shortly after
+const API_TOKEN_ACCESS_TOKEN_EXP: Duration = Duration::minutes(1);
+const REFRESH_TOKEN_EXP: Duration          = Duration::days(1);
+const JWT_KID: &str                        = "starfoundry-eve-gateway";
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AccessTokenClaims {
@@ -28,6 +31,10 @@ pub struct AccessTokenClaims {
     is_admin:       bool,
     character_info: CharacterInfo,
     key_type:       KeyType,
+    /// only set for [KeyType::ApiToken], restricts the routes the token can
+    /// be used for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scopes:         Option<Vec<ApiTokenScope>>,
 
     pub sub:        CharacterId,
 }
@@ -82,6 +89,8 @@ pub enum KeyType {
     Character,
     /// The JWT token is from a service
     Service,
+    /// The JWT token was exchanged for a personal api token
+    ApiToken,
 }
 
 #[derive(Debug, Serialize)]
@@ -93,9 +102,47 @@ impl JwtToken {
         character_info: CharacterInfo,
         is_admin:       bool,
         host:           String,
+    ) -> Result<Self> {
+        Self::access_token(
+            character_id,
+            character_info,
+            is_admin,
+            host,
+            KeyType::Character,
+            None,
+            ACCESS_TOKEN_EXP,
+        )
+    }
+
+    /// Access token for a personal api token, api tokens are never admins
+    pub fn new_api_token_access_token(
+        character_id:   CharacterId,
+        character_info: CharacterInfo,
+        scopes:         Vec<ApiTokenScope>,
+        host:           String,
+    ) -> Result<Self> {
+        Self::access_token(
+            character_id,
+            character_info,
+            false,
+            host,
+            KeyType::ApiToken,
+            Some(scopes),
+            API_TOKEN_ACCESS_TOKEN_EXP,
+        )
+    }
+
+    fn access_token(
+        character_id:   CharacterId,
+        character_info: CharacterInfo,
+        is_admin:       bool,
+        host:           String,
+        key_type:       KeyType,
+        scopes:         Option<Vec<ApiTokenScope>>,
+        expires_in:     Duration,
     ) -> Result<Self> {
         let exp = (
-                Utc::now().naive_utc() + ACCESS_TOKEN_EXP
+                Utc::now().naive_utc() + expires_in
             )
             .and_utc()
             .timestamp();
@@ -114,7 +161,8 @@ impl JwtToken {
             iss: issuer()?,
             kid: JWT_KID.into(),
 
-            key_type: KeyType::Character,
+            key_type,
+            scopes,
 
             character_info,
             is_admin,
@@ -10,6 +10,7 @@ use sha2::{Digest, Sha256};
 use starfoundry_lib_eve_gateway::CharacterInfo;
 use utoipa::ToSchema;
 
+use crate::access::service::is_access_denied;
 use crate::api_docs::Unauthorized;
 use crate::auth::{JwtToken, RefreshTokenClaims};
 use crate::auth::error::{AuthError, Result};
@@ -32,6 +33,9 @@ use crate::state::AppState;
 /// Access tokens are 15 minute valid, afterwards the client needs to request
 /// a new one using the `refresh_token`.
 /// 
+/// If the access rules of the domain no longer allow the character, no new
+/// token is issued.
+/// 
 #[utoipa::path(
     get,
     path = "/token",
@@ -96,6 +100,17 @@ pub async fn token(
             .map_err(AuthError::GetRefreshTokenError)?;
 
         if let Some(record) = character {
+            // the access may have been revoked since the login
+            if is_access_denied(
+                &state.postgres,
+                host,
+                record.character_id.into(),
+            ).await? {
+                return Ok((
+                    StatusCode::UNAUTHORIZED
+                ).into_response());
+            }
+
             let character_info = CharacterInfo {
                 character_name:   record.character_name,
                 character_id:     record.character_id.into(),
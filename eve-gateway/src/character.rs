@@ -2,6 +2,7 @@ mod error;
 mod fetch;
 mod fetch_bulk;
 mod list;
+mod list_scopes;
 
 pub mod service;
 
@@ -23,10 +24,14 @@ pub fn routes() -> OpenApiRouter<AppState> {
     let list = OpenApiRouter::new()
         .routes(routes!(self::list::api));
 
+    let list_scopes = OpenApiRouter::new()
+        .routes(routes!(self::list_scopes::api));
+
     OpenApiRouter::new()
         .merge(fetch)
         .merge(fetch_bulk)
         .merge(list)
+        .merge(list_scopes)
         // fittings
         .merge(crate::fitting::routes())
 }
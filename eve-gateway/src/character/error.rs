@@ -17,11 +17,15 @@ pub enum CharacterError {
     FetchCharacterBulk(sqlx::Error),
     #[error("error while inserting character information, error: '{0}'")]
     InsertCharacter(sqlx::Error),
+    #[error("error while listing scopes, error: '{0}'")]
+    ListScopes(sqlx::Error),
     #[error("the requested character couldn't be found")]
     NotFound,
 
     #[error("error performing eve api call, error: '{0}'")]
     EveApiError(#[from] EveApiError),
+    #[error(transparent)]
+    GatewayError(#[from] starfoundry_lib_gateway::Error),
 }
 
 impl IntoResponse for CharacterError {
@@ -40,6 +44,10 @@ impl IntoResponse for CharacterError {
                 ).into_response()
             }
 
+            Self::GatewayError(e) => {
+                starfoundry_lib_gateway::Error::into_response(e)
+            },
+
             _ => {
                 tracing::error!("{}", self.to_string());
                 (
@@ -0,0 +1,54 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::api_docs::{InternalServerError, Unauthorized};
+use crate::character::error::Result;
+use crate::character::service::list_scopes;
+use crate::state::AppState;
+
+/// List Scopes
+/// 
+/// - Alternative route: `/latest/characters/scopes`
+/// - Alternative route: `/v1/characters/scopes`
+/// 
+/// ---
+/// 
+/// Lists the EVE scopes the character granted, other services use them to
+/// decide if the character may access corporation data.
+/// 
+#[utoipa::path(
+    get,
+    path = "/scopes",
+    tag = "Character",
+    responses(
+        (
+            body = Vec<String>,
+            description = "Scopes the character granted",
+            status = OK,
+        ),
+        Unauthorized,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse> {
+    let entry = list_scopes(
+            &state.postgres,
+            identity.host()?,
+            identity.character_id,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(entry)
+        )
+        .into_response()
+    )
+}
@@ -1,7 +1,9 @@
 mod fetch;
 mod fetch_bulk;
 mod list;
+mod list_scopes;
 
 pub use self::fetch::*;
 pub use self::fetch_bulk::*;
 pub use self::list::*;
+pub use self::list_scopes::*;
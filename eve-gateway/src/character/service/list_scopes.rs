@@ -0,0 +1,27 @@
+use sqlx::PgPool;
+use starfoundry_lib_types::CharacterId;
+
+use crate::character::error::{CharacterError, Result};
+
+/// Lists the EVE scopes the character granted for the given domain.
+/// If the character never logged in, the list is empty
+pub async fn list_scopes(
+    pool:         &PgPool,
+    host:         String,
+    character_id: CharacterId,
+) -> Result<Vec<String>> {
+    sqlx::query!("
+            SELECT scopes
+            FROM eve_credential
+            WHERE
+                domain = $1 AND
+                character_id = $2
+        ",
+            host,
+            *character_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map(|x| x.and_then(|x| x.scopes).unwrap_or_default())
+        .map_err(CharacterError::ListScopes)
+}
@@ -4,6 +4,7 @@ mod file;
 pub use self::file::*;
 
 use std::collections::HashMap;
+use std::time::Duration;
 use tokio::net::TcpListener;
 
 use crate::config::env::ConfigEnv;
@@ -19,6 +20,9 @@ pub struct Config {
     /// address under which health checks and metrics are exposed
     pub service_address:  TcpListener,
 
+    /// interval in which the access rules of all domains are evaluated
+    pub access_evaluation_interval: Duration,
+
     /// list of domains that are allowed to use this service for authentication
     pub domains:          HashMap<String, ConfigFileDomain>,
 }
@@ -48,6 +52,8 @@ impl From<(ConfigEnv, ConfigFile)> for Config {
             app_address:      env.app_address,
             service_address:  env.service_address,
 
+            access_evaluation_interval: env.access_evaluation_interval,
+
             domains:          file.domains,
         }
     }
@@ -1,4 +1,5 @@
 use starfoundry_lib_eve_client::{ENV_CALLBACK, ENV_CLIENT_ID, ENV_SECRET_KEY};
+use std::time::Duration;
 use tokio::net::TcpListener;
 
 use crate::auth::{ENV_JWT_ECDSA_PRIVATE, ENV_JWT_ECDSA_PUBLIC, ENV_JWT_ISSUER_DOMAIN};
@@ -6,6 +7,7 @@ use crate::auth::{ENV_JWT_ECDSA_PRIVATE, ENV_JWT_ECDSA_PUBLIC, ENV_JWT_ISSUER_DO
 const ENV_DATABASE_URL: &str     = "STARFOUNDRY_EVE_GATEWAY_DATABASE_URL";
 const ENV_APP_ADDRESS: &str      = "STARFOUNDRY_EVE_GATEWAY_APP_ADDRESS";
 const ENV_SERVICE_ADDRESS: &str  = "STARFOUNDRY_EVE_GATEWAY_SERVICE_ADDRESS";
+const ENV_ACCESS_EVALUATION_INTERVAL: &str = "STARFOUNDRY_EVE_GATEWAY_ACCESS_EVALUATION_INTERVAL";
 
 #[derive(Debug)]
 pub struct ConfigEnv {
@@ -13,6 +15,8 @@ pub struct ConfigEnv {
 
     pub app_address:      TcpListener,
     pub service_address:  TcpListener,
+
+    pub access_evaluation_interval: Duration,
 }
 
 impl ConfigEnv {
@@ -41,10 +45,21 @@ impl ConfigEnv {
 
         let database_url = std::env::var(ENV_DATABASE_URL)?;
 
+        let access_evaluation_interval = std::env::var(ENV_ACCESS_EVALUATION_INTERVAL)
+            .unwrap_or("60".into());
+        let access_evaluation_interval = match access_evaluation_interval.parse::<u64>() {
+            Ok(x) if x > 0 => Duration::from_secs(x * 60),
+            _ => {
+                tracing::error!("Error validating config {ENV_ACCESS_EVALUATION_INTERVAL}. Must be a positive number of minutes");
+                return Err("Error while parsing access evaluation interval".into());
+            }
+        };
+
         Ok(Self {
             database_url,
             app_address,
             service_address,
+            access_evaluation_interval,
         })
     }
 
@@ -17,9 +17,11 @@ pub struct ConfigFile {
 pub struct ConfigFileDomain {
     /// List of [CharacterId]s that are admins in the domain
     pub admins:             Vec<CharacterId>,
-    /// Whitelist of CharacterIds, CorporationIds or AllianceIds that are allowed
-    /// to login
-    /// If kept empty, everybody can login
+    /// Deprecated: Whitelist of CharacterIds, CorporationIds or AllianceIds that
+    /// are allowed to login
+    /// Access is managed with access rules, the whitelist is only used to
+    /// seed the rules of domains that do not have any rules yet
+    #[serde(default)]
     pub whitelist:          Vec<i64>,
     /// Scopes that are requested when a user logs in
     /// It is recommended to always add `publicData`
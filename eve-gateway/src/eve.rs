@@ -5,6 +5,8 @@ use crate::state::AppState;
 
 /// Exposes all routes under `/eve`
 pub fn routes() -> OpenApiRouter<AppState> {
+    let list_asset_stock = OpenApiRouter::new()
+        .routes(routes!(crate::asset::eve_list_asset_stock::api));
     let resolve_character_asset = OpenApiRouter::new()
         .routes(routes!(crate::asset::eve_resolve_character_asset::api));
     let resolve_corporation_asset = OpenApiRouter::new()
@@ -12,9 +14,13 @@ pub fn routes() -> OpenApiRouter<AppState> {
 
     OpenApiRouter::new()
         // assets
+        .merge(list_asset_stock)
         .merge(resolve_character_asset)
         .merge(resolve_corporation_asset)
 
         // fittings
         .merge(crate::fitting::routes())
+
+        // wallet
+        .merge(crate::wallet::routes())
 }
@@ -1,5 +1,6 @@
 mod create;
 mod delete;
+mod list;
 
 pub mod error;
 
@@ -16,7 +17,11 @@ pub fn routes() -> OpenApiRouter<AppState> {
     let delete = OpenApiRouter::new()
         .routes(routes!(delete::api));
 
+    let list = OpenApiRouter::new()
+        .routes(routes!(list::api));
+
     OpenApiRouter::new()
         .merge(create)
         .merge(delete)
+        .merge(list)
 }
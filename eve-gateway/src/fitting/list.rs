@@ -0,0 +1,111 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_eve_client::{EveApiClient, EveApiError};
+use starfoundry_lib_eve_gateway::EveFitting;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_types::CharacterId;
+
+use crate::api_docs::{InternalServerError, Unauthorized};
+use crate::fitting::error::Result;
+use crate::state::AppState;
+use crate::utils::api_client_auth;
+
+const SCOPE: &str = "esi-fittings.read_fittings.v1";
+
+/// List Fits
+/// 
+/// - Alternative route: `/latest/characters/{CharacterId}/fittings`
+/// - Alternative route: `/v1/characters/{CharacterId}/fittings`
+/// 
+/// ---
+/// 
+/// Lists all fittings the character saved in game
+/// 
+#[utoipa::path(
+    get,
+    path = "/{CharacterId}/fittings",
+    tag = "Character - Fit",
+    params(
+        CharacterId,
+    ),
+    responses(
+        (
+            body = Vec<EveFitting>,
+            description = "All fittings of the character",
+            status = OK,
+        ),
+        (
+            description = "The character has no fittings",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:           ExtractIdentity,
+    State(state):       State<AppState>,
+    Path(character_id): Path<CharacterId>,
+) -> Result<impl IntoResponse> {
+    let api_client = api_client_auth(
+            &state.postgres,
+            state.eve_api_metric,
+            identity.host()?,
+            character_id,
+            vec![
+                SCOPE.into(),
+            ],
+        )
+        .await?;
+
+    let api_client = if let Some(x) = api_client {
+        x
+    } else {
+        return Ok(
+            (
+                StatusCode::UNAUTHORIZED,
+            )
+            .into_response()
+        )
+    };
+
+    let path = format!(
+        "latest/characters/{}/fittings",
+        character_id,
+    );
+    let mut api_url = EveApiClient::api_url()?;
+    api_url.set_path(&path);
+
+    let response = api_client
+        .send_auth(api_url.clone(), &())
+        .await?;
+
+    let fittings = if response.status() == StatusCode::NO_CONTENT {
+        Vec::new()
+    } else {
+        response
+            .json::<Vec<EveFitting>>()
+            .await
+            .map_err(|e| EveApiError::ReqwestError(e, api_url))?
+    };
+
+    if fittings.is_empty() {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(fittings),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(fittings),
+            )
+            .into_response()
+        )
+    }
+}
@@ -2,6 +2,7 @@ mod error;
 mod fetch_blueprint_dependencies_bulk;
 mod fetch_blueprint_json;
 mod fetch_system_index;
+mod list_planet_schematics;
 mod service;
 
 use utoipa_axum::router::OpenApiRouter;
@@ -20,8 +21,12 @@ pub fn routes() -> OpenApiRouter<AppState> {
     let fetch_blueprint_json = OpenApiRouter::new()
         .routes(routes!(self::fetch_blueprint_json::api));
 
+    let list_planet_schematics = OpenApiRouter::new()
+        .routes(routes!(self::list_planet_schematics::api));
+
     OpenApiRouter::new()
         .merge(fetch_blueprint_dependencies)
         .merge(fetch_blueprint_json)
         .merge(fetch_system_index)
+        .merge(list_planet_schematics)
 }
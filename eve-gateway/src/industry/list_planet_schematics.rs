@@ -0,0 +1,47 @@
+use axum::extract::State;
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_eve_gateway::PlanetSchematics;
+
+use crate::api_docs::InternalServerError;
+use crate::industry::error::Result;
+use crate::industry::service::list_planet_schematics;
+use crate::state::AppState;
+
+/// List Planet Schematics
+/// 
+/// - Alternative route: `/latest/industry/planet-schematics`
+/// - Alternative route: `/v1/industry/planet-schematics`
+/// 
+/// ---
+/// 
+/// Lists all schematics of planetary factories, and the planet types the raw
+/// resources can be extracted from
+/// 
+#[utoipa::path(
+    get,
+    path = "/planet-schematics",
+    tag = "Industry",
+    responses(
+        (
+            body = PlanetSchematics,
+            description = "All planetary industry schematics",
+            status = OK,
+        ),
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse> {
+    let schematics = list_planet_schematics(&state.postgres).await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(schematics),
+        )
+        .into_response()
+    )
+}
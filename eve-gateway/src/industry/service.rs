@@ -1,7 +1,9 @@
 mod fetch_blueprint_dependency;
 mod fetch_blueprint_json;
 mod fetch_system_index;
+mod list_planet_schematics;
 
 pub use self::fetch_blueprint_dependency::*;
 pub use self::fetch_blueprint_json::*;
 pub use self::fetch_system_index::*;
+pub use self::list_planet_schematics::*;
@@ -0,0 +1,78 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{PlanetResource, PlanetSchematic, PlanetSchematicMaterial, PlanetSchematics};
+use std::collections::HashMap;
+
+use crate::industry::error::{IndustryError, Result};
+
+pub async fn list_planet_schematics(
+    pool: &PgPool,
+) -> Result<PlanetSchematics> {
+    let mut materials = sqlx::query!("
+            SELECT
+                schematic_id,
+                type_id,
+                quantity
+            FROM planet_schematic_material
+        ")
+        .fetch_all(pool)
+        .await
+        .map_err(IndustryError::ListPlanetSchematics)?
+        .into_iter()
+        .fold(HashMap::new(), |mut acc: HashMap<i32, Vec<PlanetSchematicMaterial>>, x| {
+            acc
+                .entry(x.schematic_id)
+                .or_default()
+                .push(PlanetSchematicMaterial {
+                    type_id:  x.type_id.into(),
+                    quantity: x.quantity,
+                });
+            acc
+        });
+
+    let schematics = sqlx::query!("
+            SELECT
+                schematic_id,
+                name,
+                cycle_time,
+                type_id,
+                quantity
+            FROM planet_schematic
+            ORDER BY schematic_id
+        ")
+        .fetch_all(pool)
+        .await
+        .map_err(IndustryError::ListPlanetSchematics)?
+        .into_iter()
+        .map(|x| PlanetSchematic {
+            schematic_id: x.schematic_id,
+            name:         x.name,
+            cycle_time:   x.cycle_time,
+            type_id:      x.type_id.into(),
+            quantity:     x.quantity,
+            materials:    materials.remove(&x.schematic_id).unwrap_or_default(),
+        })
+        .collect::<Vec<_>>();
+
+    let resources = sqlx::query!(r#"
+            SELECT
+                type_id,
+                ARRAY_AGG(planet_type_id ORDER BY planet_type_id) AS "planet_type_ids!"
+            FROM planet_resource
+            GROUP BY type_id
+            ORDER BY type_id
+        "#)
+        .fetch_all(pool)
+        .await
+        .map_err(IndustryError::ListPlanetSchematics)?
+        .into_iter()
+        .map(|x| PlanetResource {
+            type_id:         x.type_id.into(),
+            planet_type_ids: x.planet_type_ids.into_iter().map(Into::into).collect::<Vec<_>>(),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(PlanetSchematics {
+        schematics,
+        resources,
+    })
+}
@@ -4,6 +4,7 @@ mod fetch_category;
 mod fetch_group;
 mod list;
 mod parse;
+mod parse_fit;
 
 pub mod services;
 pub mod error;
@@ -33,6 +34,9 @@ pub fn routes() -> OpenApiRouter<AppState> {
     let parse = OpenApiRouter::new()
         .routes(routes!(parse::api));
 
+    let parse_fit = OpenApiRouter::new()
+        .routes(routes!(parse_fit::api));
+
     OpenApiRouter::new()
         .merge(fetch)
         .merge(fetch_bulk)
@@ -40,4 +44,5 @@ pub fn routes() -> OpenApiRouter<AppState> {
         .merge(fetch_group)
         .merge(list)
         .merge(parse)
+        .merge(parse_fit)
 }
@@ -0,0 +1,50 @@
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_eve_gateway::ParsedFit;
+
+use crate::api_docs::InternalServerError;
+use crate::item::error::Result;
+use crate::item::services::{load_items_by_name, load_items_by_type_id, parse_fits};
+
+/// Parse fits
+/// 
+/// - Alternative route: `/latest/items/parse/fit`
+/// - Alternative route: `/v1/items/parse/fit`
+/// 
+/// ---
+/// 
+/// Parses one or more fits, supported formats are EFT, DNA and the XML
+/// export of the in-game fitting window
+/// 
+#[utoipa::path(
+    post,
+    path = "/parse/fit",
+    tag = "Items",
+    request_body = String,
+    responses(
+        (
+            body = Vec<ParsedFit>,
+            description = "All fits that were found",
+            status = OK,
+        ),
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    Json(content): Json<String>,
+) -> Result<impl IntoResponse> {
+    let fits = parse_fits(
+        &load_items_by_name(),
+        &load_items_by_type_id(),
+        &content,
+    );
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(fits)
+        )
+        .into_response()
+    )
+}
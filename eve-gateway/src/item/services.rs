@@ -4,6 +4,7 @@ mod fetch_group;
 mod fetch;
 mod list;
 mod parse;
+mod parse_fit;
 
 pub use self::fetch_bulk::*;
 pub use self::fetch_category::*;
@@ -11,3 +12,4 @@ pub use self::fetch_group::*;
 pub use self::fetch::*;
 pub use self::list::*;
 pub use self::parse::*;
+pub use self::parse_fit::*;
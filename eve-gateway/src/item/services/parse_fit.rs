@@ -0,0 +1,308 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use starfoundry_lib_eve_gateway::{Item, ParsedFit, ParsedItem};
+use starfoundry_lib_types::TypeId;
+use std::collections::HashMap;
+
+use crate::item::services::parse;
+
+static EFT_HEADER: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\[([^,\]]+)(?:,\s*([^\]]*))?\]$").unwrap());
+/// Ship and name of the header together with the lines of a fit
+type EftChunk<'a> = (Option<(String, Option<String>)>, Vec<&'a str>);
+
+static DNA: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d+(:(\d+_?(;\d+)?)?)*$").unwrap());
+
+/// Parses the given content as fits.
+///
+/// The format is detected automatically, supported are EFT, DNA and the XML
+/// export of the in-game fitting window.
+/// All formats support multiple fits, for DNA every line is a fit.
+///
+#[must_use]
+pub fn parse_fits(
+    cache_name:    &HashMap<String, Item>,
+    cache_type_id: &HashMap<TypeId, Item>,
+    content:       &str,
+) -> Vec<ParsedFit> {
+    let content = content.trim();
+
+    if content.contains("<fitting") {
+        parse_xml(cache_name, content)
+    } else if content.lines().all(|x| x.trim().is_empty() || DNA.is_match(x.trim())) {
+        parse_dna(cache_type_id, content)
+    } else {
+        parse_eft(cache_name, content)
+    }
+}
+
+/// `[Ship, Name]` followed by one module per line, every header starts a
+/// new fit
+fn parse_eft(
+    cache_name: &HashMap<String, Item>,
+    content:    &str,
+) -> Vec<ParsedFit> {
+    let mut chunks: Vec<EftChunk> = Vec::new();
+    for line in content.lines() {
+        if let Some(x) = EFT_HEADER.captures(line.trim()) {
+            let ship = x.get(1).map_or("", |m| m.as_str()).trim().to_string();
+            let name = x.get(2).map(|m| m.as_str().trim().to_string());
+            chunks.push((Some((ship, name)), vec![line]));
+        } else if let Some((_, lines)) = chunks.last_mut() {
+            lines.push(line);
+        } else {
+            chunks.push((None, vec![line]));
+        }
+    }
+
+    chunks
+        .into_iter()
+        .map(|(header, lines)| {
+            let mut result = parse(cache_name, &lines.join("\n"));
+
+            let ship = header
+                .as_ref()
+                .and_then(|(ship, _)| cache_name.get(&ship.to_lowercase()));
+            // the parser adds the ship of the header as first item
+            let ship = match (ship, result.items.first()) {
+                (Some(ship), Some(first)) if first.type_id == ship.type_id => {
+                    Some(result.items.remove(0))
+                },
+                _ => None,
+            };
+
+            ParsedFit {
+                name:    header.and_then(|(_, name)| name),
+                ship,
+                items:   result.items,
+                invalid: result.invalid,
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+/// `ship:module;quantity:module;quantity::`, modules with a trailing `_`
+/// are charges
+fn parse_dna(
+    cache_type_id: &HashMap<TypeId, Item>,
+    content:       &str,
+) -> Vec<ParsedFit> {
+    let mut fits = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut fit = ParsedFit::default();
+        for (index, entry) in line.split(':').enumerate() {
+            if entry.is_empty() {
+                continue;
+            }
+
+            let (type_id, quantity) = entry
+                .split_once(';')
+                .unwrap_or((entry, "1"));
+            let type_id = type_id
+                .trim_end_matches('_')
+                .parse::<i32>()
+                .map(TypeId)
+                .ok();
+            let quantity = quantity.parse::<i64>().unwrap_or(1);
+
+            let item = if let Some(x) = type_id.and_then(|x| cache_type_id.get(&x)) {
+                parsed_item(x, quantity)
+            } else {
+                fit.invalid.push(entry.to_string());
+                continue;
+            };
+
+            if index == 0 {
+                fit.ship = Some(item);
+            } else {
+                fit.items.push(item);
+            }
+        }
+
+        fits.push(fit);
+    }
+
+    fits
+}
+
+/// Export of the in-game fitting window, every `<fitting>` is a fit
+fn parse_xml(
+    cache_name: &HashMap<String, Item>,
+    content:    &str,
+) -> Vec<ParsedFit> {
+    static FITTING_NAME: Lazy<Regex> = Lazy::new(|| Regex::new(r#"^\s*name="([^"]*)""#).unwrap());
+    static SHIP_TYPE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"<shipType\s+value="([^"]*)""#).unwrap());
+    static HARDWARE: Lazy<Regex> = Lazy::new(|| Regex::new(r"<hardware\s+([^>]*)>").unwrap());
+    static ATTRIBUTE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(\w+)="([^"]*)""#).unwrap());
+
+    let mut fits = Vec::new();
+
+    for fitting in content.split("<fitting").skip(1) {
+        // skips the surrounding `<fittings>`
+        if !fitting.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let mut fit = ParsedFit {
+            name: FITTING_NAME
+                .captures(fitting)
+                .and_then(|x| x.get(1))
+                .map(|x| x.as_str().to_string()),
+            ..Default::default()
+        };
+
+        if let Some(ship) = SHIP_TYPE.captures(fitting).and_then(|x| x.get(1)) {
+            if let Some(x) = cache_name.get(&ship.as_str().to_lowercase()) {
+                fit.ship = Some(parsed_item(x, 1));
+            } else {
+                fit.invalid.push(ship.as_str().to_string());
+            }
+        }
+
+        for hardware in HARDWARE.captures_iter(fitting) {
+            let attributes = hardware
+                .get(1)
+                .map(|x| x.as_str())
+                .unwrap_or_default();
+            let attributes = ATTRIBUTE
+                .captures_iter(attributes)
+                .filter_map(|x| Some((x.get(1)?.as_str(), x.get(2)?.as_str())))
+                .collect::<HashMap<_, _>>();
+
+            let name = attributes
+                .get("type")
+                .copied()
+                .unwrap_or_default();
+            let quantity = attributes
+                .get("qty")
+                .and_then(|x| x.parse::<i64>().ok())
+                .unwrap_or(1);
+
+            if let Some(x) = cache_name.get(&name.to_lowercase()) {
+                fit.items.push(parsed_item(x, quantity));
+            } else {
+                fit.invalid.push(name.to_string());
+            }
+        }
+
+        fits.push(fit);
+    }
+
+    fits
+}
+
+fn parsed_item(
+    item:     &Item,
+    quantity: i64,
+) -> ParsedItem {
+    ParsedItem {
+        item_name:           item.name.clone(),
+        type_id:             item.type_id,
+        quantity,
+        material_efficiency: None,
+
+        raw:                 item.clone(),
+    }
+}
+
+#[cfg(test)]
+mod fit_parser_tests {
+    use sqlx::PgPool;
+
+    use crate::item::services::{load_items, load_items_by_name, load_items_by_type_id, parse_fits};
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("category", "groups", "items_min")
+        ),
+    )]
+    async fn eft_multiple_fits(
+        pool: PgPool,
+    ) {
+        dotenvy::dotenv().ok();
+        load_items(&pool).await.unwrap();
+        let content = "[Karura, Killmail 1337]
+Triple Neutron Blaster Cannon II, Void XL
+Triple Neutron Blaster Cannon II, Void XL
+
+[Nightmare, Second]
+Void XL x100";
+        let result = parse_fits(
+            &load_items_by_name(),
+            &load_items_by_type_id(),
+            content,
+        );
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, Some("Killmail 1337".into()));
+        assert_eq!(result[0].ship.as_ref().unwrap().item_name, "Karura".to_string());
+        assert_eq!(result[0].items.len(), 2);
+        assert_eq!(result[1].ship.as_ref().unwrap().item_name, "Nightmare".to_string());
+        assert_eq!(result[1].items[0].quantity, 100);
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("category", "groups", "items_min")
+        ),
+    )]
+    async fn dna(
+        pool: PgPool,
+    ) {
+        dotenvy::dotenv().ok();
+        load_items(&pool).await.unwrap();
+        let content = "77284:37300;3:41322_;100:1::";
+        let result = parse_fits(
+            &load_items_by_name(),
+            &load_items_by_type_id(),
+            content,
+        );
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].ship.as_ref().unwrap().item_name, "Karura".to_string());
+        assert_eq!(result[0].items.len(), 2);
+        assert_eq!(result[0].items[0].quantity, 3);
+        assert_eq!(result[0].items[1].quantity, 100);
+        assert_eq!(result[0].invalid, vec!["1".to_string()]);
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("category", "groups", "items_min")
+        ),
+    )]
+    async fn xml(
+        pool: PgPool,
+    ) {
+        dotenvy::dotenv().ok();
+        load_items(&pool).await.unwrap();
+        let content = r#"<?xml version="1.0" ?>
+<fittings>
+    <fitting name="Killmail 1337">
+        <description value=""/>
+        <shipType value="Karura"/>
+        <hardware slot="hi slot 0" type="Triple Neutron Blaster Cannon II"/>
+        <hardware qty="100" slot="cargo" type="Void XL"/>
+    </fitting>
+</fittings>"#;
+        let result = parse_fits(
+            &load_items_by_name(),
+            &load_items_by_type_id(),
+            content,
+        );
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, Some("Killmail 1337".into()));
+        assert_eq!(result[0].ship.as_ref().unwrap().item_name, "Karura".to_string());
+        assert_eq!(result[0].items.len(), 2);
+        assert_eq!(result[0].items[1].quantity, 100);
+    }
+}
@@ -18,6 +18,8 @@ pub mod metrics;
 pub mod state;
 pub mod utils;
 
+pub mod access;
+pub mod api_token;
 pub mod asset;
 pub mod character;
 pub mod fitting;
@@ -26,6 +28,7 @@ pub mod item;
 pub mod search;
 pub mod structure;
 pub mod system;
+pub mod wallet;
 
 pub mod eve;
 pub mod proxy;
@@ -42,6 +45,7 @@ use utoipa_axum::router::OpenApiRouter;
 use utoipa_scalar::{Scalar, Servable};
 use utoipa::OpenApi;
 
+use crate::access::service::{evaluate_periodically, seed_from_whitelist};
 use crate::api_docs::ApiDoc;
 use crate::config::Config;
 use crate::item::services::load_items;
@@ -74,6 +78,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .connect(&config.database_url)
         .await?;
     sqlx::migrate!().run(&postgres).await?;
+    seed_from_whitelist(&postgres, &config.domains).await?;
 
     // load items in the background to not interrupt the startup phase
     let pool = postgres.clone();
@@ -97,6 +102,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         auth_domains:   Arc::new(config.domains),
     };
 
+    // re-evaluate the access of all characters, so that characters that
+    // left an allowed corporation or alliance lose access
+    tokio::spawn(evaluate_periodically(
+        state.clone(),
+        config.access_evaluation_interval,
+    ));
+
     tracing::info!("Starting app server on {}", config.app_address.local_addr().unwrap());
     tracing::info!("Starting service server on {}", config.service_address.local_addr().unwrap());
 
@@ -126,6 +138,8 @@ fn app(
     let (router, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
         .nest("/.well-known", auth::well_known_routes())
 
+        .nest("/access-rules", access::routes())
+        .nest("/api-tokens", api_token::routes())
         .nest("/auth", auth::routes())
         .nest("/characters", character::routes())
         .nest("/industry", industry::routes())
@@ -128,6 +128,7 @@ enum Scope {
     Contacts,
     IndustryJob,
     Orders,
+    Wallet,
 }
 
 impl Scope {
@@ -138,6 +139,7 @@ impl Scope {
             Self::Contacts      => "esi-characters.read_contacts.v1",
             Self::IndustryJob   => "esi-industry.read_character_jobs.v1",
             Self::Orders        => "esi-markets.read_character_orders.v1",
+            Self::Wallet        => "esi-wallet.read_character_wallet.v1",
         }.into()
     }
 }
@@ -152,6 +154,8 @@ impl TryFrom<&str> for Scope {
             "contacts"      => Ok(Self::Contacts),
             "industry/jobs" => Ok(Self::IndustryJob),
             "orders"        => Ok(Self::Orders),
+            "wallet/journal" |
+            "wallet/transactions" => Ok(Self::Wallet),
             _               => {
                 tracing::error!("No scope for {}", value);
                 Err(ProxyError::NoScopeFound)
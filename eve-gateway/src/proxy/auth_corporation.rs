@@ -127,6 +127,7 @@ enum Scope {
     Contacts,
     IndustryJob,
     Orders,
+    Wallet,
 }
 
 impl Scope {
@@ -137,6 +138,7 @@ impl Scope {
             Self::Contacts      => "esi-corporations.read_contacts.v1",
             Self::IndustryJob   => "esi-industry.read_corporation_jobs.v1",
             Self::Orders        => "esi-markets.read_corporation_orders.v1",
+            Self::Wallet        => "esi-wallet.read_corporation_wallets.v1",
         }.into()
     }
 }
@@ -151,6 +153,7 @@ impl TryFrom<&str> for Scope {
             "contacts"      => Ok(Self::Contacts),
             "industry/jobs" => Ok(Self::IndustryJob),
             "orders"        => Ok(Self::Orders),
+            x if x.starts_with("wallets/") => Ok(Self::Wallet),
             _               => {
                 tracing::error!("No scope for {}", value);
                 Err(ProxyError::NoScopeFound)
@@ -2,6 +2,7 @@ mod fetch_rig_blueprint_bonus;
 mod error;
 mod fetch_rig;
 mod fetch_service;
+mod list_service_fuel;
 mod list_structure_rigs;
 mod list_structure_services;
 mod resolve_structure;
@@ -24,6 +25,9 @@ pub fn routes() -> OpenApiRouter<AppState> {
     let fetch_services = OpenApiRouter::new()
         .routes(routes!(fetch_service::api));
 
+    let list_service_fuel = OpenApiRouter::new()
+        .routes(routes!(list_service_fuel::api));
+
     let list_structure_rigs = OpenApiRouter::new()
         .routes(routes!(list_structure_rigs::api));
 
@@ -37,6 +41,7 @@ pub fn routes() -> OpenApiRouter<AppState> {
         .merge(fetch_rig)
         .merge(fetch_rig_blueprints)
         .merge(fetch_services)
+        .merge(list_service_fuel)
         .merge(list_structure_rigs)
         .merge(list_structure_services)
         .merge(resolve_structure)
@@ -33,6 +33,8 @@ pub enum StructureError {
     FetchRigInformation(sqlx::Error, TypeId),
     #[error("error while fetching structure services, type_id: '{1}', error: '{0}'")]
     FetchStructureServices(sqlx::Error, TypeId),
+    #[error("error while listing service fuel, error: '{0}'")]
+    ListServiceFuel(sqlx::Error),
 
     #[error("the item information are not available")]
     ItemNotFound,
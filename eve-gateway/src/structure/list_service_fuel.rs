@@ -0,0 +1,53 @@
+use axum::extract::State;
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_eve_gateway::StructureServiceFuel;
+use starfoundry_lib_types::TypeId;
+
+use crate::api_docs::InternalServerError;
+use crate::state::AppState;
+use crate::structure::error::Result;
+use crate::structure::services::list_service_fuel;
+
+/// List service fuel
+/// 
+/// - Alternative route: `/latest/structures/services/fuel`
+/// - Alternative route: `/v1/structures/services/fuel`
+/// 
+/// ---
+/// 
+/// Returns the fuel consumption of the given service modules.
+/// Unknown service modules are not part of the response.
+/// 
+#[utoipa::path(
+    post,
+    path = "/services/fuel",
+    tag = "Structures",
+    request_body = Vec<TypeId>,
+    responses(
+        (
+            body = Vec<StructureServiceFuel>,
+            description = "Fuel consumption of the service modules",
+            status = OK,
+        ),
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    State(state): State<AppState>,
+    Json(body):   Json<Vec<TypeId>>,
+) -> Result<impl IntoResponse> {
+    let entries = list_service_fuel(
+        &state.postgres,
+        body,
+    ).await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(entries)
+        )
+        .into_response()
+    )
+}
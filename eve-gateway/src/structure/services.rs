@@ -1,6 +1,7 @@
 mod fetch_rig_blueprint_bonus;
 mod fetch_rig;
 mod fetch_service;
+mod list_service_fuel;
 mod list_structure_rigs;
 mod list_structure;
 mod resolve_structure;
@@ -8,6 +9,7 @@ mod resolve_structure;
 pub use self::fetch_rig_blueprint_bonus::*;
 pub use self::fetch_rig::*;
 pub use self::fetch_service::*;
+pub use self::list_service_fuel::*;
 pub use self::list_structure_rigs::*;
 pub use self::list_structure::*;
 pub use self::resolve_structure::*;
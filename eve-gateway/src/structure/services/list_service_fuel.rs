@@ -0,0 +1,33 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::StructureServiceFuel;
+use starfoundry_lib_types::TypeId;
+
+use crate::structure::error::{Result, StructureError};
+
+pub async fn list_service_fuel(
+    pool:             &PgPool,
+    service_type_ids: Vec<TypeId>,
+) -> Result<Vec<StructureServiceFuel>> {
+    let entries = sqlx::query!(r#"
+            SELECT
+                type_id,
+                fuel_per_hour,
+                fuel_online
+            FROM structure_service_fuel
+            WHERE type_id = ANY($1)
+        "#,
+            &service_type_ids.into_iter().map(|x| *x).collect::<Vec<_>>(),
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(StructureError::ListServiceFuel)?
+        .into_iter()
+        .map(|x| StructureServiceFuel {
+            type_id:       x.type_id.into(),
+            fuel_per_hour: x.fuel_per_hour,
+            fuel_online:   x.fuel_online,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(entries)
+}
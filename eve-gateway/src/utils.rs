@@ -42,3 +42,33 @@ pub async fn api_client_auth(
     )
     .map(Option::Some)
 }
+
+/// Checks if the character granted one of the given scopes.
+///
+/// Synced corporation data is owned by the corporation and not by a
+/// character, it is only readable by characters that granted the matching
+/// corporation scope, which requires the in-game role.
+pub async fn has_scope(
+    pool:         &PgPool,
+    host:         String,
+    character_id: CharacterId,
+    scopes:       Vec<String>,
+) -> std::result::Result<bool, sqlx::Error> {
+    sqlx::query!(r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM eve_credential
+                WHERE
+                    domain = $1 AND
+                    character_id = $2 AND
+                    scopes && $3::VARCHAR[]
+            ) AS "exists!"
+        "#,
+            host,
+            *character_id,
+            &scopes,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|x| x.exists)
+}
@@ -0,0 +1,25 @@
+mod list_journal;
+mod list_transactions;
+
+pub mod service;
+
+mod error;
+pub use self::error::*;
+
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use crate::state::AppState;
+
+/// Exposes all routes that are under `/eve/wallet`
+pub fn routes() -> OpenApiRouter<AppState> {
+    let list_journal = OpenApiRouter::new()
+        .routes(routes!(list_journal::api));
+
+    let list_transactions = OpenApiRouter::new()
+        .routes(routes!(list_transactions::api));
+
+    OpenApiRouter::new()
+        .merge(list_journal)
+        .merge(list_transactions)
+}
@@ -0,0 +1,43 @@
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use thiserror::Error;
+
+use crate::api_docs::ErrorResponse;
+
+pub type Result<T, E = WalletError> = std::result::Result<T, E>;
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum WalletError {
+    #[error("error while listing wallet journal, error: '{0}'")]
+    ListJournal(sqlx::Error),
+    #[error("error while listing wallet transactions, error: '{0}'")]
+    ListTransactions(sqlx::Error),
+
+    #[error("gateway error, error: '{0:?}'")]
+    GatewayError(#[from] starfoundry_lib_gateway::Error),
+}
+
+impl IntoResponse for WalletError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::GatewayError(e) => {
+                starfoundry_lib_gateway::Error::into_response(e)
+            },
+            _ => {
+                tracing::error!("{}", self.to_string());
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(
+                        ErrorResponse {
+                            error: "UNKNOWN".into(),
+                            description: "An unknown error occurred, please try again later.".into(),
+                        }
+                    )
+                ).into_response()
+            }
+        }
+        .into_response()
+    }
+}
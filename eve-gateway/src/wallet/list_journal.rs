@@ -0,0 +1,62 @@
+use axum::extract::State;
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_eve_gateway::{WalletFilter, WalletJournalEntry};
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::api_docs::{InternalServerError, Unauthorized};
+use crate::state::AppState;
+use crate::wallet::Result;
+use crate::wallet::service::list_journal;
+
+/// List Wallet Journal
+/// 
+/// - Alternative route: `/latest/eve/wallet/journal`
+/// - Alternative route: `/v1/eve/wallet/journal`
+/// 
+/// ---
+/// 
+/// Lists the synced wallet journal entries of the character and its
+/// corporation, that match the given filter.
+/// Corporation entries are only listed if the character granted the scope
+/// `esi-wallet.read_corporation_wallets.v1`.
+/// The journals are synced once an hour.
+/// 
+#[utoipa::path(
+    post,
+    path = "/wallet/journal",
+    tag = "Wallet",
+    request_body = WalletFilter,
+    responses(
+        (
+            body = Vec<WalletJournalEntry>,
+            description = "Journal entries matching the filter",
+            status = OK,
+        ),
+        Unauthorized,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:       ExtractIdentity,
+    State(state):   State<AppState>,
+    Json(filter):   Json<WalletFilter>,
+) -> Result<impl IntoResponse> {
+    let entries = list_journal(
+            &state.postgres,
+            identity.host()?,
+            identity.character_id,
+            identity.corporation_id,
+            filter,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(entries),
+        )
+        .into_response()
+    )
+}
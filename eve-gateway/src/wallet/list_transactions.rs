@@ -0,0 +1,62 @@
+use axum::extract::State;
+use axum::Json;
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use starfoundry_lib_eve_gateway::{WalletFilter, WalletTransactionEntry};
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::api_docs::{InternalServerError, Unauthorized};
+use crate::state::AppState;
+use crate::wallet::Result;
+use crate::wallet::service::list_transactions;
+
+/// List Wallet Transactions
+/// 
+/// - Alternative route: `/latest/eve/wallet/transactions`
+/// - Alternative route: `/v1/eve/wallet/transactions`
+/// 
+/// ---
+/// 
+/// Lists the synced wallet transactions of the character and its
+/// corporation, that match the given filter.
+/// Corporation entries are only listed if the character granted the scope
+/// `esi-wallet.read_corporation_wallets.v1`.
+/// The transactions are synced once an hour.
+/// 
+#[utoipa::path(
+    post,
+    path = "/wallet/transactions",
+    tag = "Wallet",
+    request_body = WalletFilter,
+    responses(
+        (
+            body = Vec<WalletTransactionEntry>,
+            description = "Transactions matching the filter",
+            status = OK,
+        ),
+        Unauthorized,
+        InternalServerError,
+    ),
+)]
+pub async fn api(
+    identity:       ExtractIdentity,
+    State(state):   State<AppState>,
+    Json(filter):   Json<WalletFilter>,
+) -> Result<impl IntoResponse> {
+    let entries = list_transactions(
+            &state.postgres,
+            identity.host()?,
+            identity.character_id,
+            identity.corporation_id,
+            filter,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(entries),
+        )
+        .into_response()
+    )
+}
@@ -0,0 +1,27 @@
+mod list_journal;
+mod list_transactions;
+
+pub use self::list_journal::*;
+pub use self::list_transactions::*;
+
+use sqlx::PgPool;
+use starfoundry_lib_types::{CharacterId, CorporationId};
+
+use crate::utils::has_scope;
+
+const CORPORATION_SCOPE: &str = "esi-wallet.read_corporation_wallets.v1";
+
+/// Owners of the wallets the character can read, the corporation wallets are
+/// only readable if the character granted the corporation wallet scope
+async fn wallet_owner_ids(
+    pool:           &PgPool,
+    host:           String,
+    character_id:   CharacterId,
+    corporation_id: CorporationId,
+) -> Result<Vec<i32>, sqlx::Error> {
+    let mut owner_ids = vec![*character_id];
+    if has_scope(pool, host, character_id, vec![CORPORATION_SCOPE.into()]).await? {
+        owner_ids.push(*corporation_id);
+    }
+    Ok(owner_ids)
+}
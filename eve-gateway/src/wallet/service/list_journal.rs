@@ -0,0 +1,61 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{WalletFilter, WalletJournalEntry};
+use starfoundry_lib_types::{CharacterId, CorporationId, JournalId};
+
+use crate::wallet::{Result, WalletError};
+use crate::wallet::service::wallet_owner_ids;
+
+/// Lists all synced journal entries of the character and its corporation
+/// that match the filter, ordered by date.
+/// The corporation is only included if the character can read its wallets
+pub async fn list_journal(
+    pool:           &PgPool,
+    host:           String,
+    character_id:   CharacterId,
+    corporation_id: CorporationId,
+    filter:         WalletFilter,
+) -> Result<Vec<WalletJournalEntry>> {
+    let owner_ids = wallet_owner_ids(pool, host, character_id, corporation_id)
+        .await
+        .map_err(WalletError::ListJournal)?;
+
+    let entries = sqlx::query!(r#"
+            SELECT
+                owner_id,
+                division,
+                journal_id,
+                date,
+                ref_type,
+                amount,
+                context_id,
+                context_id_type
+            FROM wallet_journal
+            WHERE owner_id = ANY($1)
+            AND date >= $2
+            AND ($3::TIMESTAMPTZ IS NULL OR date < $3)
+            AND (CARDINALITY($4::VARCHAR[]) = 0 OR ref_type = ANY($4))
+            ORDER BY date, journal_id
+        "#,
+            &owner_ids,
+            filter.from,
+            filter.to,
+            &filter.ref_types,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(WalletError::ListJournal)?
+        .into_iter()
+        .map(|x| WalletJournalEntry {
+            owner_id:        x.owner_id,
+            division:        x.division,
+            id:              JournalId(x.journal_id),
+            date:            x.date,
+            ref_type:        x.ref_type,
+            amount:          x.amount,
+            context_id:      x.context_id,
+            context_id_type: x.context_id_type,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(entries)
+}
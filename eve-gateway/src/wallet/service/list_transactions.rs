@@ -0,0 +1,63 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{WalletFilter, WalletTransactionEntry};
+use starfoundry_lib_types::{CharacterId, CorporationId, LocationId, TransactionId, TypeId};
+
+use crate::wallet::{Result, WalletError};
+use crate::wallet::service::wallet_owner_ids;
+
+/// Lists all synced transactions of the character and its corporation that
+/// match the filter, ordered by date.
+/// The corporation is only included if the character can read its wallets
+pub async fn list_transactions(
+    pool:           &PgPool,
+    host:           String,
+    character_id:   CharacterId,
+    corporation_id: CorporationId,
+    filter:         WalletFilter,
+) -> Result<Vec<WalletTransactionEntry>> {
+    let owner_ids = wallet_owner_ids(pool, host, character_id, corporation_id)
+        .await
+        .map_err(WalletError::ListTransactions)?;
+
+    let entries = sqlx::query!(r#"
+            SELECT
+                owner_id,
+                division,
+                transaction_id,
+                date,
+                type_id,
+                quantity,
+                unit_price,
+                client_id,
+                location_id,
+                is_buy
+            FROM wallet_transaction
+            WHERE owner_id = ANY($1)
+            AND date >= $2
+            AND ($3::TIMESTAMPTZ IS NULL OR date < $3)
+            ORDER BY date, transaction_id
+        "#,
+            &owner_ids,
+            filter.from,
+            filter.to,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(WalletError::ListTransactions)?
+        .into_iter()
+        .map(|x| WalletTransactionEntry {
+            owner_id:       x.owner_id,
+            division:       x.division,
+            transaction_id: TransactionId(x.transaction_id),
+            date:           x.date,
+            type_id:        TypeId(x.type_id),
+            quantity:       x.quantity,
+            unit_price:     x.unit_price,
+            client_id:      x.client_id,
+            location_id:    LocationId(x.location_id),
+            is_buy:         x.is_buy,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(entries)
+}
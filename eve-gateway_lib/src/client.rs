@@ -6,7 +6,7 @@ use std::fmt::Debug;
 use url::Url;
 
 use crate::error::{Error, Result};
-use crate::{ApiClientExtended, ENV_EVE_GATEWAY_API, EveGatewayApiClient, EveGatewayApiClientAsset, EveGatewayApiClientEveAsset, EveGatewayApiClientFitting, EveGatewayApiClientIndustry, EveGatewayApiClientItem, EveGatewayApiClientSearch, EveGatewayApiClientStanding, EveGatewayApiClientSystem};
+use crate::{ApiClientExtended, ENV_EVE_GATEWAY_API, EveGatewayApiClient, EveGatewayApiClientAsset, EveGatewayApiClientEveAsset, EveGatewayApiClientFitting, EveGatewayApiClientIndustry, EveGatewayApiClientItem, EveGatewayApiClientSearch, EveGatewayApiClientStanding, EveGatewayApiClientSystem, EveGatewayApiClientWallet};
 use crate::contract::EveGatewayApiClientContract;
 use crate::market::EveGatewayApiClientMarket;
 use crate::utils::{has_content, page_count};
@@ -191,3 +191,4 @@ impl EveGatewayApiClientItem for EveGatewayClient {}
 impl EveGatewayApiClientSearch for EveGatewayClient {}
 impl EveGatewayApiClientStanding for EveGatewayClient {}
 impl EveGatewayApiClientSystem for EveGatewayClient {}
+impl EveGatewayApiClientWallet for EveGatewayClient {}
@@ -2,7 +2,7 @@ use serde::de::DeserializeOwned;
 use starfoundry_lib_gateway::ApiClient;
 use starfoundry_lib_types::{CharacterId, StructureId, TypeId};
 
-use crate::{AuthedCharacterInfo, CharacterInfo, EveGatewayApiClientAsset, EveGatewayApiClientEveAsset, EveGatewayApiClientFitting, EveGatewayApiClientIndustry, EveGatewayApiClientItem, EveGatewayApiClientSearch, EveGatewayApiClientStanding, EveGatewayApiClientSystem, ResolveStructureResponse, StructureRigBlueprintBonus, StructureRigResponse, StructureServiceResponse};
+use crate::{AuthedCharacterInfo, CharacterInfo, EveGatewayApiClientAsset, EveGatewayApiClientEveAsset, EveGatewayApiClientFitting, EveGatewayApiClientIndustry, EveGatewayApiClientItem, EveGatewayApiClientSearch, EveGatewayApiClientStanding, EveGatewayApiClientSystem, EveGatewayApiClientWallet, ResolveStructureResponse, StructureRigBlueprintBonus, StructureRigResponse, StructureServiceFuel, StructureServiceResponse};
 use crate::contract::EveGatewayApiClientContract;
 use crate::error::Result;
 use crate::market::EveGatewayApiClientMarket;
@@ -19,7 +19,8 @@ pub trait EveGatewayApiClient:
     EveGatewayApiClientItem +
     EveGatewayApiClientSearch +
     EveGatewayApiClientStanding +
-    EveGatewayApiClientSystem {
+    EveGatewayApiClientSystem +
+    EveGatewayApiClientWallet {
 
     #[allow(async_fn_in_trait)]
     async fn list_characters(
@@ -31,6 +32,16 @@ pub trait EveGatewayApiClient:
             .map_err(Into::into)
     }
 
+    #[allow(async_fn_in_trait)]
+    async fn list_scopes(
+        &self,
+    ) -> Result<Vec<String>> {
+        self
+            .fetch("characters/scopes", &())
+            .await
+            .map_err(Into::into)
+    }
+
     #[allow(async_fn_in_trait)]
     async fn fetch_character(
         &self,
@@ -114,6 +125,17 @@ pub trait EveGatewayApiClient:
             .map_err(Into::into)
     }
 
+    #[allow(async_fn_in_trait)]
+    async fn list_service_fuel(
+        &self,
+        service_type_ids: Vec<TypeId>,
+    ) -> Result<Vec<StructureServiceFuel>> {
+        self
+            .post("structures/services/fuel", service_type_ids)
+            .await
+            .map_err(Into::into)
+    }
+
     #[allow(async_fn_in_trait)]
     async fn list_rig_blueprints(
         &self,
@@ -1,9 +1,11 @@
 mod asset;
+mod asset_stock;
 mod blueprint;
 mod location_flag;
 mod resolved_item;
 
 pub use self::asset::*;
+pub use self::asset_stock::*;
 pub use self::blueprint::*;
 pub use self::location_flag::*;
 pub use self::resolved_item::*;
@@ -16,6 +18,22 @@ use starfoundry_lib_types::ItemId;
 use crate::Result;
 
 pub trait EveGatewayApiClientEveAsset: ApiClient {
+    /// Lists the synced assets of the character and its corporation that
+    /// match the filter, quantities of the same item are summed up
+    #[allow(async_fn_in_trait)]
+    async fn eve_list_asset_stock(
+        &self,
+        filter: &AssetStockFilter,
+    ) -> Result<Vec<AssetStock>> {
+        self
+            .post(
+                "eve/assets/stock",
+                filter,
+            )
+            .await
+            .map_err(Into::into)
+    }
+
     #[allow(async_fn_in_trait)]
     async fn eve_resolve_character_asset(
         &self,
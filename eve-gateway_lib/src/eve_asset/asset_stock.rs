@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::{LocationId, TypeId};
+use utoipa::ToSchema;
+
+/// Filter for the synced assets that should be used as stock.
+///
+/// All filters are combined, empty lists are ignored.
+///
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "location_ids": [1046664001931_i64],
+        "location_flags": ["CorpSAG3"],
+        "container_names": ["C1000"]
+    })
+)]
+pub struct AssetStockFilter {
+    /// Structures or stations the assets are located in
+    pub location_ids:      Vec<LocationId>,
+    /// Hangars within the structure, for example `Hangar` or `CorpSAG3`
+    #[serde(default)]
+    pub location_flags:    Vec<String>,
+    /// Names of the containers the assets are in
+    #[serde(default)]
+    pub container_names:   Vec<String>,
+    /// Also counts assembled items that are directly in a hangar, like ships.
+    /// Items fitted to them or within them are still only counted if they are
+    /// not assembled
+    #[serde(default)]
+    pub include_assembled: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "type_id": 34,
+        "quantity": 1000000,
+        "location_id": 1046664001931_i64,
+        "location_flag": "CorpSAG3",
+        "container_name": "C1000"
+    })
+)]
+pub struct AssetStock {
+    pub type_id:        TypeId,
+    pub quantity:       i64,
+    pub location_id:    LocationId,
+    pub location_flag:  String,
+    pub container_name: Option<String>,
+}
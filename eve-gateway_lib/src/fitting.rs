@@ -23,6 +23,20 @@ pub trait EveGatewayApiClientFitting: ApiClient {
             .map_err(Into::into)
     }
 
+    #[allow(async_fn_in_trait)]
+    async fn list_fits(
+        &self,
+        identity:   Identity,
+    ) -> Result<Vec<EveFitting>> {
+        self
+            .fetch(
+                &format!("characters/{}/fittings", identity.character_id()),
+                &(),
+            )
+            .await
+            .map_err(Into::into)
+    }
+
     #[allow(async_fn_in_trait)]
     async fn delete_fit(
         &self,
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use starfoundry_lib_types::{FittingId, TypeId};
 
-use crate::LocationFlag;
+use crate::{LocationFlag, ParsedItem};
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[schema(
@@ -38,6 +38,29 @@ pub struct EveFitItem {
     pub flag:       LocationFlag,
 }
 
+/// Fitting of a character as it is stored in eve
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct EveFitting {
+    pub fitting_id:     FittingId,
+    pub name:           String,
+    pub description:    String,
+    pub items:          Vec<EveFitItem>,
+    pub ship_type_id:   TypeId,
+}
+
+/// Single fit that was parsed from EFT, DNA or the XML export of the in-game
+/// fitting window
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct ParsedFit {
+    /// Name of the fit, if the format contains one
+    pub name:       Option<String>,
+    pub ship:       Option<ParsedItem>,
+    /// Modules, charges, drones and cargo of the fit
+    pub items:      Vec<ParsedItem>,
+    /// Lines or entries that could not be matched to an item
+    pub invalid:    Vec<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct EveFitResponse {
     fitting_id: FittingId,
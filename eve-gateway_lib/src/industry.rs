@@ -1,11 +1,13 @@
 mod blueprint_dependency;
 mod blueprint_json;
 mod industry_job;
+mod planet_schematic;
 mod system_index;
 
 pub use self::blueprint_dependency::*;
 pub use self::blueprint_json::*;
 pub use self::industry_job::*;
+pub use self::planet_schematic::*;
 pub use self::system_index::*;
 
 use starfoundry_lib_gateway::ApiClient;
@@ -39,6 +41,19 @@ pub trait EveGatewayApiClientIndustry: ApiClient + ApiClientExtended {
             .map_err(Into::into)
     }
 
+    /// Lists all planetary industry schematics and the planet types the raw
+    /// resources can be extracted from
+    /// 
+    #[allow(async_fn_in_trait)]
+    async fn list_planet_schematics(
+        &self,
+    ) -> Result<PlanetSchematics> {
+        self
+            .fetch("industry/planet-schematics", &())
+            .await
+            .map_err(Into::into)
+    }
+
     /// Lists all active industry jobs from the character from the EVE-API
     /// 
     #[allow(async_fn_in_trait)]
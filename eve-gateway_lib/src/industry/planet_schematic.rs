@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::TypeId;
+use utoipa::ToSchema;
+
+/// Schematic of a planetary factory
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct PlanetSchematic {
+    pub schematic_id: i32,
+    pub name:         String,
+    /// Duration of a single cycle in seconds
+    pub cycle_time:   i32,
+    /// Product of the schematic
+    pub type_id:      TypeId,
+    /// Quantity produced per cycle
+    pub quantity:     i32,
+    pub materials:    Vec<PlanetSchematicMaterial>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct PlanetSchematicMaterial {
+    pub type_id:  TypeId,
+    /// Quantity required per cycle
+    pub quantity: i32,
+}
+
+/// Raw resource that can be extracted from planets
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct PlanetResource {
+    pub type_id:         TypeId,
+    /// Planet types the resource can be extracted from
+    pub planet_type_ids: Vec<TypeId>,
+}
+
+/// All schematics and raw resources of planetary industry
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct PlanetSchematics {
+    pub schematics: Vec<PlanetSchematic>,
+    pub resources:  Vec<PlanetResource>,
+}
@@ -3,7 +3,7 @@ use starfoundry_lib_types::{CategoryId, GroupId, TypeId};
 use utoipa::{IntoParams, ToSchema};
 use starfoundry_lib_gateway::ApiClient;
 
-use crate::Result;
+use crate::{ParsedFit, Result};
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[schema(
@@ -179,4 +179,17 @@ pub trait EveGatewayApiClientItem: ApiClient {
             .await
             .map_err(Into::into)
     }
+
+    /// Parses EFT, DNA or the XML export of the in-game fitting window,
+    /// the content may contain multiple fits
+    #[allow(async_fn_in_trait)]
+    async fn parse_fits(
+        &self,
+        content: String,
+    ) -> Result<Vec<ParsedFit>> {
+        self
+            .post("items/parse/fit", content)
+            .await
+            .map_err(Into::into)
+    }
 }
@@ -16,6 +16,7 @@ mod structure_type;
 mod structure;
 mod system;
 mod utils;
+mod wallet;
 
 pub mod contract;
 
@@ -34,6 +35,7 @@ pub use self::standing::*;
 pub use self::structure::*;
 pub use self::structure_type::*;
 pub use self::system::*;
+pub use self::wallet::*;
 
 pub const ENV_EVE_GATEWAY_API: &str      = "STARFOUNDRY_EVE_GATEWAY_API_URL";
 pub const ENV_EVE_GATEWAY_JWT_SIGN: &str = "STARFOUNDRY_EVE_GATEWAY_JWT_SIGN";
@@ -39,6 +39,17 @@ pub struct StructureServiceResponse {
     pub slots:    i32,
 }
 
+/// Fuel consumption of a single service module
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct StructureServiceFuel {
+    /// [TypeId] of the service module
+    pub type_id:       TypeId,
+    /// Fuel blocks consumed per hour while the service is online
+    pub fuel_per_hour: i32,
+    /// Fuel blocks consumed once when the service is onlined
+    pub fuel_online:   i32,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct StructureRigResponse {
     pub item:       Item,
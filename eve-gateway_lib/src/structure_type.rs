@@ -80,6 +80,52 @@ impl StructureType {
         }
     }
 
+    /// Fuel reduction in percent the structure grants the given service
+    /// module
+    pub fn fuel_bonus(
+        &self,
+        service_type_id: TypeId,
+    ) -> f32 {
+        // Manufacturing, Capital Shipyard, Supercapital Shipyard,
+        // Invention Lab, Research Lab, Hyasyoda Research Lab
+        const ENGINEERING: [i32; 6] = [35878, 35881, 35877, 35886, 35891, 45550];
+        // Reprocessing Facility, Composite, Biochemical and Hybrid Reactor,
+        // Moon Drill
+        const RESOURCE_PROCESSING: [i32; 5] = [35899, 45009, 45537, 45538, 45539];
+        // Cloning Center, Market Hub
+        const CITADEL: [i32; 2] = [35894, 35892];
+
+        let service_type_id = *service_type_id;
+        let (services, bonus) = match self {
+            Self::Raitaru |
+            Self::Azbel   |
+            Self::Sotiyo  => (ENGINEERING.as_slice(), 25f32),
+
+            Self::Athanor => (RESOURCE_PROCESSING.as_slice(), 20f32),
+            Self::Tatara  => (RESOURCE_PROCESSING.as_slice(), 25f32),
+
+            Self::Astrahus           |
+            Self::Fortizar           |
+            Self::Keepstar           |
+            Self::MoreauFortizar     |
+            Self::DraccousFortizar   |
+            Self::HorizonFortizar    |
+            Self::MarginisFortizar   |
+            Self::PrometheusFortizar |
+            Self::PalatineKeepstar   => (CITADEL.as_slice(), 25f32),
+
+            Self::NpcAmarr |
+            Self::NpcJita  |
+            Self::Invalid  => return 0f32,
+        };
+
+        if services.contains(&service_type_id) {
+            bonus
+        } else {
+            0f32
+        }
+    }
+
     pub fn deserialize<'de, D>(
         deserializer: D
     ) -> Result<Self, D::Error>
@@ -0,0 +1,81 @@
+mod wallet_entry;
+mod wallet_model;
+
+pub use self::wallet_entry::*;
+pub use self::wallet_model::*;
+
+use starfoundry_lib_gateway::ApiClient;
+
+use crate::{ApiClientExtended, Result};
+
+pub trait EveGatewayApiClientWallet: ApiClient + ApiClientExtended {
+    #[allow(async_fn_in_trait)]
+    async fn list_character_wallet_journal(
+        &self,
+    ) -> Result<Vec<WalletJournal>> {
+        self
+            .fetch_page("proxy/auth/characters/wallet/journal")
+            .await
+    }
+
+    #[allow(async_fn_in_trait)]
+    async fn list_character_wallet_transactions(
+        &self,
+    ) -> Result<Vec<WalletTransaction>> {
+        self
+            .fetch_page("proxy/auth/characters/wallet/transactions")
+            .await
+    }
+
+    /// Division is between 1 and 7
+    #[allow(async_fn_in_trait)]
+    async fn list_corporation_wallet_journal(
+        &self,
+        division: i32,
+    ) -> Result<Vec<WalletJournal>> {
+        self
+            .fetch_page(format!("proxy/auth/corporations/wallets/{division}/journal"))
+            .await
+    }
+
+    /// Division is between 1 and 7
+    #[allow(async_fn_in_trait)]
+    async fn list_corporation_wallet_transactions(
+        &self,
+        division: i32,
+    ) -> Result<Vec<WalletTransaction>> {
+        self
+            .fetch_page(format!("proxy/auth/corporations/wallets/{division}/transactions"))
+            .await
+    }
+
+    /// Lists the synced journal entries of the character and its corporation
+    #[allow(async_fn_in_trait)]
+    async fn eve_list_wallet_journal(
+        &self,
+        filter: &WalletFilter,
+    ) -> Result<Vec<WalletJournalEntry>> {
+        self
+            .post(
+                "eve/wallet/journal",
+                filter,
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Lists the synced transactions of the character and its corporation
+    #[allow(async_fn_in_trait)]
+    async fn eve_list_wallet_transactions(
+        &self,
+        filter: &WalletFilter,
+    ) -> Result<Vec<WalletTransactionEntry>> {
+        self
+            .post(
+                "eve/wallet/transactions",
+                filter,
+            )
+            .await
+            .map_err(Into::into)
+    }
+}
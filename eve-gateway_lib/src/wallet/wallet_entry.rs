@@ -0,0 +1,85 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::{JournalId, LocationId, TransactionId, TypeId};
+use utoipa::ToSchema;
+
+/// Filter for the synced wallet entries.
+///
+/// Empty lists are ignored.
+///
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "from": "2026-10-01T00:00:00Z",
+        "ref_types": ["industry_job_tax"]
+    })
+)]
+pub struct WalletFilter {
+    /// Only entries at or after the given date
+    pub from:      DateTime<Utc>,
+    /// Only entries before the given date
+    #[serde(default)]
+    pub to:        Option<DateTime<Utc>>,
+    /// Only journal entries with the given ref types, ignored for
+    /// transactions
+    #[serde(default)]
+    pub ref_types: Vec<String>,
+}
+
+/// Synced journal entry of the character or its corporation
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "owner_id": 98000001,
+        "division": 1,
+        "id": 23081237891_i64,
+        "date": "2026-10-01T12:00:00Z",
+        "ref_type": "industry_job_tax",
+        "amount": -1250000.0,
+        "context_id": 560000001,
+        "context_id_type": "industry_job_id"
+    })
+)]
+pub struct WalletJournalEntry {
+    /// Character or corporation the wallet belongs to
+    pub owner_id:        i32,
+    /// Wallet division, `0` for character wallets
+    pub division:        i32,
+    pub id:              JournalId,
+    pub date:            DateTime<Utc>,
+    pub ref_type:        String,
+    pub amount:          f64,
+    pub context_id:      Option<i64>,
+    pub context_id_type: Option<String>,
+}
+
+/// Synced wallet transaction of the character or its corporation
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "owner_id": 98000001,
+        "division": 1,
+        "transaction_id": 6000000001_i64,
+        "date": "2026-10-01T12:00:00Z",
+        "type_id": 34,
+        "quantity": 1000000,
+        "unit_price": 4.2,
+        "client_id": 2117441999,
+        "location_id": 60003760,
+        "is_buy": true
+    })
+)]
+pub struct WalletTransactionEntry {
+    /// Character or corporation the wallet belongs to
+    pub owner_id:       i32,
+    /// Wallet division, `0` for character wallets
+    pub division:       i32,
+    pub transaction_id: TransactionId,
+    pub date:           DateTime<Utc>,
+    pub type_id:        TypeId,
+    pub quantity:       i32,
+    pub unit_price:     f64,
+    pub client_id:      i32,
+    pub location_id:    LocationId,
+    pub is_buy:         bool,
+}
@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::{JournalId, LocationId, TransactionId, TypeId};
+use utoipa::ToSchema;
+
+/// Single entry of a wallet journal, as returned by the EVE-API
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct WalletJournal {
+    /// Unique id of the journal entry
+    pub id:              JournalId,
+    /// Date of the journal entry
+    pub date:            DateTime<Utc>,
+    /// Type of the entry, for example `market_transaction` or
+    /// `industry_job_tax`
+    pub ref_type:        String,
+    /// Amount of ISK, negative if ISK was spent
+    #[serde(default)]
+    pub amount:          Option<f64>,
+    /// Id of the object the entry belongs to, for example the job id for
+    /// `industry_job_tax`
+    #[serde(default)]
+    pub context_id:      Option<i64>,
+    /// Type of the `context_id`, for example `industry_job_id`
+    #[serde(default)]
+    pub context_id_type: Option<String>,
+    /// Description of the entry
+    #[serde(default)]
+    pub description:     String,
+}
+
+/// Single wallet transaction, as returned by the EVE-API
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct WalletTransaction {
+    /// Unique id of the transaction
+    pub transaction_id: TransactionId,
+    /// Date of the transaction
+    pub date:           DateTime<Utc>,
+    /// Item that was bought or sold
+    pub type_id:        TypeId,
+    /// Number of items
+    pub quantity:       i32,
+    /// Price per item
+    pub unit_price:     f64,
+    /// Other party of the transaction
+    pub client_id:      i32,
+    /// Station or structure the transaction was done in
+    pub location_id:    LocationId,
+    /// `true` if the items were bought
+    pub is_buy:         bool,
+    /// Journal entry that belongs to the transaction
+    pub journal_ref_id: JournalId,
+}
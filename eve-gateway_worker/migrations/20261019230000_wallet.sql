@@ -0,0 +1,37 @@
+CREATE TABLE IF NOT EXISTS wallet_journal(
+    -- either a character or corporation
+    owner_id        INTEGER          NOT NULL,
+    -- 0 for character wallets, 1-7 for corporation wallets
+    division        INTEGER          NOT NULL,
+
+    journal_id      BIGINT           NOT NULL,
+    date            TIMESTAMPTZ      NOT NULL,
+    ref_type        VARCHAR          NOT NULL,
+    amount          DOUBLE PRECISION NOT NULL,
+
+    context_id      BIGINT,
+    context_id_type VARCHAR,
+
+    PRIMARY KEY(owner_id, journal_id)
+);
+CREATE INDEX IF NOT EXISTS wallet_journal_owner_date ON wallet_journal (owner_id, date);
+
+CREATE TABLE IF NOT EXISTS wallet_transaction(
+    -- either a character or corporation
+    owner_id        INTEGER          NOT NULL,
+    -- 0 for character wallets, 1-7 for corporation wallets
+    division        INTEGER          NOT NULL,
+
+    transaction_id  BIGINT           NOT NULL,
+    date            TIMESTAMPTZ      NOT NULL,
+    type_id         INTEGER          NOT NULL,
+    quantity        INTEGER          NOT NULL,
+    unit_price      DOUBLE PRECISION NOT NULL,
+    client_id       INTEGER          NOT NULL,
+    location_id     BIGINT           NOT NULL,
+    is_buy          BOOLEAN          NOT NULL,
+    journal_id      BIGINT           NOT NULL,
+
+    PRIMARY KEY(owner_id, transaction_id)
+);
+CREATE INDEX IF NOT EXISTS wallet_transaction_owner_date ON wallet_transaction (owner_id, date);
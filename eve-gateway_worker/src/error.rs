@@ -23,6 +23,9 @@ pub enum Error {
     #[error("error while inserting standings, error: '{0}'")]
     InsertStandings(sqlx::Error),
 
+    #[error("error while inserting wallet entries for '{1}', error: '{0}'")]
+    InsertWallet(sqlx::Error, i32),
+
     #[error("error while cleaning items for '{1}', error: '{0}'")]
     CleanupItems(sqlx::Error, i32),
 
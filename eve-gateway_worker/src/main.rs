@@ -7,6 +7,7 @@ mod metric;
 mod standing;
 mod sync;
 mod tasks;
+mod wallet;
 
 use prometheus_client::registry::Registry;
 use sqlx::PgPool;
@@ -190,5 +191,18 @@ async fn task_select(
                 task
             ).await
         },
+
+        WorkerEveGatewayTask::CharacterWallet => {
+            crate::wallet::character_wallet(
+                pool,
+                task
+            ).await
+        },
+        WorkerEveGatewayTask::CorporationWallet => {
+            crate::wallet::corporation_wallet(
+                pool,
+                task
+            ).await
+        },
     }
 }
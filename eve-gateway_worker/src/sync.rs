@@ -93,6 +93,27 @@ pub async fn sync_task(
         Err(e) => task.append_error(e.to_string()),
     };
 
+    match sync_character_wallets(
+        pool,
+    ).await {
+        Ok(new_entries) => {
+            if new_entries > 0 {
+                task.append_log(format!("added {new_entries} character wallets"))
+            }
+        },
+        Err(e) => task.append_error(e.to_string()),
+    };
+    match sync_corporation_wallets(
+        pool,
+    ).await {
+        Ok(new_entries) => {
+            if new_entries > 0 {
+                task.append_log(format!("added {new_entries} corporation wallets"))
+            }
+        },
+        Err(e) => task.append_error(e.to_string()),
+    };
+
     Ok(())
 }
 
@@ -129,6 +150,13 @@ pub async fn sync(
         pool
     ).await?;
 
+    sync_character_wallets(
+        pool
+    ).await?;
+    sync_corporation_wallets(
+        pool
+    ).await?;
+
     Ok(())
 }
 
@@ -556,3 +584,25 @@ async fn sync_corporation_standings(
         )
         .await
 }
+
+async fn sync_character_wallets(
+    pool: &PgPool,
+) -> Result<usize> {
+    insert_task_with_credentials(
+            pool,
+            WorkerEveGatewayTask::CharacterWallet,
+            "esi-wallet.read_character_wallet.v1".into(),
+        )
+        .await
+}
+
+async fn sync_corporation_wallets(
+    pool: &PgPool,
+) -> Result<usize> {
+    insert_task_with_credentials(
+            pool,
+            WorkerEveGatewayTask::CorporationWallet,
+            "esi-wallet.read_corporation_wallets.v1".into(),
+        )
+        .await
+}
@@ -28,6 +28,10 @@ pub enum WorkerEveGatewayTask {
     CharacterStanding,
     CorporationStanding,
 
+    /// Fetches wallet journal and transactions for character and corporation
+    CharacterWallet,
+    CorporationWallet,
+
     // Skills
     // Industry Jobs Character
     // Industry Jobs Corporation
@@ -48,6 +52,8 @@ impl WorkerTask for WorkerEveGatewayTask {
             Self::AllianceStanding      => self.add_minutes(60),
             Self::CharacterStanding     => self.add_minutes(60),
             Self::CorporationStanding   => self.add_minutes(60),
+            Self::CharacterWallet       => self.add_minutes(60),
+            Self::CorporationWallet     => self.add_minutes(60),
         }
     }
 }
@@ -67,6 +73,8 @@ impl TryFrom<String> for WorkerEveGatewayTask {
             "ALLIANCE_STANDING"         => Ok(Self::AllianceStanding),
             "CHARACTER_STANDING"        => Ok(Self::CharacterStanding),
             "CORPORATION_STANDING"      => Ok(Self::CorporationStanding),
+            "CHARACTER_WALLET"          => Ok(Self::CharacterWallet),
+            "CORPORATION_WALLET"        => Ok(Self::CorporationWallet),
             _                           => Err("Invalid".into()),
         }
     }
@@ -85,6 +93,8 @@ impl From<WorkerEveGatewayTask> for String {
             WorkerEveGatewayTask::AllianceStanding      => "ALLIANCE_STANDING",
             WorkerEveGatewayTask::CharacterStanding     => "CHARACTER_STANDING",
             WorkerEveGatewayTask::CorporationStanding   => "CORPORATION_STANDING",
+            WorkerEveGatewayTask::CharacterWallet       => "CHARACTER_WALLET",
+            WorkerEveGatewayTask::CorporationWallet     => "CORPORATION_WALLET",
         }.into()
     }
 }
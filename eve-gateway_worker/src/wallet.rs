@@ -0,0 +1,6 @@
+mod character;
+mod corporation;
+mod insert;
+
+pub use self::character::*;
+pub use self::corporation::*;
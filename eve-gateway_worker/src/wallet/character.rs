@@ -0,0 +1,90 @@
+use serde::Deserialize;
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{EveGatewayApiClientWallet, EveGatewayClient};
+use starfoundry_lib_gateway::Identity;
+use starfoundry_lib_types::{CharacterId, CorporationId};
+use starfoundry_lib_worker::Task;
+
+use crate::error::{Error, Result};
+use crate::metric::WorkerMetric;
+use crate::SERVICE_NAME;
+use crate::tasks::WorkerEveGatewayTask;
+use crate::wallet::insert::{insert_journal, insert_transactions};
+
+/// Character wallets only have a single division
+const CHARACTER_DIVISION: i32 = 0;
+
+pub async fn character_wallet(
+    pool: &PgPool,
+    task: &mut Task<WorkerMetric, WorkerEveGatewayTask>,
+) -> Result<()> {
+    let additional_data: AdditionalData = match task.additional_data() {
+        Ok(Some(x)) => x,
+        Ok(None)    => {
+            tracing::error!("[{:?}] missing additional data", task.task);
+            task.append_error("Missing additional data");
+            return Err(Error::ParseAdditionalData)
+        },
+        Err(e)      => {
+            tracing::error!("[{:?}] error parsing additional data, {}", task.task, e);
+            task.append_error(format!("Missing additional data {}", e));
+            return Err(Error::ParseAdditionalData)
+        }
+    };
+
+    let identity = Identity::new(
+        additional_data.character_id,
+        additional_data.corporation_id,
+        additional_data.source,
+    );
+    let client = EveGatewayClient::new_with_identity(SERVICE_NAME, identity)?;
+
+    let journal = match client
+        .list_character_wallet_journal()
+        .await {
+
+        Ok(x) => x,
+        Err(e) => {
+            tracing::error!("Error while fetching character wallet journal, {:?}", e);
+            task.append_error(e.to_string());
+            return Err(e.into());
+        }
+    };
+    let transactions = match client
+        .list_character_wallet_transactions()
+        .await {
+
+        Ok(x) => x,
+        Err(e) => {
+            tracing::error!("Error while fetching character wallet transactions, {:?}", e);
+            task.append_error(e.to_string());
+            return Err(e.into());
+        }
+    };
+
+    insert_journal(
+            pool,
+            *additional_data.character_id,
+            CHARACTER_DIVISION,
+            journal,
+        )
+        .await
+        .inspect_err(|e| task.append_error(e.to_string()))?;
+    insert_transactions(
+            pool,
+            *additional_data.character_id,
+            CHARACTER_DIVISION,
+            transactions,
+        )
+        .await
+        .inspect_err(|e| task.append_error(e.to_string()))?;
+
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct AdditionalData {
+    source:         String,
+    character_id:   CharacterId,
+    corporation_id: CorporationId,
+}
@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{EveGatewayApiClientWallet, EveGatewayClient};
+use starfoundry_lib_gateway::Identity;
+use starfoundry_lib_types::{CharacterId, CorporationId};
+use starfoundry_lib_worker::Task;
+
+use crate::error::{Error, Result};
+use crate::metric::WorkerMetric;
+use crate::SERVICE_NAME;
+use crate::tasks::WorkerEveGatewayTask;
+use crate::wallet::insert::{insert_journal, insert_transactions};
+
+/// Corporations have the wallet divisions 1 to 7
+const CORPORATION_DIVISIONS: std::ops::RangeInclusive<i32> = 1..=7;
+
+pub async fn corporation_wallet(
+    pool: &PgPool,
+    task: &mut Task<WorkerMetric, WorkerEveGatewayTask>,
+) -> Result<()> {
+    let additional_data: AdditionalData = match task.additional_data() {
+        Ok(Some(x)) => x,
+        Ok(None)    => {
+            tracing::error!("[{:?}] missing additional data", task.task);
+            task.append_error("Missing additional data");
+            return Err(Error::ParseAdditionalData)
+        },
+        Err(e)      => {
+            tracing::error!("[{:?}] error parsing additional data, {}", task.task, e);
+            task.append_error(format!("Missing additional data {}", e));
+            return Err(Error::ParseAdditionalData)
+        }
+    };
+
+    let identity = Identity::new(
+        additional_data.character_id,
+        additional_data.corporation_id,
+        additional_data.source,
+    );
+    let client = EveGatewayClient::new_with_identity(SERVICE_NAME, identity)?;
+
+    for division in CORPORATION_DIVISIONS {
+        let journal = match client
+            .list_corporation_wallet_journal(division)
+            .await {
+
+            Ok(x) => x,
+            Err(e) => {
+                tracing::error!("Error while fetching corporation wallet journal, {:?}", e);
+                task.append_error(e.to_string());
+                return Err(e.into());
+            }
+        };
+        let transactions = match client
+            .list_corporation_wallet_transactions(division)
+            .await {
+
+            Ok(x) => x,
+            Err(e) => {
+                tracing::error!("Error while fetching corporation wallet transactions, {:?}", e);
+                task.append_error(e.to_string());
+                return Err(e.into());
+            }
+        };
+
+        insert_journal(
+                pool,
+                *additional_data.corporation_id,
+                division,
+                journal,
+            )
+            .await
+            .inspect_err(|e| task.append_error(e.to_string()))?;
+        insert_transactions(
+                pool,
+                *additional_data.corporation_id,
+                division,
+                transactions,
+            )
+            .await
+            .inspect_err(|e| task.append_error(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct AdditionalData {
+    source:         String,
+    character_id:   CharacterId,
+    corporation_id: CorporationId,
+}
@@ -0,0 +1,151 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{WalletJournal, WalletTransaction};
+
+use crate::error::{Error, Result};
+
+/// Inserts the journal entries of the given wallet, entries that are already
+/// known are skipped, as journal entries never change
+pub async fn insert_journal(
+    pool:     &PgPool,
+    owner_id: i32,
+    division: i32,
+    entries:  Vec<WalletJournal>,
+) -> Result<()> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    let mut journal_ids      = Vec::new();
+    let mut dates            = Vec::new();
+    let mut ref_types        = Vec::new();
+    let mut amounts          = Vec::new();
+    let mut context_ids      = Vec::new();
+    let mut context_id_types = Vec::new();
+
+    for entry in entries {
+        journal_ids.push(*entry.id);
+        dates.push(entry.date);
+        ref_types.push(entry.ref_type);
+        amounts.push(entry.amount.unwrap_or_default());
+        context_ids.push(entry.context_id);
+        context_id_types.push(entry.context_id_type);
+    }
+
+    sqlx::query!("
+            INSERT INTO wallet_journal
+            (
+                owner_id,
+                division,
+                journal_id,
+                date,
+                ref_type,
+                amount,
+                context_id,
+                context_id_type
+            )
+            SELECT $1, $2, * FROM UNNEST(
+                $3::BIGINT[],
+                $4::TIMESTAMPTZ[],
+                $5::VARCHAR[],
+                $6::DOUBLE PRECISION[],
+                $7::BIGINT[],
+                $8::VARCHAR[]
+            )
+            ON CONFLICT (owner_id, journal_id) DO NOTHING
+        ",
+            owner_id,
+            division,
+            &journal_ids,
+            &dates,
+            &ref_types,
+            &amounts,
+            &context_ids as _,
+            &context_id_types as _,
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| Error::InsertWallet(e, owner_id))?;
+
+    Ok(())
+}
+
+/// Inserts the transactions of the given wallet, transactions that are
+/// already known are skipped
+pub async fn insert_transactions(
+    pool:     &PgPool,
+    owner_id: i32,
+    division: i32,
+    entries:  Vec<WalletTransaction>,
+) -> Result<()> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    let mut transaction_ids = Vec::new();
+    let mut dates           = Vec::new();
+    let mut type_ids        = Vec::new();
+    let mut quantities      = Vec::new();
+    let mut unit_prices     = Vec::new();
+    let mut client_ids      = Vec::new();
+    let mut location_ids    = Vec::new();
+    let mut is_buys         = Vec::new();
+    let mut journal_ids     = Vec::new();
+
+    for entry in entries {
+        transaction_ids.push(*entry.transaction_id);
+        dates.push(entry.date);
+        type_ids.push(*entry.type_id);
+        quantities.push(entry.quantity);
+        unit_prices.push(entry.unit_price);
+        client_ids.push(entry.client_id);
+        location_ids.push(*entry.location_id);
+        is_buys.push(entry.is_buy);
+        journal_ids.push(*entry.journal_ref_id);
+    }
+
+    sqlx::query!("
+            INSERT INTO wallet_transaction
+            (
+                owner_id,
+                division,
+                transaction_id,
+                date,
+                type_id,
+                quantity,
+                unit_price,
+                client_id,
+                location_id,
+                is_buy,
+                journal_id
+            )
+            SELECT $1, $2, * FROM UNNEST(
+                $3::BIGINT[],
+                $4::TIMESTAMPTZ[],
+                $5::INTEGER[],
+                $6::INTEGER[],
+                $7::DOUBLE PRECISION[],
+                $8::INTEGER[],
+                $9::BIGINT[],
+                $10::BOOLEAN[],
+                $11::BIGINT[]
+            )
+            ON CONFLICT (owner_id, transaction_id) DO NOTHING
+        ",
+            owner_id,
+            division,
+            &transaction_ids,
+            &dates,
+            &type_ids,
+            &quantities,
+            &unit_prices,
+            &client_ids,
+            &location_ids,
+            &is_buys,
+            &journal_ids,
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| Error::InsertWallet(e, owner_id))?;
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
+mod api_token;
 mod character_info;
 mod extractor;
 mod jwk;
 mod jwt;
 
+pub use self::api_token::*;
 pub use self::character_info::*;
 pub use self::extractor::*;
 pub use self::jwk::*;
@@ -0,0 +1,121 @@
+use reqwest::header::HOST;
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_gateway::{StarFoundryApiClient, API_TOKEN_PREFIX};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::state::AppState;
+use crate::SERVICE_NAME;
+
+/// Access tokens for api tokens are valid for one minute, they are cached
+/// a bit shorter, so that they never expire while being used
+const CACHE_DURATION: Duration = Duration::from_secs(50);
+
+/// Caches the access tokens that were exchanged for api tokens, so that not
+/// every request needs to be exchanged
+#[derive(Clone, Default)]
+pub struct ApiTokenCache(Arc<Mutex<HashMap<CacheKey, CacheEntry>>>);
+
+/// Host and api token
+type CacheKey = (String, String);
+/// Access token and the time it was exchanged
+type CacheEntry = (String, Instant);
+
+impl ApiTokenCache {
+    fn get(
+        &self,
+        host:  &str,
+        token: &str,
+    ) -> Option<String> {
+        let cache = self.0.lock().ok()?;
+        cache
+            .get(&(host.into(), token.into()))
+            .filter(|(_, created_at)| created_at.elapsed() < CACHE_DURATION)
+            .map(|(access_token, _)| access_token.clone())
+    }
+
+    fn insert(
+        &self,
+        host:         &str,
+        token:        &str,
+        access_token: String,
+    ) {
+        if let Ok(mut cache) = self.0.lock() {
+            cache.retain(|_, (_, created_at)| created_at.elapsed() < CACHE_DURATION);
+            cache.insert((host.into(), token.into()), (access_token, Instant::now()));
+        }
+    }
+}
+
+/// Returns the access token that should be verified.
+///
+/// If the `AUTHORIZATION` header contains an api token, it is exchanged for an
+/// access token, otherwise the header is returned as is.
+///
+pub async fn resolve_access_token(
+    state:         &AppState,
+    host:          &str,
+    authorization: &str,
+) -> Result<String> {
+    let token = authorization
+        .strip_prefix("Bearer ")
+        .unwrap_or(authorization);
+
+    if !token.starts_with(API_TOKEN_PREFIX) {
+        return Ok(authorization.into());
+    }
+
+    if let Some(x) = state.api_tokens.get(host, token) {
+        return Ok(x);
+    }
+
+    let access_token = exchange_api_token(state, host, token).await?;
+    state.api_tokens.insert(host, token, access_token.clone());
+    Ok(access_token)
+}
+
+async fn exchange_api_token(
+    state: &AppState,
+    host:  &str,
+    token: &str,
+) -> Result<String> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        token: &'a str,
+    }
+
+    #[derive(Deserialize)]
+    struct Response {
+        access_token: String,
+    }
+
+    let mut url = if let Some(x) = state.routes.get("auth") {
+        x.service_url.clone()
+    } else {
+        return Err(Error::InvalidApiToken);
+    };
+    url.set_path("/auth/api-token");
+
+    let response = StarFoundryApiClient::new_raw(
+            SERVICE_NAME,
+        )?
+        .post(url)
+        .header(HOST, host)
+        .json(&Request {
+            token,
+        })
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(Error::InvalidApiToken);
+    }
+
+    response
+        .json::<Response>()
+        .await
+        .map(|x| x.access_token)
+        .map_err(Into::into)
+}
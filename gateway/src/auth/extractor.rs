@@ -1,18 +1,41 @@
 use axum::extract::{FromRef, FromRequestParts, OptionalFromRequestParts};
 use axum::http::header::AUTHORIZATION;
 use axum::http::request::Parts;
-use axum::http::StatusCode;
+use axum::http::{Method, StatusCode};
 use axum::Json;
 use serde_json::json;
 use reqwest::header::HOST;
+use starfoundry_lib_gateway::ApiTokenScope;
 
-use crate::auth::{verify, CharacterInfo};
+use crate::auth::{resolve_access_token, verify, CharacterInfo};
 use crate::state::AppState;
 
 #[derive(Debug)]
 pub struct ExtractIdentity {
     pub character_info: CharacterInfo,
     pub is_admin:       bool,
+    /// Only set if a personal api token was used
+    pub scopes:         Option<Vec<ApiTokenScope>>,
+}
+
+impl ExtractIdentity {
+    /// Checks if the identity is allowed to access the given route.
+    /// Logged in characters can access all routes, api tokens only the routes
+    /// in their scopes.
+    ///
+    pub fn is_allowed(
+        &self,
+        route:  &str,
+        method: &Method,
+    ) -> bool {
+        if let Some(scopes) = &self.scopes {
+            scopes
+                .iter()
+                .any(|x| x.allows(route, method))
+        } else {
+            true
+        }
+    }
 }
 
 impl<S> FromRequestParts<S> for ExtractIdentity
@@ -42,9 +65,26 @@ where
             let authorization = authorization
                 .to_str()
                 .unwrap_or_default();
+            let authorization = match resolve_access_token(
+                &state,
+                host,
+                authorization,
+            ).await {
+                Ok(x) => x,
+                Err(e) => {
+                    tracing::error!("could not resolve {AUTHORIZATION} header, error: {e}");
+                    return Err((
+                        StatusCode::UNAUTHORIZED,
+                        Json(json!({
+                            "error": "UNAUTHORIZED",
+                            "description": "Authenticate and try again"
+                        }))
+                    ))
+                }
+            };
 
             let verify = verify(
-                authorization,
+                &authorization,
                 host,
                 state.decoding_key,
             );
@@ -66,6 +106,7 @@ where
             Ok(ExtractIdentity {
                 character_info: token.claims.character_info,
                 is_admin:       token.claims.is_admin,
+                scopes:         token.claims.scopes,
             })
         } else {
             Err((
@@ -106,9 +147,26 @@ where
             let authorization = authorization
                 .to_str()
                 .unwrap_or_default();
+            let authorization = match resolve_access_token(
+                &state,
+                host,
+                authorization,
+            ).await {
+                Ok(x) => x,
+                Err(e) => {
+                    tracing::error!("could not resolve {AUTHORIZATION} header, error: {e}");
+                    return Err((
+                        StatusCode::UNAUTHORIZED,
+                        Json(json!({
+                            "error": "UNAUTHORIZED",
+                            "description": "Authenticate and try again"
+                        }))
+                    ))
+                }
+            };
 
             let verify = verify(
-                authorization,
+                &authorization,
                 host,
                 state.decoding_key,
             );
@@ -130,6 +188,7 @@ where
             Ok(Some(ExtractIdentity {
                 character_info: token.claims.character_info,
                 is_admin:       token.claims.is_admin,
+                scopes:         token.claims.scopes,
             }))
         } else {
             Ok(None)
@@ -1,5 +1,6 @@
 use jsonwebtoken::{Algorithm, DecodingKey, TokenData, Validation};
 use serde::Deserialize;
+use starfoundry_lib_gateway::ApiTokenScope;
 use std::sync::Arc;
 
 use crate::error::{Error, Result};
@@ -46,4 +47,7 @@ pub struct Claims {
     pub is_admin:       bool,
     /// General character information
     pub character_info: CharacterInfo,
+    /// Only set if the token was exchanged for a personal api token
+    #[serde(default)]
+    pub scopes:         Option<Vec<ApiTokenScope>>,
 }
@@ -1,5 +1,5 @@
 use axum::extract::{Query, State};
-use axum::http::HeaderMap;
+use axum::http::{HeaderMap, Method};
 use axum::response::IntoResponse;
 use reqwest::header::HOST;
 use reqwest::StatusCode;
@@ -31,6 +31,12 @@ pub async fn catch_all_auth_login_corporation(
         )
     };
 
+    if !identity.is_allowed("auth", &Method::GET) {
+        return Ok((
+            StatusCode::FORBIDDEN,
+        ).into_response())
+    }
+
     if let Some(x) = state.routes.get("auth") {
         let mut url = x.service_url.clone();
         url.set_path("/auth/login/corporation");
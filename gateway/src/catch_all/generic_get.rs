@@ -1,5 +1,5 @@
 use axum::extract::{Path, Query, State};
-use axum::http::HeaderMap;
+use axum::http::{HeaderMap, Method};
 use axum::response::IntoResponse;
 use reqwest::header::HOST;
 use reqwest::StatusCode;
@@ -58,6 +58,12 @@ pub async fn catch_all_generic_get(
         let mut headers = HeaderMap::new();
         if x.require_auth {
             if let Some(identity) = identity {
+                if !identity.is_allowed(path_front, &Method::GET) {
+                    return Ok((
+                        StatusCode::FORBIDDEN,
+                    ).into_response())
+                }
+
                 add_headers(
                     &mut headers,
                     host.clone(),
@@ -22,6 +22,8 @@ pub enum Error {
     InvalidAccessToken(jsonwebtoken::errors::Error),
     #[error("no es256 key")]
     NoEs256Key,
+    #[error("the api token could not be exchanged")]
+    InvalidApiToken,
 
     #[error(transparent)]
     StarFoundryGatewayError(#[from] starfoundry_lib_gateway::Error),
@@ -16,7 +16,7 @@ use tower_http::decompression::RequestDecompressionLayer;
 use tower::ServiceBuilder;
 use tracing_subscriber::EnvFilter;
 
-use crate::auth::load_signature;
+use crate::auth::{load_signature, ApiTokenCache};
 use crate::catch_all::*;
 use crate::config::Config;
 use crate::metrics::{Metric, path_metrics};
@@ -53,6 +53,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         metric: Arc::new(metric),
 
         decoding_key,
+        api_tokens: ApiTokenCache::default(),
     };
 
     tracing::info!("Starting app server on {}", config.app_address.local_addr().unwrap());
@@ -5,6 +5,7 @@ use std::collections::HashMap;
 use std::convert::Infallible;
 use std::sync::Arc;
 
+use crate::auth::ApiTokenCache;
 use crate::config::ConfigFileRoute;
 use crate::metrics::Metric;
 
@@ -18,6 +19,8 @@ pub struct AppState {
 
     /// JWT Decoding key loaded from the eve-gateway
     pub decoding_key: Arc<DecodingKey>,
+    /// Access tokens that were exchanged for api tokens
+    pub api_tokens:   ApiTokenCache,
 }
 
 impl<S> FromRequestParts<S> for AppState
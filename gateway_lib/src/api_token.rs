@@ -0,0 +1,146 @@
+use axum::http::Method;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Prefix of all personal api tokens, used to distinguish them from the JWTs
+/// issued after a login
+pub const API_TOKEN_PREFIX: &str = "sfp_";
+
+/// Routes that can never be accessed using an api token, otherwise a token
+/// could be used to create tokens with more scopes or to change the access
+/// to the domain
+pub const API_TOKEN_RESTRICTED_ROUTES: &[&str] = &[
+    "access-rules",
+    "api-tokens",
+    "auth",
+];
+
+/// Scope of a personal api token.
+///
+/// Scopes are written as `<route>:<access>`, for example `projects:read`.
+/// The route is the first segment of the path the gateway exposes.
+///
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ApiTokenScope {
+    route:  String,
+    access: ApiTokenAccess,
+}
+
+impl ApiTokenScope {
+    /// Checks if the scope allows the given method on the given route.
+    /// [ApiTokenAccess::Write] includes [ApiTokenAccess::Read].
+    ///
+    #[must_use]
+    pub fn allows(
+        &self,
+        route:  &str,
+        method: &Method,
+    ) -> bool {
+        if self.route != route {
+            return false;
+        }
+
+        if method == Method::GET || method == Method::HEAD {
+            true
+        } else {
+            self.access == ApiTokenAccess::Write
+        }
+    }
+
+    #[must_use]
+    pub fn route(&self) -> &str {
+        &self.route
+    }
+
+    #[must_use]
+    pub fn access(&self) -> ApiTokenAccess {
+        self.access
+    }
+}
+
+impl TryFrom<String> for ApiTokenScope {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let (route, access) = if let Some(x) = value.split_once(':') {
+            x
+        } else {
+            return Err(format!("scope '{value}' must be in the format '<route>:<access>'"));
+        };
+
+        let access = match access {
+            "read"  => ApiTokenAccess::Read,
+            "write" => ApiTokenAccess::Write,
+            _       => return Err(format!("access of scope '{value}' must be either 'read' or 'write'")),
+        };
+
+        let is_valid_route = !route.is_empty() && route
+            .chars()
+            .all(|x| x.is_ascii_lowercase() || x == '-');
+        if !is_valid_route {
+            return Err(format!("route of scope '{value}' is not valid"));
+        }
+        if API_TOKEN_RESTRICTED_ROUTES.contains(&route) {
+            return Err(format!("route '{route}' cannot be accessed with an api token"));
+        }
+
+        Ok(Self {
+            route: route.into(),
+            access,
+        })
+    }
+}
+
+impl From<ApiTokenScope> for String {
+    fn from(value: ApiTokenScope) -> Self {
+        value.to_string()
+    }
+}
+
+impl fmt::Display for ApiTokenScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let access = match self.access {
+            ApiTokenAccess::Read  => "read",
+            ApiTokenAccess::Write => "write",
+        };
+        write!(f, "{}:{}", self.route, access)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ApiTokenAccess {
+    /// Only `GET` requests are allowed
+    Read,
+    /// All requests are allowed
+    Write,
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::Method;
+
+    use super::ApiTokenScope;
+
+    #[test]
+    fn parse_and_allow() {
+        let scope = ApiTokenScope::try_from(String::from("projects:read")).unwrap();
+        assert!(scope.allows("projects", &Method::GET));
+        assert!(!scope.allows("projects", &Method::PUT));
+        assert!(!scope.allows("structures", &Method::GET));
+        assert_eq!(scope.to_string(), "projects:read");
+
+        let scope = ApiTokenScope::try_from(String::from("projects:write")).unwrap();
+        assert!(scope.allows("projects", &Method::GET));
+        assert!(scope.allows("projects", &Method::DELETE));
+    }
+
+    #[test]
+    fn invalid_scopes() {
+        assert!(ApiTokenScope::try_from(String::from("projects")).is_err());
+        assert!(ApiTokenScope::try_from(String::from("projects:admin")).is_err());
+        assert!(ApiTokenScope::try_from(String::from("Projects:read")).is_err());
+        assert!(ApiTokenScope::try_from(String::from("auth:write")).is_err());
+        assert!(ApiTokenScope::try_from(String::from("api-tokens:read")).is_err());
+    }
+}
@@ -2,11 +2,13 @@
 
 mod auth;
 mod api_client;
+mod api_token;
 mod identity;
 
 pub use self::auth::*;
 pub use self::error::*;
 pub use self::api_client::*;
+pub use self::api_token::*;
 pub use self::identity::*;
 
 pub mod error;
@@ -82,6 +82,17 @@ pub enum EveApiError {
 
     #[error("error while parsing eve response data, url: '{2}', text: '{1}', error: '{0:?}'")]
     JsonParseError(serde_json::Error, String, Url),
+
+    #[error("unknown fixture mode '{0}', expected 'live', 'record' or 'replay'")]
+    InvalidFixtureMode(String),
+    #[error("no fixture recorded for '{0} {1}'")]
+    FixtureNotFound(String, Url),
+    #[error("the fixture for '{1}' is not a valid response, error: '{0}'")]
+    InvalidFixture(String, Url),
+    #[error("error while accessing fixture '{1:?}', error: '{0}'")]
+    FixtureIo(std::io::Error, std::path::PathBuf),
+    #[error("error while (de)serializing fixture '{1:?}', error: '{0}'")]
+    FixtureParse(serde_json::Error, std::path::PathBuf),
 }
 
 impl IntoResponse for EveApiError {
@@ -99,6 +110,7 @@ impl IntoResponse for EveApiError {
                     )
                 ).into_response()
             },
+            Self::FixtureNotFound(_, _) |
             Self::NotFound(_) => {
                 tracing::warn!("{}", self.to_string());
                 (
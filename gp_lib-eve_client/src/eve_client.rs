@@ -1,4 +1,4 @@
-use reqwest::{Client, Response, StatusCode};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
 use reqwest::header::{HeaderMap, HeaderValue};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
@@ -9,7 +9,7 @@ use std::ops::Deref;
 use std::sync::{Arc, Mutex};
 use url::Url;
 
-use crate::{EveApiError, EveJwtToken, EveApiClientMetric, Result};
+use crate::{EveApiError, EveFixtureMode, EveJwtToken, EveApiClientMetric, Result};
 
 /// Required by the EVE-Api
 const COMPATIBILITY_DATE_HEADER: &str       = "X-Compatibility-Date";
@@ -46,6 +46,12 @@ pub const ENV_CALLBACK: &str                = "STARFOUNDRY_EVE_GATEWAY_EVE_CALLB
 /// * `ÈVE_CLIENT_ID`  -> Client ID of the application
 /// * `EVE_SECRET_KEY` -> Secret key of the application
 /// 
+/// # Fixtures
+/// 
+/// Using the ENV [crate::ENV_FIXTURE_MODE] the client can record all responses
+/// it receives, or replay them without ever contacting the EVE-API.
+/// See [EveFixtureMode] for more information.
+/// 
 #[derive(Clone)]
 pub struct EveApiClient {
     /// Determines if the client is an authenticated client
@@ -58,6 +64,9 @@ pub struct EveApiClient {
     access_token:       Arc<Mutex<Option<String>>>,
 
     metric:             Arc<EveApiClientMetric>,
+
+    /// Determines if requests are sent, recorded or replayed
+    fixture:            EveFixtureMode,
 }
 
 impl EveApiClient {
@@ -71,20 +80,23 @@ impl EveApiClient {
     /// 
     /// - If the reqwest Client cannot be constructed.
     /// - If the ENV '[ENV_CLIENT_ID]' is not set
+    /// - If the fixture ENVs are not valid
     /// 
     pub fn new(
         // TODO: remove them and put them into the proxy function
         metric: Arc<EveApiClientMetric>,
     ) -> Result<Self> {
         let client = Self::client()?;
+        let fixture = EveFixtureMode::from_env()?;
 
         Ok(Self {
             client:        client,
-            access_token:  Arc::new(Mutex::new(None)),
+            access_token:  Arc::new(Mutex::new(Self::initial_access_token(&fixture))),
 
             authenticated: None,
 
             metric:        metric,
+            fixture:       fixture,
         })
     }
 
@@ -95,6 +107,7 @@ impl EveApiClient {
     /// # Errors
     /// 
     /// - If the reqwest client cannot be constructed
+    /// - If the fixture ENVs are not valid
     /// 
     pub fn new_with_refresh_token(
         metric:        Arc<EveApiClientMetric>,
@@ -102,11 +115,12 @@ impl EveApiClient {
         refresh_token: impl Into<String>,
     ) -> Result<Self> {
         let client = Self::client()?;
+        let fixture = EveFixtureMode::from_env()?;
 
         Ok(Self {
             client:         client,
 
-            access_token:   Arc::new(Mutex::new(None)),
+            access_token:   Arc::new(Mutex::new(Self::initial_access_token(&fixture))),
 
             authenticated:  Some(AuthenticatedClient {
                 refresh_token: refresh_token.into(),
@@ -114,6 +128,7 @@ impl EveApiClient {
             }),
 
             metric:         metric,
+            fixture:        fixture,
         })
     }
 
@@ -508,13 +523,13 @@ impl EveApiClient {
                 ));
             }
 
-            let response = self
+            let request = self
                 .client
                 .get(request_uri.clone())
-                .query(query)
-                .send()
-                .await
-                .map_err(|x| EveApiError::ReqwestError(x, request_uri.clone()))?;
+                .query(query);
+            let response = self
+                .execute(request, &request_uri, None)
+                .await?;
 
             if response.headers().get("X-Ratelimit-Group").is_some() {
                 let group = response.headers().get("X-Ratelimit-Group").unwrap().to_str().unwrap();
@@ -676,14 +691,14 @@ impl EveApiClient {
             let token = access_token
                 .as_ref()
                 .expect("We check but somehow the access_token is still None");
-            let response = self
+            let request = self
                 .client
                 .get(request_uri.clone())
                 .query(query)
-                .bearer_auth(token)
-                .send()
-                .await
-                .map_err(|x| EveApiError::ReqwestError(x, request_uri.clone()))?;
+                .bearer_auth(token);
+            let response = self
+                .execute(request, &request_uri, self.character_id())
+                .await?;
 
             if response.headers().get("X-Ratelimit-Group").is_some() {
                 let group = response.headers().get("X-Ratelimit-Group").unwrap().to_str().unwrap();
@@ -839,14 +854,14 @@ impl EveApiClient {
             let token = access_token
                 .as_ref()
                 .expect("We check but somehow the access_token is still None");
-            let response = self
+            let request = self
                 .client
                 .post(request_uri.clone())
                 .json(&data)
-                .bearer_auth(token)
-                .send()
-                .await
-                .map_err(|x| EveApiError::ReqwestError(x, request_uri.clone()))?;
+                .bearer_auth(token);
+            let response = self
+                .execute(request, &request_uri, self.character_id())
+                .await?;
 
             self
                 .metric
@@ -960,13 +975,13 @@ impl EveApiClient {
             let token = access_token
                 .as_ref()
                 .expect("We check but somehow the access_token is still None");
-            let response = self
+            let request = self
                 .client
                 .delete(request_uri.clone())
-                .bearer_auth(token)
-                .send()
-                .await
-                .map_err(|x| EveApiError::ReqwestError(x, request_uri.clone()))?;
+                .bearer_auth(token);
+            let response = self
+                .execute(request, &request_uri, self.character_id())
+                .await?;
 
             self
                 .metric
@@ -1024,6 +1039,74 @@ impl EveApiClient {
         }
     }
 
+    /// Executes the request depending on the configured [EveFixtureMode].
+    ///
+    /// In replay mode the request never leaves the application, the response
+    /// is read from the fixture store instead.
+    ///
+    /// # Errors
+    ///
+    /// - If the request cannot be constructed or sent
+    /// - If the fixture cannot be read or written
+    ///
+    async fn execute(
+        &self,
+        request:      RequestBuilder,
+        request_uri:  &Url,
+        character_id: Option<CharacterId>,
+    ) -> Result<Response> {
+        let request = request
+            .build()
+            .map_err(|x| EveApiError::ReqwestError(x, request_uri.clone()))?;
+
+        match &self.fixture {
+            EveFixtureMode::Live => {
+                self
+                    .client
+                    .execute(request)
+                    .await
+                    .map_err(|x| EveApiError::ReqwestError(x, request_uri.clone()))
+            },
+            EveFixtureMode::Record(store) => {
+                let method = request.method().clone();
+                let url = request.url().clone();
+
+                let response = self
+                    .client
+                    .execute(request)
+                    .await
+                    .map_err(|x| EveApiError::ReqwestError(x, request_uri.clone()))?;
+
+                store
+                    .record(&method, &url, character_id, response)
+                    .await
+            },
+            EveFixtureMode::Replay(store) => {
+                store.replay(&request, character_id)
+            },
+        }
+    }
+
+    /// [CharacterId] of the authenticated client, if there is one
+    fn character_id(&self) -> Option<CharacterId> {
+        self
+            .authenticated
+            .as_ref()
+            .map(|x| x.character_id)
+    }
+
+    /// While replaying fixtures no token is ever fetched from the EVE SSO,
+    /// a placeholder is used instead
+    fn initial_access_token(
+        fixture: &EveFixtureMode,
+    ) -> Option<String> {
+        if fixture.is_replay() {
+            Some("fixture".into())
+        } else {
+            None
+        }
+    }
+
     /// Makes a request to the token interface and sets necessary headers to
     /// retrieve a new `access_token`.
     ///
@@ -1140,10 +1223,14 @@ impl EveApiClient {
             COMPATIBILITY_DATE_VALUE,
         );
 
+        // plain http is only allowed if the API url is explicitly configured
+        // that way, for example when pointing at a local eve-api_stub
+        let https_only = Self::api_url()?.scheme() != "http";
+
         Client::builder()
             .user_agent(Self::user_agent())
             .default_headers(headers)
-            .https_only(true)
+            .https_only(https_only)
             .build()
             .map_err(EveApiError::CouldNotConstructClient)
     }
@@ -0,0 +1,494 @@
+use reqwest::{Method, Request, Response, StatusCode};
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::CharacterId;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use url::Url;
+
+use crate::{EveApiError, Result};
+
+/// Selects if the client should talk to the EVE-API, record the responses it
+/// gets or replay previously recorded responses.
+/// Possible values: `live` (default), `record`, `replay`
+pub const ENV_FIXTURE_MODE: &str = "STARFOUNDRY_EVE_GATEWAY_EVE_FIXTURE_MODE";
+/// Directory the fixtures are written to and read from, required if the mode
+/// is either `record` or `replay`
+pub const ENV_FIXTURE_DIR: &str  = "STARFOUNDRY_EVE_GATEWAY_EVE_FIXTURE_DIR";
+
+/// Headers that are stored together with a fixture, all others are dropped
+const RECORDED_HEADERS: [&str; 5] = [
+    "content-type",
+    "etag",
+    "expires",
+    "last-modified",
+    "x-pages",
+];
+
+/// Transport mode of the [crate::EveApiClient].
+///
+#[derive(Clone, Debug, Default)]
+pub enum EveFixtureMode {
+    /// All requests are sent to the EVE-API
+    #[default]
+    Live,
+    /// All requests are sent to the EVE-API and the responses are written
+    /// into the given store
+    Record(EveFixtureStore),
+    /// No request leaves the application, all responses are read from the
+    /// given store
+    Replay(EveFixtureStore),
+}
+
+impl EveFixtureMode {
+    /// Reads the mode from the environment.
+    ///
+    /// # Errors
+    ///
+    /// - If the ENV '[ENV_FIXTURE_MODE]' has an unknown value
+    /// - If the mode requires a store and '[ENV_FIXTURE_DIR]' is not set
+    ///
+    pub fn from_env() -> Result<Self> {
+        let mode = std::env::var(ENV_FIXTURE_MODE)
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match mode.as_str() {
+            "" | "live" => Ok(Self::Live),
+            "record"    => Ok(Self::Record(EveFixtureStore::from_env()?)),
+            "replay"    => Ok(Self::Replay(EveFixtureStore::from_env()?)),
+            _           => Err(EveApiError::InvalidFixtureMode(mode)),
+        }
+    }
+
+    #[must_use]
+    pub fn is_replay(&self) -> bool {
+        matches!(self, Self::Replay(_))
+    }
+}
+
+/// A single recorded response of the EVE-API
+///
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EveFixture {
+    pub method:       String,
+    pub path:         String,
+    pub query:        String,
+    pub character_id: Option<CharacterId>,
+
+    pub status:       u16,
+    #[serde(default)]
+    pub headers:      BTreeMap<String, String>,
+    /// If the EVE-API returned valid json it is stored as json, everything
+    /// else is stored as string
+    #[serde(default)]
+    pub body:         serde_json::Value,
+}
+
+impl EveFixture {
+    /// Serialized body as it was returned by the EVE-API
+    ///
+    #[must_use]
+    pub fn body(&self) -> String {
+        match &self.body {
+            serde_json::Value::Null      => String::new(),
+            serde_json::Value::String(x) => x.clone(),
+            x @ serde_json::Value::Bool(_)   |
+            x @ serde_json::Value::Number(_) |
+            x @ serde_json::Value::Array(_)  |
+            x @ serde_json::Value::Object(_) => x.to_string(),
+        }
+    }
+
+    /// Converts the fixture into a response, as if it was returned by the
+    /// EVE-API
+    ///
+    /// # Errors
+    ///
+    /// - If the stored status or headers are not valid
+    ///
+    pub fn into_response(
+        self,
+        url: &Url,
+    ) -> Result<Response> {
+        let mut builder = http::Response::builder()
+            .status(self.status);
+
+        for (key, value) in self.headers.iter() {
+            builder = builder.header(key, value);
+        }
+
+        builder
+            .body(self.body())
+            .map(Response::from)
+            .map_err(|e| EveApiError::InvalidFixture(e.to_string(), url.clone()))
+    }
+}
+
+/// Directory based storage of [EveFixture]s.
+///
+/// Every fixture is stored in its own file, the layout is
+/// `{dir}/{method}/{path}/{query}[@{character_id}].json`, with the query
+/// parameters sorted by their name. An empty query is stored as `_`.
+///
+/// Example: `fixtures/get/latest/characters/2117441999/assets/page=1@2117441999.json`
+///
+#[derive(Clone, Debug)]
+pub struct EveFixtureStore {
+    dir: PathBuf,
+}
+
+impl EveFixtureStore {
+    #[must_use]
+    pub fn new(
+        dir: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            dir: dir.into(),
+        }
+    }
+
+    /// Creates a new store from the ENV '[ENV_FIXTURE_DIR]'
+    ///
+    /// # Errors
+    ///
+    /// - If the ENV '[ENV_FIXTURE_DIR]' is not set
+    ///
+    pub fn from_env() -> Result<Self> {
+        std::env::var(ENV_FIXTURE_DIR)
+            .map(Self::new)
+            .map_err(|_| EveApiError::EnvNotSet(ENV_FIXTURE_DIR))
+    }
+
+    /// Looks up the fixture for the given request.
+    ///
+    /// If no fixture with the exact [CharacterId] exists, and no
+    /// [CharacterId] is given, the first fixture for the same method, path and
+    /// query is returned. This allows serving authenticated fixtures without
+    /// knowing the character behind a token.
+    ///
+    /// # Errors
+    ///
+    /// - If the fixture file cannot be read or parsed
+    ///
+    pub fn find(
+        &self,
+        method:       &Method,
+        url:          &Url,
+        character_id: Option<CharacterId>,
+    ) -> Result<Option<EveFixture>> {
+        let file = self.file(method, url, character_id);
+        if file.exists() {
+            return self.read(file).map(Some);
+        }
+
+        if character_id.is_some() {
+            return Ok(None);
+        }
+
+        let prefix = format!("{}@", Self::query_key(url));
+        let directory = self.directory(method, url);
+        let entries = match std::fs::read_dir(&directory) {
+            Ok(x) => x,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(EveApiError::FixtureIo(e, directory)),
+        };
+
+        let mut candidates = entries
+            .filter_map(|x| x.ok())
+            .map(|x| x.path())
+            .filter(|x| {
+                x.file_name()
+                    .and_then(|x| x.to_str())
+                    .map(|x| x.starts_with(&prefix) && x.ends_with(".json"))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        candidates.sort();
+
+        if let Some(file) = candidates.into_iter().next() {
+            self.read(file).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Replays the stored response for the given request.
+    ///
+    /// # Errors
+    ///
+    /// - If there is no fixture for the request
+    /// - If the fixture file cannot be read or parsed
+    ///
+    pub fn replay(
+        &self,
+        request:      &Request,
+        character_id: Option<CharacterId>,
+    ) -> Result<Response> {
+        let url = request.url();
+
+        if let Some(x) = self.find(request.method(), url, character_id)? {
+            x.into_response(url)
+        } else {
+            tracing::warn!(
+                {
+                    method = request.method().as_str(),
+                    uri = url.as_str(),
+                    file = self.file(request.method(), url, character_id).to_str(),
+                },
+                "No fixture recorded for request",
+            );
+            Err(EveApiError::FixtureNotFound(request.method().to_string(), url.clone()))
+        }
+    }
+
+    /// Writes the response into the store, and returns a new response with
+    /// the same content.
+    ///
+    /// # Errors
+    ///
+    /// - If the body of the response cannot be read
+    /// - If the fixture cannot be written
+    ///
+    pub async fn record(
+        &self,
+        method:       &Method,
+        url:          &Url,
+        character_id: Option<CharacterId>,
+        response:     Response,
+    ) -> Result<Response> {
+        let status = response.status();
+        let headers = Self::recorded_headers(response.headers());
+        let body = response
+            .text()
+            .await
+            .map_err(|e| EveApiError::ReqwestError(e, url.clone()))?;
+
+        let fixture = EveFixture {
+            method:       method.to_string(),
+            path:         url.path().into(),
+            query:        Self::query(url),
+            character_id: character_id,
+
+            status:       status.as_u16(),
+            headers:      headers,
+            body:         serde_json::from_str(&body)
+                            .unwrap_or(serde_json::Value::String(body)),
+        };
+
+        // a not modified response has no body, the previous recording is
+        // more useful
+        if status != StatusCode::NOT_MODIFIED {
+            self.write(method, url, character_id, &fixture)?;
+        }
+
+        fixture.into_response(url)
+    }
+
+    fn read(
+        &self,
+        file: PathBuf,
+    ) -> Result<EveFixture> {
+        let content = std::fs::read_to_string(&file)
+            .map_err(|e| EveApiError::FixtureIo(e, file.clone()))?;
+
+        serde_json::from_str(&content)
+            .map_err(|e| EveApiError::FixtureParse(e, file))
+    }
+
+    fn write(
+        &self,
+        method:       &Method,
+        url:          &Url,
+        character_id: Option<CharacterId>,
+        fixture:      &EveFixture,
+    ) -> Result<()> {
+        let directory = self.directory(method, url);
+        std::fs::create_dir_all(&directory)
+            .map_err(|e| EveApiError::FixtureIo(e, directory))?;
+
+        let file = self.file(method, url, character_id);
+        let content = serde_json::to_string_pretty(fixture)
+            .map_err(|e| EveApiError::FixtureParse(e, file.clone()))?;
+
+        std::fs::write(&file, content)
+            .map_err(|e| EveApiError::FixtureIo(e, file))
+    }
+
+    fn directory(
+        &self,
+        method: &Method,
+        url:    &Url,
+    ) -> PathBuf {
+        let mut directory = self.dir.join(method.as_str().to_lowercase());
+
+        url
+            .path_segments()
+            .into_iter()
+            .flatten()
+            .filter(|x| !x.is_empty() && *x != "." && *x != "..")
+            .map(Self::sanitize)
+            .for_each(|x| directory.push(x));
+
+        directory
+    }
+
+    fn file(
+        &self,
+        method:       &Method,
+        url:          &Url,
+        character_id: Option<CharacterId>,
+    ) -> PathBuf {
+        let file = if let Some(x) = character_id {
+            format!("{}@{}.json", Self::query_key(url), *x)
+        } else {
+            format!("{}.json", Self::query_key(url))
+        };
+
+        self.directory(method, url).join(file)
+    }
+
+    /// Query of the url, with all parameters sorted by their name
+    fn query(
+        url: &Url,
+    ) -> String {
+        let mut pairs = url
+            .query_pairs()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>();
+        pairs.sort();
+        pairs.join("&")
+    }
+
+    fn query_key(
+        url: &Url,
+    ) -> String {
+        let query = Self::query(url);
+
+        if query.is_empty() {
+            "_".into()
+        } else {
+            Self::sanitize(&query)
+        }
+    }
+
+    fn sanitize(
+        value: &str,
+    ) -> String {
+        value
+            .chars()
+            .map(|x| {
+                if x.is_ascii_alphanumeric() || matches!(x, '-' | '_' | '.' | '=' | '&' | ',') {
+                    x
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    fn recorded_headers(
+        headers: &HeaderMap,
+    ) -> BTreeMap<String, String> {
+        RECORDED_HEADERS
+            .iter()
+            .filter_map(|x| {
+                headers
+                    .get(*x)
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| (x.to_string(), value.to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod fixture_test {
+    use reqwest::{Method, StatusCode};
+    use starfoundry_lib_types::CharacterId;
+    use url::Url;
+
+    use super::EveFixtureStore;
+
+    fn store(name: &str) -> EveFixtureStore {
+        let dir = std::env::temp_dir()
+            .join(format!("starfoundry_fixture_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        EveFixtureStore::new(dir)
+    }
+
+    fn response(
+        status:  u16,
+        pages:   &str,
+        body:    &str,
+    ) -> reqwest::Response {
+        http::Response::builder()
+            .status(status)
+            .header("x-pages", pages)
+            .header("x-ratelimit-remaining", "10")
+            .body(body.to_string())
+            .unwrap()
+            .into()
+    }
+
+    #[tokio::test]
+    async fn record_and_replay() {
+        let store = store("record_and_replay");
+        let url = Url::parse("https://esi.evetech.net/latest/characters/1/assets?page=2&datasource=tranquility").unwrap();
+
+        let recorded = store
+            .record(&Method::GET, &url, Some(CharacterId(1)), response(200, "2", r#"[{"item_id":1}]"#))
+            .await
+            .unwrap();
+        assert_eq!(recorded.status(), StatusCode::OK);
+        assert_eq!(recorded.text().await.unwrap(), r#"[{"item_id":1}]"#);
+
+        // the host and the order of the query parameters are not relevant
+        let url = Url::parse("http://localhost:9992/latest/characters/1/assets?datasource=tranquility&page=2").unwrap();
+        let fixture = store
+            .find(&Method::GET, &url, Some(CharacterId(1)))
+            .unwrap()
+            .unwrap();
+        assert_eq!(fixture.query, "datasource=tranquility&page=2");
+        assert_eq!(fixture.headers.get("x-pages").unwrap(), "2");
+        assert!(!fixture.headers.contains_key("x-ratelimit-remaining"));
+
+        let replayed = fixture.into_response(&url).unwrap();
+        assert_eq!(replayed.headers().get("x-pages").unwrap(), "2");
+        assert_eq!(replayed.text().await.unwrap(), r#"[{"item_id":1}]"#);
+    }
+
+    #[tokio::test]
+    async fn find_by_character() {
+        let store = store("find_by_character");
+        let url = Url::parse("https://esi.evetech.net/latest/characters/1/blueprints").unwrap();
+
+        store
+            .record(&Method::GET, &url, Some(CharacterId(1)), response(200, "1", "[]"))
+            .await
+            .unwrap();
+
+        assert!(store.find(&Method::GET, &url, Some(CharacterId(2))).unwrap().is_none());
+        assert!(store.find(&Method::POST, &url, None).unwrap().is_none());
+        // without a character any recording for the request is used
+        assert!(store.find(&Method::GET, &url, None).unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn non_json_body() {
+        let store = store("non_json_body");
+        let url = Url::parse("https://esi.evetech.net/latest/status").unwrap();
+
+        store
+            .record(&Method::GET, &url, None, response(502, "1", "Bad Gateway"))
+            .await
+            .unwrap();
+
+        let fixture = store
+            .find(&Method::GET, &url, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(fixture.status, 502);
+        assert_eq!(fixture.body(), "Bad Gateway");
+    }
+}
@@ -4,6 +4,7 @@ mod character;
 mod corporation;
 mod error;
 mod eve_client;
+mod fixture;
 mod metric;
 mod jwt_key;
 mod jwt;
@@ -13,5 +14,6 @@ pub use self::character::*;
 pub use self::corporation::*;
 pub use self::error::*;
 pub use self::eve_client::*;
+pub use self::fixture::*;
 pub use self::metric::*;
 pub use self::jwt::*;
@@ -12,6 +12,7 @@ eve_id!(FittingId, "FittingId", i64, u64);
 eve_id!(GroupId, "GroupId", i32, u32);
 eve_id!(ItemId, "ItemId", i64, u64);
 eve_id!(JobId, "JobId", i32, u32);
+eve_id!(JournalId, "JournalId", i64, u64);
 eve_id!(LocationId, "LocationId", i64, u64);
 eve_id!(OrbitId, "OrbitId", i32, u32);
 eve_id!(OrderId, "OrderId", i64, u64);
@@ -20,5 +21,6 @@ eve_id!(StarId, "StarId", i32, u32);
 eve_id!(StationId, "StationId", i64, u64);
 eve_id!(StructureId, "StructureId", i64, u64);
 eve_id!(SystemId, "SystemId", i32, u32);
+eve_id!(TransactionId, "TransactionId", i64, u64);
 eve_id!(TypeId, "TypeId", i32, u32);
 eve_id!(StargateId, "StargateId", i32, u32);
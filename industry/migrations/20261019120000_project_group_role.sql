@@ -0,0 +1,89 @@
+CREATE TABLE IF NOT EXISTS project_group_role (
+    id                UUID        NOT NULL DEFAULT uuidv7(),
+    project_group_id  UUID        NOT NULL,
+
+    name              VARCHAR(64) NOT NULL,
+    -- bitmask, see project_group_permission
+    permission        INTEGER     NOT NULL,
+
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id),
+
+    FOREIGN KEY (project_group_id)
+        REFERENCES project_group (id)
+        ON DELETE CASCADE
+);
+CREATE UNIQUE INDEX IF NOT EXISTS project_group_role_group_name ON project_group_role(project_group_id, name);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON project_group_role
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+-- default roles for all existing groups
+INSERT INTO project_group_role (project_group_id, name, permission)
+SELECT id, 'Viewer', 2 FROM project_group
+UNION ALL
+SELECT id, 'Builder', 6 FROM project_group
+UNION ALL
+SELECT id, 'Manager', 126 FROM project_group
+ON CONFLICT DO NOTHING;
+
+ALTER TABLE project_group_member ADD COLUMN IF NOT EXISTS role_id UUID
+    REFERENCES project_group_role (id);
+
+-- until now every added member got the owner permission, move all members
+-- that are not the owner of the group to the manager role
+UPDATE project_group_member pgm
+SET
+    permission = 0,
+    role_id    = pgr.id
+FROM project_group pg, project_group_role pgr
+WHERE pg.id = pgm.project_group_id
+AND pgr.project_group_id = pgm.project_group_id
+AND pgr.name = 'Manager'
+AND pg.owner != pgm.character_id;
+
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'project_group_grant_entity'
+    ) THEN
+        CREATE TYPE PROJECT_GROUP_GRANT_ENTITY AS ENUM (
+            'CORPORATION',
+            'ALLIANCE'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+CREATE TABLE IF NOT EXISTS project_group_grant (
+    id                UUID                       NOT NULL DEFAULT uuidv7(),
+    project_group_id  UUID                       NOT NULL,
+
+    entity_type       PROJECT_GROUP_GRANT_ENTITY NOT NULL,
+    -- corporation or alliance id
+    entity_id         INTEGER                    NOT NULL,
+    role_id           UUID                       NOT NULL,
+
+    created_at        TIMESTAMPTZ                NOT NULL DEFAULT NOW(),
+    updated_at        TIMESTAMPTZ                NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id),
+
+    FOREIGN KEY (project_group_id)
+        REFERENCES project_group (id)
+        ON DELETE CASCADE,
+
+    FOREIGN KEY (role_id)
+        REFERENCES project_group_role (id)
+);
+CREATE UNIQUE INDEX IF NOT EXISTS project_group_grant_group_entity ON project_group_grant(project_group_id, entity_type, entity_id);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON project_group_grant
+    EXECUTE FUNCTION trigger_set_updated_at();
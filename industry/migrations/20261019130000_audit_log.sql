@@ -0,0 +1,95 @@
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'audit_entity'
+    ) THEN
+        CREATE TYPE AUDIT_ENTITY AS ENUM (
+            'PROJECT',
+            'PROJECT_GROUP',
+            'STRUCTURE',
+            'INDUSTRY_HUB'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'audit_action'
+    ) THEN
+        CREATE TYPE AUDIT_ACTION AS ENUM (
+            'CREATE',
+            'UPDATE',
+            'DELETE',
+            'ARCHIVE'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'audit_scope'
+    ) THEN
+        CREATE TYPE AUDIT_SCOPE AS ENUM (
+            'PROJECT',
+            'PROJECT_JOB',
+            'PROJECT_MARKET',
+            'PROJECT_EXCESS',
+            'PROJECT_MISC',
+            'PROJECT_GROUP',
+            'PROJECT_GROUP_DEFAULT_BLACKLIST',
+            'PROJECT_GROUP_DEFAULT_BLUEPRINT_OVERWRITE',
+            'PROJECT_GROUP_DEFAULT_JOB_SPLITTING',
+            'PROJECT_GROUP_DEFAULT_MARKET',
+            'PROJECT_GROUP_INDUSTRY_HUB',
+            'PROJECT_GROUP_MEMBER',
+            'PROJECT_GROUP_ROLE',
+            'PROJECT_GROUP_GRANT',
+            'STRUCTURE',
+            'INDUSTRY_HUB'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+-- no foreign keys, the events must survive the deletion of the entity
+CREATE TABLE IF NOT EXISTS audit_event (
+    id                UUID         NOT NULL DEFAULT uuidv7(),
+
+    character_id      INTEGER      NOT NULL,
+    entity_type       AUDIT_ENTITY NOT NULL,
+    entity_id         UUID         NOT NULL,
+    -- set for projects and project groups, so that group members can trace
+    -- changes in all projects of the group
+    project_group_id  UUID,
+
+    scope             AUDIT_SCOPE  NOT NULL,
+    action            AUDIT_ACTION NOT NULL,
+    -- only the values that changed
+    before            JSONB        NOT NULL,
+    after             JSONB        NOT NULL,
+
+    created_at        TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id)
+);
+CREATE INDEX IF NOT EXISTS audit_event_entity ON audit_event(entity_type, entity_id, created_at);
+CREATE INDEX IF NOT EXISTS audit_event_project_group ON audit_event(project_group_id, created_at);
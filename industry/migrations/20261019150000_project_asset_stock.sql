@@ -0,0 +1,49 @@
+-- filter for the synced assets that are used as stock for a project
+CREATE TABLE IF NOT EXISTS project_asset_stock (
+    project_id      UUID        NOT NULL,
+
+    -- character and corporation whose assets are used, the assets are
+    -- refreshed in their name
+    character_id    INTEGER     NOT NULL,
+    corporation_id  INTEGER     NOT NULL,
+    -- domain the character is authenticated with
+    domain          VARCHAR     NOT NULL,
+
+    location_ids    BIGINT[]    NOT NULL,
+    location_flags  VARCHAR[]   NOT NULL DEFAULT '{}',
+    container_names VARCHAR[]   NOT NULL DEFAULT '{}',
+
+    refreshed_at    TIMESTAMPTZ,
+
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (project_id),
+
+    FOREIGN KEY (project_id)
+        REFERENCES project (id)
+        ON DELETE CASCADE
+);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON project_asset_stock
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+-- assets that matched the filter during the last refresh, every project has
+-- its own entries
+CREATE TABLE IF NOT EXISTS project_asset_stock_entry (
+    project_id      UUID        NOT NULL,
+
+    type_id         INTEGER     NOT NULL,
+    quantity        BIGINT      NOT NULL,
+    location_id     BIGINT      NOT NULL,
+    location_flag   VARCHAR     NOT NULL,
+    container_name  VARCHAR,
+
+    FOREIGN KEY (project_id)
+        REFERENCES project_asset_stock (project_id)
+        ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS project_asset_stock_entry_project ON project_asset_stock_entry(project_id);
+CREATE INDEX IF NOT EXISTS project_asset_stock_entry_type ON project_asset_stock_entry(type_id, location_id);
+
+ALTER TYPE AUDIT_SCOPE ADD VALUE IF NOT EXISTS 'PROJECT_ASSET_STOCK';
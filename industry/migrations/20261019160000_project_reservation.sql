@@ -0,0 +1,45 @@
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'project_reservation_source'
+    ) THEN
+        CREATE TYPE PROJECT_RESERVATION_SOURCE AS ENUM (
+            'STOCK',
+            'EXCESS'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+-- quantities a project holds back in a location, so that other projects of
+-- the same group don't count them as their own stock
+CREATE TABLE IF NOT EXISTS project_reservation (
+    project_id   UUID                       NOT NULL,
+
+    source       PROJECT_RESERVATION_SOURCE NOT NULL,
+    type_id      INTEGER                    NOT NULL,
+    location_id  BIGINT                     NOT NULL,
+    quantity     BIGINT                     NOT NULL,
+
+    created_at   TIMESTAMPTZ                NOT NULL DEFAULT NOW(),
+    updated_at   TIMESTAMPTZ                NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (project_id, source, type_id, location_id),
+
+    FOREIGN KEY (project_id)
+        REFERENCES project (id)
+        ON DELETE CASCADE,
+
+    CHECK (quantity > 0)
+);
+CREATE INDEX IF NOT EXISTS project_reservation_type ON project_reservation(type_id, location_id);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON project_reservation
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+ALTER TYPE AUDIT_SCOPE ADD VALUE IF NOT EXISTS 'PROJECT_RESERVATION';
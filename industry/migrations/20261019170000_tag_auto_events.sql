@@ -0,0 +1,10 @@
+-- status before the last status change, required for tags that match on
+-- status transitions
+ALTER TABLE project ADD COLUMN IF NOT EXISTS previous_status PROJECT_STATUS;
+
+-- the orderer option was stored with a wrong name
+UPDATE tag_auto
+SET option = 'PROJECT_ORDERER'
+WHERE option = 'PROJECT_ORDER';
+
+CREATE INDEX IF NOT EXISTS project_tag_project ON project_tag(project_id);
@@ -0,0 +1,77 @@
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'job_assignment_payout_status'
+    ) THEN
+        CREATE TYPE JOB_ASSIGNMENT_PAYOUT_STATUS AS ENUM (
+            'OPEN',
+            'PENDING',
+            'PAID'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+-- header of a job assignment link, the jobs are in project_job_assignment
+CREATE TABLE IF NOT EXISTS job_assignment (
+    id           UUID        NOT NULL DEFAULT uuidv7(),
+
+    creator_id   INTEGER     NOT NULL,
+    expires_at   TIMESTAMPTZ,
+    revoked_at   TIMESTAMPTZ,
+
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id)
+);
+CREATE INDEX IF NOT EXISTS job_assignment_creator ON job_assignment(creator_id);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON job_assignment
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+-- existing links are owned by the owner of their first project
+INSERT INTO job_assignment (id, creator_id, created_at)
+SELECT DISTINCT ON (pja.id)
+    pja.id,
+    p.owner,
+    pja.created_at
+FROM project_job_assignment pja
+JOIN project p ON p.id = pja.project_id
+ORDER BY pja.id, pja.created_at
+ON CONFLICT DO NOTHING;
+
+-- assignments of deleted projects can't be owned by anyone
+DELETE FROM project_job_assignment
+WHERE id NOT IN (SELECT id FROM job_assignment);
+
+ALTER TABLE project_job_assignment ADD COLUMN IF NOT EXISTS claimed_by    INTEGER;
+ALTER TABLE project_job_assignment ADD COLUMN IF NOT EXISTS claimed_at    TIMESTAMPTZ;
+ALTER TABLE project_job_assignment ADD COLUMN IF NOT EXISTS payment       DOUBLE PRECISION;
+ALTER TABLE project_job_assignment ADD COLUMN IF NOT EXISTS payout_status JOB_ASSIGNMENT_PAYOUT_STATUS NOT NULL DEFAULT 'OPEN';
+ALTER TABLE project_job_assignment ADD COLUMN IF NOT EXISTS paid_at       TIMESTAMPTZ;
+
+ALTER TABLE project_job_assignment DROP CONSTRAINT IF EXISTS project_job_assignment_payment_check;
+ALTER TABLE project_job_assignment ADD CONSTRAINT project_job_assignment_payment_check CHECK (payment IS NULL OR payment >= 0);
+
+ALTER TABLE project_job_assignment DROP CONSTRAINT IF EXISTS project_job_assignment_assignment_fkey;
+ALTER TABLE project_job_assignment
+    ADD CONSTRAINT project_job_assignment_assignment_fkey
+    FOREIGN KEY (id)
+    REFERENCES job_assignment (id)
+    ON DELETE CASCADE;
+
+ALTER TABLE project_job_assignment DROP CONSTRAINT IF EXISTS project_job_assignment_project_fkey;
+ALTER TABLE project_job_assignment
+    ADD CONSTRAINT project_job_assignment_project_fkey
+    FOREIGN KEY (project_id)
+    REFERENCES project (id)
+    ON DELETE CASCADE;
+
+CREATE INDEX IF NOT EXISTS project_job_assignment_job ON project_job_assignment(job_id);
+CREATE INDEX IF NOT EXISTS project_job_assignment_claimed_by ON project_job_assignment(claimed_by);
@@ -0,0 +1,66 @@
+-- price for moving intermediates between industry hubs
+CREATE TABLE IF NOT EXISTS project_group_default_freight (
+    project_group_id UUID             NOT NULL,
+
+    -- ISK per m3
+    rate             DOUBLE PRECISION NOT NULL DEFAULT 0,
+
+    created_at       TIMESTAMPTZ      NOT NULL DEFAULT NOW(),
+    updated_at       TIMESTAMPTZ      NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (project_group_id),
+
+    FOREIGN KEY (project_group_id)
+        REFERENCES project_group (id)
+        ON DELETE CASCADE,
+
+    CHECK (rate >= 0)
+);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON project_group_default_freight
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+ALTER TYPE AUDIT_SCOPE ADD VALUE IF NOT EXISTS 'PROJECT_GROUP_DEFAULT_FREIGHT';
+
+-- all industry hubs a solution uses, solution.industry_hub_id is the hub the
+-- products are build in
+CREATE TABLE IF NOT EXISTS solution_industry_hub (
+    solution_id      UUID        NOT NULL,
+    industry_hub_id  UUID        NOT NULL,
+
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (solution_id, industry_hub_id),
+
+    FOREIGN KEY (solution_id)
+        REFERENCES solution (id)
+        ON DELETE CASCADE
+);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON solution_industry_hub
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+-- intermediates that are build in one hub and used in another
+CREATE TABLE IF NOT EXISTS solution_transfer (
+    solution_id          UUID             NOT NULL,
+
+    type_id              INTEGER          NOT NULL,
+    quantity             INTEGER          NOT NULL,
+    volume               DOUBLE PRECISION NOT NULL,
+    cost                 DOUBLE PRECISION NOT NULL,
+
+    source_hub_id        UUID             NOT NULL,
+    destination_hub_id   UUID             NOT NULL,
+
+    created_at           TIMESTAMPTZ      NOT NULL DEFAULT NOW(),
+    updated_at           TIMESTAMPTZ      NOT NULL DEFAULT NOW(),
+
+    FOREIGN KEY (solution_id)
+        REFERENCES solution (id)
+        ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS solution_transfer_solution ON solution_transfer(solution_id);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON solution_transfer
+    EXECUTE FUNCTION trigger_set_updated_at();
@@ -0,0 +1,13 @@
+-- additional products are stored together with the materials
+ALTER TABLE solution_material ADD COLUMN IF NOT EXISTS additional BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- job cost and duration of the runs, in seconds
+ALTER TABLE solution_manufacturing ADD COLUMN IF NOT EXISTS build_tax DOUBLE PRECISION NOT NULL DEFAULT 0;
+ALTER TABLE solution_manufacturing ADD COLUMN IF NOT EXISTS time DOUBLE PRECISION NOT NULL DEFAULT 0;
+
+-- solution the solution was calculated from again
+ALTER TABLE solution ADD COLUMN IF NOT EXISTS rerun_of UUID;
+ALTER TABLE solution DROP CONSTRAINT IF EXISTS solution_rerun_of;
+ALTER TABLE solution ADD CONSTRAINT solution_rerun_of FOREIGN KEY (rerun_of) REFERENCES solution(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS solution_project_group ON solution(project_group_id);
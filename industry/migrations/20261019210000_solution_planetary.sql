@@ -0,0 +1,21 @@
+-- customs office tax in percent, only set if planetary products were planned
+ALTER TABLE solution ADD COLUMN IF NOT EXISTS customs_office_tax DOUBLE PRECISION;
+
+CREATE TABLE IF NOT EXISTS solution_planetary (
+    solution_id UUID             NOT NULL,
+    type_id     INTEGER          NOT NULL,
+    -- 0 for raw resources, 1 to 4 for factory products
+    tier        SMALLINT         NOT NULL,
+    needed      REAL             NOT NULL,
+    quantity    INTEGER          NOT NULL,
+    -- number of factory cycles, 0 for raw resources
+    cycles      INTEGER          NOT NULL,
+    -- duration of a single cycle in seconds
+    cycle_time  INTEGER          NOT NULL,
+    customs_tax DOUBLE PRECISION NOT NULL,
+
+    PRIMARY KEY (solution_id, type_id),
+    FOREIGN KEY (solution_id)
+        REFERENCES solution (id)
+        ON DELETE CASCADE
+);
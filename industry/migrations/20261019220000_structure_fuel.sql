@@ -0,0 +1,21 @@
+-- last recorded fuel stock of a structure
+CREATE TABLE IF NOT EXISTS structure_fuel (
+    structure_id     UUID             NOT NULL,
+    -- fuel block type that is used by the structure
+    fuel_type_id     INTEGER          NOT NULL,
+    fuel_blocks      INTEGER          NOT NULL,
+
+    -- fuel consumption and price at the time the stock was recorded, used
+    -- to amortise the upkeep into project costs
+    fuel_per_hour    REAL             NOT NULL,
+    fuel_block_price DOUBLE PRECISION NOT NULL,
+
+    recorded_at      TIMESTAMPTZ      NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (structure_id),
+    FOREIGN KEY (structure_id)
+        REFERENCES structure (id)
+        ON DELETE CASCADE
+);
+
+ALTER TYPE AUDIT_SCOPE ADD VALUE IF NOT EXISTS 'STRUCTURE_FUEL';
@@ -0,0 +1,84 @@
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'project_wallet_kind'
+    ) THEN
+        CREATE TYPE PROJECT_WALLET_KIND AS ENUM (
+            'MARKET',
+            'JOB'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+-- wallets that are used to reconcile the costs of a project
+CREATE TABLE IF NOT EXISTS project_wallet (
+    project_id      UUID        NOT NULL,
+
+    -- character and corporation whose wallets are used, the wallets are
+    -- reconciled in their name
+    character_id    INTEGER     NOT NULL,
+    corporation_id  INTEGER     NOT NULL,
+    -- domain the character is authenticated with
+    domain          VARCHAR     NOT NULL,
+
+    reconciled_at   TIMESTAMPTZ,
+
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (project_id),
+
+    FOREIGN KEY (project_id)
+        REFERENCES project (id)
+        ON DELETE CASCADE
+);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON project_wallet
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+-- wallet entries that were matched to a market or job entry of the project
+CREATE TABLE IF NOT EXISTS project_wallet_match (
+    project_id      UUID                NOT NULL,
+
+    kind            PROJECT_WALLET_KIND NOT NULL,
+    -- id of the project_market or project_job entry
+    entry_id        UUID                NOT NULL,
+    -- transaction id for market entries, journal id for jobs
+    reference_id    BIGINT              NOT NULL,
+
+    quantity        INTEGER             NOT NULL,
+    cost            DOUBLE PRECISION    NOT NULL,
+
+    FOREIGN KEY (project_id)
+        REFERENCES project_wallet (project_id)
+        ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS project_wallet_match_project ON project_wallet_match(project_id);
+CREATE INDEX IF NOT EXISTS project_wallet_match_reference ON project_wallet_match(kind, reference_id);
+
+-- spending in the project window that could not be matched
+CREATE TABLE IF NOT EXISTS project_wallet_unmatched (
+    project_id      UUID                NOT NULL,
+
+    kind            PROJECT_WALLET_KIND NOT NULL,
+    -- transaction id for market entries, journal id for jobs
+    reference_id    BIGINT              NOT NULL,
+
+    type_id         INTEGER,
+    quantity        INTEGER,
+    amount          DOUBLE PRECISION    NOT NULL,
+    date            TIMESTAMPTZ         NOT NULL,
+
+    FOREIGN KEY (project_id)
+        REFERENCES project_wallet (project_id)
+        ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS project_wallet_unmatched_project ON project_wallet_unmatched(project_id);
+
+ALTER TYPE AUDIT_SCOPE ADD VALUE IF NOT EXISTS 'PROJECT_WALLET';
@@ -0,0 +1,96 @@
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'store_order_status'
+    ) THEN
+        CREATE TYPE STORE_ORDER_STATUS AS ENUM (
+            'QUOTED',
+            'ORDERED',
+            'DELIVERED',
+            'CANCELLED'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+-- storefront of a project group, customers can order products from it
+CREATE TABLE IF NOT EXISTS store (
+    id                  UUID                NOT NULL DEFAULT uuidv7(),
+
+    -- character the projects of confirmed orders are created for
+    owner               INTEGER             NOT NULL,
+    project_group_id    UUID                NOT NULL,
+
+    name                VARCHAR             NOT NULL,
+    description         VARCHAR,
+
+    -- margin in percent that is added to the build cost
+    margin              DOUBLE PRECISION    NOT NULL DEFAULT 0,
+    -- hours a quote can be confirmed
+    quote_validity      INTEGER             NOT NULL DEFAULT 24,
+
+    -- disabled stores don't accept new quotes
+    enabled             BOOLEAN             NOT NULL DEFAULT TRUE,
+    -- public stores accept quotes from everyone, all others only from
+    -- characters that can read the project group
+    public              BOOLEAN             NOT NULL DEFAULT FALSE,
+
+    created_at          TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id),
+
+    FOREIGN KEY (project_group_id)
+        REFERENCES project_group (id)
+        ON DELETE CASCADE
+);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON store
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+-- quotes and orders of customers
+CREATE TABLE IF NOT EXISTS store_order (
+    id              UUID                NOT NULL DEFAULT uuidv7(),
+    store_id        UUID                NOT NULL,
+
+    customer        INTEGER             NOT NULL,
+    status          STORE_ORDER_STATUS  NOT NULL DEFAULT 'QUOTED',
+
+    -- solution of the job planner the quote is based on
+    solution_id     UUID                NOT NULL,
+    -- project that is created when the order is confirmed
+    project_id      UUID,
+
+    build_cost      DOUBLE PRECISION    NOT NULL,
+    price           DOUBLE PRECISION    NOT NULL,
+    valid_until     TIMESTAMPTZ         NOT NULL,
+
+    created_at      TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ         NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id),
+
+    FOREIGN KEY (store_id)
+        REFERENCES store (id)
+        ON DELETE CASCADE,
+    FOREIGN KEY (solution_id)
+        REFERENCES solution (id)
+        ON DELETE CASCADE,
+    FOREIGN KEY (project_id)
+        REFERENCES project (id)
+        ON DELETE SET NULL
+);
+CREATE INDEX IF NOT EXISTS store_order_customer ON store_order (customer);
+CREATE INDEX IF NOT EXISTS store_order_store ON store_order (store_id);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON store_order
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+ALTER TYPE AUDIT_ENTITY ADD VALUE IF NOT EXISTS 'STORE';
+ALTER TYPE AUDIT_SCOPE ADD VALUE IF NOT EXISTS 'STORE';
+ALTER TYPE AUDIT_SCOPE ADD VALUE IF NOT EXISTS 'STORE_ORDER';
@@ -0,0 +1,84 @@
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'stock_target_restock'
+    ) THEN
+        CREATE TYPE STOCK_TARGET_RESTOCK AS ENUM (
+            'PROPOSE',
+            'CREATE'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+-- settings of the stock targets of a project group
+CREATE TABLE IF NOT EXISTS stock_target_config (
+    project_group_id    UUID                    NOT NULL,
+
+    -- character and corporation whose assets are compared with the targets,
+    -- the worker checks and restocks in their name
+    character_id        INTEGER                 NOT NULL,
+    corporation_id      INTEGER                 NOT NULL,
+    -- domain the character is authenticated with
+    domain              VARCHAR                 NOT NULL,
+
+    restock             STOCK_TARGET_RESTOCK    NOT NULL DEFAULT 'PROPOSE',
+    checked_at          TIMESTAMPTZ,
+
+    created_at          TIMESTAMPTZ             NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ             NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (project_group_id),
+
+    FOREIGN KEY (project_group_id)
+        REFERENCES project_group (id)
+        ON DELETE CASCADE
+);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON stock_target_config
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+-- quantity of an item that should be in stock at a location
+CREATE TABLE IF NOT EXISTS stock_target (
+    id                  UUID        NOT NULL DEFAULT uuidv7(),
+    project_group_id    UUID        NOT NULL,
+
+    type_id             INTEGER     NOT NULL,
+    location_id         BIGINT      NOT NULL,
+    quantity            INTEGER     NOT NULL,
+
+    -- quantity in the assets during the last check
+    available           BIGINT      NOT NULL DEFAULT 0,
+
+    PRIMARY KEY (id),
+    UNIQUE (project_group_id, type_id, location_id),
+
+    FOREIGN KEY (project_group_id)
+        REFERENCES stock_target_config (project_group_id)
+        ON DELETE CASCADE
+);
+
+-- projects that were created to restock a target, the quantity of projects
+-- that are not done yet is incoming
+CREATE TABLE IF NOT EXISTS stock_target_project (
+    stock_target_id     UUID        NOT NULL,
+    project_id          UUID        NOT NULL,
+
+    quantity            INTEGER     NOT NULL,
+
+    PRIMARY KEY (stock_target_id, project_id),
+
+    FOREIGN KEY (stock_target_id)
+        REFERENCES stock_target (id)
+        ON DELETE CASCADE,
+    FOREIGN KEY (project_id)
+        REFERENCES project (id)
+        ON DELETE CASCADE
+);
+
+ALTER TYPE AUDIT_SCOPE ADD VALUE IF NOT EXISTS 'PROJECT_GROUP_STOCK_TARGET';
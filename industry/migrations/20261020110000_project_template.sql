@@ -0,0 +1,52 @@
+-- templates for recurring projects of a project group
+CREATE TABLE IF NOT EXISTS project_template (
+    id                  UUID        NOT NULL DEFAULT uuidv7(),
+    project_group_id    UUID        NOT NULL,
+
+    -- character that last updated the template, scheduled projects are
+    -- created in their name
+    character_id        INTEGER     NOT NULL,
+    corporation_id      INTEGER     NOT NULL,
+    domain              VARCHAR     NOT NULL,
+
+    name                VARCHAR     NOT NULL,
+    orderer             VARCHAR     NOT NULL,
+    note                VARCHAR,
+    tags                UUID[]      NOT NULL DEFAULT '{}',
+
+    -- days after which a new project is created, NULL if not scheduled
+    interval_days       INTEGER,
+    next_run_at         TIMESTAMPTZ,
+    -- failed scheduled runs since the last created project, every failure
+    -- delays the next run
+    failed_attempts     INTEGER     NOT NULL DEFAULT 0,
+    last_error          VARCHAR,
+
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (id),
+
+    FOREIGN KEY (project_group_id)
+        REFERENCES project_group (id)
+        ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS project_template_project_group ON project_template (project_group_id);
+CREATE OR REPLACE TRIGGER set_updated_at
+    AFTER INSERT OR UPDATE ON project_template
+    EXECUTE FUNCTION trigger_set_updated_at();
+
+CREATE TABLE IF NOT EXISTS project_template_product (
+    template_id UUID    NOT NULL,
+
+    type_id     INTEGER NOT NULL,
+    quantity    INTEGER NOT NULL,
+
+    PRIMARY KEY (template_id, type_id),
+
+    FOREIGN KEY (template_id)
+        REFERENCES project_template (id)
+        ON DELETE CASCADE
+);
+
+ALTER TYPE AUDIT_SCOPE ADD VALUE IF NOT EXISTS 'PROJECT_GROUP_TEMPLATE';
@@ -0,0 +1,4 @@
+-- magmatic gas consumption and price at the time the stock was recorded,
+-- only Metenox Moon Drills consume magmatic gas
+ALTER TABLE structure_fuel ADD COLUMN IF NOT EXISTS magmatic_gas_per_hour REAL NOT NULL DEFAULT 0;
+ALTER TABLE structure_fuel ADD COLUMN IF NOT EXISTS magmatic_gas_price DOUBLE PRECISION NOT NULL DEFAULT 0;
@@ -162,6 +162,23 @@ pub struct UnprocessableEntity {
     pub description: String,
 }
 
+/// too many requests, wait and then try again
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+#[response(
+    status = TOO_MANY_REQUESTS,
+    example = json!({
+        "error": "TOO_MANY_REQUESTS",
+        "description": "Too many requests, try again later"
+    })
+)]
+pub struct TooManyRequests {
+    /// General error name
+    pub error: String,
+    /// Human description of the error
+    pub description: String,
+}
+
 pub fn format_json_errors(
     error: JsonRejection,
 ) -> impl IntoResponse {
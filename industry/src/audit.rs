@@ -0,0 +1,4 @@
+pub mod error;
+pub mod service;
+
+pub use self::error::*;
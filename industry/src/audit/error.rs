@@ -0,0 +1,16 @@
+use starfoundry_lib_industry::audit::AuditScope;
+use thiserror::Error;
+use uuid::Uuid;
+
+pub type Result<T, E = AuditError> = std::result::Result<T, E>;
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum AuditError {
+    #[error("error while taking snapshot of '{1:?}' '{2}', error: '{0}'")]
+    Snapshot(sqlx::Error, AuditScope, Uuid),
+    #[error("error while recording audit event for '{1:?}' '{2}', error: '{0}'")]
+    Record(sqlx::Error, AuditScope, Uuid),
+    #[error("error while listing audit events for '{1}', error: '{0}'")]
+    List(sqlx::Error, Uuid),
+}
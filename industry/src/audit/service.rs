@@ -0,0 +1,7 @@
+mod list;
+mod record;
+mod snapshot;
+
+pub use self::list::*;
+pub use self::record::*;
+pub use self::snapshot::*;
@@ -0,0 +1,152 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::{AuditAction, AuditEntity, AuditEvent, AuditFilter, AuditScope};
+use uuid::Uuid;
+
+use crate::audit::error::{AuditError, Result};
+
+const DEFAULT_LIMIT: i64 = 250;
+const MAX_LIMIT: i64 = 1_000;
+
+/// Lists the audit events of an entity, newest first.
+///
+/// Project groups also include all events of the projects in the group.
+///
+pub async fn list_events(
+    pool:        &PgPool,
+    entity_type: AuditEntity,
+    entity_id:   Uuid,
+    filter:      AuditFilter,
+) -> Result<Vec<AuditEvent>> {
+    let limit = filter
+        .limit
+        .unwrap_or(DEFAULT_LIMIT)
+        .clamp(1, MAX_LIMIT);
+
+    sqlx::query!(r#"
+            SELECT
+                id,
+                character_id,
+                entity_type AS "entity_type!: AuditEntity",
+                entity_id,
+                project_group_id,
+                scope AS "scope!: AuditScope",
+                action AS "action!: AuditAction",
+                before,
+                after,
+                created_at
+            FROM audit_event
+            WHERE
+                (
+                    (entity_type = $1 AND entity_id = $2) OR
+                    ($1 = 'PROJECT_GROUP' AND project_group_id = $2)
+                )
+                AND ($3::TIMESTAMPTZ IS NULL OR created_at >= $3)
+                AND ($4::TIMESTAMPTZ IS NULL OR created_at <= $4)
+                AND ($5::AUDIT_SCOPE IS NULL OR scope = $5)
+                AND ($6::UUID IS NULL OR entity_id = $6)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $7
+        "#,
+            entity_type as _,
+            entity_id,
+            filter.from,
+            filter.to,
+            filter.scope as _,
+            filter.entity_id,
+            limit,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| AuditError::List(e, entity_id))
+        .map(|entries| {
+            entries
+                .into_iter()
+                .map(|x| AuditEvent {
+                    id:               x.id.into(),
+                    character_id:     x.character_id.into(),
+                    entity_type:      x.entity_type,
+                    entity_id:        x.entity_id,
+                    project_group_id: x.project_group_id.map(Into::into),
+                    scope:            x.scope,
+                    action:           x.action,
+                    before:           x.before,
+                    after:            x.after,
+                    created_at:       x.created_at,
+                })
+                .collect::<Vec<_>>()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::audit::{AuditAction, AuditEntity, AuditFilter, AuditScope};
+    use starfoundry_lib_industry::ProjectUuid;
+    use starfoundry_lib_types::CharacterId;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::audit::service::{AuditRecord, AuditTarget};
+
+    #[sqlx::test(
+        fixtures(
+            path = "../../project/fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn project_events_in_group(
+        pool: PgPool,
+    ) {
+        let project_id: ProjectUuid = Uuid::from_str("00000000-0000-0000-0000-000000000102").unwrap().into();
+
+        let mut transaction = pool.begin().await.unwrap();
+        let audit = AuditRecord::start(
+                &mut transaction,
+                CharacterId(1),
+                AuditAction::Update,
+                AuditTarget::Project(project_id),
+            )
+            .await
+            .unwrap();
+        sqlx::query!("
+                UPDATE project
+                SET name = 'Renamed'
+                WHERE id = $1
+            ",
+                *project_id,
+            )
+            .execute(&mut *transaction)
+            .await
+            .unwrap();
+        audit.finish(&mut transaction).await.unwrap();
+        transaction.commit().await.unwrap();
+
+        let project_group_id = Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap();
+
+        let events = super::list_events(
+                &pool,
+                AuditEntity::ProjectGroup,
+                project_group_id,
+                AuditFilter::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        let event = events.first().unwrap();
+        assert_eq!(event.scope, AuditScope::Project);
+        assert_eq!(event.before.get("name").and_then(|x| x.as_str()), Some("Filter"));
+
+        let events = super::list_events(
+                &pool,
+                AuditEntity::ProjectGroup,
+                project_group_id,
+                AuditFilter {
+                    scope: Some(AuditScope::ProjectJob),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert!(events.is_empty());
+    }
+}
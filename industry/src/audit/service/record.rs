@@ -0,0 +1,273 @@
+use serde_json::{Map, Value};
+use sqlx::PgConnection;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_types::CharacterId;
+use std::collections::HashSet;
+
+use crate::audit::error::{AuditError, Result};
+use crate::audit::service::{snapshot, AuditSnapshot, AuditTarget};
+
+/// Records a single change to an [AuditTarget].
+///
+/// The state before the change is taken with [AuditRecord::start], the state
+/// after the change with [AuditRecord::finish].
+/// Both must be called within the same transaction as the change itself.
+///
+/// ```ignore
+/// let audit = AuditRecord::start(&mut transaction, character_id, AuditAction::Update, target).await?;
+/// // apply the change
+/// audit.finish(&mut transaction).await?;
+/// ```
+///
+#[derive(Debug)]
+pub struct AuditRecord {
+    character_id: CharacterId,
+    action:       AuditAction,
+    target:       AuditTarget,
+    before:       AuditSnapshot,
+}
+
+impl AuditRecord {
+    pub async fn start(
+        connection:   &mut PgConnection,
+        character_id: CharacterId,
+        action:       AuditAction,
+        target:       AuditTarget,
+    ) -> Result<Self> {
+        let before = snapshot(connection, target).await?;
+
+        Ok(Self {
+            character_id,
+            action,
+            target,
+            before,
+        })
+    }
+
+    /// Shortcut for newly created entities, as there is no state before
+    pub async fn created(
+        connection:   &mut PgConnection,
+        character_id: CharacterId,
+        target:       AuditTarget,
+    ) -> Result<()> {
+        Self {
+                character_id,
+                action: AuditAction::Create,
+                target,
+                before: AuditSnapshot {
+                    value:            Value::Null,
+                    project_group_id: None,
+                },
+            }
+            .finish(connection)
+            .await
+    }
+
+    pub async fn finish(
+        self,
+        connection: &mut PgConnection,
+    ) -> Result<()> {
+        let after = snapshot(connection, self.target).await?;
+        // after deleting, the group can only be resolved from the snapshot
+        // before the change
+        let project_group_id = after
+            .project_group_id
+            .or(self.before.project_group_id);
+
+        if self.before.value == after.value {
+            return Ok(());
+        }
+        let (before, after) = diff(self.before.value, after.value);
+
+        let (entity_type, entity_id) = self.target.entity();
+        let scope = self.target.scope();
+        sqlx::query!("
+                INSERT INTO audit_event
+                (
+                    character_id,
+                    entity_type,
+                    entity_id,
+                    project_group_id,
+                    scope,
+                    action,
+                    before,
+                    after
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ",
+                *self.character_id,
+                entity_type as _,
+                entity_id,
+                project_group_id,
+                scope as _,
+                self.action as _,
+                before,
+                after,
+            )
+            .execute(connection)
+            .await
+            .map(drop)
+            .map_err(|e| AuditError::Record(e, scope, entity_id))
+    }
+}
+
+/// Reduces the two snapshots to the values that changed.
+///
+/// - objects only keep the changed fields, plus the `id` if there is one
+/// - lists of objects with an `id` are matched by their `id`, changed
+///   entries are reduced like objects
+/// - all other lists only keep the removed and added entries
+///
+fn diff(
+    before: Value,
+    after:  Value,
+) -> (Value, Value) {
+    match (before, after) {
+        (Value::Object(before), Value::Object(after)) => {
+            let (before, after) = diff_object(before, after);
+            (Value::Object(before), Value::Object(after))
+        },
+        (Value::Array(before), Value::Array(after)) => {
+            let (before, after) = diff_array(before, after);
+            (Value::Array(before), Value::Array(after))
+        },
+        (before, after) => (before, after),
+    }
+}
+
+fn diff_object(
+    mut before: Map<String, Value>,
+    mut after:  Map<String, Value>,
+) -> (Map<String, Value>, Map<String, Value>) {
+    let changed = before
+        .keys()
+        .chain(after.keys())
+        .filter(|x| *x == "id" || before.get(*x) != after.get(*x))
+        .cloned()
+        .collect::<HashSet<_>>();
+
+    before.retain(|x, _| changed.contains(x));
+    after.retain(|x, _| changed.contains(x));
+    (before, after)
+}
+
+fn diff_array(
+    before: Vec<Value>,
+    after:  Vec<Value>,
+) -> (Vec<Value>, Vec<Value>) {
+    let id = |x: &Value| x.get("id").cloned();
+    let has_ids = before
+        .iter()
+        .chain(after.iter())
+        .all(|x| id(x).is_some());
+
+    if !has_ids {
+        let removed = before
+            .iter()
+            .filter(|x| !after.contains(x))
+            .cloned()
+            .collect::<Vec<_>>();
+        let added = after
+            .iter()
+            .filter(|x| !before.contains(x))
+            .cloned()
+            .collect::<Vec<_>>();
+        return (removed, added);
+    }
+
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+    for entry in before.iter() {
+        let other = after
+            .iter()
+            .find(|x| id(x) == id(entry));
+
+        match other {
+            Some(other) if other == entry => continue,
+            Some(other) => {
+                let (entry, other) = diff(entry.clone(), other.clone());
+                removed.push(entry);
+                added.push(other);
+            },
+            None => removed.push(entry.clone()),
+        }
+    }
+    for entry in after.iter() {
+        if !before.iter().any(|x| id(x) == id(entry)) {
+            added.push(entry.clone());
+        }
+    }
+
+    (removed, added)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::diff;
+
+    #[test]
+    fn object_only_changed_fields() {
+        let (before, after) = diff(
+            json!({ "id": 1, "name": "A", "status": "DRAFT" }),
+            json!({ "id": 1, "name": "A", "status": "IN_PROGRESS" }),
+        );
+
+        assert_eq!(before, json!({ "id": 1, "status": "DRAFT" }));
+        assert_eq!(after, json!({ "id": 1, "status": "IN_PROGRESS" }));
+    }
+
+    #[test]
+    fn list_without_ids() {
+        let (before, after) = diff(
+            json!([1, 2, 3]),
+            json!([2, 3, 4]),
+        );
+
+        assert_eq!(before, json!([1]));
+        assert_eq!(after, json!([4]));
+    }
+
+    #[test]
+    fn list_with_ids() {
+        let (before, after) = diff(
+            json!([
+                { "id": 1, "runs": 10, "status": "WAITING_FOR_MATERIALS" },
+                { "id": 2, "runs": 5, "status": "DONE" },
+                { "id": 3, "runs": 1, "status": "DONE" },
+            ]),
+            json!([
+                { "id": 1, "runs": 10, "status": "BUILDING" },
+                { "id": 2, "runs": 5, "status": "DONE" },
+                { "id": 4, "runs": 2, "status": "WAITING_FOR_MATERIALS" },
+            ]),
+        );
+
+        assert_eq!(before, json!([
+            { "id": 1, "status": "WAITING_FOR_MATERIALS" },
+            { "id": 3, "runs": 1, "status": "DONE" },
+        ]));
+        assert_eq!(after, json!([
+            { "id": 1, "status": "BUILDING" },
+            { "id": 4, "runs": 2, "status": "WAITING_FOR_MATERIALS" },
+        ]));
+    }
+
+    #[test]
+    fn created_and_deleted() {
+        let (before, after) = diff(
+            serde_json::Value::Null,
+            json!({ "id": 1, "name": "A" }),
+        );
+        assert_eq!(before, serde_json::Value::Null);
+        assert_eq!(after, json!({ "id": 1, "name": "A" }));
+
+        let (before, after) = diff(
+            json!({ "id": 1, "name": "A" }),
+            serde_json::Value::Null,
+        );
+        assert_eq!(before, json!({ "id": 1, "name": "A" }));
+        assert_eq!(after, serde_json::Value::Null);
+    }
+}
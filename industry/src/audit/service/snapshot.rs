@@ -0,0 +1,855 @@
+use serde_json::Value;
+use sqlx::PgConnection;
+use starfoundry_lib_industry::audit::{AuditEntity, AuditScope};
+use starfoundry_lib_industry::{IndustryHubUuid, MarketUuid, ProjectGroupUuid, ProjectJobUuid, ProjectTemplateUuid, ProjectUuid, StructureUuid};
+use uuid::Uuid;
+
+use crate::audit::error::{AuditError, Result};
+use crate::store::{StoreOrderUuid, StoreUuid};
+
+/// Part of an entity that is tracked by the audit log.
+///
+/// Single rows are stored as object, all other as list of rows.
+/// Synthetic ids of rows that are replaced on every update are removed, so
+/// that only the actual changes show up in the diff.
+#[derive(Clone, Copy, Debug)]
+pub enum AuditTarget {
+    Project(ProjectUuid),
+    ProjectJobs(ProjectUuid),
+    ProjectJob(ProjectUuid, ProjectJobUuid),
+    ProjectMarket(ProjectUuid),
+    ProjectMarketEntry(ProjectUuid, MarketUuid),
+    ProjectExcess(ProjectUuid),
+    ProjectMisc(ProjectUuid),
+    ProjectReservation(ProjectUuid),
+    ProjectAssetStock(ProjectUuid),
+    ProjectWallet(ProjectUuid),
+
+    ProjectGroup(ProjectGroupUuid),
+    ProjectGroupDefaultBlacklist(ProjectGroupUuid),
+    ProjectGroupDefaultBlueprintOverwrite(ProjectGroupUuid),
+    ProjectGroupDefaultFreight(ProjectGroupUuid),
+    ProjectGroupDefaultJobSplitting(ProjectGroupUuid),
+    ProjectGroupDefaultMarket(ProjectGroupUuid),
+    ProjectGroupIndustryHub(ProjectGroupUuid),
+    ProjectGroupMember(ProjectGroupUuid),
+    ProjectGroupRole(ProjectGroupUuid),
+    ProjectGroupGrant(ProjectGroupUuid),
+    ProjectGroupStockTarget(ProjectGroupUuid),
+    ProjectGroupTemplate(ProjectGroupUuid, ProjectTemplateUuid),
+
+    Structure(StructureUuid),
+    StructureFuel(StructureUuid),
+    IndustryHub(IndustryHubUuid),
+
+    Store(StoreUuid),
+    StoreOrder(StoreUuid, StoreOrderUuid),
+}
+
+impl AuditTarget {
+    pub fn entity(
+        &self,
+    ) -> (AuditEntity, Uuid) {
+        match self {
+            Self::Project(x)                |
+            Self::ProjectJobs(x)            |
+            Self::ProjectJob(x, _)          |
+            Self::ProjectMarket(x)          |
+            Self::ProjectMarketEntry(x, _)  |
+            Self::ProjectExcess(x)          |
+            Self::ProjectMisc(x)            |
+            Self::ProjectReservation(x)     |
+            Self::ProjectAssetStock(x)      |
+            Self::ProjectWallet(x)          => (AuditEntity::Project, **x),
+
+            Self::ProjectGroup(x)                           |
+            Self::ProjectGroupDefaultBlacklist(x)           |
+            Self::ProjectGroupDefaultBlueprintOverwrite(x)  |
+            Self::ProjectGroupDefaultFreight(x)             |
+            Self::ProjectGroupDefaultJobSplitting(x)        |
+            Self::ProjectGroupDefaultMarket(x)              |
+            Self::ProjectGroupIndustryHub(x)                |
+            Self::ProjectGroupMember(x)                     |
+            Self::ProjectGroupRole(x)                       |
+            Self::ProjectGroupGrant(x)                      |
+            Self::ProjectGroupStockTarget(x)                |
+            Self::ProjectGroupTemplate(x, _)                => (AuditEntity::ProjectGroup, **x),
+
+            Self::Structure(x)              |
+            Self::StructureFuel(x)          => (AuditEntity::Structure, **x),
+            Self::IndustryHub(x)            => (AuditEntity::IndustryHub, **x),
+
+            Self::Store(x)                  |
+            Self::StoreOrder(x, _)          => (AuditEntity::Store, **x),
+        }
+    }
+
+    pub fn scope(
+        &self,
+    ) -> AuditScope {
+        match self {
+            Self::Project(_)                                => AuditScope::Project,
+            Self::ProjectJobs(_)                            |
+            Self::ProjectJob(_, _)                          => AuditScope::ProjectJob,
+            Self::ProjectMarket(_)                          |
+            Self::ProjectMarketEntry(_, _)                  => AuditScope::ProjectMarket,
+            Self::ProjectExcess(_)                          => AuditScope::ProjectExcess,
+            Self::ProjectMisc(_)                            => AuditScope::ProjectMisc,
+            Self::ProjectReservation(_)                     => AuditScope::ProjectReservation,
+            Self::ProjectAssetStock(_)                      => AuditScope::ProjectAssetStock,
+            Self::ProjectWallet(_)                          => AuditScope::ProjectWallet,
+
+            Self::ProjectGroup(_)                           => AuditScope::ProjectGroup,
+            Self::ProjectGroupDefaultBlacklist(_)           => AuditScope::ProjectGroupDefaultBlacklist,
+            Self::ProjectGroupDefaultBlueprintOverwrite(_)  => AuditScope::ProjectGroupDefaultBlueprintOverwrite,
+            Self::ProjectGroupDefaultFreight(_)             => AuditScope::ProjectGroupDefaultFreight,
+            Self::ProjectGroupDefaultJobSplitting(_)        => AuditScope::ProjectGroupDefaultJobSplitting,
+            Self::ProjectGroupDefaultMarket(_)              => AuditScope::ProjectGroupDefaultMarket,
+            Self::ProjectGroupIndustryHub(_)                => AuditScope::ProjectGroupIndustryHub,
+            Self::ProjectGroupMember(_)                     => AuditScope::ProjectGroupMember,
+            Self::ProjectGroupRole(_)                       => AuditScope::ProjectGroupRole,
+            Self::ProjectGroupGrant(_)                      => AuditScope::ProjectGroupGrant,
+            Self::ProjectGroupStockTarget(_)                => AuditScope::ProjectGroupStockTarget,
+            Self::ProjectGroupTemplate(_, _)                => AuditScope::ProjectGroupTemplate,
+
+            Self::Structure(_)                              => AuditScope::Structure,
+            Self::StructureFuel(_)                          => AuditScope::StructureFuel,
+            Self::IndustryHub(_)                            => AuditScope::IndustryHub,
+
+            Self::Store(_)                                  => AuditScope::Store,
+            Self::StoreOrder(_, _)                          => AuditScope::StoreOrder,
+        }
+    }
+}
+
+/// Current state of an [AuditTarget]
+#[derive(Clone, Debug)]
+pub struct AuditSnapshot {
+    pub value:            Value,
+    pub project_group_id: Option<Uuid>,
+}
+
+/// Takes a snapshot of the current state of the target.
+///
+/// Should be called within the same transaction as the change, otherwise
+/// concurrent changes might end up in the diff.
+///
+pub async fn snapshot(
+    connection: &mut PgConnection,
+    target:     AuditTarget,
+) -> Result<AuditSnapshot> {
+    let (_, entity_id) = target.entity();
+    let map_err = |e| AuditError::Snapshot(e, target.scope(), entity_id);
+
+    match target {
+        AuditTarget::Project(project_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT
+                                    (to_jsonb(p) - 'created_at' - 'updated_at') ||
+                                    jsonb_build_object(
+                                        'tags',
+                                        COALESCE(
+                                            (
+                                                SELECT jsonb_agg(pt.tag_id ORDER BY pt.tag_id)
+                                                FROM project_tag pt
+                                                WHERE pt.project_id = p.id
+                                            ),
+                                            '[]'::JSONB
+                                        )
+                                    )
+                                FROM project p
+                                WHERE p.id = $1
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM project WHERE id = $1) AS project_group_id
+                "#,
+                    *project_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectJobs(project_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg((to_jsonb(pj) - 'project_id' - 'created_at' - 'updated_at') ORDER BY pj.id)
+                                FROM project_job pj
+                                WHERE pj.project_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM project WHERE id = $1) AS project_group_id
+                "#,
+                    *project_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectJob(project_id, job_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT to_jsonb(pj) - 'project_id' - 'created_at' - 'updated_at'
+                                FROM project_job pj
+                                WHERE pj.project_id = $1
+                                AND pj.id = $2
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM project WHERE id = $1) AS project_group_id
+                "#,
+                    *project_id,
+                    *job_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectMarket(project_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg((to_jsonb(pm) - 'project_id' - 'created_at' - 'updated_at') ORDER BY pm.id)
+                                FROM project_market pm
+                                WHERE pm.project_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM project WHERE id = $1) AS project_group_id
+                "#,
+                    *project_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectMarketEntry(project_id, market_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT to_jsonb(pm) - 'project_id' - 'created_at' - 'updated_at'
+                                FROM project_market pm
+                                WHERE pm.project_id = $1
+                                AND pm.id = $2
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM project WHERE id = $1) AS project_group_id
+                "#,
+                    *project_id,
+                    *market_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectExcess(project_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg((to_jsonb(pe) - 'project_id' - 'created_at' - 'updated_at') ORDER BY pe.type_id)
+                                FROM project_excess pe
+                                WHERE pe.project_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM project WHERE id = $1) AS project_group_id
+                "#,
+                    *project_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectMisc(project_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg((to_jsonb(pm) - 'id' - 'project_id' - 'created_at' - 'updated_at') ORDER BY pm.item)
+                                FROM project_misc pm
+                                WHERE pm.project_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM project WHERE id = $1) AS project_group_id
+                "#,
+                    *project_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectReservation(project_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg((to_jsonb(pr) - 'project_id' - 'created_at' - 'updated_at') ORDER BY pr.type_id, pr.location_id, pr.source)
+                                FROM project_reservation pr
+                                WHERE pr.project_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM project WHERE id = $1) AS project_group_id
+                "#,
+                    *project_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+        // the entries are replaced on every refresh, only the filter is tracked
+        AuditTarget::ProjectAssetStock(project_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT to_jsonb(pas) - 'project_id' - 'refreshed_at' - 'created_at' - 'updated_at'
+                                FROM project_asset_stock pas
+                                WHERE pas.project_id = $1
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM project WHERE id = $1) AS project_group_id
+                "#,
+                    *project_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+        // the matches are replaced on every reconciliation, the costs they
+        // result in are tracked by the market and job entries
+        AuditTarget::ProjectWallet(project_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT to_jsonb(pw) - 'project_id' - 'reconciled_at' - 'created_at' - 'updated_at'
+                                FROM project_wallet pw
+                                WHERE pw.project_id = $1
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM project WHERE id = $1) AS project_group_id
+                "#,
+                    *project_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+
+        AuditTarget::ProjectGroup(project_group_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT to_jsonb(pg) - 'created_at' - 'updated_at'
+                                FROM project_group pg
+                                WHERE pg.id = $1
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectGroupDefaultBlacklist(project_group_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg(type_id ORDER BY type_id)
+                                FROM project_group_default_blacklist
+                                WHERE project_group_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectGroupDefaultBlueprintOverwrite(project_group_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg((to_jsonb(x) - 'project_group_id' - 'created_at' - 'updated_at') ORDER BY x.type_id)
+                                FROM project_group_default_blueprint_overwrite x
+                                WHERE x.project_group_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectGroupDefaultFreight(project_group_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT to_jsonb(x) - 'project_group_id' - 'created_at' - 'updated_at'
+                                FROM project_group_default_freight x
+                                WHERE x.project_group_id = $1
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectGroupDefaultJobSplitting(project_group_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg((to_jsonb(x) - 'project_group_id' - 'created_at' - 'updated_at') ORDER BY x.type_id)
+                                FROM project_group_default_job_splitting_run x
+                                WHERE x.project_group_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectGroupDefaultMarket(project_group_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg(structure_id ORDER BY structure_id)
+                                FROM project_group_default_market
+                                WHERE project_group_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectGroupIndustryHub(project_group_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg(industry_hub_id ORDER BY industry_hub_id)
+                                FROM project_group_industry_hub
+                                WHERE project_group_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectGroupMember(project_group_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg((to_jsonb(x) - 'id' - 'project_group_id' - 'created_at' - 'updated_at') ORDER BY x.character_id)
+                                FROM project_group_member x
+                                WHERE x.project_group_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectGroupRole(project_group_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg((to_jsonb(x) - 'project_group_id' - 'created_at' - 'updated_at') ORDER BY x.id)
+                                FROM project_group_role x
+                                WHERE x.project_group_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectGroupGrant(project_group_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT jsonb_agg((to_jsonb(x) - 'id' - 'project_group_id' - 'created_at' - 'updated_at') ORDER BY x.entity_type, x.entity_id)
+                                FROM project_group_grant x
+                                WHERE x.project_group_id = $1
+                            ),
+                            '[]'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+
+        AuditTarget::ProjectGroupStockTarget(project_group_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        jsonb_build_object(
+                            'config',
+                            COALESCE(
+                                (
+                                    SELECT to_jsonb(stc) - 'project_group_id' - 'checked_at' - 'created_at' - 'updated_at'
+                                    FROM stock_target_config stc
+                                    WHERE stc.project_group_id = $1
+                                ),
+                                'null'::JSONB
+                            ),
+                            'targets',
+                            COALESCE(
+                                (
+                                    SELECT jsonb_agg(
+                                        jsonb_build_object(
+                                            'type_id', st.type_id,
+                                            'location_id', st.location_id,
+                                            'quantity', st.quantity
+                                        )
+                                        ORDER BY st.type_id, st.location_id
+                                    )
+                                    FROM stock_target st
+                                    WHERE st.project_group_id = $1
+                                ),
+                                '[]'::JSONB
+                            )
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::ProjectGroupTemplate(project_group_id, template_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT
+                                    (
+                                        to_jsonb(pt) - 'project_group_id' - 'next_run_at' -
+                                        'failed_attempts' - 'last_error' - 'created_at' - 'updated_at'
+                                    ) ||
+                                    jsonb_build_object(
+                                        'products',
+                                        COALESCE(
+                                            (
+                                                SELECT jsonb_agg(
+                                                    jsonb_build_object(
+                                                        'type_id', ptp.type_id,
+                                                        'quantity', ptp.quantity
+                                                    )
+                                                    ORDER BY ptp.type_id
+                                                )
+                                                FROM project_template_product ptp
+                                                WHERE ptp.template_id = pt.id
+                                            ),
+                                            '[]'::JSONB
+                                        )
+                                    )
+                                FROM project_template pt
+                                WHERE pt.project_group_id = $1
+                                AND pt.id = $2
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *project_group_id,
+                    *template_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: Some(*project_group_id),
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::Structure(structure_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT
+                                    (to_jsonb(s) - 'created_at' - 'updated_at') ||
+                                    jsonb_build_object(
+                                        'taxes',
+                                        COALESCE(
+                                            (
+                                                SELECT jsonb_object_agg(st.service_type_id, st.tax)
+                                                FROM structure_tax st
+                                                WHERE st.structure_id = s.id
+                                            ),
+                                            '{}'::JSONB
+                                        )
+                                    )
+                                FROM structure s
+                                WHERE s.id = $1
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *structure_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: None,
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::StructureFuel(structure_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT to_jsonb(sf) - 'structure_id'
+                                FROM structure_fuel sf
+                                WHERE sf.structure_id = $1
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *structure_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: None,
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::IndustryHub(industry_hub_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT
+                                    (to_jsonb(ih) - 'created_at' - 'updated_at') ||
+                                    jsonb_build_object(
+                                        'structures',
+                                        COALESCE(
+                                            (
+                                                SELECT jsonb_agg(ihs.structure_id ORDER BY ihs.structure_id)
+                                                FROM industry_hub_structure ihs
+                                                WHERE ihs.industry_hub_id = ih.id
+                                            ),
+                                            '[]'::JSONB
+                                        ),
+                                        'shares',
+                                        COALESCE(
+                                            (
+                                                SELECT jsonb_agg(jsonb_build_object(
+                                                    'share_id', ihs.share_id,
+                                                    'share_type', ihs.share_type,
+                                                    'name', ihs.name
+                                                ) ORDER BY ihs.share_id)
+                                                FROM industry_hub_share ihs
+                                                WHERE ihs.industry_hub_id = ih.id
+                                            ),
+                                            '[]'::JSONB
+                                        )
+                                    )
+                                FROM industry_hub ih
+                                WHERE ih.id = $1
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!"
+                "#,
+                    *industry_hub_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: None,
+                })
+                .map_err(map_err)
+        },
+
+        AuditTarget::Store(store_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT to_jsonb(s) - 'created_at' - 'updated_at'
+                                FROM store s
+                                WHERE s.id = $1
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM store WHERE id = $1) AS project_group_id
+                "#,
+                    *store_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+        AuditTarget::StoreOrder(store_id, order_id) => {
+            sqlx::query!(r#"
+                    SELECT
+                        COALESCE(
+                            (
+                                SELECT to_jsonb(so) - 'store_id' - 'created_at' - 'updated_at'
+                                FROM store_order so
+                                WHERE so.store_id = $1
+                                AND so.id = $2
+                            ),
+                            'null'::JSONB
+                        ) AS "snapshot!",
+                        (SELECT project_group_id FROM store WHERE id = $1) AS project_group_id
+                "#,
+                    *store_id,
+                    *order_id,
+                )
+                .fetch_one(connection)
+                .await
+                .map(|x| AuditSnapshot {
+                    value:            x.snapshot,
+                    project_group_id: x.project_group_id,
+                })
+                .map_err(map_err)
+        },
+    }
+}
@@ -1,6 +1,10 @@
 mod error;
+mod fitting;
 mod job_planner;
+mod sensitivity;
 
+pub use self::error::*;
+pub use self::fitting::*;
 pub use self::job_planner::*;
 
 use utoipa_axum::router::OpenApiRouter;
@@ -10,7 +14,8 @@ use crate::AppState;
 
 pub fn routes() -> OpenApiRouter<AppState> {
     let calculation = OpenApiRouter::new()
-        .routes(routes!(job_planner::api));
+        .routes(routes!(job_planner::api))
+        .routes(routes!(sensitivity::api));
 
     OpenApiRouter::new()
         .merge(calculation)
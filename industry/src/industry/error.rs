@@ -2,7 +2,8 @@ use axum::extract::rejection::JsonRejection;
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::{IntoResponse, Response};
-use starfoundry_lib_gateway::ErrorResponse;
+use starfoundry_lib_gateway::{ErrorResponse, boxed_from};
+use starfoundry_lib_industry::SolutionUuid;
 use thiserror::Error;
 
 use crate::api_docs::format_json_errors;
@@ -12,17 +13,28 @@ pub type Result<T, E = IndustryError> = std::result::Result<T, E>;
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum IndustryError {
+    #[error("invalid request, '{0}'")]
+    InvalidRequest(String),
+
     #[error("error while parsing dependency, {0}")]
     ParseJsonToDependency(serde_json::Error),
+    #[error("error while storing the planetary steps of solution '{1}', error: '{0}'")]
+    StoreSolutionPlanetary(sqlx::Error, SolutionUuid),
+    #[error("error while storing the industry hubs of solution '{1}', error: '{0}'")]
+    StoreSolutionHubs(sqlx::Error, SolutionUuid),
 
     #[error(transparent)]
     JsonExtractorRejection(#[from] JsonRejection),
     #[error(transparent)]
+    GatewayLibError(Box<starfoundry_lib_gateway::Error>),
+    #[error(transparent)]
     EveGatewayLibError(#[from] starfoundry_lib_eve_gateway::Error),
     #[error(transparent)]
     MarketLibError(#[from] starfoundry_lib_market::Error),
     #[error(transparent)]
     ProjectGroupError(#[from] crate::project_group::ProjectGroupError),
+    #[error(transparent)]
+    ProjectError(Box<crate::project::error::ProjectError>),
 }
 
 impl IntoResponse for IndustryError {
@@ -32,6 +44,26 @@ impl IntoResponse for IndustryError {
                 format_json_errors(x).into_response()
             },
 
+            Self::InvalidRequest(description) => {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(
+                        ErrorResponse {
+                            error: "INVALID_REQUEST".into(),
+                            description,
+                        }
+                    )
+                ).into_response()
+            },
+
+            Self::GatewayLibError(e) => {
+                starfoundry_lib_gateway::Error::into_response(*e)
+            },
+
+            Self::ProjectGroupError(e) => {
+                e.into_response()
+            },
+
             _ => {
                 tracing::error!("{}", self.to_string());
                 (
@@ -48,3 +80,6 @@ impl IntoResponse for IndustryError {
         .into_response()
     }
 }
+
+boxed_from!(IndustryError::GatewayLibError, starfoundry_lib_gateway::Error);
+boxed_from!(IndustryError::ProjectError, crate::project::error::ProjectError);
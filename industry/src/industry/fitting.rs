@@ -0,0 +1,287 @@
+use starfoundry_lib_eve_gateway::{EveGatewayApiClientFitting, EveGatewayApiClientItem, Item};
+use starfoundry_lib_gateway::Identity;
+use starfoundry_lib_industry::industry::FittingImport;
+use starfoundry_lib_types::{GroupId, TypeId};
+use std::collections::HashMap;
+
+use crate::{eve_gateway_api_client, eve_gateway_api_client_with_identity};
+use crate::industry::error::{IndustryError, Result};
+
+/// Meta groups that can't be build, or are usually cheaper on the market
+///
+/// Storyline, Faction, Officer, Deadspace, Abyssal, Premium and Limited
+const BUY_META_GROUPS: [GroupId; 7] = [
+    GroupId(3),
+    GroupId(4),
+    GroupId(5),
+    GroupId(6),
+    GroupId(15),
+    GroupId(17),
+    GroupId(19),
+];
+
+/// Single hull or module of all given fits
+#[derive(Clone, Debug, PartialEq)]
+pub struct FittingProduct {
+    pub type_id:  TypeId,
+    pub name:     String,
+    pub quantity: u32,
+}
+
+/// Hulls and modules of the fits, split by how they are acquired
+#[derive(Debug, Default)]
+pub struct FittingProducts {
+    pub build: Vec<FittingProduct>,
+    pub buy:   Vec<FittingProduct>,
+}
+
+impl FittingProducts {
+    /// One line per product in the format `<name> <quantity>`, same format
+    /// as the item parser
+    pub fn format(
+        products: &[FittingProduct],
+    ) -> Option<String> {
+        if products.is_empty() {
+            return None;
+        }
+
+        let lines = products
+            .iter()
+            .map(|x| format!("{} {}", x.name, x.quantity))
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(lines)
+    }
+}
+
+/// Checks if the content is a fit instead of an item list.
+///
+/// Detects the same formats as the fit parser, EFT by its `[Ship, Name]`
+/// header, the XML export of the in-game fitting window and DNA.
+///
+pub fn is_fit(
+    content: &str,
+) -> bool {
+    let content = content.trim();
+    if content.is_empty() {
+        return false;
+    }
+
+    let eft = content
+        .lines()
+        .any(|x| {
+            let x = x.trim();
+            x.starts_with('[') && x.ends_with(']')
+        });
+    let dna = content
+        .lines()
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .all(|x| {
+            x.contains(':') &&
+            x.chars().all(|c| c.is_ascii_digit() || matches!(c, ':' | ';' | '_'))
+        });
+
+    content.contains("<fitting") || eft || dna
+}
+
+/// Expands the given fits into their hulls and modules.
+///
+/// Modules with a faction, officer, deadspace or similar meta group are
+/// bought by default, this can be overwritten by the `buy` and `build` lists
+/// of every fit.
+///
+/// # Errors
+///
+/// - [IndustryError::InvalidRequest] if a fit has neither `fit` nor
+///   `fitting_id`, or the fitting does not exist
+///
+pub async fn expand_fittings(
+    identity: Option<Identity>,
+    fittings: &[FittingImport],
+) -> Result<FittingProducts> {
+    // type_id -> (name, quantity, buy)
+    let mut products: HashMap<TypeId, (String, u32, bool)> = HashMap::new();
+
+    for fitting in fittings {
+        let items = if let Some(x) = &fitting.fit {
+            fetch_parsed(x).await?
+        } else if let Some(fitting_id) = fitting.fitting_id {
+            let identity = identity
+                .clone()
+                .ok_or_else(|| IndustryError::InvalidRequest(
+                    "Fittings of a character require an authenticated request".into()
+                ))?;
+            let fit = eve_gateway_api_client_with_identity(identity.clone())?
+                .list_fits(identity)
+                .await?
+                .into_iter()
+                .find(|x| x.fitting_id == fitting_id)
+                .ok_or_else(|| IndustryError::InvalidRequest(
+                    format!("Fitting '{}' not found", *fitting_id)
+                ))?;
+
+            let mut quantities = vec![(fit.ship_type_id, 1i64)];
+            quantities.extend(fit.items.iter().map(|x| (x.type_id, x.quantity)));
+
+            let items = eve_gateway_api_client()?
+                .fetch_item_bulk(quantities.iter().map(|(x, _)| *x).collect::<Vec<_>>())
+                .await?
+                .into_iter()
+                .map(|x| (x.type_id, x))
+                .collect::<HashMap<_, _>>();
+            quantities
+                .into_iter()
+                .filter_map(|(type_id, quantity)| {
+                    items.get(&type_id).map(|x| (x.clone(), quantity))
+                })
+                .collect::<Vec<_>>()
+        } else {
+            return Err(IndustryError::InvalidRequest(
+                "Either fit or fitting_id must be set.".into()
+            ));
+        };
+
+        for (item, quantity) in items {
+            let buy = if fitting.buy.contains(&item.type_id) {
+                true
+            } else if fitting.build.contains(&item.type_id) {
+                false
+            } else {
+                item
+                    .meta_group
+                    .map(|x| BUY_META_GROUPS.contains(&x))
+                    .unwrap_or_default()
+            };
+
+            let quantity = (quantity.max(0) as u32).saturating_mul(fitting.multiplier);
+            products
+                .entry(item.type_id)
+                .and_modify(|(_, x, _)| *x += quantity)
+                .or_insert((item.name, quantity, buy));
+        }
+    }
+
+    let mut result = FittingProducts::default();
+    for (type_id, (name, quantity, buy)) in products {
+        let product = FittingProduct {
+            type_id,
+            name,
+            quantity,
+        };
+
+        if buy {
+            result.buy.push(product);
+        } else {
+            result.build.push(product);
+        }
+    }
+    result.build.sort_by_key(|x| x.type_id);
+    result.buy.sort_by_key(|x| x.type_id);
+
+    Ok(result)
+}
+
+/// Parses the fits and returns the hull and modules of all fits
+async fn fetch_parsed(
+    fit: &str,
+) -> Result<Vec<(Item, i64)>> {
+    let fits = eve_gateway_api_client()?
+        .parse_fits(fit.to_string())
+        .await?;
+
+    let items = fits
+        .into_iter()
+        .flat_map(|x| x.ship.into_iter().chain(x.items))
+        .map(|x| (x.raw, x.quantity))
+        .collect::<Vec<_>>();
+
+    if items.is_empty() {
+        return Err(IndustryError::InvalidRequest(
+            "The fit does not contain any known items".into()
+        ));
+    }
+
+    Ok(items)
+}
+
+#[cfg(test)]
+mod expand_fittings_test {
+    use starfoundry_lib_industry::industry::FittingImport;
+    use starfoundry_lib_types::{FittingId, TypeId};
+
+    use crate::industry::IndustryError;
+    use super::{FittingProduct, expand_fittings, is_fit};
+
+    fn summary(
+        products: &[FittingProduct],
+    ) -> Vec<(TypeId, u32)> {
+        products
+            .iter()
+            .map(|x| (x.type_id, x.quantity))
+            .collect::<Vec<_>>()
+    }
+
+    fn import(
+        buy:   Vec<TypeId>,
+        build: Vec<TypeId>,
+    ) -> FittingImport {
+        FittingImport {
+            fit:        Some("[Charon, Hauler]\nCapital Cargo Bay\nCapital Cargo Bay".into()),
+            fitting_id: None,
+            multiplier: 2,
+            buy,
+            build,
+        }
+    }
+
+    #[tokio::test]
+    async fn multiplies_and_splits() {
+        let result = expand_fittings(None, &[import(Vec::new(), Vec::new())]).await.unwrap();
+
+        assert_eq!(summary(&result.build), vec![(TypeId(20185), 2)]);
+        // the cargo bay is a faction module in the mocked data
+        assert_eq!(summary(&result.buy), vec![(TypeId(21027), 4)]);
+    }
+
+    #[tokio::test]
+    async fn overwrites() {
+        let result = expand_fittings(
+                None,
+                &[import(vec![TypeId(20185)], vec![TypeId(21027)])],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(summary(&result.build), vec![(TypeId(21027), 4)]);
+        assert_eq!(summary(&result.buy), vec![(TypeId(20185), 2)]);
+    }
+
+    #[tokio::test]
+    async fn fitting_requires_identity() {
+        let result = expand_fittings(
+                None,
+                &[FittingImport {
+                    fit:        None,
+                    fitting_id: Some(FittingId(1)),
+                    multiplier: 1,
+                    buy:        Vec::new(),
+                    build:      Vec::new(),
+                }],
+            )
+            .await;
+
+        assert!(matches!(result, Err(IndustryError::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn detects_fits() {
+        assert!(is_fit("[Charon, Hauler]\nCapital Cargo Bay"));
+        assert!(is_fit("20185:21027;2::"));
+        assert!(is_fit("<fittings><fitting name=\"Hauler\"></fitting></fittings>"));
+
+        assert!(!is_fit("Nyx 1"));
+        assert!(!is_fit("Charon 2\nCapital Cargo Bay 4"));
+        assert!(!is_fit(""));
+    }
+}
@@ -1,34 +1,49 @@
 mod planner;
 mod models;
+mod planetary;
 mod project_config_builder;
 mod project_config;
 mod result;
+mod sensitivity;
+mod settings;
+mod transfer;
 
 pub use self::planner::*;
 pub use self::models::*;
+pub use self::planetary::*;
 pub use self::project_config::*;
 pub use self::project_config_builder::*;
+pub use self::sensitivity::*;
+pub use self::settings::*;
+pub use self::transfer::*;
 
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
 use sqlx::PgPool;
-use starfoundry_lib_eve_gateway::{EveGatewayApiClientIndustry, EveGatewayApiClientItem};
-use starfoundry_lib_gateway::{ErrorResponse, ExtractIdentity};
-use starfoundry_lib_industry::industry::{BuildEngine, BuildEngineAdditionalProduct, BuildEngineManufacturingResponse, BuildEngineMaterialResponse, BuildEngineProduct, BuildEngineResponse, StockMinimal};
+use starfoundry_lib_eve_gateway::{EveGatewayApiClientEveAsset, EveGatewayApiClientIndustry, EveGatewayApiClientItem};
+use starfoundry_lib_gateway::{ExtractIdentity, Identity};
+use starfoundry_lib_industry::industry::{BuildEngine, BuildEngineAdditionalProduct, BuildEngineManufacturingResponse, BuildEngineMaterialResponse, BuildEnginePlanetaryResponse, BuildEngineProduct, BuildEngineResponse, BuildEngineTransferResponse, StockMinimal, TmpBlueprintOverwrite, TmpJobSplitting};
 use starfoundry_lib_industry::IndustryHubUuid;
 use starfoundry_lib_industry::ProjectGroupUuid;
 use starfoundry_lib_industry::SolutionUuid;
+use starfoundry_lib_industry::industry_hub::IndustryHub;
+use starfoundry_lib_industry::structure::Structure;
 use starfoundry_lib_market::{MarketStrategy, MarketApiClientOrder, MarketApiClientPrice, MarketBulkRequest, MarketItem};
-use starfoundry_lib_types::TypeId;
+use starfoundry_lib_types::{CharacterId, SystemId, TypeId};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::{AppState, eve_gateway_api_client, market_api_client, sort_by_job_flat, sort_by_market_group_flat};
+use crate::{AppState, eve_gateway_api_client, eve_gateway_api_client_with_identity, market_api_client, sort_by_job_flat, sort_by_market_group_flat};
 use crate::api_docs::{Forbidden, InternalServerError, Unauthorized};
-use crate::industry::error::Result;
-use crate::project_group::service::{list_default_blacklist, list_default_blueprint_overwrite, list_default_job_splitting, list_industry_hubs};
+use crate::industry::error::{IndustryError, Result};
+use crate::industry::{FittingProducts, expand_fittings};
+use crate::project::service::ReservedQuantities;
+use crate::project_group::service::{Freight, fetch_default_freight, list_default_blacklist, list_default_blueprint_overwrite, list_default_job_splitting, list_industry_hubs};
+
+/// Customs office tax in percent, if the request does not set one
+const DEFAULT_CUSTOMS_OFFICE_TAX: f64 = 10f64;
 
 // TODO: refactor
 /// Build plan
@@ -38,7 +53,21 @@ use crate::project_group::service::{list_default_blacklist, list_default_bluepri
 /// 
 /// ---
 /// 
-/// Creates a build solution
+/// Creates a build solution for every industry hub of the project group.
+/// Hubs that can't build every job are skipped.
+/// 
+/// With `combine_industry_hubs` the structures of all hubs are combined into
+/// a single solution, e.g. reactions in one system and capitals in another.
+/// Intermediates that are build in one hub and used in another are reported
+/// as transfers and priced with the freight rate of the project group.
+/// 
+/// With `expand_planetary` planetary products are planned as factory cycles
+/// per tier, together with the planet types of the raw resources and the
+/// customs office tax, instead of being bought.
+/// 
+/// With `fittings` EFT, DNA, in-game exports or stored fittings of the
+/// character are expanded into their hull and modules. Modules that are
+/// usually bought, like faction modules, are added as additional products.
 /// 
 /// ## Security
 /// - authenticated
@@ -67,7 +96,81 @@ pub async fn api(
     State(state): State<AppState>,
     Json(config): Json<BuildEngine>,
 ) -> Result<impl IntoResponse> {
-    let products = if let Some(x) = config.products {
+    let solutions = calculate(
+            &state.postgres,
+            &identity,
+            config,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(solutions)
+        )
+        .into_response()
+    )
+}
+
+/// Calculates and stores a build solution for the industry hubs of the
+/// project group
+/// 
+/// # Errors
+/// 
+/// - [IndustryError::InvalidRequest] if neither products are given, or the
+///   freight rate is invalid
+/// 
+pub async fn calculate(
+    pool:     &PgPool,
+    identity: &ExtractIdentity,
+    config:   BuildEngine,
+) -> Result<Vec<BuildEngineResponse>> {
+    // the host is only required for reading the assets or fittings of the
+    // character
+    let uses_fitting = config
+        .fittings
+        .as_ref()
+        .map(|x| x.iter().any(|x| x.fit.is_none() && x.fitting_id.is_some()))
+        .unwrap_or_default();
+    let asset_identity = if config.stocks_from_assets.is_some() || uses_fitting {
+        Some(Identity::new(
+            identity.character_id,
+            identity.corporation_id,
+            identity.host()?,
+        ))
+    } else {
+        None
+    };
+
+    calculate_as(
+            pool,
+            identity.character_id,
+            asset_identity,
+            config,
+        )
+        .await
+}
+
+/// Same as [calculate], but in the name of the given character.
+/// The industry hubs of the project group are resolved with the permissions
+/// of the character.
+/// 
+/// `stocks_from_assets` and fittings of the character are only applied if an
+/// `asset_identity` is given
+/// 
+pub async fn calculate_as(
+    pool:           &PgPool,
+    character_id:   CharacterId,
+    asset_identity: Option<Identity>,
+    config:         BuildEngine,
+) -> Result<Vec<BuildEngineResponse>> {
+    let fittings = if let Some(x) = &config.fittings {
+        expand_fittings(asset_identity.clone(), x).await?
+    } else {
+        FittingProducts::default()
+    };
+
+    let mut products = if let Some(x) = config.products {
         x
     } else if let Some(x) = config.products_str {
         eve_gateway_api_client()?
@@ -81,21 +184,25 @@ pub async fn api(
                 material_efficiency: x.material_efficiency.map(|x| x as u32).unwrap_or(0),
             })
             .collect::<Vec<_>>()
+    } else if config.fittings.is_some() {
+        Vec::new()
     } else {
-        return Ok(
-            (
-                StatusCode::BAD_REQUEST,
-                Json(
-                    ErrorResponse {
-                        error: "INVALID_REQUEST".into(),
-                        description: "Either materials or materials_str must be set.".into(),
-                    }
-                )
-            )
-            .into_response()
-        );
+        return Err(IndustryError::InvalidRequest(
+            "Either materials, materials_str or fittings must be set.".into()
+        ));
     };
-    let additional_products = if let Some(x) = config.additional_products {
+    products.extend(
+        fittings
+            .build
+            .iter()
+            .map(|x| BuildEngineProduct {
+                quantity:            x.quantity,
+                type_id:             x.type_id,
+                material_efficiency: 0,
+            })
+    );
+
+    let mut additional_products = if let Some(x) = config.additional_products {
         x
     } else if let Some(x) = config.additional_products_str {
         eve_gateway_api_client()?
@@ -112,8 +219,18 @@ pub async fn api(
     } else {
         Vec::new()
     };
+    additional_products.extend(
+        fittings
+            .buy
+            .iter()
+            .map(|x| BuildEngineAdditionalProduct {
+                quantity:   x.quantity,
+                type_id:    x.type_id,
+                price:      None,
+            })
+    );
 
-    let stocks = if let Some(x) = config.stocks {
+    let mut stocks = if let Some(x) = config.stocks {
         x
     } else if let Some(x) = config.stocks_str {
         eve_gateway_api_client()?
@@ -129,152 +246,125 @@ pub async fn api(
     } else {
         Vec::new()
     };
+    if let (Some(filter), Some(asset_identity)) = (config.stocks_from_assets, asset_identity) {
+        let asset_stock = eve_gateway_api_client_with_identity(asset_identity)?
+            .eve_list_asset_stock(&filter)
+            .await?
+            .into_iter()
+            .map(|x| StockMinimal {
+                quantity:   x.quantity.min(i32::MAX as i64) as i32,
+                type_id:    x.type_id,
+            });
+        stocks.extend(asset_stock);
+    }
+
+    // the stock may be shared with other projects of the group, the
+    // reservations of the project itself are part of the plan
+    let mut reserved = ReservedQuantities::load(
+            pool,
+            vec![*config.project_group_id],
+            config.project_id.map(|x| vec![*x]).unwrap_or_default(),
+            config.stock_location_id,
+        )
+        .await?;
+    let stocks = stocks
+        .into_iter()
+        .map(|x| StockMinimal {
+            quantity: reserved.unreserved(x.type_id, x.quantity),
+            type_id:  x.type_id,
+        })
+        .filter(|x| x.quantity > 0)
+        .collect::<Vec<_>>();
 
     let industry_hubs = list_industry_hubs(
-            &state.postgres,
+            pool,
             &eve_gateway_api_client()?,
-            identity.character_id,
+            character_id,
             config.project_group_id,
         )
+        .await?
+        .into_iter()
+        .filter(|x| {
+            config
+                .industry_hubs
+                .as_ref()
+                .map(|y| y.contains(&x.id))
+                .unwrap_or(true)
+        })
+        .collect::<Vec<_>>();
+
+    let settings = planner_settings(
+            pool,
+            config.project_group_id,
+            &products,
+            config.blacklist,
+            config.job_splitting,
+            config.blueprint_overwrite,
+        )
         .await?;
+    let dependencies = fetch_dependencies(&products).await?;
 
-    let blacklist = if let Some(x) = config.blacklist {
-        x
-    } else {
-        list_default_blacklist(
-                &state.postgres,
-                &eve_gateway_api_client()?,
-                config.project_group_id,
-            )
-            .await?
-            .into_iter()
-            .map(|x| x.type_id)
-            .collect::<Vec<_>>()
+    let freight = match config.freight_rate {
+        Some(rate) if !rate.is_finite() || rate < 0f64 => {
+            return Err(IndustryError::InvalidRequest(
+                "The freight rate must be positive.".into()
+            ));
+        },
+        Some(rate) => Freight { rate },
+        None => fetch_default_freight(pool, config.project_group_id).await?,
     };
 
-    let job_splitting = if let Some(x) = config.job_splitting {
-        x
-            .into_iter()
-            .map(|x| (x.type_id, x.runs))
-            .collect::<HashMap<_, _>>()
+    let customs_office_tax = match config.customs_office_tax {
+        Some(tax) if !tax.is_finite() || !(0f64..=100f64).contains(&tax) => {
+            return Err(IndustryError::InvalidRequest(
+                "The customs office tax must be between 0 and 100.".into()
+            ));
+        },
+        Some(tax) => tax,
+        None => DEFAULT_CUSTOMS_OFFICE_TAX,
+    };
+    let planet_schematics = if config.expand_planetary.unwrap_or_default() {
+        Some(eve_gateway_api_client()?.list_planet_schematics().await?)
     } else {
-        list_default_job_splitting(
-                &state.postgres,
-                &eve_gateway_api_client()?,
-                config.project_group_id,
-            )
-            .await?
-            .runs
-            .into_iter()
-            .map(|x| (x.item.type_id, x.max_runs as u32))
-            .collect::<HashMap<_, _>>()
+        None
     };
 
-    let mut blueprint_overwrites = if let Some(x) = config.blueprint_overwrite {
-        x
-            .into_iter()
-            .map(|x| (x.type_id, BlueprintBonus { material: x.material_efficiency as f32, type_id: x.type_id, time: 0f32}))
-            .collect::<HashMap<_, _>>()
+    // either one solution per hub, or a single solution using all hubs
+    let hub_combinations = if config.combine_industry_hubs.unwrap_or_default() {
+        if industry_hubs.is_empty() {
+            Vec::new()
+        } else {
+            vec![industry_hubs]
+        }
     } else {
-        list_default_blueprint_overwrite(
-                &state.postgres,
-                &eve_gateway_api_client()?,
-                config.project_group_id,
-            )
-            .await?
+        industry_hubs
             .into_iter()
-            .map(|x| (x.item.type_id, BlueprintBonus { material: x.material_efficiency as f32, type_id: x.item.type_id, time: 0f32}))
-            .collect::<HashMap<_, _>>()
+            .map(|x| vec![x])
+            .collect::<Vec<_>>()
     };
-    let tmp_blueprint_overwrites = products
-        .iter()
-        .map(|x| (
-            x.type_id,
-            BlueprintBonus {
-                type_id:    x.type_id,
-                material:   x.material_efficiency as f32,
-                time:       0f32,
-            }
-        ))
-        .collect::<HashMap<_, _>>();
-    blueprint_overwrites.extend(tmp_blueprint_overwrites);
-
-    let market_prices = market_api_client()?
-        .all_prices()
-        .await?
-        .into_iter()
-        .map(|x| (x.type_id, x.adjusted_price))
-        .collect::<HashMap<_, _>>();
 
     let mut solutions = Vec::new();
-    for industry_hub in industry_hubs {
-        let mapping = industry_hub
-            .structures
-            .iter()
-            .map(|x| {
-                StructureMapping {
-                    category_group: x.joined_categories_groups(),
-                    structure_uuid: x.id,
+    for industry_hubs in hub_combinations {
+        // structures that are in multiple hubs belong to the first hub
+        let mut structure_hubs = HashMap::new();
+        let mut structures = Vec::new();
+        for industry_hub in industry_hubs.iter() {
+            for structure in industry_hub.structures.iter() {
+                if structure_hubs.contains_key(&structure.id) {
+                    continue;
                 }
-            })
-            .collect::<Vec<_>>();
-
-        let mut system_index = HashMap::new();
-        let mut all_system_ids = Vec::new();
-        for structure in industry_hub.structures.iter() {
-            all_system_ids.push(structure.system.system_id);
-        }
-        all_system_ids.sort();
-        all_system_ids.dedup();
-
-        for system_id in all_system_ids {
-            let index = &eve_gateway_api_client()?
-                .fetch_system_index(system_id)
-                .await
-                .unwrap()
-                .unwrap();
-            system_index.insert(
-                system_id,
-                (
-                    index.manufacturing,
-                    index.reaction,
-                )
-            );
-        }
 
-        let project_config = ProjectConfigBuilder::default()
-            .add_blacklists(blacklist.clone())
-            .add_blueprint_overwrites(blueprint_overwrites.clone())
-            .add_structures(industry_hub.structures.clone())
-            .add_structure_mappings(mapping)
-            .set_max_runs(job_splitting.clone())
-            .set_material_cost(market_prices.clone())
-            .set_system_index(system_index.clone())
-            .build();
-
-        let mut dependency_tree = JobPlannerEngine::new(project_config);
-
-        let eve_gateway_client = eve_gateway_api_client()?;
-        for product in products.iter() {
-            let dependency = if let Ok(Some(x)) = eve_gateway_client.fetch_blueprint_json(product.type_id).await {
-                x.data
-            } else {
-                continue
-            };
-
-            let json = serde_json::to_value(&dependency).unwrap();
-
-            if let Ok(x) = Dependency::try_from(product.quantity, json) {
-                dependency_tree.add(x);
-            } else {
-                continue;
-            };
+                structure_hubs.insert(structure.id, industry_hub.id);
+                structures.push(structure.clone());
+            }
         }
 
-        let dependency_result = dependency_tree
-            .apply_bonus()
-            .add_stocks(&stocks)
-            .finalize();
+        let settings = PlannerSettings {
+            system_index: fetch_system_index(&structures).await?,
+            structures,
+            ..settings.clone()
+        };
+        let dependency_result = settings.run(&dependencies, &stocks);
 
         let manufacturing = dependency_result
             .tree
@@ -299,6 +389,38 @@ pub async fn api(
                 continue;
         }
 
+        let hubs_by_type = dependency_result
+            .tree
+            .values()
+            .filter_map(|x| {
+                let structure = x.structure.as_ref()?;
+                Some((x.product_type_id, *structure_hubs.get(&structure.id)?))
+            })
+            .collect::<HashMap<_, _>>();
+        let transfers = hub_transfers(
+            &dependency_result.tree,
+            &hubs_by_type,
+            freight,
+        );
+
+        // the hub the products are build in is the main hub of the solution
+        let main_hub_id = products
+            .iter()
+            .find_map(|x| hubs_by_type.get(&x.type_id))
+            .copied();
+        let industry_hub = if let Some(x) = industry_hubs
+            .iter()
+            .find(|x| Some(x.id) == main_hub_id)
+            .or(industry_hubs.first()) {
+            x.clone()
+        } else {
+            continue;
+        };
+        let used_hubs = industry_hubs
+            .into_iter()
+            .filter(|x| x.id == industry_hub.id || hubs_by_type.values().any(|y| *y == x.id))
+            .collect::<Vec<_>>();
+
         let mut material = dependency_result
             .tree
             .iter()
@@ -311,6 +433,23 @@ pub async fn api(
                 price:  None,
             })
             .collect::<Vec<_>>();
+        let planetary = if let Some(planet_schematics) = planet_schematics.as_ref() {
+            let steps = planetary_chain(
+                &material
+                    .iter()
+                    .map(|x| (x.item.type_id, x.needed))
+                    .collect::<HashMap<_, _>>(),
+                planet_schematics,
+                customs_office_tax,
+            );
+
+            // planetary products are produced instead of bought
+            material.retain(|x| !steps.iter().any(|y| y.type_id == x.item.type_id));
+            planetary_response(steps).await?
+        } else {
+            Vec::new()
+        };
+
         if let Some(true) = config.calculate_market_cost {
             let items = material
                 .iter()
@@ -403,12 +542,12 @@ pub async fn api(
         }
 
         let solution_id = store_solution(
-                &state.postgres,
+                pool,
                 industry_hub.id,
                 config.project_group_id,
-                blacklist.clone(),
-                blueprint_overwrites.clone(),
-                job_splitting.clone(),
+                settings.blacklist.clone(),
+                settings.blueprint_overwrites.clone(),
+                settings.job_splitting.clone(),
                 updated_stocks.clone(),
                 products.clone(),
                 excess.clone(),
@@ -419,21 +558,212 @@ pub async fn api(
             .await
             .unwrap();
 
+        store_solution_hubs(
+                pool,
+                solution_id,
+                &used_hubs,
+                &transfers,
+            )
+            .await?;
+
+        if planet_schematics.is_some() {
+            store_solution_planetary(
+                    pool,
+                    solution_id,
+                    customs_office_tax,
+                    &planetary,
+                )
+                .await?;
+        }
+
         solutions.push(BuildEngineResponse {
             solution_id:    solution_id,
             industry_hub:   industry_hub,
+            industry_hubs:  used_hubs,
             material:       sort_market(material),
             manufacturing:  sort_jobs(manufacturing),
+            transfers,
+            planetary,
         });
     }
 
-    Ok(
-        (
-            StatusCode::OK,
-            Json(solutions)
-        )
-        .into_response()
-    )
+    Ok(solutions)
+}
+
+/// Adds the item information to the planetary steps
+async fn planetary_response(
+    steps: Vec<PlanetaryStep>,
+) -> Result<Vec<BuildEnginePlanetaryResponse>> {
+    let items = eve_gateway_api_client()?
+        .fetch_item_bulk(steps.iter().map(|x| x.type_id).collect::<Vec<_>>())
+        .await?
+        .into_iter()
+        .map(|x| (x.type_id, x))
+        .collect::<HashMap<_, _>>();
+
+    let planetary = steps
+        .into_iter()
+        .filter_map(|x| {
+            Some(BuildEnginePlanetaryResponse {
+                item:            items.get(&x.type_id)?.clone(),
+                tier:            x.tier,
+                needed:          x.needed,
+                quantity:        x.quantity,
+                cycles:          x.cycles,
+                cycle_time:      x.cycle_time,
+                planet_type_ids: x.planet_type_ids,
+                customs_tax:     x.customs_tax,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(planetary)
+}
+
+/// Resolves the settings of the job planner, settings that are not given are
+/// taken from the defaults of the project group.
+/// The structures and system indices are set per industry hub
+pub(crate) async fn planner_settings(
+    pool:                &PgPool,
+    project_group_id:    ProjectGroupUuid,
+    products:            &[BuildEngineProduct],
+    blacklist:           Option<Vec<TypeId>>,
+    job_splitting:       Option<Vec<TmpJobSplitting>>,
+    blueprint_overwrite: Option<Vec<TmpBlueprintOverwrite>>,
+) -> Result<PlannerSettings> {
+    let blacklist = if let Some(x) = blacklist {
+        x
+    } else {
+        list_default_blacklist(
+                pool,
+                &eve_gateway_api_client()?,
+                project_group_id,
+            )
+            .await?
+            .into_iter()
+            .map(|x| x.type_id)
+            .collect::<Vec<_>>()
+    };
+
+    let job_splitting = if let Some(x) = job_splitting {
+        x
+            .into_iter()
+            .map(|x| (x.type_id, x.runs))
+            .collect::<HashMap<_, _>>()
+    } else {
+        list_default_job_splitting(
+                pool,
+                &eve_gateway_api_client()?,
+                project_group_id,
+            )
+            .await?
+            .runs
+            .into_iter()
+            .map(|x| (x.item.type_id, x.max_runs as u32))
+            .collect::<HashMap<_, _>>()
+    };
+
+    let mut blueprint_overwrites = if let Some(x) = blueprint_overwrite {
+        x
+            .into_iter()
+            .map(|x| (x.type_id, BlueprintBonus { material: x.material_efficiency as f32, type_id: x.type_id, time: 0f32}))
+            .collect::<HashMap<_, _>>()
+    } else {
+        list_default_blueprint_overwrite(
+                pool,
+                &eve_gateway_api_client()?,
+                project_group_id,
+            )
+            .await?
+            .into_iter()
+            .map(|x| (x.item.type_id, BlueprintBonus { material: x.material_efficiency as f32, type_id: x.item.type_id, time: 0f32}))
+            .collect::<HashMap<_, _>>()
+    };
+    let tmp_blueprint_overwrites = products
+        .iter()
+        .map(|x| (
+            x.type_id,
+            BlueprintBonus {
+                type_id:    x.type_id,
+                material:   x.material_efficiency as f32,
+                time:       0f32,
+            }
+        ))
+        .collect::<HashMap<_, _>>();
+    blueprint_overwrites.extend(tmp_blueprint_overwrites);
+
+    let material_cost = market_api_client()?
+        .all_prices()
+        .await?
+        .into_iter()
+        .map(|x| (x.type_id, x.adjusted_price))
+        .collect::<HashMap<_, _>>();
+
+    Ok(PlannerSettings {
+        blacklist,
+        blueprint_overwrites,
+        job_splitting,
+        material_cost,
+        ..Default::default()
+    })
+}
+
+/// Fetches the blueprint dependencies of all products, products without a
+/// blueprint are skipped
+pub(crate) async fn fetch_dependencies(
+    products: &[BuildEngineProduct],
+) -> Result<Vec<Dependency>> {
+    let eve_gateway_client = eve_gateway_api_client()?;
+
+    let mut dependencies = Vec::new();
+    for product in products.iter() {
+        let dependency = if let Ok(Some(x)) = eve_gateway_client.fetch_blueprint_json(product.type_id).await {
+            x.data
+        } else {
+            continue
+        };
+
+        let json = serde_json::to_value(&dependency).unwrap();
+
+        if let Ok(x) = Dependency::try_from(product.quantity, json) {
+            dependencies.push(x);
+        } else {
+            continue;
+        };
+    }
+
+    Ok(dependencies)
+}
+
+/// Fetches the manufacturing and reaction cost indices of all systems the
+/// structures are in
+pub(crate) async fn fetch_system_index(
+    structures: &[Structure],
+) -> Result<HashMap<SystemId, (f32, f32)>> {
+    let mut all_system_ids = structures
+        .iter()
+        .map(|x| x.system.system_id)
+        .collect::<Vec<_>>();
+    all_system_ids.sort();
+    all_system_ids.dedup();
+
+    let mut system_index = HashMap::new();
+    for system_id in all_system_ids {
+        let index = eve_gateway_api_client()?
+            .fetch_system_index(system_id)
+            .await
+            .unwrap()
+            .unwrap();
+        system_index.insert(
+            system_id,
+            (
+                index.manufacturing,
+                index.reaction,
+            )
+        );
+    }
+
+    Ok(system_index)
 }
 
 async fn store_solution(
@@ -627,9 +957,10 @@ async fn store_solution(
                 solution_id,
                 type_id,
                 quantity,
-                cost
+                cost,
+                additional
             )
-            SELECT $1, * FROM UNNEST(
+            SELECT $1, *, TRUE FROM UNNEST(
                 $2::INTEGER[],
                 $3::INTEGER[],
                 $4::DOUBLE PRECISION[]
@@ -656,21 +987,29 @@ async fn store_solution(
     let mut type_id = Vec::new();
     let mut runs = Vec::new();
     let mut structure = Vec::new();
+    let mut build_tax = Vec::new();
+    let mut time = Vec::new();
     for job in manufacturing {
         let structure_id = if let Some(x) = job.structure.clone() {
             *x.id
         } else {
             Uuid::default()
         };
+        let total_runs = job.runs.iter().sum::<u32>().max(1) as f64;
 
         for run in job.runs.iter() {
             if *run == 0 {
                 continue;
             }
 
+            // the cost is only known for all runs together
+            let share = *run as f64 / total_runs;
+
             type_id.push(*job.item.type_id);
             runs.push(*run as i32);
             structure.push(structure_id);
+            build_tax.push(job.build_tax as f64 * share);
+            time.push(job.time as f64 * *run as f64);
         }
     }
 
@@ -680,18 +1019,24 @@ async fn store_solution(
                 solution_id,
                 type_id,
                 runs,
-                structure_id
+                structure_id,
+                build_tax,
+                time
             )
             SELECT $1, * FROM UNNEST(
                 $2::INTEGER[],
                 $3::INTEGER[],
-                $4::UUID[]
+                $4::UUID[],
+                $5::DOUBLE PRECISION[],
+                $6::DOUBLE PRECISION[]
             )
         ",
             solution_id,
             &type_id,
             &runs,
             &structure,
+            &build_tax,
+            &time,
         )
         .execute(&mut *transaction)
         .await
@@ -702,5 +1047,140 @@ async fn store_solution(
     Ok(solution_id.into())
 }
 
+/// Stores the hubs a solution uses and the transfers between them
+async fn store_solution_hubs(
+    pool:          &PgPool,
+    solution_id:   SolutionUuid,
+    industry_hubs: &[IndustryHub],
+    transfers:     &[BuildEngineTransferResponse],
+) -> Result<()> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(|e| IndustryError::StoreSolutionHubs(e, solution_id))?;
+
+    sqlx::query!("
+            INSERT INTO solution_industry_hub
+            (
+                solution_id,
+                industry_hub_id
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::UUID[]
+            )
+        ",
+            *solution_id,
+            &industry_hubs.iter().map(|x| *x.id).collect::<Vec<_>>(),
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| IndustryError::StoreSolutionHubs(e, solution_id))?;
+
+    sqlx::query!("
+            INSERT INTO solution_transfer
+            (
+                solution_id,
+                type_id,
+                quantity,
+                volume,
+                cost,
+                source_hub_id,
+                destination_hub_id
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::INTEGER[],
+                $3::INTEGER[],
+                $4::DOUBLE PRECISION[],
+                $5::DOUBLE PRECISION[],
+                $6::UUID[],
+                $7::UUID[]
+            )
+        ",
+            *solution_id,
+            &transfers.iter().map(|x| *x.item.type_id).collect::<Vec<_>>(),
+            &transfers.iter().map(|x| x.quantity as i32).collect::<Vec<_>>(),
+            &transfers.iter().map(|x| x.volume).collect::<Vec<_>>(),
+            &transfers.iter().map(|x| x.cost).collect::<Vec<_>>(),
+            &transfers.iter().map(|x| *x.source).collect::<Vec<_>>(),
+            &transfers.iter().map(|x| *x.destination).collect::<Vec<_>>(),
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| IndustryError::StoreSolutionHubs(e, solution_id))?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| IndustryError::StoreSolutionHubs(e, solution_id))
+}
+
 sort_by_market_group_flat!(sort_market, BuildEngineMaterialResponse);
 sort_by_job_flat!(sort_jobs, BuildEngineManufacturingResponse);
+
+/// Stores the planetary steps of the solution, the tax is kept so that the
+/// solution can be calculated again
+async fn store_solution_planetary(
+    pool:               &PgPool,
+    solution_id:        SolutionUuid,
+    customs_office_tax: f64,
+    planetary:          &[BuildEnginePlanetaryResponse],
+) -> Result<()> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(|e| IndustryError::StoreSolutionPlanetary(e, solution_id))?;
+
+    sqlx::query!("
+            UPDATE solution
+            SET customs_office_tax = $2
+            WHERE id = $1
+        ",
+            *solution_id,
+            customs_office_tax,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| IndustryError::StoreSolutionPlanetary(e, solution_id))?;
+
+    sqlx::query!("
+            INSERT INTO solution_planetary
+            (
+                solution_id,
+                type_id,
+                tier,
+                needed,
+                quantity,
+                cycles,
+                cycle_time,
+                customs_tax
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::INTEGER[],
+                $3::SMALLINT[],
+                $4::REAL[],
+                $5::INTEGER[],
+                $6::INTEGER[],
+                $7::INTEGER[],
+                $8::DOUBLE PRECISION[]
+            )
+        ",
+            *solution_id,
+            &planetary.iter().map(|x| *x.item.type_id).collect::<Vec<_>>(),
+            &planetary.iter().map(|x| x.tier as i16).collect::<Vec<_>>(),
+            &planetary.iter().map(|x| x.needed).collect::<Vec<_>>(),
+            &planetary.iter().map(|x| x.quantity as i32).collect::<Vec<_>>(),
+            &planetary.iter().map(|x| x.cycles as i32).collect::<Vec<_>>(),
+            &planetary.iter().map(|x| x.cycle_time).collect::<Vec<_>>(),
+            &planetary.iter().map(|x| x.customs_tax).collect::<Vec<_>>(),
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| IndustryError::StoreSolutionPlanetary(e, solution_id))?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| IndustryError::StoreSolutionPlanetary(e, solution_id))?;
+
+    Ok(())
+}
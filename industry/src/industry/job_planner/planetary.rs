@@ -0,0 +1,255 @@
+use starfoundry_lib_eve_gateway::{PlanetSchematic, PlanetSchematics};
+use starfoundry_lib_types::TypeId;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+
+/// Base value per unit of a planetary product by tier, used by the customs
+/// offices to calculate the tax
+const BASE_VALUE: [f64; 5] = [5f64, 400f64, 7_200f64, 60_000f64, 1_200_000f64];
+
+/// Single step of a planetary chain
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanetaryStep {
+    pub type_id:         TypeId,
+    pub tier:            u8,
+    pub needed:          f32,
+    pub quantity:        u32,
+    pub cycles:          u32,
+    pub cycle_time:      i32,
+    pub planet_type_ids: Vec<TypeId>,
+    pub customs_tax:     f64,
+}
+
+/// Expands the given materials into the factory cycles and raw resources that
+/// are required to produce them.
+///
+/// Every produced product is exported once from a customs office, and every
+/// input that is not a raw resource is imported, for half the tax.
+/// Materials that are not a planetary product are ignored.
+///
+/// `customs_office_tax` is in percent
+pub fn planetary_chain(
+    materials:          &HashMap<TypeId, f32>,
+    planetary:          &PlanetSchematics,
+    customs_office_tax: f64,
+) -> Vec<PlanetaryStep> {
+    let schematics = planetary
+        .schematics
+        .iter()
+        .map(|x| (x.type_id, x))
+        .collect::<HashMap<_, _>>();
+    let resources = planetary
+        .resources
+        .iter()
+        .map(|x| (x.type_id, x.planet_type_ids.clone()))
+        .collect::<HashMap<_, _>>();
+    let tax = customs_office_tax / 100f64;
+
+    let mut tiers = HashMap::new();
+    // needed quantity by tier, highest tier first, so that all consumers of a
+    // product are known before its cycles are calculated
+    let mut needed: BTreeMap<Reverse<u8>, HashMap<TypeId, f32>> = BTreeMap::new();
+    for (type_id, quantity) in materials {
+        if *quantity <= 0f32 ||
+            (!schematics.contains_key(type_id) && !resources.contains_key(type_id)) {
+            continue;
+        }
+
+        let tier = tier_of(*type_id, &schematics, &mut tiers);
+        *needed
+            .entry(Reverse(tier))
+            .or_default()
+            .entry(*type_id)
+            .or_default() += quantity;
+    }
+
+    let mut steps = Vec::new();
+    while let Some((Reverse(tier), products)) = needed.pop_first() {
+        for (type_id, quantity) in products {
+            let schematic = if let Some(x) = schematics.get(&type_id) {
+                x
+            } else {
+                steps.push(PlanetaryStep {
+                    type_id,
+                    tier,
+                    needed:          quantity,
+                    quantity:        quantity.ceil() as u32,
+                    cycles:          0,
+                    cycle_time:      0,
+                    planet_type_ids: resources.get(&type_id).cloned().unwrap_or_default(),
+                    customs_tax:     0f64,
+                });
+                continue;
+            };
+
+            let cycles = (quantity / schematic.quantity.max(1) as f32).ceil() as u32;
+            let produced = cycles * schematic.quantity as u32;
+            let mut customs_tax = produced as f64 * base_value(tier) * tax;
+
+            for material in schematic.materials.iter() {
+                let material_tier = tier_of(material.type_id, &schematics, &mut tiers);
+                let material_quantity = cycles as f32 * material.quantity as f32;
+                if material_tier > 0 {
+                    customs_tax += material_quantity as f64 * base_value(material_tier) * tax * 0.5f64;
+                }
+
+                *needed
+                    .entry(Reverse(material_tier))
+                    .or_default()
+                    .entry(material.type_id)
+                    .or_default() += material_quantity;
+            }
+
+            steps.push(PlanetaryStep {
+                type_id,
+                tier,
+                needed:          quantity,
+                quantity:        produced,
+                cycles,
+                cycle_time:      schematic.cycle_time,
+                planet_type_ids: Vec::new(),
+                customs_tax,
+            });
+        }
+    }
+
+    steps.sort_by_key(|x| (Reverse(x.tier), x.type_id));
+    steps
+}
+
+/// Tier of a planetary product, raw resources are tier 0.
+/// Results are cached in `tiers`
+fn tier_of(
+    type_id:    TypeId,
+    schematics: &HashMap<TypeId, &PlanetSchematic>,
+    tiers:      &mut HashMap<TypeId, u8>,
+) -> u8 {
+    if let Some(x) = tiers.get(&type_id) {
+        return *x;
+    }
+
+    let tier = if let Some(schematic) = schematics.get(&type_id) {
+        // insert a placeholder, so that a cyclic schematic can't recurse
+        // forever
+        tiers.insert(type_id, 0);
+        schematic
+            .materials
+            .iter()
+            .map(|x| tier_of(x.type_id, schematics, tiers))
+            .max()
+            .unwrap_or_default()
+            .saturating_add(1)
+    } else {
+        0
+    };
+
+    tiers.insert(type_id, tier);
+    tier
+}
+
+fn base_value(
+    tier: u8,
+) -> f64 {
+    BASE_VALUE
+        .get(tier as usize)
+        .or(BASE_VALUE.last())
+        .copied()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use starfoundry_lib_eve_gateway::{PlanetResource, PlanetSchematic, PlanetSchematicMaterial, PlanetSchematics};
+    use starfoundry_lib_types::TypeId;
+    use std::collections::HashMap;
+
+    use super::planetary_chain;
+
+    fn schematic(
+        type_id:    i32,
+        quantity:   i32,
+        cycle_time: i32,
+        materials:  Vec<(i32, i32)>,
+    ) -> PlanetSchematic {
+        PlanetSchematic {
+            schematic_id: type_id,
+            name:         format!("Schematic {type_id}"),
+            cycle_time,
+            type_id:      TypeId(type_id),
+            quantity,
+            materials:    materials
+                .into_iter()
+                .map(|(type_id, quantity)| PlanetSchematicMaterial {
+                    type_id: TypeId(type_id),
+                    quantity,
+                })
+                .collect::<Vec<_>>(),
+        }
+    }
+
+    fn planetary() -> PlanetSchematics {
+        PlanetSchematics {
+            schematics: vec![
+                // P1 from P0
+                schematic(10, 20, 1_800, vec![(1, 3_000)]),
+                schematic(11, 20, 1_800, vec![(2, 3_000)]),
+                // P2 from two P1
+                schematic(20, 5, 3_600, vec![(10, 40), (11, 40)]),
+            ],
+            resources:  vec![
+                PlanetResource { type_id: TypeId(1), planet_type_ids: vec![TypeId(11), TypeId(2016)] },
+                PlanetResource { type_id: TypeId(2), planet_type_ids: vec![TypeId(13)] },
+            ],
+        }
+    }
+
+    #[test]
+    fn expands_tiers() {
+        let materials = HashMap::from([
+            (TypeId(20), 12f32),
+            // not a planetary product
+            (TypeId(34), 1_000f32),
+        ]);
+
+        let steps = planetary_chain(&materials, &planetary(), 10f64);
+        assert_eq!(steps.len(), 5);
+
+        let p2 = steps.first().unwrap();
+        assert_eq!(p2.type_id, TypeId(20));
+        assert_eq!(p2.tier, 2);
+        assert_eq!(p2.cycles, 3);
+        assert_eq!(p2.quantity, 15);
+        // export 15 * 7200 * 0.1 + import 120 * 400 * 0.1 * 0.5 twice
+        assert_eq!(p2.customs_tax, 10_800f64 + 4_800f64);
+
+        let p1 = steps.iter().find(|x| x.type_id == TypeId(10)).unwrap();
+        assert_eq!(p1.tier, 1);
+        assert_eq!(p1.needed, 120f32);
+        assert_eq!(p1.cycles, 6);
+        assert_eq!(p1.cycle_time, 1_800);
+        // raw resources are not imported
+        assert_eq!(p1.customs_tax, 120f64 * 400f64 * 0.1f64);
+
+        let p0 = steps.iter().find(|x| x.type_id == TypeId(1)).unwrap();
+        assert_eq!(p0.tier, 0);
+        assert_eq!(p0.quantity, 18_000);
+        assert_eq!(p0.cycles, 0);
+        assert_eq!(p0.planet_type_ids, vec![TypeId(11), TypeId(2016)]);
+    }
+
+    #[test]
+    fn combines_shared_inputs() {
+        let materials = HashMap::from([
+            (TypeId(20), 5f32),
+            (TypeId(10), 10f32),
+        ]);
+
+        let steps = planetary_chain(&materials, &planetary(), 0f64);
+
+        // 40 for the P2 and 10 requested directly
+        let p1 = steps.iter().find(|x| x.type_id == TypeId(10)).unwrap();
+        assert_eq!(p1.needed, 50f32);
+        assert_eq!(p1.cycles, 3);
+        assert_eq!(p1.customs_tax, 0f64);
+    }
+}
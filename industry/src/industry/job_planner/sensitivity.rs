@@ -0,0 +1,220 @@
+use starfoundry_lib_industry::industry::{SensitivityResult, SensitivityVariation};
+use starfoundry_lib_types::TypeId;
+use std::collections::HashMap;
+
+use crate::industry::job_planner::models::{BlueprintBonus, BlueprintTyp};
+use crate::industry::job_planner::result::EngineResult;
+use crate::industry::job_planner::settings::PlannerSettings;
+
+impl PlannerSettings {
+    /// Copy of the settings with the given variation applied.
+    /// Material prices are not part of the settings, see [vary_prices]
+    pub fn vary(
+        &self,
+        variation: &SensitivityVariation,
+    ) -> Self {
+        let mut settings = self.clone();
+
+        match variation {
+            SensitivityVariation::BlueprintMaterialEfficiency { type_id, material_efficiency } => {
+                // keeps the time efficiency of an existing overwrite
+                let time = settings
+                    .blueprint_overwrites
+                    .get(type_id)
+                    .map(|x| x.time)
+                    .unwrap_or(20f32);
+
+                settings.blueprint_overwrites.insert(
+                    *type_id,
+                    BlueprintBonus {
+                        type_id:  *type_id,
+                        material: *material_efficiency as f32,
+                        time,
+                    },
+                );
+            },
+            SensitivityVariation::RigTier { tier } => {
+                for structure in settings.structures.iter_mut() {
+                    for rig in structure.rigs.iter_mut() {
+                        rig.material = tier.bonus().map(|(x, _)| x);
+                        rig.time = tier.bonus().map(|(_, x)| x);
+                    }
+                }
+            },
+            SensitivityVariation::SystemCostIndex { percent } => {
+                let factor = 1f32 + percent / 100f32;
+                for (manufacturing, reaction) in settings.system_index.values_mut() {
+                    *manufacturing = (*manufacturing * factor).max(0f32);
+                    *reaction = (*reaction * factor).max(0f32);
+                }
+            },
+            SensitivityVariation::Blacklist { type_id } => {
+                if !settings.blacklist.contains(type_id) {
+                    settings.blacklist.push(*type_id);
+                }
+            },
+            SensitivityVariation::MaterialPrice { .. } => (),
+        }
+
+        settings
+    }
+}
+
+/// Copy of the material prices with the given variation applied
+pub fn vary_prices(
+    prices:    &HashMap<TypeId, f64>,
+    variation: &SensitivityVariation,
+) -> HashMap<TypeId, f64> {
+    let mut prices = prices.clone();
+
+    if let SensitivityVariation::MaterialPrice { type_id, percent } = variation {
+        let factor = 1f64 + percent / 100f64;
+        for (_, price) in prices
+            .iter_mut()
+            .filter(|(x, _)| type_id.map(|y| y == **x).unwrap_or(true)) {
+
+            *price = (*price * factor).max(0f64);
+        }
+    }
+
+    prices
+}
+
+/// Summarizes the cost and time of a planner result, materials are priced
+/// with the given unit prices
+pub fn summarize(
+    result: &EngineResult,
+    prices: &HashMap<TypeId, f64>,
+) -> SensitivityResult {
+    let mut summary = SensitivityResult::default();
+
+    for entry in result.tree.values() {
+        if entry.typ == BlueprintTyp::Material {
+            if entry.needed > 0f32 {
+                summary.material_cost += entry.needed.ceil() as f64 *
+                    prices.get(&entry.product_type_id).unwrap_or(&0f64);
+            }
+            continue;
+        }
+
+        if entry.structure.is_none() {
+            summary.missing_jobs += 1;
+        }
+
+        summary.build_tax += entry.build_cost.total_job_cost as f64;
+        for runs in entry.runs.iter() {
+            let time = *runs as f64 * entry.time as f64;
+            summary.time += time;
+            summary.longest_job = summary.longest_job.max(time);
+        }
+    }
+
+    summary.total_cost = summary.material_cost + summary.build_tax;
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use starfoundry_lib_industry::industry::SensitivityVariation;
+    use starfoundry_lib_types::{SystemId, TypeId};
+    use std::collections::HashMap;
+
+    use super::vary_prices;
+    use crate::industry::job_planner::models::BlueprintBonus;
+    use crate::industry::job_planner::settings::PlannerSettings;
+
+    #[test]
+    fn blueprint_material_efficiency() {
+        let settings = PlannerSettings {
+            blueprint_overwrites: HashMap::from([
+                (TypeId(1), BlueprintBonus { type_id: TypeId(1), material: 10f32, time: 0f32 }),
+            ]),
+            ..Default::default()
+        };
+
+        let varied = settings.vary(&SensitivityVariation::BlueprintMaterialEfficiency {
+            type_id:             TypeId(1),
+            material_efficiency: 5,
+        });
+        let overwrite = varied.blueprint_overwrites.get(&TypeId(1)).unwrap();
+        assert_eq!(overwrite.material, 5f32);
+        assert_eq!(overwrite.time, 0f32);
+
+        let varied = settings.vary(&SensitivityVariation::BlueprintMaterialEfficiency {
+            type_id:             TypeId(2),
+            material_efficiency: 8,
+        });
+        let overwrite = varied.blueprint_overwrites.get(&TypeId(2)).unwrap();
+        assert_eq!(overwrite.material, 8f32);
+        assert_eq!(overwrite.time, 20f32);
+
+        // the baseline is untouched
+        assert_eq!(settings.blueprint_overwrites.len(), 1);
+    }
+
+    #[test]
+    fn system_cost_index() {
+        let settings = PlannerSettings {
+            system_index: HashMap::from([
+                (SystemId(1), (0.1f32, 0.2f32)),
+            ]),
+            ..Default::default()
+        };
+
+        let varied = settings.vary(&SensitivityVariation::SystemCostIndex {
+            percent: -50f32,
+        });
+        assert_eq!(varied.system_index.get(&SystemId(1)), Some(&(0.05f32, 0.1f32)));
+
+        let varied = settings.vary(&SensitivityVariation::SystemCostIndex {
+            percent: -200f32,
+        });
+        assert_eq!(varied.system_index.get(&SystemId(1)), Some(&(0f32, 0f32)));
+    }
+
+    #[test]
+    fn blacklist() {
+        let settings = PlannerSettings {
+            blacklist: vec![TypeId(1)],
+            ..Default::default()
+        };
+
+        let varied = settings.vary(&SensitivityVariation::Blacklist {
+            type_id: TypeId(1),
+        });
+        assert_eq!(varied.blacklist, vec![TypeId(1)]);
+
+        let varied = settings.vary(&SensitivityVariation::Blacklist {
+            type_id: TypeId(2),
+        });
+        assert_eq!(varied.blacklist, vec![TypeId(1), TypeId(2)]);
+    }
+
+    #[test]
+    fn material_price() {
+        let prices = HashMap::from([
+            (TypeId(1), 100f64),
+            (TypeId(2), 200f64),
+        ]);
+
+        let varied = vary_prices(&prices, &SensitivityVariation::MaterialPrice {
+            type_id: Some(TypeId(1)),
+            percent: 50f64,
+        });
+        assert_eq!(varied.get(&TypeId(1)), Some(&150f64));
+        assert_eq!(varied.get(&TypeId(2)), Some(&200f64));
+
+        let varied = vary_prices(&prices, &SensitivityVariation::MaterialPrice {
+            type_id: None,
+            percent: -25f64,
+        });
+        assert_eq!(varied.get(&TypeId(1)), Some(&75f64));
+        assert_eq!(varied.get(&TypeId(2)), Some(&150f64));
+
+        // other variations keep the prices
+        let varied = vary_prices(&prices, &SensitivityVariation::Blacklist {
+            type_id: TypeId(1),
+        });
+        assert_eq!(varied, prices);
+    }
+}
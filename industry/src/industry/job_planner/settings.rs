@@ -0,0 +1,66 @@
+use starfoundry_lib_industry::industry::StockMinimal;
+use starfoundry_lib_industry::structure::Structure;
+use starfoundry_lib_types::{SystemId, TypeId};
+use std::collections::HashMap;
+
+use crate::industry::job_planner::models::{BlueprintBonus, Dependency, StructureMapping};
+use crate::industry::job_planner::planner::JobPlannerEngine;
+use crate::industry::job_planner::project_config_builder::ProjectConfigBuilder;
+use crate::industry::job_planner::result::EngineResult;
+
+/// Everything the [JobPlannerEngine] needs besides the products and stock.
+/// The same settings always result in the same tree, so that they can be
+/// varied one at a time
+#[derive(Clone, Debug, Default)]
+pub struct PlannerSettings {
+    /// list of items that should not be build
+    pub blacklist:            Vec<TypeId>,
+    pub blueprint_overwrites: HashMap<TypeId, BlueprintBonus>,
+    /// maximum amount of runs per job
+    pub job_splitting:        HashMap<TypeId, u32>,
+    pub structures:           Vec<Structure>,
+    /// manufacturing and reaction cost index by system
+    pub system_index:         HashMap<SystemId, (f32, f32)>,
+    /// adjusted prices used for the job cost
+    pub material_cost:        HashMap<TypeId, f64>,
+}
+
+impl PlannerSettings {
+    /// Runs the [JobPlannerEngine] for the given dependencies
+    pub fn run(
+        &self,
+        dependencies: &[Dependency],
+        stocks:       &Vec<StockMinimal>,
+    ) -> EngineResult {
+        let mapping = self
+            .structures
+            .iter()
+            .map(|x| {
+                StructureMapping {
+                    category_group: x.joined_categories_groups(),
+                    structure_uuid: x.id,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let project_config = ProjectConfigBuilder::default()
+            .add_blacklists(self.blacklist.clone())
+            .add_blueprint_overwrites(self.blueprint_overwrites.clone())
+            .add_structures(self.structures.clone())
+            .add_structure_mappings(mapping)
+            .set_max_runs(self.job_splitting.clone())
+            .set_material_cost(self.material_cost.clone())
+            .set_system_index(self.system_index.clone())
+            .build();
+
+        let mut dependency_tree = JobPlannerEngine::new(project_config);
+        for dependency in dependencies {
+            dependency_tree.add(dependency.clone());
+        }
+
+        dependency_tree
+            .apply_bonus()
+            .add_stocks(stocks)
+            .finalize()
+    }
+}
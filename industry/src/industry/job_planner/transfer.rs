@@ -0,0 +1,194 @@
+use starfoundry_lib_industry::industry::BuildEngineTransferResponse;
+use starfoundry_lib_industry::IndustryHubUuid;
+use starfoundry_lib_types::TypeId;
+use std::collections::HashMap;
+
+use crate::industry::job_planner::models::{BlueprintTyp, DependencyTreeEntry};
+use crate::project_group::service::Freight;
+
+/// Collects all intermediates that are build in one industry hub and used by
+/// a job in another hub.
+///
+/// # Params
+///
+/// - `tree` - Finalized tree of the engine
+/// - `hubs` - Industry hub each product is build in
+/// - `freight` - Rate the transfers are priced with
+///
+pub fn hub_transfers(
+    tree:    &HashMap<TypeId, DependencyTreeEntry>,
+    hubs:    &HashMap<TypeId, IndustryHubUuid>,
+    freight: Freight,
+) -> Vec<BuildEngineTransferResponse> {
+    let mut quantities: HashMap<(TypeId, IndustryHubUuid, IndustryHubUuid), u32> = HashMap::new();
+
+    for parent in tree.values() {
+        if parent.typ == BlueprintTyp::Material {
+            continue;
+        }
+
+        let destination = if let Some(x) = hubs.get(&parent.product_type_id) {
+            *x
+        } else {
+            continue;
+        };
+
+        for (child_type_id, child_quantity) in parent.children.iter() {
+            let child = if let Some(x) = tree.get(child_type_id) {
+                x
+            } else {
+                continue;
+            };
+
+            // materials are bought and components from the stock are
+            // already where they are needed
+            if child.typ == BlueprintTyp::Material || child.runs.is_empty() {
+                continue;
+            }
+
+            let source = if let Some(x) = hubs.get(child_type_id) {
+                *x
+            } else {
+                continue;
+            };
+            if source == destination {
+                continue;
+            }
+
+            let quantity = parent
+                .runs
+                .iter()
+                .map(|x| (child_quantity * *x as f32).ceil() as u32)
+                .sum::<u32>();
+
+            *quantities
+                .entry((*child_type_id, source, destination))
+                .or_default() += quantity;
+        }
+    }
+
+    let mut transfers = quantities
+        .into_iter()
+        .filter(|(_, quantity)| *quantity > 0)
+        .filter_map(|((type_id, source, destination), quantity)| {
+            let item = tree.get(&type_id)?.item.clone();
+            // ships are moved packaged
+            let unit_volume = item
+                .repackaged
+                .map(|x| x as f64)
+                .unwrap_or(item.volume as f64);
+            let volume = unit_volume * quantity as f64;
+
+            Some(BuildEngineTransferResponse {
+                item,
+                quantity,
+                volume,
+                cost:        freight.cost(volume),
+                source,
+                destination,
+            })
+        })
+        .collect::<Vec<_>>();
+    transfers.sort_by(|a, b| {
+        a.item.name
+            .cmp(&b.item.name)
+            .then(a.source.cmp(&b.source))
+    });
+    transfers
+}
+
+#[cfg(test)]
+mod tests {
+    use starfoundry_lib_eve_gateway::Item;
+    use starfoundry_lib_industry::IndustryHubUuid;
+    use starfoundry_lib_types::TypeId;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    use super::hub_transfers;
+    use crate::industry::job_planner::models::{BlueprintTyp, DependencyBuildCost, DependencyTreeEntry};
+    use crate::project_group::service::Freight;
+
+    fn entry(
+        type_id:  i32,
+        typ:      BlueprintTyp,
+        runs:     Vec<u32>,
+        children: Vec<(i32, f32)>,
+    ) -> DependencyTreeEntry {
+        let item: Item = serde_json::from_value(serde_json::json!({
+                "type_id": type_id,
+                "category": { "category_id": 1, "name": "Category" },
+                "group": { "group_id": 1, "category_id": 1, "name": "Group" },
+                "volume": 10,
+                "name": format!("Item {type_id}"),
+                "meta_group": null,
+                "repackaged": null,
+            }))
+            .unwrap();
+
+        DependencyTreeEntry {
+            blueprint_type_id:  TypeId(type_id),
+            product_type_id:    TypeId(type_id),
+            needed:             0f32,
+            time:               0f32,
+            produces:           1,
+            runs,
+            children:           children.iter().map(|(x, y)| (TypeId(*x), *y)).collect(),
+            children_unbonused: HashMap::new(),
+            typ,
+            item,
+            stock:              0,
+            is_product:         false,
+            build_cost:         DependencyBuildCost::default(),
+            structure:          None,
+            bonus:              Vec::new(),
+        }
+    }
+
+    #[test]
+    fn intermediates_between_hubs() {
+        let reactions: IndustryHubUuid = Uuid::from_u128(1).into();
+        let capitals: IndustryHubUuid = Uuid::from_u128(2).into();
+
+        let tree = HashMap::from([
+            (TypeId(1), entry(1, BlueprintTyp::Blueprint, vec![2, 1], vec![(2, 1.5), (3, 4f32), (4, 1f32)])),
+            (TypeId(2), entry(2, BlueprintTyp::Reaction, vec![5], vec![])),
+            (TypeId(3), entry(3, BlueprintTyp::Material, vec![], vec![])),
+            (TypeId(4), entry(4, BlueprintTyp::Blueprint, vec![3], vec![])),
+        ]);
+        let hubs = HashMap::from([
+            (TypeId(1), capitals),
+            (TypeId(2), reactions),
+            (TypeId(4), capitals),
+        ]);
+
+        let transfers = hub_transfers(&tree, &hubs, Freight { rate: 100f64 });
+        assert_eq!(transfers.len(), 1);
+
+        let transfer = transfers.first().unwrap();
+        assert_eq!(transfer.item.type_id, TypeId(2));
+        // ceil(1.5 * 2) + ceil(1.5 * 1)
+        assert_eq!(transfer.quantity, 5);
+        assert_eq!(transfer.volume, 50f64);
+        assert_eq!(transfer.cost, 5_000f64);
+        assert_eq!(transfer.source, reactions);
+        assert_eq!(transfer.destination, capitals);
+    }
+
+    #[test]
+    fn components_from_stock_are_not_moved() {
+        let reactions: IndustryHubUuid = Uuid::from_u128(1).into();
+        let capitals: IndustryHubUuid = Uuid::from_u128(2).into();
+
+        let tree = HashMap::from([
+            (TypeId(1), entry(1, BlueprintTyp::Blueprint, vec![1], vec![(2, 1f32)])),
+            (TypeId(2), entry(2, BlueprintTyp::Reaction, vec![], vec![])),
+        ]);
+        let hubs = HashMap::from([
+            (TypeId(1), capitals),
+            (TypeId(2), reactions),
+        ]);
+
+        assert!(hub_transfers(&tree, &hubs, Freight::default()).is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use sqlx::PgPool;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::industry::{SensitivityRequest, SensitivityResponse, SensitivityVariationResult};
+use starfoundry_lib_market::{MarketApiClientOrder, MarketBulkRequest, MarketItem, MarketStrategy};
+use starfoundry_lib_types::TypeId;
+use std::collections::HashMap;
+
+use crate::{AppState, eve_gateway_api_client, market_api_client};
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, Unauthorized};
+use crate::industry::error::{IndustryError, Result};
+use crate::industry::job_planner::{BlueprintTyp, PlannerSettings, fetch_dependencies, fetch_system_index, planner_settings, summarize, vary_prices};
+use crate::project_group::permission::assert_read_access_check;
+use crate::project_group::service::list_industry_hubs;
+
+/// Sensitivity of a build plan
+///
+/// - Alternative route: `/latest/industry/calculation/sensitivity`
+/// - Alternative route: `/v1/industry/calculation/sensitivity`
+///
+/// ---
+///
+/// Calculates the products in the given industry hub, and calculates them
+/// again for every variation, changing one input at a time.
+/// Every variation reports its cost and time, and the difference to the
+/// baseline, e.g. to see if a better blueprint or rig pays off.
+///
+/// Nothing is stored.
+///
+/// ## Security
+/// - authenticated
+/// - project_group:read
+///
+#[utoipa::path(
+    post,
+    path = "/calculation/sensitivity",
+    tag = "Industry",
+    request_body = SensitivityRequest,
+    responses(
+        (
+            body = SensitivityResponse,
+            description = "Cost and time of the baseline and every variation",
+            status = OK,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:      ExtractIdentity,
+    State(state):  State<AppState>,
+    Json(request): Json<SensitivityRequest>,
+) -> Result<impl IntoResponse> {
+    assert_read_access_check(
+            &state.postgres,
+            request.project_group_id,
+            (&identity).into(),
+        )
+        .await?;
+
+    let response = sensitivity(
+            &state.postgres,
+            &identity,
+            request,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(response)
+        )
+        .into_response()
+    )
+}
+
+/// Runs the job planner for the baseline and every variation of the request
+///
+/// # Errors
+///
+/// - [IndustryError::InvalidRequest] if no products are given, or the
+///   industry hub does not belong to the project group
+///
+pub async fn sensitivity(
+    pool:     &PgPool,
+    identity: &ExtractIdentity,
+    request:  SensitivityRequest,
+) -> Result<SensitivityResponse> {
+    if request.products.is_empty() {
+        return Err(IndustryError::InvalidRequest(
+            "At least one product must be set.".into()
+        ));
+    }
+
+    let industry_hub = list_industry_hubs(
+            pool,
+            &eve_gateway_api_client()?,
+            identity.character_id,
+            request.project_group_id,
+        )
+        .await?
+        .into_iter()
+        .find(|x| x.id == request.industry_hub_id)
+        .ok_or(IndustryError::InvalidRequest(
+            "The industry hub is not part of the project group.".into()
+        ))?;
+
+    let settings = planner_settings(
+            pool,
+            request.project_group_id,
+            &request.products,
+            request.blacklist,
+            request.job_splitting,
+            request.blueprint_overwrite,
+        )
+        .await?;
+    let settings = PlannerSettings {
+        system_index: fetch_system_index(&industry_hub.structures).await?,
+        structures:   industry_hub.structures,
+        ..settings
+    };
+    let dependencies = fetch_dependencies(&request.products).await?;
+
+    let baseline = settings.run(&dependencies, &request.stocks);
+    let variations = request
+        .variations
+        .into_iter()
+        .map(|x| {
+            let result = settings
+                .vary(&x)
+                .run(&dependencies, &request.stocks);
+            (x, result)
+        })
+        .collect::<Vec<_>>();
+
+    // the prices are fetched once for all materials, so that every variation
+    // uses the same prices
+    let mut materials: HashMap<TypeId, f32> = HashMap::new();
+    for result in std::iter::once(&baseline).chain(variations.iter().map(|(_, x)| x)) {
+        for entry in result.tree.values().filter(|x| x.typ == BlueprintTyp::Material) {
+            let needed = materials.entry(entry.product_type_id).or_default();
+            *needed = needed.max(entry.needed);
+        }
+    }
+    let prices = if let Some(markets) = request.markets {
+        market_prices(materials, markets).await?
+    } else {
+        materials
+            .into_keys()
+            .map(|x| (x, settings.material_cost.get(&x).copied().unwrap_or_default()))
+            .collect::<HashMap<_, _>>()
+    };
+
+    let baseline = summarize(&baseline, &prices);
+    let variations = variations
+        .into_iter()
+        .map(|(variation, result)| {
+            let result = summarize(&result, &vary_prices(&prices, &variation));
+            SensitivityVariationResult {
+                difference: result.difference(&baseline),
+                variation,
+                result,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(SensitivityResponse {
+        baseline,
+        variations,
+    })
+}
+
+/// Unit price of the materials when bought with multibuy in the given
+/// markets, materials that are bought from multiple orders use the average
+async fn market_prices(
+    materials: HashMap<TypeId, f32>,
+    markets:   Vec<starfoundry_lib_types::StructureId>,
+) -> Result<HashMap<TypeId, f64>> {
+    let items = materials
+        .into_iter()
+        .filter(|(_, x)| *x > 0f32)
+        .map(|(type_id, quantity)| MarketItem {
+            quantity: quantity.ceil() as i32,
+            type_id,
+        })
+        .collect::<Vec<_>>();
+
+    let market_entries = market_api_client()?
+        .bulk_latest_orders(MarketBulkRequest {
+            strategy:   MarketStrategy::MultiBuy,
+            markets,
+            item_list:  Some(items),
+            ..Default::default()
+        })
+        .await?;
+
+    let mut totals: HashMap<TypeId, (f64, f64)> = HashMap::new();
+    for market_entry in market_entries {
+        let (quantity, cost) = totals.entry(market_entry.item.type_id).or_default();
+        *quantity += market_entry.quantity as f64;
+        *cost += market_entry.quantity as f64 * market_entry.price;
+    }
+
+    Ok(
+        totals
+            .into_iter()
+            .filter(|(_, (quantity, _))| *quantity > 0f64)
+            .map(|(type_id, (quantity, cost))| (type_id, cost / quantity))
+            .collect::<HashMap<_, _>>()
+    )
+}
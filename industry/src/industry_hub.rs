@@ -3,6 +3,7 @@ mod create;
 mod delete;
 mod error;
 mod fetch;
+mod list_audit_events;
 mod list;
 mod permission;
 mod update;
@@ -29,6 +30,11 @@ pub fn routes(
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
 
+    let list_audit_events = OpenApiRouter::new()
+        .routes(routes!(list_audit_events::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
     let create = OpenApiRouter::new()
         .routes(routes!(create::api));
 
@@ -50,6 +56,7 @@ pub fn routes(
     OpenApiRouter::new()
         .merge(list)
         .merge(fetch)
+        .merge(list_audit_events)
         .merge(create)
         .merge(update)
         .merge(clone)
@@ -45,6 +45,8 @@ pub enum IndustryHubError {
     #[error(transparent)]
     JsonExtractorRejection(#[from] JsonRejection),
     #[error(transparent)]
+    AuditError(Box<crate::audit::AuditError>),
+    #[error(transparent)]
     StructureError(#[from] StructureError),
     #[error(transparent)]
     GatewayLibError(Box<starfoundry_lib_gateway::error::Error>),
@@ -117,3 +119,4 @@ impl IntoResponse for IndustryHubError {
 }
 
 boxed_from!(IndustryHubError::GatewayLibError, starfoundry_lib_gateway::error::Error);
+boxed_from!(IndustryHubError::AuditError, crate::audit::AuditError);
@@ -2,6 +2,7 @@ use sqlx::PgPool;
 use starfoundry_lib_industry::IndustryHubUuid;
 use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::industry_hub::error::Result;
 use crate::industry_hub::error::IndustryHubError;
 
@@ -108,6 +109,13 @@ pub async fn clone(
         .await
         .map_err(IndustryHubError::CreateIndustryHub)?;
 
+    AuditRecord::created(
+            &mut transaction,
+            character_id,
+            AuditTarget::IndustryHub(cloned_id),
+        )
+        .await?;
+
     transaction
         .commit()
         .await
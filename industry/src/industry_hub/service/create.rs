@@ -4,6 +4,7 @@ use starfoundry_lib_industry::IndustryHubUuid;
 use starfoundry_lib_types::CharacterId;
 use utoipa::ToSchema;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::industry_hub::error::Result;
 use crate::industry_hub::error::IndustryHubError;
 
@@ -14,7 +15,12 @@ pub async fn create(
 ) -> Result<IndustryHubUuid> {
     info.valid()?;
 
-    sqlx::query!("
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(IndustryHubError::TransactionError)?;
+
+    let industry_hub_id = sqlx::query!("
             INSERT INTO industry_hub
             (
                 owner,
@@ -26,10 +32,24 @@ pub async fn create(
             *character_id,
             info.name,
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *transaction)
         .await
         .map(|x| IndustryHubUuid::new(x.id))
-        .map_err(IndustryHubError::CreateIndustryHub)
+        .map_err(IndustryHubError::CreateIndustryHub)?;
+
+    AuditRecord::created(
+            &mut transaction,
+            character_id,
+            AuditTarget::IndustryHub(industry_hub_id),
+        )
+        .await?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(IndustryHubError::TransactionError)?;
+
+    Ok(industry_hub_id)
 }
 
 #[cfg(test)]
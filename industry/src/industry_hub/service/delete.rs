@@ -1,7 +1,9 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::IndustryHubUuid;
 use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::industry_hub::error::{Result, IndustryHubError};
 
 pub async fn delete(
@@ -9,19 +11,39 @@ pub async fn delete(
     character_id:    CharacterId,
     industry_hub_id: IndustryHubUuid,
 ) -> Result<IndustryHubUuid> {
-    sqlx::query!("
-        DELETE FROM industry_hub
-        WHERE owner = $1
-            AND id = $2
-        RETURNING id
-    ",
-        *character_id,
-        *industry_hub_id,
-    )
-    .fetch_one(pool)
-    .await
-    .map(|x| IndustryHubUuid::new(x.id))
-    .map_err(|e| IndustryHubError::DeleteIndustryHub(e, industry_hub_id))
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(IndustryHubError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Delete,
+            AuditTarget::IndustryHub(industry_hub_id),
+        )
+        .await?;
+
+    let industry_hub_id = sqlx::query!("
+            DELETE FROM industry_hub
+            WHERE owner = $1
+                AND id = $2
+            RETURNING id
+        ",
+            *character_id,
+            *industry_hub_id,
+        )
+        .fetch_one(&mut *transaction)
+        .await
+        .map(|x| IndustryHubUuid::new(x.id))
+        .map_err(|e| IndustryHubError::DeleteIndustryHub(e, industry_hub_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(IndustryHubError::TransactionError)?;
+
+    Ok(industry_hub_id)
 }
 
 #[cfg(test)]
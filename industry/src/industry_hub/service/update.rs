@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::industry_hub::IndustryHubShare;
 use starfoundry_lib_industry::IndustryHubUuid;
 use starfoundry_lib_types::CharacterId;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::industry_hub::error::{Result, IndustryHubError};
 
 pub async fn update(
@@ -18,6 +20,13 @@ pub async fn update(
         .begin()
         .await
         .map_err(IndustryHubError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::IndustryHub(industry_hub_uuid),
+        )
+        .await?;
 
     sqlx::query!("
             UPDATE industry_hub
@@ -98,6 +107,7 @@ pub async fn update(
         .await
         .map_err(|e| IndustryHubError::UpdateIndustryHub(e, industry_hub_uuid))?;
 
+    audit.finish(&mut transaction).await?;
     transaction
         .commit()
         .await
@@ -1,6 +1,11 @@
+mod claim_job_assignment;
 mod create_job_assignment;
 mod error;
+mod list_created_job_assignments;
 mod list_job_assignments;
+mod payout_job_assignment;
+mod release_job_assignment;
+mod revoke_job_assignment;
 mod update_job_assignment;
 mod service;
 
@@ -10,21 +15,40 @@ use utoipa_axum::routes;
 
 use crate::AppState;
 
-// TODO: check write permission
 pub fn routes() -> OpenApiRouter<AppState> {
     let create_job_assignment = OpenApiRouter::new()
         .routes(routes!(create_job_assignment::api));
 
+    let list_created_job_assignments = OpenApiRouter::new()
+        .routes(routes!(list_created_job_assignments::api));
+
     let list_job_assignments = OpenApiRouter::new()
         .routes(routes!(list_job_assignments::api));
 
+    let revoke_job_assignment = OpenApiRouter::new()
+        .routes(routes!(revoke_job_assignment::api));
+
     let update_job_assignment = OpenApiRouter::new()
         .routes(routes!(update_job_assignment::api));
 
+    let claim_job_assignment = OpenApiRouter::new()
+        .routes(routes!(claim_job_assignment::api));
+
+    let release_job_assignment = OpenApiRouter::new()
+        .routes(routes!(release_job_assignment::api));
+
+    let payout_job_assignment = OpenApiRouter::new()
+        .routes(routes!(payout_job_assignment::api));
+
     OpenApiRouter::new()
         .merge(create_job_assignment)
+        .merge(list_created_job_assignments)
         .merge(list_job_assignments)
+        .merge(revoke_job_assignment)
         .merge(update_job_assignment)
+        .merge(claim_job_assignment)
+        .merge(release_job_assignment)
+        .merge(payout_job_assignment)
 }
 
 starfoundry_uuid!(JobAssignmentUuid, "JobAssignmentUuid");
@@ -0,0 +1,66 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectJobUuid;
+
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity};
+use crate::AppState;
+use crate::job_assignments::JobAssignmentUuid;
+use crate::job_assignments::error::Result;
+use crate::job_assignments::service::claim_job_assignment;
+
+/// Claim Job Assignment
+/// 
+/// - Alternative route: `/latest/job-assignments/{JobAssignmentUuid}/{ProjectJobUuid}/claim`
+/// - Alternative route: `/v1/job-assignments/{JobAssignmentUuid}/{ProjectJobUuid}/claim`
+/// 
+/// ---
+/// 
+/// Claims the job for the character, so that no other builder can start it.
+/// Claiming a job that is already claimed by the character is a no-op.
+/// 
+/// ## Security
+/// - authenticated
+/// - the assignment is neither revoked nor expired
+/// - the job is not claimed by another character
+/// 
+#[utoipa::path(
+    put,
+    path = "/{JobAssignmentUuid}/{ProjectJobUuid}/claim",
+    tag = "job_assignment",
+    params(
+        JobAssignmentUuid,
+        ProjectJobUuid,
+    ),
+    responses(
+        (
+            description = "The job was claimed",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:                       ExtractIdentity,
+    State(state):                   State<AppState>,
+    Path((assignment_id, job_id)):  Path<(JobAssignmentUuid, ProjectJobUuid)>,
+) -> Result<impl IntoResponse> {
+    claim_job_assignment(
+        &state.postgres,
+        identity.character_id,
+        assignment_id,
+        job_id,
+    ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
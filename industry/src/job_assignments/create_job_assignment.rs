@@ -3,12 +3,13 @@ use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
 use serde::Serialize;
+use starfoundry_lib_gateway::ExtractIdentity;
 use utoipa::ToSchema;
 
-use crate::api_docs::{BadRequest, InternalServerError, Unauthorized};
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, Unauthorized, UnprocessableEntity};
 use crate::AppState;
 use crate::job_assignments::error::Result;
-use crate::job_assignments::service::{CreateProjectJobAssignment, create_job_assignment};
+use crate::job_assignments::service::{CreateJobAssignment, create_job_assignment};
 use crate::job_assignments::JobAssignmentUuid;
 
 /// Create Job Assignment
@@ -18,13 +19,20 @@ use crate::job_assignments::JobAssignmentUuid;
 /// 
 /// ---
 /// 
-/// Creates a new job assignment
+/// Creates a new job assignment link.
+/// Builders that open the link can claim and start the jobs, the agreed
+/// payment of a job is added to the misc costs of the project once the job
+/// is done.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_project for all projects of the jobs
 /// 
 #[utoipa::path(
     post,
     path = "/",
     tag = "job_assignment",
-    request_body = Vec<CreateProjectJobAssignmentResponse>,
+    request_body = CreateJobAssignment,
     responses(
         (
             body = CreateProjectJobAssignmentResponse,
@@ -33,6 +41,8 @@ use crate::job_assignments::JobAssignmentUuid;
         ),
         BadRequest,
         Unauthorized,
+        Forbidden,
+        UnprocessableEntity,
         InternalServerError,
     ),
     security(
@@ -40,12 +50,14 @@ use crate::job_assignments::JobAssignmentUuid;
     ),
 )]
 pub async fn api(
-    State(state):   State<AppState>,
-    Json(jobs):     Json<Vec<CreateProjectJobAssignment>>,
+    identity:         ExtractIdentity,
+    State(state):     State<AppState>,
+    Json(assignment): Json<CreateJobAssignment>,
 ) -> Result<impl IntoResponse> {
     let id = create_job_assignment(
             &state.postgres,
-            jobs,
+            (&identity).into(),
+            assignment,
         ).await?;
 
     Ok(
@@ -1,32 +1,104 @@
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::{IntoResponse, Response};
-use starfoundry_lib_gateway::ErrorResponse;
+use starfoundry_lib_gateway::{ErrorResponse, boxed_from};
+use starfoundry_lib_industry::ProjectJobUuid;
+use starfoundry_lib_types::CharacterId;
 use thiserror::Error;
 
+use crate::job_assignments::JobAssignmentUuid;
+use crate::project_group::ProjectGroupError;
+
 pub type Result<T, E = JobAssignmentError> = std::result::Result<T, E>;
 
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum JobAssignmentError {
+    #[error("the character '{1}' is not allowed to access '{0}'")]
+    Forbidden(JobAssignmentUuid, CharacterId),
+    #[error("job assignment with id '{0}' not found")]
+    NotFound(JobAssignmentUuid),
+    #[error("job '{1}' not found in job assignment '{0}'")]
+    JobNotFound(JobAssignmentUuid, ProjectJobUuid),
+    #[error("job '{1}' of job assignment '{0}' is claimed by another character")]
+    AlreadyClaimed(JobAssignmentUuid, ProjectJobUuid),
+    #[error("validation error: '{0}'")]
+    ValidationError(String),
+
     #[error("error while listing jobs, error: '{0}'")]
     List(sqlx::Error),
+    #[error("error while fetching job assignment '{1}', error: '{0}'")]
+    Fetch(sqlx::Error, JobAssignmentUuid),
 
     #[error("error while creating job assignment, error: '{0}'")]
     Create(sqlx::Error),
 
     #[error("error while updating job assignment, error: '{0}'")]
     Update(sqlx::Error),
+    #[error("error while claiming job '{2}' of job assignment '{1}', error: '{0}'")]
+    Claim(sqlx::Error, JobAssignmentUuid, ProjectJobUuid),
+    #[error("error while revoking job assignment '{1}', error: '{0}'")]
+    Revoke(sqlx::Error, JobAssignmentUuid),
+    #[error("error while updating payout of job '{2}' of job assignment '{1}', error: '{0}'")]
+    Payout(sqlx::Error, JobAssignmentUuid, ProjectJobUuid),
+
+    #[error("transaction error, '{0}'")]
+    TransactionError(sqlx::Error),
 
     #[error(transparent)]
     EveGatewayLibError(#[from] starfoundry_lib_eve_gateway::Error),
+    #[error(transparent)]
+    ProjectGroupError(Box<ProjectGroupError>),
 }
 
 impl IntoResponse for JobAssignmentError {
-    // for future extensions this is fine
-    #[allow(clippy::match_single_binding)]
     fn into_response(self) -> Response {
         match self {
+            Self::Forbidden(_, _) => {
+                tracing::info!("{}", self.to_string());
+                (
+                    StatusCode::FORBIDDEN,
+                    Json(
+                        ErrorResponse {
+                            error: "FORBIDDEN".into(),
+                            description: "You are not allowed this resource".into(),
+                        }
+                    )
+                ).into_response()
+            },
+
+            Self::NotFound(_) |
+            Self::JobNotFound(_, _) => {
+                tracing::info!("{}", self.to_string());
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(
+                        ErrorResponse {
+                            error: "NOT_FOUND".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+
+            Self::AlreadyClaimed(_, _) |
+            Self::ValidationError(_) => {
+                tracing::info!("{}", self.to_string());
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    Json(
+                        ErrorResponse {
+                            error: "UNPROCESSABLE_ENTITY".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+
+            Self::ProjectGroupError(e) => {
+                ProjectGroupError::into_response(*e)
+            },
+
             _ => {
                 tracing::error!("{}", self.to_string());
                 (
@@ -43,3 +115,5 @@ impl IntoResponse for JobAssignmentError {
         .into_response()
     }
 }
+
+boxed_from!(JobAssignmentError::ProjectGroupError, ProjectGroupError);
@@ -0,0 +1,72 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::AppState;
+use crate::api_docs::{InternalServerError, Unauthorized};
+use crate::job_assignments::error::Result;
+use crate::job_assignments::service::{JobAssignment, list_created_job_assignments};
+
+/// List Created Job Assignments
+/// 
+/// - Alternative route: `/latest/job-assignments`
+/// - Alternative route: `/v1/job-assignments`
+/// 
+/// ---
+/// 
+/// Lists all assignments the character created, including the progress of
+/// the jobs and the outstanding payouts.
+/// 
+/// ## Security
+/// - authenticated
+/// 
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "job_assignment",
+    responses(
+        (
+            body = Vec<JobAssignment>,
+            description = "All assignments the character created",
+            status = OK,
+        ),
+        (
+            description = "The character has not created any assignments",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse> {
+    let data = list_created_job_assignments(
+            &state.postgres,
+            identity.character_id,
+        ).await?;
+
+    if data.is_empty() {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(data),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(data),
+            )
+            .into_response()
+        )
+    }
+}
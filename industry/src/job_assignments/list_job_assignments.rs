@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 
 use crate::{AppState, eve_gateway_api_client};
 use crate::api_docs::{BadRequest, InternalServerError, NotFound, Unauthorized};
@@ -16,6 +17,12 @@ use crate::job_assignments::JobAssignmentUuid;
 /// 
 /// ---
 /// 
+/// Lists all jobs of the assignment grouped by project.
+/// Revoked or expired assignments are only visible to their creator.
+/// 
+/// ## Security
+/// - authenticated
+/// 
 #[utoipa::path(
     get,
     path = "/{JobAssignmentUuid}",
@@ -29,18 +36,27 @@ use crate::job_assignments::JobAssignmentUuid;
             description = "List all jobs for the given project",
             status = OK,
         ),
+        (
+            description = "The assignment has no jobs",
+            status = NO_CONTENT,
+        ),
         NotFound,
         BadRequest,
         Unauthorized,
         InternalServerError,
     ),
+    security(
+        ("api_key" = [])
+    ),
 )]
 pub async fn api(
+    identity:               ExtractIdentity,
     State(state):           State<AppState>,
     Path(assignment_id):    Path<JobAssignmentUuid>,
 ) -> Result<impl IntoResponse> {
     let data = list_job_assignments(
             &state.postgres,
+            identity.character_id,
             assignment_id,
             &eve_gateway_api_client()?,
         ).await?;
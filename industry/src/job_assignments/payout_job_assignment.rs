@@ -0,0 +1,64 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectJobUuid;
+
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity};
+use crate::AppState;
+use crate::job_assignments::JobAssignmentUuid;
+use crate::job_assignments::error::Result;
+use crate::job_assignments::service::payout_job_assignment;
+
+/// Payout Job Assignment
+/// 
+/// - Alternative route: `/latest/job-assignments/{JobAssignmentUuid}/{ProjectJobUuid}/payout`
+/// - Alternative route: `/v1/job-assignments/{JobAssignmentUuid}/{ProjectJobUuid}/payout`
+/// 
+/// ---
+/// 
+/// Confirms that the builder of a done job received the agreed payment.
+/// 
+/// ## Security
+/// - authenticated
+/// - the character created the assignment
+/// 
+#[utoipa::path(
+    put,
+    path = "/{JobAssignmentUuid}/{ProjectJobUuid}/payout",
+    tag = "job_assignment",
+    params(
+        JobAssignmentUuid,
+        ProjectJobUuid,
+    ),
+    responses(
+        (
+            description = "The payout was confirmed",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:                       ExtractIdentity,
+    State(state):                   State<AppState>,
+    Path((assignment_id, job_id)):  Path<(JobAssignmentUuid, ProjectJobUuid)>,
+) -> Result<impl IntoResponse> {
+    payout_job_assignment(
+        &state.postgres,
+        identity.character_id,
+        assignment_id,
+        job_id,
+    ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
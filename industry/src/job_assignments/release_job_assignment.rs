@@ -0,0 +1,66 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectJobUuid;
+
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity};
+use crate::AppState;
+use crate::job_assignments::JobAssignmentUuid;
+use crate::job_assignments::error::Result;
+use crate::job_assignments::service::release_job_assignment;
+
+/// Release Job Assignment
+/// 
+/// - Alternative route: `/latest/job-assignments/{JobAssignmentUuid}/{ProjectJobUuid}/claim`
+/// - Alternative route: `/v1/job-assignments/{JobAssignmentUuid}/{ProjectJobUuid}/claim`
+/// 
+/// ---
+/// 
+/// Releases the claim of a job that is not started yet, so that another
+/// builder can claim it.
+/// 
+/// ## Security
+/// - authenticated
+/// - the job is claimed by the character or the character created the
+///   assignment
+/// 
+#[utoipa::path(
+    delete,
+    path = "/{JobAssignmentUuid}/{ProjectJobUuid}/claim",
+    tag = "job_assignment",
+    params(
+        JobAssignmentUuid,
+        ProjectJobUuid,
+    ),
+    responses(
+        (
+            description = "The claim was released",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:                       ExtractIdentity,
+    State(state):                   State<AppState>,
+    Path((assignment_id, job_id)):  Path<(JobAssignmentUuid, ProjectJobUuid)>,
+) -> Result<impl IntoResponse> {
+    release_job_assignment(
+        &state.postgres,
+        identity.character_id,
+        assignment_id,
+        job_id,
+    ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
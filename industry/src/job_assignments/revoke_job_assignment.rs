@@ -0,0 +1,62 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::AppState;
+use crate::job_assignments::JobAssignmentUuid;
+use crate::job_assignments::error::Result;
+use crate::job_assignments::service::revoke_job_assignment;
+
+/// Revoke Job Assignment
+/// 
+/// - Alternative route: `/latest/job-assignments/{JobAssignmentUuid}`
+/// - Alternative route: `/v1/job-assignments/{JobAssignmentUuid}`
+/// 
+/// ---
+/// 
+/// Revokes the assignment link, builders can no longer open it or claim
+/// jobs.
+/// Pending payouts of done jobs are kept.
+/// 
+/// ## Security
+/// - authenticated
+/// - the character created the assignment
+/// 
+#[utoipa::path(
+    delete,
+    path = "/{JobAssignmentUuid}",
+    tag = "job_assignment",
+    params(
+        JobAssignmentUuid,
+    ),
+    responses(
+        (
+            description = "The assignment was revoked",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:               ExtractIdentity,
+    State(state):           State<AppState>,
+    Path(assignment_id):    Path<JobAssignmentUuid>,
+) -> Result<impl IntoResponse> {
+    revoke_job_assignment(
+        &state.postgres,
+        identity.character_id,
+        assignment_id,
+    ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
@@ -1,7 +1,17 @@
+mod claim_job_assignment;
 mod create_job_assignment;
+mod fetch_job_assignment;
+mod list_created_job_assignments;
 mod list_job_assignments;
+mod payout_job_assignment;
+mod revoke_job_assignment;
 mod update_job_assignment;
 
+pub use self::claim_job_assignment::*;
 pub use self::create_job_assignment::*;
+pub use self::fetch_job_assignment::*;
+pub use self::list_created_job_assignments::*;
 pub use self::list_job_assignments::*;
+pub use self::payout_job_assignment::*;
+pub use self::revoke_job_assignment::*;
 pub use self::update_job_assignment::*;
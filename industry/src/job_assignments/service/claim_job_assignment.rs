@@ -0,0 +1,238 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::ProjectJobUuid;
+use starfoundry_lib_types::CharacterId;
+
+use crate::job_assignments::JobAssignmentUuid;
+use crate::job_assignments::error::{JobAssignmentError, Result};
+use crate::job_assignments::service::fetch_job_assignment;
+
+/// Claims the job of the assignment for the character.
+///
+/// A job can only be claimed by a single character, claiming an already
+/// claimed job again is a no-op.
+///
+pub async fn claim_job_assignment(
+    pool:          &PgPool,
+    character_id:  CharacterId,
+    assignment_id: JobAssignmentUuid,
+    job_id:        ProjectJobUuid,
+) -> Result<()> {
+    fetch_job_assignment(pool, assignment_id)
+        .await?
+        .assert_active()?;
+
+    let claimed_by = sqlx::query!("
+            SELECT claimed_by
+            FROM project_job_assignment
+            WHERE id = $1
+            AND job_id = $2
+        ",
+            *assignment_id,
+            *job_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| JobAssignmentError::Claim(e, assignment_id, job_id))?
+        .ok_or(JobAssignmentError::JobNotFound(assignment_id, job_id))?
+        .claimed_by;
+
+    match claimed_by {
+        Some(x) if x == *character_id => return Ok(()),
+        Some(_) => return Err(JobAssignmentError::AlreadyClaimed(assignment_id, job_id)),
+        None => {},
+    }
+
+    let result = sqlx::query!("
+            UPDATE project_job_assignment
+            SET
+                claimed_by = $3,
+                claimed_at = NOW()
+            WHERE id = $1
+            AND job_id = $2
+            AND claimed_by IS NULL
+        ",
+            *assignment_id,
+            *job_id,
+            *character_id,
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| JobAssignmentError::Claim(e, assignment_id, job_id))?;
+
+    // another character was faster
+    if result.rows_affected() == 0 {
+        return Err(JobAssignmentError::AlreadyClaimed(assignment_id, job_id));
+    }
+
+    Ok(())
+}
+
+/// Releases the claim of a job, either by the builder that claimed it or by
+/// the creator of the assignment.
+///
+/// Jobs that are already started can't be released.
+///
+pub async fn release_job_assignment(
+    pool:          &PgPool,
+    character_id:  CharacterId,
+    assignment_id: JobAssignmentUuid,
+    job_id:        ProjectJobUuid,
+) -> Result<()> {
+    let assignment = fetch_job_assignment(pool, assignment_id).await?;
+    assignment.assert_visible(character_id)?;
+
+    let entry = sqlx::query!("
+            SELECT
+                claimed_by,
+                started
+            FROM project_job_assignment
+            WHERE id = $1
+            AND job_id = $2
+        ",
+            *assignment_id,
+            *job_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| JobAssignmentError::Claim(e, assignment_id, job_id))?
+        .ok_or(JobAssignmentError::JobNotFound(assignment_id, job_id))?;
+
+    let claimed_by = if let Some(x) = entry.claimed_by {
+        x
+    } else {
+        return Ok(());
+    };
+
+    if claimed_by != *character_id && !assignment.is_creator(character_id) {
+        return Err(JobAssignmentError::Forbidden(assignment_id, character_id));
+    }
+
+    if entry.started {
+        return Err(JobAssignmentError::ValidationError(
+            "started jobs can't be released".into()
+        ));
+    }
+
+    sqlx::query!("
+            UPDATE project_job_assignment
+            SET
+                claimed_by = NULL,
+                claimed_at = NULL
+            WHERE id = $1
+            AND job_id = $2
+            AND started = FALSE
+        ",
+            *assignment_id,
+            *job_id,
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| JobAssignmentError::Claim(e, assignment_id, job_id))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+    use uuid::Uuid;
+
+    use crate::job_assignments::error::JobAssignmentError;
+    use crate::job_assignments::service::{claim_job_assignment, create_job_assignment, release_job_assignment, CreateJobAssignment};
+    use crate::project_group::permission::MemberIdentity;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../../project/fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn claim_is_exclusive(
+        pool: PgPool,
+    ) {
+        let job = sqlx::query!("
+                SELECT id, project_id
+                FROM project_job
+                WHERE project_id = '00000000-0000-0000-0000-000000000102'
+            ")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        let assignment: CreateJobAssignment = serde_json::from_value(serde_json::json!({
+                "jobs": [{
+                    "project_id": job.project_id,
+                    "job_id": job.id,
+                    "payment": 1000
+                }]
+            }))
+            .unwrap();
+        let assignment_id = create_job_assignment(
+                &pool,
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                assignment,
+            )
+            .await
+            .unwrap();
+
+        claim_job_assignment(&pool, CharacterId(10), assignment_id, job.id.into())
+            .await
+            .unwrap();
+        // claiming again is fine
+        claim_job_assignment(&pool, CharacterId(10), assignment_id, job.id.into())
+            .await
+            .unwrap();
+
+        let result = claim_job_assignment(&pool, CharacterId(11), assignment_id, job.id.into()).await;
+        assert!(matches!(result, Err(JobAssignmentError::AlreadyClaimed(_, _))));
+
+        let result = release_job_assignment(&pool, CharacterId(11), assignment_id, job.id.into()).await;
+        assert!(matches!(result, Err(JobAssignmentError::Forbidden(_, _))));
+
+        // the creator can release the claim
+        release_job_assignment(&pool, CharacterId(1), assignment_id, job.id.into())
+            .await
+            .unwrap();
+        claim_job_assignment(&pool, CharacterId(11), assignment_id, job.id.into())
+            .await
+            .unwrap();
+
+        let result = claim_job_assignment(&pool, CharacterId(10), assignment_id, Uuid::default().into()).await;
+        assert!(matches!(result, Err(JobAssignmentError::JobNotFound(_, _))));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../../project/fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn create_requires_write_access(
+        pool: PgPool,
+    ) {
+        let job = sqlx::query!("
+                SELECT id, project_id
+                FROM project_job
+                WHERE project_id = '00000000-0000-0000-0000-000000000102'
+            ")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        let assignment: CreateJobAssignment = serde_json::from_value(serde_json::json!({
+                "jobs": [{
+                    "project_id": job.project_id,
+                    "job_id": job.id
+                }]
+            }))
+            .unwrap();
+        let result = create_job_assignment(
+                &pool,
+                MemberIdentity::new(CharacterId(2), CorporationId(2), None),
+                assignment,
+            )
+            .await;
+        assert!(matches!(result, Err(JobAssignmentError::ProjectGroupError(_))));
+    }
+}
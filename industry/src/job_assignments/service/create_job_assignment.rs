@@ -1,52 +1,192 @@
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use sqlx::PgPool;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
 use starfoundry_lib_industry::{ProjectJobUuid, ProjectUuid};
+use std::collections::{HashMap, HashSet};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::job_assignments::error::{JobAssignmentError, Result};
 use crate::job_assignments::JobAssignmentUuid;
+use crate::project_group::permission::{assert_write_access_check, MemberIdentity};
 
+/// Creates a new assignment link owned by the character.
+///
+/// The character requires write access to the projects of all given jobs.
+///
 pub async fn create_job_assignment(
-    pool:         &PgPool,
-    project_info: Vec<CreateProjectJobAssignment>,
+    pool:       &PgPool,
+    identity:   MemberIdentity,
+    assignment: CreateJobAssignment,
 ) -> Result<JobAssignmentUuid> {
-    let id = Uuid::now_v7();
+    assignment.validate()?;
+
     let mut project_ids = Vec::new();
     let mut job_ids = Vec::new();
+    let mut payments = Vec::new();
 
-    project_info
+    assignment
+        .jobs
         .iter()
         .for_each(|x| {
             project_ids.push(*x.project_id);
             job_ids.push(*x.job_id);
+            payments.push(x.payment);
         });
 
+    let jobs = sqlx::query!("
+            SELECT
+                pj.id,
+                pj.project_id,
+                p.project_group_id
+            FROM project_job pj
+            JOIN project p ON p.id = pj.project_id
+            WHERE pj.id = ANY($1)
+        ",
+            &job_ids,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(JobAssignmentError::Create)?
+        .into_iter()
+        .map(|x| (x.id, (x.project_id, x.project_group_id)))
+        .collect::<HashMap<_, _>>();
+
+    let mut project_group_ids = HashSet::new();
+    for entry in assignment.jobs.iter() {
+        match jobs.get(&*entry.job_id) {
+            Some((project_id, project_group_id)) if *project_id == *entry.project_id => {
+                project_group_ids.insert(*project_group_id);
+            },
+            _ => return Err(JobAssignmentError::ValidationError(
+                format!("job '{}' is not part of project '{}'", entry.job_id, entry.project_id)
+            )),
+        }
+    }
+
+    for project_group_id in project_group_ids {
+        assert_write_access_check(
+                pool,
+                project_group_id.into(),
+                identity,
+                ProjectGroupPermissionCode::WriteProject,
+            )
+            .await?;
+    }
+
+    let id = Uuid::now_v7();
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(JobAssignmentError::TransactionError)?;
+
+    sqlx::query!("
+            INSERT INTO job_assignment
+            (
+                id,
+                creator_id,
+                expires_at
+            )
+            VALUES ($1, $2, $3)
+        ",
+            id,
+            *identity.character_id,
+            assignment.expires_at,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(JobAssignmentError::Create)?;
+
     sqlx::query!(r#"
             INSERT INTO project_job_assignment
             (
                 id,
                 project_id,
-                job_id
+                job_id,
+                payment
             )
             SELECT $1, * FROM UNNEST(
                 $2::UUID[],
-                $3::UUID[]
+                $3::UUID[],
+                $4::DOUBLE PRECISION[]
             )
         "#,
             id,
             &project_ids,
             &job_ids,
+            &payments as _,
         )
-        .execute(pool)
+        .execute(&mut *transaction)
         .await
         .map_err(JobAssignmentError::Create)?;
 
+    transaction
+        .commit()
+        .await
+        .map_err(JobAssignmentError::TransactionError)?;
+
     Ok(id.into())
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(
+    example = json!({
+        "expires_at": "2026-11-01T00:00:00Z",
+        "jobs": [{
+            "project_id": "019a0e27-5e9c-7a53-8c52-e4ecae0b12f4",
+            "job_id": "019a0e27-5e9c-7a53-8c52-e4ecb1ec0a77",
+            "payment": 15000000
+        }]
+    })
+)]
+pub struct CreateJobAssignment {
+    /// After the date the link can no longer be opened or claimed, if not set
+    /// the link is valid until it is revoked
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+    pub jobs:       Vec<CreateProjectJobAssignment>,
+}
+
+impl CreateJobAssignment {
+    pub fn validate(&self) -> Result<()> {
+        if self.jobs.is_empty() {
+            return Err(JobAssignmentError::ValidationError(
+                "at least one job is required".into()
+            ));
+        }
+
+        if let Some(x) = self.expires_at && x <= Utc::now() {
+            return Err(JobAssignmentError::ValidationError(
+                "the expire date must be in the future".into()
+            ));
+        }
+
+        let mut job_ids = HashSet::new();
+        for entry in self.jobs.iter() {
+            if !job_ids.insert(entry.job_id) {
+                return Err(JobAssignmentError::ValidationError(
+                    format!("job '{}' is added multiple times", entry.job_id)
+                ));
+            }
+
+            if let Some(x) = entry.payment && (!x.is_finite() || x < 0f64) {
+                return Err(JobAssignmentError::ValidationError(
+                    format!("payment of job '{}' must be positive", entry.job_id)
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateProjectJobAssignment {
     project_id: ProjectUuid,
     job_id:     ProjectJobUuid,
+    /// Agreed payment for building the job, added to the misc costs of the
+    /// project once the job is done
+    #[serde(default)]
+    payment:    Option<f64>,
 }
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use starfoundry_lib_types::CharacterId;
+
+use crate::job_assignments::JobAssignmentUuid;
+use crate::job_assignments::error::{JobAssignmentError, Result};
+
+/// Header of a job assignment link
+#[derive(Clone, Debug)]
+pub struct JobAssignmentHeader {
+    pub id:         JobAssignmentUuid,
+    pub creator_id: CharacterId,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl JobAssignmentHeader {
+    /// An assignment is active as long as it is neither revoked nor expired
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none() &&
+        self.expires_at.map(|x| x > Utc::now()).unwrap_or(true)
+    }
+
+    pub fn is_creator(
+        &self,
+        character_id: CharacterId,
+    ) -> bool {
+        self.creator_id == character_id
+    }
+
+    /// Only the creator can access inactive assignments, for everybody else
+    /// they don't exist anymore
+    pub fn assert_visible(
+        &self,
+        character_id: CharacterId,
+    ) -> Result<()> {
+        if self.is_active() || self.is_creator(character_id) {
+            Ok(())
+        } else {
+            Err(JobAssignmentError::NotFound(self.id))
+        }
+    }
+
+    pub fn assert_active(&self) -> Result<()> {
+        if self.is_active() {
+            Ok(())
+        } else {
+            Err(JobAssignmentError::NotFound(self.id))
+        }
+    }
+
+    pub fn assert_creator(
+        &self,
+        character_id: CharacterId,
+    ) -> Result<()> {
+        if self.is_creator(character_id) {
+            Ok(())
+        } else {
+            Err(JobAssignmentError::Forbidden(self.id, character_id))
+        }
+    }
+}
+
+pub async fn fetch_job_assignment(
+    pool:          &PgPool,
+    assignment_id: JobAssignmentUuid,
+) -> Result<JobAssignmentHeader> {
+    sqlx::query!("
+            SELECT
+                id,
+                creator_id,
+                expires_at,
+                revoked_at
+            FROM job_assignment
+            WHERE id = $1
+        ",
+            *assignment_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| JobAssignmentError::Fetch(e, assignment_id))?
+        .map(|x| JobAssignmentHeader {
+            id:         x.id.into(),
+            creator_id: x.creator_id.into(),
+            expires_at: x.expires_at,
+            revoked_at: x.revoked_at,
+        })
+        .ok_or(JobAssignmentError::NotFound(assignment_id))
+}
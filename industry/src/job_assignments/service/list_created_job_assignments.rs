@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use starfoundry_lib_types::CharacterId;
+use utoipa::ToSchema;
+
+use crate::job_assignments::JobAssignmentUuid;
+use crate::job_assignments::error::{JobAssignmentError, Result};
+
+/// Lists all assignments the character created, including revoked and
+/// expired ones
+pub async fn list_created_job_assignments(
+    pool:         &PgPool,
+    character_id: CharacterId,
+) -> Result<Vec<JobAssignment>> {
+    let entries = sqlx::query!(r#"
+            SELECT
+                ja.id,
+                ja.expires_at,
+                ja.revoked_at,
+                ja.created_at,
+                COUNT(pja.job_id) AS "jobs!",
+                COUNT(pja.claimed_by) AS "claimed!",
+                COUNT(pja.job_id) FILTER (WHERE pja.started) AS "started!",
+                COALESCE(SUM(pja.payment) FILTER (WHERE pja.payout_status = 'PENDING'), 0) AS "payout_pending!",
+                COALESCE(SUM(pja.payment) FILTER (WHERE pja.payout_status = 'PAID'), 0) AS "payout_paid!"
+            FROM job_assignment ja
+            LEFT JOIN project_job_assignment pja ON pja.id = ja.id
+            WHERE ja.creator_id = $1
+            GROUP BY ja.id
+            ORDER BY ja.created_at DESC
+        "#,
+            *character_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(JobAssignmentError::List)?
+        .into_iter()
+        .map(|x| JobAssignment {
+            id:             x.id.into(),
+            expires_at:     x.expires_at,
+            revoked_at:     x.revoked_at,
+            created_at:     x.created_at,
+            jobs:           x.jobs,
+            claimed:        x.claimed,
+            started:        x.started,
+            payout_pending: x.payout_pending,
+            payout_paid:    x.payout_paid,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(entries)
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct JobAssignment {
+    pub id:             JobAssignmentUuid,
+    pub expires_at:     Option<DateTime<Utc>>,
+    pub revoked_at:     Option<DateTime<Utc>>,
+    pub created_at:     DateTime<Utc>,
+    /// Number of jobs in the assignment
+    pub jobs:           i64,
+    /// Number of jobs claimed by a builder
+    pub claimed:        i64,
+    /// Number of jobs that are started
+    pub started:        i64,
+    /// Payments of done jobs that are not paid yet
+    pub payout_pending: f64,
+    /// Payments that were already paid
+    pub payout_paid:    f64,
+}
@@ -1,19 +1,31 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use starfoundry_lib_eve_gateway::{EveGatewayApiClient, Item};
 use starfoundry_lib_industry::ProjectJobUuid;
+use starfoundry_lib_types::CharacterId;
 use std::collections::HashMap;
 use utoipa::ToSchema;
 
 use crate::sort_by_job_flat;
 use crate::job_assignments::error::{JobAssignmentError, Result};
 use crate::job_assignments::JobAssignmentUuid;
+use crate::job_assignments::service::fetch_job_assignment;
 
+/// Lists the jobs of the assignment grouped by project.
+///
+/// Revoked or expired assignments are only visible to their creator.
+///
 pub async fn list_job_assignments(
     pool:                   &PgPool,
+    character_id:           CharacterId,
     assignment_id:          JobAssignmentUuid,
     eve_gateway_api_client: &impl EveGatewayApiClient,
 ) -> Result<Vec<ProjectJobAssignmentGroup>> {
+    fetch_job_assignment(pool, assignment_id)
+        .await?
+        .assert_visible(character_id)?;
+
     // TODO: merge end_date into project_job?
     let entries = sqlx::query!(r#"
             SELECT
@@ -21,6 +33,10 @@ pub async fn list_job_assignments(
                 pja.job_id AS "job_id!",
                 pja.project_id AS "project_id!",
                 pja.started AS "started!",
+                pja.claimed_by,
+                pja.payment,
+                pja.payout_status AS "payout_status!: JobAssignmentPayoutStatus",
+                pja.paid_at,
                 pj.runs,
                 pj.structure_id,
                 pj.type_id
@@ -118,6 +134,11 @@ pub async fn list_job_assignments(
             started:        entry.started,
             item:           item,
             runs:           entry.runs,
+            claimed_by:     entry.claimed_by.map(Into::into),
+            claimed_by_me:  entry.claimed_by == Some(*character_id),
+            payment:        entry.payment,
+            payout_status:  entry.payout_status,
+            paid_at:        entry.paid_at,
         };
         project_jobs
             .entry(project)
@@ -149,6 +170,32 @@ pub struct ProjectJobAssignment {
     pub started:        bool,
     pub item:           Item,
     pub runs:           i32,
+    /// Builder that claimed the job
+    pub claimed_by:     Option<CharacterId>,
+    /// The requesting character claimed the job
+    pub claimed_by_me:  bool,
+    /// Agreed payment for building the job
+    pub payment:        Option<f64>,
+    pub payout_status:  JobAssignmentPayoutStatus,
+    pub paid_at:        Option<DateTime<Utc>>,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "JOB_ASSIGNMENT_PAYOUT_STATUS")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum JobAssignmentPayoutStatus {
+    /// the job is not done yet
+    Open,
+    /// the job is done and the payment is added to the misc costs of the
+    /// project, but not paid yet
+    Pending,
+    /// the builder received the payment
+    Paid,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
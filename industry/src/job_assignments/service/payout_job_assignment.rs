@@ -0,0 +1,48 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::ProjectJobUuid;
+use starfoundry_lib_types::CharacterId;
+
+use crate::job_assignments::JobAssignmentUuid;
+use crate::job_assignments::error::{JobAssignmentError, Result};
+use crate::job_assignments::service::fetch_job_assignment;
+
+/// Marks the payment of a done job as paid, only the creator of the
+/// assignment can confirm the payout.
+///
+/// The payment is already part of the project misc costs, it was added when
+/// the job detection marked the job as done.
+///
+pub async fn payout_job_assignment(
+    pool:          &PgPool,
+    character_id:  CharacterId,
+    assignment_id: JobAssignmentUuid,
+    job_id:        ProjectJobUuid,
+) -> Result<()> {
+    fetch_job_assignment(pool, assignment_id)
+        .await?
+        .assert_creator(character_id)?;
+
+    let result = sqlx::query!("
+            UPDATE project_job_assignment
+            SET
+                payout_status = 'PAID',
+                paid_at = NOW()
+            WHERE id = $1
+            AND job_id = $2
+            AND payout_status = 'PENDING'
+        ",
+            *assignment_id,
+            *job_id,
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| JobAssignmentError::Payout(e, assignment_id, job_id))?;
+
+    if result.rows_affected() == 0 {
+        return Err(JobAssignmentError::ValidationError(
+            "only payments of done jobs can be paid out".into()
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use sqlx::PgPool;
+use starfoundry_lib_types::CharacterId;
+
+use crate::job_assignments::JobAssignmentUuid;
+use crate::job_assignments::error::{JobAssignmentError, Result};
+use crate::job_assignments::service::fetch_job_assignment;
+
+/// Revokes the assignment, only the creator can revoke it.
+///
+/// Claims and payouts are kept, so that builders of already started jobs
+/// still get paid.
+///
+pub async fn revoke_job_assignment(
+    pool:          &PgPool,
+    character_id:  CharacterId,
+    assignment_id: JobAssignmentUuid,
+) -> Result<()> {
+    fetch_job_assignment(pool, assignment_id)
+        .await?
+        .assert_creator(character_id)?;
+
+    sqlx::query!("
+            UPDATE job_assignment
+            SET revoked_at = NOW()
+            WHERE id = $1
+            AND revoked_at IS NULL
+        ",
+            *assignment_id,
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| JobAssignmentError::Revoke(e, assignment_id))?;
+
+    Ok(())
+}
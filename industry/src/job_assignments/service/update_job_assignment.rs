@@ -1,22 +1,36 @@
 use sqlx::PgPool;
 use starfoundry_lib_industry::ProjectJobUuid;
+use starfoundry_lib_types::CharacterId;
 
 use crate::job_assignments::JobAssignmentUuid;
 use crate::job_assignments::error::{JobAssignmentError, Result};
+use crate::job_assignments::service::claim_job_assignment;
 
+/// Marks the job as started, unclaimed jobs are claimed by the character
 pub async fn update_job_assignment(
     pool:           &PgPool,
+    character_id:   CharacterId,
     assignment_id:  JobAssignmentUuid,
     job_id:         ProjectJobUuid,
 ) -> Result<()> {
+    claim_job_assignment(
+            pool,
+            character_id,
+            assignment_id,
+            job_id,
+        )
+        .await?;
+
     sqlx::query!("
             UPDATE project_job_assignment
             SET started = TRUE
             WHERE id = $1
             AND job_id = $2
+            AND claimed_by = $3
         ",
             *assignment_id,
             *job_id,
+            *character_id,
         )
         .execute(pool)
         .await
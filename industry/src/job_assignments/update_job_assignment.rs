@@ -1,6 +1,7 @@
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::ProjectJobUuid;
 
 use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
@@ -9,23 +10,24 @@ use crate::job_assignments::JobAssignmentUuid;
 use crate::job_assignments::error::Result;
 use crate::job_assignments::service::update_job_assignment;
 
-/// Update Project Job
+/// Start Job Assignment
 /// 
 /// - Alternative route: `/v1/job-assignments/{JobAssignmentUuid}/{ProjectJobUuid}`
 /// - Alternative route: `/latest/job-assignments/{JobAssignmentUuid}/{ProjectJobUuid}`
 /// 
 /// ---
 /// 
-/// Updates the project jobs
+/// Marks the job as started, if the job is not claimed yet it is claimed by
+/// the character.
 /// 
 /// ## Security
 /// - authenticated
-/// - project_group:write
+/// - the job is not claimed by another character
 /// 
 #[utoipa::path(
     put,
     path = "/{JobAssignmentUuid}/{ProjectJobUuid}",
-    tag = "job_assignments",
+    tag = "job_assignment",
     params(
         JobAssignmentUuid,
         ProjectJobUuid,
@@ -47,11 +49,13 @@ use crate::job_assignments::service::update_job_assignment;
     ),
 )]
 pub async fn api(
+    identity:                       ExtractIdentity,
     State(state):                   State<AppState>,
     Path((assignment_id, job_id)):  Path<(JobAssignmentUuid, ProjectJobUuid)>,
 ) -> Result<impl IntoResponse> {
     update_job_assignment(
         &state.postgres,
+        identity.character_id,
         assignment_id,
         job_id,
     ).await?;
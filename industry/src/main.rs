@@ -1,4 +1,5 @@
 mod api_docs;
+mod audit;
 mod config;
 mod healthcheck;
 mod industry_hub;
@@ -7,8 +8,11 @@ mod job_assignments;
 mod metrics;
 mod project_group;
 mod project;
+mod project_template;
 mod sort;
 mod state;
+mod stock_target;
+mod store;
 mod structure;
 mod tag;
 
@@ -90,6 +94,9 @@ fn app(
     let (router, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
         .nest("/projects", project::routes(state.clone()))
         .nest("/project-groups", project_group::routes(state.clone()))
+        .nest("/project-templates", project_template::routes())
+        .nest("/stock-targets", stock_target::routes(state.clone()))
+        .nest("/stores", store::routes())
         .nest("/structures", structure::routes(state.clone()))
         .nest("/industry", industry::routes())
         .nest("/industry-hubs", industry_hub::routes(state.clone()))
@@ -130,6 +137,14 @@ use starfoundry_lib_eve_gateway::EveGatewayClient;
 pub fn eve_gateway_api_client() -> Result<EveGatewayClient, starfoundry_lib_eve_gateway::Error> {
     EveGatewayClient::new(SERVICE_NAME)
 }
+/// Client that acts in the name of the given identity, required for routes
+/// that access character data
+#[cfg(not(test))]
+pub fn eve_gateway_api_client_with_identity(
+    identity: starfoundry_lib_gateway::Identity,
+) -> Result<EveGatewayClient, starfoundry_lib_eve_gateway::Error> {
+    EveGatewayClient::new_with_identity(SERVICE_NAME, identity)
+}
 #[cfg(not(test))]
 use starfoundry_lib_market::MarketClient;
 #[cfg(not(test))]
@@ -146,6 +161,12 @@ pub fn eve_gateway_api_client() -> Result<EveGatewayTestApiClient, starfoundry_l
     Ok(EveGatewayTestApiClient {})
 }
 #[cfg(test)]
+pub fn eve_gateway_api_client_with_identity(
+    _identity: starfoundry_lib_gateway::Identity,
+) -> Result<EveGatewayTestApiClient, starfoundry_lib_eve_gateway::Error> {
+    Ok(EveGatewayTestApiClient {})
+}
+#[cfg(test)]
 use crate::test_util::MarketTestApiClient;
 use std::sync::Arc;
 use prometheus_client::registry::Registry;
@@ -5,20 +5,36 @@ mod check_resources;
 mod create;
 mod delete_job;
 mod delete_market_entry;
+mod delete_asset_stock;
+mod delete_reservations;
 mod delete;
+mod delivery_manifest;
+mod export;
+mod export_cost_csv;
+mod export_jobs_csv;
+mod export_market_csv;
 mod fetch_cost;
 mod fetch_time_left;
+mod fetch_asset_stock;
+mod fetch_wallet;
 mod fetch;
+mod import;
 mod initialize;
 mod list_all_jobs;
+mod list_audit_events;
 mod list_excess;
 mod list_jobs;
 mod list_market_buy;
+mod list_market_multibuy;
+mod list_market_sell;
 mod list_market_structures;
 mod list_market;
 mod list_misc;
+mod list_reservations;
 mod list;
 mod permission;
+mod refresh_asset_stock;
+mod refresh_wallet;
 mod split_job_check;
 mod update_job;
 mod update_market_bulk;
@@ -26,6 +42,9 @@ mod update_market_entry;
 mod update_misc;
 mod update_notes;
 mod update_orderer;
+mod update_asset_stock;
+mod update_reservations;
+mod update_wallet;
 mod update;
 
 pub mod error;
@@ -56,18 +75,57 @@ pub fn routes(
         .routes(routes!(fetch::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let fetch_asset_stock = OpenApiRouter::new()
+        .routes(routes!(fetch_asset_stock::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
     let fetch_cost = OpenApiRouter::new()
         .routes(routes!(fetch_cost::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let fetch_wallet = OpenApiRouter::new()
+        .routes(routes!(fetch_wallet::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
     let fetch_time_left = OpenApiRouter::new()
         .routes(routes!(fetch_time_left::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
 
+    let delivery_manifest = OpenApiRouter::new()
+        .routes(routes!(delivery_manifest::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let export = OpenApiRouter::new()
+        .routes(routes!(export::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let export_cost_csv = OpenApiRouter::new()
+        .routes(routes!(export_cost_csv::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let export_jobs_csv = OpenApiRouter::new()
+        .routes(routes!(export_jobs_csv::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let export_market_csv = OpenApiRouter::new()
+        .routes(routes!(export_market_csv::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let import = OpenApiRouter::new()
+        .routes(routes!(import::api));
+
     let delete = OpenApiRouter::new()
         .routes(routes!(delete::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let delete_asset_stock = OpenApiRouter::new()
+        .routes(routes!(delete_asset_stock::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let delete_reservations = OpenApiRouter::new()
+        .routes(routes!(delete_reservations::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
     let delete_market_entry = OpenApiRouter::new()
         .routes(routes!(delete_market_entry::api));
     let delete_job = OpenApiRouter::new()
@@ -81,6 +139,10 @@ pub fn routes(
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
     let list_all_jobs = OpenApiRouter::new()
         .routes(routes!(list_all_jobs::api));
+    let list_audit_events = OpenApiRouter::new()
+        .routes(routes!(list_audit_events::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
     let list_excess = OpenApiRouter::new()
         .routes(routes!(list_excess::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
@@ -93,6 +155,14 @@ pub fn routes(
         .routes(routes!(list_market_buy::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let list_market_multibuy = OpenApiRouter::new()
+        .routes(routes!(list_market_multibuy::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let list_market_sell = OpenApiRouter::new()
+        .routes(routes!(list_market_sell::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
     let list_market_structures = OpenApiRouter::new()
         .routes(routes!(list_market_structures::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
@@ -101,6 +171,10 @@ pub fn routes(
         .routes(routes!(list_misc::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let list_reservations = OpenApiRouter::new()
+        .routes(routes!(list_reservations::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
 
     let check_resources = OpenApiRouter::new()
         .routes(routes!(check_resources::api));
@@ -110,6 +184,26 @@ pub fn routes(
     let update = OpenApiRouter::new()
         .routes(routes!(update::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let refresh_asset_stock = OpenApiRouter::new()
+        .routes(routes!(refresh_asset_stock::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let refresh_wallet = OpenApiRouter::new()
+        .routes(routes!(refresh_wallet::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let update_asset_stock = OpenApiRouter::new()
+        .routes(routes!(update_asset_stock::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let update_reservations = OpenApiRouter::new()
+        .routes(routes!(update_reservations::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+    let update_wallet = OpenApiRouter::new()
+        .routes(routes!(update_wallet::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
     let update_job = OpenApiRouter::new()
         .routes(routes!(update_job::api));
     let update_market_bulk = OpenApiRouter::new()
@@ -137,23 +231,42 @@ pub fn routes(
         .merge(add_job)
         .merge(add_market)
         .merge(fetch)
+        .merge(fetch_asset_stock)
         .merge(fetch_cost)
         .merge(fetch_time_left)
+        .merge(fetch_wallet)
         .merge(delete)
+        .merge(delete_asset_stock)
+        .merge(delete_reservations)
         .merge(delete_market_entry)
         .merge(delete_job)
+        .merge(delivery_manifest)
+        .merge(export)
+        .merge(export_cost_csv)
+        .merge(export_jobs_csv)
+        .merge(export_market_csv)
+        .merge(import)
         .merge(initialize)
         .merge(list)
         .merge(list_excess)
         .merge(list_jobs)
         .merge(list_all_jobs)
+        .merge(list_audit_events)
         .merge(list_market)
         .merge(list_market_buy)
+        .merge(list_market_multibuy)
+        .merge(list_market_sell)
         .merge(list_market_structures)
         .merge(list_misc)
+        .merge(list_reservations)
         .merge(check_resources)
         .merge(split_job_check)
         .merge(update)
+        .merge(refresh_asset_stock)
+        .merge(refresh_wallet)
+        .merge(update_asset_stock)
+        .merge(update_reservations)
+        .merge(update_wallet)
         .merge(update_job)
         .merge(update_market_bulk)
         .merge(update_market_entry)
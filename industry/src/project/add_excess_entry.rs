@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::project::AddExcessEntryRequest;
 use starfoundry_lib_industry::ProjectUuid;
 
@@ -42,12 +43,14 @@ use crate::project::service::add_excess;
     ),
 )]
 pub async fn api(
+    identity:           ExtractIdentity,
     State(state):       State<AppState>,
     Path(project_id):   Path<ProjectUuid>,
     Json(entries):      Json<Vec<AddExcessEntryRequest>>,
 ) -> Result<impl IntoResponse> {
     add_excess(
             &state.postgres,
+            identity.character_id,
             project_id,
             entries,
         ).await?;
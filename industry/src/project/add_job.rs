@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::project::AddJobEntryRequest;
 use starfoundry_lib_industry::ProjectUuid;
 
@@ -45,12 +46,14 @@ use crate::project::service::add_job;
     ),
 )]
 pub async fn api(
+    identity:           ExtractIdentity,
     State(state):       State<AppState>,
     Path(project_id):   Path<ProjectUuid>,
     Json(entries):      Json<Vec<AddJobEntryRequest>>,
 ) -> Result<impl IntoResponse> {
     add_job(
             &state.postgres,
+            identity.character_id,
             project_id,
             entries,
         ).await?;
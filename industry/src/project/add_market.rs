@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::project::AddMarketEntryRequest;
 use starfoundry_lib_industry::ProjectUuid;
 
@@ -42,12 +43,14 @@ use crate::project::service::add_market;
     ),
 )]
 pub async fn api(
+    identity:           ExtractIdentity,
     State(state):       State<AppState>,
     Path(project_id):   Path<ProjectUuid>,
     Json(entries):      Json<Vec<AddMarketEntryRequest>>,
 ) -> Result<impl IntoResponse> {
     add_market(
             &state.postgres,
+            identity.character_id,
             project_id,
             entries,
         ).await?;
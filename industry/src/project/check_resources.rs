@@ -79,6 +79,7 @@ pub async fn api(
             identity.character_id,
             info.job_ids,
             materials,
+            info.location_id,
         ).await?;
 
     Ok(
@@ -2,13 +2,15 @@ use axum::extract::State;
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
-use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_gateway::{ExtractIdentity, Identity};
 use starfoundry_lib_industry::project::{CreateProject, CreateProjectResponse};
 
 use crate::api_docs::{BadRequest, InternalServerError, Unauthorized};
-use crate::AppState;
+use crate::{AppState, eve_gateway_api_client};
+use crate::industry::{FittingProducts, expand_fittings};
 use crate::project::error::Result;
 use crate::project::service::create;
+use crate::tag::service::apply_auto_tags;
 
 /// Create Project
 /// 
@@ -17,7 +19,13 @@ use crate::project::service::create;
 /// 
 /// ---
 /// 
-/// Creates a new project
+/// Creates a new project.
+/// If a `solution_id` is given, the project is directly initialized with the
+/// jobs, materials and stock of the solution.
+/// 
+/// `fittings` are expanded into their hull and modules and appended to the
+/// products, modules that are usually bought are appended to the additional
+/// products
 /// 
 /// ## Security
 /// - authenticated
@@ -51,12 +59,37 @@ pub async fn api(
     State(state):       State<AppState>,
     Json(project_info): Json<CreateProject>,
 ) -> Result<impl IntoResponse> {
+    let mut project_info = project_info;
+    if let Some(fittings) = project_info.fittings.take() {
+        let identity = Identity::new(
+            identity.character_id,
+            identity.corporation_id,
+            identity.host()?,
+        );
+        let fittings = expand_fittings(Some(identity), &fittings).await?;
+
+        project_info.pre_products = join_lines(
+            project_info.pre_products,
+            FittingProducts::format(&fittings.build),
+        );
+        project_info.pre_additional = join_lines(
+            project_info.pre_additional,
+            FittingProducts::format(&fittings.buy),
+        );
+    }
+
     let id = create(
             &state.postgres,
             identity.character_id,
             project_info,
         ).await?;
 
+    // the project is already stored, so a failure only leaves the automatic
+    // tags outdated until the next change
+    if let Err(e) = apply_auto_tags(&state.postgres, &eve_gateway_api_client()?, id).await {
+        tracing::error!("error while applying automatic tags to project '{}', error: '{}'", id, e);
+    }
+
     Ok(
         (
             StatusCode::CREATED,
@@ -66,3 +99,14 @@ pub async fn api(
         )
     )
 }
+
+fn join_lines(
+    existing: Option<String>,
+    lines:    Option<String>,
+) -> Option<String> {
+    match (existing, lines) {
+        (Some(existing), Some(lines)) => Some(format!("{}\n{}", existing.trim_end(), lines)),
+        (existing, None)              => existing,
+        (None, lines)                 => lines,
+    }
+}
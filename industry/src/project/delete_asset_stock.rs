@@ -0,0 +1,64 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::AppState;
+use crate::project::error::Result;
+use crate::project::service::delete_asset_stock;
+
+/// Delete Asset Stock
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/asset-stock`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/asset-stock`
+/// 
+/// ---
+/// 
+/// Stops using the synced assets as stock for the project
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    delete,
+    path = "/{ProjectUuid}/asset-stock",
+    tag = "Projects",
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            description = "The asset stock was removed",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:         ExtractIdentity,
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+) -> Result<impl IntoResponse> {
+    delete_asset_stock(
+            &state.postgres,
+            identity.character_id,
+            project_id,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+        )
+        .into_response()
+    )
+}
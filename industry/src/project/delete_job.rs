@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::{ProjectJobUuid, ProjectUuid};
 
 use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
@@ -44,11 +45,13 @@ use crate::project::service::delete_job;
     ),
 )]
 pub async fn api(
+    identity:                       ExtractIdentity,
     State(state):                   State<AppState>,
     Path((project_id, job_id)):     Path<(ProjectUuid, ProjectJobUuid)>,
 ) -> Result<impl IntoResponse> {
     delete_job(
             &state.postgres,
+            identity.character_id,
             project_id,
             job_id,
         )
@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::{MarketUuid, ProjectUuid};
 
 use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
@@ -43,11 +44,13 @@ use crate::project::service::delete_market_entry;
     ),
 )]
 pub async fn api(
+    identity:                       ExtractIdentity,
     State(state):                   State<AppState>,
     Path((project_id, market_id)):  Path<(ProjectUuid, MarketUuid)>,
 ) -> Result<impl IntoResponse> {
     delete_market_entry(
             &state.postgres,
+            identity.character_id,
             project_id,
             market_id,
         )
@@ -0,0 +1,64 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::AppState;
+use crate::project::error::Result;
+use crate::project::service::delete_reservations;
+
+/// Delete Reservations
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/reservations`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/reservations`
+/// 
+/// ---
+/// 
+/// Releases all materials the project reserved
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    delete,
+    path = "/{ProjectUuid}/reservations",
+    tag = "Projects",
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            description = "The reservations were released",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:         ExtractIdentity,
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+) -> Result<impl IntoResponse> {
+    delete_reservations(
+            &state.postgres,
+            identity.character_id,
+            project_id,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+        )
+        .into_response()
+    )
+}
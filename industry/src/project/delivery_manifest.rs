@@ -0,0 +1,73 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_industry::project::{DeliveryManifest, DeliveryManifestRequest};
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{BadRequest, InternalServerError, NotFound, Unauthorized};
+use crate::project::error::Result;
+use crate::project::service::delivery_manifest;
+
+/// Delivery Manifest
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/delivery`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/delivery`
+/// 
+/// ---
+/// 
+/// Creates a manifest for a courier or item exchange contract, either to
+/// deliver the products to the customer or to move items between industry
+/// hubs.
+/// If no items are given, the products of the project are used.
+/// The manifest contains the total volume and a suggested collateral based on
+/// the current average prices
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    post,
+    path = "/{ProjectUuid}/delivery",
+    tag = "projects",
+    params(
+        ProjectUuid,
+    ),
+    request_body = DeliveryManifestRequest,
+    responses(
+        (
+            body = DeliveryManifest,
+            description = "Items, volume and collateral of the delivery",
+            status = OK,
+        ),
+        NotFound,
+        BadRequest,
+        Unauthorized,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):      State<AppState>,
+    Path(project_id):  Path<ProjectUuid>,
+    Json(request):     Json<DeliveryManifestRequest>,
+) -> Result<impl IntoResponse> {
+    let data = delivery_manifest(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            project_id,
+            request,
+        ).await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(data),
+        )
+        .into_response()
+    )
+}
@@ -26,6 +26,8 @@ pub enum ProjectError {
     NoIndustryHub,
     #[error("The solution could not be found, but the operation requires it")]
     SolutionNotFound,
+    #[error("validation error: '{0}'")]
+    ValidationError(String),
 
     #[error("error while adding market entries, error: '{0}'")]
     AddExcessEntry(sqlx::Error),
@@ -46,6 +48,23 @@ pub enum ProjectError {
     #[error("error while fetching project cost '{1}', error: '{0}'")]
     FetchCost(sqlx::Error, ProjectUuid),
 
+    #[error("error while fetching asset stock of project '{1}', error: '{0}'")]
+    FetchAssetStock(sqlx::Error, ProjectUuid),
+    #[error("error while updating asset stock of project '{1}', error: '{0}'")]
+    UpdateAssetStock(sqlx::Error, ProjectUuid),
+
+    #[error("error while fetching wallet of project '{1}', error: '{0}'")]
+    FetchWallet(sqlx::Error, ProjectUuid),
+    #[error("error while updating wallet of project '{1}', error: '{0}'")]
+    UpdateWallet(sqlx::Error, ProjectUuid),
+
+    #[error("error while listing reservations of project '{1}', error: '{0}'")]
+    ListReservations(sqlx::Error, ProjectUuid),
+    #[error("error while listing reserved quantities, error: '{0}'")]
+    ListReservedQuantities(sqlx::Error),
+    #[error("error while updating reservations of project '{1}', error: '{0}'")]
+    UpdateReservations(sqlx::Error, ProjectUuid),
+
     #[error("error while deleting project '{1}', error: '{0}'")]
     Delete(sqlx::Error, ProjectUuid),
 
@@ -57,16 +76,29 @@ pub enum ProjectError {
     #[error("error while updating project, error: '{0}'")]
     Update(sqlx::Error),
 
+    #[error("error while exporting project '{1}', error: '{0}'")]
+    Export(sqlx::Error, ProjectUuid),
+    #[error("error while importing project, error: '{0}'")]
+    Import(sqlx::Error),
+    #[error("error while writing csv export, error: '{0}'")]
+    CsvExport(csv::Error),
+
     #[error("transaction error, '{0}'")]
     TransactionError(sqlx::Error),
 
     #[error(transparent)]
     JsonExtractorRejection(#[from] JsonRejection),
     #[error(transparent)]
+    AuditError(Box<crate::audit::AuditError>),
+    #[error(transparent)]
     ProjectGroupError(Box<ProjectGroupError>),
     #[error(transparent)]
     IndustryHubError(Box<IndustryHubError>),
     #[error(transparent)]
+    IndustryError(Box<crate::industry::IndustryError>),
+    #[error(transparent)]
+    GatewayLibError(#[from] starfoundry_lib_gateway::Error),
+    #[error(transparent)]
     EveGatewayLibError(#[from] starfoundry_lib_eve_gateway::Error),
     #[error(transparent)]
     MarketLibError(#[from] starfoundry_lib_market::Error),
@@ -105,10 +137,39 @@ impl IntoResponse for ProjectError {
                 ).into_response()
             },
 
+            Self::ValidationError(_) => {
+                tracing::info!("{}", self.to_string());
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    Json(
+                        ErrorResponse {
+                            error: "UNPROCESSABLE_ENTITY".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+
             Self::JsonExtractorRejection(x) => {
                 format_json_errors(x).into_response()
             },
 
+            Self::GatewayLibError(e) => {
+                starfoundry_lib_gateway::Error::into_response(e)
+            },
+
+            Self::ProjectGroupError(e) => {
+                ProjectGroupError::into_response(*e)
+            },
+
+            Self::IndustryError(e) => {
+                crate::industry::IndustryError::into_response(*e)
+            },
+
+            Self::StructureError(e) => {
+                e.into_response()
+            },
+
             _ => {
                 tracing::error!("{}", self.to_string());
                 (
@@ -128,4 +189,6 @@ impl IntoResponse for ProjectError {
 
 boxed_from!(ProjectError::ProjectGroupError, ProjectGroupError);
 boxed_from!(ProjectError::IndustryHubError, IndustryHubError);
+boxed_from!(ProjectError::IndustryError, crate::industry::IndustryError);
 boxed_from!(ProjectError::ProjectLibError, starfoundry_lib_industry::Error);
+boxed_from!(ProjectError::AuditError, crate::audit::AuditError);
@@ -0,0 +1,67 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_industry::project::ProjectExport;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::AppState;
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project::error::Result;
+use crate::project::service::export_project;
+
+/// Export Project
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/export`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/export`
+/// 
+/// ---
+/// 
+/// Exports the project into a versioned, portable JSON format.
+/// The export contains the products, jobs, market entries, misc costs, excess
+/// and stock, together with the structures that are referenced, so that it
+/// can be imported on another instance
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectUuid}/export",
+    tag = "Projects",
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            body = ProjectExport,
+            description = "Portable export of the project",
+            status = OK,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+) -> Result<impl IntoResponse> {
+    let entry = export_project(
+            &state.postgres,
+            project_id,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(entry)
+        )
+    )
+}
@@ -0,0 +1,76 @@
+use axum::extract::{Path, State};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project::error::Result;
+use crate::project::service::export_cost_csv;
+
+/// Export Cost Breakdown as CSV
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/cost/csv`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/cost/csv`
+/// 
+/// ---
+/// 
+/// Exports the estimated build cost of every job as CSV.
+/// 
+/// The products are planned again with the structures of the project and the
+/// defaults of the project group, every row contains the material value,
+/// system cost, facility tax and SCC surcharge of a job
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectUuid}/cost/csv",
+    tag = "projects",
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            body = String,
+            content_type = "text/csv",
+            description = "CSV of the build cost of every job",
+            status = OK,
+        ),
+        NotFound,
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:         ExtractIdentity,
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+) -> Result<impl IntoResponse> {
+    let data = export_cost_csv(
+            &state.postgres,
+            identity.character_id,
+            &eve_gateway_api_client()?,
+            project_id,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            [
+                (CONTENT_TYPE, "text/csv; charset=utf-8"),
+                (CONTENT_DISPOSITION, "attachment; filename=\"cost_breakdown.csv\""),
+            ],
+            data,
+        )
+    )
+}
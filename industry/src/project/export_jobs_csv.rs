@@ -0,0 +1,74 @@
+use axum::extract::{Path, State};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project::error::Result;
+use crate::project::service::export_jobs_csv;
+
+/// Export Jobs as CSV
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/jobs/csv`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/jobs/csv`
+/// 
+/// ---
+/// 
+/// Exports all jobs of the project as CSV.
+/// The jobs are grouped the same way as in the job list, the group is the
+/// first column of every row
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectUuid}/jobs/csv",
+    tag = "projects",
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            body = String,
+            content_type = "text/csv",
+            description = "CSV of all jobs",
+            status = OK,
+        ),
+        NotFound,
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:         ExtractIdentity,
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+) -> Result<impl IntoResponse> {
+    let data = export_jobs_csv(
+            &state.postgres,
+            identity.character_id,
+            &eve_gateway_api_client()?,
+            project_id,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            [
+                (CONTENT_TYPE, "text/csv; charset=utf-8"),
+                (CONTENT_DISPOSITION, "attachment; filename=\"jobs.csv\""),
+            ],
+            data,
+        )
+    )
+}
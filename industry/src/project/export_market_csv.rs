@@ -0,0 +1,72 @@
+use axum::extract::{Path, State};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project::error::Result;
+use crate::project::service::export_market_csv;
+
+/// Export Shopping List as CSV
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/market/csv`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/market/csv`
+/// 
+/// ---
+/// 
+/// Exports the materials that need to be bought as CSV, e.g. for a shopping
+/// list.
+/// The materials are grouped by their market group, the group is the first
+/// column of every row
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectUuid}/market/csv",
+    tag = "projects",
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            body = String,
+            content_type = "text/csv",
+            description = "CSV of all materials that need to be bought",
+            status = OK,
+        ),
+        NotFound,
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+) -> Result<impl IntoResponse> {
+    let data = export_market_csv(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            project_id,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            [
+                (CONTENT_TYPE, "text/csv; charset=utf-8"),
+                (CONTENT_DISPOSITION, "attachment; filename=\"shopping_list.csv\""),
+            ],
+            data,
+        )
+    )
+}
@@ -0,0 +1,82 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_industry::project::ProjectAssetStock;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project::error::Result;
+use crate::project::service::fetch_asset_stock;
+
+/// Fetch Asset Stock
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/asset-stock`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/asset-stock`
+/// 
+/// ---
+/// 
+/// Fetches the assets that are used as stock for the project, and which of
+/// the materials that still need to be bought are covered, reserved by
+/// another project or still missing.
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectUuid}/asset-stock",
+    tag = "Projects",
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            body = ProjectAssetStock,
+            description = "Asset stock of the project",
+            status = OK,
+        ),
+        (
+            description = "The project doesn't use assets as stock",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+) -> Result<impl IntoResponse> {
+    let entry = fetch_asset_stock(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            project_id,
+        )
+        .await?;
+
+    if let Some(x) = entry {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(x)
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(())
+            )
+            .into_response()
+        )
+    }
+}
@@ -0,0 +1,128 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_industry::project::ProjectWallet;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::AppState;
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project::error::Result;
+use crate::project::service::fetch_wallet;
+
+/// Fetch Wallet
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/wallet`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/wallet`
+/// 
+/// ---
+/// 
+/// Fetches the result of the last wallet reconciliation, which transactions
+/// and journal entries were matched to the market and job entries, and the
+/// spending that could not be matched.
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectUuid}/wallet",
+    tag = "Projects",
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            body = ProjectWallet,
+            description = "Wallet reconciliation of the project",
+            status = OK,
+        ),
+        (
+            description = "The project doesn't reconcile its costs with a wallet",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+) -> Result<impl IntoResponse> {
+    let entry = fetch_wallet(
+            &state.postgres,
+            project_id,
+        )
+        .await?;
+
+    if let Some(x) = entry {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(x)
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(())
+            )
+            .into_response()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::StatusCode;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE};
+
+    use crate::project::project_test_routes;
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn no_wallet(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000101/wallet")
+            .method("GET")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .body(Body::empty())
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn forbidden(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000105/wallet")
+            .method("GET")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .body(Body::empty())
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
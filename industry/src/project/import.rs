@@ -0,0 +1,78 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::project::{CreateProjectResponse, ImportProject};
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, Unauthorized};
+use crate::project::error::Result;
+use crate::project::service::import_project;
+use crate::tag::service::apply_auto_tags;
+
+/// Import Project
+/// 
+/// - Alternative route: `/latest/projects/import`
+/// - Alternative route: `/v1/projects/import`
+/// 
+/// ---
+/// 
+/// Imports a previously exported project into the given project group.
+/// 
+/// All type ids are validated against the current SDE.
+/// Structures are either mapped using `structure_mapping`, or matched by
+/// their in-game id against the structures of the character
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_project
+/// 
+#[utoipa::path(
+    post,
+    path = "/import",
+    tag = "Projects",
+    request_body = ImportProject,
+    responses(
+        (
+            body = CreateProjectResponse,
+            description = "Id of the imported project",
+            status = CREATED,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+    Json(import): Json<ImportProject>,
+) -> Result<impl IntoResponse> {
+    let id = import_project(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            (&identity).into(),
+            import,
+        )
+        .await?;
+
+    // the project is already stored, so a failure only leaves the automatic
+    // tags outdated until the next change
+    if let Err(e) = apply_auto_tags(&state.postgres, &eve_gateway_api_client()?, id).await {
+        tracing::error!("error while applying automatic tags to project '{}', error: '{}'", id, e);
+    }
+
+    Ok(
+        (
+            StatusCode::CREATED,
+            Json(CreateProjectResponse {
+                id,
+            })
+        )
+    )
+}
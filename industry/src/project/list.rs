@@ -53,7 +53,7 @@ pub async fn api(
 ) -> Result<impl IntoResponse> {
     let data = list(
             &state.postgres,
-            identity.character_id,
+            (&identity).into(),
             filter,
         ).await?;
 
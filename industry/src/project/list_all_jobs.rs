@@ -56,7 +56,7 @@ pub async fn api(
 ) -> Result<impl IntoResponse> {
     let data = list_all_jobs(
             &state.postgres,
-            identity.character_id,
+            (&identity).into(),
             &eve_gateway_api_client()?,
         ).await?;
 
@@ -0,0 +1,72 @@
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_industry::audit::{AuditEntity, AuditEvent, AuditFilter};
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::AppState;
+use crate::audit::service::list_events;
+use crate::project::error::Result;
+
+/// List Audit Events
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/audit`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/audit`
+/// 
+/// ---
+/// 
+/// Lists all changes that were made to the project
+/// 
+/// Every event contains only the values that changed, `before` holds the
+/// removed or replaced values, `after` the added ones.
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectUuid}/audit",
+    tag = "projects",
+    params(
+        ProjectUuid,
+        AuditFilter,
+    ),
+    responses(
+        (
+            body = Vec<AuditEvent>,
+            description = "Audit events, newest first",
+            status = OK,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):       State<AppState>,
+    Path(project_uuid): Path<ProjectUuid>,
+    Query(filter):      Query<AuditFilter>,
+) -> Result<impl IntoResponse> {
+    let data = list_events(
+            &state.postgres,
+            AuditEntity::Project,
+            *project_uuid,
+            filter,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(data),
+        )
+        .into_response()
+    )
+}
@@ -95,4 +95,7 @@ pub struct ListMarketBuyQuery {
     pub gas_decompression:      Option<GasDecompressionEfficiency>,
     // mineral compression is active
     pub mineral_compression:    Option<OreReprocessingEfficiency>,
+    // public item exchange contracts are used as source
+    #[serde(default)]
+    pub contracts:              bool,
 }
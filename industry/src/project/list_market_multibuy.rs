@@ -0,0 +1,98 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use starfoundry_lib_industry::project::ProjectMultiBuy;
+use starfoundry_lib_industry::ProjectUuid;
+use utoipa::ToSchema;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{BadRequest, InternalServerError, NotFound, Unauthorized};
+use crate::project::error::Result;
+use crate::project::list_market_buy::ListMarketBuyQuery;
+use crate::project::service::list_market_multibuy;
+
+/// List Market as MultiBuy
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/market/buy/multibuy`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/market/buy/multibuy`
+/// 
+/// ---
+/// 
+/// Lists all materials that need to be bought, formatted for the in-game
+/// MultiBuy window.
+/// Every market gets its own lists, if a cargo capacity is set, no list is
+/// larger than the capacity.
+/// Items that are bought with a public contract are not part of any list.
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    post,
+    path = "/{ProjectUuid}/market/buy/multibuy",
+    tag = "projects",
+    params(
+        ProjectUuid,
+    ),
+    request_body = ListMarketMultiBuyQuery,
+    responses(
+        (
+            body = Vec<ProjectMultiBuy>,
+            description = "MultiBuy lists split by market and cargo capacity",
+            status = OK,
+        ),
+        (
+            description = "There aren't any materials required for the project",
+            status = NO_CONTENT,
+        ),
+        NotFound,
+        BadRequest,
+        Unauthorized,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+    Json(config):     Json<ListMarketMultiBuyQuery>,
+) -> Result<impl IntoResponse> {
+    let data = list_market_multibuy(
+            &state.postgres,
+            project_id,
+            &eve_gateway_api_client()?,
+            config.market,
+            config.cargo_capacity,
+        ).await?;
+
+    if data.is_empty() {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(data),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(data),
+            )
+            .into_response()
+        )
+    }
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct ListMarketMultiBuyQuery {
+    #[serde(flatten)]
+    pub market:         ListMarketBuyQuery,
+    // cargo capacity in m3 of the hauling ship, lists are split by it
+    pub cargo_capacity: Option<f64>,
+}
@@ -0,0 +1,145 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_market::{MarketSellFees, MarketSellResponse};
+use starfoundry_lib_types::StructureId;
+use utoipa::ToSchema;
+
+use crate::AppState;
+use crate::api_docs::{BadRequest, InternalServerError, NotFound, Unauthorized};
+use crate::project::error::Result;
+use crate::project::service::list_market_sell;
+
+/// List Market Sell
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/market/sell`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/market/sell`
+/// 
+/// ---
+/// 
+/// Plans where the products and the excess of the project should be sold.
+/// 
+/// For every item it is recommended to either sell into buy orders or to
+/// list a sell order, together with the expected income after sales tax
+/// and broker fee per market.
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    post,
+    path = "/{ProjectUuid}/market/sell",
+    tag = "projects",
+    params(
+        ProjectUuid,
+    ),
+    request_body = ListMarketSellQuery,
+    responses(
+        (
+            body = MarketSellResponse,
+            description = "Recommendation where the products and excess should be sold",
+            status = OK,
+        ),
+        (
+            description = "The project has no products or excess",
+            status = NO_CONTENT,
+        ),
+        NotFound,
+        BadRequest,
+        Unauthorized,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+    Json(config):     Json<ListMarketSellQuery>,
+) -> Result<impl IntoResponse> {
+    let data = list_market_sell(
+            &state.postgres,
+            project_id,
+            config,
+        ).await?;
+
+    if let Some(data) = data {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(data),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(MarketSellResponse::default()),
+            )
+            .into_response()
+        )
+    }
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct ListMarketSellQuery {
+    pub structure_ids:  Vec<StructureId>,
+    /// skills and standings of the selling character
+    #[serde(default)]
+    pub fees:           MarketSellFees,
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::StatusCode;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE};
+
+    use crate::project::project_test_routes;
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn nothing_to_sell(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000101/market/sell")
+            .method("POST")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header("Content-Type", "application/json")
+            .body(Body::from(r#"{ "structure_ids": [60003760] }"#))
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn forbidden(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000105/market/sell")
+            .method("POST")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header("Content-Type", "application/json")
+            .body(Body::from(r#"{ "structure_ids": [60003760] }"#))
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
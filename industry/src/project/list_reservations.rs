@@ -0,0 +1,80 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_industry::project::ProjectReservation;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::{AppState, eve_gateway_api_client};
+use crate::project::error::Result;
+use crate::project::service::list_reservations;
+
+/// List Reservations
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/reservations`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/reservations`
+/// 
+/// ---
+/// 
+/// Lists all materials the project reserved
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectUuid}/reservations",
+    tag = "Projects",
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            body = Vec<ProjectReservation>,
+            description = "List of all reservations of the project",
+            status = OK,
+        ),
+        (
+            description = "The project didn't reserve anything",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+) -> Result<impl IntoResponse> {
+    let data = list_reservations(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            project_id,
+        )
+        .await?;
+
+    if data.is_empty() {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(data),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(data),
+            )
+            .into_response()
+        )
+    }
+}
@@ -4,10 +4,10 @@ use axum::response::IntoResponse;
 use sqlx::PgPool;
 use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::ProjectUuid;
-use starfoundry_lib_types::CharacterId;
 
 use crate::AppState;
 use crate::project::error::{ProjectError, Result};
+use crate::project_group::permission::MemberIdentity;
 
 pub async fn assert_read(
     State(state):       State<AppState>,
@@ -19,7 +19,7 @@ pub async fn assert_read(
     assert_read_access_check(
             &state.postgres,
             project_uuid,
-            identity.character_id,
+            (&identity).into(),
         )
         .await?;
 
@@ -29,7 +29,7 @@ pub async fn assert_read(
 async fn assert_read_access_check(
     pool:         &PgPool,
     project_id:   ProjectUuid,
-    character_id: CharacterId,
+    identity:     MemberIdentity,
 ) -> Result<()> {
     let project = sqlx::query!("
             SELECT project_group_id
@@ -45,13 +45,13 @@ async fn assert_read_access_check(
     let project_group_id = if let Some(x) = project {
         x.project_group_id
     } else {
-        return Err(ProjectError::Forbidden(project_id, character_id));
+        return Err(ProjectError::Forbidden(project_id, identity.character_id));
     };
 
     crate::project_group::permission::assert_read_access_check(
             pool,
             project_group_id.into(),
-            character_id,
+            identity,
         )
         .await
         .map_err(Into::into)
@@ -0,0 +1,155 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::{AppState, eve_gateway_api_client_with_identity};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project::error::Result;
+use crate::project::service::refresh_wallet;
+
+/// Refresh Wallet
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/wallet/refresh`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/wallet/refresh`
+/// 
+/// ---
+/// 
+/// Reconciles the costs of the project with the currently synced wallets.
+/// Only the character that configured the wallet can reconcile it, the worker
+/// calls it every hour in their name.
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    post,
+    path = "/{ProjectUuid}/wallet/refresh",
+    tag = "Projects",
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            description = "The wallet was reconciled",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:         ExtractIdentity,
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+) -> Result<impl IntoResponse> {
+    let character_id = identity.character_id;
+
+    refresh_wallet(
+            &state.postgres,
+            &eve_gateway_api_client_with_identity(identity.into())?,
+            character_id,
+            project_id,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+        )
+        .into_response()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::StatusCode;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE, HEADER_SOURCE};
+
+    use crate::project::project_test_routes;
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000101/wallet")
+            .method("PUT")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HEADER_SOURCE, "test.starfoundry.space")
+            .body(Body::empty())
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        sqlx::query!("
+                UPDATE project_wallet
+                SET reconciled_at = NULL
+            ")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000101/wallet/refresh")
+            .method("POST")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HEADER_SOURCE, "test.starfoundry.space")
+            .body(Body::empty())
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let reconciled = sqlx::query!("
+                SELECT reconciled_at
+                FROM project_wallet
+            ")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(reconciled.reconciled_at.is_some());
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn other_character(
+        pool: PgPool,
+    ) {
+        sqlx::query!("
+                INSERT INTO project_wallet (project_id, character_id, corporation_id, domain)
+                VALUES ('00000000-0000-0000-0000-000000000101', 2, 1, 'test.starfoundry.space')
+            ")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000101/wallet/refresh")
+            .method("POST")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HEADER_SOURCE, "test.starfoundry.space")
+            .body(Body::empty())
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -4,29 +4,48 @@ mod add_market;
 mod check_resources;
 mod create;
 mod delete;
+mod delete_asset_stock;
 mod delete_market;
+mod delete_reservations;
 mod delete_job;
+mod delivery_manifest;
+mod export;
+mod export_csv;
 mod fetch;
+mod fetch_asset_stock;
 mod fetch_cost;
 mod fetch_time_left;
+mod fetch_wallet;
+mod import;
 mod initialize;
 mod list_excess;
 mod list_jobs;
 mod list_all_jobs;
 mod list_market;
 mod list_market_buy;
+mod list_market_sell;
 mod list_market_structures;
 mod list_misc;
+mod multibuy;
 mod list_product;
+mod list_reservations;
 mod list_stock;
 mod list_tags;
 mod list;
+mod refresh_asset_stock;
+mod refresh_wallet;
+mod reserved_quantities;
 mod split_job_check;
 mod update;
+mod update_asset_stock;
 mod update_job;
 mod update_market_bulk;
 mod update_market_entry;
 mod update_misc;
+mod update_notes;
+mod update_orderer;
+mod update_reservations;
+mod update_wallet;
 
 pub use self::add_excess::*;
 pub use self::add_job::*;
@@ -34,26 +53,45 @@ pub use self::add_market::*;
 pub use self::check_resources::*;
 pub use self::create::*;
 pub use self::delete::*;
+pub use self::delete_asset_stock::*;
 pub use self::delete_market::*;
+pub use self::delete_reservations::*;
 pub use self::delete_job::*;
+pub use self::delivery_manifest::*;
+pub use self::export::*;
+pub use self::export_csv::*;
 pub use self::fetch::*;
+pub use self::fetch_asset_stock::*;
 pub use self::fetch_cost::*;
 pub use self::fetch_time_left::*;
+pub use self::fetch_wallet::*;
+pub use self::import::*;
 pub use self::initialize::*;
 pub use self::list_excess::*;
 pub use self::list_jobs::*;
 pub use self::list_all_jobs::*;
 pub use self::list_market::*;
 pub use self::list_market_buy::*;
+pub use self::list_market_sell::*;
 pub use self::list_market_structures::*;
 pub use self::list_misc::*;
+pub use self::multibuy::*;
 pub use self::list_product::*;
+pub use self::list_reservations::*;
 pub use self::list_stock::*;
 pub use self::list_tags::*;
 pub use self::list::*;
+pub use self::refresh_asset_stock::*;
+pub use self::refresh_wallet::*;
+pub use self::reserved_quantities::*;
 pub use self::split_job_check::*;
 pub use self::update::*;
+pub use self::update_asset_stock::*;
 pub use self::update_job::*;
 pub use self::update_market_bulk::*;
 pub use self::update_market_entry::*;
 pub use self::update_misc::*;
+pub use self::update_notes::*;
+pub use self::update_orderer::*;
+pub use self::update_reservations::*;
+pub use self::update_wallet::*;
@@ -1,13 +1,17 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::{ProjectError, Result};
 use starfoundry_lib_industry::project::AddExcessEntryRequest;
 
 pub async fn add_excess(
-    pool:       &PgPool,
-    project_id: ProjectUuid,
-    entries:    Vec<AddExcessEntryRequest>,
+    pool:         &PgPool,
+    character_id: CharacterId,
+    project_id:   ProjectUuid,
+    entries:      Vec<AddExcessEntryRequest>,
 ) -> Result<()> {
     let type_ids = entries
         .iter()
@@ -18,6 +22,18 @@ pub async fn add_excess(
         .map(|x| x.quantity)
         .collect::<Vec<_>>();
 
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Create,
+            AuditTarget::ProjectExcess(project_id),
+        )
+        .await?;
+
     sqlx::query!("
             INSERT INTO project_excess
             (
@@ -34,8 +50,13 @@ pub async fn add_excess(
             &type_ids,
             &quantities,
         )
-        .execute(pool)
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::AddExcessEntry)?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
         .await
-        .map(drop)
-        .map_err(ProjectError::AddExcessEntry)
+        .map_err(ProjectError::TransactionError)
 }
@@ -1,13 +1,17 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::project::AddJobEntryRequest;
 use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::{ProjectError, Result};
 
 pub async fn add_job(
-    pool:       &PgPool,
-    project_id: ProjectUuid,
-    entries:    Vec<AddJobEntryRequest>,
+    pool:         &PgPool,
+    character_id: CharacterId,
+    project_id:   ProjectUuid,
+    entries:      Vec<AddJobEntryRequest>,
 ) -> Result<()> {
     let type_ids = entries
         .iter()
@@ -22,6 +26,18 @@ pub async fn add_job(
         .map(|x| *x.structure_id)
         .collect::<Vec<_>>();
 
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Create,
+            AuditTarget::ProjectJobs(project_id),
+        )
+        .await?;
+
     sqlx::query!("
             INSERT INTO project_job
             (
@@ -41,8 +57,13 @@ pub async fn add_job(
             &runs,
             &structure_ids,
         )
-        .execute(pool)
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::AddJobEntry)?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
         .await
-        .map(drop)
-        .map_err(ProjectError::AddJobEntry)
+        .map_err(ProjectError::TransactionError)
 }
@@ -1,13 +1,17 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::project::AddMarketEntryRequest;
 use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::{ProjectError, Result};
 
 pub async fn add_market(
-    pool:       &PgPool,
-    project_id: ProjectUuid,
-    entries:    Vec<AddMarketEntryRequest>,
+    pool:         &PgPool,
+    character_id: CharacterId,
+    project_id:   ProjectUuid,
+    entries:      Vec<AddMarketEntryRequest>,
 ) -> Result<()> {
     let type_ids = entries
         .iter()
@@ -18,6 +22,18 @@ pub async fn add_market(
         .map(|x| x.quantity)
         .collect::<Vec<_>>();
 
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Create,
+            AuditTarget::ProjectMarket(project_id),
+        )
+        .await?;
+
     sqlx::query!("
             INSERT INTO project_market
             (
@@ -34,8 +50,13 @@ pub async fn add_market(
             &type_ids,
             &quantities,
         )
-        .execute(pool)
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::AddMarketEntry)?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
         .await
-        .map(drop)
-        .map_err(ProjectError::AddMarketEntry)
+        .map_err(ProjectError::TransactionError)
 }
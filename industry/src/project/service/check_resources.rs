@@ -2,13 +2,14 @@ use sqlx::PgPool;
 use starfoundry_lib_eve_gateway::{EveGatewayApiClient, Item};
 use starfoundry_lib_industry::{ProjectJobUuid, StructureUuid};
 use starfoundry_lib_market::MarketApiClient;
-use starfoundry_lib_types::{CharacterId, TypeId};
+use starfoundry_lib_types::{CharacterId, LocationId, TypeId};
 use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::{sort_by_job_flat, sort_by_market_group_flat};
 use crate::industry::{JobPlannerEngine, Dependency, ProjectConfigBuilder, StructureMapping};
 use crate::project::error::{ProjectError, Result};
+use crate::project::service::ReservedQuantities;
 use crate::structure::service::FetchStructureQuery;
 use starfoundry_lib_industry::project::{CheckMaterialsResponse, CheckMaterialsResponseBlueprint, CheckMaterialsResponseMaterial, JobToStart, Material};
 
@@ -19,12 +20,39 @@ pub async fn check_resources(
     character_id:               CharacterId,
     job_ids:                    Vec<ProjectJobUuid>,
     materials:                  Vec<Material>,
+    location_id:                Option<LocationId>,
 ) -> Result<CheckMaterialsResponse> {
     let mut total_cost           = 0f32;
     let mut required_resources   = HashMap::new();
     let mut required_blueprints: HashMap<TypeId, Vec<u32>>  = HashMap::new();
     let mut grouped_by_structure = HashMap::new();
 
+    let job_ids = job_ids
+        .into_iter()
+        .map(|x| *x)
+        .collect::<Vec<_>>();
+
+    let projects = sqlx::query!("
+            SELECT DISTINCT
+                p.id,
+                p.project_group_id
+            FROM project_job pj
+            JOIN project p ON p.id = pj.project_id
+            WHERE pj.id = ANY($1)
+        ",
+            &job_ids,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(ProjectError::ListJobs)?;
+    let mut reserved = ReservedQuantities::load(
+            pool,
+            projects.iter().map(|x| x.project_group_id).collect::<Vec<_>>(),
+            projects.iter().map(|x| x.id).collect::<Vec<_>>(),
+            location_id,
+        )
+        .await?;
+
     sqlx::query!("
             SELECT
                 type_id,
@@ -33,7 +61,7 @@ pub async fn check_resources(
             FROM project_job
             WHERE id = ANY($1)
         ",
-            &job_ids.into_iter().map(|x| *x).collect::<Vec<_>>(),
+            &job_ids,
         )
         .fetch_all(pool)
         .await
@@ -174,11 +202,12 @@ pub async fn check_resources(
         .map(|x| (x.type_id, x))
         .collect::<HashMap<TypeId, Item>>();
 
-    // remove stock
+    // remove stock, without the quantities other projects reserved
     for resource in materials {
+        let quantity = reserved.unreserved(resource.type_id, resource.quantity);
         required_resources
             .entry(resource.type_id)
-            .and_modify(|x: &mut i32| *x -= resource.quantity);
+            .and_modify(|x: &mut i32| *x -= quantity);
     }
 
     let mut materials = Vec::new();
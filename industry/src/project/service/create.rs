@@ -1,18 +1,47 @@
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use starfoundry_lib_industry::project::CreateProject;
 use starfoundry_lib_industry::ProjectUuid;
 use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::{ProjectError, Result};
+use crate::project::service::initialize_from_solution;
 
 pub async fn create(
     pool:         &PgPool,
     character_id: CharacterId,
     project_info: CreateProject,
+) -> Result<ProjectUuid> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+
+    let project_id = create_in_transaction(
+            &mut transaction,
+            character_id,
+            project_info,
+        )
+        .await?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+
+    Ok(project_id)
+}
+
+/// Same as [create], but within the given transaction, for services that
+/// create a project as part of a larger change
+pub(crate) async fn create_in_transaction(
+    connection:   &mut PgConnection,
+    character_id: CharacterId,
+    project_info: CreateProject,
 ) -> Result<ProjectUuid> {
     project_info.validate()?;
 
-    let project_id = sqlx::query!(r#"
+    let project_id: ProjectUuid = sqlx::query!(r#"
             INSERT INTO project
             (
                 owner,
@@ -36,9 +65,26 @@ pub async fn create(
             project_info.pre_products,
             project_info.pre_additional,
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *connection)
         .await
+        .map(|x| x.id.into())
         .map_err(ProjectError::Create)?;
 
-    Ok(project_id.id.into())
+    AuditRecord::created(
+            connection,
+            character_id,
+            AuditTarget::Project(project_id),
+        )
+        .await?;
+
+    if let Some(solution_id) = project_info.solution_id {
+        initialize_from_solution(
+                connection,
+                project_id,
+                solution_id,
+            )
+            .await?;
+    }
+
+    Ok(project_id)
 }
@@ -1,21 +1,42 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::ProjectError;
 use crate::project::error::Result;
 
 pub async fn delete(
     pool:           &PgPool,
+    character_id:   CharacterId,
     project_id:     ProjectUuid,
 ) -> Result<()> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Delete,
+            AuditTarget::Project(project_id),
+        )
+        .await?;
+
     sqlx::query!(r#"
             DELETE FROM project
             WHERE id = $1
         "#,
             *project_id,
         )
-        .execute(pool)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::Delete(e, project_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
         .await
-        .map(drop)
-        .map_err(|e| ProjectError::Delete(e, project_id))
+        .map_err(ProjectError::TransactionError)
 }
@@ -0,0 +1,42 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::CharacterId;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project::error::{ProjectError, Result};
+
+/// Stops using the assets as stock for the project
+pub async fn delete_asset_stock(
+    pool:         &PgPool,
+    character_id: CharacterId,
+    project_id:   ProjectUuid,
+) -> Result<()> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Delete,
+            AuditTarget::ProjectAssetStock(project_id),
+        )
+        .await?;
+
+    sqlx::query!("
+            DELETE FROM project_asset_stock
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateAssetStock(e, project_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectError::TransactionError)
+}
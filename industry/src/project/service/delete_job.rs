@@ -1,14 +1,30 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::{ProjectJobUuid, ProjectUuid};
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::ProjectError;
 use crate::project::error::Result;
 
 pub async fn delete_job(
     pool:           &PgPool,
+    character_id:   CharacterId,
     project_id:     ProjectUuid,
     job_id:         ProjectJobUuid,
 ) -> Result<()> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Delete,
+            AuditTarget::ProjectJob(project_id, job_id),
+        )
+        .await?;
+
     sqlx::query!(r#"
             DELETE FROM project_job
             WHERE id = $1
@@ -17,8 +33,13 @@ pub async fn delete_job(
             *job_id,
             *project_id,
         )
-        .execute(pool)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::Delete(e, project_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
         .await
-        .map(drop)
-        .map_err(|e| ProjectError::Delete(e, project_id))
+        .map_err(ProjectError::TransactionError)
 }
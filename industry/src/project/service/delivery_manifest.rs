@@ -0,0 +1,160 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClientItem;
+use starfoundry_lib_industry::project::{DeliveryManifest, DeliveryManifestEntry, DeliveryManifestItem, DeliveryManifestRequest};
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_market::MarketApiClientPrice;
+use std::collections::HashMap;
+
+use crate::market_api_client;
+use crate::project::error::{ProjectError, Result};
+
+/// Creates a manifest for contracting items, either to the customer or
+/// between industry hubs.
+///
+/// If no items are given, the products of the project are used.
+/// The collateral is suggested based on the current average prices.
+///
+/// # Errors
+///
+/// - [ProjectError::ValidationError] if a quantity is not positive
+///
+pub async fn delivery_manifest(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClientItem,
+    project_id:             ProjectUuid,
+    request:                DeliveryManifestRequest,
+) -> Result<DeliveryManifest> {
+    if request.items.iter().any(|x| x.quantity <= 0) {
+        return Err(ProjectError::ValidationError(
+            "Field 'quantity' must be positive".into()
+        ));
+    }
+
+    let entries = if request.items.is_empty() {
+        sqlx::query!("
+                SELECT type_id, quantity
+                FROM project_product
+                WHERE project_id = $1
+            ",
+                *project_id,
+            )
+            .fetch_all(pool)
+            .await
+            .map_err(|e| ProjectError::Fetch(e, project_id))?
+            .into_iter()
+            .map(|x| DeliveryManifestEntry {
+                type_id:  x.type_id.into(),
+                quantity: x.quantity as i64,
+            })
+            .collect::<Vec<_>>()
+    } else {
+        request.items
+    };
+
+    // the same item may be listed multiple times
+    let mut quantities = HashMap::new();
+    for entry in entries {
+        *quantities.entry(entry.type_id).or_insert(0i64) += entry.quantity;
+    }
+
+    let items = eve_gateway_api_client
+        .fetch_item_bulk(quantities.keys().cloned().collect::<Vec<_>>())
+        .await?;
+    let prices = market_api_client()?
+        .all_prices()
+        .await?
+        .into_iter()
+        .map(|x| (x.type_id, x.average_price))
+        .collect::<HashMap<_, _>>();
+
+    let mut manifest_items = Vec::new();
+    for item in items {
+        let quantity = if let Some(x) = quantities.get(&item.type_id) {
+            *x
+        } else {
+            continue;
+        };
+
+        // ships can only be contracted packaged
+        let unit_volume = item
+            .repackaged
+            .map(|x| x as f64)
+            .unwrap_or(item.volume as f64);
+
+        manifest_items.push(DeliveryManifestItem {
+            price:  prices.get(&item.type_id).copied().unwrap_or_default(),
+            volume: unit_volume * quantity as f64,
+            item,
+            quantity,
+        });
+    }
+    manifest_items.sort_by(|a, b| a.item.name.cmp(&b.item.name));
+
+    Ok(DeliveryManifest {
+        volume:     manifest_items.iter().map(|x| x.volume).sum(),
+        collateral: manifest_items.iter().map(|x| x.price * x.quantity as f64).sum(),
+        contract:   manifest_items
+                        .iter()
+                        .map(|x| format!("{}\t{}", x.item.name, x.quantity))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+        items:      manifest_items,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::project::{DeliveryManifestEntry, DeliveryManifestRequest};
+    use starfoundry_lib_types::TypeId;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::project::error::ProjectError;
+    use crate::project::service::delivery_manifest;
+    use crate::test_util::EveGatewayTestApiClient;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn collateral_from_average_prices(
+        pool: PgPool,
+    ) {
+        let project_id = Uuid::from_str("00000000-0000-0000-0000-000000000101").unwrap().into();
+
+        let result = delivery_manifest(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                project_id,
+                DeliveryManifestRequest {
+                    items: vec![
+                        DeliveryManifestEntry { type_id: TypeId(4051), quantity: 2 },
+                        DeliveryManifestEntry { type_id: TypeId(4246), quantity: 3 },
+                        DeliveryManifestEntry { type_id: TypeId(4051), quantity: 1 },
+                    ],
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.items.len(), 2);
+        assert_eq!(result.collateral, 12_000f64 * 3f64 + 10_000f64 * 3f64);
+        assert_eq!(result.contract.lines().count(), 2);
+
+        let result = delivery_manifest(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                project_id,
+                DeliveryManifestRequest {
+                    items: vec![
+                        DeliveryManifestEntry { type_id: TypeId(4051), quantity: 0 },
+                    ],
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectError::ValidationError(_))));
+    }
+}
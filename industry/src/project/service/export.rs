@@ -0,0 +1,216 @@
+use chrono::Utc;
+use sqlx::PgPool;
+use starfoundry_lib_industry::{ProjectUuid, StructureUuid};
+use starfoundry_lib_industry::project::{PROJECT_EXPORT_VERSION, ProjectExport, ProjectExportJob, ProjectExportMarket, ProjectExportMisc, ProjectExportProduct, ProjectExportStock, ProjectJobStatus, ProjectStatus};
+use starfoundry_lib_industry::structure::ExportStructure;
+use uuid::Uuid;
+
+use crate::project::error::{ProjectError, Result};
+
+/// Exports the project with its jobs, market entries, misc costs, excess
+/// and stock into the portable export format
+pub async fn export_project(
+    pool:       &PgPool,
+    project_id: ProjectUuid,
+) -> Result<ProjectExport> {
+    let project = sqlx::query!(r#"
+            SELECT
+                name,
+                orderer,
+                status AS "status: ProjectStatus",
+                sell_price,
+                note
+            FROM project
+            WHERE id = $1
+        "#,
+            *project_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ProjectError::Export(e, project_id))?
+        .ok_or(ProjectError::NotFound(project_id))?;
+
+    let products = sqlx::query!("
+            SELECT type_id, quantity, material_efficiency
+            FROM project_product
+            WHERE project_id = $1
+            ORDER BY type_id
+        ",
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::Export(e, project_id))?
+        .into_iter()
+        .map(|x| ProjectExportProduct {
+            type_id:             x.type_id.into(),
+            quantity:            x.quantity,
+            material_efficiency: x.material_efficiency,
+        })
+        .collect::<Vec<_>>();
+
+    let jobs = sqlx::query!(r#"
+            SELECT
+                type_id,
+                runs,
+                status AS "status: ProjectJobStatus",
+                cost,
+                structure_id
+            FROM project_job
+            WHERE project_id = $1
+            ORDER BY type_id, runs
+        "#,
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::Export(e, project_id))?
+        .into_iter()
+        .map(|x| ProjectExportJob {
+            type_id:      x.type_id.into(),
+            runs:         x.runs,
+            status:       x.status,
+            cost:         x.cost,
+            structure_id: x.structure_id.into(),
+        })
+        .collect::<Vec<_>>();
+
+    let market = sqlx::query!("
+            SELECT type_id, quantity, cost, source
+            FROM project_market
+            WHERE project_id = $1
+            ORDER BY type_id
+        ",
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::Export(e, project_id))?
+        .into_iter()
+        .map(|x| ProjectExportMarket {
+            type_id:  x.type_id.into(),
+            quantity: x.quantity,
+            cost:     x.cost,
+            source:   x.source,
+        })
+        .collect::<Vec<_>>();
+
+    let misc = sqlx::query!("
+            SELECT item, cost, quantity, description
+            FROM project_misc
+            WHERE project_id = $1
+            ORDER BY item
+        ",
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::Export(e, project_id))?
+        .into_iter()
+        .map(|x| ProjectExportMisc {
+            item:        x.item,
+            cost:        x.cost,
+            quantity:    x.quantity,
+            description: x.description,
+        })
+        .collect::<Vec<_>>();
+
+    let excess = sqlx::query!("
+            SELECT type_id, quantity, cost
+            FROM project_excess
+            WHERE project_id = $1
+            ORDER BY type_id
+        ",
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::Export(e, project_id))?
+        .into_iter()
+        .map(|x| ProjectExportStock {
+            type_id:  x.type_id.into(),
+            quantity: x.quantity,
+            cost:     x.cost,
+        })
+        .collect::<Vec<_>>();
+
+    let stock = sqlx::query!("
+            SELECT type_id, quantity, cost
+            FROM project_stock
+            WHERE project_id = $1
+            ORDER BY type_id
+        ",
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::Export(e, project_id))?
+        .into_iter()
+        .map(|x| ProjectExportStock {
+            type_id:  x.type_id.into(),
+            quantity: x.quantity,
+            cost:     x.cost,
+        })
+        .collect::<Vec<_>>();
+
+    let market_structures = sqlx::query!("
+            SELECT structure_id
+            FROM project_market_structure
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::Export(e, project_id))?
+        .into_iter()
+        .map(|x| x.structure_id.into())
+        .collect::<Vec<StructureUuid>>();
+
+    let structure_ids = jobs
+        .iter()
+        .map(|x| *x.structure_id)
+        .chain(market_structures.iter().map(|x| **x))
+        .collect::<Vec<Uuid>>();
+    let structures = sqlx::query!("
+            SELECT id, structure_id, name, system_id, type_id
+            FROM structure
+            WHERE id = ANY($1::UUID[])
+            ORDER BY name
+        ",
+            &structure_ids,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::Export(e, project_id))?
+        .into_iter()
+        .map(|x| ExportStructure {
+            id:           x.id.into(),
+            structure_id: x.structure_id.into(),
+            name:         x.name,
+            system_id:    x.system_id.into(),
+            type_id:      x.type_id.into(),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ProjectExport {
+        version:     PROJECT_EXPORT_VERSION,
+        exported_at: Utc::now(),
+
+        name:        project.name,
+        orderer:     project.orderer,
+        status:      project.status,
+        sell_price:  project.sell_price,
+        notes:       project.note,
+
+        products,
+        jobs,
+        market,
+        misc,
+        excess,
+        stock,
+        market_structures,
+
+        structures,
+    })
+}
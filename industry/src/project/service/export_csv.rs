@@ -0,0 +1,347 @@
+use serde::Serialize;
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::industry::BuildEngineProduct;
+use starfoundry_lib_industry::project::{ProjectJobFilter, ProjectJobStatus, ProjectMarket};
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::{CharacterId, TypeId};
+
+use crate::industry::{BlueprintTyp, PlannerSettings, fetch_dependencies, fetch_system_index, planner_settings};
+use crate::project::error::{ProjectError, Result};
+use crate::project::service::{list_jobs, list_market};
+use crate::sort_by_market_group;
+use crate::structure::service::FetchStructureQuery;
+
+/// Exports the jobs of the project as CSV, every row contains the group the
+/// job is sorted into
+pub async fn export_jobs_csv(
+    pool:                   &PgPool,
+    character_id:           CharacterId,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_id:             ProjectUuid,
+) -> Result<Vec<u8>> {
+    let rows = list_jobs(
+            pool,
+            character_id,
+            eve_gateway_api_client,
+            project_id,
+            ProjectJobFilter::default(),
+        )
+        .await?
+        .into_iter()
+        .flat_map(|group| {
+            group
+                .entries
+                .into_iter()
+                .map(move |x| JobRow {
+                    group:     escape_cell(group.header.clone()),
+                    item:      escape_cell(x.item.name),
+                    type_id:   x.item.type_id,
+                    runs:      x.runs,
+                    status:    x.status,
+                    structure: escape_cell(x.structure.name),
+                    cost:      x.cost,
+                    job_id:    x.job_id,
+                    end_date:  x.end_date.map(|x| x.to_string()),
+                })
+        })
+        .collect::<Vec<_>>();
+
+    write_csv(rows).map_err(ProjectError::CsvExport)
+}
+
+/// Exports the materials that need to be bought as CSV, every row contains
+/// the market group the material is sorted into
+pub async fn export_market_csv(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_id:             ProjectUuid,
+) -> Result<Vec<u8>> {
+    let entries = list_market(
+            pool,
+            eve_gateway_api_client,
+            project_id,
+        )
+        .await?;
+
+    let rows = group_market(entries)
+        .into_iter()
+        .flat_map(|(header, entries)| {
+            entries
+                .into_iter()
+                .map(move |x| MarketRow {
+                    group:    escape_cell(header.clone()),
+                    item:     escape_cell(x.item.name),
+                    type_id:  x.item.type_id,
+                    quantity: x.quantity,
+                    volume:   x.item.volume as f64 * x.quantity as f64,
+                    cost:     x.cost,
+                    source:   x.source.map(escape_cell),
+                })
+        })
+        .collect::<Vec<_>>();
+
+    write_csv(rows).map_err(ProjectError::CsvExport)
+}
+
+/// Groups the entries by their market group, same as the market list
+fn group_market(
+    entries: Vec<ProjectMarket>,
+) -> Vec<(String, Vec<ProjectMarket>)> {
+    struct MarketGroup {
+        header:  String,
+        entries: Vec<ProjectMarket>,
+    }
+
+    sort_by_market_group!(inner_sort, ProjectMarket, MarketGroup);
+
+    inner_sort(entries)
+        .into_iter()
+        .map(|x| (x.header, x.entries))
+        .collect::<Vec<_>>()
+}
+
+/// Exports the estimated build cost of every job as CSV.
+///
+/// The products of the project are planned again with the structures the
+/// project uses and the defaults of the project group, every row contains the
+/// fields of the cost estimate
+pub async fn export_cost_csv(
+    pool:                   &PgPool,
+    character_id:           CharacterId,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_id:             ProjectUuid,
+) -> Result<Vec<u8>> {
+    let project_group_id = sqlx::query!("
+            SELECT project_group_id
+            FROM project
+            WHERE id = $1
+        ",
+            *project_id,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|x| x.project_group_id.into())
+        .map_err(|e| ProjectError::FetchCost(e, project_id))?;
+
+    let products = sqlx::query!("
+            SELECT type_id, quantity, material_efficiency
+            FROM project_product
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::FetchCost(e, project_id))?
+        .into_iter()
+        .map(|x| BuildEngineProduct {
+            type_id:             x.type_id.into(),
+            material_efficiency: x.material_efficiency.max(0) as u32,
+            quantity:            x.quantity.max(0) as u32,
+        })
+        .collect::<Vec<_>>();
+
+    let structure_ids = sqlx::query!("
+            SELECT DISTINCT structure_id
+            FROM project_job
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::FetchCost(e, project_id))?
+        .into_iter()
+        .map(|x| x.structure_id.into())
+        .collect::<Vec<_>>();
+    let structures = crate::structure::service::fetch_bulk(
+            pool,
+            eve_gateway_api_client,
+            character_id,
+            structure_ids,
+            FetchStructureQuery::default(),
+        )
+        .await?;
+
+    let settings = planner_settings(
+            pool,
+            project_group_id,
+            &products,
+            None,
+            None,
+            None,
+        )
+        .await?;
+    let settings = PlannerSettings {
+        system_index: fetch_system_index(&structures).await?,
+        structures,
+        ..settings
+    };
+    let dependencies = fetch_dependencies(&products).await?;
+    let result = settings.run(&dependencies, &Vec::new());
+
+    let mut rows = result
+        .tree
+        .into_values()
+        .filter(|x| x.typ != BlueprintTyp::Material)
+        .map(|x| CostRow {
+            item:                escape_cell(x.item.name),
+            type_id:             x.product_type_id,
+            runs:                x.runs.iter().sum(),
+            structure:           x.structure.map(|x| escape_cell(x.name)),
+            base_item_cost:      x.build_cost.base_item_cost,
+            system_cost_percent: x.build_cost.system_cost_percent,
+            system_cost:         x.build_cost.system_cost,
+            total_job_gross:     x.build_cost.total_job_gross,
+            facility:            x.build_cost.facility,
+            facility_percent:    x.build_cost.facility_percent,
+            scc:                 x.build_cost.scc,
+            scc_percent:         x.build_cost.scc_percent,
+            total_tax:           x.build_cost.total_tax,
+            total_job_cost:      x.build_cost.total_job_cost,
+        })
+        .collect::<Vec<_>>();
+    rows.sort_by(|a, b| a.item.cmp(&b.item));
+
+    write_csv(rows).map_err(ProjectError::CsvExport)
+}
+
+/// Prefixes cells that a spreadsheet would read as formula with `'`.
+///
+/// Names of structures, groups and the market source are chosen by players,
+/// so a name like `=HYPERLINK(..)` must not be executed when the file is
+/// opened
+fn escape_cell(
+    value: String,
+) -> String {
+    if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{value}")
+    } else {
+        value
+    }
+}
+
+/// Serializes the rows with a header row.
+///
+/// All exports are only provided as CSV, there is no XLSX export. Text
+/// cells must be passed through [escape_cell] before
+fn write_csv<T: Serialize>(
+    rows: Vec<T>,
+) -> std::result::Result<Vec<u8>, csv::Error> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for row in rows {
+        writer.serialize(row)?;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|e| e.into_error().into())
+}
+
+#[derive(Serialize)]
+struct JobRow {
+    group:     String,
+    item:      String,
+    type_id:   TypeId,
+    runs:      i32,
+    status:    ProjectJobStatus,
+    structure: String,
+    cost:      Option<f64>,
+    job_id:    Option<i32>,
+    end_date:  Option<String>,
+}
+
+#[derive(Serialize)]
+struct MarketRow {
+    group:    String,
+    item:     String,
+    type_id:  TypeId,
+    quantity: i32,
+    volume:   f64,
+    cost:     Option<f64>,
+    source:   Option<String>,
+}
+
+#[derive(Serialize)]
+struct CostRow {
+    item:                String,
+    type_id:             TypeId,
+    runs:                u32,
+    structure:           Option<String>,
+    base_item_cost:      f32,
+    system_cost_percent: f32,
+    system_cost:         f32,
+    total_job_gross:     f32,
+    facility:            f32,
+    facility_percent:    f32,
+    scc:                 f32,
+    scc_percent:         f32,
+    total_tax:           f32,
+    total_job_cost:      f32,
+}
+
+#[cfg(test)]
+mod export_csv_test {
+    use sqlx::PgPool;
+    use starfoundry_lib_types::CharacterId;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::test_util::EveGatewayTestApiClient;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base")
+        ),
+    )]
+    async fn jobs(
+        pool: PgPool,
+    ) {
+        let result = super::export_jobs_csv(
+                &pool,
+                CharacterId(1),
+                &EveGatewayTestApiClient::new(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000101").unwrap().into(),
+            )
+            .await
+            .unwrap();
+        let result = String::from_utf8(result).unwrap();
+
+        let mut lines = result.lines();
+        assert_eq!(
+            lines.next(),
+            Some("group,item,type_id,runs,status,structure,cost,job_id,end_date"),
+        );
+        assert_eq!(lines.count(), 2);
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base")
+        ),
+    )]
+    async fn empty_market(
+        pool: PgPool,
+    ) {
+        let result = super::export_market_csv(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000101").unwrap().into(),
+            )
+            .await
+            .unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn escape_cell() {
+        assert_eq!(super::escape_cell("=HYPERLINK(\"x\")".into()), "'=HYPERLINK(\"x\")");
+        assert_eq!(super::escape_cell("+1".into()), "'+1");
+        assert_eq!(super::escape_cell("-1".into()), "'-1");
+        assert_eq!(super::escape_cell("@SUM(A1)".into()), "'@SUM(A1)");
+        assert_eq!(super::escape_cell("Some Test Structure".into()), "Some Test Structure");
+    }
+}
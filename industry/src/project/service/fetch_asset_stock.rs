@@ -0,0 +1,269 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{AssetStockFilter, EveGatewayApiClient};
+use starfoundry_lib_industry::project::{ProjectAssetStock, ProjectAssetStockMaterial, ProjectAssetStockStatus};
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::{LocationId, TypeId};
+use std::collections::HashMap;
+
+use crate::project::error::{ProjectError, Result};
+use crate::project::service::ReservedQuantities;
+use crate::sort_by_market_group_flat;
+
+/// Fetches the asset stock of the project and how much of the materials that
+/// still need to be bought are covered by it.
+///
+/// The reservations of the other projects in the same project group are taken
+/// from the assets first, see [ReservedQuantities].
+///
+pub async fn fetch_asset_stock(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_id:             ProjectUuid,
+) -> Result<Option<ProjectAssetStock>> {
+    let config = sqlx::query!("
+            SELECT
+                location_ids,
+                location_flags,
+                container_names,
+                refreshed_at
+            FROM project_asset_stock
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ProjectError::FetchAssetStock(e, project_id))?;
+
+    let config = if let Some(x) = config {
+        x
+    } else {
+        return Ok(None);
+    };
+
+    let entries = sqlx::query!("
+            SELECT
+                type_id,
+                quantity,
+                location_id
+            FROM project_asset_stock_entry
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::FetchAssetStock(e, project_id))?;
+
+    let project_group_id = sqlx::query!("
+            SELECT project_group_id
+            FROM project
+            WHERE id = $1
+        ",
+            *project_id,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|x| x.project_group_id)
+        .map_err(|e| ProjectError::FetchAssetStock(e, project_id))?;
+
+    let mut location_ids = entries
+        .iter()
+        .map(|x| x.location_id)
+        .collect::<Vec<_>>();
+    location_ids.sort();
+    location_ids.dedup();
+
+    // reservations are per location, so only the reservations in the same
+    // location reduce the stock
+    let mut reserved = HashMap::new();
+    for location_id in location_ids {
+        reserved.insert(
+            location_id,
+            ReservedQuantities::load(
+                    pool,
+                    vec![project_group_id],
+                    vec![*project_id],
+                    Some(LocationId(location_id)),
+                )
+                .await?,
+        );
+    }
+
+    let stock = entries
+        .into_iter()
+        .map(|x| (x.type_id.into(), x.location_id, x.quantity))
+        .collect::<Vec<_>>();
+    let own_needs = open_market_quantities(pool, project_id)
+        .await?;
+    let coverage = coverage(stock, reserved, &own_needs);
+
+    let type_ids = own_needs
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+    let items = eve_gateway_api_client
+        .fetch_item_bulk(type_ids)
+        .await?
+        .into_iter()
+        .map(|x| (x.type_id, x))
+        .collect::<HashMap<_, _>>();
+
+    let mut materials = Vec::new();
+    for (type_id, needed) in own_needs {
+        let item = if let Some(x) = items.get(&type_id) {
+            x.clone()
+        } else {
+            continue;
+        };
+
+        let (covered, reserved) = coverage
+            .get(&type_id)
+            .cloned()
+            .unwrap_or_default();
+        let missing = needed - covered - reserved;
+
+        let status = if missing > 0 {
+            ProjectAssetStockStatus::Missing
+        } else if reserved > 0 {
+            ProjectAssetStockStatus::Reserved
+        } else {
+            ProjectAssetStockStatus::Covered
+        };
+
+        materials.push(ProjectAssetStockMaterial {
+            item,
+            needed:   needed as i32,
+            covered:  covered as i32,
+            reserved: reserved as i32,
+            missing:  missing as i32,
+            status,
+        });
+    }
+
+    Ok(Some(ProjectAssetStock {
+        filter: AssetStockFilter {
+            location_ids:    config.location_ids.into_iter().map(Into::into).collect::<Vec<_>>(),
+            location_flags:  config.location_flags,
+            container_names: config.container_names,
+            ..Default::default()
+        },
+        refreshed_at: config.refreshed_at,
+        materials:    sort_market(materials),
+    }))
+}
+
+/// Quantities of the market entries that were not bought yet
+async fn open_market_quantities(
+    pool:       &PgPool,
+    project_id: ProjectUuid,
+) -> Result<HashMap<TypeId, i64>> {
+    let entries = sqlx::query!(r#"
+            SELECT
+                type_id,
+                SUM(quantity) AS "quantity!"
+            FROM project_market
+            WHERE project_id = $1
+            AND cost IS NULL
+            GROUP BY type_id
+        "#,
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::FetchAssetStock(e, project_id))?
+        .into_iter()
+        .map(|x| (x.type_id.into(), x.quantity))
+        .collect::<HashMap<_, _>>();
+    Ok(entries)
+}
+
+/// Returns how much of the needs are covered by the stock, and how much would
+/// have been there, but is reserved by other projects.
+///
+/// # Params
+///
+/// - `stock` - Type, location and quantity of all assets the project can use
+/// - `reserved` - Reservations of the other projects, by location
+/// - `needs` - Quantities the project needs
+///
+fn coverage(
+    stock:        Vec<(TypeId, i64, i64)>,
+    mut reserved: HashMap<i64, ReservedQuantities>,
+    needs:        &HashMap<TypeId, i64>,
+) -> HashMap<TypeId, (i64, i64)> {
+    let mut available: HashMap<TypeId, (i64, i64)> = HashMap::new();
+    for (type_id, location_id, quantity) in stock {
+        if !needs.contains_key(&type_id) {
+            continue;
+        }
+
+        let quantity = quantity.min(i32::MAX as i64) as i32;
+        let unreserved = reserved
+            .get_mut(&location_id)
+            .map(|x| x.unreserved(type_id, quantity))
+            .unwrap_or(quantity);
+
+        let (free, taken) = available.entry(type_id).or_default();
+        *free += unreserved as i64;
+        *taken += (quantity - unreserved) as i64;
+    }
+
+    let mut result = HashMap::new();
+    for (type_id, needed) in needs {
+        let (free, taken) = available
+            .get(type_id)
+            .cloned()
+            .unwrap_or_default();
+
+        let covered = (*needed).min(free);
+        let reserved = (needed - covered).min(taken);
+        result.insert(*type_id, (covered, reserved));
+    }
+    result
+}
+
+sort_by_market_group_flat!(sort_market, ProjectAssetStockMaterial);
+
+#[cfg(test)]
+mod tests {
+    use starfoundry_lib_types::TypeId;
+    use std::collections::HashMap;
+
+    use super::coverage;
+    use crate::project::service::ReservedQuantities;
+
+    #[test]
+    fn covered_without_reservations() {
+        let stock = vec![(TypeId(34), 1, 1_000)];
+        let needs = HashMap::from([(TypeId(34), 500), (TypeId(35), 10)]);
+
+        let result = coverage(stock, HashMap::new(), &needs);
+        assert_eq!(result.get(&TypeId(34)), Some(&(500, 0)));
+        assert_eq!(result.get(&TypeId(35)), Some(&(0, 0)));
+    }
+
+    #[test]
+    fn reserved_by_other_project() {
+        let stock = vec![(TypeId(34), 1, 600), (TypeId(34), 1, 400)];
+        let needs = HashMap::from([(TypeId(34), 500)]);
+        let reserved = HashMap::from([
+            (1, ReservedQuantities::from(HashMap::from([(TypeId(34), 800)]))),
+        ]);
+
+        let result = coverage(stock, reserved, &needs);
+        assert_eq!(result.get(&TypeId(34)), Some(&(200, 300)));
+    }
+
+    #[test]
+    fn reserved_in_other_location() {
+        let stock = vec![(TypeId(34), 1, 1_000)];
+        let needs = HashMap::from([(TypeId(34), 500)]);
+        let reserved = HashMap::from([
+            (2, ReservedQuantities::from(HashMap::from([(TypeId(34), 800)]))),
+        ]);
+
+        let result = coverage(stock, reserved, &needs);
+        assert_eq!(result.get(&TypeId(34)), Some(&(500, 0)));
+    }
+}
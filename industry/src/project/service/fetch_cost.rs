@@ -81,6 +81,8 @@ pub async fn fetch_cost(
         .map(|x| x.map(|x| x.cost).unwrap_or_default())
         .map_err(|e| ProjectError::FetchCost(e, project_id))?;
 
+    let upkeep_cost = fetch_upkeep_cost(pool, project_id).await?;
+
     if let Some(x) = sell_price {
         Ok(Some(ProjectCost {
             sell_price:     x.sell_price.unwrap_or_default(),
@@ -90,8 +92,79 @@ pub async fn fetch_cost(
             misc_cost:      misc_cost.unwrap_or_default(),
             excess_cost:    excess_cost.unwrap_or_default(),
             stock_cost:     stock_cost.unwrap_or_default(),
+            upkeep_cost,
         }))
     } else {
         Ok(None)
     }
 }
+
+/// Amortises the fuel upkeep of all structures the project has jobs in.
+///
+/// The upkeep of a structure during the lifetime of the project is split
+/// between all jobs that were started in the structure in that time, the
+/// project pays the share of its own jobs.
+/// Structures without a recorded fuel stock don't add any upkeep
+async fn fetch_upkeep_cost(
+    pool:       &PgPool,
+    project_id: ProjectUuid,
+) -> Result<f64> {
+    let entries = sqlx::query!(r#"
+            WITH project_lifetime AS (
+                SELECT
+                    created_at AS started_at,
+                    CASE
+                        WHEN status = 'DONE' THEN updated_at
+                        ELSE NOW()
+                    END AS finished_at
+                FROM project
+                WHERE id = $1
+            )
+            SELECT
+                sf.fuel_per_hour,
+                sf.fuel_block_price,
+                sf.magmatic_gas_per_hour,
+                sf.magmatic_gas_price,
+                EXTRACT(EPOCH FROM (pl.finished_at - pl.started_at))::FLOAT / 3600 AS "hours!",
+                COUNT(*) FILTER (WHERE pj.project_id = $1) AS "project_jobs!",
+                COUNT(*) AS "all_jobs!"
+            FROM structure_fuel sf
+            JOIN project_job pj ON pj.structure_id = sf.structure_id
+            CROSS JOIN project_lifetime pl
+            WHERE
+                sf.structure_id IN (
+                    SELECT structure_id
+                    FROM project_job
+                    WHERE project_id = $1
+                ) AND
+                (
+                    pj.project_id = $1 OR
+                    pj.created_at BETWEEN pl.started_at AND pl.finished_at
+                )
+            GROUP BY
+                sf.structure_id,
+                sf.fuel_per_hour,
+                sf.fuel_block_price,
+                sf.magmatic_gas_per_hour,
+                sf.magmatic_gas_price,
+                pl.started_at,
+                pl.finished_at
+        "#,
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::FetchCost(e, project_id))?;
+
+    Ok(
+        entries
+            .into_iter()
+            .filter(|x| x.all_jobs > 0)
+            .map(|x| {
+                let upkeep = x.fuel_per_hour as f64 * x.fuel_block_price * x.hours +
+                    x.magmatic_gas_per_hour as f64 * x.magmatic_gas_price * x.hours;
+                upkeep * x.project_jobs as f64 / x.all_jobs as f64
+            })
+            .sum::<f64>()
+    )
+}
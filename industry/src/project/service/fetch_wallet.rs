@@ -0,0 +1,89 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::project::{ProjectWallet, ProjectWalletKind, ProjectWalletMatch, ProjectWalletUnmatched};
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::TypeId;
+
+use crate::project::error::{ProjectError, Result};
+
+/// Fetches the result of the last wallet reconciliation of the project
+pub async fn fetch_wallet(
+    pool:       &PgPool,
+    project_id: ProjectUuid,
+) -> Result<Option<ProjectWallet>> {
+    let reconciled_at = sqlx::query!("
+            SELECT reconciled_at
+            FROM project_wallet
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ProjectError::FetchWallet(e, project_id))?;
+
+    let reconciled_at = if let Some(x) = reconciled_at {
+        x.reconciled_at
+    } else {
+        return Ok(None);
+    };
+
+    let matches = sqlx::query!(r#"
+            SELECT
+                kind AS "kind: ProjectWalletKind",
+                entry_id,
+                reference_id,
+                quantity,
+                cost
+            FROM project_wallet_match
+            WHERE project_id = $1
+            ORDER BY kind, reference_id
+        "#,
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::FetchWallet(e, project_id))?
+        .into_iter()
+        .map(|x| ProjectWalletMatch {
+            kind:         x.kind,
+            entry_id:     x.entry_id,
+            reference_id: x.reference_id,
+            quantity:     x.quantity,
+            cost:         x.cost,
+        })
+        .collect::<Vec<_>>();
+
+    let unmatched = sqlx::query!(r#"
+            SELECT
+                kind AS "kind: ProjectWalletKind",
+                reference_id,
+                type_id,
+                quantity,
+                amount,
+                date
+            FROM project_wallet_unmatched
+            WHERE project_id = $1
+            ORDER BY date
+        "#,
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::FetchWallet(e, project_id))?
+        .into_iter()
+        .map(|x| ProjectWalletUnmatched {
+            kind:         x.kind,
+            reference_id: x.reference_id,
+            type_id:      x.type_id.map(TypeId),
+            quantity:     x.quantity,
+            amount:       x.amount,
+            date:         x.date,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Some(ProjectWallet {
+        reconciled_at,
+        matches,
+        unmatched,
+    }))
+}
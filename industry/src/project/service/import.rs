@@ -0,0 +1,460 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::{ProjectUuid, StructureUuid};
+use starfoundry_lib_industry::project::{ImportProject, PROJECT_EXPORT_VERSION};
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project::error::{ProjectError, Result};
+use crate::project_group::permission::{assert_write_access_check, MemberIdentity};
+use crate::structure::service::remap_structures;
+
+/// Imports an exported project into the given project group.
+///
+/// All type ids must exist in the current SDE, and all structures must be
+/// mappable to structures of the character.
+/// The project is owned by the importing character.
+/// The project and its entries are recorded as created in the audit log.
+///
+/// # Errors
+///
+/// - [ProjectError::ValidationError] if the export is from a newer version,
+///   or references unknown types or structures
+///
+pub async fn import_project(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    identity:               MemberIdentity,
+    import:                 ImportProject,
+) -> Result<ProjectUuid> {
+    let project = import.project;
+    if project.version > PROJECT_EXPORT_VERSION {
+        return Err(ProjectError::ValidationError(
+            format!("export version '{}' is not supported", project.version)
+        ));
+    }
+    if project.name.trim().is_empty() || project.name.len() > 100 {
+        return Err(ProjectError::ValidationError(
+            "Field 'name' must be set, max length: 100".into()
+        ));
+    }
+
+    assert_write_access_check(
+            pool,
+            import.project_group_id,
+            identity,
+            ProjectGroupPermissionCode::WriteProject,
+        )
+        .await?;
+
+    let type_ids = project.type_ids();
+    if !type_ids.is_empty() {
+        let known = eve_gateway_api_client
+            .fetch_item_bulk(type_ids.clone())
+            .await?
+            .into_iter()
+            .map(|x| x.type_id)
+            .collect::<HashSet<_>>();
+        let unknown = type_ids
+            .into_iter()
+            .filter(|x| !known.contains(x))
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+        if !unknown.is_empty() {
+            return Err(ProjectError::ValidationError(
+                format!("unknown type ids: {}", unknown.join(", "))
+            ));
+        }
+    }
+
+    let structures = remap_structures(
+            pool,
+            identity.character_id,
+            &project.structures,
+            &import.structure_mapping,
+        )
+        .await?;
+    let job_structures = project
+        .jobs
+        .iter()
+        .map(|x| map_structure(&structures, x.structure_id))
+        .collect::<Result<Vec<_>, String>>()
+        .map_err(ProjectError::ValidationError)?;
+    let market_structures = project
+        .market_structures
+        .iter()
+        .map(|x| map_structure(&structures, *x))
+        .collect::<Result<Vec<_>, String>>()
+        .map_err(ProjectError::ValidationError)?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+
+    let project_id: ProjectUuid = sqlx::query!("
+            INSERT INTO project
+            (
+                owner,
+                project_group_id,
+                status,
+                orderer,
+                name,
+                note,
+                sell_price
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id
+        ",
+            *identity.character_id,
+            *import.project_group_id,
+            project.status as _,
+            project.orderer,
+            project.name,
+            project.notes,
+            project.sell_price,
+        )
+        .fetch_one(&mut *transaction)
+        .await
+        .map(|x| x.id.into())
+        .map_err(ProjectError::Import)?;
+
+    sqlx::query!("
+            INSERT INTO project_product
+            (
+                project_id,
+                type_id,
+                quantity,
+                material_efficiency
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::INTEGER[],
+                $3::INTEGER[],
+                $4::INTEGER[]
+            )
+        ",
+            *project_id,
+            &project.products.iter().map(|x| *x.type_id).collect::<Vec<_>>(),
+            &project.products.iter().map(|x| x.quantity).collect::<Vec<_>>(),
+            &project.products.iter().map(|x| x.material_efficiency).collect::<Vec<_>>(),
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::Import)?;
+
+    sqlx::query!("
+            INSERT INTO project_job
+            (
+                project_id,
+                type_id,
+                runs,
+                status,
+                cost,
+                structure_id
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::INTEGER[],
+                $3::INTEGER[],
+                $4::PROJECT_JOB_STATUS[],
+                $5::DOUBLE PRECISION[],
+                $6::UUID[]
+            )
+        ",
+            *project_id,
+            &project.jobs.iter().map(|x| *x.type_id).collect::<Vec<_>>(),
+            &project.jobs.iter().map(|x| x.runs).collect::<Vec<_>>(),
+            &project.jobs.iter().map(|x| x.status).collect::<Vec<_>>() as _,
+            &project.jobs.iter().map(|x| x.cost).collect::<Vec<_>>() as _,
+            &job_structures,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::Import)?;
+
+    sqlx::query!("
+            INSERT INTO project_market
+            (
+                project_id,
+                type_id,
+                quantity,
+                cost,
+                source
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::INTEGER[],
+                $3::INTEGER[],
+                $4::DOUBLE PRECISION[],
+                $5::VARCHAR[]
+            )
+        ",
+            *project_id,
+            &project.market.iter().map(|x| *x.type_id).collect::<Vec<_>>(),
+            &project.market.iter().map(|x| x.quantity).collect::<Vec<_>>(),
+            &project.market.iter().map(|x| x.cost).collect::<Vec<_>>() as _,
+            &project.market.iter().map(|x| x.source.clone()).collect::<Vec<_>>() as _,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::Import)?;
+
+    sqlx::query!("
+            INSERT INTO project_misc
+            (
+                project_id,
+                item,
+                cost,
+                quantity,
+                description
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::VARCHAR[],
+                $3::DOUBLE PRECISION[],
+                $4::INTEGER[],
+                $5::VARCHAR[]
+            )
+        ",
+            *project_id,
+            &project.misc.iter().map(|x| x.item.clone()).collect::<Vec<_>>(),
+            &project.misc.iter().map(|x| x.cost).collect::<Vec<_>>(),
+            &project.misc.iter().map(|x| x.quantity).collect::<Vec<_>>() as _,
+            &project.misc.iter().map(|x| x.description.clone()).collect::<Vec<_>>() as _,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::Import)?;
+
+    sqlx::query!("
+            INSERT INTO project_excess
+            (
+                project_id,
+                type_id,
+                quantity,
+                cost
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::INTEGER[],
+                $3::INTEGER[],
+                $4::DOUBLE PRECISION[]
+            )
+        ",
+            *project_id,
+            &project.excess.iter().map(|x| *x.type_id).collect::<Vec<_>>(),
+            &project.excess.iter().map(|x| x.quantity).collect::<Vec<_>>(),
+            &project.excess.iter().map(|x| x.cost).collect::<Vec<_>>() as _,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::Import)?;
+
+    sqlx::query!("
+            INSERT INTO project_stock
+            (
+                project_id,
+                type_id,
+                quantity,
+                cost
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::INTEGER[],
+                $3::INTEGER[],
+                $4::DOUBLE PRECISION[]
+            )
+        ",
+            *project_id,
+            &project.stock.iter().map(|x| *x.type_id).collect::<Vec<_>>(),
+            &project.stock.iter().map(|x| x.quantity).collect::<Vec<_>>(),
+            &project.stock.iter().map(|x| x.cost).collect::<Vec<_>>() as _,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::Import)?;
+
+    sqlx::query!("
+            INSERT INTO project_market_structure
+            (
+                project_id,
+                structure_id
+            )
+            SELECT $1, * FROM UNNEST($2::UUID[])
+        ",
+            *project_id,
+            &market_structures,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::Import)?;
+
+    let targets = [
+        (true,                        AuditTarget::Project(project_id)),
+        (!project.jobs.is_empty(),    AuditTarget::ProjectJobs(project_id)),
+        (!project.market.is_empty(),  AuditTarget::ProjectMarket(project_id)),
+        (!project.misc.is_empty(),    AuditTarget::ProjectMisc(project_id)),
+        (!project.excess.is_empty(),  AuditTarget::ProjectExcess(project_id)),
+    ];
+    for (_, target) in targets.into_iter().filter(|(imported, _)| *imported) {
+        AuditRecord::created(
+                &mut transaction,
+                identity.character_id,
+                target,
+            )
+            .await?;
+    }
+
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+
+    Ok(project_id)
+}
+
+fn map_structure(
+    structures:   &HashMap<StructureUuid, StructureUuid>,
+    structure_id: StructureUuid,
+) -> Result<Uuid, String> {
+    structures
+        .get(&structure_id)
+        .map(|x| **x)
+        .ok_or_else(|| format!("structure '{}' is not part of the export", structure_id))
+}
+
+#[cfg(test)]
+mod import_project_test {
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::ProjectGroupUuid;
+    use starfoundry_lib_industry::project::{ImportProject, ProjectExportProduct};
+    use starfoundry_lib_types::{CharacterId, CorporationId, TypeId};
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::project::error::ProjectError;
+    use crate::project::service::export_project;
+    use crate::project_group::permission::MemberIdentity;
+    use crate::test_util::EveGatewayTestApiClient;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base")
+        ),
+    )]
+    async fn roundtrip(
+        pool: PgPool,
+    ) {
+        let export = export_project(
+                &pool,
+                Uuid::from_str("00000000-0000-0000-0000-000000000101").unwrap().into(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(export.jobs.len(), 2);
+        assert_eq!(export.structures.len(), 1);
+
+        let result = super::import_project(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                ImportProject {
+                    project_group_id:  Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                    structure_mapping: HashMap::new(),
+                    project:           export.clone(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let imported = export_project(&pool, result).await.unwrap();
+        assert_eq!(imported.name, export.name);
+        assert_eq!(imported.jobs.len(), export.jobs.len());
+        assert_eq!(
+            imported.structures.iter().map(|x| x.id).collect::<Vec<_>>(),
+            export.structures.iter().map(|x| x.id).collect::<Vec<_>>(),
+        );
+
+        let scopes = sqlx::query!(r#"
+                SELECT scope::TEXT AS "scope!"
+                FROM audit_event
+                WHERE entity_id = $1
+                AND action = 'CREATE'
+            "#,
+                *result,
+            )
+            .fetch_all(&pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|x| x.scope)
+            .collect::<Vec<_>>();
+        assert!(scopes.contains(&"PROJECT".into()));
+        assert!(scopes.contains(&"PROJECT_JOB".into()));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base")
+        ),
+    )]
+    async fn validation_error(
+        pool: PgPool,
+    ) {
+        let export = export_project(
+                &pool,
+                Uuid::from_str("00000000-0000-0000-0000-000000000101").unwrap().into(),
+            )
+            .await
+            .unwrap();
+        let project_group_id: ProjectGroupUuid = Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into();
+
+        let mut newer = export.clone();
+        newer.version += 1;
+        let result = super::import_project(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                ImportProject {
+                    project_group_id,
+                    structure_mapping: HashMap::new(),
+                    project:           newer,
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectError::ValidationError(_))));
+
+        let mut unknown_type = export.clone();
+        unknown_type.products.push(ProjectExportProduct {
+            type_id:             TypeId(1),
+            quantity:            1,
+            material_efficiency: 0,
+        });
+        let result = super::import_project(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                ImportProject {
+                    project_group_id,
+                    structure_mapping: HashMap::new(),
+                    project:           unknown_type,
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectError::ValidationError(_))));
+
+        // character 3 doesn't have access to the structure
+        let result = super::import_project(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                MemberIdentity::new(CharacterId(3), CorporationId(3), None),
+                ImportProject {
+                    project_group_id,
+                    structure_mapping: HashMap::new(),
+                    project:           export,
+                },
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -1,10 +1,14 @@
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::{ProjectUuid, SolutionUuid};
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::{ProjectError, Result};
 
 pub async fn initialize(
     pool:           &PgPool,
+    character_id:   CharacterId,
     project_id:     ProjectUuid,
     solution_id:    SolutionUuid,
 ) -> Result<()> {
@@ -12,18 +16,78 @@ pub async fn initialize(
         .begin()
         .await
         .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::Project(project_id),
+        )
+        .await?;
+
+    initialize_from_solution(
+            &mut transaction,
+            project_id,
+            solution_id,
+        )
+        .await?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+
+    Ok(())
+}
+
+/// Takes over the jobs, materials, stock and excess of the solution into the
+/// project.
+/// 
+/// # Errors
+/// 
+/// - [ProjectError::ValidationError] if the solution belongs to another
+///   project group than the project
+/// 
+pub(crate) async fn initialize_from_solution(
+    connection:  &mut PgConnection,
+    project_id:  ProjectUuid,
+    solution_id: SolutionUuid,
+) -> Result<()> {
+    let same_group = sqlx::query!("
+            SELECT s.id
+            FROM solution s
+            JOIN project p ON p.project_group_id = s.project_group_id
+            WHERE s.id = $1
+            AND p.id = $2
+        ",
+            *solution_id,
+            *project_id,
+        )
+        .fetch_optional(&mut *connection)
+        .await
+        .map_err(ProjectError::Initialize)?
+        .is_some();
+    if !same_group {
+        return Err(ProjectError::ValidationError(
+            "the solution does not belong to the project group of the project".into()
+        ));
+    }
 
     sqlx::query!("
             UPDATE project
             SET
                 solution_id = $2,
+                previous_status = CASE
+                    WHEN status != 'READY_TO_START' THEN status
+                    ELSE previous_status
+                END,
                 status = 'READY_TO_START'
             WHERE id = $1
         ",
             *project_id,
             *solution_id,
         )
-        .execute(&mut *transaction)
+        .execute(&mut *connection)
         .await
         .map_err(ProjectError::Initialize)?;
 
@@ -43,7 +107,7 @@ pub async fn initialize(
             *project_id,
             *solution_id,
         )
-        .execute(&mut *transaction)
+        .execute(&mut *connection)
         .await
         .map_err(ProjectError::Initialize)?;
 
@@ -63,7 +127,7 @@ pub async fn initialize(
             *project_id,
             *solution_id,
         )
-        .execute(&mut *transaction)
+        .execute(&mut *connection)
         .await
         .map_err(ProjectError::Initialize)?;
 
@@ -87,7 +151,7 @@ pub async fn initialize(
             *project_id,
             *solution_id,
         )
-        .execute(&mut *transaction)
+        .execute(&mut *connection)
         .await
         .map_err(ProjectError::Initialize)?;
 
@@ -108,14 +172,9 @@ pub async fn initialize(
             *project_id,
             *solution_id,
         )
-        .execute(&mut *transaction)
+        .execute(&mut *connection)
         .await
         .map_err(ProjectError::Initialize)?;
 
-    transaction
-        .commit()
-        .await
-        .map_err(ProjectError::TransactionError)?;
-
     Ok(())
 }
@@ -1,19 +1,19 @@
 use sqlx::PgPool;
 use starfoundry_lib_industry::project::{ProjectFilter, ProjectMinimal, ProjectStatus};
-use starfoundry_lib_types::CharacterId;
 use std::collections::HashMap;
 
 use crate::project::error::{ProjectError, Result};
+use crate::project_group::permission::MemberIdentity;
 use crate::project_group::service::ProjectGroupFilter;
 
 pub async fn list(
     pool:           &PgPool,
-    character_id:   CharacterId,
+    identity:       MemberIdentity,
     filter:         ProjectFilter,
 ) -> Result<Vec<ProjectMinimal>> {
     let user_project_groups = crate::project_group::service::list(
             pool,
-            character_id,
+            identity,
             Default::default()
         )
         .await?
@@ -66,7 +66,7 @@ pub async fn list(
                 )
             ORDER BY name
         "#,
-            *character_id,
+            *identity.character_id,
             filter.name,
             &filter_status as _,
             filter.orderer,
@@ -79,7 +79,7 @@ pub async fn list(
 
     let project_groups = crate::project_group::service::list(
             pool,
-            character_id,
+            identity,
             ProjectGroupFilter::default(),
         )
         .await?
@@ -112,9 +112,10 @@ pub async fn list(
 #[cfg(test)]
 mod list_project_group_test {
     use sqlx::PgPool;
-    use starfoundry_lib_types::CharacterId;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
 
     use crate::project::service::list::ProjectFilter;
+    use crate::project_group::permission::MemberIdentity;
 
     #[sqlx::test(
         fixtures(
@@ -127,7 +128,7 @@ mod list_project_group_test {
     ) {
         let result = super::list(
                 &pool,
-                CharacterId(1),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
                 ProjectFilter::default(),
             )
             .await;
@@ -136,7 +137,7 @@ mod list_project_group_test {
 
         let result = super::list(
                 &pool,
-                CharacterId(1),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
                 ProjectFilter {
                     name: Some(String::from("Filter")),
                     ..Default::default()
@@ -148,7 +149,7 @@ mod list_project_group_test {
 
         let result = super::list(
                 &pool,
-                CharacterId(1),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
                 ProjectFilter {
                     name: Some(String::from("SomeGibberish")),
                     ..Default::default()
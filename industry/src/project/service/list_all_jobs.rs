@@ -2,22 +2,22 @@ use chrono::NaiveDateTime;
 use sqlx::PgPool;
 use starfoundry_lib_eve_gateway::EveGatewayApiClient;
 use starfoundry_lib_industry::project::{ProjectFilter, ProjectJob, ProjectJobAllGroup, ProjectJobStatus};
-use starfoundry_lib_types::CharacterId;
 use std::collections::HashMap;
 
 use crate::{sort_by_job_flat};
 use crate::project::error::{ProjectError, Result};
 use crate::project::service::{determine_ready_to_start, list};
+use crate::project_group::permission::MemberIdentity;
 use crate::structure::service::FetchStructureQuery;
 
 pub async fn list_all_jobs(
     pool:                   &PgPool,
-    character_id:           CharacterId,
+    identity:               MemberIdentity,
     eve_gateway_api_client: &impl EveGatewayApiClient,
 ) -> Result<Vec<ProjectJobAllGroup>> {
     let projects = list(
             pool,
-            character_id,
+            identity,
             ProjectFilter {
                 status: Some("IN_PROGRESS".into()),
                 ..Default::default()
@@ -78,7 +78,7 @@ pub async fn list_all_jobs(
     let structures = crate::structure::service::fetch_bulk(
             pool,
             eve_gateway_api_client,
-            character_id,
+            identity.character_id,
             structure_ids,
             FetchStructureQuery::default(),
         )
@@ -87,6 +87,7 @@ pub async fn list_market_buy(
                 smart_buy_config: Some(SmartBuyConfig {
                     gas_decompression: config.gas_decompression,
                     mineral_compression: config.mineral_compression,
+                    contracts: config.contracts,
                 }),
                 ..Default::default()
             })
@@ -0,0 +1,62 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_market::{MarketApiClientOrder, MarketItem, MarketSellRequest, MarketSellResponse};
+
+use crate::market_api_client;
+use crate::project::error::{ProjectError, Result};
+use crate::project::list_market_sell::ListMarketSellQuery;
+
+/// Asks the market service where the products and the excess of the
+/// project should be sold.
+///
+/// Returns `None` if the project has neither products nor excess.
+///
+pub async fn list_market_sell(
+    pool:       &PgPool,
+    project_id: ProjectUuid,
+    config:     ListMarketSellQuery,
+) -> Result<Option<MarketSellResponse>> {
+    let items = sqlx::query!(r#"
+            SELECT
+                type_id AS "type_id!",
+                SUM(quantity)::INTEGER AS "quantity!"
+            FROM (
+                SELECT sp.type_id, sp.quantity
+                FROM solution_product sp
+                JOIN project p ON p.solution_id = sp.solution_id
+                WHERE p.id = $1
+                UNION ALL
+                SELECT type_id, quantity
+                FROM project_excess
+                WHERE project_id = $1
+            ) items
+            GROUP BY type_id
+            ORDER BY type_id
+        "#,
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::Fetch(e, project_id))?
+        .into_iter()
+        .filter(|x| x.quantity > 0)
+        .map(|x| MarketItem {
+            type_id:  x.type_id.into(),
+            quantity: x.quantity,
+        })
+        .collect::<Vec<_>>();
+
+    if items.is_empty() {
+        return Ok(None);
+    }
+
+    market_api_client()?
+        .sell_plan(MarketSellRequest {
+            markets: config.structure_ids,
+            items,
+            fees:    config.fees,
+        })
+        .await
+        .map(Some)
+        .map_err(Into::into)
+}
@@ -0,0 +1,62 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::project::{ProjectReservation, ProjectReservationSource};
+use starfoundry_lib_industry::ProjectUuid;
+use std::collections::HashMap;
+
+use crate::project::error::{ProjectError, Result};
+use crate::sort_by_market_group_flat;
+
+pub async fn list_reservations(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_id:             ProjectUuid,
+) -> Result<Vec<ProjectReservation>> {
+    let entries = sqlx::query!(r#"
+            SELECT
+                source AS "source!: ProjectReservationSource",
+                type_id,
+                location_id,
+                quantity
+            FROM project_reservation
+            WHERE project_id = $1
+        "#,
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::ListReservations(e, project_id))?;
+
+    let mut type_ids = entries
+        .iter()
+        .map(|x| x.type_id.into())
+        .collect::<Vec<_>>();
+    type_ids.sort();
+    type_ids.dedup();
+    let items = eve_gateway_api_client
+        .fetch_item_bulk(type_ids)
+        .await?
+        .into_iter()
+        .map(|x| (x.type_id, x))
+        .collect::<HashMap<_, _>>();
+
+    let mut result = Vec::new();
+    for entry in entries {
+        let item = if let Some(x) = items.get(&entry.type_id.into()) {
+            x.clone()
+        } else {
+            continue;
+        };
+
+        result.push(ProjectReservation {
+            item:        item,
+            source:      entry.source,
+            location_id: entry.location_id.into(),
+            quantity:    entry.quantity,
+        });
+    }
+
+    Ok(sort_market(result))
+}
+
+sort_by_market_group_flat!(sort_market, ProjectReservation);
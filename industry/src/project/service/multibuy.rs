@@ -0,0 +1,240 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClientItem;
+use starfoundry_lib_industry::project::ProjectMultiBuy;
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_market::MarketBulkResponse;
+use std::collections::BTreeMap;
+
+use crate::project::error::{ProjectError, Result};
+use crate::project::list_market_buy::ListMarketBuyQuery;
+use crate::project::service::list_market_buy;
+
+/// Formats the materials that need to be bought for the in-game MultiBuy
+/// window.
+///
+/// Every market gets its own lists, and if a cargo capacity is given a list
+/// never exceeds it, so that every list can be bought and hauled in one go.
+/// Items that are bought with a public contract can't be bought with MultiBuy
+/// and are not included.
+///
+/// # Errors
+///
+/// - [ProjectError::ValidationError] if the cargo capacity is not positive
+///
+pub async fn list_market_multibuy(
+    pool:                   &PgPool,
+    project_id:             ProjectUuid,
+    eve_gateway_api_client: &impl EveGatewayApiClientItem,
+    config:                 ListMarketBuyQuery,
+    cargo_capacity:         Option<f64>,
+) -> Result<Vec<ProjectMultiBuy>> {
+    if let Some(x) = cargo_capacity && (!x.is_finite() || x <= 0f64) {
+        return Err(ProjectError::ValidationError(
+            "Field 'cargo_capacity' must be positive".into()
+        ));
+    }
+
+    let entries = list_market_buy(
+            pool,
+            project_id,
+            eve_gateway_api_client,
+            config,
+        )
+        .await?
+        .into_iter()
+        .flat_map(|x| x.entries)
+        .collect::<Vec<_>>();
+
+    Ok(split_multibuy(entries, cargo_capacity))
+}
+
+/// Splits the entries by their market, and every market into lists that fit
+/// into the cargo capacity.
+/// A single unit that is larger than the cargo capacity gets a list on its
+/// own, entries of contracts are skipped
+fn split_multibuy(
+    entries:        Vec<MarketBulkResponse>,
+    cargo_capacity: Option<f64>,
+) -> Vec<ProjectMultiBuy> {
+    let mut by_source = BTreeMap::new();
+    for entry in entries {
+        if entry.insufficient_data ||
+            entry.quantity == 0 ||
+            entry.contract_id.is_some() {
+            continue;
+        }
+
+        by_source
+            .entry(entry.source)
+            .or_insert_with(Vec::new)
+            .push(entry);
+    }
+
+    let mut result = Vec::new();
+    for (source, mut entries) in by_source {
+        entries.sort_by(|a, b| a.item.name.cmp(&b.item.name));
+
+        let mut list = MultiBuyList::default();
+        for entry in entries {
+            // ships are bought packaged
+            let unit_volume = entry
+                .item
+                .repackaged
+                .map(|x| x as f64)
+                .unwrap_or(entry.item.volume as f64);
+
+            let mut remaining = entry.quantity;
+            while remaining > 0 {
+                let quantity = match cargo_capacity {
+                    Some(capacity) if unit_volume > 0f64 => {
+                        let fits = ((capacity - list.volume) / unit_volume).floor().max(0f64) as u64;
+                        if fits == 0 && !list.lines.is_empty() {
+                            result.push(list.finish(source));
+                            list = MultiBuyList::default();
+                            continue;
+                        }
+                        fits.clamp(1, remaining)
+                    },
+                    Some(_) |
+                    None => remaining,
+                };
+
+                list.lines.push(format!("{} {}", entry.item.name, quantity));
+                list.volume += unit_volume * quantity as f64;
+                list.cost += entry.price * quantity as f64;
+                remaining -= quantity;
+            }
+        }
+
+        if !list.lines.is_empty() {
+            result.push(list.finish(source));
+        }
+    }
+
+    result
+}
+
+#[derive(Default)]
+struct MultiBuyList {
+    lines:  Vec<String>,
+    volume: f64,
+    cost:   f64,
+}
+
+impl MultiBuyList {
+    fn finish(
+        self,
+        source: starfoundry_lib_types::StructureId,
+    ) -> ProjectMultiBuy {
+        ProjectMultiBuy {
+            source,
+            volume:   self.volume,
+            cost:     self.cost,
+            multibuy: self.lines.join("\n"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use starfoundry_lib_market::MarketBulkResponse;
+    use starfoundry_lib_types::ContractId;
+
+    use super::split_multibuy;
+
+    fn entry(
+        source:   i64,
+        name:     &str,
+        volume:   f32,
+        quantity: u64,
+    ) -> MarketBulkResponse {
+        serde_json::from_value(serde_json::json!({
+            "source": source,
+            "item": {
+                "type_id": 34,
+                "category": { "category_id": 4, "name": "Material" },
+                "group": { "group_id": 18, "category_id": 4, "name": "Mineral" },
+                "volume": volume,
+                "name": name,
+                "meta_group": null,
+                "repackaged": null
+            },
+            "quantity": quantity,
+            "price": 5f64,
+            "insufficient_data": false,
+            "last_fetch": null,
+            "buy_price": null,
+            "sell_price": null
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn split_by_market() {
+        let result = split_multibuy(
+            vec![
+                entry(1, "Tritanium", 0.01, 100),
+                entry(2, "Pyerite", 0.01, 50),
+                entry(1, "Mexallon", 0.01, 10),
+            ],
+            None,
+        );
+
+        assert_eq!(
+            result.iter().map(|x| x.multibuy.clone()).collect::<Vec<_>>(),
+            vec!["Mexallon 10\nTritanium 100".to_string(), "Pyerite 50".to_string()],
+        );
+        assert_eq!(
+            result.iter().map(|x| x.cost).collect::<Vec<_>>(),
+            vec![550f64, 250f64],
+        );
+    }
+
+    #[test]
+    fn split_by_cargo_capacity() {
+        let result = split_multibuy(
+            vec![
+                entry(1, "Mexallon", 1f32, 15),
+                entry(1, "Tritanium", 1f32, 20),
+                entry(1, "Large Thing", 50f32, 1),
+            ],
+            Some(20f64),
+        );
+
+        assert_eq!(
+            result.iter().map(|x| x.multibuy.clone()).collect::<Vec<_>>(),
+            vec![
+                "Large Thing 1".to_string(),
+                "Mexallon 15\nTritanium 5".to_string(),
+                "Tritanium 15".to_string(),
+            ],
+        );
+        assert_eq!(
+            result.iter().map(|x| x.volume).collect::<Vec<_>>(),
+            vec![50f64, 20f64, 15f64],
+        );
+    }
+
+    #[test]
+    fn skip_contracts() {
+        let mut contract = entry(1, "Pyerite", 0.01, 50);
+        contract.contract_id = Some(ContractId(1));
+
+        let result = split_multibuy(
+            vec![
+                entry(1, "Tritanium", 0.01, 100),
+                contract,
+            ],
+            None,
+        );
+
+        assert_eq!(
+            result.iter().map(|x| x.multibuy.clone()).collect::<Vec<_>>(),
+            vec!["Tritanium 100".to_string()],
+        );
+        assert_eq!(
+            result.iter().map(|x| x.cost).collect::<Vec<_>>(),
+            vec![500f64],
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{AssetStockFilter, EveGatewayApiClient};
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::CharacterId;
+
+use crate::project::error::{ProjectError, Result};
+
+/// Replaces the asset stock entries of the project with the currently synced
+/// assets.
+///
+/// The eve gateway client must be authenticated as the character that is
+/// stored with the filter, the worker uses it to keep the stock up to date.
+///
+/// # Errors
+///
+/// - [ProjectError::Forbidden] if the character is not the one stored with
+///   the filter
+///
+pub async fn refresh_asset_stock(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    character_id:           CharacterId,
+    project_id:             ProjectUuid,
+) -> Result<()> {
+    let filter = sqlx::query!("
+            SELECT
+                character_id,
+                location_ids,
+                location_flags,
+                container_names
+            FROM project_asset_stock
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ProjectError::FetchAssetStock(e, project_id))?;
+
+    let filter = if let Some(x) = filter {
+        if x.character_id != *character_id {
+            return Err(ProjectError::Forbidden(project_id, character_id));
+        }
+
+        AssetStockFilter {
+            location_ids:    x.location_ids.into_iter().map(Into::into).collect::<Vec<_>>(),
+            location_flags:  x.location_flags,
+            container_names: x.container_names,
+            ..Default::default()
+        }
+    } else {
+        return Ok(());
+    };
+
+    let stock = eve_gateway_api_client
+        .eve_list_asset_stock(&filter)
+        .await?;
+
+    let mut type_ids        = Vec::new();
+    let mut quantities      = Vec::new();
+    let mut location_ids    = Vec::new();
+    let mut location_flags  = Vec::new();
+    let mut container_names = Vec::new();
+    for entry in stock {
+        type_ids.push(*entry.type_id);
+        quantities.push(entry.quantity);
+        location_ids.push(*entry.location_id);
+        location_flags.push(entry.location_flag);
+        container_names.push(entry.container_name);
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+
+    sqlx::query!("
+            DELETE FROM project_asset_stock_entry
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateAssetStock(e, project_id))?;
+
+    sqlx::query!("
+            INSERT INTO project_asset_stock_entry
+            (
+                project_id,
+                type_id,
+                quantity,
+                location_id,
+                location_flag,
+                container_name
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::INTEGER[],
+                $3::BIGINT[],
+                $4::BIGINT[],
+                $5::VARCHAR[],
+                $6::VARCHAR[]
+            )
+        ",
+            *project_id,
+            &type_ids,
+            &quantities,
+            &location_ids,
+            &location_flags,
+            &container_names as _,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateAssetStock(e, project_id))?;
+
+    sqlx::query!("
+            UPDATE project_asset_stock
+            SET refreshed_at = NOW()
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateAssetStock(e, project_id))?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectError::TransactionError)
+}
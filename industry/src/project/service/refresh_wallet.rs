@@ -0,0 +1,374 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{EveGatewayApiClient, WalletFilter};
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::project::{JOB_REF_TYPES, ProjectWalletKind, WalletJobEntry, WalletMarketEntry, reconcile_wallet};
+use starfoundry_lib_industry::{MarketUuid, ProjectJobUuid, ProjectUuid};
+use starfoundry_lib_types::{CharacterId, JobId, TypeId};
+use std::collections::{HashMap, HashSet};
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project::error::{ProjectError, Result};
+
+/// Matches the synced wallets against the market and job entries of the
+/// project and takes over the costs.
+///
+/// Only wallet entries between the creation of the project and its
+/// completion are considered.
+/// Transactions and journal entries that are already matched to another
+/// project are skipped.
+/// Changed costs are recorded as updates of the market and job entries.
+///
+/// The eve gateway client must be authenticated as the character that is
+/// stored with the wallet, the worker uses it to keep the costs up to date.
+///
+/// # Errors
+///
+/// - [ProjectError::Forbidden] if the character is not the one stored with
+///   the wallet
+///
+pub async fn refresh_wallet(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    character_id:           CharacterId,
+    project_id:             ProjectUuid,
+) -> Result<()> {
+    let project = sqlx::query!(r#"
+            SELECT
+                pw.character_id,
+                p.created_at,
+                CASE
+                    WHEN p.status = 'DONE'
+                    THEN p.updated_at
+                    ELSE NULL
+                END AS finished_at
+            FROM project p
+            JOIN project_wallet pw ON pw.project_id = p.id
+            WHERE p.id = $1
+        "#,
+            *project_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ProjectError::FetchWallet(e, project_id))?;
+
+    let project = if let Some(x) = project {
+        if x.character_id != *character_id {
+            return Err(ProjectError::Forbidden(project_id, character_id));
+        }
+
+        x
+    } else {
+        return Ok(());
+    };
+
+    let market = sqlx::query!("
+            SELECT
+                id,
+                type_id,
+                quantity
+            FROM project_market
+            WHERE project_id = $1
+            ORDER BY created_at, id
+        ",
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::FetchWallet(e, project_id))?
+        .into_iter()
+        .map(|x| WalletMarketEntry {
+            id:       MarketUuid::new(x.id),
+            type_id:  TypeId(x.type_id),
+            quantity: x.quantity,
+        })
+        .collect::<Vec<_>>();
+
+    let jobs = sqlx::query!(r#"
+            SELECT
+                id,
+                job_id AS "job_id!"
+            FROM project_job
+            WHERE project_id = $1
+            AND job_id IS NOT NULL
+        "#,
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::FetchWallet(e, project_id))?
+        .into_iter()
+        .map(|x| WalletJobEntry {
+            id:     ProjectJobUuid::new(x.id),
+            job_id: JobId(x.job_id),
+        })
+        .collect::<Vec<_>>();
+
+    let filter = WalletFilter {
+        from:      project.created_at,
+        to:        project.finished_at,
+        ref_types: JOB_REF_TYPES
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>(),
+    };
+    let mut transactions = eve_gateway_api_client
+        .eve_list_wallet_transactions(&filter)
+        .await?;
+    let mut journal = eve_gateway_api_client
+        .eve_list_wallet_journal(&filter)
+        .await?;
+
+    // remove everything that is already claimed by other projects
+    let claimed = sqlx::query!(r#"
+            SELECT
+                kind AS "kind!: ProjectWalletKind",
+                reference_id,
+                SUM(quantity)::INTEGER AS "quantity!"
+            FROM project_wallet_match
+            WHERE project_id != $1
+            GROUP BY kind, reference_id
+        "#,
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::FetchWallet(e, project_id))?
+        .into_iter()
+        .map(|x| ((x.kind, x.reference_id), x.quantity))
+        .collect::<HashMap<_, _>>();
+
+    let context_ids = journal
+        .iter()
+        .filter_map(|x| x.context_id)
+        .filter_map(|x| i32::try_from(x).ok())
+        .collect::<Vec<_>>();
+    let foreign_jobs = sqlx::query!(r#"
+            SELECT job_id AS "job_id!"
+            FROM project_job
+            WHERE project_id != $1
+            AND job_id = ANY($2)
+        "#,
+            *project_id,
+            &context_ids,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectError::FetchWallet(e, project_id))?
+        .into_iter()
+        .map(|x| x.job_id as i64)
+        .collect::<HashSet<_>>();
+
+    transactions
+        .iter_mut()
+        .for_each(|x| {
+            let claimed = claimed
+                .get(&(ProjectWalletKind::Market, *x.transaction_id))
+                .copied()
+                .unwrap_or_default();
+            x.quantity = (x.quantity - claimed).max(0);
+        });
+    journal
+        .retain(|x| {
+            !claimed.contains_key(&(ProjectWalletKind::Job, *x.id)) &&
+            !x.context_id.map(|x| foreign_jobs.contains(&x)).unwrap_or_default()
+        });
+
+    let reconciliation = reconcile_wallet(
+        &market,
+        &jobs,
+        transactions,
+        journal,
+    );
+
+    let mut match_kinds         = Vec::new();
+    let mut match_entry_ids     = Vec::new();
+    let mut match_reference_ids = Vec::new();
+    let mut match_quantities    = Vec::new();
+    let mut match_costs         = Vec::new();
+    for entry in reconciliation.matches {
+        match_kinds.push(entry.kind);
+        match_entry_ids.push(entry.entry_id);
+        match_reference_ids.push(entry.reference_id);
+        match_quantities.push(entry.quantity);
+        match_costs.push(entry.cost);
+    }
+
+    let mut unmatched_kinds         = Vec::new();
+    let mut unmatched_reference_ids = Vec::new();
+    let mut unmatched_type_ids      = Vec::new();
+    let mut unmatched_quantities    = Vec::new();
+    let mut unmatched_amounts       = Vec::new();
+    let mut unmatched_dates         = Vec::new();
+    for entry in reconciliation.unmatched {
+        unmatched_kinds.push(entry.kind);
+        unmatched_reference_ids.push(entry.reference_id);
+        unmatched_type_ids.push(entry.type_id.map(|x| *x));
+        unmatched_quantities.push(entry.quantity);
+        unmatched_amounts.push(entry.amount);
+        unmatched_dates.push(entry.date);
+    }
+
+    let (market_ids, market_costs): (Vec<_>, Vec<_>) = reconciliation
+        .market_costs
+        .into_iter()
+        .map(|(id, cost)| (*id, cost))
+        .unzip();
+    let (job_ids, job_costs): (Vec<_>, Vec<_>) = reconciliation
+        .job_costs
+        .into_iter()
+        .map(|(id, cost)| (*id, cost))
+        .unzip();
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let market_audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectMarket(project_id),
+        )
+        .await?;
+    let job_audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectJobs(project_id),
+        )
+        .await?;
+
+    sqlx::query!("
+            DELETE FROM project_wallet_match
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateWallet(e, project_id))?;
+    sqlx::query!("
+            DELETE FROM project_wallet_unmatched
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateWallet(e, project_id))?;
+
+    sqlx::query!("
+            INSERT INTO project_wallet_match
+            (
+                project_id,
+                kind,
+                entry_id,
+                reference_id,
+                quantity,
+                cost
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::PROJECT_WALLET_KIND[],
+                $3::UUID[],
+                $4::BIGINT[],
+                $5::INTEGER[],
+                $6::DOUBLE PRECISION[]
+            )
+        ",
+            *project_id,
+            &match_kinds as _,
+            &match_entry_ids,
+            &match_reference_ids,
+            &match_quantities,
+            &match_costs,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateWallet(e, project_id))?;
+
+    sqlx::query!("
+            INSERT INTO project_wallet_unmatched
+            (
+                project_id,
+                kind,
+                reference_id,
+                type_id,
+                quantity,
+                amount,
+                date
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::PROJECT_WALLET_KIND[],
+                $3::BIGINT[],
+                $4::INTEGER[],
+                $5::INTEGER[],
+                $6::DOUBLE PRECISION[],
+                $7::TIMESTAMPTZ[]
+            )
+        ",
+            *project_id,
+            &unmatched_kinds as _,
+            &unmatched_reference_ids,
+            &unmatched_type_ids as _,
+            &unmatched_quantities as _,
+            &unmatched_amounts,
+            &unmatched_dates,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateWallet(e, project_id))?;
+
+    sqlx::query!("
+            UPDATE project_market
+            SET cost = data.cost
+            FROM UNNEST(
+                $2::UUID[],
+                $3::DOUBLE PRECISION[]
+            ) AS data(id, cost)
+            WHERE project_market.id = data.id
+            AND project_market.project_id = $1
+        ",
+            *project_id,
+            &market_ids,
+            &market_costs,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateWallet(e, project_id))?;
+
+    sqlx::query!("
+            UPDATE project_job
+            SET cost = data.cost
+            FROM UNNEST(
+                $2::UUID[],
+                $3::DOUBLE PRECISION[]
+            ) AS data(id, cost)
+            WHERE project_job.id = data.id
+            AND project_job.project_id = $1
+        ",
+            *project_id,
+            &job_ids,
+            &job_costs,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateWallet(e, project_id))?;
+
+    sqlx::query!("
+            UPDATE project_wallet
+            SET reconciled_at = NOW()
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateWallet(e, project_id))?;
+
+    market_audit.finish(&mut transaction).await?;
+    job_audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectError::TransactionError)
+}
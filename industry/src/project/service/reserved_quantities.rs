@@ -0,0 +1,103 @@
+use sqlx::PgPool;
+use starfoundry_lib_types::{LocationId, TypeId};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::project::error::{ProjectError, Result};
+
+/// Quantities that are reserved by projects, grouped by type.
+///
+/// Used to hide the reserved quantities from the stock other projects see.
+///
+#[derive(Clone, Debug, Default)]
+pub struct ReservedQuantities(HashMap<TypeId, i64>);
+
+impl ReservedQuantities {
+    /// Loads the reservations of all projects in the given groups that are
+    /// not done yet.
+    ///
+    /// # Params
+    ///
+    /// - `project_group_ids` - Groups the reservations are loaded from
+    /// - `exclude` - Projects whose reservations are ignored, usually the
+    ///   projects the stock is checked for
+    /// - `location_id` - Only loads the reservations in the location, if
+    ///   `None` the reservations of all locations are loaded
+    ///
+    pub async fn load(
+        pool:              &PgPool,
+        project_group_ids: Vec<Uuid>,
+        exclude:           Vec<Uuid>,
+        location_id:       Option<LocationId>,
+    ) -> Result<Self> {
+        let entries = sqlx::query!(r#"
+                SELECT
+                    pr.type_id,
+                    SUM(pr.quantity)::BIGINT AS "quantity!"
+                FROM project_reservation pr
+                JOIN project p ON p.id = pr.project_id
+                WHERE p.project_group_id = ANY($1)
+                AND NOT (p.id = ANY($2))
+                AND p.status != 'DONE'
+                AND ($3::BIGINT IS NULL OR pr.location_id = $3)
+                GROUP BY pr.type_id
+            "#,
+                &project_group_ids,
+                &exclude,
+                location_id.map(|x| *x),
+            )
+            .fetch_all(pool)
+            .await
+            .map_err(ProjectError::ListReservedQuantities)?
+            .into_iter()
+            .map(|x| (x.type_id.into(), x.quantity))
+            .collect::<HashMap<_, _>>();
+
+        Ok(Self(entries))
+    }
+
+    /// Takes the given quantity and returns the part that is not reserved.
+    ///
+    /// The reservation is used up by the call, so that stock entries of the
+    /// same type are only reduced once.
+    ///
+    pub fn unreserved(
+        &mut self,
+        type_id:  TypeId,
+        quantity: i32,
+    ) -> i32 {
+        let reserved = if let Some(x) = self.0.get_mut(&type_id) {
+            x
+        } else {
+            return quantity;
+        };
+
+        let taken = (*reserved).min(quantity.max(0) as i64);
+        *reserved -= taken;
+        quantity - taken as i32
+    }
+}
+
+impl From<HashMap<TypeId, i64>> for ReservedQuantities {
+    fn from(value: HashMap<TypeId, i64>) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use starfoundry_lib_types::TypeId;
+    use std::collections::HashMap;
+
+    use super::ReservedQuantities;
+
+    #[test]
+    fn unreserved_is_reduced_once() {
+        let mut reserved = ReservedQuantities(HashMap::from([(TypeId(34), 1_500)]));
+
+        assert_eq!(reserved.unreserved(TypeId(34), 1_000), 0);
+        assert_eq!(reserved.unreserved(TypeId(34), 1_000), 500);
+        assert_eq!(reserved.unreserved(TypeId(34), 1_000), 1_000);
+        assert_eq!(reserved.unreserved(TypeId(35), 10), 10);
+    }
+}
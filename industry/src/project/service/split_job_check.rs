@@ -2,6 +2,7 @@ use sqlx::PgPool;
 use starfoundry_lib_eve_gateway::EveGatewayApiClient;
 use starfoundry_lib_industry::{ProjectUuid, StructureUuid};
 use starfoundry_lib_industry::industry::StockMinimal;
+use starfoundry_lib_industry::structure::Structure;
 use starfoundry_lib_industry::project::{ProjectJobFilter, ProjectJobStatus, SplitJobRequest, SplitJobResponse, SplitJobResponseJobEntry, SplitJobResponseMarketEntry};
 use starfoundry_lib_types::{CharacterId, TypeId};
 use std::collections::HashMap;
@@ -35,27 +36,47 @@ pub async fn split_job_check(
         return Err(ProjectError::NoIndustryHub);
     };
 
-    let industry_hub_id = sqlx::query!("
-            SELECT industry_hub_id
-            FROM solution
-            WHERE id = $1
-        ",
+    // combined solutions use the structures of multiple hubs
+    let industry_hub_ids = sqlx::query!(r#"
+            SELECT COALESCE(
+                NULLIF(
+                    ARRAY(
+                        SELECT industry_hub_id
+                        FROM solution_industry_hub
+                        WHERE solution_id = s.id
+                        ORDER BY industry_hub_id
+                    ),
+                    '{}'
+                ),
+                ARRAY[s.industry_hub_id]
+            ) AS "industry_hub_ids!"
+            FROM solution s
+            WHERE s.id = $1
+        "#,
             *solution_id,
         )
         .fetch_one(pool)
         .await
         .map_err(|_| ProjectError::SolutionNotFound)?
-        .industry_hub_id
-        .into();
+        .industry_hub_ids;
 
-    let industry_hub = crate::industry_hub::service::fetch(
-            pool,
-            eve_gateway_api_client,
-            character_id,
-            industry_hub_id,
-        )
-        .await?
-        .ok_or(ProjectError::NoIndustryHub)?;
+    let mut structures = Vec::new();
+    for industry_hub_id in industry_hub_ids {
+        let industry_hub = crate::industry_hub::service::fetch(
+                pool,
+                eve_gateway_api_client,
+                character_id,
+                industry_hub_id.into(),
+            )
+            .await?
+            .ok_or(ProjectError::NoIndustryHub)?;
+
+        for structure in industry_hub.structures {
+            if structures.iter().all(|x: &Structure| x.id != structure.id) {
+                structures.push(structure);
+            }
+        }
+    }
 
     let project_group_uuid = project.project_group.id;
     let blacklist_type_ids = list_default_blacklist(
@@ -129,8 +150,7 @@ pub async fn split_job_check(
         .collect::<Vec<_>>();
     excess.extend(jobs);
 
-    let structure_mappings = industry_hub
-        .structures
+    let structure_mappings = structures
         .iter()
         .map(|x| StructureMapping {
             category_group: x.joined_categories_groups(),
@@ -144,7 +164,7 @@ pub async fn split_job_check(
         .unwrap();
 
     let project_config = ProjectConfigBuilder::default()
-        .add_structures(industry_hub.structures)
+        .add_structures(structures)
         .add_structure_mappings(structure_mappings)
         .add_blacklists(blacklist_type_ids)
         .add_blueprint_overwrites(overwrites)
@@ -1,11 +1,15 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::{ProjectError, Result};
-use starfoundry_lib_industry::project::UpdateProject;
+use starfoundry_lib_industry::project::{ProjectStatus, UpdateProject};
 
 pub async fn update(
     pool:           &PgPool,
+    character_id:   CharacterId,
     project_id:     ProjectUuid,
     update:         UpdateProject,
 ) -> Result<()> {
@@ -13,6 +17,13 @@ pub async fn update(
         .begin()
         .await
         .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::Project(project_id),
+        )
+        .await?;
 
     let result = sqlx::query!("
             UPDATE project
@@ -21,6 +32,10 @@ pub async fn update(
                 orderer = $3,
                 name = $4,
                 note = $5,
+                previous_status = CASE
+                    WHEN status != $6 THEN status
+                    ELSE previous_status
+                END,
                 status = $6
             WHERE id = $1
         ",
@@ -39,11 +54,20 @@ pub async fn update(
         return Err(ProjectError::NotFound(project_id));
     }
 
+    // only the manual tags of the character are replaced, automatic tags and
+    // tags of other characters stay
     sqlx::query!("
             DELETE FROM project_tag
             WHERE project_id = $1
+            AND tag_id IN (
+                SELECT id
+                FROM tag
+                WHERE owner_id = $2
+                AND typ = 'MANUAL'
+            )
         ",
             *project_id,
+            *character_id,
         )
         .execute(&mut *transaction)
         .await
@@ -55,17 +79,34 @@ pub async fn update(
                 project_id,
                 tag_id
             )
-            SELECT $1, * FROM UNNEST(
-                $2::UUID[]
-            )
+            SELECT $1, id
+            FROM tag
+            WHERE id = ANY($2::UUID[])
+            AND owner_id = $3
+            AND typ = 'MANUAL'
         ",
             *project_id,
             &update.tags.into_iter().map(|x| *x).collect::<Vec<_>>(),
+            *character_id,
         )
         .execute(&mut *transaction)
         .await
         .map_err(ProjectError::Update)?;
 
+    // a finished project no longer needs the materials it reserved
+    if update.status == ProjectStatus::Done {
+        sqlx::query!("
+                DELETE FROM project_reservation
+                WHERE project_id = $1
+            ",
+                *project_id,
+            )
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| ProjectError::UpdateReservations(e, project_id))?;
+    }
+
+    audit.finish(&mut transaction).await?;
     transaction
         .commit()
         .await
@@ -0,0 +1,94 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{AssetStockFilter, EveGatewayApiClient};
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::{CharacterId, CorporationId};
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project::error::{ProjectError, Result};
+use crate::project::service::refresh_asset_stock;
+
+/// Sets the filter of the assets that should be used as stock for the
+/// project and refreshes the stock right away.
+///
+/// The assets of the given character and its corporation are used, the
+/// worker keeps refreshing them in their name.
+///
+pub async fn update_asset_stock(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    character_id:           CharacterId,
+    corporation_id:         CorporationId,
+    domain:                 String,
+    project_id:             ProjectUuid,
+    filter:                 AssetStockFilter,
+) -> Result<()> {
+    if filter.location_ids.is_empty() {
+        return Err(ProjectError::ValidationError("at least one location is required".into()));
+    }
+
+    let location_ids = filter
+        .location_ids
+        .iter()
+        .map(|x| **x)
+        .collect::<Vec<_>>();
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectAssetStock(project_id),
+        )
+        .await?;
+
+    sqlx::query!("
+            INSERT INTO project_asset_stock
+            (
+                project_id,
+                character_id,
+                corporation_id,
+                domain,
+                location_ids,
+                location_flags,
+                container_names
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (project_id)
+            DO UPDATE SET
+                character_id    = EXCLUDED.character_id,
+                corporation_id  = EXCLUDED.corporation_id,
+                domain          = EXCLUDED.domain,
+                location_ids    = EXCLUDED.location_ids,
+                location_flags  = EXCLUDED.location_flags,
+                container_names = EXCLUDED.container_names
+        ",
+            *project_id,
+            *character_id,
+            *corporation_id,
+            domain,
+            &location_ids,
+            &filter.location_flags,
+            &filter.container_names,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateAssetStock(e, project_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+
+    refresh_asset_stock(
+        pool,
+        eve_gateway_api_client,
+        character_id,
+        project_id,
+    )
+    .await
+}
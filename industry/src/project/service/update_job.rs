@@ -1,11 +1,15 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::{ProjectJobUuid, ProjectUuid};
 use starfoundry_lib_industry::project::UpdateJob;
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::{ProjectError, Result};
 
 pub async fn update_job(
     pool:           &PgPool,
+    character_id:   CharacterId,
     project_id:     ProjectUuid,
     project_job_id: ProjectJobUuid,
     update:         UpdateJob,
@@ -18,6 +22,18 @@ pub async fn update_job(
         update.job_id
     };
 
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectJob(project_id, project_job_id),
+        )
+        .await?;
+
     let changes = sqlx::query!("
             UPDATE project_job
             SET
@@ -35,7 +51,7 @@ pub async fn update_job(
             job_id,
             update.runs,
         )
-        .execute(pool)
+        .execute(&mut *transaction)
         .await
         .map_err(ProjectError::Update)?;
 
@@ -43,5 +59,9 @@ pub async fn update_job(
         return Err(ProjectError::JobNotFound(project_id, project_job_id));
     }
 
-    Ok(())
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectError::TransactionError)
 }
@@ -1,16 +1,19 @@
 use serde::Deserialize;
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::ProjectUuid;
 use starfoundry_lib_market::{Asteroid, Gas, GasDecompressionEfficiency, MarketApiClient, MarketVirtualRequest, OreReprocessingEfficiency};
-use starfoundry_lib_types::{StructureId, TypeId};
+use starfoundry_lib_types::{CharacterId, StructureId, TypeId};
 use std::collections::HashMap;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::{ProjectError, Result};
 
 pub async fn update_market_bulk(
     pool:               &PgPool,
+    character_id:       CharacterId,
     project_id:         ProjectUuid,
     entries:            Vec<UpdateProjectMarket>,
     market_api_client:  &impl MarketApiClient,
@@ -175,6 +178,20 @@ pub async fn update_market_bulk(
         .begin()
         .await
         .map_err(ProjectError::TransactionError)?;
+    let audit_market = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectMarket(project_id),
+        )
+        .await?;
+    let audit_excess = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectExcess(project_id),
+        )
+        .await?;
 
     if !new_entries.is_empty() {
         let type_ids = new_entries
@@ -299,6 +316,8 @@ pub async fn update_market_bulk(
             .map_err(ProjectError::Update)?;
     }
 
+    audit_market.finish(&mut transaction).await?;
+    audit_excess.finish(&mut transaction).await?;
     transaction
         .commit()
         .await
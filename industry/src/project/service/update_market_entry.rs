@@ -1,16 +1,32 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::{ProjectUuid, MarketUuid};
 use starfoundry_lib_industry::project::UpdateMarketEntry;
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::ProjectError;
 use crate::project::error::Result;
 
 pub async fn update_market_entry(
     pool:           &PgPool,
+    character_id:   CharacterId,
     project_id:     ProjectUuid,
     market_id:      MarketUuid,
     update:         UpdateMarketEntry,
 ) -> Result<()> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectMarketEntry(project_id, market_id),
+        )
+        .await?;
+
     sqlx::query!(r#"
             UPDATE project_market
             SET
@@ -26,8 +42,13 @@ pub async fn update_market_entry(
             update.cost,
             update.source,
         )
-        .execute(pool)
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::Update)?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
         .await
-        .map(drop)
-        .map_err(ProjectError::Update)
+        .map_err(ProjectError::TransactionError)
 }
@@ -1,11 +1,15 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::project::UpdateMisc;
 use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project::error::{ProjectError, Result};
 
 pub async fn update_misc(
     pool:           &PgPool,
+    character_id:   CharacterId,
     project_id:     ProjectUuid,
     update:         Vec<UpdateMisc>,
 ) -> Result<()> {
@@ -13,6 +17,13 @@ pub async fn update_misc(
         .begin()
         .await
         .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectMisc(project_id),
+        )
+        .await?;
 
     sqlx::query!("
             DELETE FROM project_misc
@@ -63,10 +74,11 @@ pub async fn update_misc(
             &description as _,
             &cost,
         )
-        .execute(pool)
+        .execute(&mut *transaction)
         .await
         .map_err(ProjectError::Update)?;
 
+    audit.finish(&mut transaction).await?;
     transaction
         .commit()
         .await
@@ -0,0 +1,44 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::CharacterId;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project::error::{ProjectError, Result};
+
+pub async fn update_orderer(
+    pool:           &PgPool,
+    character_id:   CharacterId,
+    project_id:     ProjectUuid,
+    orderer:        String,
+) -> Result<()> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::Project(project_id),
+        )
+        .await?;
+
+    sqlx::query!("
+            UPDATE project
+            SET orderer = $2
+            WHERE id = $1
+        ",
+            *project_id,
+            orderer,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectError::Update)?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectError::TransactionError)
+}
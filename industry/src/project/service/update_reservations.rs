@@ -0,0 +1,131 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::project::{ProjectReservationEntry, ProjectReservationSource, ProjectStatus};
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::CharacterId;
+use std::collections::HashSet;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project::error::{ProjectError, Result};
+
+/// Replaces all reservations of the project.
+///
+/// Projects that are done can't reserve anything, their reservations are
+/// released when they are set to done.
+///
+pub async fn update_reservations(
+    pool:         &PgPool,
+    character_id: CharacterId,
+    project_id:   ProjectUuid,
+    entries:      Vec<ProjectReservationEntry>,
+) -> Result<()> {
+    let mut keys = HashSet::new();
+    for entry in entries.iter() {
+        if entry.quantity <= 0 {
+            return Err(ProjectError::ValidationError(
+                format!("the quantity of '{}' must be greater than 0", entry.type_id)
+            ));
+        }
+
+        if !keys.insert((entry.source, entry.type_id, entry.location_id)) {
+            return Err(ProjectError::ValidationError(
+                format!("'{}' is reserved twice in location '{}'", entry.type_id, entry.location_id)
+            ));
+        }
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+
+    let status = sqlx::query!(r#"
+            SELECT status AS "status: ProjectStatus"
+            FROM project
+            WHERE id = $1
+            FOR UPDATE
+        "#,
+            *project_id,
+        )
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateReservations(e, project_id))?
+        .map(|x| x.status);
+
+    match status {
+        Some(ProjectStatus::Done) => {
+            return Err(ProjectError::ValidationError(
+                "a project that is done can't reserve materials".into()
+            ));
+        },
+        Some(_) => (),
+        None => return Err(ProjectError::NotFound(project_id)),
+    }
+
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectReservation(project_id),
+        )
+        .await?;
+
+    sqlx::query!("
+            DELETE FROM project_reservation
+            WHERE project_id = $1
+        ",
+            *project_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateReservations(e, project_id))?;
+
+    let sources = entries
+        .iter()
+        .map(|x| x.source)
+        .collect::<Vec<ProjectReservationSource>>();
+    let type_ids = entries
+        .iter()
+        .map(|x| *x.type_id)
+        .collect::<Vec<_>>();
+    let location_ids = entries
+        .iter()
+        .map(|x| *x.location_id)
+        .collect::<Vec<_>>();
+    let quantities = entries
+        .iter()
+        .map(|x| x.quantity)
+        .collect::<Vec<_>>();
+
+    sqlx::query!("
+            INSERT INTO project_reservation
+            (
+                project_id,
+                source,
+                type_id,
+                location_id,
+                quantity
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::PROJECT_RESERVATION_SOURCE[],
+                $3::INTEGER[],
+                $4::BIGINT[],
+                $5::BIGINT[]
+            )
+        ",
+            *project_id,
+            &sources as _,
+            &type_ids,
+            &location_ids,
+            &quantities,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateReservations(e, project_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectError::TransactionError)
+}
@@ -0,0 +1,74 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::{CharacterId, CorporationId};
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project::error::{ProjectError, Result};
+use crate::project::service::refresh_wallet;
+
+/// Uses the wallets of the given character and its corporation to reconcile
+/// the costs of the project, and reconciles them right away.
+///
+/// The worker keeps reconciling them in their name until the project is
+/// done.
+///
+pub async fn update_wallet(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    character_id:           CharacterId,
+    corporation_id:         CorporationId,
+    domain:                 String,
+    project_id:             ProjectUuid,
+) -> Result<()> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectWallet(project_id),
+        )
+        .await?;
+
+    sqlx::query!("
+            INSERT INTO project_wallet
+            (
+                project_id,
+                character_id,
+                corporation_id,
+                domain
+            )
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (project_id)
+            DO UPDATE SET
+                character_id   = EXCLUDED.character_id,
+                corporation_id = EXCLUDED.corporation_id,
+                domain         = EXCLUDED.domain
+        ",
+            *project_id,
+            *character_id,
+            *corporation_id,
+            domain,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectError::UpdateWallet(e, project_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectError::TransactionError)?;
+
+    refresh_wallet(
+        pool,
+        eve_gateway_api_client,
+        character_id,
+        project_id,
+    )
+    .await
+}
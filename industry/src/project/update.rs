@@ -2,13 +2,15 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::project::UpdateProject;
 use starfoundry_lib_industry::ProjectUuid;
 
 use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
-use crate::AppState;
+use crate::{AppState, eve_gateway_api_client};
 use crate::project::error::Result;
 use crate::project::service::update;
+use crate::tag::service::apply_auto_tags;
 
 /// Update
 /// 
@@ -48,16 +50,24 @@ use crate::project::service::update;
     ),
 )]
 pub async fn api(
+    identity:           ExtractIdentity,
     State(state):       State<AppState>,
     Path(project_id):   Path<ProjectUuid>,
     Json(update_info):  Json<UpdateProject>,
 ) -> Result<impl IntoResponse> {
     update(
         &state.postgres,
+        identity.character_id,
         project_id,
         update_info,
     ).await?;
 
+    // the project is already stored, so a failure only leaves the automatic
+    // tags outdated until the next change
+    if let Err(e) = apply_auto_tags(&state.postgres, &eve_gateway_api_client()?, project_id).await {
+        tracing::error!("error while applying automatic tags to project '{}', error: '{}'", project_id, e);
+    }
+
     Ok((
         StatusCode::NO_CONTENT,
     ))
@@ -0,0 +1,203 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_eve_gateway::AssetStockFilter;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::{AppState, eve_gateway_api_client_with_identity};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
+use crate::project::error::Result;
+use crate::project::service::update_asset_stock;
+
+/// Update Asset Stock
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/asset-stock`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/asset-stock`
+/// 
+/// ---
+/// 
+/// Uses the synced assets of the character and its corporation as stock for
+/// the project.
+/// The assets are filtered by structure, hangar and container name, a
+/// container named after the project is the same convention the job
+/// detection uses.
+/// The stock is refreshed right away and afterwards every hour.
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    put,
+    path = "/{ProjectUuid}/asset-stock",
+    tag = "Projects",
+    request_body = AssetStockFilter,
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            description = "The asset stock was updated",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnsupportedMediaType,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:         ExtractIdentity,
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+    Json(filter):     Json<AssetStockFilter>,
+) -> Result<impl IntoResponse> {
+    let domain = identity.host()?;
+    let character_id = identity.character_id;
+    let corporation_id = identity.corporation_id;
+
+    update_asset_stock(
+            &state.postgres,
+            &eve_gateway_api_client_with_identity(identity.into())?,
+            character_id,
+            corporation_id,
+            domain,
+            project_id,
+            filter,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+        )
+        .into_response()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::StatusCode;
+    use http_body_util::BodyExt;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE, HEADER_SOURCE};
+    use starfoundry_lib_industry::project::{ProjectAssetStock, ProjectAssetStockStatus};
+    use starfoundry_lib_types::TypeId;
+
+    use crate::project::project_test_routes;
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        sqlx::query!("
+                INSERT INTO project_market (project_id, type_id, quantity)
+                VALUES ('00000000-0000-0000-0000-000000000101', 4051, 600)
+            ")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000101/asset-stock")
+            .method("PUT")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HEADER_SOURCE, "test.starfoundry.space")
+            .header("Content-Type", "application/json")
+            .body(Body::from(r#"{
+                "location_ids": [1046664001931],
+                "location_flags": ["CorpSAG1"]
+            }"#))
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let audit = sqlx::query!(r#"
+                SELECT COUNT(*) AS "count!"
+                FROM audit_event
+                WHERE scope = 'PROJECT_ASSET_STOCK'
+                AND entity_id = '00000000-0000-0000-0000-000000000101'
+            "#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(audit.count, 1);
+
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000101/asset-stock")
+            .method("GET")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .body(Body::empty())
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: ProjectAssetStock = serde_json::from_slice(
+            &response.into_body().collect().await.unwrap().to_bytes()
+        ).unwrap();
+        assert!(body.refreshed_at.is_some());
+        assert_eq!(body.materials.len(), 1);
+        let material = body.materials.first().unwrap();
+        assert_eq!(material.item.type_id, TypeId(4051));
+        assert_eq!(material.covered, 600);
+        assert_eq!(material.status, ProjectAssetStockStatus::Covered);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn no_location(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000101/asset-stock")
+            .method("PUT")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HEADER_SOURCE, "test.starfoundry.space")
+            .header("Content-Type", "application/json")
+            .body(Body::from(r#"{
+                "location_ids": []
+            }"#))
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn forbidden(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000105/asset-stock")
+            .method("PUT")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HEADER_SOURCE, "test.starfoundry.space")
+            .header("Content-Type", "application/json")
+            .body(Body::from(r#"{
+                "location_ids": [1046664001931]
+            }"#))
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
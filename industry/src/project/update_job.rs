@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::{ProjectJobUuid, ProjectUuid};
 use starfoundry_lib_industry::project::UpdateJob;
 
@@ -49,12 +50,14 @@ use crate::project::error::Result;
     ),
 )]
 pub async fn api(
+    identity:                           ExtractIdentity,
     State(state):                       State<AppState>,
     Path((project_id, project_job_id)): Path<(ProjectUuid, ProjectJobUuid)>,
     Json(update_info):                  Json<UpdateJob>,
 ) -> Result<impl IntoResponse> {
     update_job(
         &state.postgres,
+        identity.character_id,
         project_id,
         project_job_id,
         update_info,
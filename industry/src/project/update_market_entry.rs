@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::{MarketUuid, ProjectUuid};
 use starfoundry_lib_industry::project::UpdateMarketEntry;
 
@@ -45,12 +46,14 @@ use crate::project::service::update_market_entry;
     ),
 )]
 pub async fn api(
+    identity:                       ExtractIdentity,
     State(state):                   State<AppState>,
     Path((project_id, market_id)):  Path<(ProjectUuid, MarketUuid)>,
     Json(update):                   Json<UpdateMarketEntry>,
 ) -> Result<impl IntoResponse> {
     update_market_entry(
             &state.postgres,
+            identity.character_id,
             project_id,
             market_id,
             update,
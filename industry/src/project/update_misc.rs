@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::project::UpdateMisc;
 use starfoundry_lib_industry::ProjectUuid;
 
@@ -48,12 +49,14 @@ use crate::project::service::update_misc;
     ),
 )]
 pub async fn api(
+    identity:           ExtractIdentity,
     State(state):       State<AppState>,
     Path(project_id):   Path<ProjectUuid>,
     Json(update_info):  Json<Vec<UpdateMisc>>,
 ) -> Result<impl IntoResponse> {
     update_misc(
         &state.postgres,
+        identity.character_id,
         project_id,
         update_info,
     ).await?;
@@ -2,11 +2,13 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::ProjectUuid;
 
 use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
 use crate::AppState;
-use crate::project::error::{ProjectError, Result};
+use crate::project::error::Result;
+use crate::project::service::update_notes;
 
 /// Update
 /// 
@@ -46,21 +48,17 @@ use crate::project::error::{ProjectError, Result};
     ),
 )]
 pub async fn api(
+    identity:           ExtractIdentity,
     State(state):       State<AppState>,
     Path(project_id):   Path<ProjectUuid>,
     Json(notes):        Json<String>,
 ) -> Result<impl IntoResponse> {
-    sqlx::query!("
-            UPDATE project
-            SET note = $2
-            WHERE id = $1
-        ",
-            *project_id,
-            notes,
-        )
-        .execute(&state.postgres)
-        .await
-        .map_err(ProjectError::Update)?;
+    update_notes(
+        &state.postgres,
+        identity.character_id,
+        project_id,
+        notes,
+    ).await?;
 
     Ok((
         StatusCode::NO_CONTENT,
@@ -0,0 +1,258 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::project::ProjectReservationEntry;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::AppState;
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
+use crate::project::error::Result;
+use crate::project::service::update_reservations;
+
+/// Update Reservations
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/reservations`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/reservations`
+/// 
+/// ---
+/// 
+/// Replaces the materials the project reserves from the stock or excess in a
+/// location.
+/// Other projects of the same group no longer see the reserved quantities
+/// when checking resources or planning a solution.
+/// The reservations are released when the project is done or deleted.
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    put,
+    path = "/{ProjectUuid}/reservations",
+    tag = "Projects",
+    request_body = Vec<ProjectReservationEntry>,
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            description = "The reservations were updated",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnsupportedMediaType,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:         ExtractIdentity,
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+    Json(entries):    Json<Vec<ProjectReservationEntry>>,
+) -> Result<impl IntoResponse> {
+    update_reservations(
+            &state.postgres,
+            identity.character_id,
+            project_id,
+            entries,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+        )
+        .into_response()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::StatusCode;
+    use http_body_util::BodyExt;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE};
+    use starfoundry_lib_industry::project::{ProjectReservation, ProjectStatus, UpdateProject};
+    use starfoundry_lib_industry::ProjectUuid;
+    use starfoundry_lib_types::{CharacterId, LocationId, TypeId};
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::project::project_test_routes;
+    use crate::project::service::ReservedQuantities;
+
+    fn reserve_request(
+        project_id: &str,
+        body:       &'static str,
+    ) -> Request<Body> {
+        Request::builder()
+            .uri(format!("/{project_id}/reservations"))
+            .method("PUT")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header("Content-Type", "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let request = reserve_request(
+            "00000000-0000-0000-0000-000000000101",
+            r#"[{
+                "type_id": 4051,
+                "source": "STOCK",
+                "location_id": 1046664001931,
+                "quantity": 600
+            }]"#,
+        );
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000101/reservations")
+            .method("GET")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .body(Body::empty())
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Vec<ProjectReservation> = serde_json::from_slice(
+            &response.into_body().collect().await.unwrap().to_bytes()
+        ).unwrap();
+        assert_eq!(body.len(), 1);
+        assert_eq!(body.first().unwrap().quantity, 600);
+
+        let project_group_id = Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap();
+        let other_project_id = Uuid::from_str("00000000-0000-0000-0000-000000000102").unwrap();
+        let mut reserved = ReservedQuantities::load(
+                &pool,
+                vec![project_group_id],
+                vec![other_project_id],
+                Some(LocationId(1046664001931)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(reserved.unreserved(TypeId(4051), 1_000), 400);
+
+        let mut reserved = ReservedQuantities::load(
+                &pool,
+                vec![project_group_id],
+                vec![other_project_id],
+                Some(LocationId(1)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(reserved.unreserved(TypeId(4051), 1_000), 1_000);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn released_when_done(
+        pool: PgPool,
+    ) {
+        let request = reserve_request(
+            "00000000-0000-0000-0000-000000000101",
+            r#"[{
+                "type_id": 4051,
+                "source": "EXCESS",
+                "location_id": 1046664001931,
+                "quantity": 600
+            }]"#,
+        );
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let project_id: ProjectUuid = Uuid::from_str("00000000-0000-0000-0000-000000000101").unwrap().into();
+        crate::project::service::update(
+                &pool,
+                CharacterId(1),
+                project_id,
+                UpdateProject {
+                    orderer:    "Test".into(),
+                    name:       "Test".into(),
+                    status:     ProjectStatus::Done,
+                    tags:       Vec::new(),
+                    sell_price: None,
+                    note:       None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let entries = sqlx::query!("
+                SELECT type_id
+                FROM project_reservation
+                WHERE project_id = $1
+            ",
+                *project_id,
+            )
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert!(entries.is_empty());
+
+        let request = reserve_request(
+            "00000000-0000-0000-0000-000000000101",
+            r#"[{
+                "type_id": 4051,
+                "source": "STOCK",
+                "location_id": 1046664001931,
+                "quantity": 600
+            }]"#,
+        );
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn invalid_quantity(
+        pool: PgPool,
+    ) {
+        let request = reserve_request(
+            "00000000-0000-0000-0000-000000000101",
+            r#"[{
+                "type_id": 4051,
+                "source": "STOCK",
+                "location_id": 1046664001931,
+                "quantity": 0
+            }]"#,
+        );
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn forbidden(
+        pool: PgPool,
+    ) {
+        let request = reserve_request(
+            "00000000-0000-0000-0000-000000000105",
+            r#"[]"#,
+        );
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
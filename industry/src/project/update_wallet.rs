@@ -0,0 +1,267 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectUuid;
+
+use crate::{AppState, eve_gateway_api_client_with_identity};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project::error::Result;
+use crate::project::service::update_wallet;
+
+/// Update Wallet
+/// 
+/// - Alternative route: `/latest/projects/{ProjectUuid}/wallet`
+/// - Alternative route: `/v1/projects/{ProjectUuid}/wallet`
+/// 
+/// ---
+/// 
+/// Uses the synced wallets of the character and its corporation to
+/// reconcile the costs of the project.
+/// Buy transactions are matched to market entries of the same type, and job
+/// fees to the jobs by their job id.
+/// The wallets are reconciled right away and afterwards every hour, until
+/// the project is done.
+/// 
+/// ## Security
+/// - authenticated
+/// - project:read
+/// 
+#[utoipa::path(
+    put,
+    path = "/{ProjectUuid}/wallet",
+    tag = "Projects",
+    params(
+        ProjectUuid,
+    ),
+    responses(
+        (
+            description = "The wallet was reconciled",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:         ExtractIdentity,
+    State(state):     State<AppState>,
+    Path(project_id): Path<ProjectUuid>,
+) -> Result<impl IntoResponse> {
+    let domain = identity.host()?;
+    let character_id = identity.character_id;
+    let corporation_id = identity.corporation_id;
+
+    update_wallet(
+            &state.postgres,
+            &eve_gateway_api_client_with_identity(identity.into())?,
+            character_id,
+            corporation_id,
+            domain,
+            project_id,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+        )
+        .into_response()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::StatusCode;
+    use http_body_util::BodyExt;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE, HEADER_SOURCE};
+    use starfoundry_lib_industry::project::{ProjectWallet, ProjectWalletKind};
+    use starfoundry_lib_types::TypeId;
+
+    use crate::project::project_test_routes;
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        sqlx::query!("
+                INSERT INTO project_market (project_id, type_id, quantity)
+                VALUES ('00000000-0000-0000-0000-000000000101', 4051, 600)
+            ")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query!("
+                UPDATE project_job
+                SET job_id = 5001
+                WHERE id = (
+                    SELECT id
+                    FROM project_job
+                    WHERE project_id = '00000000-0000-0000-0000-000000000101'
+                    LIMIT 1
+                )
+            ")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000101/wallet")
+            .method("PUT")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HEADER_SOURCE, "test.starfoundry.space")
+            .body(Body::empty())
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000101/wallet")
+            .method("GET")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .body(Body::empty())
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: ProjectWallet = serde_json::from_slice(
+            &response.into_body().collect().await.unwrap().to_bytes()
+        ).unwrap();
+        assert!(body.reconciled_at.is_some());
+        assert_eq!(body.matches.len(), 3);
+
+        // the second transaction is only used partially, the rest and the
+        // fee of an unknown job are flagged
+        assert_eq!(body.unmatched.len(), 2);
+        let leftover = body
+            .unmatched
+            .iter()
+            .find(|x| x.kind == ProjectWalletKind::Market)
+            .unwrap();
+        assert_eq!(leftover.type_id, Some(TypeId(4051)));
+        assert_eq!(leftover.quantity, Some(200));
+        let fee = body
+            .unmatched
+            .iter()
+            .find(|x| x.kind == ProjectWalletKind::Job)
+            .unwrap();
+        assert_eq!(fee.reference_id, 1002);
+
+        let market_cost = sqlx::query!("
+                SELECT cost
+                FROM project_market
+                WHERE project_id = '00000000-0000-0000-0000-000000000101'
+            ")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(market_cost.cost, Some(400f64 * 100f64 + 200f64 * 110f64));
+        let job_cost = sqlx::query!("
+                SELECT cost
+                FROM project_job
+                WHERE job_id = 5001
+            ")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(job_cost.cost, Some(50_000f64));
+
+        let audit = sqlx::query!(r#"
+                SELECT scope::TEXT AS "scope!"
+                FROM audit_event
+                WHERE entity_id = '00000000-0000-0000-0000-000000000101'
+                ORDER BY scope::TEXT
+            "#)
+            .fetch_all(&pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|x| x.scope)
+            .collect::<Vec<_>>();
+        assert_eq!(audit, vec!["PROJECT_JOB", "PROJECT_MARKET", "PROJECT_WALLET"]);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn claimed_by_other_project(
+        pool: PgPool,
+    ) {
+        sqlx::query!("
+                INSERT INTO project_market (project_id, type_id, quantity)
+                VALUES
+                    ('00000000-0000-0000-0000-000000000101', 4051, 600),
+                    ('00000000-0000-0000-0000-000000000102', 4051, 600)
+            ")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        for project_id in ["00000000-0000-0000-0000-000000000101", "00000000-0000-0000-0000-000000000102"] {
+            let request = Request::builder()
+                .uri(format!("/{project_id}/wallet"))
+                .method("PUT")
+                .header(HEADER_SERVICE, "industry.test")
+                .header(HEADER_CHARACTER_ID, 1)
+                .header(HEADER_CORPORATION_ID, 1)
+                .header(HEADER_SOURCE, "test.starfoundry.space")
+                .body(Body::empty())
+                .unwrap();
+            let response = project_test_routes(pool.clone(), request).await;
+            assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        }
+
+        // only 200 units are left for the second project, so its cost stays
+        // untouched
+        let market_cost = sqlx::query!("
+                SELECT cost
+                FROM project_market
+                WHERE project_id = '00000000-0000-0000-0000-000000000102'
+            ")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(market_cost.cost, None);
+        let matched = sqlx::query!(r#"
+                SELECT SUM(quantity)::INTEGER AS "quantity!"
+                FROM project_wallet_match
+                WHERE project_id = '00000000-0000-0000-0000-000000000102'
+            "#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(matched.quantity, 200);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn forbidden(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000105/wallet")
+            .method("PUT")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HEADER_SOURCE, "test.starfoundry.space")
+            .body(Body::empty())
+            .unwrap();
+        let response = project_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
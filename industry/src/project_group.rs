@@ -1,22 +1,36 @@
 mod archive;
+mod compare_solutions;
+mod create_role;
 mod create;
+mod delete_role;
 mod delete;
 mod error;
+mod export;
+mod fetch_default_freight;
 mod fetch_members_self;
 mod fetch;
+mod import;
+mod list_audit_events;
 mod list_default_blacklist;
 mod list_default_blueprint_overwrites;
 mod list_default_job_splitting;
 mod list_default_market;
+mod list_grants;
 mod list_industry_hub;
 mod list_members;
+mod list_roles;
+mod list_solutions;
 mod list;
+mod rerun_solution;
 mod update_default_blacklist;
 mod update_default_blueprint_overwrite;
+mod update_default_freight;
 mod update_default_job_splitting;
 mod update_default_market;
+mod update_grants;
 mod update_industry_hubs;
 mod update_member;
+mod update_role;
 mod update;
 
 pub mod permission;
@@ -29,7 +43,7 @@ use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
 use crate::AppState;
-use crate::project_group::permission::{assert_exists, assert_read, assert_owner, assert_write};
+use crate::project_group::permission::{assert_exists, assert_read, assert_owner, assert_write, assert_write_member};
 
 pub fn routes(
     state: AppState,
@@ -60,6 +74,14 @@ pub fn routes(
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
 
+    let export = OpenApiRouter::new()
+        .routes(routes!(export::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    let import = OpenApiRouter::new()
+        .routes(routes!(import::api));
+
     let list_members = OpenApiRouter::new()
         .routes(routes!(list_members::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
@@ -80,6 +102,11 @@ pub fn routes(
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
 
+    let fetch_default_freight = OpenApiRouter::new()
+        .routes(routes!(fetch_default_freight::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
     let list_default_job_splitting = OpenApiRouter::new()
         .routes(routes!(list_default_job_splitting::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
@@ -105,6 +132,11 @@ pub fn routes(
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_write))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
 
+    let update_default_freight = OpenApiRouter::new()
+        .routes(routes!(update_default_freight::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_write))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
     let update_default_job_splitting = OpenApiRouter::new()
         .routes(routes!(update_default_job_splitting::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_write))
@@ -122,7 +154,55 @@ pub fn routes(
 
     let update_members = OpenApiRouter::new()
         .routes(routes!(update_member::api))
-        .route_layer(middleware::from_fn_with_state(state.clone(), assert_write))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_write_member))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    let list_roles = OpenApiRouter::new()
+        .routes(routes!(list_roles::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    let create_role = OpenApiRouter::new()
+        .routes(routes!(create_role::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_write_member))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    // permission is checked in the handler, as the middlewares only support
+    // a single path parameter
+    let update_role = OpenApiRouter::new()
+        .routes(routes!(update_role::api));
+
+    let delete_role = OpenApiRouter::new()
+        .routes(routes!(delete_role::api));
+
+    let list_solutions = OpenApiRouter::new()
+        .routes(routes!(list_solutions::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    let compare_solutions = OpenApiRouter::new()
+        .routes(routes!(compare_solutions::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    // permission is checked in the handler, as the middlewares only support
+    // a single path parameter
+    let rerun_solution = OpenApiRouter::new()
+        .routes(routes!(rerun_solution::api));
+
+    let list_grants = OpenApiRouter::new()
+        .routes(routes!(list_grants::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    let list_audit_events = OpenApiRouter::new()
+        .routes(routes!(list_audit_events::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    let update_grants = OpenApiRouter::new()
+        .routes(routes!(update_grants::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_write_member))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
 
     OpenApiRouter::new()
@@ -131,20 +211,34 @@ pub fn routes(
         .merge(list)
         .merge(update)
         .merge(delete)
+        .merge(export)
+        .merge(import)
         .merge(fetch)
         .merge(list_members)
         .merge(fetch_members_self)
         .merge(list_default_blacklist)
         .merge(list_default_blueprint_overwrites)
+        .merge(fetch_default_freight)
         .merge(list_default_job_splitting)
         .merge(list_default_market)
         .merge(list_industry_hubs)
         .merge(update_default_blacklist)
         .merge(update_default_blueprint_overwrite)
+        .merge(update_default_freight)
         .merge(update_default_job_splitting)
         .merge(update_default_market)
         .merge(update_industry_hubs)
         .merge(update_members)
+        .merge(list_roles)
+        .merge(create_role)
+        .merge(update_role)
+        .merge(delete_role)
+        .merge(list_solutions)
+        .merge(compare_solutions)
+        .merge(rerun_solution)
+        .merge(list_grants)
+        .merge(list_audit_events)
+        .merge(update_grants)
 }
 
 #[cfg(test)]
@@ -0,0 +1,69 @@
+use axum::response::IntoResponse;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use starfoundry_lib_industry::ProjectGroupUuid;
+
+use crate::{eve_gateway_api_client, AppState};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project_group::error::Result;
+use crate::project_group::service::{compare_solutions, CompareSolutionsQuery, SolutionComparison};
+
+/// Compare Solutions
+/// 
+/// - Alternative route: `/latest/project-groups/{ProjectGroupUuid}/solutions/compare`
+/// - Alternative route: `/v1/project-groups/{ProjectGroupUuid}/solutions/compare`
+/// 
+/// ---
+/// 
+/// Compares the cost, jobs, materials and time of two solutions.
+/// All differences are calculated as `other - base`, materials and jobs that
+/// are equal in both solutions are not returned
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectGroupUuid}/solutions/compare",
+    tag = "Project Groups",
+    params(
+        ProjectGroupUuid,
+        CompareSolutionsQuery,
+    ),
+    responses(
+        (
+            body = SolutionComparison,
+            description = "Differences between the two solutions",
+            status = OK,
+        ),
+        NotFound,
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):             State<AppState>,
+    Path(project_group_uuid): Path<ProjectGroupUuid>,
+    Query(query):             Query<CompareSolutionsQuery>,
+) -> Result<impl IntoResponse> {
+    let data = compare_solutions(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            project_group_uuid,
+            query,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(data),
+        )
+    )
+}
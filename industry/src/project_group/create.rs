@@ -74,7 +74,7 @@ pub async fn api(
     })
 )]
 pub struct CreateProjectGroupResponse {
-    id: ProjectGroupUuid,
+    pub id: ProjectGroupUuid,
 }
 
 #[cfg(test)]
@@ -0,0 +1,136 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::{ProjectGroupRoleUuid, ProjectGroupUuid};
+
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
+use crate::AppState;
+use crate::project_group::error::Result;
+use crate::project_group::service::{create_role, ProjectGroupRoleRequest};
+
+/// Create Role
+/// 
+/// - Alternative route: `/latest/project-groups/{ProjectGroupUuid}/roles`
+/// - Alternative route: `/v1/project-groups/{ProjectGroupUuid}/roles`
+/// 
+/// ---
+/// 
+/// Creates a new role, that can be assigned to members or granted to
+/// corporations and alliances
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_member
+/// 
+#[utoipa::path(
+    post,
+    path = "/{ProjectGroupUuid}/roles",
+    tag = "Project Groups",
+    request_body = ProjectGroupRoleRequest,
+    params(
+        ProjectGroupUuid,
+    ),
+    responses(
+        (
+            body = ProjectGroupRoleUuid,
+            description = "Id of the new role",
+            status = CREATED,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnsupportedMediaType,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:                 ExtractIdentity,
+    State(state):             State<AppState>,
+    Path(project_group_uuid): Path<ProjectGroupUuid>,
+    Json(role):               Json<ProjectGroupRoleRequest>,
+) -> Result<impl IntoResponse> {
+    let id = create_role(
+            &state.postgres,
+            identity.character_id,
+            project_group_uuid,
+            role,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::CREATED,
+            Json(id),
+        )
+        .into_response()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::header::{CONTENT_TYPE, HOST};
+    use axum::http::StatusCode;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE};
+
+    use crate::project_group::project_group_test_routes;
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000001/roles")
+            .header(CONTENT_TYPE, "application/json")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .method("POST")
+            .body(Body::new(
+                serde_json::json!({
+                    "name": "Structure Manager",
+                    "permissions": ["READ", "WRITE_STRUCTURE"],
+                }).to_string()
+            ))
+            .unwrap();
+        let response = project_group_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn forbidden(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000001/roles")
+            .header(CONTENT_TYPE, "application/json")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 2)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .method("POST")
+            .body(Body::new(
+                serde_json::json!({
+                    "name": "Structure Manager",
+                    "permissions": ["READ", "WRITE_STRUCTURE"],
+                }).to_string()
+            ))
+            .unwrap();
+        let response = project_group_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -1,6 +1,7 @@
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::ProjectGroupUuid;
 
 use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
@@ -43,11 +44,13 @@ use crate::project_group::service::delete;
     ),
 )]
 pub async fn api(
+    identity:                 ExtractIdentity,
     State(state):             State<AppState>,
     Path(project_group_uuid): Path<ProjectGroupUuid>,
 ) -> Result<impl IntoResponse> {
     delete(
         &state.postgres,
+        identity.character_id,
         project_group_uuid,
     ).await?;
 
@@ -0,0 +1,144 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use starfoundry_lib_industry::{ProjectGroupRoleUuid, ProjectGroupUuid};
+
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::AppState;
+use crate::project_group::error::Result;
+use crate::project_group::permission::assert_write_access_check;
+use crate::project_group::service::delete_role;
+
+/// Delete Role
+/// 
+/// - Alternative route: `/latest/project-groups/{ProjectGroupUuid}/roles/{ProjectGroupRoleUuid}`
+/// - Alternative route: `/v1/project-groups/{ProjectGroupUuid}/roles/{ProjectGroupRoleUuid}`
+/// 
+/// ---
+/// 
+/// Deletes a role.
+/// Roles that are still assigned to members or granted to corporations or
+/// alliances cannot be deleted.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_member
+/// 
+#[utoipa::path(
+    delete,
+    path = "/{ProjectGroupUuid}/roles/{ProjectGroupRoleUuid}",
+    tag = "Project Groups",
+    params(
+        ProjectGroupUuid,
+        ProjectGroupRoleUuid,
+    ),
+    responses(
+        (
+            description = "The role was deleted",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+    Path((project_group_uuid, role_uuid)): Path<(ProjectGroupUuid, ProjectGroupRoleUuid)>,
+) -> Result<impl IntoResponse> {
+    assert_write_access_check(
+            &state.postgres,
+            project_group_uuid,
+            (&identity).into(),
+            ProjectGroupPermissionCode::WriteMember,
+        )
+        .await?;
+
+    delete_role(
+        &state.postgres,
+        identity.character_id,
+        project_group_uuid,
+        role_uuid,
+    ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::header::HOST;
+    use axum::http::StatusCode;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE};
+
+    use crate::project_group::project_group_test_routes;
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000001/roles/00000000-0000-0000-0000-000000000003")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .method("DELETE")
+            .body(Body::empty())
+            .unwrap();
+        let response = project_group_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn in_use(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000005/roles/00000000-0000-0000-0000-000000000005")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 2)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .method("DELETE")
+            .body(Body::empty())
+            .unwrap();
+        let response = project_group_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn forbidden(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000005/roles/00000000-0000-0000-0000-000000000004")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 3)
+            .header(HEADER_CORPORATION_ID, 1000)
+            .header(HOST, "test.starfoundry.space")
+            .method("DELETE")
+            .body(Body::empty())
+            .unwrap();
+        let response = project_group_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -3,7 +3,7 @@ use axum::http::StatusCode;
 use axum::Json;
 use axum::response::{IntoResponse, Response};
 use starfoundry_lib_gateway::{ErrorResponse, boxed_from};
-use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_industry::{ProjectGroupRoleUuid, ProjectGroupUuid, SolutionUuid};
 use starfoundry_lib_types::CharacterId;
 use thiserror::Error;
 
@@ -36,6 +36,8 @@ pub enum ProjectGroupError {
     UpdateGroup(sqlx::Error, ProjectGroupUuid),
     #[error("error while archiving project group '{1}', error: '{0}'")]
     ArchiveGroup(sqlx::Error, ProjectGroupUuid),
+    #[error("error while exporting project group '{1}', error: '{0}'")]
+    ExportGroup(sqlx::Error, ProjectGroupUuid),
 
     #[error("error while fetching defaults for project group '{1}', error: '{0}'")]
     FetchGroupDefaults(sqlx::Error, ProjectGroupUuid),
@@ -54,6 +56,24 @@ pub enum ProjectGroupError {
     #[error("error while updating project group members '{1}', error: '{0}'")]
     UpdateMembers(sqlx::Error, ProjectGroupUuid),
 
+    #[error("error while fetching project group roles for group '{1}', error: '{0}'")]
+    ListRoles(sqlx::Error, ProjectGroupUuid),
+    #[error("error while creating project group role for group '{1}', error: '{0}'")]
+    CreateRole(sqlx::Error, ProjectGroupUuid),
+    #[error("error while updating project group role '{1}', error: '{0}'")]
+    UpdateRole(sqlx::Error, ProjectGroupRoleUuid),
+    #[error("error while deleting project group role '{1}', error: '{0}'")]
+    DeleteRole(sqlx::Error, ProjectGroupRoleUuid),
+    #[error("project group role with id '{0}' not found")]
+    RoleNotFound(ProjectGroupRoleUuid),
+    #[error("project group role '{0}' is still assigned to members or grants")]
+    RoleInUse(ProjectGroupRoleUuid),
+
+    #[error("error while fetching project group grants for group '{1}', error: '{0}'")]
+    ListGrants(sqlx::Error, ProjectGroupUuid),
+    #[error("error while updating project group grants for group '{1}', error: '{0}'")]
+    UpdateGrants(sqlx::Error, ProjectGroupUuid),
+
     #[error("error while fetching project group industry hubs for group '{1}', error: '{0}'")]
     ListIndustryHubs(sqlx::Error, ProjectGroupUuid),
     #[error("error while deleting project group industry hubs for group '{1}', error: '{0}'")]
@@ -61,6 +81,15 @@ pub enum ProjectGroupError {
     #[error("error while updating project group industry hubs for group '{1}', error: '{0}'")]
     UpdateIndustryHubs(sqlx::Error, ProjectGroupUuid),
 
+    #[error("error while fetching solutions for project group '{1}', error: '{0}'")]
+    ListSolutions(sqlx::Error, ProjectGroupUuid),
+    #[error("error while fetching solution '{1}', error: '{0}'")]
+    FetchSolution(sqlx::Error, SolutionUuid),
+    #[error("error while updating solution '{1}', error: '{0}'")]
+    UpdateSolution(sqlx::Error, SolutionUuid),
+    #[error("solution with id '{0}' not found")]
+    SolutionNotFound(SolutionUuid),
+
     #[error("error while performing transaction, error: '{0}'")]
     TransactionError(sqlx::Error),
 
@@ -70,6 +99,8 @@ pub enum ProjectGroupError {
     #[error(transparent)]
     JsonExtractorRejection(#[from] JsonRejection),
     #[error(transparent)]
+    AuditError(Box<crate::audit::AuditError>),
+    #[error(transparent)]
     IndustryHubError(Box<crate::industry_hub::IndustryHubError>),
     #[error(transparent)]
     GatewayLibError(Box<starfoundry_lib_gateway::error::Error>),
@@ -100,7 +131,9 @@ impl IntoResponse for ProjectGroupError {
                 ).into_response()
             },
 
-            Self::NotFound(_) => {
+            Self::NotFound(_) |
+            Self::RoleNotFound(_) |
+            Self::SolutionNotFound(_) => {
                 tracing::info!("{}", self.to_string());
                 (
                     StatusCode::NOT_FOUND,
@@ -126,6 +159,19 @@ impl IntoResponse for ProjectGroupError {
                 ).into_response()
             },
 
+            Self::RoleInUse(_) => {
+                tracing::warn!("{}", self.to_string());
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(
+                        ErrorResponse {
+                            error: "ROLE_IN_USE".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+
             Self::ProjectIsAssignedToGroup => {
                 tracing::warn!("{}", self.to_string());
                 (
@@ -143,6 +189,10 @@ impl IntoResponse for ProjectGroupError {
                 format_json_errors(x).into_response()
             },
 
+            Self::StructureError(e) => {
+                e.into_response()
+            },
+
             _ => {
                 tracing::error!("{}", self.to_string());
                 (
@@ -163,3 +213,4 @@ impl IntoResponse for ProjectGroupError {
 boxed_from!(ProjectGroupError::IndustryHubError, crate::industry_hub::IndustryHubError);
 boxed_from!(ProjectGroupError::EveGatewayLibError, starfoundry_lib_eve_gateway::Error);
 boxed_from!(ProjectGroupError::GatewayLibError, starfoundry_lib_gateway::error::Error);
+boxed_from!(ProjectGroupError::AuditError, crate::audit::AuditError);
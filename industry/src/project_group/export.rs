@@ -0,0 +1,66 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_industry::project_group::ProjectGroupExport;
+
+use crate::AppState;
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project_group::error::Result;
+use crate::project_group::service::export_project_group;
+
+/// Export Group
+/// 
+/// - Alternative route: `/latest/project-groups/{ProjectGroupUuid}/export`
+/// - Alternative route: `/v1/project-groups/{ProjectGroupUuid}/export`
+/// 
+/// ---
+/// 
+/// Exports the project group with its defaults and industry hubs into a
+/// versioned, portable JSON format.
+/// Members, roles and grants are not part of the export
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectGroupUuid}/export",
+    tag = "Project Groups",
+    params(
+        ProjectGroupUuid,
+    ),
+    responses(
+        (
+            body = ProjectGroupExport,
+            description = "Portable export of the project group",
+            status = OK,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):             State<AppState>,
+    Path(project_group_uuid): Path<ProjectGroupUuid>,
+) -> Result<impl IntoResponse> {
+    let data = export_project_group(
+            &state.postgres,
+            project_group_uuid,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(data),
+        )
+    )
+}
@@ -0,0 +1,65 @@
+use axum::response::IntoResponse;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::extract::{Path, State};
+use starfoundry_lib_industry::ProjectGroupUuid;
+
+use crate::AppState;
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project_group::error::Result;
+use crate::project_group::service::{Freight, fetch_default_freight};
+
+/// Fetch Freight
+/// 
+/// - Alternative route: `/latest/project-groups/{ProjectGroupUuid}/defaults/freight`
+/// - Alternative route: `/v1/project-groups/{ProjectGroupUuid}/defaults/freight`
+/// 
+/// ---
+/// 
+/// Fetches the freight rate used for moving intermediates between industry
+/// hubs when a build is split across multiple hubs
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectGroupUuid}/defaults/freight",
+    tag = "Project Groups",
+    params(
+        ProjectGroupUuid,
+    ),
+    responses(
+        (
+            body = Freight,
+            description = "Freight rate of the project group",
+            status = OK,
+        ),
+        NotFound,
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):             State<AppState>,
+    Path(project_group_uuid): Path<ProjectGroupUuid>,
+) -> Result<impl IntoResponse> {
+    let data = fetch_default_freight(
+            &state.postgres,
+            project_group_uuid,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(data),
+        )
+        .into_response()
+    )
+}
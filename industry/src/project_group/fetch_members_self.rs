@@ -50,7 +50,7 @@ pub async fn api(
 ) -> Result<impl IntoResponse> {
     let data = fetch_members_self(
             &state.postgres,
-            identity.character_id,
+            (&identity).into(),
             &eve_gateway_api_client()?,
             project_group_uuid,
         )
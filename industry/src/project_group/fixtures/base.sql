@@ -16,6 +16,17 @@ INSERT INTO project_group_member (project_group_id, character_id, permission) VA
 ('00000000-0000-0000-0000-000000000004', 2, 2),
 ('00000000-0000-0000-0000-000000000005', 2, 1);
 
+INSERT INTO project_group_role (id, project_group_id, name, permission) VALUES
+('00000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000001', 'Viewer', 2),
+('00000000-0000-0000-0000-000000000002', '00000000-0000-0000-0000-000000000001', 'Builder', 6),
+('00000000-0000-0000-0000-000000000003', '00000000-0000-0000-0000-000000000001', 'Manager', 126),
+('00000000-0000-0000-0000-000000000004', '00000000-0000-0000-0000-000000000005', 'Viewer', 2),
+('00000000-0000-0000-0000-000000000005', '00000000-0000-0000-0000-000000000005', 'Builder', 6);
+
+INSERT INTO project_group_grant (project_group_id, entity_type, entity_id, role_id) VALUES
+('00000000-0000-0000-0000-000000000005', 'CORPORATION', 1000, '00000000-0000-0000-0000-000000000005'),
+('00000000-0000-0000-0000-000000000005', 'ALLIANCE', 2000, '00000000-0000-0000-0000-000000000004');
+
 INSERT INTO project (owner, name, orderer, project_group_id) VALUES
 (1, 'Test', 'Test', '00000000-0000-0000-0000-000000000001'),
 (1, 'Test', 'Test', '00000000-0000-0000-0000-000000000002'),
@@ -0,0 +1,40 @@
+INSERT INTO solution (id, industry_hub_id, project_group_id, created_at) VALUES
+('00000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000001', NOW() - INTERVAL '1 day'),
+('00000000-0000-0000-0000-000000000002', '00000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000001', NOW());
+
+INSERT INTO solution_industry_hub (solution_id, industry_hub_id) VALUES
+('00000000-0000-0000-0000-000000000002', '00000000-0000-0000-0000-000000000001'),
+('00000000-0000-0000-0000-000000000002', '00000000-0000-0000-0000-000000000002');
+
+INSERT INTO solution_blacklist (solution_id, type_id) VALUES
+('00000000-0000-0000-0000-000000000001', 4051);
+
+INSERT INTO solution_blueprint_overwrite (solution_id, type_id, material_efficiency) VALUES
+('00000000-0000-0000-0000-000000000001', 20185, 10);
+
+INSERT INTO solution_job_split (solution_id, type_id, runs) VALUES
+('00000000-0000-0000-0000-000000000001', 21027, 5);
+
+INSERT INTO solution_stock (solution_id, type_id, quantity) VALUES
+('00000000-0000-0000-0000-000000000001', 4312, 3);
+
+INSERT INTO solution_product (solution_id, type_id, quantity, material_efficiency) VALUES
+('00000000-0000-0000-0000-000000000001', 20185, 1, 10),
+('00000000-0000-0000-0000-000000000002', 20185, 1, 10);
+
+INSERT INTO solution_material (solution_id, type_id, quantity, cost, additional) VALUES
+('00000000-0000-0000-0000-000000000001', 4246, 100, 5, FALSE),
+('00000000-0000-0000-0000-000000000001', 4247, 10, 20, FALSE),
+('00000000-0000-0000-0000-000000000002', 4246, 80, 5, FALSE),
+('00000000-0000-0000-0000-000000000002', 4247, 10, 25, FALSE),
+('00000000-0000-0000-0000-000000000002', 4312, 1, 10, TRUE);
+
+INSERT INTO solution_manufacturing (solution_id, type_id, runs, structure_id, build_tax, time) VALUES
+('00000000-0000-0000-0000-000000000001', 20185, 1, '00000000-0000-0000-0000-000000000001', 100, 2000),
+('00000000-0000-0000-0000-000000000001', 21027, 5, '00000000-0000-0000-0000-000000000001', 50, 1000),
+('00000000-0000-0000-0000-000000000002', 20185, 1, '00000000-0000-0000-0000-000000000001', 120, 2000),
+('00000000-0000-0000-0000-000000000002', 21027, 3, '00000000-0000-0000-0000-000000000002', 20, 600),
+('00000000-0000-0000-0000-000000000002', 21027, 3, '00000000-0000-0000-0000-000000000002', 20, 600);
+
+INSERT INTO solution_transfer (solution_id, type_id, quantity, volume, cost, source_hub_id, destination_hub_id) VALUES
+('00000000-0000-0000-0000-000000000002', 21027, 6, 60, 100, '00000000-0000-0000-0000-000000000002', '00000000-0000-0000-0000-000000000001');
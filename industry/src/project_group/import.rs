@@ -0,0 +1,73 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::project_group::ImportProjectGroup;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{BadRequest, InternalServerError, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
+use crate::project_group::create::CreateProjectGroupResponse;
+use crate::project_group::error::Result;
+use crate::project_group::service::import_project_group;
+
+/// Import Group
+/// 
+/// - Alternative route: `/latest/project-groups/import`
+/// - Alternative route: `/v1/project-groups/import`
+/// 
+/// ---
+/// 
+/// Imports a previously exported project group as a new project group.
+/// The industry hubs of the export are created as new industry hubs.
+/// 
+/// All type ids are validated against the current SDE.
+/// Structures are either mapped using `structure_mapping`, or matched by
+/// their in-game id against the structures of the character
+/// 
+/// ## Security
+/// - authenticated
+/// 
+#[utoipa::path(
+    post,
+    path = "/import",
+    tag = "Project Groups",
+    request_body = ImportProjectGroup,
+    responses(
+        (
+            body = CreateProjectGroupResponse,
+            description = "Id of the imported project group",
+            status = CREATED,
+        ),
+        BadRequest,
+        Unauthorized,
+        UnsupportedMediaType,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state): State<AppState>,
+    identity:     ExtractIdentity,
+    Json(import): Json<ImportProjectGroup>,
+) -> Result<impl IntoResponse> {
+    let id = import_project_group(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            identity.character_id,
+            import,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::CREATED,
+            Json(CreateProjectGroupResponse {
+                id,
+            })
+        )
+    )
+}
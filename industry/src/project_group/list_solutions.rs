@@ -0,0 +1,80 @@
+use axum::response::IntoResponse;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::extract::{Path, State};
+use starfoundry_lib_industry::ProjectGroupUuid;
+
+use crate::{eve_gateway_api_client, AppState};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project_group::error::Result;
+use crate::project_group::service::{list_solutions, SolutionSummary};
+
+/// List Solutions
+/// 
+/// - Alternative route: `/latest/project-groups/{ProjectGroupUuid}/solutions`
+/// - Alternative route: `/v1/project-groups/{ProjectGroupUuid}/solutions`
+/// 
+/// ---
+/// 
+/// Lists all build solutions that were calculated for the project group,
+/// newest first
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectGroupUuid}/solutions",
+    tag = "Project Groups",
+    params(
+        ProjectGroupUuid,
+    ),
+    responses(
+        (
+            body = Vec<SolutionSummary>,
+            description = "All solutions of the project group",
+            status = OK,
+        ),
+        (
+            description = "No solution was calculated yet",
+            status = NO_CONTENT,
+        ),
+        NotFound,
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):             State<AppState>,
+    Path(project_group_uuid): Path<ProjectGroupUuid>,
+) -> Result<impl IntoResponse> {
+    let data = list_solutions(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            project_group_uuid,
+        )
+        .await?;
+
+    if data.is_empty() {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(data),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(data),
+            )
+            .into_response()
+        )
+    }
+}
@@ -2,8 +2,14 @@ mod assert_exists;
 mod assert_owner;
 mod assert_read;
 mod assert_write;
+mod assert_write_member;
+mod fetch_permission;
+mod identity;
 
 pub use self::assert_exists::*;
 pub use self::assert_owner::*;
 pub use self::assert_read::*;
 pub use self::assert_write::*;
+pub use self::assert_write_member::*;
+pub use self::fetch_permission::*;
+pub use self::identity::*;
@@ -5,10 +5,10 @@ use sqlx::PgPool;
 use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
 use starfoundry_lib_industry::ProjectGroupUuid;
-use starfoundry_lib_types::CharacterId;
 
 use crate::AppState;
 use crate::project_group::error::{ProjectGroupError, Result};
+use crate::project_group::permission::{fetch_permission, MemberIdentity};
 
 pub async fn assert_owner(
     State(state):             State<AppState>,
@@ -20,7 +20,7 @@ pub async fn assert_owner(
     assert_owner_check(
             &state.postgres,
             project_group_uuid,
-            identity.character_id,
+            (&identity).into(),
         )
         .await?;
 
@@ -30,27 +30,20 @@ pub async fn assert_owner(
 async fn assert_owner_check(
     pool:               &PgPool,
     project_group_uuid: ProjectGroupUuid,
-    character_id:       CharacterId,
+    identity:           MemberIdentity,
 ) -> Result<()> {
-    let result = sqlx::query!("
-            SELECT pg.id
-            FROM project_group pg
-            JOIN project_group_member pgm ON pgm.project_group_id = pg.id
-            WHERE pg.id = $1
-                AND pgm.character_id = $2
-                AND permission & $3 = $3
-        ",
-            *project_group_uuid,
-            *character_id,
-            *ProjectGroupPermissionCode::Owner,
+    let permission = fetch_permission(
+            pool,
+            project_group_uuid,
+            identity,
         )
-        .fetch_optional(pool)
-        .await
-        .map_err(|e| ProjectGroupError::FetchGroupPermissions(e, project_group_uuid))?;
+        .await?;
 
-    if result.is_none() {
-        Err(ProjectGroupError::Forbidden(project_group_uuid, character_id))
-    } else {
+    // only the owner bit counts, roles can never grant ownership
+    let owner = *ProjectGroupPermissionCode::Owner;
+    if *permission & owner == owner {
         Ok(())
+    } else {
+        Err(ProjectGroupError::Forbidden(project_group_uuid, identity.character_id))
     }
 }
@@ -0,0 +1,29 @@
+use axum::extract::{Path, Request, State};
+use axum::middleware::Next;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use starfoundry_lib_industry::ProjectGroupUuid;
+
+use crate::AppState;
+use crate::project_group::error::Result;
+use crate::project_group::permission::assert_write_access_check;
+
+/// Asserts that the character is allowed to manage members, roles and grants
+pub async fn assert_write_member(
+    State(state):             State<AppState>,
+    Path(project_group_uuid): Path<ProjectGroupUuid>,
+    identity:                 ExtractIdentity,
+    request:                  Request,
+    next:                     Next,
+) -> Result<impl IntoResponse> {
+    assert_write_access_check(
+            &state.postgres,
+            project_group_uuid,
+            (&identity).into(),
+            ProjectGroupPermissionCode::WriteMember,
+        )
+        .await?;
+
+    Ok(next.run(request).await)
+}
@@ -0,0 +1,140 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::project_group::ProjectGroupPermission;
+use starfoundry_lib_industry::ProjectGroupUuid;
+
+use crate::project_group::error::{ProjectGroupError, Result};
+use crate::project_group::permission::MemberIdentity;
+
+/// Combines the permissions of the character, its role and all roles that
+/// are granted to the corporation or alliance of the character
+pub async fn fetch_permission(
+    pool:               &PgPool,
+    project_group_uuid: ProjectGroupUuid,
+    identity:           MemberIdentity,
+) -> Result<ProjectGroupPermission> {
+    sqlx::query!(r#"
+            SELECT COALESCE(BIT_OR(permission), 0) AS "permission!"
+            FROM (
+                SELECT pgm.permission | COALESCE(pgr.permission, 0) AS permission
+                FROM project_group_member pgm
+                LEFT JOIN project_group_role pgr ON pgr.id = pgm.role_id
+                WHERE pgm.project_group_id = $1
+                AND pgm.character_id = $2
+
+                UNION ALL
+
+                SELECT pgr.permission
+                FROM project_group_grant pgg
+                JOIN project_group_role pgr ON pgr.id = pgg.role_id
+                WHERE pgg.project_group_id = $1
+                AND (
+                    (pgg.entity_type = 'CORPORATION' AND pgg.entity_id = $3) OR
+                    (pgg.entity_type = 'ALLIANCE' AND pgg.entity_id = $4)
+                )
+            ) permissions
+        "#,
+            *project_group_uuid,
+            *identity.character_id,
+            *identity.corporation_id,
+            identity.alliance_id.map(|x| *x),
+        )
+        .fetch_one(pool)
+        .await
+        .map(|x| ProjectGroupPermission::new(x.permission))
+        .map_err(|e| ProjectGroupError::FetchGroupPermissions(e, project_group_uuid))
+}
+
+#[cfg(test)]
+mod fetch_permission_test {
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+    use starfoundry_lib_types::{AllianceId, CharacterId, CorporationId};
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::project_group::permission::MemberIdentity;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn direct_member(
+        pool: PgPool,
+    ) {
+        let permission = super::fetch_permission(
+                &pool,
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                MemberIdentity::new(CharacterId(2), CorporationId(1), None),
+            )
+            .await
+            .unwrap();
+
+        assert!(permission.has(ProjectGroupPermissionCode::Read));
+        assert!(!permission.has(ProjectGroupPermissionCode::WriteProject));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn granted_to_corporation(
+        pool: PgPool,
+    ) {
+        let permission = super::fetch_permission(
+                &pool,
+                Uuid::from_str("00000000-0000-0000-0000-000000000005").unwrap().into(),
+                MemberIdentity::new(CharacterId(3), CorporationId(1000), None),
+            )
+            .await
+            .unwrap();
+
+        assert!(permission.has(ProjectGroupPermissionCode::Read));
+        assert!(permission.has(ProjectGroupPermissionCode::WriteProject));
+        assert!(!permission.has(ProjectGroupPermissionCode::WriteGroup));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn granted_to_alliance(
+        pool: PgPool,
+    ) {
+        let permission = super::fetch_permission(
+                &pool,
+                Uuid::from_str("00000000-0000-0000-0000-000000000005").unwrap().into(),
+                MemberIdentity::new(CharacterId(3), CorporationId(1), Some(AllianceId(2000))),
+            )
+            .await
+            .unwrap();
+
+        assert!(permission.has(ProjectGroupPermissionCode::Read));
+        assert!(!permission.has(ProjectGroupPermissionCode::WriteProject));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn no_permission(
+        pool: PgPool,
+    ) {
+        let permission = super::fetch_permission(
+                &pool,
+                Uuid::from_str("00000000-0000-0000-0000-000000000005").unwrap().into(),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(*permission, 0);
+    }
+}
@@ -0,0 +1,36 @@
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_types::{AllianceId, CharacterId, CorporationId};
+
+/// Character with its current affiliation.
+/// Permissions can either be granted to the character directly or to its
+/// corporation or alliance.
+#[derive(Clone, Copy, Debug)]
+pub struct MemberIdentity {
+    pub character_id:   CharacterId,
+    pub corporation_id: CorporationId,
+    pub alliance_id:    Option<AllianceId>,
+}
+
+impl MemberIdentity {
+    pub fn new(
+        character_id:   CharacterId,
+        corporation_id: CorporationId,
+        alliance_id:    Option<AllianceId>,
+    ) -> Self {
+        Self {
+            character_id,
+            corporation_id,
+            alliance_id,
+        }
+    }
+}
+
+impl From<&ExtractIdentity> for MemberIdentity {
+    fn from(identity: &ExtractIdentity) -> Self {
+        Self::new(
+            identity.character_id,
+            identity.corporation_id,
+            identity.alliance_id,
+        )
+    }
+}
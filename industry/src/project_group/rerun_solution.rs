@@ -0,0 +1,115 @@
+use axum::response::IntoResponse;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::extract::{Path, State};
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::industry::BuildEngineResponse;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use starfoundry_lib_industry::{ProjectGroupUuid, SolutionUuid};
+
+use crate::{eve_gateway_api_client, AppState};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::industry::{calculate, Result};
+use crate::project_group::permission::assert_write_access_check;
+use crate::project_group::service::{mark_solution_rerun, solution_rerun_config};
+
+/// Rerun Solution
+/// 
+/// - Alternative route: `/latest/project-groups/{ProjectGroupUuid}/solutions/{SolutionUuid}/rerun`
+/// - Alternative route: `/v1/project-groups/{ProjectGroupUuid}/solutions/{SolutionUuid}/rerun`
+/// 
+/// ---
+/// 
+/// Calculates a stored solution again with the current prices and system
+/// indices. The products, stock, blacklist, blueprint overwrites, job splits
+/// and industry hubs are taken from the stored solution.
+/// 
+/// The result is stored as a new solution, the stored solution stays
+/// unchanged, so that both can be compared.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_project
+/// 
+#[utoipa::path(
+    post,
+    path = "/{ProjectGroupUuid}/solutions/{SolutionUuid}/rerun",
+    tag = "Project Groups",
+    params(
+        ProjectGroupUuid,
+        SolutionUuid,
+    ),
+    responses(
+        (
+            body = BuildEngineResponse,
+            description = "The new solution",
+            status = OK,
+        ),
+        (
+            description = "The industry hubs of the solution can no longer build all jobs",
+            status = NO_CONTENT,
+        ),
+        NotFound,
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+    Path((project_group_uuid, solution_uuid)): Path<(ProjectGroupUuid, SolutionUuid)>,
+) -> Result<impl IntoResponse> {
+    assert_write_access_check(
+            &state.postgres,
+            project_group_uuid,
+            (&identity).into(),
+            ProjectGroupPermissionCode::WriteProject,
+        )
+        .await?;
+
+    let config = solution_rerun_config(
+            &state.postgres,
+            identity.character_id,
+            &eve_gateway_api_client()?,
+            project_group_uuid,
+            solution_uuid,
+        )
+        .await?;
+
+    let solution = calculate(
+            &state.postgres,
+            &identity,
+            config,
+        )
+        .await?
+        .into_iter()
+        .next();
+
+    if let Some(x) = solution {
+        mark_solution_rerun(
+                &state.postgres,
+                x.solution_id,
+                solution_uuid,
+            )
+            .await?;
+
+        Ok(
+            (
+                StatusCode::OK,
+                Json(x),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+            )
+            .into_response()
+        )
+    }
+}
@@ -1,39 +1,65 @@
 mod archive;
+mod compare_solutions;
 mod create;
+mod create_role;
 mod delete;
+mod delete_role;
+mod export;
+mod fetch_default_freight;
 mod fetch_members_self;
 mod fetch;
+mod import;
 mod list_default_blacklist;
 mod list_default_blueprint_overwrite;
 mod list_default_job_splitting;
 mod list_default_market;
+mod list_grants;
 mod list_industry_hub;
 mod list_members;
+mod list_roles;
+mod list_solutions;
 mod list;
+mod rerun_solution;
 mod update;
 mod update_default_blacklist;
 mod update_default_blueprint_overwrite;
+mod update_default_freight;
 mod update_default_job_splitting;
 mod update_default_market;
+mod update_grants;
 mod update_industry_hub;
 mod update_member;
+mod update_role;
 
 pub use self::archive::*;
+pub use self::compare_solutions::*;
 pub use self::create::*;
+pub use self::create_role::*;
 pub use self::delete::*;
+pub use self::delete_role::*;
+pub use self::export::*;
+pub use self::fetch_default_freight::*;
 pub use self::fetch_members_self::*;
 pub use self::fetch::*;
+pub use self::import::*;
 pub use self::list_default_blacklist::*;
 pub use self::list_default_blueprint_overwrite::*;
 pub use self::list_default_job_splitting::*;
 pub use self::list_default_market::*;
+pub use self::list_grants::*;
 pub use self::list_members::*;
+pub use self::list_roles::*;
+pub use self::list_solutions::*;
 pub use self::list_industry_hub::*;
 pub use self::list::*;
+pub use self::rerun_solution::*;
 pub use self::update::*;
 pub use self::update_default_blacklist::*;
 pub use self::update_default_blueprint_overwrite::*;
+pub use self::update_default_freight::*;
 pub use self::update_default_job_splitting::*;
 pub use self::update_default_market::*;
+pub use self::update_grants::*;
 pub use self::update_industry_hub::*;
 pub use self::update_member::*;
+pub use self::update_role::*;
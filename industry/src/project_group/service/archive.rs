@@ -1,21 +1,42 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project_group::error::{ProjectGroupError, Result};
 
 pub async fn archive(
-    pool:     &PgPool,
-    group_id: ProjectGroupUuid,
+    pool:         &PgPool,
+    character_id: CharacterId,
+    group_id:     ProjectGroupUuid,
 ) -> Result<()> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Archive,
+            AuditTarget::ProjectGroup(group_id),
+        )
+        .await?;
+
     sqlx::query!("
-        UPDATE project_group
-        SET archived = NOT archived
-        WHERE id = $1
-    ",
-        *group_id,
-    )
-    .execute(pool)
-    .await
-    .map(drop)
-    .map_err(|e| ProjectGroupError::ArchiveGroup(e, group_id))
+            UPDATE project_group
+            SET archived = NOT archived
+            WHERE id = $1
+        ",
+            *group_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectGroupError::ArchiveGroup(e, group_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectGroupError::TransactionError)
 }
@@ -0,0 +1,320 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{EveGatewayApiClient, Item};
+use starfoundry_lib_industry::{ProjectGroupUuid, SolutionUuid};
+use starfoundry_lib_types::TypeId;
+use std::collections::{BTreeMap, HashMap};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::project_group::error::{ProjectGroupError, Result};
+use crate::project_group::service::{SolutionSummary, fetch_solution_summaries};
+
+/// Compares two solutions of the project group.
+/// The differences are always calculated as `other - base`
+///
+/// # Errors
+///
+/// - [ProjectGroupError::SolutionNotFound] if one of the solutions does not
+///   exist or belongs to another group
+///
+pub async fn compare_solutions(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_group_uuid:     ProjectGroupUuid,
+    query:                  CompareSolutionsQuery,
+) -> Result<SolutionComparison> {
+    let mut solutions = fetch_solution_summaries(
+            pool,
+            eve_gateway_api_client,
+            project_group_uuid,
+            Some(vec![query.base, query.other]),
+        )
+        .await?;
+
+    let base = if let Some(x) = solutions.iter().position(|x| x.id == query.base) {
+        solutions.swap_remove(x)
+    } else {
+        return Err(ProjectGroupError::SolutionNotFound(query.base));
+    };
+    let other = if let Some(x) = solutions.iter().position(|x| x.id == query.other) {
+        solutions.swap_remove(x)
+    } else if query.base == query.other {
+        base.clone()
+    } else {
+        return Err(ProjectGroupError::SolutionNotFound(query.other));
+    };
+
+    let materials = sqlx::query!(r#"
+            SELECT
+                type_id,
+                additional,
+                COALESCE(SUM(quantity) FILTER (WHERE solution_id = $1), 0) AS "base_quantity!",
+                COALESCE(SUM(quantity) FILTER (WHERE solution_id = $2), 0) AS "other_quantity!",
+                COALESCE(SUM(quantity * COALESCE(cost, 0)) FILTER (WHERE solution_id = $1), 0) AS "base_cost!",
+                COALESCE(SUM(quantity * COALESCE(cost, 0)) FILTER (WHERE solution_id = $2), 0) AS "other_cost!"
+            FROM solution_material
+            WHERE solution_id = ANY(ARRAY[$1, $2])
+            GROUP BY type_id, additional
+        "#,
+            *query.base,
+            *query.other,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::FetchSolution(e, query.base))?;
+
+    let jobs = sqlx::query!(r#"
+            SELECT
+                type_id,
+                COALESCE(SUM(runs) FILTER (WHERE solution_id = $1), 0) AS "base_runs!",
+                COALESCE(SUM(runs) FILTER (WHERE solution_id = $2), 0) AS "other_runs!",
+                COUNT(*) FILTER (WHERE solution_id = $1) AS "base_jobs!",
+                COUNT(*) FILTER (WHERE solution_id = $2) AS "other_jobs!",
+                COALESCE(SUM(build_tax) FILTER (WHERE solution_id = $1), 0) AS "base_build_tax!",
+                COALESCE(SUM(build_tax) FILTER (WHERE solution_id = $2), 0) AS "other_build_tax!",
+                COALESCE(SUM(time) FILTER (WHERE solution_id = $1), 0) AS "base_time!",
+                COALESCE(SUM(time) FILTER (WHERE solution_id = $2), 0) AS "other_time!"
+            FROM solution_manufacturing
+            WHERE solution_id = ANY(ARRAY[$1, $2])
+            GROUP BY type_id
+        "#,
+            *query.base,
+            *query.other,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::FetchSolution(e, query.base))?;
+
+    let mut type_ids = materials
+        .iter()
+        .map(|x| x.type_id)
+        .chain(jobs.iter().map(|x| x.type_id))
+        .map(Into::into)
+        .collect::<Vec<TypeId>>();
+    type_ids.sort();
+    type_ids.dedup();
+    let items = eve_gateway_api_client
+        .fetch_item_bulk(type_ids)
+        .await?
+        .into_iter()
+        .map(|x| (x.type_id, x))
+        .collect::<HashMap<_, _>>();
+
+    // sorted by name, so that the order is stable between requests
+    let mut material_diffs = BTreeMap::new();
+    for material in materials {
+        if material.base_quantity == material.other_quantity &&
+            material.base_cost == material.other_cost {
+            continue;
+        }
+
+        let item = if let Some(x) = items.get(&material.type_id.into()) {
+            x.clone()
+        } else {
+            continue;
+        };
+
+        material_diffs.insert(
+            (item.name.clone(), material.additional),
+            SolutionMaterialDifference {
+                item,
+                additional:     material.additional,
+                base_quantity:  material.base_quantity,
+                other_quantity: material.other_quantity,
+                base_cost:      material.base_cost,
+                other_cost:     material.other_cost,
+            }
+        );
+    }
+
+    let mut job_diffs = BTreeMap::new();
+    for job in jobs {
+        if job.base_runs == job.other_runs &&
+            job.base_jobs == job.other_jobs &&
+            job.base_build_tax == job.other_build_tax &&
+            job.base_time == job.other_time {
+            continue;
+        }
+
+        let item = if let Some(x) = items.get(&job.type_id.into()) {
+            x.clone()
+        } else {
+            continue;
+        };
+
+        job_diffs.insert(
+            item.name.clone(),
+            SolutionJobDifference {
+                item,
+                base_runs:       job.base_runs,
+                other_runs:      job.other_runs,
+                base_jobs:       job.base_jobs,
+                other_jobs:      job.other_jobs,
+                base_build_tax:  job.base_build_tax,
+                other_build_tax: job.other_build_tax,
+                base_time:       job.base_time,
+                other_time:      job.other_time,
+            }
+        );
+    }
+
+    let difference = SolutionDifference {
+        material:    other.cost.material - base.cost.material,
+        build_tax:   other.cost.build_tax - base.cost.build_tax,
+        transfer:    other.cost.transfer - base.cost.transfer,
+        total:       other.cost.total - base.cost.total,
+        jobs:        other.jobs - base.jobs,
+        time:        other.time - base.time,
+        longest_job: other.longest_job - base.longest_job,
+    };
+
+    Ok(SolutionComparison {
+        base,
+        other,
+        difference,
+        materials: material_diffs.into_values().collect::<Vec<_>>(),
+        jobs:      job_diffs.into_values().collect::<Vec<_>>(),
+    })
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CompareSolutionsQuery {
+    /// Solution that is compared against
+    pub base:  SolutionUuid,
+    /// Solution that is compared
+    pub other: SolutionUuid,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SolutionComparison {
+    pub base:       SolutionSummary,
+    pub other:      SolutionSummary,
+    pub difference: SolutionDifference,
+    /// Materials with a different quantity or cost
+    pub materials:  Vec<SolutionMaterialDifference>,
+    /// Jobs with different runs, cost or time
+    pub jobs:       Vec<SolutionJobDifference>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SolutionDifference {
+    pub material:    f64,
+    pub build_tax:   f64,
+    pub transfer:    f64,
+    pub total:       f64,
+    pub jobs:        i64,
+    pub time:        f64,
+    pub longest_job: f64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SolutionMaterialDifference {
+    pub item:           Item,
+    /// Set if the material was an additional product
+    pub additional:     bool,
+    pub base_quantity:  i64,
+    pub other_quantity: i64,
+    pub base_cost:      f64,
+    pub other_cost:     f64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SolutionJobDifference {
+    pub item:            Item,
+    pub base_runs:       i64,
+    pub other_runs:      i64,
+    pub base_jobs:       i64,
+    pub other_jobs:      i64,
+    pub base_build_tax:  f64,
+    pub other_build_tax: f64,
+    pub base_time:       f64,
+    pub other_time:      f64,
+}
+
+#[cfg(test)]
+mod compare_solutions_project_group_test {
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use starfoundry_lib_types::TypeId;
+    use uuid::Uuid;
+
+    use crate::eve_gateway_api_client;
+    use crate::project_group::ProjectGroupError;
+    use crate::project_group::service::CompareSolutionsQuery;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base", "solution"),
+        ),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let response = super::compare_solutions(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                CompareSolutionsQuery {
+                    base:  Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                    other: Uuid::from_str("00000000-0000-0000-0000-000000000002").unwrap().into(),
+                },
+            )
+            .await
+            .unwrap();
+
+        // 400 + 250 + 10 + 160 + 100 - 850
+        assert_eq!(response.difference.total, 70f64);
+        assert_eq!(response.difference.transfer, 100f64);
+        assert_eq!(response.difference.jobs, 1);
+        assert_eq!(response.difference.time, 200f64);
+
+        // all materials changed
+        assert_eq!(response.materials.len(), 3);
+        let additional = response
+            .materials
+            .iter()
+            .find(|x| x.additional)
+            .unwrap();
+        assert_eq!(additional.item.type_id, TypeId(4312));
+        assert_eq!(additional.base_quantity, 0);
+        assert_eq!(additional.other_quantity, 1);
+
+        // the main product is build the same way, except the cost
+        assert_eq!(response.jobs.len(), 2);
+        let split = response
+            .jobs
+            .iter()
+            .find(|x| x.item.type_id == TypeId(21027))
+            .unwrap();
+        assert_eq!(split.base_runs, 5);
+        assert_eq!(split.other_runs, 6);
+        assert_eq!(split.base_jobs, 1);
+        assert_eq!(split.other_jobs, 2);
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base", "solution"),
+        ),
+    )]
+    async fn other_group(
+        pool: PgPool,
+    ) {
+        let response = super::compare_solutions(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000002").unwrap().into(),
+                CompareSolutionsQuery {
+                    base:  Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                    other: Uuid::from_str("00000000-0000-0000-0000-000000000002").unwrap().into(),
+                },
+            )
+            .await;
+
+        assert!(matches!(response, Err(ProjectGroupError::SolutionNotFound(_))));
+    }
+}
@@ -1,12 +1,13 @@
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
-use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use starfoundry_lib_industry::project_group::{ProjectGroupPermission, ProjectGroupPermissionCode};
 use starfoundry_lib_industry::ProjectGroupUuid;
 use starfoundry_lib_types::CharacterId;
 use std::str::FromStr;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project_group::error::{ProjectGroupError, Result};
 
 pub async fn create(
@@ -55,6 +56,30 @@ pub async fn create(
         .await
         .map_err(ProjectGroupError::CreateGroup)?;
 
+    // add the default roles
+    let (role_names, role_permissions): (Vec<String>, Vec<i32>) = default_roles()
+        .into_iter()
+        .map(|(name, permission)| (name.to_string(), *permission))
+        .unzip();
+    sqlx::query!("
+            INSERT INTO project_group_role(
+                project_group_id,
+                name,
+                permission
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::VARCHAR[],
+                $3::INTEGER[]
+            )
+        ",
+            *group_id,
+            &role_names,
+            &role_permissions,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(ProjectGroupError::CreateGroup)?;
+
     // add the defaults
     sqlx::query!("
             INSERT INTO project_group_default_market(
@@ -72,6 +97,13 @@ pub async fn create(
         .await
         .map_err(ProjectGroupError::CreateGroup)?;
 
+    AuditRecord::created(
+            &mut transaction,
+            character_id,
+            AuditTarget::ProjectGroup(group_id),
+        )
+        .await?;
+
     transaction
         .commit()
         .await
@@ -79,6 +111,30 @@ pub async fn create(
     Ok(group_id)
 }
 
+/// Roles every new group starts with, they can be changed afterwards
+fn default_roles() -> Vec<(&'static str, ProjectGroupPermission)> {
+    let mut viewer = ProjectGroupPermission::default();
+    viewer
+        .add(ProjectGroupPermissionCode::Read);
+
+    let mut builder = viewer;
+    builder
+        .add(ProjectGroupPermissionCode::WriteProject);
+
+    let mut manager = builder;
+    manager
+        .add(ProjectGroupPermissionCode::WriteStructure)
+        .add(ProjectGroupPermissionCode::WriteDefault)
+        .add(ProjectGroupPermissionCode::WriteMember)
+        .add(ProjectGroupPermissionCode::WriteGroup);
+
+    vec![
+        ("Viewer", viewer),
+        ("Builder", builder),
+        ("Manager", manager),
+    ]
+}
+
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[schema(
     example = json!({
@@ -166,5 +222,10 @@ mod create_project_group_test {
             .await
             .unwrap();
         assert_eq!(entry.name, "My shared projects");
+
+        let roles = crate::project_group::service::list_roles(&pool, entry.id.into())
+            .await
+            .unwrap();
+        assert_eq!(roles.len(), 3);
     }
 }
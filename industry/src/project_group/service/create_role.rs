@@ -0,0 +1,188 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::project_group::{ProjectGroupPermission, ProjectGroupPermissionCode};
+use starfoundry_lib_industry::{ProjectGroupRoleUuid, ProjectGroupUuid};
+use starfoundry_lib_types::CharacterId;
+use utoipa::ToSchema;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project_group::error::{ProjectGroupError, Result};
+
+pub async fn create_role(
+    pool:               &PgPool,
+    character_id:       CharacterId,
+    project_group_uuid: ProjectGroupUuid,
+    role:               ProjectGroupRoleRequest,
+) -> Result<ProjectGroupRoleUuid> {
+    role.validate()?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Create,
+            AuditTarget::ProjectGroupRole(project_group_uuid),
+        )
+        .await?;
+
+    let role_uuid = sqlx::query!("
+            INSERT INTO project_group_role (
+                project_group_id,
+                name,
+                permission
+            )
+            VALUES ($1, $2, $3)
+            RETURNING id
+        ",
+            *project_group_uuid,
+            role.name.trim(),
+            *role.permissions,
+        )
+        .fetch_one(&mut *transaction)
+        .await
+        .map(|x| x.id.into())
+        .map_err(|e| {
+            if is_unique_violation(&e) {
+                ProjectGroupError::ValidationError("A role with the same name already exists".into())
+            } else {
+                ProjectGroupError::CreateRole(e, project_group_uuid)
+            }
+        })?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectGroupError::TransactionError)?;
+
+    Ok(role_uuid)
+}
+
+pub(crate) fn is_unique_violation(
+    error: &sqlx::Error,
+) -> bool {
+    error
+        .as_database_error()
+        .map(|x| x.is_unique_violation())
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "name": "Builder",
+        "permissions": [
+            "READ",
+            "WRITE_PROJECT"
+        ]
+    })
+)]
+pub struct ProjectGroupRoleRequest {
+    /// Maximum length 64
+    pub name:        String,
+    /// Ownership cannot be granted by a role
+    pub permissions: ProjectGroupPermission,
+}
+
+impl ProjectGroupRoleRequest {
+    pub fn validate(&self) -> Result<bool> {
+        if self.name.trim().is_empty() {
+            return Err(ProjectGroupError::ValidationError("Field 'name' must be set".into()));
+        } else if self.name.len() > 64 {
+            return Err(ProjectGroupError::ValidationError("Field 'name' is too long, max length: 64".into()));
+        }
+
+        if self.permissions.as_permissions().contains(&ProjectGroupPermissionCode::Owner) {
+            return Err(ProjectGroupError::ValidationError("Field 'permissions' must not contain 'OWNER'".into()));
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod create_role_test {
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::project_group::{ProjectGroupPermission, ProjectGroupPermissionCode};
+    use starfoundry_lib_types::CharacterId;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::project_group::error::ProjectGroupError;
+    use crate::project_group::service::ProjectGroupRoleRequest;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let mut permissions = ProjectGroupPermission::new(0);
+        permissions
+            .add(ProjectGroupPermissionCode::Read)
+            .add(ProjectGroupPermissionCode::WriteStructure);
+
+        let result = super::create_role(
+                &pool,
+                CharacterId(1),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                ProjectGroupRoleRequest {
+                    name:        "Structure Manager".into(),
+                    permissions: permissions,
+                },
+            )
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn duplicate_name(
+        pool: PgPool,
+    ) {
+        let result = super::create_role(
+                &pool,
+                CharacterId(1),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                ProjectGroupRoleRequest {
+                    name:        "Viewer".into(),
+                    permissions: ProjectGroupPermission::new(*ProjectGroupPermissionCode::Read),
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectGroupError::ValidationError(_))));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn owner_not_allowed(
+        pool: PgPool,
+    ) {
+        let result = super::create_role(
+                &pool,
+                CharacterId(1),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                ProjectGroupRoleRequest {
+                    name:        "Co-Owner".into(),
+                    permissions: ProjectGroupPermission::new(*ProjectGroupPermissionCode::Owner),
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectGroupError::ValidationError(_))));
+    }
+}
@@ -1,11 +1,15 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project_group::error::{ProjectGroupError, Result};
 
 pub async fn delete(
-    pool:     &PgPool,
-    group_id: ProjectGroupUuid,
+    pool:         &PgPool,
+    character_id: CharacterId,
+    group_id:     ProjectGroupUuid,
 ) -> Result<ProjectGroupUuid> {
     let has_project = sqlx::query!("
             SELECT 1 AS entry
@@ -22,22 +26,43 @@ pub async fn delete(
         return Err(ProjectGroupError::ProjectIsAssignedToGroup);
     }
 
-    sqlx::query!("
-        DELETE FROM project_group
-        WHERE id = $1
-        RETURNING id
-    ",
-        *group_id,
-    )
-    .fetch_one(pool)
-    .await
-    .map(|x| ProjectGroupUuid::new(x.id))
-    .map_err(|e| ProjectGroupError::DeleteGroup(e, group_id))
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Delete,
+            AuditTarget::ProjectGroup(group_id),
+        )
+        .await?;
+
+    let group_id = sqlx::query!("
+            DELETE FROM project_group
+            WHERE id = $1
+            RETURNING id
+        ",
+            *group_id,
+        )
+        .fetch_one(&mut *transaction)
+        .await
+        .map(|x| ProjectGroupUuid::new(x.id))
+        .map_err(|e| ProjectGroupError::DeleteGroup(e, group_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectGroupError::TransactionError)?;
+
+    Ok(group_id)
 }
 
 #[cfg(test)]
 mod delete_project_group_test {
     use sqlx::PgPool;
+    use starfoundry_lib_types::CharacterId;
     use std::str::FromStr;
     use uuid::Uuid;
 
@@ -52,6 +77,7 @@ mod delete_project_group_test {
     ) {
         let result = super::delete(
                 &pool,
+                CharacterId(1),
                 Uuid::from_str("00000000-0000-0000-0000-000000000010").unwrap().into(),
             )
             .await;
@@ -69,6 +95,7 @@ mod delete_project_group_test {
     ) {
         let result = super::delete(
                 &pool,
+                CharacterId(1),
                 Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
             )
             .await;
@@ -0,0 +1,133 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::{ProjectGroupRoleUuid, ProjectGroupUuid};
+use starfoundry_lib_types::CharacterId;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project_group::error::{ProjectGroupError, Result};
+
+/// Deletes the role, roles that are still assigned cannot be deleted
+pub async fn delete_role(
+    pool:               &PgPool,
+    character_id:       CharacterId,
+    project_group_uuid: ProjectGroupUuid,
+    role_uuid:          ProjectGroupRoleUuid,
+) -> Result<()> {
+    let in_use = sqlx::query!(r#"
+            SELECT (
+                EXISTS(SELECT 1 FROM project_group_member WHERE role_id = $1) OR
+                EXISTS(SELECT 1 FROM project_group_grant WHERE role_id = $1)
+            ) AS "in_use!"
+        "#,
+            *role_uuid,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|x| x.in_use)
+        .map_err(|e| ProjectGroupError::DeleteRole(e, role_uuid))?;
+
+    if in_use {
+        return Err(ProjectGroupError::RoleInUse(role_uuid));
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Delete,
+            AuditTarget::ProjectGroupRole(project_group_uuid),
+        )
+        .await?;
+
+    let result = sqlx::query!("
+            DELETE FROM project_group_role
+            WHERE project_group_id = $1
+            AND id = $2
+        ",
+            *project_group_uuid,
+            *role_uuid,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectGroupError::DeleteRole(e, role_uuid))?;
+
+    if result.rows_affected() == 0 {
+        return Err(ProjectGroupError::RoleNotFound(role_uuid));
+    }
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectGroupError::TransactionError)
+}
+
+#[cfg(test)]
+mod delete_role_test {
+    use sqlx::PgPool;
+    use starfoundry_lib_types::CharacterId;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::project_group::error::ProjectGroupError;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let result = super::delete_role(
+                &pool,
+                CharacterId(1),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000003").unwrap().into(),
+            )
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn in_use(
+        pool: PgPool,
+    ) {
+        let result = super::delete_role(
+                &pool,
+                CharacterId(1),
+                Uuid::from_str("00000000-0000-0000-0000-000000000005").unwrap().into(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000005").unwrap().into(),
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectGroupError::RoleInUse(_))));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn other_group(
+        pool: PgPool,
+    ) {
+        let result = super::delete_role(
+                &pool,
+                CharacterId(1),
+                Uuid::from_str("00000000-0000-0000-0000-000000000002").unwrap().into(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000003").unwrap().into(),
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectGroupError::RoleNotFound(_))));
+    }
+}
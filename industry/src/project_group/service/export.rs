@@ -0,0 +1,174 @@
+use chrono::Utc;
+use sqlx::PgPool;
+use starfoundry_lib_industry::{ProjectGroupUuid, StructureUuid};
+use starfoundry_lib_industry::project_group::{PROJECT_GROUP_EXPORT_VERSION, ProjectGroupExport, ProjectGroupExportBlueprintOverwrite, ProjectGroupExportIndustryHub, ProjectGroupExportJobSplitting};
+use starfoundry_lib_industry::structure::ExportStructure;
+use uuid::Uuid;
+
+use crate::project_group::error::{ProjectGroupError, Result};
+
+/// Exports the project group with its defaults and industry hubs into the
+/// portable export format
+pub async fn export_project_group(
+    pool:               &PgPool,
+    project_group_uuid: ProjectGroupUuid,
+) -> Result<ProjectGroupExport> {
+    let group = sqlx::query!("
+            SELECT name, description
+            FROM project_group
+            WHERE id = $1
+        ",
+            *project_group_uuid,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ProjectGroupError::ExportGroup(e, project_group_uuid))?
+        .ok_or(ProjectGroupError::NotFound(project_group_uuid))?;
+
+    let blacklist = sqlx::query!("
+            SELECT type_id
+            FROM project_group_default_blacklist
+            WHERE project_group_id = $1
+            ORDER BY type_id
+        ",
+            *project_group_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::ExportGroup(e, project_group_uuid))?
+        .into_iter()
+        .map(|x| x.type_id.into())
+        .collect::<Vec<_>>();
+
+    let blueprint_overwrites = sqlx::query!("
+            SELECT type_id, material_efficiency
+            FROM project_group_default_blueprint_overwrite
+            WHERE project_group_id = $1
+            ORDER BY type_id
+        ",
+            *project_group_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::ExportGroup(e, project_group_uuid))?
+        .into_iter()
+        .map(|x| ProjectGroupExportBlueprintOverwrite {
+            type_id:             x.type_id.into(),
+            material_efficiency: x.material_efficiency,
+        })
+        .collect::<Vec<_>>();
+
+    let job_splitting = sqlx::query!("
+            SELECT type_id, max_runs
+            FROM project_group_default_job_splitting_run
+            WHERE project_group_id = $1
+            ORDER BY type_id
+        ",
+            *project_group_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::ExportGroup(e, project_group_uuid))?
+        .into_iter()
+        .map(|x| ProjectGroupExportJobSplitting {
+            type_id:  x.type_id.into(),
+            max_runs: x.max_runs,
+        })
+        .collect::<Vec<_>>();
+
+    let freight_rate = sqlx::query!("
+            SELECT rate
+            FROM project_group_default_freight
+            WHERE project_group_id = $1
+        ",
+            *project_group_uuid,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ProjectGroupError::ExportGroup(e, project_group_uuid))?
+        .map(|x| x.rate);
+
+    let markets = sqlx::query!("
+            SELECT structure_id
+            FROM project_group_default_market
+            WHERE project_group_id = $1
+        ",
+            *project_group_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::ExportGroup(e, project_group_uuid))?
+        .into_iter()
+        .map(|x| x.structure_id.into())
+        .collect::<Vec<StructureUuid>>();
+
+    let industry_hubs = sqlx::query!(r#"
+            SELECT
+                ih.name,
+                ih.description,
+                ARRAY(
+                    SELECT structure_id
+                    FROM industry_hub_structure
+                    WHERE industry_hub_id = ih.id
+                ) AS "structures!"
+            FROM project_group_industry_hub pgih
+            JOIN industry_hub ih ON ih.id = pgih.industry_hub_id
+            WHERE pgih.project_group_id = $1
+            ORDER BY ih.name
+        "#,
+            *project_group_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::ExportGroup(e, project_group_uuid))?
+        .into_iter()
+        .map(|x| ProjectGroupExportIndustryHub {
+            name:        x.name,
+            description: x.description,
+            structures:  x.structures.into_iter().map(Into::into).collect(),
+        })
+        .collect::<Vec<_>>();
+
+    let structure_ids = markets
+        .iter()
+        .chain(industry_hubs.iter().flat_map(|x| x.structures.iter()))
+        .map(|x| **x)
+        .collect::<Vec<Uuid>>();
+    let structures = sqlx::query!("
+            SELECT id, structure_id, name, system_id, type_id
+            FROM structure
+            WHERE id = ANY($1::UUID[])
+            ORDER BY name
+        ",
+            &structure_ids,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::ExportGroup(e, project_group_uuid))?
+        .into_iter()
+        .map(|x| ExportStructure {
+            id:           x.id.into(),
+            structure_id: x.structure_id.into(),
+            name:         x.name,
+            system_id:    x.system_id.into(),
+            type_id:      x.type_id.into(),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ProjectGroupExport {
+        version:     PROJECT_GROUP_EXPORT_VERSION,
+        exported_at: Utc::now(),
+
+        name:        group.name,
+        description: group.description,
+
+        blacklist,
+        blueprint_overwrites,
+        job_splitting,
+        freight_rate,
+        markets,
+        industry_hubs,
+
+        structures,
+    })
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use starfoundry_lib_industry::ProjectGroupUuid;
+use utoipa::ToSchema;
+
+use crate::project_group::error::{ProjectGroupError, Result};
+
+/// Fetches the freight rate used for moving intermediates between industry
+/// hubs, groups without a configured rate move them for free
+pub async fn fetch_default_freight(
+    pool:               &PgPool,
+    project_group_uuid: ProjectGroupUuid,
+) -> Result<Freight> {
+    let rate = sqlx::query!("
+            SELECT rate
+            FROM project_group_default_freight
+            WHERE project_group_id = $1
+        ",
+            *project_group_uuid,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ProjectGroupError::FetchGroupDefaults(e, project_group_uuid))?
+        .map(|x| x.rate)
+        .unwrap_or_default();
+
+    Ok(Freight {
+        rate,
+    })
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "rate": 1000
+    })
+)]
+pub struct Freight {
+    /// ISK per m3
+    pub rate: f64,
+}
+
+impl Freight {
+    /// Cost for moving the given volume
+    pub fn cost(
+        &self,
+        volume: f64,
+    ) -> f64 {
+        volume * self.rate
+    }
+}
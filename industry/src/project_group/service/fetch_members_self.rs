@@ -1,43 +1,54 @@
 use sqlx::PgPool;
 use starfoundry_lib_eve_gateway::EveGatewayApiClient;
-use starfoundry_lib_industry::project_group::{ProjectGroupMember, ProjectGroupPermission};
+use starfoundry_lib_industry::project_group::ProjectGroupMember;
 use starfoundry_lib_industry::ProjectGroupUuid;
-use starfoundry_lib_types::CharacterId;
 
 use crate::project_group::error::{ProjectGroupError, Result};
+use crate::project_group::permission::{fetch_permission, MemberIdentity};
 
+/// Fetches the membership of the requesting character, the permissions
+/// include the roles granted to the corporation and alliance
 pub async fn fetch_members_self(
     pool:                   &PgPool,
-    character_id:           CharacterId,
+    identity:               MemberIdentity,
     eve_gateway_api_client: &impl EveGatewayApiClient,
     project_group_uuid:     ProjectGroupUuid,
 ) -> Result<Option<ProjectGroupMember>> {
     let entry = sqlx::query!(
-        "
+        r#"
             SELECT
-                permission,
-                (pg.owner = $2) AS is_owner
-            FROM project_group_member pgm
-            JOIN project_group pg ON pg.id = pgm.project_group_id
-            WHERE project_group_id = $1
-            AND character_id = $2
-        ",
+                (pg.owner = $2) AS is_owner,
+                pgm.role_id AS "role_id?"
+            FROM project_group pg
+            LEFT JOIN project_group_member pgm
+                ON pgm.project_group_id = pg.id
+                AND pgm.character_id = $2
+            WHERE pg.id = $1
+        "#,
             *project_group_uuid,
-            *character_id,
+            *identity.character_id,
         )
         .fetch_one(pool)
         .await
         .map_err(|e| ProjectGroupError::FetchMembersSelf(e, project_group_uuid))?;
 
+    let permissions = fetch_permission(
+            pool,
+            project_group_uuid,
+            identity,
+        )
+        .await?;
+
     match eve_gateway_api_client
         .fetch_character(
-            character_id,
+            identity.character_id,
         )
         .await {
 
         Ok(Some(x)) => Ok(Some(ProjectGroupMember {
             character:      x,
-            permissions:    ProjectGroupPermission::new(entry.permission),
+            permissions:    permissions,
+            role_id:        entry.role_id.map(Into::into),
             is_owner:       entry.is_owner.unwrap_or(false),
         })),
         Ok(None) => Ok(None),
@@ -48,10 +59,11 @@ pub async fn fetch_members_self(
 #[cfg(test)]
 mod fetch_members_self_test {
     use sqlx::PgPool;
-    use starfoundry_lib_types::CharacterId;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
     use std::str::FromStr;
     use uuid::Uuid;
 
+    use crate::project_group::permission::MemberIdentity;
     use crate::test_util::EveGatewayTestApiClient;
 
     #[sqlx::test(
@@ -67,7 +79,7 @@ mod fetch_members_self_test {
 
         let response = super::fetch_members_self(
                 &pool,
-                CharacterId(1),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
                 &gateway_client,
                 Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
             )
@@ -89,7 +101,7 @@ mod fetch_members_self_test {
 
         let response = super::fetch_members_self(
                 &pool,
-                CharacterId(1),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
                 &gateway_client,
                 Uuid::from_str("00000000-0000-0000-0000-000000000000").unwrap().into(),
             )
@@ -0,0 +1,333 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::{ProjectGroupUuid, StructureUuid};
+use starfoundry_lib_industry::project_group::{ImportProjectGroup, PROJECT_GROUP_EXPORT_VERSION};
+use starfoundry_lib_types::CharacterId;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::industry_hub::service::{CreateIndustryHub, UpdateIndustryHub};
+use crate::project_group::error::{ProjectGroupError, Result};
+use crate::project_group::service::{create, update, update_default_blacklist, update_default_blueprint_overwrite, update_default_freight, update_default_job_splitting, update_default_market, update_industry_hubs, CreateProjectGroup, Freight, UpdateProjectGroup, UpdateProjectGroupDefaultBlueprintOverwrite, UpdateProjectGroupDefaultJobSplitting};
+use crate::structure::service::remap_structures;
+
+/// Imports an exported project group as a new project group owned by the
+/// character.
+///
+/// Type ids and structures are validated before anything is written.
+/// The industry hubs of the export are created as new industry hubs of the
+/// character.
+///
+/// # Errors
+///
+/// - [ProjectGroupError::ValidationError] if the export is from a newer
+///   version, or references unknown types or structures
+///
+pub async fn import_project_group(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    character_id:           CharacterId,
+    import:                 ImportProjectGroup,
+) -> Result<ProjectGroupUuid> {
+    let group = import.project_group;
+    if group.version > PROJECT_GROUP_EXPORT_VERSION {
+        return Err(ProjectGroupError::ValidationError(
+            format!("export version '{}' is not supported", group.version)
+        ));
+    }
+    CreateProjectGroup {
+        name: group.name.clone(),
+    }.validate()?;
+
+    let type_ids = group.type_ids();
+    if !type_ids.is_empty() {
+        let known = eve_gateway_api_client
+            .fetch_item_bulk(type_ids.clone())
+            .await?
+            .into_iter()
+            .map(|x| x.type_id)
+            .collect::<HashSet<_>>();
+        let unknown = type_ids
+            .into_iter()
+            .filter(|x| !known.contains(x))
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+        if !unknown.is_empty() {
+            return Err(ProjectGroupError::ValidationError(
+                format!("unknown type ids: {}", unknown.join(", "))
+            ));
+        }
+    }
+
+    let structures = remap_structures(
+            pool,
+            character_id,
+            &group.structures,
+            &import.structure_mapping,
+        )
+        .await?;
+    let markets = group
+        .markets
+        .iter()
+        .map(|x| map_structure(&structures, *x).map(StructureUuid::new))
+        .collect::<Result<Vec<_>>>()?;
+    let mut industry_hubs = Vec::new();
+    for industry_hub in group.industry_hubs {
+        let hub_structures = industry_hub
+            .structures
+            .iter()
+            .map(|x| map_structure(&structures, *x))
+            .collect::<Result<Vec<_>>>()?;
+        industry_hubs.push((industry_hub.name, industry_hub.description, hub_structures));
+    }
+
+    let project_group_id = create(
+            pool,
+            character_id,
+            CreateProjectGroup {
+                name: group.name.clone(),
+            },
+        )
+        .await?;
+
+    if group.description.is_some() {
+        update(
+                pool,
+                character_id,
+                project_group_id,
+                UpdateProjectGroup {
+                    name:        group.name,
+                    description: group.description,
+                },
+            )
+            .await?;
+    }
+
+    update_default_blacklist(
+            pool,
+            character_id,
+            project_group_id,
+            group.blacklist,
+        )
+        .await?;
+    update_default_blueprint_overwrite(
+            pool,
+            character_id,
+            project_group_id,
+            group
+                .blueprint_overwrites
+                .into_iter()
+                .map(|x| UpdateProjectGroupDefaultBlueprintOverwrite {
+                    material_efficiency: x.material_efficiency,
+                    type_id:             x.type_id,
+                })
+                .collect(),
+        )
+        .await?;
+    update_default_job_splitting(
+            pool,
+            character_id,
+            project_group_id,
+            group
+                .job_splitting
+                .into_iter()
+                .map(|x| UpdateProjectGroupDefaultJobSplitting {
+                    type_id:  x.type_id,
+                    max_runs: x.max_runs,
+                })
+                .collect(),
+        )
+        .await?;
+    if let Some(rate) = group.freight_rate {
+        update_default_freight(
+                pool,
+                character_id,
+                project_group_id,
+                Freight {
+                    rate,
+                },
+            )
+            .await?;
+    }
+    update_default_market(
+            pool,
+            character_id,
+            project_group_id,
+            markets,
+        )
+        .await?;
+
+    let mut industry_hub_ids = Vec::new();
+    for (name, description, structures) in industry_hubs {
+        let industry_hub_id = crate::industry_hub::service::create(
+                pool,
+                character_id,
+                CreateIndustryHub {
+                    name: name.clone(),
+                },
+            )
+            .await?;
+        crate::industry_hub::service::update(
+                pool,
+                character_id,
+                industry_hub_id,
+                UpdateIndustryHub {
+                    name,
+                    structures,
+                    shares: Vec::new(),
+                    description,
+                },
+            )
+            .await?;
+        industry_hub_ids.push(industry_hub_id);
+    }
+    update_industry_hubs(
+            pool,
+            character_id,
+            project_group_id,
+            industry_hub_ids,
+        )
+        .await?;
+
+    Ok(project_group_id)
+}
+
+fn map_structure(
+    structures:   &HashMap<StructureUuid, StructureUuid>,
+    structure_id: StructureUuid,
+) -> Result<Uuid> {
+    structures
+        .get(&structure_id)
+        .map(|x| **x)
+        .ok_or_else(|| ProjectGroupError::ValidationError(
+            format!("structure '{}' is not part of the export", structure_id)
+        ))
+}
+
+#[cfg(test)]
+mod import_project_group_test {
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::project_group::ImportProjectGroup;
+    use starfoundry_lib_types::{CharacterId, TypeId};
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::project_group::error::ProjectGroupError;
+    use crate::project_group::service::export_project_group;
+    use crate::test_util::EveGatewayTestApiClient;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base")
+        ),
+    )]
+    async fn roundtrip(
+        pool: PgPool,
+    ) {
+        let export = export_project_group(
+                &pool,
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(export.blacklist.len(), 4);
+        assert_eq!(export.structures.len(), 1);
+
+        let result = super::import_project_group(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                CharacterId(1),
+                ImportProjectGroup {
+                    structure_mapping: HashMap::new(),
+                    project_group:     export.clone(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let imported = export_project_group(&pool, result).await.unwrap();
+        assert_eq!(imported.name, export.name);
+        assert_eq!(imported.description, export.description);
+        assert_eq!(imported.blacklist, export.blacklist);
+        assert_eq!(imported.blueprint_overwrites.len(), 1);
+        assert_eq!(imported.job_splitting.len(), 1);
+        assert_eq!(imported.markets, export.markets);
+
+        // every part of the group is recorded by the service that creates it
+        let scopes = sqlx::query!(r#"
+                SELECT scope::TEXT AS "scope!"
+                FROM audit_event
+                WHERE entity_id = $1
+            "#,
+                *result,
+            )
+            .fetch_all(&pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|x| x.scope)
+            .collect::<Vec<_>>();
+        assert!(scopes.contains(&"PROJECT_GROUP".into()));
+        assert!(scopes.contains(&"PROJECT_GROUP_DEFAULT_BLACKLIST".into()));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base")
+        ),
+    )]
+    async fn validation_error(
+        pool: PgPool,
+    ) {
+        let export = export_project_group(
+                &pool,
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+            )
+            .await
+            .unwrap();
+
+        let mut newer = export.clone();
+        newer.version += 1;
+        let result = super::import_project_group(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                CharacterId(1),
+                ImportProjectGroup {
+                    structure_mapping: HashMap::new(),
+                    project_group:     newer,
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectGroupError::ValidationError(_))));
+
+        let mut unknown_type = export.clone();
+        unknown_type.blacklist.push(TypeId(1));
+        let result = super::import_project_group(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                CharacterId(1),
+                ImportProjectGroup {
+                    structure_mapping: HashMap::new(),
+                    project_group:     unknown_type,
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectGroupError::ValidationError(_))));
+
+        // character 3 doesn't have access to the market structure
+        let result = super::import_project_group(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                CharacterId(3),
+                ImportProjectGroup {
+                    structure_mapping: HashMap::new(),
+                    project_group:     export,
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectGroupError::StructureError(_))));
+    }
+}
@@ -1,14 +1,16 @@
 use serde::Deserialize;
 use sqlx::PgPool;
 use starfoundry_lib_industry::project_group::ProjectGroupMinimal;
-use starfoundry_lib_types::CharacterId;
 use utoipa::{IntoParams, ToSchema};
 
 use crate::project_group::error::{ProjectGroupError, Result};
+use crate::project_group::permission::MemberIdentity;
 
+/// Lists all groups the character is a member of, either directly or by a
+/// role granted to its corporation or alliance
 pub async fn list(
     pool:           &PgPool,
-    character_id:   CharacterId,
+    identity:       MemberIdentity,
     filter:         ProjectGroupFilter,
 ) -> Result<Vec<ProjectGroupMinimal>> {
     let owner_filter = match filter.owner {
@@ -35,18 +37,35 @@ pub async fn list(
                     WHERE project_group_id = pg.id
                 ) AS projects
             FROM project_group pg
-            JOIN project_group_member pgm ON pg.id = pgm.project_group_id
             -- fetch all projects where the user is a member
-            WHERE pgm.character_id = $1 AND
+            WHERE (
+                    EXISTS (
+                        SELECT 1
+                        FROM project_group_member pgm
+                        WHERE pgm.project_group_id = pg.id
+                        AND pgm.character_id = $1
+                    ) OR
+                    EXISTS (
+                        SELECT 1
+                        FROM project_group_grant pgg
+                        WHERE pgg.project_group_id = pg.id
+                        AND (
+                            (pgg.entity_type = 'CORPORATION' AND pgg.entity_id = $5) OR
+                            (pgg.entity_type = 'ALLIANCE' AND pgg.entity_id = $6)
+                        )
+                    )
+                ) AND
                 NOT (LOWER(name) LIKE '%' || LOWER($2) || '%') IS FALSE AND
                 NOT (owner = $1 OR $3) IS FALSE AND
                 archived = $4
             ORDER BY pg.name ASC
         ",
-            *character_id,
+            *identity.character_id,
             filter.name,
             owner_filter,
             archived_filter,
+            *identity.corporation_id,
+            identity.alliance_id.map(|x| *x),
         )
         .fetch_all(pool)
         .await
@@ -88,8 +107,9 @@ pub struct ProjectGroupFilter {
 #[cfg(test)]
 mod list_project_group_test {
     use sqlx::PgPool;
-    use starfoundry_lib_types::CharacterId;
+    use starfoundry_lib_types::{AllianceId, CharacterId, CorporationId};
 
+    use crate::project_group::permission::MemberIdentity;
     use crate::project_group::service::list::ProjectGroupFilter;
 
     #[sqlx::test(
@@ -103,7 +123,7 @@ mod list_project_group_test {
     ) {
         let result = super::list(
                 &pool,
-                CharacterId(1),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
                 ProjectGroupFilter {
                     name: None,
                     owner: None,
@@ -116,7 +136,7 @@ mod list_project_group_test {
 
         let result = super::list(
                 &pool,
-                CharacterId(1),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
                 ProjectGroupFilter {
                     name: Some(String::from("Filter")),
                     owner: None,
@@ -129,7 +149,7 @@ mod list_project_group_test {
 
         let result = super::list(
                 &pool,
-                CharacterId(1),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
                 ProjectGroupFilter {
                     name: Some(String::from("SomeGibberish")),
                     owner: None,
@@ -142,7 +162,7 @@ mod list_project_group_test {
 
         let result = super::list(
                 &pool,
-                CharacterId(2),
+                MemberIdentity::new(CharacterId(2), CorporationId(1), None),
                 ProjectGroupFilter {
                     name: None,
                     owner: Some(true),
@@ -155,7 +175,7 @@ mod list_project_group_test {
 
         let result = super::list(
                 &pool,
-                CharacterId(1),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
                 ProjectGroupFilter {
                     name: None,
                     owner: Some(false),
@@ -166,4 +186,41 @@ mod list_project_group_test {
         assert!(result.is_ok());
         assert_eq!(result.unwrap().len(), 4);
     }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base")
+        ),
+    )]
+    async fn granted_to_corporation_or_alliance(
+        pool: PgPool,
+    ) {
+        let result = super::list(
+                &pool,
+                MemberIdentity::new(CharacterId(3), CorporationId(1000), None),
+                ProjectGroupFilter::default(),
+            )
+            .await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 1);
+
+        let result = super::list(
+                &pool,
+                MemberIdentity::new(CharacterId(3), CorporationId(1), Some(AllianceId(2000))),
+                ProjectGroupFilter::default(),
+            )
+            .await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 1);
+
+        let result = super::list(
+                &pool,
+                MemberIdentity::new(CharacterId(3), CorporationId(1), None),
+                ProjectGroupFilter::default(),
+            )
+            .await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 0);
+    }
 }
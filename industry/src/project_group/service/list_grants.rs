@@ -0,0 +1,35 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::project_group::{ProjectGroupGrant, ProjectGroupGrantEntity};
+use starfoundry_lib_industry::ProjectGroupUuid;
+
+use crate::project_group::error::{ProjectGroupError, Result};
+
+pub async fn list_grants(
+    pool:               &PgPool,
+    project_group_uuid: ProjectGroupUuid,
+) -> Result<Vec<ProjectGroupGrant>> {
+    sqlx::query!(r#"
+            SELECT
+                entity_type AS "entity_type!: ProjectGroupGrantEntity",
+                entity_id,
+                role_id
+            FROM project_group_grant
+            WHERE project_group_id = $1
+            ORDER BY entity_type, entity_id
+        "#,
+            *project_group_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map(|entries| {
+            entries
+                .into_iter()
+                .map(|x| ProjectGroupGrant {
+                    entity_type: x.entity_type,
+                    entity_id:   x.entity_id,
+                    role_id:     x.role_id.into(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .map_err(|e| ProjectGroupError::ListGrants(e, project_group_uuid))
+}
@@ -11,15 +11,17 @@ pub async fn list_members(
     project_group_uuid:     ProjectGroupUuid,
 ) -> Result<Vec<ProjectGroupMember>> {
     let entries = sqlx::query!(
-        "
+        r#"
             SELECT
                 pgm.character_id,
-                permission,
+                pgm.permission | COALESCE(pgr.permission, 0) AS "permission!",
+                pgm.role_id,
                 (pg.owner = pgm.character_id) AS is_owner
             FROM project_group_member pgm
             JOIN project_group pg ON pg.id = pgm.project_group_id
-            WHERE project_group_id = $1
-        ",
+            LEFT JOIN project_group_role pgr ON pgr.id = pgm.role_id
+            WHERE pgm.project_group_id = $1
+        "#,
             *project_group_uuid,
         )
         .fetch_all(pool)
@@ -43,6 +45,7 @@ pub async fn list_members(
         let member = ProjectGroupMember {
             character:      character,
             permissions:    ProjectGroupPermission::new(entry.permission),
+            role_id:        entry.role_id.map(Into::into),
             is_owner:       entry.is_owner.unwrap_or(false),
         };
         members.push(member);
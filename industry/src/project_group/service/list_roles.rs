@@ -0,0 +1,62 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::project_group::{ProjectGroupPermission, ProjectGroupRole};
+use starfoundry_lib_industry::ProjectGroupUuid;
+
+use crate::project_group::error::{ProjectGroupError, Result};
+
+pub async fn list_roles(
+    pool:               &PgPool,
+    project_group_uuid: ProjectGroupUuid,
+) -> Result<Vec<ProjectGroupRole>> {
+    sqlx::query!("
+            SELECT
+                id,
+                name,
+                permission
+            FROM project_group_role
+            WHERE project_group_id = $1
+            ORDER BY permission ASC, name ASC
+        ",
+            *project_group_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map(|entries| {
+            entries
+                .into_iter()
+                .map(|x| ProjectGroupRole {
+                    id:          x.id.into(),
+                    name:        x.name,
+                    permissions: ProjectGroupPermission::new(x.permission),
+                })
+                .collect::<Vec<_>>()
+        })
+        .map_err(|e| ProjectGroupError::ListRoles(e, project_group_uuid))
+}
+
+#[cfg(test)]
+mod list_roles_test {
+    use sqlx::PgPool;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let result = super::list_roles(
+                &pool,
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.first().unwrap().name, "Viewer");
+    }
+}
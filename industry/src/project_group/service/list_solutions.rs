@@ -0,0 +1,319 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{EveGatewayApiClient, Item};
+use starfoundry_lib_industry::{IndustryHubUuid, ProjectGroupUuid, ProjectUuid, SolutionUuid};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::project_group::error::{ProjectGroupError, Result};
+
+/// Lists all stored build solutions of the project group, newest first
+pub async fn list_solutions(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_group_uuid:     ProjectGroupUuid,
+) -> Result<Vec<SolutionSummary>> {
+    fetch_solution_summaries(
+            pool,
+            eve_gateway_api_client,
+            project_group_uuid,
+            None,
+        )
+        .await
+}
+
+/// Fetches the summaries of the solutions of the project group.
+/// If `solution_uuids` is set, only the given solutions are fetched, otherwise
+/// all solutions that don't belong to a store quote
+pub(crate) async fn fetch_solution_summaries(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_group_uuid:     ProjectGroupUuid,
+    solution_uuids:         Option<Vec<SolutionUuid>>,
+) -> Result<Vec<SolutionSummary>> {
+    let solutions = sqlx::query!(r#"
+            SELECT
+                s.id,
+                s.industry_hub_id,
+                s.rerun_of,
+                s.created_at,
+                COALESCE(
+                    NULLIF(ARRAY(
+                        SELECT industry_hub_id
+                        FROM solution_industry_hub
+                        WHERE solution_id = s.id
+                    ), '{}'),
+                    ARRAY[s.industry_hub_id]
+                ) AS "industry_hubs!",
+                ARRAY(
+                    SELECT id
+                    FROM project
+                    WHERE solution_id = s.id
+                ) AS "projects!",
+                (
+                    SELECT COALESCE(SUM(quantity * COALESCE(cost, 0)), 0)
+                    FROM solution_material
+                    WHERE solution_id = s.id
+                ) AS "material_cost!",
+                (
+                    SELECT COALESCE(SUM(cost), 0)
+                    FROM solution_transfer
+                    WHERE solution_id = s.id
+                ) AS "transfer_cost!",
+                (
+                    SELECT COALESCE(SUM(build_tax), 0)
+                    FROM solution_manufacturing
+                    WHERE solution_id = s.id
+                ) AS "build_tax!",
+                (
+                    SELECT COUNT(*)
+                    FROM solution_manufacturing
+                    WHERE solution_id = s.id
+                ) AS "jobs!",
+                (
+                    SELECT COALESCE(SUM(time), 0)
+                    FROM solution_manufacturing
+                    WHERE solution_id = s.id
+                ) AS "time!",
+                (
+                    SELECT COALESCE(MAX(time), 0)
+                    FROM solution_manufacturing
+                    WHERE solution_id = s.id
+                ) AS "longest_job!"
+            FROM solution s
+            WHERE s.project_group_id = $1
+            AND (
+                $2::UUID[] IS NULL OR
+                s.id = ANY($2)
+            )
+            -- solutions of store quotes are only listed with their orders
+            AND (
+                $2::UUID[] IS NOT NULL OR
+                NOT EXISTS (
+                    SELECT 1
+                    FROM store_order so
+                    WHERE so.solution_id = s.id
+                )
+            )
+            ORDER BY s.created_at DESC
+        "#,
+            *project_group_uuid,
+            solution_uuids
+                .map(|x| x.into_iter().map(|y| *y).collect::<Vec<_>>())
+                as Option<Vec<uuid::Uuid>>,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::ListSolutions(e, project_group_uuid))?;
+
+    let products = sqlx::query!("
+            SELECT
+                solution_id,
+                type_id,
+                quantity,
+                material_efficiency
+            FROM solution_product
+            WHERE solution_id = ANY($1)
+        ",
+            &solutions.iter().map(|x| x.id).collect::<Vec<_>>(),
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::ListSolutions(e, project_group_uuid))?;
+
+    let mut type_ids = products
+        .iter()
+        .map(|x| x.type_id.into())
+        .collect::<Vec<_>>();
+    type_ids.sort();
+    type_ids.dedup();
+    let items = eve_gateway_api_client
+        .fetch_item_bulk(type_ids)
+        .await?
+        .into_iter()
+        .map(|x| (x.type_id, x))
+        .collect::<HashMap<_, _>>();
+
+    let mut products_by_solution: HashMap<uuid::Uuid, Vec<SolutionProduct>> = HashMap::new();
+    for product in products {
+        let item = if let Some(x) = items.get(&product.type_id.into()) {
+            x.clone()
+        } else {
+            continue;
+        };
+
+        products_by_solution
+            .entry(product.solution_id)
+            .or_default()
+            .push(SolutionProduct {
+                item,
+                quantity:            product.quantity,
+                material_efficiency: product.material_efficiency,
+            });
+    }
+
+    let solutions = solutions
+        .into_iter()
+        .map(|x| SolutionSummary {
+            id:              x.id.into(),
+            industry_hub_id: x.industry_hub_id.into(),
+            industry_hubs:   x.industry_hubs.into_iter().map(Into::into).collect::<Vec<_>>(),
+            rerun_of:        x.rerun_of.map(Into::into),
+            projects:        x.projects.into_iter().map(Into::into).collect::<Vec<_>>(),
+            products:        products_by_solution.remove(&x.id).unwrap_or_default(),
+            cost:            SolutionCost {
+                material:  x.material_cost,
+                build_tax: x.build_tax,
+                transfer:  x.transfer_cost,
+                total:     x.material_cost + x.build_tax + x.transfer_cost,
+            },
+            jobs:            x.jobs,
+            time:            x.time,
+            longest_job:     x.longest_job,
+            created_at:      x.created_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(solutions)
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SolutionSummary {
+    pub id:              SolutionUuid,
+    /// Hub the products are build in
+    pub industry_hub_id: IndustryHubUuid,
+    /// All hubs the solution uses
+    pub industry_hubs:   Vec<IndustryHubUuid>,
+    /// Solution this solution was calculated from again
+    pub rerun_of:        Option<SolutionUuid>,
+    /// Projects that were created from the solution
+    pub projects:        Vec<ProjectUuid>,
+    pub products:        Vec<SolutionProduct>,
+    pub cost:            SolutionCost,
+    /// Number of jobs
+    pub jobs:            i64,
+    /// Duration of all jobs together in seconds
+    pub time:            f64,
+    /// Duration of the longest job in seconds
+    pub longest_job:     f64,
+    pub created_at:      DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SolutionProduct {
+    pub item:                Item,
+    pub quantity:            i32,
+    pub material_efficiency: i32,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SolutionCost {
+    /// Materials and additional products, only contains market prices if
+    /// they were calculated
+    pub material:  f64,
+    pub build_tax: f64,
+    /// Moving intermediates between industry hubs
+    pub transfer:  f64,
+    pub total:     f64,
+}
+
+#[cfg(test)]
+mod list_solutions_project_group_test {
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    use crate::eve_gateway_api_client;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base", "solution"),
+        ),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let response = super::list_solutions(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.len(), 2);
+
+        let solution = response
+            .iter()
+            .find(|x| *x.id == Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap())
+            .unwrap();
+        assert_eq!(solution.jobs, 2);
+        // 100 * 5 + 10 * 20
+        assert_eq!(solution.cost.material, 700f64);
+        assert_eq!(solution.cost.build_tax, 150f64);
+        assert_eq!(solution.cost.total, 850f64);
+        assert_eq!(solution.time, 3_000f64);
+        assert_eq!(solution.longest_job, 2_000f64);
+        assert_eq!(solution.industry_hubs.len(), 1);
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base", "solution"),
+        ),
+    )]
+    async fn other_group(
+        pool: PgPool,
+    ) {
+        let response = super::list_solutions(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000002").unwrap().into(),
+            )
+            .await
+            .unwrap();
+
+        assert!(response.is_empty());
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base", "solution"),
+        ),
+    )]
+    async fn store_quotes_are_hidden(
+        pool: PgPool,
+    ) {
+        sqlx::query!(r#"
+                WITH store AS (
+                    INSERT INTO store (owner, project_group_id, name)
+                    VALUES (1, '00000000-0000-0000-0000-000000000001', 'Store')
+                    RETURNING id
+                )
+                INSERT INTO store_order (store_id, customer, solution_id, build_cost, price, valid_until)
+                SELECT id, 2, '00000000-0000-0000-0000-000000000002', 100, 110, NOW()
+                FROM store
+            "#)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let response = super::list_solutions(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.iter().map(|x| *x.id).collect::<Vec<_>>(),
+            vec![Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap()],
+        );
+    }
+}
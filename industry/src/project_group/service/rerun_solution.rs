@@ -0,0 +1,286 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::industry::{BuildEngine, BuildEngineAdditionalProduct, BuildEngineProduct, StockMinimal, TmpBlueprintOverwrite, TmpJobSplitting};
+use starfoundry_lib_industry::{ProjectGroupUuid, SolutionUuid};
+use starfoundry_lib_types::{CharacterId, StructureId};
+
+use crate::project_group::error::{ProjectGroupError, Result};
+use crate::project_group::service::list_default_market;
+
+/// Restores the input of a stored solution, so that it can be calculated
+/// again with the current prices and system indices.
+///
+/// The solution is limited to the industry hubs it used, market prices are
+/// calculated with the default markets of the project group.
+/// Planetary products are only planned if the solution planned them.
+///
+/// # Errors
+///
+/// - [ProjectGroupError::SolutionNotFound] if the solution does not exist or
+///   belongs to another group
+///
+pub async fn solution_rerun_config(
+    pool:                   &PgPool,
+    character_id:           CharacterId,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_group_uuid:     ProjectGroupUuid,
+    solution_uuid:          SolutionUuid,
+) -> Result<BuildEngine> {
+    let solution = sqlx::query!(r#"
+            SELECT
+                s.customs_office_tax,
+                COALESCE(
+                    NULLIF(ARRAY(
+                        SELECT industry_hub_id
+                        FROM solution_industry_hub
+                        WHERE solution_id = s.id
+                    ), '{}'),
+                    ARRAY[s.industry_hub_id]
+                ) AS "industry_hubs!"
+            FROM solution s
+            WHERE s.id = $1
+            AND s.project_group_id = $2
+        "#,
+            *solution_uuid,
+            *project_group_uuid,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ProjectGroupError::FetchSolution(e, solution_uuid))?
+        .ok_or(ProjectGroupError::SolutionNotFound(solution_uuid))?;
+    let industry_hubs = solution
+        .industry_hubs
+        .into_iter()
+        .map(Into::into)
+        .collect::<Vec<_>>();
+
+    let products = sqlx::query!("
+            SELECT
+                type_id,
+                quantity,
+                material_efficiency
+            FROM solution_product
+            WHERE solution_id = $1
+        ",
+            *solution_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::FetchSolution(e, solution_uuid))?
+        .into_iter()
+        .map(|x| BuildEngineProduct {
+            type_id:             x.type_id.into(),
+            material_efficiency: x.material_efficiency as u32,
+            quantity:            x.quantity as u32,
+        })
+        .collect::<Vec<_>>();
+
+    let additional_products = sqlx::query!("
+            SELECT
+                type_id,
+                quantity
+            FROM solution_material
+            WHERE solution_id = $1
+            AND additional = TRUE
+        ",
+            *solution_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::FetchSolution(e, solution_uuid))?
+        .into_iter()
+        .map(|x| BuildEngineAdditionalProduct {
+            type_id:  x.type_id.into(),
+            quantity: x.quantity as u32,
+            price:    None,
+        })
+        .collect::<Vec<_>>();
+
+    let stocks = sqlx::query!("
+            SELECT
+                type_id,
+                quantity
+            FROM solution_stock
+            WHERE solution_id = $1
+        ",
+            *solution_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::FetchSolution(e, solution_uuid))?
+        .into_iter()
+        .map(|x| StockMinimal {
+            type_id:  x.type_id.into(),
+            quantity: x.quantity,
+        })
+        .collect::<Vec<_>>();
+
+    let blacklist = sqlx::query!("
+            SELECT type_id
+            FROM solution_blacklist
+            WHERE solution_id = $1
+        ",
+            *solution_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::FetchSolution(e, solution_uuid))?
+        .into_iter()
+        .map(|x| x.type_id.into())
+        .collect::<Vec<_>>();
+
+    let blueprint_overwrite = sqlx::query!("
+            SELECT
+                type_id,
+                material_efficiency
+            FROM solution_blueprint_overwrite
+            WHERE solution_id = $1
+        ",
+            *solution_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::FetchSolution(e, solution_uuid))?
+        .into_iter()
+        .map(|x| TmpBlueprintOverwrite {
+            type_id:             x.type_id.into(),
+            material_efficiency: x.material_efficiency as u32,
+        })
+        .collect::<Vec<_>>();
+
+    let job_splitting = sqlx::query!("
+            SELECT
+                type_id,
+                runs
+            FROM solution_job_split
+            WHERE solution_id = $1
+        ",
+            *solution_uuid,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ProjectGroupError::FetchSolution(e, solution_uuid))?
+        .into_iter()
+        .map(|x| TmpJobSplitting {
+            type_id: x.type_id.into(),
+            runs:    x.runs as u32,
+        })
+        .collect::<Vec<_>>();
+
+    let markets = list_default_market(
+            pool,
+            character_id,
+            eve_gateway_api_client,
+            project_group_uuid,
+        )
+        .await?
+        .into_iter()
+        .map(|x| StructureId(x.structure_id))
+        .collect::<Vec<_>>();
+
+    Ok(BuildEngine {
+        project_group_id:        project_group_uuid,
+        products:                Some(products),
+        products_str:            None,
+        additional_products:     Some(additional_products),
+        additional_products_str: None,
+        fittings:                None,
+        stocks:                  Some(stocks),
+        stocks_str:              None,
+        stocks_from_assets:      None,
+        stock_location_id:       None,
+        project_id:              None,
+        blacklist:               Some(blacklist),
+        blueprint_overwrite:     Some(blueprint_overwrite),
+        job_splitting:           Some(job_splitting),
+        markets:                 Some(markets),
+        calculate_market_cost:   Some(true),
+        combine_industry_hubs:   Some(industry_hubs.len() > 1),
+        freight_rate:            None,
+        industry_hubs:           Some(industry_hubs),
+        expand_planetary:        Some(solution.customs_office_tax.is_some()),
+        customs_office_tax:      solution.customs_office_tax,
+    })
+}
+
+/// Links a solution to the solution it was calculated from
+pub async fn mark_solution_rerun(
+    pool:          &PgPool,
+    solution_uuid: SolutionUuid,
+    rerun_of:      SolutionUuid,
+) -> Result<()> {
+    sqlx::query!("
+            UPDATE solution
+            SET rerun_of = $2
+            WHERE id = $1
+        ",
+            *solution_uuid,
+            *rerun_of,
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| ProjectGroupError::UpdateSolution(e, solution_uuid))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod rerun_solution_project_group_test {
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use starfoundry_lib_types::{CharacterId, TypeId};
+    use uuid::Uuid;
+
+    use crate::eve_gateway_api_client;
+    use crate::project_group::ProjectGroupError;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base", "solution"),
+        ),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let config = super::solution_rerun_config(
+                &pool,
+                CharacterId(1),
+                &eve_gateway_api_client().unwrap(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000002").unwrap().into(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(config.industry_hubs.unwrap().len(), 2);
+        assert_eq!(config.combine_industry_hubs, Some(true));
+        assert_eq!(config.products.unwrap().len(), 1);
+
+        let additional_products = config.additional_products.unwrap();
+        assert_eq!(additional_products.len(), 1);
+        assert_eq!(additional_products.first().unwrap().type_id, TypeId(4312));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base", "solution"),
+        ),
+    )]
+    async fn other_group(
+        pool: PgPool,
+    ) {
+        let config = super::solution_rerun_config(
+                &pool,
+                CharacterId(1),
+                &eve_gateway_api_client().unwrap(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000002").unwrap().into(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+            )
+            .await;
+
+        assert!(matches!(config, Err(ProjectGroupError::SolutionNotFound(_))));
+    }
+}
@@ -1,32 +1,53 @@
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_types::CharacterId;
 use utoipa::ToSchema;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project_group::error::{ProjectGroupError, Result};
 
 pub async fn update(
     pool:               &PgPool,
+    character_id:       CharacterId,
     project_group_uuid: ProjectGroupUuid,
     update_info:        UpdateProjectGroup,
 ) -> Result<()> {
     update_info.valid()?;
 
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectGroup(project_group_uuid),
+        )
+        .await?;
+
     sqlx::query!("
-        UPDATE project_group
-        SET
-            name = $2,
-            description = $3
-        WHERE id = $1
-    ",
-        *project_group_uuid,
-        update_info.name,
-        update_info.description,
-    )
-    .execute(pool)
-    .await
-    .map(drop)
-    .map_err(|e| ProjectGroupError::UpdateGroup(e, project_group_uuid))
+            UPDATE project_group
+            SET
+                name = $2,
+                description = $3
+            WHERE id = $1
+        ",
+            *project_group_uuid,
+            update_info.name,
+            update_info.description,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectGroupError::UpdateGroup(e, project_group_uuid))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectGroupError::TransactionError)
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
@@ -62,6 +83,7 @@ impl UpdateProjectGroup {
 #[cfg(test)]
 mod update_project_group_test {
     use sqlx::PgPool;
+    use starfoundry_lib_types::CharacterId;
     use std::str::FromStr;
     use uuid::Uuid;
 
@@ -79,6 +101,7 @@ mod update_project_group_test {
     ) {
         let result = super::update(
                 &pool,
+                CharacterId(1),
                 Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
                 UpdateProjectGroup {
                     name:        String::new(),
@@ -101,6 +124,7 @@ mod update_project_group_test {
     ) {
         let result = super::update(
                 &pool,
+                CharacterId(1),
                 Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
                 UpdateProjectGroup {
                     name:        String::new(),
@@ -123,6 +147,7 @@ mod update_project_group_test {
     ) {
         let result = super::update(
                 &pool,
+                CharacterId(1),
                 Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
                 UpdateProjectGroup {
                     name:        String::from("My shared projects"),
@@ -1,11 +1,15 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_types::CharacterId;
 use starfoundry_lib_types::TypeId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project_group::error::{ProjectGroupError, Result};
 
 pub async fn update_default_blacklist(
     pool:                   &PgPool,
+    character_id:           CharacterId,
     project_group_uuid:     ProjectGroupUuid,
     type_ids:               Vec<TypeId>,
 ) -> Result<()> {
@@ -13,6 +17,13 @@ pub async fn update_default_blacklist(
         .begin()
         .await
         .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectGroupDefaultBlacklist(project_group_uuid),
+        )
+        .await?;
 
     sqlx::query!("
             DELETE FROM project_group_default_blacklist
@@ -41,6 +52,7 @@ pub async fn update_default_blacklist(
         .await
         .map_err(|e| ProjectGroupError::UpdateGroupDefaults(e, project_group_uuid))?;
 
+    audit.finish(&mut transaction).await?;
     transaction
         .commit()
         .await
@@ -50,7 +62,7 @@ pub async fn update_default_blacklist(
 #[cfg(test)]
 mod update_default_blacklist_project_group_test {
     use sqlx::PgPool;
-    use starfoundry_lib_types::TypeId;
+    use starfoundry_lib_types::{CharacterId, TypeId};
     use std::str::FromStr;
     use uuid::Uuid;
 
@@ -65,6 +77,7 @@ mod update_default_blacklist_project_group_test {
     ) {
         let response = super::update_default_blacklist(
                 &pool,
+                CharacterId(1),
                 Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
                 vec![TypeId(1)],
             )
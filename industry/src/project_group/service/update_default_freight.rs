@@ -0,0 +1,101 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_types::CharacterId;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project_group::error::{ProjectGroupError, Result};
+use crate::project_group::service::Freight;
+
+pub async fn update_default_freight(
+    pool:               &PgPool,
+    character_id:       CharacterId,
+    project_group_uuid: ProjectGroupUuid,
+    freight:            Freight,
+) -> Result<()> {
+    if !freight.rate.is_finite() || freight.rate < 0f64 {
+        return Err(ProjectGroupError::ValidationError("Field 'rate' must be positive".into()));
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectGroupDefaultFreight(project_group_uuid),
+        )
+        .await?;
+
+    sqlx::query!("
+            INSERT INTO project_group_default_freight
+            (
+                project_group_id,
+                rate
+            )
+            VALUES ($1, $2)
+            ON CONFLICT (project_group_id)
+            DO UPDATE SET rate = EXCLUDED.rate
+        ",
+            *project_group_uuid,
+            freight.rate,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectGroupError::UpdateGroupDefaults(e, project_group_uuid))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectGroupError::TransactionError)
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+    use starfoundry_lib_types::CharacterId;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::project_group::error::ProjectGroupError;
+    use crate::project_group::service::{fetch_default_freight, Freight};
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let project_group_uuid = Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into();
+
+        let freight = fetch_default_freight(&pool, project_group_uuid).await.unwrap();
+        assert_eq!(freight.rate, 0f64);
+
+        super::update_default_freight(
+                &pool,
+                CharacterId(1),
+                project_group_uuid,
+                Freight { rate: 750f64 },
+            )
+            .await
+            .unwrap();
+        let freight = fetch_default_freight(&pool, project_group_uuid).await.unwrap();
+        assert_eq!(freight.rate, 750f64);
+        assert_eq!(freight.cost(2f64), 1_500f64);
+
+        let result = super::update_default_freight(
+                &pool,
+                CharacterId(1),
+                project_group_uuid,
+                Freight { rate: -1f64 },
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectGroupError::ValidationError(_))));
+    }
+}
@@ -1,13 +1,17 @@
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_types::CharacterId;
 use starfoundry_lib_types::TypeId;
 use utoipa::ToSchema;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project_group::error::{ProjectGroupError, Result};
 
 pub async fn update_default_job_splitting(
     pool:               &PgPool,
+    character_id:       CharacterId,
     project_group_uuid: ProjectGroupUuid,
     job_splitting:      Vec<UpdateProjectGroupDefaultJobSplitting>,
 ) -> Result<()> {
@@ -15,6 +19,13 @@ pub async fn update_default_job_splitting(
         .begin()
         .await
         .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectGroupDefaultJobSplitting(project_group_uuid),
+        )
+        .await?;
 
     sqlx::query!("
             DELETE FROM project_group_default_job_splitting_run
@@ -46,6 +57,7 @@ pub async fn update_default_job_splitting(
         .await
         .map_err(|e| ProjectGroupError::UpdateGroupDefaults(e, project_group_uuid))?;
 
+    audit.finish(&mut transaction).await?;
     transaction
         .commit()
         .await
@@ -67,7 +79,7 @@ pub struct UpdateProjectGroupDefaultJobSplitting {
 #[cfg(test)]
 mod update_default_blueprint_overwrite_project_group_test {
     use sqlx::PgPool;
-    use starfoundry_lib_types::TypeId;
+    use starfoundry_lib_types::{CharacterId, TypeId};
     use std::str::FromStr;
     use uuid::Uuid;
 
@@ -84,6 +96,7 @@ mod update_default_blueprint_overwrite_project_group_test {
     ) {
         let response = super::update_default_job_splitting(
                 &pool,
+                CharacterId(1),
                 Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
                 vec![
                     UpdateProjectGroupDefaultJobSplitting {
@@ -1,10 +1,14 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::{ProjectGroupUuid, StructureUuid};
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project_group::error::{ProjectGroupError, Result};
 
 pub async fn update_default_market(
     pool:               &PgPool,
+    character_id:       CharacterId,
     project_group_uuid: ProjectGroupUuid,
     structures:         Vec<StructureUuid>,
 ) -> Result<()> {
@@ -12,6 +16,13 @@ pub async fn update_default_market(
         .begin()
         .await
         .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectGroupDefaultMarket(project_group_uuid),
+        )
+        .await?;
 
     sqlx::query!("
             DELETE FROM project_group_default_market
@@ -40,6 +51,7 @@ pub async fn update_default_market(
         .await
         .map_err(|e| ProjectGroupError::UpdateGroupDefaults(e, project_group_uuid))?;
 
+    audit.finish(&mut transaction).await?;
     transaction
         .commit()
         .await
@@ -51,6 +63,7 @@ pub async fn update_default_market(
 mod update_default_blueprint_overwrite_project_group_test {
     use sqlx::PgPool;
     use starfoundry_lib_industry::StructureUuid;
+    use starfoundry_lib_types::CharacterId;
     use std::str::FromStr;
     use uuid::Uuid;
 
@@ -65,6 +78,7 @@ mod update_default_blueprint_overwrite_project_group_test {
     ) {
         let response = super::update_default_market(
                 &pool,
+                CharacterId(1),
                 Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
                 vec![StructureUuid::from(Uuid::now_v7())],
             )
@@ -0,0 +1,157 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::project_group::{ProjectGroupGrant, ProjectGroupGrantEntity};
+use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_types::CharacterId;
+use std::collections::HashSet;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project_group::error::{ProjectGroupError, Result};
+
+/// Replaces all grants of the group
+pub async fn update_grants(
+    pool:               &PgPool,
+    character_id:       CharacterId,
+    project_group_uuid: ProjectGroupUuid,
+    grants:             Vec<ProjectGroupGrant>,
+) -> Result<()> {
+    let unique_entities = grants
+        .iter()
+        .map(|x| (x.entity_type, x.entity_id))
+        .collect::<HashSet<_>>();
+    if unique_entities.len() != grants.len() {
+        return Err(ProjectGroupError::ValidationError("Every corporation and alliance can only be granted a single role".into()));
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectGroupGrant(project_group_uuid),
+        )
+        .await?;
+
+    sqlx::query!("
+            DELETE FROM project_group_grant
+            WHERE project_group_id = $1
+        ",
+            *project_group_uuid,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectGroupError::UpdateGrants(e, project_group_uuid))?;
+
+    let role_ids = grants
+        .iter()
+        .map(|x| *x.role_id)
+        .collect::<Vec<_>>();
+
+    // only roles of the same group can be granted
+    let inserted = sqlx::query!("
+            INSERT INTO project_group_grant
+            (
+                project_group_id,
+                entity_type,
+                entity_id,
+                role_id
+            )
+            SELECT $1, grants.entity_type, grants.entity_id, grants.role_id
+            FROM UNNEST(
+                $2::PROJECT_GROUP_GRANT_ENTITY[],
+                $3::INTEGER[],
+                $4::UUID[]
+            ) AS grants(entity_type, entity_id, role_id)
+            JOIN project_group_role pgr ON pgr.id = grants.role_id
+            WHERE pgr.project_group_id = $1
+        ",
+            *project_group_uuid,
+            &grants.iter().map(|x| x.entity_type).collect::<Vec<_>>() as &[ProjectGroupGrantEntity],
+            &grants.iter().map(|x| x.entity_id).collect::<Vec<_>>(),
+            &role_ids,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectGroupError::UpdateGrants(e, project_group_uuid))?;
+
+    if (inserted.rows_affected() as usize) < grants.len() {
+        return Err(ProjectGroupError::ValidationError("At least one role does not belong to the group".into()));
+    }
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectGroupError::TransactionError)
+}
+
+#[cfg(test)]
+mod update_grants_test {
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::project_group::{ProjectGroupGrant, ProjectGroupGrantEntity};
+    use starfoundry_lib_types::CharacterId;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::project_group::error::ProjectGroupError;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let project_group_uuid = Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap();
+
+        let result = super::update_grants(
+                &pool,
+                CharacterId(1),
+                project_group_uuid.into(),
+                vec![
+                    ProjectGroupGrant {
+                        entity_type: ProjectGroupGrantEntity::Corporation,
+                        entity_id:   1000,
+                        role_id:     Uuid::from_str("00000000-0000-0000-0000-000000000002").unwrap().into(),
+                    },
+                ],
+            )
+            .await;
+        assert!(result.is_ok());
+
+        let grants = crate::project_group::service::list_grants(&pool, project_group_uuid.into())
+            .await
+            .unwrap();
+        assert_eq!(grants.len(), 1);
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn role_of_other_group(
+        pool: PgPool,
+    ) {
+        let result = super::update_grants(
+                &pool,
+                CharacterId(1),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                vec![
+                    ProjectGroupGrant {
+                        entity_type: ProjectGroupGrantEntity::Alliance,
+                        entity_id:   2000,
+                        role_id:     Uuid::from_str("00000000-0000-0000-0000-000000000004").unwrap().into(),
+                    },
+                ],
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectGroupError::ValidationError(_))));
+    }
+}
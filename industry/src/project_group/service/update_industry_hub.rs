@@ -1,10 +1,14 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::{IndustryHubUuid, ProjectGroupUuid};
+use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project_group::error::{ProjectGroupError, Result};
 
 pub async fn update_industry_hubs(
     pool:               &PgPool,
+    character_id:       CharacterId,
     project_group_uuid: ProjectGroupUuid,
     update_info:        Vec<IndustryHubUuid>,
 ) -> Result<()> {
@@ -12,6 +16,13 @@ pub async fn update_industry_hubs(
         .begin()
         .await
         .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectGroupIndustryHub(project_group_uuid),
+        )
+        .await?;
 
     sqlx::query!("
             DELETE FROM project_group_industry_hub
@@ -40,6 +51,7 @@ pub async fn update_industry_hubs(
         .await
         .map_err(|e| ProjectGroupError::UpdateIndustryHubs(e, project_group_uuid))?;
 
+    audit.finish(&mut transaction).await?;
     transaction
         .commit()
         .await
@@ -49,6 +61,7 @@ pub async fn update_industry_hubs(
 #[cfg(test)]
 mod update_default_blueprint_overwrite_project_group_test {
     use sqlx::PgPool;
+    use starfoundry_lib_types::CharacterId;
     use std::str::FromStr;
     use uuid::Uuid;
 
@@ -63,6 +76,7 @@ mod update_default_blueprint_overwrite_project_group_test {
     ) {
         let response = super::update_industry_hubs(
                 &pool,
+                CharacterId(1),
                 Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
                 vec![
                 ],
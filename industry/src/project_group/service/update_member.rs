@@ -1,53 +1,108 @@
 use serde::Deserialize;
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
-use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_industry::{ProjectGroupRoleUuid, ProjectGroupUuid};
 use starfoundry_lib_types::CharacterId;
+use std::collections::HashSet;
 use utoipa::ToSchema;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::project_group::error::{ProjectGroupError, Result};
 
+/// Replaces all members of the group, the owner always stays a member
 pub async fn update_member(
     pool:               &PgPool,
+    character_id:       CharacterId,
     project_group_uuid: ProjectGroupUuid,
     update_info:        Vec<UpdateMemberRequest>,
 ) -> Result<()> {
+    let role_ids = update_info
+        .iter()
+        .filter_map(|x| x.role_id)
+        .map(|x| *x)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    // only roles of the same group can be assigned
+    let valid_roles = sqlx::query!(r#"
+            SELECT COUNT(*) AS "count!"
+            FROM project_group_role
+            WHERE project_group_id = $1
+            AND id = ANY($2)
+        "#,
+            *project_group_uuid,
+            &role_ids,
+        )
+        .fetch_one(pool)
+        .await
+        .map_err(|e| ProjectGroupError::ListRoles(e, project_group_uuid))?;
+
+    if valid_roles.count as usize != role_ids.len() {
+        return Err(ProjectGroupError::ValidationError("At least one role does not belong to the group".into()));
+    }
+
     let mut transaction = pool
         .begin()
         .await
         .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectGroupMember(project_group_uuid),
+        )
+        .await?;
 
     sqlx::query!("
             DELETE FROM project_group_member
             WHERE project_group_id = $1
+            AND character_id != (
+                SELECT owner
+                FROM project_group
+                WHERE id = $1
+            )
         ",
             *project_group_uuid,
         )
         .execute(&mut *transaction)
         .await
-        .map_err(|e| ProjectGroupError::DeleteIndustryHubs(e, project_group_uuid))?;
+        .map_err(|e| ProjectGroupError::UpdateMembers(e, project_group_uuid))?;
 
+    // members without a role can only read the group
     sqlx::query!("
             INSERT INTO project_group_member
             (
                 project_group_id,
+                character_id,
                 permission,
-                character_id
-            )
-            SELECT $1, $2, * FROM UNNEST(
-                $3::INTEGER[]
+                role_id
             )
+            SELECT
+                $1,
+                members.character_id,
+                CASE
+                    WHEN members.role_id IS NULL THEN $2
+                    ELSE 0
+                END,
+                members.role_id
+            FROM UNNEST(
+                $3::INTEGER[],
+                $4::UUID[]
+            ) AS members(character_id, role_id)
             ON CONFLICT (project_group_id, character_id) DO NOTHING
         ",
             *project_group_uuid,
-            // TODO: proper permission
-            *ProjectGroupPermissionCode::Owner,
-            &update_info.into_iter().map(|x| *x.character_id).collect::<Vec<_>>(),
+            *ProjectGroupPermissionCode::Read,
+            &update_info.iter().map(|x| *x.character_id).collect::<Vec<_>>(),
+            &update_info.iter().map(|x| x.role_id.map(|x| *x)).collect::<Vec<_>>() as &[Option<uuid::Uuid>],
         )
         .execute(&mut *transaction)
         .await
         .map_err(|e| ProjectGroupError::UpdateMembers(e, project_group_uuid))?;
 
+    audit.finish(&mut transaction).await?;
     transaction
         .commit()
         .await
@@ -55,6 +110,112 @@ pub async fn update_member(
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
+#[schema(
+    example = json!({
+        "character_id": 2117441999,
+        "role_id": "019a0a3b-6a3c-7e43-9b7c-3bd1e0d1c6a1"
+    })
+)]
 pub struct UpdateMemberRequest {
     character_id: CharacterId,
+    /// Role of the member, without a role the member can only read the group
+    #[serde(default)]
+    role_id:      Option<ProjectGroupRoleUuid>,
+}
+
+#[cfg(test)]
+mod update_member_test {
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::project_group::error::ProjectGroupError;
+    use crate::project_group::permission::{fetch_permission, MemberIdentity};
+    use crate::project_group::service::UpdateMemberRequest;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let project_group_uuid = Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap();
+
+        let result = super::update_member(
+                &pool,
+                CharacterId(1),
+                project_group_uuid.into(),
+                vec![
+                    UpdateMemberRequest {
+                        character_id: CharacterId(2),
+                        role_id:      Some(Uuid::from_str("00000000-0000-0000-0000-000000000002").unwrap().into()),
+                    },
+                    UpdateMemberRequest {
+                        character_id: CharacterId(3),
+                        role_id:      None,
+                    },
+                ],
+            )
+            .await;
+        assert!(result.is_ok());
+
+        // the owner is kept
+        let owner = fetch_permission(
+                &pool,
+                project_group_uuid.into(),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+            )
+            .await
+            .unwrap();
+        assert!(owner.has(ProjectGroupPermissionCode::Owner));
+
+        let builder = fetch_permission(
+                &pool,
+                project_group_uuid.into(),
+                MemberIdentity::new(CharacterId(2), CorporationId(1), None),
+            )
+            .await
+            .unwrap();
+        assert!(builder.has(ProjectGroupPermissionCode::WriteProject));
+        assert!(!builder.has(ProjectGroupPermissionCode::Owner));
+
+        let reader = fetch_permission(
+                &pool,
+                project_group_uuid.into(),
+                MemberIdentity::new(CharacterId(3), CorporationId(1), None),
+            )
+            .await
+            .unwrap();
+        assert!(reader.has(ProjectGroupPermissionCode::Read));
+        assert!(!reader.has(ProjectGroupPermissionCode::WriteProject));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn role_of_other_group(
+        pool: PgPool,
+    ) {
+        let result = super::update_member(
+                &pool,
+                CharacterId(1),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                vec![
+                    UpdateMemberRequest {
+                        character_id: CharacterId(2),
+                        role_id:      Some(Uuid::from_str("00000000-0000-0000-0000-000000000005").unwrap().into()),
+                    },
+                ],
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectGroupError::ValidationError(_))));
+    }
 }
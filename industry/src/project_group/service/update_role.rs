@@ -0,0 +1,65 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::{ProjectGroupRoleUuid, ProjectGroupUuid};
+use starfoundry_lib_types::CharacterId;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project_group::error::{ProjectGroupError, Result};
+use crate::project_group::service::{is_unique_violation, ProjectGroupRoleRequest};
+
+/// Updates the role, all members and grants with the role are affected
+/// immediately
+pub async fn update_role(
+    pool:               &PgPool,
+    character_id:       CharacterId,
+    project_group_uuid: ProjectGroupUuid,
+    role_uuid:          ProjectGroupRoleUuid,
+    role:               ProjectGroupRoleRequest,
+) -> Result<()> {
+    role.validate()?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectGroupError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectGroupRole(project_group_uuid),
+        )
+        .await?;
+
+    let result = sqlx::query!("
+            UPDATE project_group_role
+            SET
+                name       = $3,
+                permission = $4
+            WHERE project_group_id = $1
+            AND id = $2
+        ",
+            *project_group_uuid,
+            *role_uuid,
+            role.name.trim(),
+            *role.permissions,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| {
+            if is_unique_violation(&e) {
+                ProjectGroupError::ValidationError("A role with the same name already exists".into())
+            } else {
+                ProjectGroupError::UpdateRole(e, role_uuid)
+            }
+        })?;
+
+    if result.rows_affected() == 0 {
+        return Err(ProjectGroupError::RoleNotFound(role_uuid));
+    }
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectGroupError::TransactionError)
+}
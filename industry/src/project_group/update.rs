@@ -1,3 +1,4 @@
+use starfoundry_lib_gateway::ExtractIdentity;
 
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
@@ -48,12 +49,14 @@ use crate::project_group::service::{UpdateProjectGroup, update};
     ),
 )]
 pub async fn api(
+    identity:                 ExtractIdentity,
     State(state):             State<AppState>,
     Path(project_group_uuid): Path<ProjectGroupUuid>,
     Json(update_info):        Json<UpdateProjectGroup>,
 ) -> Result<impl IntoResponse> {
     update(
         &state.postgres,
+        identity.character_id,
         project_group_uuid,
         update_info,
     ).await?;
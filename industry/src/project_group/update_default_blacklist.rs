@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::ProjectGroupUuid;
 use starfoundry_lib_types::TypeId;
 
@@ -48,12 +49,14 @@ use crate::project_group::service::update_default_blacklist;
     ),
 )]
 pub async fn api(
+    identity:                 ExtractIdentity,
     State(state):             State<AppState>,
     Path(project_group_uuid): Path<ProjectGroupUuid>,
     Json(update_info):        Json<Vec<TypeId>>,
 ) -> Result<impl IntoResponse> {
     update_default_blacklist(
         &state.postgres,
+        identity.character_id,
         project_group_uuid,
         update_info,
     ).await?;
@@ -0,0 +1,66 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectGroupUuid;
+
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
+use crate::AppState;
+use crate::project_group::error::Result;
+use crate::project_group::service::{Freight, update_default_freight};
+
+/// Update Freight
+/// 
+/// - Alternative route: `/v1/project-groups/{ProjectGroupUuid}/defaults/freight`
+/// - Alternative route: `/latest/project-groups/{ProjectGroupUuid}/defaults/freight`
+/// 
+/// ---
+/// 
+/// Updates the freight rate in ISK per m3
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write
+/// 
+#[utoipa::path(
+    put,
+    path = "/{ProjectGroupUuid}/defaults/freight",
+    tag = "Project Groups",
+    request_body = Freight,
+    params(
+        ProjectGroupUuid,
+    ),
+    responses(
+        (
+            description = "The freight rate was updated",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnsupportedMediaType,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:                 ExtractIdentity,
+    State(state):             State<AppState>,
+    Path(project_group_uuid): Path<ProjectGroupUuid>,
+    Json(freight):            Json<Freight>,
+) -> Result<impl IntoResponse> {
+    update_default_freight(
+        &state.postgres,
+        identity.character_id,
+        project_group_uuid,
+        freight,
+    ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::ProjectGroupUuid;
 
 use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
@@ -47,12 +48,14 @@ use crate::project_group::service::{UpdateProjectGroupDefaultJobSplitting, updat
     ),
 )]
 pub async fn api(
+    identity:                 ExtractIdentity,
     State(state):             State<AppState>,
     Path(project_group_uuid): Path<ProjectGroupUuid>,
     Json(update_info):        Json<Vec<UpdateProjectGroupDefaultJobSplitting>>,
 ) -> Result<impl IntoResponse> {
     update_default_job_splitting(
         &state.postgres,
+        identity.character_id,
         project_group_uuid,
         update_info,
     ).await?;
@@ -0,0 +1,130 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::project_group::ProjectGroupGrant;
+use starfoundry_lib_industry::ProjectGroupUuid;
+
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
+use crate::AppState;
+use crate::project_group::error::Result;
+use crate::project_group::service::update_grants;
+
+/// Update Grants
+/// 
+/// - Alternative route: `/latest/project-groups/{ProjectGroupUuid}/grants`
+/// - Alternative route: `/v1/project-groups/{ProjectGroupUuid}/grants`
+/// 
+/// ---
+/// 
+/// Replaces all grants of the group.
+/// Every character of a granted corporation or alliance gets the permissions
+/// of the granted role, without being added as a member.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_member
+/// 
+#[utoipa::path(
+    put,
+    path = "/{ProjectGroupUuid}/grants",
+    tag = "Project Groups",
+    request_body = Vec<ProjectGroupGrant>,
+    params(
+        ProjectGroupUuid,
+    ),
+    responses(
+        (
+            description = "The grants were updated",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnsupportedMediaType,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:                 ExtractIdentity,
+    State(state):             State<AppState>,
+    Path(project_group_uuid): Path<ProjectGroupUuid>,
+    Json(grants):             Json<Vec<ProjectGroupGrant>>,
+) -> Result<impl IntoResponse> {
+    update_grants(
+        &state.postgres,
+        identity.character_id,
+        project_group_uuid,
+        grants,
+    ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::header::{CONTENT_TYPE, HOST};
+    use axum::http::StatusCode;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE};
+
+    use crate::project_group::project_group_test_routes;
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000001/grants")
+            .header(CONTENT_TYPE, "application/json")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .method("PUT")
+            .body(Body::new(
+                serde_json::json!([{
+                    "entity_type": "CORPORATION",
+                    "entity_id": 1000,
+                    "role_id": "00000000-0000-0000-0000-000000000001",
+                }]).to_string()
+            ))
+            .unwrap();
+        let response = project_group_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn forbidden(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000005/grants")
+            .header(CONTENT_TYPE, "application/json")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 3)
+            .header(HEADER_CORPORATION_ID, 1000)
+            .header(HOST, "test.starfoundry.space")
+            .method("PUT")
+            .body(Body::new(
+                serde_json::json!([]).to_string()
+            ))
+            .unwrap();
+        let response = project_group_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::ProjectGroupUuid;
 
 use crate::api_docs::{Forbidden, InternalServerError, Unauthorized};
@@ -16,11 +17,11 @@ use crate::project_group::service::{UpdateMemberRequest, update_member};
 /// 
 /// ---
 /// 
-/// Updates a default market
+/// Replaces all members of the group, except the owner
 /// 
 /// ## Security
 /// - authenticated
-/// - project_group:write
+/// - project_group:write_member
 /// 
 #[utoipa::path(
     put,
@@ -41,12 +42,14 @@ use crate::project_group::service::{UpdateMemberRequest, update_member};
     ),
 )]
 pub async fn api(
+    identity:                 ExtractIdentity,
     State(state):             State<AppState>,
     Path(project_group_uuid): Path<ProjectGroupUuid>,
     Json(update_info):        Json<Vec<UpdateMemberRequest>>,
 ) -> Result<impl IntoResponse> {
     update_member(
         &state.postgres,
+        identity.character_id,
         project_group_uuid,
         update_info,
     ).await?;
@@ -0,0 +1,167 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use starfoundry_lib_industry::{ProjectGroupRoleUuid, ProjectGroupUuid};
+
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
+use crate::AppState;
+use crate::project_group::error::Result;
+use crate::project_group::permission::assert_write_access_check;
+use crate::project_group::service::{update_role, ProjectGroupRoleRequest};
+
+/// Update Role
+/// 
+/// - Alternative route: `/latest/project-groups/{ProjectGroupUuid}/roles/{ProjectGroupRoleUuid}`
+/// - Alternative route: `/v1/project-groups/{ProjectGroupUuid}/roles/{ProjectGroupRoleUuid}`
+/// 
+/// ---
+/// 
+/// Updates the name and permissions of a role.
+/// All members, corporations and alliances with the role are affected.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_member
+/// 
+#[utoipa::path(
+    put,
+    path = "/{ProjectGroupUuid}/roles/{ProjectGroupRoleUuid}",
+    tag = "Project Groups",
+    request_body = ProjectGroupRoleRequest,
+    params(
+        ProjectGroupUuid,
+        ProjectGroupRoleUuid,
+    ),
+    responses(
+        (
+            description = "The role was updated",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnsupportedMediaType,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+    Path((project_group_uuid, role_uuid)): Path<(ProjectGroupUuid, ProjectGroupRoleUuid)>,
+    Json(role):   Json<ProjectGroupRoleRequest>,
+) -> Result<impl IntoResponse> {
+    assert_write_access_check(
+            &state.postgres,
+            project_group_uuid,
+            (&identity).into(),
+            ProjectGroupPermissionCode::WriteMember,
+        )
+        .await?;
+
+    update_role(
+        &state.postgres,
+        identity.character_id,
+        project_group_uuid,
+        role_uuid,
+        role,
+    ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::header::{CONTENT_TYPE, HOST};
+    use axum::http::StatusCode;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE};
+
+    use crate::project_group::project_group_test_routes;
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000001/roles/00000000-0000-0000-0000-000000000001")
+            .header(CONTENT_TYPE, "application/json")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .method("PUT")
+            .body(Body::new(
+                serde_json::json!({
+                    "name": "Reader",
+                    "permissions": ["READ"],
+                }).to_string()
+            ))
+            .unwrap();
+        let response = project_group_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn forbidden(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000001/roles/00000000-0000-0000-0000-000000000001")
+            .header(CONTENT_TYPE, "application/json")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 2)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .method("PUT")
+            .body(Body::new(
+                serde_json::json!({
+                    "name": "Reader",
+                    "permissions": ["READ"],
+                }).to_string()
+            ))
+            .unwrap();
+        let response = project_group_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn not_found(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000001/roles/00000000-0000-0000-0000-000000000099")
+            .header(CONTENT_TYPE, "application/json")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .method("PUT")
+            .body(Body::new(
+                serde_json::json!({
+                    "name": "Reader",
+                    "permissions": ["READ"],
+                }).to_string()
+            ))
+            .unwrap();
+        let response = project_group_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
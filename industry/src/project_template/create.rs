@@ -0,0 +1,82 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use serde::Serialize;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectTemplateUuid;
+use starfoundry_lib_industry::project_template::CreateProjectTemplate;
+use utoipa::ToSchema;
+
+use crate::AppState;
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, Unauthorized, UnprocessableEntity};
+use crate::project_template::error::Result;
+use crate::project_template::service::create_template;
+
+/// Create Project Template
+/// 
+/// - Alternative route: `/latest/project-templates`
+/// - Alternative route: `/v1/project-templates`
+/// 
+/// ---
+/// 
+/// Creates a new template for a project group.
+/// If `interval_days` is set, a new project is created from the template
+/// every given days in the name of the character.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_project
+/// 
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "Project Templates",
+    request_body = CreateProjectTemplate,
+    responses(
+        (
+            body = CreateProjectTemplateResponse,
+            description = "Id of the new template",
+            status = CREATED,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:       ExtractIdentity,
+    State(state):   State<AppState>,
+    Json(template): Json<CreateProjectTemplate>,
+) -> Result<impl IntoResponse> {
+    let id = create_template(
+            &state.postgres,
+            (&identity).into(),
+            identity.host()?,
+            template,
+        ).await?;
+
+    Ok(
+        (
+            StatusCode::CREATED,
+            Json(CreateProjectTemplateResponse {
+                id,
+            })
+        )
+    )
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "id": "fd324c9f-ecda-49c8-948e-18f4b4b23bff"
+    })
+)]
+pub struct CreateProjectTemplateResponse {
+    id: ProjectTemplateUuid,
+}
@@ -0,0 +1,60 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectTemplateUuid;
+
+use crate::AppState;
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project_template::error::Result;
+use crate::project_template::service::delete_template;
+
+/// Delete Project Template
+/// 
+/// - Alternative route: `/latest/project-templates/{ProjectTemplateUuid}`
+/// - Alternative route: `/v1/project-templates/{ProjectTemplateUuid}`
+/// 
+/// ---
+/// 
+/// Deletes the template, projects created from it are kept
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_project
+/// 
+#[utoipa::path(
+    delete,
+    path = "/{ProjectTemplateUuid}",
+    tag = "Project Templates",
+    params(
+        ProjectTemplateUuid,
+    ),
+    responses(
+        (
+            description = "The template was deleted",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:          ExtractIdentity,
+    State(state):      State<AppState>,
+    Path(template_id): Path<ProjectTemplateUuid>,
+) -> Result<impl IntoResponse> {
+    delete_template(
+            &state.postgres,
+            (&identity).into(),
+            template_id,
+        ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
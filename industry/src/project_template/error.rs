@@ -0,0 +1,108 @@
+use axum::extract::rejection::JsonRejection;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use starfoundry_lib_gateway::{ErrorResponse, boxed_from};
+use starfoundry_lib_industry::ProjectTemplateUuid;
+use thiserror::Error;
+
+use crate::api_docs::format_json_errors;
+use crate::project::error::ProjectError;
+use crate::project_group::ProjectGroupError;
+
+pub type Result<T, E = ProjectTemplateError> = std::result::Result<T, E>;
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ProjectTemplateError {
+    #[error("project template with id '{0}' not found")]
+    NotFound(ProjectTemplateUuid),
+
+    #[error("error while listing project templates, error: '{0}'")]
+    List(sqlx::Error),
+    #[error("error while fetching project template '{1}', error: '{0}'")]
+    Fetch(sqlx::Error, ProjectTemplateUuid),
+    #[error("error while creating project template, error: '{0}'")]
+    Create(sqlx::Error),
+    #[error("error while updating project template '{1}', error: '{0}'")]
+    Update(sqlx::Error, ProjectTemplateUuid),
+    #[error("error while deleting project template '{1}', error: '{0}'")]
+    Delete(sqlx::Error, ProjectTemplateUuid),
+    #[error("error while creating a project from template '{1}', error: '{0}'")]
+    Instantiate(sqlx::Error, ProjectTemplateUuid),
+
+    #[error("transaction error, '{0}'")]
+    TransactionError(sqlx::Error),
+
+    #[error(transparent)]
+    AuditError(Box<crate::audit::AuditError>),
+    #[error(transparent)]
+    JsonExtractorRejection(#[from] JsonRejection),
+    #[error(transparent)]
+    GatewayLibError(#[from] starfoundry_lib_gateway::Error),
+    #[error(transparent)]
+    EveGatewayLibError(#[from] starfoundry_lib_eve_gateway::Error),
+    #[error(transparent)]
+    IndustryLibError(#[from] starfoundry_lib_industry::Error),
+    #[error(transparent)]
+    ProjectGroupError(Box<ProjectGroupError>),
+    #[error(transparent)]
+    ProjectError(Box<ProjectError>),
+}
+
+impl IntoResponse for ProjectTemplateError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(_) => {
+                tracing::info!("{}", self.to_string());
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(
+                        ErrorResponse {
+                            error: "NOT_FOUND".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+
+            Self::JsonExtractorRejection(x) => {
+                format_json_errors(x).into_response()
+            },
+
+            Self::GatewayLibError(e) => {
+                starfoundry_lib_gateway::Error::into_response(e)
+            },
+
+            Self::IndustryLibError(e) => {
+                e.into_response()
+            },
+
+            Self::ProjectGroupError(e) => {
+                ProjectGroupError::into_response(*e)
+            },
+
+            Self::ProjectError(e) => {
+                ProjectError::into_response(*e)
+            },
+
+            _ => {
+                tracing::error!("{}", self.to_string());
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(
+                        ErrorResponse {
+                            error: "UNKNOWN".into(),
+                            description: "An unknown error occurred, please try again later.".into(),
+                        }
+                    )
+                ).into_response()
+            },
+        }
+        .into_response()
+    }
+}
+
+boxed_from!(ProjectTemplateError::AuditError, crate::audit::AuditError);
+boxed_from!(ProjectTemplateError::ProjectGroupError, ProjectGroupError);
+boxed_from!(ProjectTemplateError::ProjectError, ProjectError);
@@ -0,0 +1,67 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectTemplateUuid;
+use starfoundry_lib_industry::project_template::ProjectTemplate;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project_template::error::Result;
+use crate::project_template::service::fetch_template;
+
+/// Fetch Project Template
+/// 
+/// - Alternative route: `/latest/project-templates/{ProjectTemplateUuid}`
+/// - Alternative route: `/v1/project-templates/{ProjectTemplateUuid}`
+/// 
+/// ---
+/// 
+/// Fetches a single project template
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{ProjectTemplateUuid}",
+    tag = "Project Templates",
+    params(
+        ProjectTemplateUuid,
+    ),
+    responses(
+        (
+            body = ProjectTemplate,
+            description = "The requested template",
+            status = OK,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:          ExtractIdentity,
+    State(state):      State<AppState>,
+    Path(template_id): Path<ProjectTemplateUuid>,
+) -> Result<impl IntoResponse> {
+    let entry = fetch_template(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            (&identity).into(),
+            template_id,
+        ).await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(entry),
+        )
+    )
+}
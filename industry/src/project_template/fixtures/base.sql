@@ -0,0 +1,16 @@
+INSERT INTO project_group (id, owner, name, description) VALUES
+('00000000-0000-0000-0000-000000000001', 1, 'First', 'Description');
+
+INSERT INTO project_group_member (project_group_id, character_id, permission) VALUES
+('00000000-0000-0000-0000-000000000001', 1, 1),
+('00000000-0000-0000-0000-000000000001', 2, 2);
+
+INSERT INTO tag (id, owner_id, content, color, typ) VALUES
+('00000000-0000-0000-0000-000000001001', 1, 'Fuel', '#ffffff', 'MANUAL'),
+('00000000-0000-0000-0000-000000001002', 2, 'Recurring', '#ffffff', 'MANUAL');
+
+INSERT INTO project_template (id, project_group_id, character_id, corporation_id, domain, name, orderer, tags, interval_days, next_run_at) VALUES
+('00000000-0000-0000-0000-000000000101', '00000000-0000-0000-0000-000000000001', 1, 1, 'test.starfoundry.space', 'Fuel Blocks', 'Alliance', '{00000000-0000-0000-0000-000000001001,00000000-0000-0000-0000-000000001002}', 7, NOW() - INTERVAL '1 hour');
+
+INSERT INTO project_template_product (template_id, type_id, quantity) VALUES
+('00000000-0000-0000-0000-000000000101', 4051, 1000);
@@ -0,0 +1,83 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectTemplateUuid;
+use starfoundry_lib_industry::project::CreateProjectResponse;
+use starfoundry_lib_industry::project_template::InstantiateProjectTemplate;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity};
+use crate::project_template::error::Result;
+use crate::project_template::service::instantiate_template;
+use crate::tag::service::apply_auto_tags;
+
+/// Instantiate Project Template
+/// 
+/// - Alternative route: `/latest/project-templates/{ProjectTemplateUuid}/instantiate`
+/// - Alternative route: `/v1/project-templates/{ProjectTemplateUuid}/instantiate`
+/// 
+/// ---
+/// 
+/// Creates a new project from the template, the quantities of all products
+/// are multiplied by the `multiplier`
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_project
+/// 
+#[utoipa::path(
+    post,
+    path = "/{ProjectTemplateUuid}/instantiate",
+    tag = "Project Templates",
+    params(
+        ProjectTemplateUuid,
+    ),
+    request_body = InstantiateProjectTemplate,
+    responses(
+        (
+            body = CreateProjectResponse,
+            description = "Id of the new project",
+            status = CREATED,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:          ExtractIdentity,
+    State(state):      State<AppState>,
+    Path(template_id): Path<ProjectTemplateUuid>,
+    Json(instantiate): Json<InstantiateProjectTemplate>,
+) -> Result<impl IntoResponse> {
+    let id = instantiate_template(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            (&identity).into(),
+            template_id,
+            instantiate,
+        ).await?;
+
+    // the project is already stored, so a failure only leaves the automatic
+    // tags outdated until the next change
+    if let Err(e) = apply_auto_tags(&state.postgres, &eve_gateway_api_client()?, id).await {
+        tracing::error!("error while applying automatic tags to project '{}', error: '{}'", id, e);
+    }
+
+    Ok(
+        (
+            StatusCode::CREATED,
+            Json(CreateProjectResponse {
+                id,
+            })
+        )
+    )
+}
@@ -0,0 +1,78 @@
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::project_template::{ProjectTemplate, ProjectTemplateFilter};
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{BadRequest, InternalServerError, Unauthorized};
+use crate::project_template::error::Result;
+use crate::project_template::service::list_templates;
+
+/// List Project Templates
+/// 
+/// - Alternative route: `/latest/project-templates`
+/// - Alternative route: `/v1/project-templates`
+/// 
+/// ---
+/// 
+/// Lists the templates of all project groups the character is a member of
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "Project Templates",
+    params(ProjectTemplateFilter),
+    responses(
+        (
+            body = Vec<ProjectTemplate>,
+            description = "All templates that match the filter",
+            status = OK,
+        ),
+        (
+            description = "There aren't any templates matching the filter",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:      ExtractIdentity,
+    State(state):  State<AppState>,
+    Query(filter): Query<ProjectTemplateFilter>,
+) -> Result<impl IntoResponse> {
+    let data = list_templates(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            (&identity).into(),
+            filter,
+        ).await?;
+
+    if data.is_empty() {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(data),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(data),
+            )
+            .into_response()
+        )
+    }
+}
@@ -0,0 +1,13 @@
+mod create_template;
+mod delete_template;
+mod fetch_template;
+mod instantiate_template;
+mod list_templates;
+mod update_template;
+
+pub use self::create_template::*;
+pub use self::delete_template::*;
+pub use self::fetch_template::*;
+pub use self::instantiate_template::*;
+pub use self::list_templates::*;
+pub use self::update_template::*;
@@ -0,0 +1,181 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::ProjectTemplateUuid;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use starfoundry_lib_industry::project_template::CreateProjectTemplate;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project_group::permission::{assert_write_access_check, MemberIdentity};
+use crate::project_template::error::{ProjectTemplateError, Result};
+use crate::project_template::service::insert_products;
+
+/// Creates a new template for the project group.
+///
+/// If the template is scheduled, the first project is created after the
+/// given interval.
+///
+pub async fn create_template(
+    pool:     &PgPool,
+    identity: MemberIdentity,
+    domain:   String,
+    template: CreateProjectTemplate,
+) -> Result<ProjectTemplateUuid> {
+    template.template.validate()?;
+
+    assert_write_access_check(
+            pool,
+            template.project_group_id,
+            identity,
+            ProjectGroupPermissionCode::WriteProject,
+        )
+        .await?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectTemplateError::TransactionError)?;
+
+    let template_id: ProjectTemplateUuid = sqlx::query!("
+            INSERT INTO project_template
+            (
+                project_group_id,
+                character_id,
+                corporation_id,
+                domain,
+                name,
+                orderer,
+                note,
+                tags,
+                interval_days,
+                next_run_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, NOW() + make_interval(days => $9))
+            RETURNING id
+        ",
+            *template.project_group_id,
+            *identity.character_id,
+            *identity.corporation_id,
+            domain,
+            template.template.name,
+            template.template.orderer,
+            template.template.notes,
+            &template.template.tags.iter().map(|x| **x).collect::<Vec<_>>(),
+            template.template.interval_days,
+        )
+        .fetch_one(&mut *transaction)
+        .await
+        .map(|x| x.id.into())
+        .map_err(ProjectTemplateError::Create)?;
+
+    insert_products(
+            &mut transaction,
+            template_id,
+            &template.template.products,
+        )
+        .await?;
+
+    AuditRecord::created(
+            &mut transaction,
+            identity.character_id,
+            AuditTarget::ProjectGroupTemplate(template.project_group_id, template_id),
+        )
+        .await?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectTemplateError::TransactionError)?;
+
+    Ok(template_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::project_template::CreateProjectTemplate;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+
+    use crate::project_group::permission::MemberIdentity;
+    use crate::project_template::error::ProjectTemplateError;
+    use crate::project_template::service::create_template;
+
+    fn template(
+        quantity: i32,
+    ) -> CreateProjectTemplate {
+        serde_json::from_value(serde_json::json!({
+                "project_group_id": "00000000-0000-0000-0000-000000000001",
+                "name": "Fuel Blocks",
+                "orderer": "Alliance",
+                "products": [{
+                    "type_id": 4051,
+                    "quantity": quantity
+                }],
+                "interval_days": 7
+            }))
+            .unwrap()
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn create_requires_write_access(
+        pool: PgPool,
+    ) {
+        let result = create_template(
+                &pool,
+                MemberIdentity::new(CharacterId(2), CorporationId(2), None),
+                "test.starfoundry.space".into(),
+                template(1000),
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectTemplateError::ProjectGroupError(_))));
+
+        let result = create_template(
+                &pool,
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                "test.starfoundry.space".into(),
+                template(0),
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectTemplateError::IndustryLibError(_))));
+
+        let id = create_template(
+                &pool,
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                "test.starfoundry.space".into(),
+                template(1000),
+            )
+            .await
+            .unwrap();
+
+        let entry = sqlx::query!(r#"
+                SELECT
+                    character_id,
+                    next_run_at > NOW() + INTERVAL '6 days' AS "scheduled!",
+                    (SELECT quantity FROM project_template_product WHERE template_id = $1) AS "quantity!"
+                FROM project_template
+                WHERE id = $1
+            "#,
+                *id,
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(entry.character_id, 1);
+        assert!(entry.scheduled);
+        assert_eq!(entry.quantity, 1000);
+
+        let audit = sqlx::query!(r#"
+                SELECT after
+                FROM audit_event
+                WHERE scope = 'PROJECT_GROUP_TEMPLATE'
+                AND action = 'CREATE'
+            "#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(audit.after.get("name"), Some(&serde_json::json!("Fuel Blocks")));
+    }
+}
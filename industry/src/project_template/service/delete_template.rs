@@ -0,0 +1,97 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::ProjectTemplateUuid;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project_group::permission::{assert_write_access_check, MemberIdentity};
+use crate::project_template::error::{ProjectTemplateError, Result};
+use crate::project_template::service::fetch_template_group;
+
+/// Deletes the template, projects that were created from it stay
+pub async fn delete_template(
+    pool:        &PgPool,
+    identity:    MemberIdentity,
+    template_id: ProjectTemplateUuid,
+) -> Result<()> {
+    let project_group_id = fetch_template_group(pool, template_id).await?;
+    assert_write_access_check(
+            pool,
+            project_group_id,
+            identity,
+            ProjectGroupPermissionCode::WriteProject,
+        )
+        .await?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectTemplateError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            identity.character_id,
+            AuditAction::Delete,
+            AuditTarget::ProjectGroupTemplate(project_group_id, template_id),
+        )
+        .await?;
+
+    sqlx::query!("
+            DELETE FROM project_template
+            WHERE id = $1
+        ",
+            *template_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectTemplateError::Delete(e, template_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectTemplateError::TransactionError)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+    use uuid::Uuid;
+
+    use crate::project_group::permission::MemberIdentity;
+    use crate::project_template::service::delete_template;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn records_audit(
+        pool: PgPool,
+    ) {
+        delete_template(
+                &pool,
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                Uuid::from_str("00000000-0000-0000-0000-000000000101").unwrap().into(),
+            )
+            .await
+            .unwrap();
+
+        let audit = sqlx::query!(r#"
+                SELECT project_group_id
+                FROM audit_event
+                WHERE scope = 'PROJECT_GROUP_TEMPLATE'
+                AND action = 'DELETE'
+            "#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            audit.project_group_id.map(|x| x.to_string()),
+            Some("00000000-0000-0000-0000-000000000001".into()),
+        );
+    }
+}
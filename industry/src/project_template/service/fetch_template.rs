@@ -0,0 +1,164 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::{ProjectGroupUuid, ProjectTemplateUuid};
+use starfoundry_lib_industry::project_template::{ProjectTemplate, ProjectTemplateProduct};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::project_group::permission::{assert_read_access_check, MemberIdentity};
+use crate::project_template::error::{ProjectTemplateError, Result};
+
+/// Fetches a single template, the character requires read access to the
+/// project group of the template
+pub async fn fetch_template(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    identity:               MemberIdentity,
+    template_id:            ProjectTemplateUuid,
+) -> Result<ProjectTemplate> {
+    let project_group_id = fetch_template_group(pool, template_id).await?;
+    assert_read_access_check(
+            pool,
+            project_group_id,
+            identity,
+        )
+        .await?;
+
+    load_templates(
+            pool,
+            eve_gateway_api_client,
+            &[*project_group_id],
+            Some(template_id),
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(ProjectTemplateError::NotFound(template_id))
+}
+
+/// Project group the template belongs to
+///
+/// # Errors
+///
+/// - [ProjectTemplateError::NotFound] if the template does not exist
+///
+pub async fn fetch_template_group(
+    pool:        &PgPool,
+    template_id: ProjectTemplateUuid,
+) -> Result<ProjectGroupUuid> {
+    sqlx::query!("
+            SELECT project_group_id
+            FROM project_template
+            WHERE id = $1
+        ",
+            *template_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ProjectTemplateError::Fetch(e, template_id))?
+        .map(|x| x.project_group_id.into())
+        .ok_or(ProjectTemplateError::NotFound(template_id))
+}
+
+/// Loads the templates of the given project groups together with their
+/// products
+pub(super) async fn load_templates(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_group_ids:      &[Uuid],
+    template_id:            Option<ProjectTemplateUuid>,
+) -> Result<Vec<ProjectTemplate>> {
+    let entries = sqlx::query!("
+            SELECT
+                id,
+                project_group_id,
+                name,
+                orderer,
+                note,
+                tags,
+                interval_days,
+                next_run_at,
+                failed_attempts,
+                last_error
+            FROM project_template
+            WHERE project_group_id = ANY($1::UUID[])
+            AND NOT (id = $2::UUID) IS FALSE
+            ORDER BY name
+        ",
+            project_group_ids,
+            template_id.map(|x| *x),
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(ProjectTemplateError::List)?;
+
+    let template_ids = entries
+        .iter()
+        .map(|x| x.id)
+        .collect::<Vec<_>>();
+    let products = sqlx::query!("
+            SELECT
+                template_id,
+                type_id,
+                quantity
+            FROM project_template_product
+            WHERE template_id = ANY($1::UUID[])
+            ORDER BY type_id
+        ",
+            &template_ids,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(ProjectTemplateError::List)?;
+
+    let type_ids = products
+        .iter()
+        .map(|x| x.type_id.into())
+        .collect::<Vec<_>>();
+    let items = if type_ids.is_empty() {
+        HashMap::new()
+    } else {
+        eve_gateway_api_client
+            .fetch_item_bulk(type_ids)
+            .await?
+            .into_iter()
+            .map(|x| (x.type_id, x))
+            .collect::<HashMap<_, _>>()
+    };
+
+    let mut template_products: HashMap<Uuid, Vec<ProjectTemplateProduct>> = HashMap::new();
+    for product in products {
+        let item = if let Some(x) = items.get(&product.type_id.into()) {
+            x.clone()
+        } else {
+            tracing::debug!("Couldn't find item {}", product.type_id);
+            continue;
+        };
+
+        template_products
+            .entry(product.template_id)
+            .or_default()
+            .push(ProjectTemplateProduct {
+                item,
+                quantity: product.quantity,
+            });
+    }
+
+    let templates = entries
+        .into_iter()
+        .map(|x| ProjectTemplate {
+            id:               x.id.into(),
+            project_group_id: x.project_group_id.into(),
+            name:             x.name,
+            orderer:          x.orderer,
+            notes:            x.note,
+            products:         template_products.remove(&x.id).unwrap_or_default(),
+            tags:             x.tags.into_iter().map(Into::into).collect::<Vec<_>>(),
+            interval_days:    x.interval_days,
+            next_run_at:      x.next_run_at,
+            failed_attempts:  x.failed_attempts,
+            last_error:       x.last_error,
+        })
+        .collect::<Vec<_>>();
+    Ok(templates)
+}
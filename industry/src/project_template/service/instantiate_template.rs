@@ -0,0 +1,161 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::{ProjectTemplateUuid, ProjectUuid};
+use starfoundry_lib_industry::project::CreateProject;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use starfoundry_lib_industry::project_template::InstantiateProjectTemplate;
+
+use crate::project::service::create;
+use crate::project_group::permission::{assert_write_access_check, MemberIdentity};
+use crate::project_template::error::{ProjectTemplateError, Result};
+use crate::project_template::service::{fetch_template_group, load_templates};
+
+/// Creates a new project from the template.
+///
+/// The quantities of all products are multiplied by the given multiplier,
+/// tags of the template are only added if they belong to the character.
+///
+pub async fn instantiate_template(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    identity:               MemberIdentity,
+    template_id:            ProjectTemplateUuid,
+    instantiate:            InstantiateProjectTemplate,
+) -> Result<ProjectUuid> {
+    instantiate.validate()?;
+
+    let project_group_id = fetch_template_group(pool, template_id).await?;
+    assert_write_access_check(
+            pool,
+            project_group_id,
+            identity,
+            ProjectGroupPermissionCode::WriteProject,
+        )
+        .await?;
+
+    let template = load_templates(
+            pool,
+            eve_gateway_api_client,
+            &[*project_group_id],
+            Some(template_id),
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(ProjectTemplateError::NotFound(template_id))?;
+
+    let products = template
+        .products
+        .iter()
+        .map(|x| format!("{} {}", x.item.name, x.quantity.saturating_mul(instantiate.multiplier)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let project_id = create(
+            pool,
+            identity.character_id,
+            CreateProject {
+                project_group_id,
+                orderer:        template.orderer,
+                name:           template.name,
+                sell_price:     None,
+                notes:          template.notes,
+                pre_products:   Some(products),
+                pre_additional: None,
+                fittings:       None,
+                solution_id:    None,
+            },
+        )
+        .await?;
+
+    sqlx::query!("
+            INSERT INTO project_tag
+            (
+                project_id,
+                tag_id
+            )
+            SELECT $1, id
+            FROM tag
+            WHERE id = ANY($2::UUID[])
+            AND owner_id = $3
+            AND typ = 'MANUAL'
+        ",
+            *project_id,
+            &template.tags.into_iter().map(|x| *x).collect::<Vec<_>>(),
+            *identity.character_id,
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| ProjectTemplateError::Instantiate(e, template_id))?;
+
+    Ok(project_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::project_template::InstantiateProjectTemplate;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+    use uuid::Uuid;
+
+    use crate::eve_gateway_api_client;
+    use crate::project_group::permission::MemberIdentity;
+    use crate::project_template::error::ProjectTemplateError;
+    use crate::project_template::service::instantiate_template;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn multiplies_products(
+        pool: PgPool,
+    ) {
+        let template_id = Uuid::from_str("00000000-0000-0000-0000-000000000101").unwrap();
+
+        let result = instantiate_template(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                MemberIdentity::new(CharacterId(2), CorporationId(2), None),
+                template_id.into(),
+                InstantiateProjectTemplate::default(),
+            )
+            .await;
+        assert!(matches!(result, Err(ProjectTemplateError::ProjectGroupError(_))));
+
+        let project_id = instantiate_template(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                template_id.into(),
+                InstantiateProjectTemplate {
+                    multiplier: 3,
+                },
+            )
+            .await
+            .unwrap();
+
+        let project = sqlx::query!(r#"
+                SELECT
+                    name,
+                    orderer,
+                    pre_products,
+                    (SELECT ARRAY_AGG(tag_id) FROM project_tag WHERE project_id = $1) AS "tags!"
+                FROM project
+                WHERE id = $1
+            "#,
+                *project_id,
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(project.name, "Fuel Blocks");
+        assert_eq!(project.orderer, "Alliance");
+        assert_eq!(project.pre_products, Some("Nitrogen Fuel Block 3000".into()));
+        // the tag of the second character is ignored
+        assert_eq!(project.tags, vec![Uuid::from_str("00000000-0000-0000-0000-000000001001").unwrap()]);
+    }
+}
@@ -0,0 +1,39 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::project_template::{ProjectTemplate, ProjectTemplateFilter};
+
+use crate::project_group::permission::MemberIdentity;
+use crate::project_template::error::Result;
+use crate::project_template::service::load_templates;
+
+/// Lists the templates of all project groups the character is a member of
+pub async fn list_templates(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    identity:               MemberIdentity,
+    filter:                 ProjectTemplateFilter,
+) -> Result<Vec<ProjectTemplate>> {
+    let project_group_ids = crate::project_group::service::list(
+            pool,
+            identity,
+            Default::default(),
+        )
+        .await?
+        .into_iter()
+        .map(|x| x.id)
+        .filter(|x| filter.project_group_id.map(|y| y == *x).unwrap_or(true))
+        .map(|x| *x)
+        .collect::<Vec<_>>();
+
+    if project_group_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    load_templates(
+            pool,
+            eve_gateway_api_client,
+            &project_group_ids,
+            None,
+        )
+        .await
+}
@@ -0,0 +1,247 @@
+use sqlx::{PgPool, Postgres, Transaction};
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::ProjectTemplateUuid;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use starfoundry_lib_industry::project_template::{ProjectTemplateProductEntry, UpdateProjectTemplate};
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project_group::permission::{assert_write_access_check, MemberIdentity};
+use crate::project_template::error::{ProjectTemplateError, Result};
+use crate::project_template::service::fetch_template_group;
+
+/// Replaces the template, scheduled projects are afterwards created in the
+/// name of the given character.
+///
+/// An existing schedule keeps its next run, removing the interval stops the
+/// schedule. Failures of previous scheduled runs are reset.
+///
+pub async fn update_template(
+    pool:        &PgPool,
+    identity:    MemberIdentity,
+    domain:      String,
+    template_id: ProjectTemplateUuid,
+    template:    UpdateProjectTemplate,
+) -> Result<()> {
+    template.validate()?;
+
+    let project_group_id = fetch_template_group(pool, template_id).await?;
+    assert_write_access_check(
+            pool,
+            project_group_id,
+            identity,
+            ProjectGroupPermissionCode::WriteProject,
+        )
+        .await?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(ProjectTemplateError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            identity.character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectGroupTemplate(project_group_id, template_id),
+        )
+        .await?;
+
+    sqlx::query!("
+            UPDATE project_template
+            SET
+                character_id   = $2,
+                corporation_id = $3,
+                domain         = $4,
+                name           = $5,
+                orderer        = $6,
+                note           = $7,
+                tags           = $8,
+                interval_days  = $9,
+                next_run_at    = CASE
+                    WHEN $9::INTEGER IS NULL THEN NULL
+                    ELSE COALESCE(next_run_at, NOW() + make_interval(days => $9))
+                END,
+                failed_attempts = 0,
+                last_error      = NULL
+            WHERE id = $1
+        ",
+            *template_id,
+            *identity.character_id,
+            *identity.corporation_id,
+            domain,
+            template.name,
+            template.orderer,
+            template.notes,
+            &template.tags.iter().map(|x| **x).collect::<Vec<_>>(),
+            template.interval_days,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectTemplateError::Update(e, template_id))?;
+
+    sqlx::query!("
+            DELETE FROM project_template_product
+            WHERE template_id = $1
+        ",
+            *template_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| ProjectTemplateError::Update(e, template_id))?;
+
+    insert_products(
+            &mut transaction,
+            template_id,
+            &template.products,
+        )
+        .await?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(ProjectTemplateError::TransactionError)?;
+
+    Ok(())
+}
+
+/// Inserts the products of the template, quantities of duplicated items are
+/// summed up
+pub(super) async fn insert_products(
+    transaction: &mut Transaction<'_, Postgres>,
+    template_id: ProjectTemplateUuid,
+    products:    &[ProjectTemplateProductEntry],
+) -> Result<()> {
+    let type_ids = products
+        .iter()
+        .map(|x| *x.type_id)
+        .collect::<Vec<_>>();
+    let quantities = products
+        .iter()
+        .map(|x| x.quantity)
+        .collect::<Vec<_>>();
+
+    sqlx::query!("
+            INSERT INTO project_template_product
+            (
+                template_id,
+                type_id,
+                quantity
+            )
+            SELECT $1, type_id, SUM(quantity)
+            FROM UNNEST(
+                $2::INTEGER[],
+                $3::INTEGER[]
+            ) AS x(type_id, quantity)
+            GROUP BY type_id
+        ",
+            *template_id,
+            &type_ids,
+            &quantities,
+        )
+        .execute(&mut **transaction)
+        .await
+        .map_err(|e| ProjectTemplateError::Update(e, template_id))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::project_template::UpdateProjectTemplate;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+    use uuid::Uuid;
+
+    use crate::project_group::permission::MemberIdentity;
+    use crate::project_template::service::update_template;
+
+    fn template(
+        interval_days: Option<i32>,
+    ) -> UpdateProjectTemplate {
+        serde_json::from_value(serde_json::json!({
+                "name": "Fuel Blocks",
+                "orderer": "Alliance",
+                "products": [{
+                    "type_id": 4051,
+                    "quantity": 1000
+                }, {
+                    "type_id": 4051,
+                    "quantity": 500
+                }],
+                "interval_days": interval_days
+            }))
+            .unwrap()
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn keeps_schedule(
+        pool: PgPool,
+    ) {
+        let template_id = Uuid::from_str("00000000-0000-0000-0000-000000000101").unwrap();
+
+        update_template(
+                &pool,
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                "test.starfoundry.space".into(),
+                template_id.into(),
+                template(Some(14)),
+            )
+            .await
+            .unwrap();
+
+        let entry = sqlx::query!(r#"
+                SELECT
+                    next_run_at < NOW() AS "due!",
+                    (SELECT ARRAY_AGG(quantity) FROM project_template_product WHERE template_id = $1) AS "quantities!"
+                FROM project_template
+                WHERE id = $1
+            "#,
+                template_id,
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(entry.due);
+        assert_eq!(entry.quantities, vec![1500]);
+
+        let audit = sqlx::query!(r#"
+                SELECT after
+                FROM audit_event
+                WHERE scope = 'PROJECT_GROUP_TEMPLATE'
+                AND action = 'UPDATE'
+            "#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(audit.after.get("interval_days"), Some(&serde_json::json!(14)));
+
+        update_template(
+                &pool,
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                "test.starfoundry.space".into(),
+                template_id.into(),
+                template(None),
+            )
+            .await
+            .unwrap();
+
+        let entry = sqlx::query!("
+                SELECT next_run_at
+                FROM project_template
+                WHERE id = $1
+            ",
+                template_id,
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(entry.next_run_at, None);
+    }
+}
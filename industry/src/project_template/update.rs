@@ -0,0 +1,69 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectTemplateUuid;
+use starfoundry_lib_industry::project_template::UpdateProjectTemplate;
+
+use crate::AppState;
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity};
+use crate::project_template::error::Result;
+use crate::project_template::service::update_template;
+
+/// Update Project Template
+/// 
+/// - Alternative route: `/latest/project-templates/{ProjectTemplateUuid}`
+/// - Alternative route: `/v1/project-templates/{ProjectTemplateUuid}`
+/// 
+/// ---
+/// 
+/// Replaces the template, scheduled projects are afterwards created in the
+/// name of the character
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_project
+/// 
+#[utoipa::path(
+    put,
+    path = "/{ProjectTemplateUuid}",
+    tag = "Project Templates",
+    params(
+        ProjectTemplateUuid,
+    ),
+    request_body = UpdateProjectTemplate,
+    responses(
+        (
+            description = "The template was updated",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:          ExtractIdentity,
+    State(state):      State<AppState>,
+    Path(template_id): Path<ProjectTemplateUuid>,
+    Json(template):    Json<UpdateProjectTemplate>,
+) -> Result<impl IntoResponse> {
+    update_template(
+            &state.postgres,
+            (&identity).into(),
+            identity.host()?,
+            template_id,
+            template,
+        ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
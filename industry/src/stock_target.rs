@@ -0,0 +1,43 @@
+mod check;
+mod error;
+mod fetch;
+mod restock;
+mod service;
+mod update;
+
+use axum::middleware;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use crate::AppState;
+use crate::project_group::permission::{assert_exists, assert_read, assert_write};
+
+pub fn routes(
+    state: AppState,
+) -> OpenApiRouter<AppState> {
+    let fetch = OpenApiRouter::new()
+        .routes(routes!(fetch::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    let update = OpenApiRouter::new()
+        .routes(routes!(update::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_write))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    let check = OpenApiRouter::new()
+        .routes(routes!(check::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_write))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    let restock = OpenApiRouter::new()
+        .routes(routes!(restock::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_write))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    OpenApiRouter::new()
+        .merge(fetch)
+        .merge(update)
+        .merge(check)
+        .merge(restock)
+}
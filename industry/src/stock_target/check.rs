@@ -0,0 +1,89 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_industry::stock_target::StockTargets;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::stock_target::error::Result;
+use crate::stock_target::service::{check_stock_targets, fetch_stock_targets};
+
+/// Check Stock Targets
+/// 
+/// - Alternative route: `/latest/stock-targets/{ProjectGroupUuid}/check`
+/// - Alternative route: `/v1/stock-targets/{ProjectGroupUuid}/check`
+/// 
+/// ---
+/// 
+/// Compares the stock targets with the synced assets and returns the
+/// updated targets.
+/// The assets are read in the name of the character that configured the
+/// stock targets.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write
+/// 
+#[utoipa::path(
+    post,
+    path = "/{ProjectGroupUuid}/check",
+    tag = "Stock Targets",
+    params(
+        ProjectGroupUuid,
+    ),
+    responses(
+        (
+            body = StockTargets,
+            description = "Stock targets of the project group",
+            status = OK,
+        ),
+        (
+            description = "The project group has no stock targets",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):           State<AppState>,
+    Path(project_group_id): Path<ProjectGroupUuid>,
+) -> Result<impl IntoResponse> {
+    check_stock_targets(
+            &state.postgres,
+            project_group_id,
+        )
+        .await?;
+
+    let entry = fetch_stock_targets(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            project_group_id,
+        )
+        .await?;
+
+    if let Some(x) = entry {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(x)
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(())
+            )
+            .into_response()
+        )
+    }
+}
@@ -0,0 +1,112 @@
+use axum::extract::rejection::JsonRejection;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use starfoundry_lib_gateway::{ErrorResponse, boxed_from};
+use starfoundry_lib_industry::ProjectGroupUuid;
+use thiserror::Error;
+
+use crate::api_docs::format_json_errors;
+use crate::industry::IndustryError;
+use crate::project::error::ProjectError;
+use crate::project_group::ProjectGroupError;
+
+pub type Result<T, E = StockTargetError> = std::result::Result<T, E>;
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum StockTargetError {
+    #[error("the project group '{0}' can't build the shortfall of its stock targets")]
+    NoSolution(ProjectGroupUuid),
+
+    #[error("error while fetching stock targets of project group '{1}', error: '{0}'")]
+    Fetch(sqlx::Error, ProjectGroupUuid),
+    #[error("error while updating stock targets of project group '{1}', error: '{0}'")]
+    Update(sqlx::Error, ProjectGroupUuid),
+    #[error("error while checking stock targets of project group '{1}', error: '{0}'")]
+    Check(sqlx::Error, ProjectGroupUuid),
+    #[error("error while restocking stock targets of project group '{1}', error: '{0}'")]
+    Restock(sqlx::Error, ProjectGroupUuid),
+
+    #[error("transaction error, '{0}'")]
+    TransactionError(sqlx::Error),
+
+    #[error(transparent)]
+    AuditError(Box<crate::audit::AuditError>),
+    #[error(transparent)]
+    JsonExtractorRejection(#[from] JsonRejection),
+    #[error(transparent)]
+    GatewayLibError(#[from] starfoundry_lib_gateway::Error),
+    #[error(transparent)]
+    EveGatewayLibError(#[from] starfoundry_lib_eve_gateway::Error),
+    #[error(transparent)]
+    IndustryLibError(#[from] starfoundry_lib_industry::Error),
+    #[error(transparent)]
+    ProjectGroupError(Box<ProjectGroupError>),
+    #[error(transparent)]
+    ProjectError(Box<ProjectError>),
+    #[error(transparent)]
+    IndustryError(Box<IndustryError>),
+}
+
+impl IntoResponse for StockTargetError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NoSolution(_) => {
+                tracing::info!("{}", self.to_string());
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    Json(
+                        ErrorResponse {
+                            error: "UNPROCESSABLE_ENTITY".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+
+            Self::JsonExtractorRejection(x) => {
+                format_json_errors(x).into_response()
+            },
+
+            Self::GatewayLibError(e) => {
+                starfoundry_lib_gateway::Error::into_response(e)
+            },
+
+            Self::IndustryLibError(e) => {
+                e.into_response()
+            },
+
+            Self::ProjectGroupError(e) => {
+                ProjectGroupError::into_response(*e)
+            },
+
+            Self::ProjectError(e) => {
+                ProjectError::into_response(*e)
+            },
+
+            Self::IndustryError(e) => {
+                IndustryError::into_response(*e)
+            },
+
+            _ => {
+                tracing::error!("{}", self.to_string());
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(
+                        ErrorResponse {
+                            error: "UNKNOWN".into(),
+                            description: "An unknown error occurred, please try again later.".into(),
+                        }
+                    )
+                ).into_response()
+            },
+        }
+        .into_response()
+    }
+}
+
+boxed_from!(StockTargetError::AuditError, crate::audit::AuditError);
+boxed_from!(StockTargetError::ProjectGroupError, ProjectGroupError);
+boxed_from!(StockTargetError::ProjectError, ProjectError);
+boxed_from!(StockTargetError::IndustryError, IndustryError);
@@ -0,0 +1,2 @@
+INSERT INTO stock_target (id, project_group_id, type_id, location_id, quantity) VALUES
+('00000000-0000-0000-0000-000000000003', '00000000-0000-0000-0000-000000000001', 20185, 1046664001931, 1);
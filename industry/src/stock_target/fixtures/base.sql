@@ -0,0 +1,21 @@
+INSERT INTO project_group (id, owner, name, description) VALUES
+('00000000-0000-0000-0000-000000000001', 1, 'First', 'Description');
+
+INSERT INTO project_group_member (project_group_id, character_id, permission) VALUES
+('00000000-0000-0000-0000-000000000001', 1, 1),
+('00000000-0000-0000-0000-000000000001', 2, 2);
+
+INSERT INTO project (id, owner, name, orderer, project_group_id, status) VALUES
+('00000000-0000-0000-0000-000000000101', 1, 'Restock', 'Stock targets', '00000000-0000-0000-0000-000000000001', 'IN_PROGRESS'),
+('00000000-0000-0000-0000-000000000102', 1, 'Restock', 'Stock targets', '00000000-0000-0000-0000-000000000001', 'DONE');
+
+INSERT INTO stock_target_config (project_group_id, character_id, corporation_id, domain) VALUES
+('00000000-0000-0000-0000-000000000001', 1, 1, 'test.starfoundry.space');
+
+INSERT INTO stock_target (id, project_group_id, type_id, location_id, quantity) VALUES
+('00000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000001', 4051, 1046664001931, 1500),
+('00000000-0000-0000-0000-000000000002', '00000000-0000-0000-0000-000000000001', 4246, 1046664001931, 100);
+
+INSERT INTO stock_target_project (stock_target_id, project_id, quantity) VALUES
+('00000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000102', 300),
+('00000000-0000-0000-0000-000000000002', '00000000-0000-0000-0000-000000000101', 40);
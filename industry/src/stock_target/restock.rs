@@ -0,0 +1,102 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_industry::stock_target::StockTargetRestock;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity};
+use crate::stock_target::error::Result;
+use crate::stock_target::service::{check_stock_targets, restock};
+use crate::tag::service::apply_auto_tags;
+
+/// Restock
+/// 
+/// - Alternative route: `/latest/stock-targets/{ProjectGroupUuid}/restock`
+/// - Alternative route: `/v1/stock-targets/{ProjectGroupUuid}/restock`
+/// 
+/// ---
+/// 
+/// Compares the stock targets with the synced assets and creates a project
+/// for the shortfall.
+/// The job planner uses the default blacklist, blueprint overwrites, job
+/// splitting and markets of the project group, the project is owned by the
+/// requesting character.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write
+/// 
+#[utoipa::path(
+    post,
+    path = "/{ProjectGroupUuid}/restock",
+    tag = "Stock Targets",
+    params(
+        ProjectGroupUuid,
+    ),
+    responses(
+        (
+            body = StockTargetRestock,
+            description = "The restock project was created",
+            status = CREATED,
+        ),
+        (
+            description = "There is no shortfall",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:               ExtractIdentity,
+    State(state):           State<AppState>,
+    Path(project_group_id): Path<ProjectGroupUuid>,
+) -> Result<impl IntoResponse> {
+    check_stock_targets(
+            &state.postgres,
+            project_group_id,
+        )
+        .await?;
+
+    let eve_gateway_api_client = eve_gateway_api_client()?;
+    let entry = restock(
+            &state.postgres,
+            &eve_gateway_api_client,
+            identity.character_id,
+            project_group_id,
+        )
+        .await?;
+
+    if let Some(x) = entry {
+        // the project is already stored, so a failure only leaves the
+        // automatic tags outdated until the next change
+        if let Err(e) = apply_auto_tags(&state.postgres, &eve_gateway_api_client, x.project_id).await {
+            tracing::error!("error while applying automatic tags to project '{}', error: '{}'", x.project_id, e);
+        }
+
+        Ok(
+            (
+                StatusCode::CREATED,
+                Json(x)
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(())
+            )
+            .into_response()
+        )
+    }
+}
@@ -0,0 +1,9 @@
+mod check_stock_targets;
+mod fetch_stock_targets;
+mod restock;
+mod update_stock_targets;
+
+pub use self::check_stock_targets::*;
+pub use self::fetch_stock_targets::*;
+pub use self::restock::*;
+pub use self::update_stock_targets::*;
@@ -0,0 +1,234 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{AssetStockFilter, EveGatewayApiClientEveAsset};
+use starfoundry_lib_gateway::Identity;
+use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_types::{CharacterId, CorporationId};
+use std::collections::HashMap;
+
+use crate::eve_gateway_api_client_with_identity;
+use crate::stock_target::error::{StockTargetError, Result};
+
+/// Compares the stock targets of the project group with the synced assets at
+/// their locations.
+///
+/// The assets are read in the name of the character that configured the
+/// stock targets, every hangar and container of the location counts.
+/// Assembled ships in a hangar count as well.
+///
+pub async fn check_stock_targets(
+    pool:             &PgPool,
+    project_group_id: ProjectGroupUuid,
+) -> Result<()> {
+    let config = sqlx::query!("
+            SELECT
+                character_id,
+                corporation_id,
+                domain
+            FROM stock_target_config
+            WHERE project_group_id = $1
+        ",
+            *project_group_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| StockTargetError::Check(e, project_group_id))?;
+
+    let identity = if let Some(x) = config {
+        Identity::new(
+            CharacterId(x.character_id),
+            CorporationId(x.corporation_id),
+            x.domain,
+        )
+    } else {
+        return Ok(());
+    };
+
+    let location_ids = sqlx::query!("
+            SELECT DISTINCT location_id
+            FROM stock_target
+            WHERE project_group_id = $1
+        ",
+            *project_group_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StockTargetError::Check(e, project_group_id))?
+        .into_iter()
+        .map(|x| x.location_id.into())
+        .collect::<Vec<_>>();
+
+    let stock = if location_ids.is_empty() {
+        Vec::new()
+    } else {
+        eve_gateway_api_client_with_identity(identity)?
+            .eve_list_asset_stock(&AssetStockFilter {
+                location_ids,
+                // ships of a doctrine are stocked assembled
+                include_assembled: true,
+                ..Default::default()
+            })
+            .await?
+    };
+
+    let mut available = HashMap::new();
+    for entry in stock {
+        *available
+            .entry((*entry.type_id, *entry.location_id))
+            .or_insert(0i64) += entry.quantity;
+    }
+
+    let mut type_ids     = Vec::new();
+    let mut location_ids = Vec::new();
+    let mut quantities   = Vec::new();
+    for ((type_id, location_id), quantity) in available {
+        type_ids.push(type_id);
+        location_ids.push(location_id);
+        quantities.push(quantity);
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(StockTargetError::TransactionError)?;
+
+    sqlx::query!("
+            UPDATE stock_target st
+            SET available = COALESCE(a.quantity, 0)
+            FROM stock_target target
+            LEFT JOIN UNNEST(
+                $2::INTEGER[],
+                $3::BIGINT[],
+                $4::BIGINT[]
+            ) AS a(type_id, location_id, quantity)
+                ON a.type_id = target.type_id
+                AND a.location_id = target.location_id
+            WHERE st.id = target.id
+            AND st.project_group_id = $1
+        ",
+            *project_group_id,
+            &type_ids,
+            &location_ids,
+            &quantities,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StockTargetError::Check(e, project_group_id))?;
+
+    sqlx::query!("
+            UPDATE stock_target_config
+            SET checked_at = NOW()
+            WHERE project_group_id = $1
+        ",
+            *project_group_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StockTargetError::Check(e, project_group_id))?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(StockTargetError::TransactionError)
+}
+
+#[cfg(test)]
+mod check_stock_targets_test {
+    use sqlx::PgPool;
+    use starfoundry_lib_types::TypeId;
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::eve_gateway_api_client;
+    use crate::stock_target::service::fetch_stock_targets;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn shortfall(
+        pool: PgPool,
+    ) {
+        let project_group_id = Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into();
+        super::check_stock_targets(&pool, project_group_id)
+            .await
+            .unwrap();
+
+        let response = fetch_stock_targets(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                project_group_id,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(response.checked_at.is_some());
+
+        let targets = response
+            .targets
+            .iter()
+            .map(|x| (x.item.type_id, x.available, x.incoming, x.shortfall))
+            .collect::<Vec<_>>();
+        assert_eq!(targets, vec![
+            // the restock project is done, so it only counts with the assets
+            (TypeId(4051), 1000, 0, 500),
+            (TypeId(4246), 0, 40, 60),
+        ]);
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base", "assembled"),
+        ),
+    )]
+    async fn assembled_ship(
+        pool: PgPool,
+    ) {
+        let project_group_id = Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into();
+        super::check_stock_targets(&pool, project_group_id)
+            .await
+            .unwrap();
+
+        let response = fetch_stock_targets(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                project_group_id,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        let ship = response
+            .targets
+            .iter()
+            .find(|x| x.item.type_id == TypeId(20185))
+            .map(|x| (x.available, x.shortfall));
+        assert_eq!(ship, Some((1, 0)));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn no_config(
+        pool: PgPool,
+    ) {
+        let project_group_id = Uuid::from_str("00000000-0000-0000-0000-000000000002").unwrap().into();
+        super::check_stock_targets(&pool, project_group_id)
+            .await
+            .unwrap();
+
+        let response = fetch_stock_targets(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                project_group_id,
+            )
+            .await
+            .unwrap();
+        assert!(response.is_none());
+    }
+}
@@ -0,0 +1,98 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_industry::stock_target::{StockTarget, StockTargetRestockMode, StockTargets};
+use std::collections::HashMap;
+
+use crate::stock_target::error::{StockTargetError, Result};
+
+/// Fetches the stock targets of the project group together with the result
+/// of the last check.
+///
+/// Returns `None` if the project group has no stock targets configured.
+///
+pub async fn fetch_stock_targets(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_group_id:       ProjectGroupUuid,
+) -> Result<Option<StockTargets>> {
+    let config = sqlx::query!(r#"
+            SELECT
+                restock AS "restock: StockTargetRestockMode",
+                checked_at
+            FROM stock_target_config
+            WHERE project_group_id = $1
+        "#,
+            *project_group_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| StockTargetError::Fetch(e, project_group_id))?;
+
+    let config = if let Some(x) = config {
+        x
+    } else {
+        return Ok(None);
+    };
+
+    // restock projects that are done count as available, as soon as the
+    // products are in the assets
+    let entries = sqlx::query!(r#"
+            SELECT
+                st.id,
+                st.type_id,
+                st.location_id,
+                st.quantity,
+                st.available,
+                COALESCE(SUM(stp.quantity) FILTER (WHERE p.status != 'DONE'), 0)::BIGINT AS "incoming!"
+            FROM stock_target st
+            LEFT JOIN stock_target_project stp ON stp.stock_target_id = st.id
+            LEFT JOIN project p ON p.id = stp.project_id
+            WHERE st.project_group_id = $1
+            GROUP BY st.id
+            ORDER BY st.type_id, st.location_id
+        "#,
+            *project_group_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StockTargetError::Fetch(e, project_group_id))?;
+
+    let type_ids = entries
+        .iter()
+        .map(|x| x.type_id.into())
+        .collect::<Vec<_>>();
+    let items = eve_gateway_api_client
+        .fetch_item_bulk(type_ids)
+        .await?
+        .into_iter()
+        .map(|x| (x.type_id, x))
+        .collect::<HashMap<_, _>>();
+
+    let mut targets = Vec::new();
+    for entry in entries {
+        let item = if let Some(x) = items.get(&entry.type_id.into()) {
+            x.clone()
+        } else {
+            tracing::debug!("Couldn't find item {}", entry.type_id);
+            continue;
+        };
+
+        let shortfall = (entry.quantity as i64 - entry.available - entry.incoming).max(0);
+        targets.push(StockTarget {
+            id:          entry.id.into(),
+            item,
+            location_id: entry.location_id.into(),
+            quantity:    entry.quantity,
+            available:   entry.available,
+            incoming:    entry.incoming,
+            shortfall,
+        });
+    }
+
+    Ok(Some(StockTargets {
+        restock:    config.restock,
+        checked_at: config.checked_at,
+        targets,
+    }))
+}
@@ -0,0 +1,221 @@
+use chrono::Utc;
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::industry::{BuildEngine, BuildEngineProduct};
+use starfoundry_lib_industry::project::CreateProject;
+use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_industry::stock_target::{StockTarget, StockTargetRestock};
+use starfoundry_lib_types::{CharacterId, StructureId, TypeId};
+use std::collections::HashMap;
+
+use crate::industry::calculate_as;
+use crate::project::service::create_in_transaction;
+use crate::project_group::service::{fetch_solution_summaries, list_default_blueprint_overwrite, list_default_market};
+use crate::stock_target::error::{StockTargetError, Result};
+use crate::stock_target::service::fetch_stock_targets;
+
+/// Creates a project that builds the shortfall of the stock targets.
+///
+/// The shortfall is taken from the last check. The job planner runs with the
+/// defaults of the project group, the project is initialized with the
+/// cheapest solution and counts as incoming for the targets until it is done.
+/// The project and its link to the targets are stored in a single
+/// transaction.
+///
+/// Returns `None` if there is no shortfall.
+///
+/// # Errors
+///
+/// - [StockTargetError::NoSolution] if none of the industry hubs can build
+///   the shortfall
+///
+pub async fn restock(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    character_id:           CharacterId,
+    project_group_id:       ProjectGroupUuid,
+) -> Result<Option<StockTargetRestock>> {
+    let targets = if let Some(x) = fetch_stock_targets(
+            pool,
+            eve_gateway_api_client,
+            project_group_id,
+        )
+        .await? {
+
+        x
+            .targets
+            .into_iter()
+            .filter(|x| x.shortfall > 0)
+            .collect::<Vec<_>>()
+    } else {
+        return Ok(None);
+    };
+
+    if targets.is_empty() {
+        return Ok(None);
+    }
+
+    let products = shortfall_products(
+            pool,
+            eve_gateway_api_client,
+            project_group_id,
+            &targets,
+        )
+        .await?;
+
+    let markets = list_default_market(
+            pool,
+            character_id,
+            eve_gateway_api_client,
+            project_group_id,
+        )
+        .await?
+        .into_iter()
+        .map(|x| StructureId(x.structure_id))
+        .collect::<Vec<_>>();
+
+    let solution_ids = calculate_as(
+            pool,
+            character_id,
+            None,
+            BuildEngine {
+                project_group_id,
+                products:                Some(products),
+                products_str:            None,
+                additional_products:     None,
+                additional_products_str: None,
+                fittings:                None,
+                stocks:                  None,
+                stocks_str:              None,
+                stocks_from_assets:      None,
+                stock_location_id:       None,
+                project_id:              None,
+                blacklist:               None,
+                blueprint_overwrite:     None,
+                job_splitting:           None,
+                markets:                 Some(markets),
+                calculate_market_cost:   Some(true),
+                combine_industry_hubs:   None,
+                freight_rate:            None,
+                industry_hubs:           None,
+                expand_planetary:        None,
+                customs_office_tax:      None,
+            },
+        )
+        .await?
+        .into_iter()
+        .map(|x| x.solution_id)
+        .collect::<Vec<_>>();
+
+    if solution_ids.is_empty() {
+        return Err(StockTargetError::NoSolution(project_group_id));
+    }
+
+    let solution = fetch_solution_summaries(
+            pool,
+            eve_gateway_api_client,
+            project_group_id,
+            Some(solution_ids),
+        )
+        .await?
+        .into_iter()
+        .min_by(|a, b| a.cost.total.total_cmp(&b.cost.total))
+        .ok_or(StockTargetError::NoSolution(project_group_id))?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(StockTargetError::TransactionError)?;
+
+    let project_id = create_in_transaction(
+            &mut transaction,
+            character_id,
+            CreateProject {
+                project_group_id,
+                orderer:          "Stock targets".into(),
+                name:             format!("Restock {}", Utc::now().format("%Y-%m-%d %H:%M")),
+                sell_price:       None,
+                notes:            None,
+                pre_products:     Some(
+                    targets
+                        .iter()
+                        .map(|x| format!("{} {}", x.item.name, x.shortfall))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                ),
+                pre_additional:   None,
+                fittings:         None,
+                solution_id:      Some(solution.id),
+            },
+        )
+        .await?;
+
+    sqlx::query!("
+            INSERT INTO stock_target_project
+            (
+                project_id,
+                stock_target_id,
+                quantity
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::UUID[],
+                $3::INTEGER[]
+            )
+        ",
+            *project_id,
+            &targets.iter().map(|x| *x.id).collect::<Vec<_>>(),
+            &targets.iter().map(|x| x.shortfall as i32).collect::<Vec<_>>(),
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StockTargetError::Restock(e, project_group_id))?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(StockTargetError::TransactionError)?;
+
+    Ok(Some(StockTargetRestock {
+        project_id,
+        targets,
+    }))
+}
+
+/// Sums the shortfall of all locations per item, the material efficiency is
+/// taken from the default blueprint overwrites of the project group
+async fn shortfall_products(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_group_id:       ProjectGroupUuid,
+    targets:                &[StockTarget],
+) -> Result<Vec<BuildEngineProduct>> {
+    let material_efficiency = list_default_blueprint_overwrite(
+            pool,
+            eve_gateway_api_client,
+            project_group_id,
+        )
+        .await?
+        .into_iter()
+        .map(|x| (x.item.type_id, x.material_efficiency as u32))
+        .collect::<HashMap<_, _>>();
+
+    let mut quantities: HashMap<TypeId, u32> = HashMap::new();
+    for target in targets {
+        *quantities
+            .entry(target.item.type_id)
+            .or_default() += target.shortfall as u32;
+    }
+
+    let products = quantities
+        .into_iter()
+        .map(|(type_id, quantity)| BuildEngineProduct {
+            quantity,
+            type_id,
+            material_efficiency: material_efficiency
+                .get(&type_id)
+                .copied()
+                .unwrap_or_default(),
+        })
+        .collect::<Vec<_>>();
+    Ok(products)
+}
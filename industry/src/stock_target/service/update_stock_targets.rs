@@ -0,0 +1,235 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_industry::stock_target::UpdateStockTargets;
+use starfoundry_lib_types::{CharacterId, CorporationId};
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::stock_target::error::{StockTargetError, Result};
+
+/// Replaces the stock targets of the project group.
+///
+/// The assets of the given character and its corporation are compared with
+/// the targets, the worker keeps checking them in their name.
+/// Targets that stay keep their restock projects.
+///
+pub async fn update_stock_targets(
+    pool:             &PgPool,
+    character_id:     CharacterId,
+    corporation_id:   CorporationId,
+    domain:           String,
+    project_group_id: ProjectGroupUuid,
+    update:           UpdateStockTargets,
+) -> Result<()> {
+    update.validate()?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(StockTargetError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::ProjectGroupStockTarget(project_group_id),
+        )
+        .await?;
+
+    sqlx::query!("
+            INSERT INTO stock_target_config
+            (
+                project_group_id,
+                character_id,
+                corporation_id,
+                domain,
+                restock
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (project_group_id)
+            DO UPDATE SET
+                character_id   = EXCLUDED.character_id,
+                corporation_id = EXCLUDED.corporation_id,
+                domain         = EXCLUDED.domain,
+                restock        = EXCLUDED.restock
+        ",
+            *project_group_id,
+            *character_id,
+            *corporation_id,
+            domain,
+            update.restock as _,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StockTargetError::Update(e, project_group_id))?;
+
+    let type_ids = update
+        .targets
+        .iter()
+        .map(|x| *x.type_id)
+        .collect::<Vec<_>>();
+    let location_ids = update
+        .targets
+        .iter()
+        .map(|x| *x.location_id)
+        .collect::<Vec<_>>();
+    let quantities = update
+        .targets
+        .iter()
+        .map(|x| x.quantity)
+        .collect::<Vec<_>>();
+
+    sqlx::query!("
+            DELETE FROM stock_target
+            WHERE project_group_id = $1
+            AND (type_id, location_id) NOT IN (
+                SELECT * FROM UNNEST(
+                    $2::INTEGER[],
+                    $3::BIGINT[]
+                )
+            )
+        ",
+            *project_group_id,
+            &type_ids,
+            &location_ids,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StockTargetError::Update(e, project_group_id))?;
+
+    sqlx::query!("
+            INSERT INTO stock_target
+            (
+                project_group_id,
+                type_id,
+                location_id,
+                quantity
+            )
+            SELECT $1, * FROM UNNEST(
+                $2::INTEGER[],
+                $3::BIGINT[],
+                $4::INTEGER[]
+            )
+            ON CONFLICT (project_group_id, type_id, location_id)
+            DO UPDATE SET quantity = EXCLUDED.quantity
+        ",
+            *project_group_id,
+            &type_ids,
+            &location_ids,
+            &quantities,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StockTargetError::Update(e, project_group_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(StockTargetError::TransactionError)
+}
+
+#[cfg(test)]
+mod update_stock_targets_test {
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::stock_target::{StockTargetEntry, StockTargetRestockMode, UpdateStockTargets};
+    use starfoundry_lib_types::{CharacterId, CorporationId, LocationId, TypeId};
+    use std::str::FromStr;
+    use uuid::Uuid;
+
+    use crate::stock_target::error::StockTargetError;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn keeps_restock_projects(
+        pool: PgPool,
+    ) {
+        super::update_stock_targets(
+                &pool,
+                CharacterId(2),
+                CorporationId(1),
+                "test.starfoundry.space".into(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                UpdateStockTargets {
+                    restock: StockTargetRestockMode::Create,
+                    targets: vec![
+                        StockTargetEntry {
+                            type_id:     TypeId(4246),
+                            location_id: LocationId(1046664001931),
+                            quantity:    200,
+                        },
+                    ],
+                },
+            )
+            .await
+            .unwrap();
+
+        let targets = sqlx::query!("
+                SELECT id, type_id, quantity
+                FROM stock_target
+            ")
+            .fetch_all(&pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|x| (x.id.to_string(), x.type_id, x.quantity))
+            .collect::<Vec<_>>();
+        assert_eq!(targets, vec![
+            ("00000000-0000-0000-0000-000000000002".to_string(), 4246, 200),
+        ]);
+
+        let config = sqlx::query!("
+                SELECT character_id, restock::VARCHAR AS restock
+                FROM stock_target_config
+            ")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(config.character_id, 2);
+        assert_eq!(config.restock, Some("CREATE".into()));
+
+        let audit = sqlx::query!(r#"
+                SELECT character_id
+                FROM audit_event
+                WHERE scope = 'PROJECT_GROUP_STOCK_TARGET'
+                AND action = 'UPDATE'
+            "#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(audit.character_id, 2);
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn invalid_quantity(
+        pool: PgPool,
+    ) {
+        let response = super::update_stock_targets(
+                &pool,
+                CharacterId(1),
+                CorporationId(1),
+                "test.starfoundry.space".into(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                UpdateStockTargets {
+                    restock: StockTargetRestockMode::Propose,
+                    targets: vec![
+                        StockTargetEntry {
+                            type_id:     TypeId(4246),
+                            location_id: LocationId(1046664001931),
+                            quantity:    0,
+                        },
+                    ],
+                },
+            )
+            .await;
+        assert!(matches!(response, Err(StockTargetError::IndustryLibError(_))));
+    }
+}
@@ -0,0 +1,84 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_industry::stock_target::UpdateStockTargets;
+
+use crate::AppState;
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
+use crate::stock_target::error::Result;
+use crate::stock_target::service::{check_stock_targets, update_stock_targets};
+
+/// Update Stock Targets
+/// 
+/// - Alternative route: `/latest/stock-targets/{ProjectGroupUuid}`
+/// - Alternative route: `/v1/stock-targets/{ProjectGroupUuid}`
+/// 
+/// ---
+/// 
+/// Replaces the stock targets of the project group.
+/// The targets are compared with the synced assets of the character and its
+/// corporation, right away and afterwards every hour.
+/// With `restock` set to `CREATE` a restock project is created for the
+/// shortfall after every check, with `PROPOSE` only the shortfall is shown.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write
+/// 
+#[utoipa::path(
+    put,
+    path = "/{ProjectGroupUuid}",
+    tag = "Stock Targets",
+    request_body = UpdateStockTargets,
+    params(
+        ProjectGroupUuid,
+    ),
+    responses(
+        (
+            description = "The stock targets were updated",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnsupportedMediaType,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:               ExtractIdentity,
+    State(state):           State<AppState>,
+    Path(project_group_id): Path<ProjectGroupUuid>,
+    Json(update):           Json<UpdateStockTargets>,
+) -> Result<impl IntoResponse> {
+    update_stock_targets(
+            &state.postgres,
+            identity.character_id,
+            identity.corporation_id,
+            identity.host()?,
+            project_group_id,
+            update,
+        )
+        .await?;
+
+    check_stock_targets(
+            &state.postgres,
+            project_group_id,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+        )
+        .into_response()
+    )
+}
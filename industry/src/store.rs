@@ -0,0 +1,60 @@
+mod cancel_order;
+mod confirm_order;
+mod create_quote;
+mod create_store;
+mod deliver_order;
+mod error;
+mod list_orders;
+mod list_store_orders;
+mod list_stores;
+mod service;
+mod update_store;
+
+use starfoundry_lib_types::starfoundry_uuid;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use crate::AppState;
+
+pub fn routes() -> OpenApiRouter<AppState> {
+    let create_store = OpenApiRouter::new()
+        .routes(routes!(create_store::api));
+
+    let list_stores = OpenApiRouter::new()
+        .routes(routes!(list_stores::api));
+
+    let update_store = OpenApiRouter::new()
+        .routes(routes!(update_store::api));
+
+    let create_quote = OpenApiRouter::new()
+        .routes(routes!(create_quote::api));
+
+    let list_orders = OpenApiRouter::new()
+        .routes(routes!(list_orders::api));
+
+    let confirm_order = OpenApiRouter::new()
+        .routes(routes!(confirm_order::api));
+
+    let cancel_order = OpenApiRouter::new()
+        .routes(routes!(cancel_order::api));
+
+    let list_store_orders = OpenApiRouter::new()
+        .routes(routes!(list_store_orders::api));
+
+    let deliver_order = OpenApiRouter::new()
+        .routes(routes!(deliver_order::api));
+
+    OpenApiRouter::new()
+        .merge(create_store)
+        .merge(list_stores)
+        .merge(update_store)
+        .merge(create_quote)
+        .merge(list_orders)
+        .merge(confirm_order)
+        .merge(cancel_order)
+        .merge(list_store_orders)
+        .merge(deliver_order)
+}
+
+starfoundry_uuid!(StoreUuid, "StoreUuid");
+starfoundry_uuid!(StoreOrderUuid, "StoreOrderUuid");
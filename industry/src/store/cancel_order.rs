@@ -0,0 +1,60 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::AppState;
+use crate::api_docs::{InternalServerError, NotFound, Unauthorized, UnprocessableEntity};
+use crate::store::error::Result;
+use crate::store::service::cancel_order;
+use crate::store::StoreOrderUuid;
+
+/// Cancel Order
+/// 
+/// - Alternative route: `/latest/stores/orders/{StoreOrderUuid}/cancel`
+/// - Alternative route: `/v1/stores/orders/{StoreOrderUuid}/cancel`
+/// 
+/// ---
+/// 
+/// Declines the quote, confirmed orders can't be cancelled
+/// 
+/// ## Security
+/// - authenticated
+/// - the character requested the quote
+/// 
+#[utoipa::path(
+    put,
+    path = "/orders/{StoreOrderUuid}/cancel",
+    tag = "store",
+    params(
+        StoreOrderUuid,
+    ),
+    responses(
+        (
+            description = "The quote was cancelled",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:        ExtractIdentity,
+    State(state):    State<AppState>,
+    Path(order_id):  Path<StoreOrderUuid>,
+) -> Result<impl IntoResponse> {
+    cancel_order(
+        &state.postgres,
+        identity.character_id,
+        order_id,
+    ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
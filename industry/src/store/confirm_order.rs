@@ -0,0 +1,80 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::project::CreateProjectResponse;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{InternalServerError, NotFound, Unauthorized, UnprocessableEntity};
+use crate::store::error::Result;
+use crate::store::service::confirm_order;
+use crate::store::StoreOrderUuid;
+use crate::tag::service::apply_auto_tags;
+
+/// Confirm Order
+/// 
+/// - Alternative route: `/latest/stores/orders/{StoreOrderUuid}/confirm`
+/// - Alternative route: `/v1/stores/orders/{StoreOrderUuid}/confirm`
+/// 
+/// ---
+/// 
+/// Confirms the quote before it expires.
+/// A project is created in the project group of the store, its status is
+/// visible in the orders of the customer until the order is delivered.
+/// 
+/// ## Security
+/// - authenticated
+/// - the character requested the quote
+/// 
+#[utoipa::path(
+    put,
+    path = "/orders/{StoreOrderUuid}/confirm",
+    tag = "store",
+    params(
+        StoreOrderUuid,
+    ),
+    responses(
+        (
+            body = CreateProjectResponse,
+            description = "The project that builds the order",
+            status = OK,
+        ),
+        Unauthorized,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:        ExtractIdentity,
+    State(state):    State<AppState>,
+    Path(order_id):  Path<StoreOrderUuid>,
+) -> Result<impl IntoResponse> {
+    let eve_gateway_api_client = eve_gateway_api_client()?;
+    let id = confirm_order(
+            &state.postgres,
+            &eve_gateway_api_client,
+            identity.character_id,
+            order_id,
+        )
+        .await?;
+
+    // the project is already stored, so a failure only leaves the automatic
+    // tags outdated until the next change
+    if let Err(e) = apply_auto_tags(&state.postgres, &eve_gateway_api_client, id).await {
+        tracing::error!("error while applying automatic tags to project '{}', error: '{}'", id, e);
+    }
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(CreateProjectResponse {
+                id,
+            }),
+        )
+    )
+}
@@ -0,0 +1,83 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, NotFound, TooManyRequests, Unauthorized, UnprocessableEntity};
+use crate::store::error::Result;
+use crate::store::service::{CreateQuote, StoreOrder, create_quote};
+use crate::store::StoreUuid;
+
+/// Create Quote
+/// 
+/// - Alternative route: `/latest/stores/{StoreUuid}/quotes`
+/// - Alternative route: `/v1/stores/{StoreUuid}/quotes`
+/// 
+/// ---
+/// 
+/// Calculates the given products with the job planner of the store.
+/// Instead of an item list, `products_str` can also contain fits in the EFT,
+/// DNA or XML format, modules with a faction or similar meta group are bought.
+/// The cheapest solution is used, the price is the build cost plus the margin
+/// of the store.
+/// The quote can be confirmed until `valid_until`.
+/// 
+/// Disabled stores don't accept quotes.
+/// Stores that are not public only accept quotes from characters with read
+/// access to the project group of the store.
+/// A character can request up to 10 quotes per hour over all stores.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:read, if the store is not public
+/// 
+#[utoipa::path(
+    post,
+    path = "/{StoreUuid}/quotes",
+    tag = "store",
+    params(
+        StoreUuid,
+    ),
+    request_body = CreateQuote,
+    responses(
+        (
+            body = StoreOrder,
+            description = "The new quote",
+            status = CREATED,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        TooManyRequests,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:        ExtractIdentity,
+    State(state):    State<AppState>,
+    Path(store_id):  Path<StoreUuid>,
+    Json(quote):     Json<CreateQuote>,
+) -> Result<impl IntoResponse> {
+    let quote = create_quote(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            (&identity).into(),
+            store_id,
+            quote,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::CREATED,
+            Json(quote),
+        )
+    )
+}
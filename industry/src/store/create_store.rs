@@ -0,0 +1,82 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use serde::Serialize;
+use starfoundry_lib_gateway::ExtractIdentity;
+use utoipa::ToSchema;
+
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, Unauthorized, UnprocessableEntity};
+use crate::AppState;
+use crate::store::error::Result;
+use crate::store::service::{CreateStore, create_store};
+use crate::store::StoreUuid;
+
+/// Create Store
+/// 
+/// - Alternative route: `/latest/stores`
+/// - Alternative route: `/v1/stores`
+/// 
+/// ---
+/// 
+/// Creates a new store for a project group.
+/// Customers can request quotes for products, the quote is calculated with
+/// the job planner of the project group plus the margin of the store.
+/// Confirmed orders become projects of the group that are owned by the
+/// creator of the store.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_project
+/// 
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "store",
+    request_body = CreateStore,
+    responses(
+        (
+            body = CreateStoreResponse,
+            description = "Creates a new store",
+            status = CREATED,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+    Json(store):  Json<CreateStore>,
+) -> Result<impl IntoResponse> {
+    let id = create_store(
+            &state.postgres,
+            (&identity).into(),
+            store,
+        ).await?;
+
+    Ok(
+        (
+            StatusCode::CREATED,
+            Json(CreateStoreResponse {
+                id,
+            })
+        )
+    )
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "id": "fd324c9f-ecda-49c8-948e-18f4b4b23bff"
+    })
+)]
+pub struct CreateStoreResponse {
+    id: StoreUuid,
+}
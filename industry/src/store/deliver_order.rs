@@ -0,0 +1,63 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::AppState;
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity};
+use crate::store::error::Result;
+use crate::store::service::deliver_order;
+use crate::store::{StoreOrderUuid, StoreUuid};
+
+/// Deliver Order
+/// 
+/// - Alternative route: `/latest/stores/{StoreUuid}/orders/{StoreOrderUuid}/deliver`
+/// - Alternative route: `/v1/stores/{StoreUuid}/orders/{StoreOrderUuid}/deliver`
+/// 
+/// ---
+/// 
+/// Marks a confirmed order as delivered to the customer
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_project
+/// 
+#[utoipa::path(
+    put,
+    path = "/{StoreUuid}/orders/{StoreOrderUuid}/deliver",
+    tag = "store",
+    params(
+        StoreUuid,
+        StoreOrderUuid,
+    ),
+    responses(
+        (
+            description = "The order was delivered",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:                   ExtractIdentity,
+    State(state):               State<AppState>,
+    Path((store_id, order_id)): Path<(StoreUuid, StoreOrderUuid)>,
+) -> Result<impl IntoResponse> {
+    deliver_order(
+        &state.postgres,
+        (&identity).into(),
+        store_id,
+        order_id,
+    ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
@@ -0,0 +1,145 @@
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use starfoundry_lib_gateway::{ErrorResponse, boxed_from};
+use starfoundry_lib_types::CharacterId;
+use thiserror::Error;
+
+use crate::industry::IndustryError;
+use crate::project::error::ProjectError;
+use crate::project_group::ProjectGroupError;
+use crate::store::{StoreOrderUuid, StoreUuid};
+
+pub type Result<T, E = StoreError> = std::result::Result<T, E>;
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum StoreError {
+    #[error("store with id '{0}' not found")]
+    NotFound(StoreUuid),
+    #[error("store order with id '{0}' not found")]
+    OrderNotFound(StoreOrderUuid),
+    #[error("the store '{0}' can't build the requested products")]
+    NoSolution(StoreUuid),
+    #[error("validation error: '{0}'")]
+    ValidationError(String),
+    #[error("the store '{0}' doesn't accept new quotes")]
+    Disabled(StoreUuid),
+    #[error("character '{0}' requested too many quotes, try again later")]
+    TooManyQuotes(CharacterId),
+
+    #[error("error while listing stores, error: '{0}'")]
+    List(sqlx::Error),
+    #[error("error while fetching store '{1}', error: '{0}'")]
+    Fetch(sqlx::Error, StoreUuid),
+    #[error("error while creating store, error: '{0}'")]
+    Create(sqlx::Error),
+    #[error("error while updating store '{1}', error: '{0}'")]
+    Update(sqlx::Error, StoreUuid),
+
+    #[error("error while listing store orders, error: '{0}'")]
+    ListOrders(sqlx::Error),
+    #[error("error while fetching store order '{1}', error: '{0}'")]
+    FetchOrder(sqlx::Error, StoreOrderUuid),
+    #[error("error while creating quote for store '{1}', error: '{0}'")]
+    CreateQuote(sqlx::Error, StoreUuid),
+    #[error("error while counting quotes of character '{1}', error: '{0}'")]
+    CountQuotes(sqlx::Error, CharacterId),
+    #[error("error while deleting unused solutions of store '{1}', error: '{0}'")]
+    DeleteSolutions(sqlx::Error, StoreUuid),
+    #[error("error while updating store order '{1}', error: '{0}'")]
+    UpdateOrder(sqlx::Error, StoreOrderUuid),
+
+    #[error("transaction error, '{0}'")]
+    TransactionError(sqlx::Error),
+
+    #[error(transparent)]
+    AuditError(Box<crate::audit::AuditError>),
+    #[error(transparent)]
+    EveGatewayLibError(#[from] starfoundry_lib_eve_gateway::Error),
+    #[error(transparent)]
+    ProjectGroupError(Box<ProjectGroupError>),
+    #[error(transparent)]
+    ProjectError(Box<ProjectError>),
+    #[error(transparent)]
+    IndustryError(Box<IndustryError>),
+}
+
+impl IntoResponse for StoreError {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(_) |
+            Self::OrderNotFound(_) => {
+                tracing::info!("{}", self.to_string());
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(
+                        ErrorResponse {
+                            error: "NOT_FOUND".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+
+            Self::NoSolution(_) |
+            Self::Disabled(_) |
+            Self::ValidationError(_) => {
+                tracing::info!("{}", self.to_string());
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    Json(
+                        ErrorResponse {
+                            error: "UNPROCESSABLE_ENTITY".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+
+            Self::TooManyQuotes(_) => {
+                tracing::info!("{}", self.to_string());
+                (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    Json(
+                        ErrorResponse {
+                            error: "TOO_MANY_REQUESTS".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            },
+
+            Self::ProjectGroupError(e) => {
+                ProjectGroupError::into_response(*e)
+            },
+
+            Self::ProjectError(e) => {
+                ProjectError::into_response(*e)
+            },
+
+            Self::IndustryError(e) => {
+                IndustryError::into_response(*e)
+            },
+
+            _ => {
+                tracing::error!("{}", self.to_string());
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(
+                        ErrorResponse {
+                            error: "UNKNOWN".into(),
+                            description: "An unknown error occurred, please try again later.".into(),
+                        }
+                    )
+                ).into_response()
+            },
+        }
+        .into_response()
+    }
+}
+
+boxed_from!(StoreError::AuditError, crate::audit::AuditError);
+boxed_from!(StoreError::ProjectGroupError, ProjectGroupError);
+boxed_from!(StoreError::ProjectError, ProjectError);
+boxed_from!(StoreError::IndustryError, IndustryError);
@@ -0,0 +1,20 @@
+INSERT INTO project_group (id, owner, name, description) VALUES
+('00000000-0000-0000-0000-000000000001', 1, 'First', 'Description');
+
+INSERT INTO project_group_member (project_group_id, character_id, permission) VALUES
+('00000000-0000-0000-0000-000000000001', 1, 1),
+('00000000-0000-0000-0000-000000000001', 2, 2);
+
+INSERT INTO solution (id, industry_hub_id, project_group_id) VALUES
+('00000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000001');
+
+INSERT INTO solution_product (solution_id, type_id, quantity, material_efficiency) VALUES
+('00000000-0000-0000-0000-000000000001', 20185, 1, 10);
+
+INSERT INTO store (id, owner, project_group_id, name, margin) VALUES
+('00000000-0000-0000-0000-000000000001', 1, '00000000-0000-0000-0000-000000000001', 'Store', 10);
+
+INSERT INTO store_order (id, store_id, customer, status, solution_id, build_cost, price, valid_until) VALUES
+('00000000-0000-0000-0000-000000000101', '00000000-0000-0000-0000-000000000001', 2, 'QUOTED', '00000000-0000-0000-0000-000000000001', 100, 110, NOW() + INTERVAL '1 day'),
+('00000000-0000-0000-0000-000000000102', '00000000-0000-0000-0000-000000000001', 2, 'QUOTED', '00000000-0000-0000-0000-000000000001', 100, 110, NOW() - INTERVAL '1 hour'),
+('00000000-0000-0000-0000-000000000103', '00000000-0000-0000-0000-000000000001', 11, 'ORDERED', '00000000-0000-0000-0000-000000000001', 100, 110, NOW() - INTERVAL '1 day');
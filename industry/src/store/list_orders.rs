@@ -0,0 +1,74 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{InternalServerError, Unauthorized};
+use crate::store::error::Result;
+use crate::store::service::{StoreOrder, list_customer_orders};
+
+/// List Orders
+/// 
+/// - Alternative route: `/latest/stores/orders`
+/// - Alternative route: `/v1/stores/orders`
+/// 
+/// ---
+/// 
+/// Lists all quotes and orders of the character, newest first.
+/// Confirmed orders contain the status of the project that builds them.
+/// 
+/// ## Security
+/// - authenticated
+/// 
+#[utoipa::path(
+    get,
+    path = "/orders",
+    tag = "store",
+    responses(
+        (
+            body = Vec<StoreOrder>,
+            description = "All orders of the character",
+            status = OK,
+        ),
+        (
+            description = "The character has no orders",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse> {
+    let data = list_customer_orders(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            identity.character_id,
+        )
+        .await?;
+
+    if data.is_empty() {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(data),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(data),
+            )
+            .into_response()
+        )
+    }
+}
@@ -0,0 +1,91 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::project_group::permission::assert_read_access_check;
+use crate::store::error::Result;
+use crate::store::service::{StoreOrder, fetch_store, list_store_orders};
+use crate::store::StoreUuid;
+
+/// List Store Orders
+/// 
+/// - Alternative route: `/latest/stores/{StoreUuid}/orders`
+/// - Alternative route: `/v1/stores/{StoreUuid}/orders`
+/// 
+/// ---
+/// 
+/// Lists all quotes and orders of the store, newest first, including the
+/// build cost of the quotes
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{StoreUuid}/orders",
+    tag = "store",
+    params(
+        StoreUuid,
+    ),
+    responses(
+        (
+            body = Vec<StoreOrder>,
+            description = "All orders of the store",
+            status = OK,
+        ),
+        (
+            description = "The store has no orders",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:        ExtractIdentity,
+    State(state):    State<AppState>,
+    Path(store_id):  Path<StoreUuid>,
+) -> Result<impl IntoResponse> {
+    let store = fetch_store(&state.postgres, store_id).await?;
+    assert_read_access_check(
+            &state.postgres,
+            store.project_group_id,
+            (&identity).into(),
+        )
+        .await?;
+
+    let data = list_store_orders(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            store_id,
+        )
+        .await?;
+
+    if data.is_empty() {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(data),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(data),
+            )
+            .into_response()
+        )
+    }
+}
@@ -0,0 +1,74 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::api_docs::{InternalServerError, Unauthorized};
+use crate::AppState;
+use crate::store::error::Result;
+use crate::store::service::{Store, list_stores};
+
+/// List Stores
+/// 
+/// - Alternative route: `/latest/stores`
+/// - Alternative route: `/v1/stores`
+/// 
+/// ---
+/// 
+/// Lists all stores the character can see.
+/// Enabled public stores are listed for everyone, all other stores only for
+/// characters with read access to the project group of the store.
+/// 
+/// ## Security
+/// - authenticated
+/// 
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "store",
+    responses(
+        (
+            body = Vec<Store>,
+            description = "All stores",
+            status = OK,
+        ),
+        (
+            description = "There are no stores",
+            status = NO_CONTENT,
+        ),
+        Unauthorized,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:     ExtractIdentity,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse> {
+    let data = list_stores(
+            &state.postgres,
+            (&identity).into(),
+        )
+        .await?;
+
+    if data.is_empty() {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(data),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(data),
+            )
+            .into_response()
+        )
+    }
+}
@@ -0,0 +1,15 @@
+mod create_quote;
+mod create_store;
+mod fetch_store;
+mod list_orders;
+mod list_stores;
+mod update_order;
+mod update_store;
+
+pub use self::create_quote::*;
+pub use self::create_store::*;
+pub use self::fetch_store::*;
+pub use self::list_orders::*;
+pub use self::list_stores::*;
+pub use self::update_order::*;
+pub use self::update_store::*;
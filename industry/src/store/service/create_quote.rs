@@ -0,0 +1,355 @@
+use serde::Deserialize;
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::industry::{BuildEngine, BuildEngineProduct, FittingImport};
+use starfoundry_lib_types::StructureId;
+use utoipa::ToSchema;
+
+use crate::industry::{calculate_as, is_fit};
+use crate::project_group::permission::{assert_read_access_check, MemberIdentity};
+use crate::project_group::service::{fetch_solution_summaries, list_default_market};
+use crate::store::StoreUuid;
+use crate::store::error::{StoreError, Result};
+use crate::store::service::{StoreOrder, StoreOrderStatus, fetch_store};
+
+/// Number of quotes a character can request per hour over all stores, every
+/// quote runs the job planner of the store
+const MAX_QUOTES_PER_HOUR: i64 = 10;
+
+/// Calculates the products with the job planner of the store and stores the
+/// cheapest solution as quote for the customer.
+///
+/// The calculation runs in the name of the store owner, with the defaults
+/// of the project group and market prices of its default markets.
+/// The price of the quote is the build cost plus the margin of the store.
+/// Only the solution of the quote is kept, the solutions of the other
+/// industry hubs are removed again.
+/// Fits in `products_str` are expanded into their hull and modules, modules
+/// that are usually bought are added as additional products.
+///
+/// # Errors
+///
+/// - [StoreError::Disabled] if the store doesn't accept new quotes
+/// - [StoreError::ProjectGroupError] if the store isn't public and the
+///   customer can't read the project group
+/// - [StoreError::TooManyQuotes] if the customer requested more than
+///   [MAX_QUOTES_PER_HOUR] quotes within the last hour
+/// - [StoreError::NoSolution] if none of the industry hubs can build the
+///   products
+///
+pub async fn create_quote(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    identity:               MemberIdentity,
+    store_id:               StoreUuid,
+    quote:                  CreateQuote,
+) -> Result<StoreOrder> {
+    quote.validate()?;
+    let customer = identity.character_id;
+    let store = fetch_store(pool, store_id).await?;
+
+    if !store.enabled {
+        return Err(StoreError::Disabled(store_id));
+    }
+
+    if !store.public {
+        assert_read_access_check(
+                pool,
+                store.project_group_id,
+                identity,
+            )
+            .await?;
+    }
+
+    let recent_quotes = sqlx::query!(r#"
+            SELECT COUNT(*) AS "count!"
+            FROM store_order
+            WHERE customer = $1
+            AND created_at > NOW() - INTERVAL '1 hour'
+        "#,
+            *customer,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|x| x.count)
+        .map_err(|e| StoreError::CountQuotes(e, customer))?;
+
+    if recent_quotes >= MAX_QUOTES_PER_HOUR {
+        return Err(StoreError::TooManyQuotes(customer));
+    }
+
+    let markets = list_default_market(
+            pool,
+            store.owner,
+            eve_gateway_api_client,
+            store.project_group_id,
+        )
+        .await?
+        .into_iter()
+        .map(|x| StructureId(x.structure_id))
+        .collect::<Vec<_>>();
+
+    let (products_str, fittings) = match quote.products_str {
+        Some(x) if quote.products.is_none() && is_fit(&x) => {
+            let fitting = FittingImport {
+                fit:        Some(x),
+                fitting_id: None,
+                multiplier: 1,
+                buy:        Vec::new(),
+                build:      Vec::new(),
+            };
+            (None, Some(vec![fitting]))
+        },
+        x => (x, None),
+    };
+
+    let solution_ids = calculate_as(
+            pool,
+            store.owner,
+            None,
+            BuildEngine {
+                project_group_id:        store.project_group_id,
+                products:                quote.products,
+                products_str,
+                additional_products:     None,
+                additional_products_str: None,
+                fittings,
+                stocks:                  None,
+                stocks_str:              None,
+                stocks_from_assets:      None,
+                stock_location_id:       None,
+                project_id:              None,
+                blacklist:               None,
+                blueprint_overwrite:     None,
+                job_splitting:           None,
+                markets:                 Some(markets),
+                calculate_market_cost:   Some(true),
+                combine_industry_hubs:   None,
+                freight_rate:            None,
+                industry_hubs:           None,
+                expand_planetary:        None,
+                customs_office_tax:      None,
+            },
+        )
+        .await?
+        .into_iter()
+        .map(|x| x.solution_id)
+        .collect::<Vec<_>>();
+
+    if solution_ids.is_empty() {
+        return Err(StoreError::NoSolution(store_id));
+    }
+
+    let solution = fetch_solution_summaries(
+            pool,
+            eve_gateway_api_client,
+            store.project_group_id,
+            Some(solution_ids.clone()),
+        )
+        .await?
+        .into_iter()
+        .min_by(|a, b| a.cost.total.total_cmp(&b.cost.total))
+        .ok_or(StoreError::NoSolution(store_id))?;
+
+    let build_cost = solution.cost.total;
+    let price = build_cost * (1f64 + store.margin / 100f64);
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(StoreError::TransactionError)?;
+
+    sqlx::query!("
+            DELETE FROM solution
+            WHERE id = ANY($1)
+            AND id != $2
+        ",
+            &solution_ids.into_iter().map(|x| *x).collect::<Vec<_>>(),
+            *solution.id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StoreError::DeleteSolutions(e, store_id))?;
+
+    let order = sqlx::query!("
+            INSERT INTO store_order
+            (
+                store_id,
+                customer,
+                solution_id,
+                build_cost,
+                price,
+                valid_until
+            )
+            VALUES ($1, $2, $3, $4, $5, NOW() + make_interval(hours => $6))
+            RETURNING id, valid_until, created_at
+        ",
+            *store_id,
+            *customer,
+            *solution.id,
+            build_cost,
+            price,
+            store.quote_validity,
+        )
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|e| StoreError::CreateQuote(e, store_id))?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(StoreError::TransactionError)?;
+
+    Ok(StoreOrder {
+        id:             order.id.into(),
+        store_id,
+        store_name:     store.name,
+        customer,
+        status:         StoreOrderStatus::Quoted,
+        products:       solution.products,
+        build_cost:     None,
+        price,
+        valid_until:    order.valid_until,
+        project_id:     None,
+        project_status: None,
+        created_at:     order.created_at,
+    })
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(
+    example = json!({
+        "products_str": "Nyx 1"
+    })
+)]
+pub struct CreateQuote {
+    #[serde(default)]
+    pub products:     Option<Vec<BuildEngineProduct>>,
+    /// Item list or a pasted fit in the EFT, DNA or XML format, only used if
+    /// `products` is not set
+    #[serde(default)]
+    pub products_str: Option<String>,
+}
+
+impl CreateQuote {
+    pub fn validate(&self) -> Result<()> {
+        let has_products = self
+            .products
+            .as_ref()
+            .map(|x| !x.is_empty())
+            .unwrap_or_default();
+        let has_products_str = self
+            .products_str
+            .as_ref()
+            .map(|x| !x.trim().is_empty())
+            .unwrap_or_default();
+
+        if !has_products && !has_products_str {
+            return Err(StoreError::ValidationError(
+                "either 'products' or 'products_str' must be set".into()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+    use uuid::Uuid;
+
+    use crate::eve_gateway_api_client;
+    use crate::project_group::permission::MemberIdentity;
+    use crate::store::error::StoreError;
+    use crate::store::service::{CreateQuote, create_quote};
+    use crate::store::StoreUuid;
+
+    fn store_id() -> StoreUuid {
+        Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into()
+    }
+
+    fn quote() -> CreateQuote {
+        CreateQuote {
+            products:     None,
+            products_str: Some("Nyx 1".into()),
+        }
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn only_enabled_and_visible_stores(
+        pool: PgPool,
+    ) {
+        let result = create_quote(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                MemberIdentity::new(CharacterId(11), CorporationId(11), None),
+                store_id(),
+                quote(),
+            )
+            .await;
+        assert!(matches!(result, Err(StoreError::ProjectGroupError(_))));
+
+        sqlx::query!(r#"
+                UPDATE store
+                SET enabled = FALSE
+            "#)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let result = create_quote(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                MemberIdentity::new(CharacterId(2), CorporationId(1), None),
+                store_id(),
+                quote(),
+            )
+            .await;
+        assert!(matches!(result, Err(StoreError::Disabled(_))));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn rate_limited(
+        pool: PgPool,
+    ) {
+        // the fixture already contains quotes of the customer
+        sqlx::query!(r#"
+                INSERT INTO store_order (store_id, customer, solution_id, build_cost, price, valid_until)
+                SELECT
+                    '00000000-0000-0000-0000-000000000001',
+                    2,
+                    '00000000-0000-0000-0000-000000000001',
+                    100,
+                    110,
+                    NOW()
+                FROM generate_series(1, 8)
+            "#)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let result = create_quote(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                MemberIdentity::new(CharacterId(2), CorporationId(1), None),
+                store_id(),
+                quote(),
+            )
+            .await;
+        assert!(matches!(result, Err(StoreError::TooManyQuotes(_))));
+    }
+}
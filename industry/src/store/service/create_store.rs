@@ -0,0 +1,245 @@
+use serde::Deserialize;
+use sqlx::PgPool;
+use starfoundry_lib_industry::ProjectGroupUuid;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use utoipa::ToSchema;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project_group::permission::{assert_write_access_check, MemberIdentity};
+use crate::store::StoreUuid;
+use crate::store::error::{StoreError, Result};
+
+/// Creates a new store for the project group.
+///
+/// The character becomes the owner of all projects that are created from
+/// confirmed orders, and therefore requires write access to the group.
+///
+pub async fn create_store(
+    pool:     &PgPool,
+    identity: MemberIdentity,
+    store:    CreateStore,
+) -> Result<StoreUuid> {
+    store.validate()?;
+
+    assert_write_access_check(
+            pool,
+            store.project_group_id,
+            identity,
+            ProjectGroupPermissionCode::WriteProject,
+        )
+        .await?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(StoreError::TransactionError)?;
+
+    let store_id: StoreUuid = sqlx::query!("
+            INSERT INTO store
+            (
+                owner,
+                project_group_id,
+                name,
+                description,
+                margin,
+                quote_validity,
+                enabled,
+                public
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id
+        ",
+            *identity.character_id,
+            *store.project_group_id,
+            store.name,
+            store.description,
+            store.margin,
+            store.quote_validity,
+            store.enabled,
+            store.public,
+        )
+        .fetch_one(&mut *transaction)
+        .await
+        .map(|x| x.id.into())
+        .map_err(StoreError::Create)?;
+
+    AuditRecord::created(
+            &mut transaction,
+            identity.character_id,
+            AuditTarget::Store(store_id),
+        )
+        .await?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(StoreError::TransactionError)?;
+
+    Ok(store_id)
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(
+    example = json!({
+        "project_group_id": "019a0e27-5e9c-7a53-8c52-e4ecb1ec0a77",
+        "name": "Capital Store",
+        "description": "Capitals delivered to 1DQ1-A",
+        "margin": 10,
+        "quote_validity": 24,
+        "enabled": true,
+        "public": false
+    })
+)]
+pub struct CreateStore {
+    /// Group the quotes are calculated with and the projects are created in
+    pub project_group_id: ProjectGroupUuid,
+    pub name:             String,
+    #[serde(default)]
+    pub description:      Option<String>,
+    /// Margin in percent that is added to the build cost
+    #[serde(default)]
+    pub margin:           f64,
+    /// Hours a quote can be confirmed, defaults to 24 hours
+    #[serde(default = "default_quote_validity")]
+    pub quote_validity:   i32,
+    /// Disabled stores don't accept new quotes, defaults to enabled
+    #[serde(default = "default_enabled")]
+    pub enabled:          bool,
+    /// Public stores accept quotes from everyone, all others only from
+    /// characters that can read the project group
+    #[serde(default)]
+    pub public:           bool,
+}
+
+impl CreateStore {
+    pub fn validate(&self) -> Result<()> {
+        validate_store(&self.name, self.margin, self.quote_validity)
+    }
+}
+
+/// Validates the fields that are shared between creating and updating a
+/// store
+pub(crate) fn validate_store(
+    name:           &str,
+    margin:         f64,
+    quote_validity: i32,
+) -> Result<()> {
+    if name.trim().is_empty() {
+        return Err(StoreError::ValidationError(
+            "Field 'name' must be set".into()
+        ));
+    }
+
+    if name.len() > 100 {
+        return Err(StoreError::ValidationError(
+            "Field 'name' is too long, max length: 100".into()
+        ));
+    }
+
+    if !margin.is_finite() || margin < 0f64 {
+        return Err(StoreError::ValidationError(
+            "the margin must be positive".into()
+        ));
+    }
+
+    if quote_validity <= 0 {
+        return Err(StoreError::ValidationError(
+            "the quote validity must be at least one hour".into()
+        ));
+    }
+
+    Ok(())
+}
+
+fn default_quote_validity() -> i32 {
+    24
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+
+    use crate::project_group::permission::MemberIdentity;
+    use crate::store::error::StoreError;
+    use crate::store::service::{CreateStore, create_store};
+
+    fn store(
+        margin: f64,
+    ) -> CreateStore {
+        serde_json::from_value(serde_json::json!({
+                "project_group_id": "00000000-0000-0000-0000-000000000001",
+                "name": "Capital Store",
+                "margin": margin
+            }))
+            .unwrap()
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn create_requires_write_access(
+        pool: PgPool,
+    ) {
+        let result = create_store(
+                &pool,
+                MemberIdentity::new(CharacterId(2), CorporationId(2), None),
+                store(10f64),
+            )
+            .await;
+        assert!(matches!(result, Err(StoreError::ProjectGroupError(_))));
+
+        let result = create_store(
+                &pool,
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                store(-1f64),
+            )
+            .await;
+        assert!(matches!(result, Err(StoreError::ValidationError(_))));
+
+        let id = create_store(
+                &pool,
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                store(10f64),
+            )
+            .await
+            .unwrap();
+
+        let entry = sqlx::query!("
+                SELECT owner, quote_validity
+                FROM store
+                WHERE id = $1
+            ",
+                *id,
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(entry.owner, 1);
+        assert_eq!(entry.quote_validity, 24);
+
+        let audit = sqlx::query!(r#"
+                SELECT project_group_id
+                FROM audit_event
+                WHERE entity_type = 'STORE'
+                AND entity_id = $1
+                AND action = 'CREATE'
+            "#,
+                *id,
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            audit.project_group_id.map(|x| x.to_string()),
+            Some("00000000-0000-0000-0000-000000000001".into()),
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use starfoundry_lib_industry::{ProjectGroupUuid, SolutionUuid};
+use starfoundry_lib_types::CharacterId;
+use utoipa::ToSchema;
+
+use crate::store::{StoreOrderUuid, StoreUuid};
+use crate::store::error::{StoreError, Result};
+use crate::store::service::StoreOrderStatus;
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "id": "019a0e27-5e9c-7a53-8c52-e4ecae0b12f4",
+        "owner": 2117441999,
+        "project_group_id": "019a0e27-5e9c-7a53-8c52-e4ecb1ec0a77",
+        "name": "Capital Store",
+        "description": "Capitals delivered to 1DQ1-A",
+        "margin": 10,
+        "quote_validity": 24,
+        "enabled": true,
+        "public": false
+    })
+)]
+pub struct Store {
+    pub id:               StoreUuid,
+    /// Character the projects of confirmed orders are created for
+    pub owner:            CharacterId,
+    pub project_group_id: ProjectGroupUuid,
+    pub name:             String,
+    pub description:      Option<String>,
+    /// Margin in percent that is added to the build cost
+    pub margin:           f64,
+    /// Hours a quote can be confirmed
+    pub quote_validity:   i32,
+    /// Disabled stores don't accept new quotes
+    pub enabled:          bool,
+    /// Public stores accept quotes from everyone, all others only from
+    /// characters that can read the project group
+    pub public:           bool,
+}
+
+pub async fn fetch_store(
+    pool:     &PgPool,
+    store_id: StoreUuid,
+) -> Result<Store> {
+    sqlx::query!("
+            SELECT
+                id,
+                owner,
+                project_group_id,
+                name,
+                description,
+                margin,
+                quote_validity,
+                enabled,
+                public
+            FROM store
+            WHERE id = $1
+        ",
+            *store_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| StoreError::Fetch(e, store_id))?
+        .map(|x| Store {
+            id:               x.id.into(),
+            owner:            x.owner.into(),
+            project_group_id: x.project_group_id.into(),
+            name:             x.name,
+            description:      x.description,
+            margin:           x.margin,
+            quote_validity:   x.quote_validity,
+            enabled:          x.enabled,
+            public:           x.public,
+        })
+        .ok_or(StoreError::NotFound(store_id))
+}
+
+/// Header of a single quote or order
+#[derive(Clone, Debug)]
+pub struct StoreOrderHeader {
+    pub id:          StoreOrderUuid,
+    pub store_id:    StoreUuid,
+    pub customer:    CharacterId,
+    pub status:      StoreOrderStatus,
+    pub solution_id: SolutionUuid,
+    pub price:       f64,
+    pub valid_until: DateTime<Utc>,
+}
+
+impl StoreOrderHeader {
+    /// Orders are only visible to the customer that requested them, for
+    /// everybody else they don't exist
+    pub fn assert_customer(
+        &self,
+        character_id: CharacterId,
+    ) -> Result<()> {
+        if self.customer == character_id {
+            Ok(())
+        } else {
+            Err(StoreError::OrderNotFound(self.id))
+        }
+    }
+
+    /// A quote can be confirmed or cancelled until it expires
+    pub fn assert_open_quote(&self) -> Result<()> {
+        if self.status != StoreOrderStatus::Quoted {
+            return Err(StoreError::ValidationError(
+                format!("order '{}' is not a quote", self.id)
+            ));
+        }
+
+        if self.valid_until <= Utc::now() {
+            return Err(StoreError::ValidationError(
+                format!("quote '{}' expired", self.id)
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+pub async fn fetch_order(
+    pool:     &PgPool,
+    order_id: StoreOrderUuid,
+) -> Result<StoreOrderHeader> {
+    sqlx::query!(r#"
+            SELECT
+                id,
+                store_id,
+                customer,
+                status AS "status: StoreOrderStatus",
+                solution_id,
+                price,
+                valid_until
+            FROM store_order
+            WHERE id = $1
+        "#,
+            *order_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| StoreError::FetchOrder(e, order_id))?
+        .map(|x| StoreOrderHeader {
+            id:          x.id.into(),
+            store_id:    x.store_id.into(),
+            customer:    x.customer.into(),
+            status:      x.status,
+            solution_id: x.solution_id.into(),
+            price:       x.price,
+            valid_until: x.valid_until,
+        })
+        .ok_or(StoreError::OrderNotFound(order_id))
+}
@@ -0,0 +1,216 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::project::ProjectStatus;
+use starfoundry_lib_industry::{ProjectGroupUuid, ProjectUuid, SolutionUuid};
+use starfoundry_lib_types::CharacterId;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::project_group::service::{SolutionProduct, fetch_solution_summaries};
+use crate::store::{StoreOrderUuid, StoreUuid};
+use crate::store::error::{StoreError, Result};
+
+/// Lists all quotes and orders of the customer, newest first
+pub async fn list_customer_orders(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    customer:               CharacterId,
+) -> Result<Vec<StoreOrder>> {
+    fetch_orders(
+            pool,
+            eve_gateway_api_client,
+            Some(customer),
+            None,
+        )
+        .await
+}
+
+/// Lists all quotes and orders of the store, newest first.
+///
+/// In contrast to the customer view, the build cost is included.
+///
+pub async fn list_store_orders(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    store_id:               StoreUuid,
+) -> Result<Vec<StoreOrder>> {
+    fetch_orders(
+            pool,
+            eve_gateway_api_client,
+            None,
+            Some(store_id),
+        )
+        .await
+}
+
+/// Fetches the orders filtered by customer and store.
+/// The build cost is only included when filtering by store
+pub(crate) async fn fetch_orders(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    customer:               Option<CharacterId>,
+    store_id:               Option<StoreUuid>,
+) -> Result<Vec<StoreOrder>> {
+    let orders = sqlx::query!(r#"
+            SELECT
+                so.id,
+                so.store_id,
+                s.name AS store_name,
+                s.project_group_id,
+                so.customer,
+                so.status AS "status: StoreOrderStatus",
+                so.solution_id,
+                so.project_id,
+                p.status AS "project_status?: ProjectStatus",
+                so.build_cost,
+                so.price,
+                so.valid_until,
+                so.created_at
+            FROM store_order so
+            JOIN store s ON s.id = so.store_id
+            LEFT JOIN project p ON p.id = so.project_id
+            WHERE ($1::INTEGER IS NULL OR so.customer = $1)
+            AND ($2::UUID IS NULL OR so.store_id = $2)
+            ORDER BY so.created_at DESC
+        "#,
+            customer.map(|x| *x),
+            store_id.map(|x| *x),
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(StoreError::ListOrders)?;
+
+    let mut solutions_by_group: HashMap<ProjectGroupUuid, Vec<SolutionUuid>> = HashMap::new();
+    for order in orders.iter() {
+        solutions_by_group
+            .entry(order.project_group_id.into())
+            .or_default()
+            .push(order.solution_id.into());
+    }
+
+    let mut products: HashMap<SolutionUuid, Vec<SolutionProduct>> = HashMap::new();
+    for (project_group_id, solution_ids) in solutions_by_group {
+        fetch_solution_summaries(
+                pool,
+                eve_gateway_api_client,
+                project_group_id,
+                Some(solution_ids),
+            )
+            .await?
+            .into_iter()
+            .for_each(|x| {
+                products.insert(x.id, x.products);
+            });
+    }
+
+    let orders = orders
+        .into_iter()
+        .map(|x| StoreOrder {
+            id:             x.id.into(),
+            store_id:       x.store_id.into(),
+            store_name:     x.store_name,
+            customer:       x.customer.into(),
+            status:         x.status,
+            products:       products
+                                .get(&x.solution_id.into())
+                                .cloned()
+                                .unwrap_or_default(),
+            build_cost:     store_id.map(|_| x.build_cost),
+            price:          x.price,
+            valid_until:    x.valid_until,
+            project_id:     x.project_id.map(Into::into),
+            project_status: x.project_status,
+            created_at:     x.created_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(orders)
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct StoreOrder {
+    pub id:             StoreOrderUuid,
+    pub store_id:       StoreUuid,
+    pub store_name:     String,
+    pub customer:       CharacterId,
+    pub status:         StoreOrderStatus,
+    pub products:       Vec<SolutionProduct>,
+    /// Cost of the solution the quote is based on, only visible to the store
+    pub build_cost:     Option<f64>,
+    /// Build cost including the margin of the store
+    pub price:          f64,
+    /// The quote must be confirmed before the date
+    pub valid_until:    DateTime<Utc>,
+    /// Project that builds the order, set once the order is confirmed
+    pub project_id:     Option<ProjectUuid>,
+    pub project_status: Option<ProjectStatus>,
+    pub created_at:     DateTime<Utc>,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq, PartialOrd, Ord,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "STORE_ORDER_STATUS")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StoreOrderStatus {
+    /// The customer received a price, but hasn't confirmed it yet
+    ///
+    Quoted,
+    /// The customer confirmed the quote, and the project was created
+    ///
+    Ordered,
+    /// The products were handed over to the customer
+    ///
+    Delivered,
+    /// The customer declined the quote
+    ///
+    Cancelled,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use starfoundry_lib_types::CharacterId;
+    use uuid::Uuid;
+
+    use crate::eve_gateway_api_client;
+    use crate::store::service::{list_customer_orders, list_store_orders};
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn build_cost_only_visible_to_store(
+        pool: PgPool,
+    ) {
+        let orders = list_customer_orders(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                CharacterId(2),
+            )
+            .await
+            .unwrap();
+        assert_eq!(orders.len(), 2);
+        assert!(orders.iter().all(|x| x.build_cost.is_none()));
+        assert!(orders.iter().all(|x| x.products.len() == 1));
+
+        let orders = list_store_orders(
+                &pool,
+                &eve_gateway_api_client().unwrap(),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(orders.len(), 3);
+        assert!(orders.iter().all(|x| x.build_cost == Some(100f64)));
+    }
+}
@@ -0,0 +1,112 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+
+use crate::project_group::permission::{fetch_permission, MemberIdentity};
+use crate::store::error::{StoreError, Result};
+use crate::store::service::Store;
+
+/// Lists all stores the character can see, sorted by name.
+///
+/// Enabled public stores are visible for everyone, all other stores only for
+/// characters that can read the project group of the store.
+///
+pub async fn list_stores(
+    pool:     &PgPool,
+    identity: MemberIdentity,
+) -> Result<Vec<Store>> {
+    let stores = sqlx::query!("
+            SELECT
+                id,
+                owner,
+                project_group_id,
+                name,
+                description,
+                margin,
+                quote_validity,
+                enabled,
+                public
+            FROM store
+            ORDER BY name
+        ")
+        .fetch_all(pool)
+        .await
+        .map_err(StoreError::List)?
+        .into_iter()
+        .map(|x| Store {
+            id:               x.id.into(),
+            owner:            x.owner.into(),
+            project_group_id: x.project_group_id.into(),
+            name:             x.name,
+            description:      x.description,
+            margin:           x.margin,
+            quote_validity:   x.quote_validity,
+            enabled:          x.enabled,
+            public:           x.public,
+        })
+        .collect::<Vec<_>>();
+
+    let mut visible = Vec::new();
+    for store in stores {
+        if store.enabled && store.public {
+            visible.push(store);
+            continue;
+        }
+
+        let permission = fetch_permission(
+                pool,
+                store.project_group_id,
+                identity,
+            )
+            .await?;
+
+        if permission.has(ProjectGroupPermissionCode::Read) {
+            visible.push(store);
+        }
+    }
+
+    Ok(visible)
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+
+    use crate::project_group::permission::MemberIdentity;
+    use crate::store::service::list_stores;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn only_public_or_readable_stores(
+        pool: PgPool,
+    ) {
+        let member = MemberIdentity::new(CharacterId(2), CorporationId(1), None);
+        let outsider = MemberIdentity::new(CharacterId(11), CorporationId(11), None);
+
+        assert_eq!(list_stores(&pool, member).await.unwrap().len(), 1);
+        assert!(list_stores(&pool, outsider).await.unwrap().is_empty());
+
+        sqlx::query!(r#"
+                UPDATE store
+                SET public = TRUE
+            "#)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(list_stores(&pool, outsider).await.unwrap().len(), 1);
+
+        sqlx::query!(r#"
+                UPDATE store
+                SET enabled = FALSE
+            "#)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(list_stores(&pool, outsider).await.unwrap().is_empty());
+        assert_eq!(list_stores(&pool, member).await.unwrap().len(), 1);
+    }
+}
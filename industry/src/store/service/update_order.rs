@@ -0,0 +1,354 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_industry::project::CreateProject;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use starfoundry_lib_types::CharacterId;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project::service::create_in_transaction;
+use crate::project_group::permission::{assert_write_access_check, MemberIdentity};
+use crate::store::{StoreOrderUuid, StoreUuid};
+use crate::store::error::{StoreError, Result};
+use crate::store::service::{StoreOrderStatus, fetch_order, fetch_store};
+
+/// Confirms the quote of the customer and creates the project in the project
+/// group of the store.
+///
+/// The project is owned by the store owner, initialized with the solution of
+/// the quote and uses the quoted price as sell price.
+/// The order and the project are changed in a single transaction, so that a
+/// failing project leaves the quote untouched.
+///
+pub async fn confirm_order(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    customer:               CharacterId,
+    order_id:               StoreOrderUuid,
+) -> Result<ProjectUuid> {
+    let order = fetch_order(pool, order_id).await?;
+    order.assert_customer(customer)?;
+    order.assert_open_quote()?;
+
+    let store = fetch_store(pool, order.store_id).await?;
+
+    let orderer = eve_gateway_api_client
+        .fetch_character(customer)
+        .await
+        .ok()
+        .flatten()
+        .map(|x| x.character_name)
+        .unwrap_or_else(|| customer.to_string());
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(StoreError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            customer,
+            AuditAction::Update,
+            AuditTarget::StoreOrder(order.store_id, order_id),
+        )
+        .await?;
+
+    // reserve the quote, so that confirming it twice doesn't create two
+    // projects
+    let result = sqlx::query!("
+            UPDATE store_order
+            SET status = 'ORDERED'
+            WHERE id = $1
+            AND status = 'QUOTED'
+            AND valid_until > NOW()
+        ",
+            *order_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StoreError::UpdateOrder(e, order_id))?;
+    if result.rows_affected() == 0 {
+        return Err(StoreError::ValidationError(
+            format!("order '{}' is not a quote", order_id)
+        ));
+    }
+
+    let project_id = create_in_transaction(
+            &mut transaction,
+            store.owner,
+            CreateProject {
+                project_group_id: store.project_group_id,
+                orderer,
+                name:             store.name,
+                sell_price:       Some(order.price),
+                notes:            Some(format!("Store order {}", order_id)),
+                pre_products:     None,
+                pre_additional:   None,
+                fittings:         None,
+                solution_id:      Some(order.solution_id),
+            },
+        )
+        .await?;
+
+    sqlx::query!("
+            UPDATE store_order
+            SET project_id = $2
+            WHERE id = $1
+        ",
+            *order_id,
+            *project_id,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StoreError::UpdateOrder(e, order_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(StoreError::TransactionError)?;
+
+    Ok(project_id)
+}
+
+/// Declines the quote, only quotes that are not confirmed yet can be
+/// cancelled
+pub async fn cancel_order(
+    pool:     &PgPool,
+    customer: CharacterId,
+    order_id: StoreOrderUuid,
+) -> Result<()> {
+    let order = fetch_order(pool, order_id).await?;
+    order.assert_customer(customer)?;
+
+    if order.status != StoreOrderStatus::Quoted {
+        return Err(StoreError::ValidationError(
+            format!("order '{}' is not a quote", order_id)
+        ));
+    }
+
+    set_status(
+            pool,
+            customer,
+            order.store_id,
+            order_id,
+            StoreOrderStatus::Quoted,
+            StoreOrderStatus::Cancelled,
+        )
+        .await
+}
+
+/// Marks a confirmed order as handed over to the customer.
+///
+/// The character requires write access to the project group of the store.
+///
+pub async fn deliver_order(
+    pool:     &PgPool,
+    identity: MemberIdentity,
+    store_id: StoreUuid,
+    order_id: StoreOrderUuid,
+) -> Result<()> {
+    let store = fetch_store(pool, store_id).await?;
+    assert_write_access_check(
+            pool,
+            store.project_group_id,
+            identity,
+            ProjectGroupPermissionCode::WriteProject,
+        )
+        .await?;
+
+    let order = fetch_order(pool, order_id).await?;
+    if order.store_id != store_id {
+        return Err(StoreError::OrderNotFound(order_id));
+    }
+
+    if order.status != StoreOrderStatus::Ordered {
+        return Err(StoreError::ValidationError(
+            format!("order '{}' is not confirmed", order_id)
+        ));
+    }
+
+    set_status(
+            pool,
+            identity.character_id,
+            store_id,
+            order_id,
+            StoreOrderStatus::Ordered,
+            StoreOrderStatus::Delivered,
+        )
+        .await
+}
+
+/// Moves the order from one status to the next, the change is only applied
+/// if the order still has the expected status
+async fn set_status(
+    pool:         &PgPool,
+    character_id: CharacterId,
+    store_id:     StoreUuid,
+    order_id:     StoreOrderUuid,
+    from:         StoreOrderStatus,
+    to:           StoreOrderStatus,
+) -> Result<()> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(StoreError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::StoreOrder(store_id, order_id),
+        )
+        .await?;
+
+    sqlx::query!("
+            UPDATE store_order
+            SET status = $3
+            WHERE id = $1
+            AND status = $2
+        ",
+            *order_id,
+            from as _,
+            to as _,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StoreError::UpdateOrder(e, order_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(StoreError::TransactionError)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+    use uuid::Uuid;
+
+    use crate::eve_gateway_api_client;
+    use crate::project_group::permission::MemberIdentity;
+    use crate::store::error::StoreError;
+    use crate::store::service::{cancel_order, confirm_order, deliver_order};
+    use crate::store::{StoreOrderUuid, StoreUuid};
+
+    fn order_id(
+        id: &str,
+    ) -> StoreOrderUuid {
+        Uuid::from_str(id).unwrap().into()
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn confirm_only_own_open_quotes(
+        pool: PgPool,
+    ) {
+        let quote = order_id("00000000-0000-0000-0000-000000000101");
+        let result = confirm_order(&pool, &eve_gateway_api_client().unwrap(), CharacterId(11), quote).await;
+        assert!(matches!(result, Err(StoreError::OrderNotFound(_))));
+
+        let expired = order_id("00000000-0000-0000-0000-000000000102");
+        let result = confirm_order(&pool, &eve_gateway_api_client().unwrap(), CharacterId(2), expired).await;
+        assert!(matches!(result, Err(StoreError::ValidationError(_))));
+
+        let ordered = order_id("00000000-0000-0000-0000-000000000103");
+        let result = confirm_order(&pool, &eve_gateway_api_client().unwrap(), CharacterId(11), ordered).await;
+        assert!(matches!(result, Err(StoreError::ValidationError(_))));
+
+        let order = sqlx::query!(r#"
+                SELECT status::TEXT AS "status!", project_id
+                FROM store_order
+                WHERE id = $1
+            "#,
+                *expired,
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(order.status, "QUOTED");
+        assert_eq!(order.project_id, None);
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn cancel_only_quotes(
+        pool: PgPool,
+    ) {
+        let quote = order_id("00000000-0000-0000-0000-000000000101");
+        let ordered = order_id("00000000-0000-0000-0000-000000000103");
+
+        let result = cancel_order(&pool, CharacterId(11), quote).await;
+        assert!(matches!(result, Err(StoreError::OrderNotFound(_))));
+
+        cancel_order(&pool, CharacterId(2), quote).await.unwrap();
+
+        let result = cancel_order(&pool, CharacterId(11), ordered).await;
+        assert!(matches!(result, Err(StoreError::ValidationError(_))));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn deliver_requires_write_access(
+        pool: PgPool,
+    ) {
+        let store_id: StoreUuid = Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into();
+        let quote = order_id("00000000-0000-0000-0000-000000000101");
+        let ordered = order_id("00000000-0000-0000-0000-000000000103");
+
+        let result = deliver_order(
+                &pool,
+                MemberIdentity::new(CharacterId(2), CorporationId(2), None),
+                store_id,
+                ordered,
+            )
+            .await;
+        assert!(matches!(result, Err(StoreError::ProjectGroupError(_))));
+
+        let result = deliver_order(
+                &pool,
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                store_id,
+                quote,
+            )
+            .await;
+        assert!(matches!(result, Err(StoreError::ValidationError(_))));
+
+        deliver_order(
+                &pool,
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                store_id,
+                ordered,
+            )
+            .await
+            .unwrap();
+
+        let audit = sqlx::query!(r#"
+                SELECT after
+                FROM audit_event
+                WHERE scope = 'STORE_ORDER'
+                AND entity_id = $1
+            "#,
+                *store_id,
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(audit.after.get("status"), Some(&serde_json::json!("DELIVERED")));
+    }
+}
@@ -0,0 +1,182 @@
+use serde::Deserialize;
+use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use utoipa::ToSchema;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::project_group::permission::{assert_write_access_check, MemberIdentity};
+use crate::store::StoreUuid;
+use crate::store::error::{StoreError, Result};
+use crate::store::service::{fetch_store, validate_store};
+
+/// Updates the settings of the store.
+///
+/// The character requires write access to the project group of the store.
+/// The owner and the project group of a store can't be changed.
+///
+pub async fn update_store(
+    pool:     &PgPool,
+    identity: MemberIdentity,
+    store_id: StoreUuid,
+    store:    UpdateStore,
+) -> Result<()> {
+    store.validate()?;
+
+    let project_group_id = fetch_store(pool, store_id)
+        .await?
+        .project_group_id;
+    assert_write_access_check(
+            pool,
+            project_group_id,
+            identity,
+            ProjectGroupPermissionCode::WriteProject,
+        )
+        .await?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(StoreError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            identity.character_id,
+            AuditAction::Update,
+            AuditTarget::Store(store_id),
+        )
+        .await?;
+
+    sqlx::query!("
+            UPDATE store
+            SET
+                name = $2,
+                description = $3,
+                margin = $4,
+                quote_validity = $5,
+                enabled = $6,
+                public = $7
+            WHERE id = $1
+        ",
+            *store_id,
+            store.name,
+            store.description,
+            store.margin,
+            store.quote_validity,
+            store.enabled,
+            store.public,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StoreError::Update(e, store_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(StoreError::TransactionError)
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[schema(
+    example = json!({
+        "name": "Capital Store",
+        "description": "Capitals delivered to 1DQ1-A",
+        "margin": 10,
+        "quote_validity": 24,
+        "enabled": true,
+        "public": false
+    })
+)]
+pub struct UpdateStore {
+    pub name:           String,
+    pub description:    Option<String>,
+    /// Margin in percent that is added to the build cost
+    pub margin:         f64,
+    /// Hours a quote can be confirmed
+    pub quote_validity: i32,
+    /// Disabled stores don't accept new quotes
+    pub enabled:        bool,
+    /// Public stores accept quotes from everyone, all others only from
+    /// characters that can read the project group
+    pub public:         bool,
+}
+
+impl UpdateStore {
+    pub fn validate(&self) -> Result<()> {
+        validate_store(&self.name, self.margin, self.quote_validity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+    use uuid::Uuid;
+
+    use crate::project_group::permission::MemberIdentity;
+    use crate::store::error::StoreError;
+    use crate::store::service::{UpdateStore, fetch_store, update_store};
+    use crate::store::StoreUuid;
+
+    fn store(
+        enabled: bool,
+    ) -> UpdateStore {
+        UpdateStore {
+            name:           "Capital Store".into(),
+            description:    None,
+            margin:         15f64,
+            quote_validity: 12,
+            enabled,
+            public:         true,
+        }
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn update_requires_write_access(
+        pool: PgPool,
+    ) {
+        let store_id: StoreUuid = Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into();
+
+        let result = update_store(
+                &pool,
+                MemberIdentity::new(CharacterId(2), CorporationId(2), None),
+                store_id,
+                store(false),
+            )
+            .await;
+        assert!(matches!(result, Err(StoreError::ProjectGroupError(_))));
+
+        update_store(
+                &pool,
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+                store_id,
+                store(false),
+            )
+            .await
+            .unwrap();
+
+        let entry = fetch_store(&pool, store_id).await.unwrap();
+        assert_eq!(entry.name, "Capital Store");
+        assert_eq!(entry.quote_validity, 12);
+        assert!(!entry.enabled);
+        assert!(entry.public);
+
+        let audit = sqlx::query!(r#"
+                SELECT after
+                FROM audit_event
+                WHERE entity_type = 'STORE'
+                AND action = 'UPDATE'
+            "#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(audit.after.get("enabled"), Some(&serde_json::json!(false)));
+    }
+}
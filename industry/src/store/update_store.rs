@@ -0,0 +1,70 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+
+use crate::AppState;
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity};
+use crate::store::error::Result;
+use crate::store::service::{UpdateStore, update_store};
+use crate::store::StoreUuid;
+
+/// Update Store
+/// 
+/// - Alternative route: `/latest/stores/{StoreUuid}`
+/// - Alternative route: `/v1/stores/{StoreUuid}`
+/// 
+/// ---
+/// 
+/// Updates the settings of the store.
+/// Disabled stores don't accept new quotes, already confirmed orders are not
+/// affected.
+/// Public stores accept quotes from everyone, all others only from characters
+/// with read access to the project group.
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:write_project
+/// 
+#[utoipa::path(
+    put,
+    path = "/{StoreUuid}",
+    tag = "store",
+    params(
+        StoreUuid,
+    ),
+    request_body = UpdateStore,
+    responses(
+        (
+            description = "The store was updated",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:       ExtractIdentity,
+    State(state):   State<AppState>,
+    Path(store_id): Path<StoreUuid>,
+    Json(store):    Json<UpdateStore>,
+) -> Result<impl IntoResponse> {
+    update_store(
+        &state.postgres,
+        (&identity).into(),
+        store_id,
+        store,
+    ).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+    ))
+}
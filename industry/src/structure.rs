@@ -1,9 +1,12 @@
 mod create;
 mod delete;
 mod fetch;
+mod fetch_fuel;
+mod list_audit_events;
 mod list;
 mod permission;
 mod update;
+mod update_fuel;
 
 pub mod error;
 pub mod service;
@@ -28,6 +31,16 @@ pub fn routes(
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
 
+    let fetch_fuel = OpenApiRouter::new()
+        .routes(routes!(fetch_fuel::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
+    let list_audit_events = OpenApiRouter::new()
+        .routes(routes!(list_audit_events::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_read))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
     let create = OpenApiRouter::new()
         .routes(routes!(create::api));
 
@@ -36,6 +49,11 @@ pub fn routes(
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_write))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
 
+    let update_fuel = OpenApiRouter::new()
+        .routes(routes!(update_fuel::api))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_write))
+        .route_layer(middleware::from_fn_with_state(state.clone(), assert_exists));
+
     let delete = OpenApiRouter::new()
         .routes(routes!(delete::api))
         .route_layer(middleware::from_fn_with_state(state.clone(), assert_write))
@@ -44,8 +62,11 @@ pub fn routes(
     OpenApiRouter::new()
         .merge(list)
         .merge(fetch)
+        .merge(fetch_fuel)
+        .merge(list_audit_events)
         .merge(create)
         .merge(update)
+        .merge(update_fuel)
         .merge(delete)
 }
 
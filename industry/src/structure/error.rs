@@ -38,13 +38,21 @@ pub enum StructureError {
     DeleteStructure(sqlx::Error, StructureUuid),
     #[error("error while updating structure '{1}', error: '{0}'")]
     UpdateStructure(sqlx::Error, StructureUuid),
+    #[error("error while fetching structure fuel '{1}', error: '{0}'")]
+    FetchStructureFuel(sqlx::Error, StructureUuid),
+    #[error("error while updating structure fuel '{1}', error: '{0}'")]
+    UpdateStructureFuel(sqlx::Error, StructureUuid),
 
     #[error(transparent)]
     JsonExtractorRejection(#[from] JsonRejection),
     #[error(transparent)]
+    AuditError(Box<crate::audit::AuditError>),
+    #[error(transparent)]
     GatewayLibError(Box<starfoundry_lib_gateway::error::Error>),
     #[error(transparent)]
     EveGatewayLibError(#[from] starfoundry_lib_eve_gateway::Error),
+    #[error(transparent)]
+    MarketLibError(#[from] starfoundry_lib_market::Error),
 }
 
 impl IntoResponse for StructureError {
@@ -112,3 +120,4 @@ impl IntoResponse for StructureError {
 }
 
 boxed_from!(StructureError::GatewayLibError, starfoundry_lib_gateway::error::Error);
+boxed_from!(StructureError::AuditError, crate::audit::AuditError);
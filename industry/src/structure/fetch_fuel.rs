@@ -0,0 +1,164 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::structure::StructureFuel;
+use starfoundry_lib_industry::StructureUuid;
+
+use crate::eve_gateway_api_client;
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::structure::service::fetch_fuel;
+use crate::AppState;
+use crate::structure::error::Result;
+
+/// Fetch Structure Fuel
+/// 
+/// - Alternative route: `/latest/structures/{StructureUuid}/fuel`
+/// - Alternative route: `/v1/structures/{StructureUuid}/fuel`
+/// 
+/// ---
+/// 
+/// Fetches the fuel consumption of the online services, the estimated fuel
+/// stock, the time the fuel runs out and the monthly upkeep of the structure
+/// 
+/// Metenox Moon Drills additionally consume fuel blocks and magmatic gas on
+/// their own, both are part of the consumption and the upkeep.
+/// 
+/// ## Security
+/// - authenticated
+/// - structure:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{StructureUuid}/fuel",
+    tag = "Structures",
+    params(
+        StructureUuid,
+    ),
+    responses(
+        (
+            body = StructureFuel,
+            description = "Fuel information about the structure",
+            status = OK,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:             ExtractIdentity,
+    State(state):         State<AppState>,
+    Path(structure_uuid): Path<StructureUuid>,
+) -> Result<impl IntoResponse> {
+    let entry = fetch_fuel(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            identity.character_id,
+            structure_uuid,
+        )
+        .await?;
+
+    if let Some(x) = entry {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(x)
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                ()
+            )
+            .into_response()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::header::HOST;
+    use axum::http::StatusCode;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE};
+    use starfoundry_lib_industry::structure::StructureFuel;
+    use starfoundry_lib_types::TypeId;
+
+    use crate::structure::structure_test_routes;
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000001/fuel")
+            .method("GET")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .body(Body::empty())
+            .unwrap();
+        let response = structure_test_routes(pool, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let fuel: StructureFuel = serde_json::from_slice(&body).unwrap();
+        assert_eq!(fuel.services.len(), 1);
+        assert_eq!(fuel.fuel_per_hour, 40f32);
+        // no stock recorded, the cheapest fuel block is used
+        assert_eq!(fuel.fuel_type_id, TypeId(4246));
+        assert_eq!(fuel.fuel_blocks, None);
+        assert_eq!(fuel.runs_out_at, None);
+        assert_eq!(fuel.magmatic_gas_per_hour, 0f32);
+        assert_eq!(fuel.monthly_upkeep, 40f64 * 720f64 * 10_000f64);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn unauthorized(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000001/fuel")
+            .method("GET")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HOST, "test.starfoundry.space")
+            .body(Body::empty())
+            .unwrap();
+        let response = structure_test_routes(pool, request).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn forbidden(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000002/fuel")
+            .method("GET")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .body(Body::empty())
+            .unwrap();
+        let response = structure_test_routes(pool, request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
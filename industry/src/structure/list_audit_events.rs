@@ -0,0 +1,150 @@
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_industry::audit::{AuditEntity, AuditEvent, AuditFilter};
+use starfoundry_lib_industry::StructureUuid;
+
+use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized};
+use crate::AppState;
+use crate::audit::service::list_events;
+use crate::structure::error::Result;
+
+/// List Audit Events
+/// 
+/// - Alternative route: `/latest/structures/{StructureUuid}/audit`
+/// - Alternative route: `/v1/structures/{StructureUuid}/audit`
+/// 
+/// ---
+/// 
+/// Lists all changes that were made to the structure
+/// 
+/// Every event contains only the values that changed, `before` holds the
+/// removed or replaced values, `after` the added ones.
+/// 
+/// ## Security
+/// - authenticated
+/// - structure:read
+/// 
+#[utoipa::path(
+    get,
+    path = "/{StructureUuid}/audit",
+    tag = "Structures",
+    params(
+        StructureUuid,
+        AuditFilter,
+    ),
+    responses(
+        (
+            body = Vec<AuditEvent>,
+            description = "Audit events, newest first",
+            status = OK,
+        ),
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    State(state):         State<AppState>,
+    Path(structure_uuid): Path<StructureUuid>,
+    Query(filter):        Query<AuditFilter>,
+) -> Result<impl IntoResponse> {
+    let data = list_events(
+            &state.postgres,
+            AuditEntity::Structure,
+            *structure_uuid,
+            filter,
+        )
+        .await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(data),
+        )
+        .into_response()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::header::HOST;
+    use axum::http::StatusCode;
+    use http_body_util::BodyExt;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE};
+    use starfoundry_lib_industry::audit::{AuditAction, AuditEvent, AuditScope};
+    use starfoundry_lib_types::CharacterId;
+    use std::str::FromStr;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    use crate::structure::service::{update, UpdateStructure};
+    use crate::structure::structure_test_routes;
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        update(
+                &pool,
+                CharacterId(1),
+                Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
+                UpdateStructure {
+                    rigs:     vec![1.into()],
+                    services: vec![1.into(), 2.into()],
+                    taxes:    HashMap::new(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000001/audit")
+            .method("GET")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .body(Body::empty())
+            .unwrap();
+        let response = structure_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Vec<AuditEvent> = serde_json::from_slice(
+            &response.into_body().collect().await.unwrap().to_bytes()
+        ).unwrap();
+        assert_eq!(body.len(), 1);
+        let event = body.first().unwrap();
+        assert_eq!(event.scope, AuditScope::Structure);
+        assert_eq!(event.action, AuditAction::Update);
+        assert_eq!(event.character_id, CharacterId(1));
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn forbidden(
+        pool: PgPool,
+    ) {
+        let request = Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000002/audit")
+            .method("GET")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .body(Body::empty())
+            .unwrap();
+        let response = structure_test_routes(pool.clone(), request).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -1,11 +1,17 @@
 mod create;
 mod delete;
 mod fetch;
+mod fetch_fuel;
 mod list;
+mod remap;
 mod update;
+mod update_fuel;
 
 pub use self::create::*;
 pub use self::delete::*;
 pub use self::fetch::*;
+pub use self::fetch_fuel::*;
 pub use self::list::*;
+pub use self::remap::*;
 pub use self::update::*;
+pub use self::update_fuel::*;
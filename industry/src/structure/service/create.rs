@@ -6,6 +6,7 @@ use starfoundry_lib_types::{SystemId, TypeId};
 use starfoundry_lib_types::CharacterId;
 use utoipa::ToSchema;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::structure::StructureError;
 use crate::structure::error::Result;
 
@@ -54,6 +55,13 @@ pub async fn create(
         .map(|x| StructureUuid::new(x.id))
         .map_err(StructureError::CreateStructure)?;
 
+    AuditRecord::created(
+            &mut transaction,
+            character_id,
+            AuditTarget::Structure(structure_id),
+        )
+        .await?;
+
     transaction
         .commit()
         .await
@@ -1,7 +1,9 @@
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::StructureUuid;
 use starfoundry_lib_types::CharacterId;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::structure::StructureError;
 use crate::structure::error::Result;
 
@@ -10,19 +12,39 @@ pub async fn delete(
     character_id: CharacterId,
     structure_id: StructureUuid,
 ) -> Result<StructureUuid> {
-    sqlx::query!("
-        DELETE FROM structure
-        WHERE owner = $1
-            AND id = $2
-        RETURNING id
-    ",
-        *character_id,
-        *structure_id,
-    )
-    .fetch_one(pool)
-    .await
-    .map(|x| StructureUuid::new(x.id))
-    .map_err(|e| StructureError::DeleteStructure(e, structure_id))
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(StructureError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Delete,
+            AuditTarget::Structure(structure_id),
+        )
+        .await?;
+
+    let structure_id = sqlx::query!("
+            DELETE FROM structure
+            WHERE owner = $1
+                AND id = $2
+            RETURNING id
+        ",
+            *character_id,
+            *structure_id,
+        )
+        .fetch_one(&mut *transaction)
+        .await
+        .map(|x| StructureUuid::new(x.id))
+        .map_err(|e| StructureError::DeleteStructure(e, structure_id))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(StructureError::TransactionError)?;
+
+    Ok(structure_id)
 }
 
 #[cfg(test)]
@@ -0,0 +1,333 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::{EveGatewayApiClient, Item, StructureServiceFuel, StructureType};
+use starfoundry_lib_industry::StructureUuid;
+use starfoundry_lib_industry::structure::{StructureFuel, StructureServiceFuelUsage};
+use starfoundry_lib_market::MarketApiClientPrice;
+use starfoundry_lib_types::{CharacterId, TypeId};
+use std::collections::HashMap;
+
+use crate::market_api_client;
+use crate::structure::StructureError;
+use crate::structure::error::Result;
+use crate::structure::service::{fetch, FetchStructureQuery};
+
+/// Nitrogen, Hydrogen, Helium and Oxygen Fuel Block
+pub const FUEL_BLOCKS: [i32; 4] = [4051, 4246, 4247, 4312];
+
+/// Magmatic Gas, consumed by Metenox Moon Drills
+pub const MAGMATIC_GAS: i32 = 81143;
+
+/// Metenox Moon Drill, consumes magmatic gas in addition to fuel blocks
+pub const METENOX_MOON_DRILL: i32 = 81826;
+/// Fuel blocks a Metenox Moon Drill consumes per hour, without any services
+pub const METENOX_FUEL_PER_HOUR: f32 = 5f32;
+/// Magmatic gas a Metenox Moon Drill consumes per hour
+pub const METENOX_MAGMATIC_GAS_PER_HOUR: f32 = 200f32;
+
+/// Hours that are used for the monthly upkeep
+pub const HOURS_PER_MONTH: f64 = 720f64;
+
+/// Fuel consumption, stock and upkeep of the structure.
+///
+/// Besides the fuel blocks of the services, Metenox Moon Drills consume a
+/// fixed amount of fuel blocks and magmatic gas on their own.
+///
+pub async fn fetch_fuel(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    character_id:           CharacterId,
+    structure_uuid:         StructureUuid,
+) -> Result<Option<StructureFuel>> {
+    let structure = if let Some(x) = fetch(
+            pool,
+            eve_gateway_api_client,
+            character_id,
+            structure_uuid,
+            FetchStructureQuery::default(),
+        ).await? {
+        x
+    } else {
+        return Ok(None);
+    };
+
+    let service_fuel = eve_gateway_api_client
+        .list_service_fuel(
+            structure
+                .services
+                .iter()
+                .map(|x| x.type_id)
+                .collect::<Vec<_>>(),
+        )
+        .await?;
+    let services = fuel_usage(
+        &structure.structure_type,
+        &structure.services,
+        &service_fuel,
+    );
+    let (drill_fuel_per_hour, magmatic_gas_per_hour) = structure_consumption(
+        structure.item.type_id,
+    );
+    let fuel_per_hour = services
+        .iter()
+        .map(|x| x.fuel_per_hour)
+        .sum::<f32>() + drill_fuel_per_hour;
+
+    let stock = sqlx::query!("
+            SELECT
+                fuel_type_id,
+                fuel_blocks,
+                recorded_at
+            FROM structure_fuel
+            WHERE structure_id = $1
+        ",
+            *structure_uuid,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| StructureError::FetchStructureFuel(e, structure_uuid))?;
+
+    let prices = fuel_prices().await?;
+    let magmatic_gas_price = prices
+        .get(&TypeId(MAGMATIC_GAS))
+        .copied()
+        .unwrap_or_default();
+    let (fuel_type_id, fuel_block_price) = if let Some(x) = stock.as_ref() {
+        let type_id = TypeId(x.fuel_type_id);
+        (type_id, prices.get(&type_id).copied().unwrap_or_default())
+    } else {
+        prices
+            .iter()
+            .filter(|(type_id, _)| FUEL_BLOCKS.contains(&***type_id))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(type_id, price)| (*type_id, *price))
+            .unwrap_or((TypeId(*FUEL_BLOCKS.first().unwrap_or(&0)), 0f64))
+    };
+
+    let (fuel_blocks, recorded_at, runs_out_at) = if let Some(x) = stock {
+        let (fuel_blocks, runs_out_at) = remaining_fuel(
+            x.fuel_blocks,
+            fuel_per_hour,
+            x.recorded_at,
+            Utc::now(),
+        );
+        (Some(fuel_blocks), Some(x.recorded_at), runs_out_at)
+    } else {
+        (None, None, None)
+    };
+
+    Ok(Some(StructureFuel {
+        structure_id:   structure_uuid,
+        services,
+        fuel_per_hour,
+        magmatic_gas_per_hour,
+
+        fuel_type_id,
+        fuel_blocks,
+        recorded_at,
+        runs_out_at,
+
+        fuel_block_price,
+        magmatic_gas_price,
+        monthly_upkeep: monthly_upkeep(
+            fuel_per_hour,
+            fuel_block_price,
+            magmatic_gas_per_hour,
+            magmatic_gas_price,
+        ),
+    }))
+}
+
+/// Average price of all fuel blocks and magmatic gas
+pub(crate) async fn fuel_prices() -> Result<HashMap<TypeId, f64>> {
+    let prices = market_api_client()?
+        .all_prices()
+        .await?
+        .into_iter()
+        .filter(|x| {
+            FUEL_BLOCKS.contains(&*x.type_id) ||
+            *x.type_id == MAGMATIC_GAS
+        })
+        .map(|x| (x.type_id, x.average_price))
+        .collect::<HashMap<_, _>>();
+    Ok(prices)
+}
+
+/// Fuel consumption of the given services, including the fuel reduction of
+/// the structure.
+/// Services without fuel information are ignored
+pub(crate) fn fuel_usage(
+    structure_type: &StructureType,
+    services:       &[Item],
+    service_fuel:   &[StructureServiceFuel],
+) -> Vec<StructureServiceFuelUsage> {
+    let service_fuel = service_fuel
+        .iter()
+        .map(|x| (x.type_id, x.fuel_per_hour))
+        .collect::<HashMap<_, _>>();
+
+    services
+        .iter()
+        .filter_map(|service| {
+            let fuel_per_hour = service_fuel.get(&service.type_id)?;
+            let bonus = structure_type.fuel_bonus(service.type_id);
+
+            Some(StructureServiceFuelUsage {
+                item:          service.clone(),
+                fuel_per_hour: *fuel_per_hour as f32 * (1f32 - bonus / 100f32),
+                bonus,
+            })
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Fuel blocks and magmatic gas the structure consumes per hour on its own,
+/// without any services
+pub(crate) fn structure_consumption(
+    type_id: TypeId,
+) -> (f32, f32) {
+    if *type_id == METENOX_MOON_DRILL {
+        (METENOX_FUEL_PER_HOUR, METENOX_MAGMATIC_GAS_PER_HOUR)
+    } else {
+        (0f32, 0f32)
+    }
+}
+
+/// Cost of the fuel blocks and magmatic gas that are consumed in 30 days
+pub(crate) fn monthly_upkeep(
+    fuel_per_hour:         f32,
+    fuel_block_price:      f64,
+    magmatic_gas_per_hour: f32,
+    magmatic_gas_price:    f64,
+) -> f64 {
+    fuel_per_hour as f64 * HOURS_PER_MONTH * fuel_block_price +
+    magmatic_gas_per_hour as f64 * HOURS_PER_MONTH * magmatic_gas_price
+}
+
+/// Estimates the fuel that is left at `now` and the time the fuel runs out.
+/// Structures without any consumption never run out of fuel
+pub(crate) fn remaining_fuel(
+    fuel_blocks:   i32,
+    fuel_per_hour: f32,
+    recorded_at:   DateTime<Utc>,
+    now:           DateTime<Utc>,
+) -> (i32, Option<DateTime<Utc>>) {
+    if fuel_per_hour <= 0f32 {
+        return (fuel_blocks, None);
+    }
+
+    let hours_left = fuel_blocks as f64 / fuel_per_hour as f64;
+    let runs_out_at = recorded_at + TimeDelta::seconds((hours_left * 3_600f64) as i64);
+
+    let hours_passed = (now - recorded_at).num_seconds().max(0) as f64 / 3_600f64;
+    let consumed = (hours_passed * fuel_per_hour as f64).ceil() as i32;
+
+    ((fuel_blocks - consumed).max(0), Some(runs_out_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, TimeZone, Utc};
+    use starfoundry_lib_eve_gateway::{Category, Group, Item, StructureServiceFuel, StructureType};
+    use starfoundry_lib_types::{CategoryId, GroupId, TypeId};
+
+    use super::{fuel_usage, monthly_upkeep, remaining_fuel, structure_consumption};
+
+    fn service(
+        type_id: i32,
+    ) -> Item {
+        Item {
+            type_id:    TypeId(type_id),
+            category:   Category {
+                category_id: CategoryId(66),
+                name:        "Structure Module".into(),
+            },
+            group:      Group {
+                group_id:    GroupId(1321),
+                category_id: CategoryId(66),
+                name:        "Structure Service Module".into(),
+            },
+            volume:     1f32,
+            name:       format!("Service {type_id}"),
+            meta_group: None,
+            repackaged: None,
+        }
+    }
+
+    #[test]
+    fn structure_bonus() {
+        let services = vec![
+            // Manufacturing Plant
+            service(35878),
+            // Market Hub
+            service(35892),
+            // no fuel information
+            service(1),
+        ];
+        let service_fuel = vec![
+            StructureServiceFuel { type_id: TypeId(35878), fuel_per_hour: 12, fuel_online: 0 },
+            StructureServiceFuel { type_id: TypeId(35892), fuel_per_hour: 40, fuel_online: 0 },
+        ];
+
+        let usage = fuel_usage(&StructureType::Azbel, &services, &service_fuel);
+        assert_eq!(usage.len(), 2);
+        let manufacturing = usage.first().unwrap();
+        assert_eq!(manufacturing.fuel_per_hour, 9f32);
+        assert_eq!(manufacturing.bonus, 25f32);
+        let market = usage.last().unwrap();
+        assert_eq!(market.fuel_per_hour, 40f32);
+        assert_eq!(market.bonus, 0f32);
+
+        let usage = fuel_usage(&StructureType::Fortizar, &services, &service_fuel);
+        assert_eq!(usage.first().unwrap().fuel_per_hour, 12f32);
+        assert_eq!(usage.last().unwrap().fuel_per_hour, 30f32);
+    }
+
+    #[test]
+    fn runs_out() {
+        let recorded_at = Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap();
+
+        // 10 blocks per hour, 240 blocks last a day
+        let (fuel_blocks, runs_out_at) = remaining_fuel(
+            240,
+            10f32,
+            recorded_at,
+            recorded_at + TimeDelta::hours(6),
+        );
+        assert_eq!(fuel_blocks, 180);
+        assert_eq!(runs_out_at, Some(recorded_at + TimeDelta::days(1)));
+
+        let (fuel_blocks, _) = remaining_fuel(
+            240,
+            10f32,
+            recorded_at,
+            recorded_at + TimeDelta::days(2),
+        );
+        assert_eq!(fuel_blocks, 0);
+
+        let (fuel_blocks, runs_out_at) = remaining_fuel(
+            240,
+            0f32,
+            recorded_at,
+            recorded_at + TimeDelta::days(2),
+        );
+        assert_eq!(fuel_blocks, 240);
+        assert_eq!(runs_out_at, None);
+    }
+
+    #[test]
+    fn metenox_moon_drill() {
+        // Metenox Moon Drill
+        let (fuel_per_hour, magmatic_gas_per_hour) = structure_consumption(TypeId(81826));
+        assert_eq!(fuel_per_hour, 5f32);
+        assert_eq!(magmatic_gas_per_hour, 200f32);
+        // 5 blocks at 10 000 ISK and 200 gas at 1 000 ISK per hour
+        assert_eq!(
+            monthly_upkeep(fuel_per_hour, 10_000f64, magmatic_gas_per_hour, 1_000f64),
+            720f64 * (50_000f64 + 200_000f64),
+        );
+
+        // Athanor
+        assert_eq!(structure_consumption(TypeId(35835)), (0f32, 0f32));
+    }
+}
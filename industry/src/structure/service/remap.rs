@@ -0,0 +1,191 @@
+use sqlx::PgPool;
+use starfoundry_lib_industry::StructureUuid;
+use starfoundry_lib_industry::structure::ExportStructure;
+use starfoundry_lib_types::CharacterId;
+use std::collections::HashMap;
+
+use crate::structure::error::{StructureError, Result};
+
+/// Maps the structures of an export to structures the character can use.
+///
+/// Explicitly mapped structures must be owned by the character or be an NPC
+/// station. All others keep their id if the character can use them, which is
+/// the case when importing on the same instance, otherwise they are matched
+/// by their in-game id, preferring the structures of the character.
+///
+/// # Errors
+///
+/// - [StructureError::ValidationError] if a structure can't be mapped
+///
+pub async fn remap_structures(
+    pool:         &PgPool,
+    character_id: CharacterId,
+    structures:   &[ExportStructure],
+    mapping:      &HashMap<StructureUuid, StructureUuid>,
+) -> Result<HashMap<StructureUuid, StructureUuid>> {
+    if structures.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let mapped_ids = mapping
+        .values()
+        .chain(structures.iter().map(|x| &x.id))
+        .map(|x| **x)
+        .collect::<Vec<_>>();
+    let structure_ids = structures
+        .iter()
+        .map(|x| *x.structure_id)
+        .collect::<Vec<_>>();
+
+    // own structures are sorted before NPC stations
+    let available = sqlx::query!("
+            SELECT id, structure_id
+            FROM structure
+            WHERE (owner = $1 OR owner = 0)
+            AND (id = ANY($2::UUID[]) OR structure_id = ANY($3::BIGINT[]))
+            ORDER BY owner DESC
+        ",
+            *character_id,
+            &mapped_ids,
+            &structure_ids,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(StructureError::ListStructures)?;
+
+    let mut result = HashMap::new();
+    let mut unmapped = Vec::new();
+    for structure in structures {
+        let target = if let Some(x) = mapping.get(&structure.id) {
+            available
+                .iter()
+                .find(|y| y.id == **x)
+        } else {
+            available
+                .iter()
+                .find(|y| y.id == *structure.id)
+                .or_else(|| available
+                    .iter()
+                    .find(|y| y.structure_id == *structure.structure_id)
+                )
+        };
+
+        if let Some(x) = target {
+            result.insert(structure.id, x.id.into());
+        } else {
+            unmapped.push(structure.name.clone());
+        }
+    }
+
+    if unmapped.is_empty() {
+        Ok(result)
+    } else {
+        Err(StructureError::ValidationError(
+            format!("the structures '{}' can't be mapped", unmapped.join("', '"))
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::StructureUuid;
+    use starfoundry_lib_industry::structure::ExportStructure;
+    use starfoundry_lib_types::{CharacterId, StructureId, SystemId, TypeId};
+    use uuid::Uuid;
+
+    use crate::structure::error::StructureError;
+    use crate::structure::service::remap_structures;
+
+    fn uuid(
+        id: &str,
+    ) -> StructureUuid {
+        Uuid::from_str(id).unwrap().into()
+    }
+
+    fn structure(
+        id:           &str,
+        structure_id: i64,
+    ) -> ExportStructure {
+        ExportStructure {
+            id:           uuid(id),
+            structure_id: StructureId(structure_id),
+            name:         "Exported".into(),
+            system_id:    SystemId(30004759),
+            type_id:      TypeId(35827),
+        }
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn maps_by_id_and_mapping(
+        pool: PgPool,
+    ) {
+        let exported = vec![
+            structure("10000000-0000-0000-0000-000000000001", 1337),
+        ];
+
+        let exported_id = uuid("10000000-0000-0000-0000-000000000001");
+
+        let result = remap_structures(
+                &pool,
+                CharacterId(1),
+                &exported,
+                &HashMap::from([(exported_id, uuid("00000000-0000-0000-0000-000000000003"))]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.get(&exported_id), Some(&uuid("00000000-0000-0000-0000-000000000003")));
+
+        let result = remap_structures(
+                &pool,
+                CharacterId(2),
+                &exported,
+                &HashMap::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.get(&exported_id), Some(&uuid("00000000-0000-0000-0000-000000000002")));
+
+        // the character has no structure with the same in-game id
+        let result = remap_structures(
+                &pool,
+                CharacterId(3),
+                &exported,
+                &HashMap::new(),
+            )
+            .await;
+        assert!(matches!(result, Err(StructureError::ValidationError(_))));
+
+        // same instance, the structure keeps its id
+        let result = remap_structures(
+                &pool,
+                CharacterId(1),
+                &[structure("00000000-0000-0000-0000-000000000003", 1337)],
+                &HashMap::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            result.get(&uuid("00000000-0000-0000-0000-000000000003")),
+            Some(&uuid("00000000-0000-0000-0000-000000000003")),
+        );
+
+        // the structure belongs to another character
+        let result = remap_structures(
+                &pool,
+                CharacterId(2),
+                &exported,
+                &HashMap::from([(exported_id, uuid("00000000-0000-0000-0000-000000000001"))]),
+            )
+            .await;
+        assert!(matches!(result, Err(StructureError::ValidationError(_))));
+    }
+}
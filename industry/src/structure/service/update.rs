@@ -1,15 +1,19 @@
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use starfoundry_lib_industry::audit::AuditAction;
 use starfoundry_lib_industry::StructureUuid;
+use starfoundry_lib_types::CharacterId;
 use starfoundry_lib_types::TypeId;
 use utoipa::ToSchema;
 
+use crate::audit::service::{AuditRecord, AuditTarget};
 use crate::structure::StructureError;
 use crate::structure::error::Result;
 use std::collections::HashMap;
 
 pub async fn update(
     pool:         &PgPool,
+    character_id: CharacterId,
     structure_id: StructureUuid,
     data:         UpdateStructure,
 ) -> Result<StructureUuid> {
@@ -17,6 +21,13 @@ pub async fn update(
         .begin()
         .await
         .map_err(StructureError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::Structure(structure_id),
+        )
+        .await?;
 
     sqlx::query!("
             DELETE FROM structure_tax
@@ -74,6 +85,7 @@ pub async fn update(
         .map(|_| structure_id)
         .map_err(|e| StructureError::UpdateStructure(e, structure_id))?;
 
+    audit.finish(&mut transaction).await?;
     transaction
         .commit()
         .await
@@ -85,6 +97,7 @@ pub async fn update(
 #[cfg(test)]
 mod update_structure_test {
     use sqlx::PgPool;
+    use starfoundry_lib_types::CharacterId;
     use std::collections::HashMap;
     use std::str::FromStr;
     use uuid::Uuid;
@@ -102,6 +115,7 @@ mod update_structure_test {
     ) {
         let result = super::update(
                 &pool,
+                CharacterId(1),
                 Uuid::from_str("00000000-0000-0000-0000-000000000001").unwrap().into(),
                 UpdateStructure {
                     rigs:     vec![1, 2, 3].into_iter().map(Into::into).collect::<Vec<_>>(),
@@ -0,0 +1,98 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::audit::AuditAction;
+use starfoundry_lib_industry::StructureUuid;
+use starfoundry_lib_industry::structure::UpdateStructureFuel;
+use starfoundry_lib_types::CharacterId;
+
+use crate::audit::service::{AuditRecord, AuditTarget};
+use crate::structure::StructureError;
+use crate::structure::error::Result;
+use crate::structure::service::{fetch_fuel, fuel_prices, FUEL_BLOCKS};
+
+/// Records the current fuel stock of the structure, together with the
+/// consumption and prices of fuel blocks and magmatic gas at that time
+pub async fn update_fuel(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    character_id:           CharacterId,
+    structure_uuid:         StructureUuid,
+    data:                   UpdateStructureFuel,
+) -> Result<()> {
+    if !FUEL_BLOCKS.contains(&*data.fuel_type_id) {
+        return Err(StructureError::ValidationError(
+            format!("'{}' is not a fuel block", data.fuel_type_id)
+        ));
+    }
+    if data.fuel_blocks < 0 {
+        return Err(StructureError::ValidationError(
+            "The number of fuel blocks must not be negative".into()
+        ));
+    }
+
+    let fuel = fetch_fuel(
+            pool,
+            eve_gateway_api_client,
+            character_id,
+            structure_uuid,
+        )
+        .await?
+        .ok_or(StructureError::NotFound(structure_uuid))?;
+    let fuel_block_price = fuel_prices()
+        .await?
+        .get(&data.fuel_type_id)
+        .copied()
+        .unwrap_or_default();
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(StructureError::TransactionError)?;
+    let audit = AuditRecord::start(
+            &mut transaction,
+            character_id,
+            AuditAction::Update,
+            AuditTarget::StructureFuel(structure_uuid),
+        )
+        .await?;
+
+    sqlx::query!("
+            INSERT INTO structure_fuel
+            (
+                structure_id,
+                fuel_type_id,
+                fuel_blocks,
+                fuel_per_hour,
+                fuel_block_price,
+                magmatic_gas_per_hour,
+                magmatic_gas_price
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (structure_id)
+            DO UPDATE SET
+                fuel_type_id = EXCLUDED.fuel_type_id,
+                fuel_blocks = EXCLUDED.fuel_blocks,
+                fuel_per_hour = EXCLUDED.fuel_per_hour,
+                fuel_block_price = EXCLUDED.fuel_block_price,
+                magmatic_gas_per_hour = EXCLUDED.magmatic_gas_per_hour,
+                magmatic_gas_price = EXCLUDED.magmatic_gas_price,
+                recorded_at = NOW()
+        ",
+            *structure_uuid,
+            *data.fuel_type_id,
+            data.fuel_blocks,
+            fuel.fuel_per_hour,
+            fuel_block_price,
+            fuel.magmatic_gas_per_hour,
+            fuel.magmatic_gas_price,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| StructureError::UpdateStructureFuel(e, structure_uuid))?;
+
+    audit.finish(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(StructureError::TransactionError)
+}
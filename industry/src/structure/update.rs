@@ -2,6 +2,7 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
 use starfoundry_lib_industry::StructureUuid;
 
 use crate::structure::service::{update, UpdateStructure};
@@ -42,12 +43,14 @@ use crate::api_docs::{BadRequest, InternalServerError, Unauthorized, Unprocessab
     ),
 )]
 pub async fn api(
+    identity:             ExtractIdentity,
     State(state):         State<AppState>,
     Path(structure_uuid): Path<StructureUuid>,
     Json(data):           Json<UpdateStructure>,
 ) -> Result<impl IntoResponse> {
     update(
         &state.postgres,
+        identity.character_id,
         structure_uuid,
         data,
     ).await?;
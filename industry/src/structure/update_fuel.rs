@@ -0,0 +1,168 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::structure::UpdateStructureFuel;
+use starfoundry_lib_industry::StructureUuid;
+
+use crate::eve_gateway_api_client;
+use crate::structure::service::update_fuel;
+use crate::AppState;
+use crate::structure::error::Result;
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
+
+/// Update Structure Fuel
+/// 
+/// - Alternative route: `/latest/structures/{StructureUuid}/fuel`
+/// - Alternative route: `/v1/structures/{StructureUuid}/fuel`
+/// 
+/// ---
+/// 
+/// Records the fuel blocks that are currently in the fuel bay of the
+/// structure.
+/// The fuel consumption and fuel block price are stored with the stock, and
+/// are used to amortise the upkeep into project costs
+/// 
+/// ## Security
+/// - authenticated
+/// - structure:write
+/// 
+#[utoipa::path(
+    put,
+    path = "/{StructureUuid}/fuel",
+    tag = "Structures",
+    params(
+        StructureUuid,
+    ),
+    request_body = UpdateStructureFuel,
+    responses(
+        (
+            description = "The fuel stock was recorded",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        NotFound,
+        UnsupportedMediaType,
+        UnprocessableEntity,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:             ExtractIdentity,
+    State(state):         State<AppState>,
+    Path(structure_uuid): Path<StructureUuid>,
+    Json(data):           Json<UpdateStructureFuel>,
+) -> Result<impl IntoResponse> {
+    update_fuel(
+        &state.postgres,
+        &eve_gateway_api_client()?,
+        identity.character_id,
+        structure_uuid,
+        data,
+    ).await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+            ()
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::header::{CONTENT_TYPE, HOST};
+    use axum::http::StatusCode;
+    use sqlx::PgPool;
+    use starfoundry_lib_gateway::{HEADER_CHARACTER_ID, HEADER_CORPORATION_ID, HEADER_SERVICE};
+    use starfoundry_lib_industry::structure::UpdateStructureFuel;
+    use starfoundry_lib_types::TypeId;
+
+    use crate::structure::structure_test_routes;
+
+    fn request(
+        data: UpdateStructureFuel,
+    ) -> Request<Body> {
+        Request::builder()
+            .uri("/00000000-0000-0000-0000-000000000001/fuel")
+            .header(CONTENT_TYPE, "application/json")
+            .header(HEADER_SERVICE, "industry.test")
+            .header(HEADER_CHARACTER_ID, 1)
+            .header(HEADER_CORPORATION_ID, 1)
+            .header(HOST, "test.starfoundry.space")
+            .method("PUT")
+            .body(Body::new(serde_json::to_string(&data).unwrap()))
+            .unwrap()
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn happy_path(
+        pool: PgPool,
+    ) {
+        let response = structure_test_routes(pool.clone(), request(UpdateStructureFuel {
+            fuel_type_id: TypeId(4051),
+            fuel_blocks:  960,
+        })).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let entry = sqlx::query!("
+                SELECT *
+                FROM structure_fuel
+                WHERE structure_id = '00000000-0000-0000-0000-000000000001'
+            ")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(entry.fuel_type_id, 4051);
+        assert_eq!(entry.fuel_blocks, 960);
+        assert_eq!(entry.fuel_per_hour, 40f32);
+        assert_eq!(entry.fuel_block_price, 12_000f64);
+
+        let audit = sqlx::query!(r#"
+                SELECT COUNT(*) AS "count!"
+                FROM audit_event
+                WHERE scope = 'STRUCTURE_FUEL'
+                AND entity_id = '00000000-0000-0000-0000-000000000001'
+            "#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(audit.count, 1);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn not_a_fuel_block(
+        pool: PgPool,
+    ) {
+        let response = structure_test_routes(pool, request(UpdateStructureFuel {
+            fuel_type_id: TypeId(34),
+            fuel_blocks:  960,
+        })).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[sqlx::test(
+        fixtures("base"),
+    )]
+    async fn negative_fuel_blocks(
+        pool: PgPool,
+    ) {
+        let response = structure_test_routes(pool, request(UpdateStructureFuel {
+            fuel_type_id: TypeId(4051),
+            fuel_blocks:  -1,
+        })).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
@@ -1,5 +1,6 @@
 mod create;
 mod delete;
+mod dry_run;
 mod fetch;
 mod list;
 mod trigger;
@@ -32,6 +33,9 @@ pub fn routes() -> OpenApiRouter<AppState> {
     let trigger = OpenApiRouter::new()
         .routes(routes!(trigger::api));
 
+    let dry_run = OpenApiRouter::new()
+        .routes(routes!(dry_run::api));
+
     OpenApiRouter::new()
         .merge(list)
         .merge(fetch)
@@ -39,4 +43,5 @@ pub fn routes() -> OpenApiRouter<AppState> {
         .merge(update)
         .merge(delete)
         .merge(trigger)
+        .merge(dry_run)
 }
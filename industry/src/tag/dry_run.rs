@@ -0,0 +1,82 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_gateway::ExtractIdentity;
+use starfoundry_lib_industry::project::ProjectMinimal;
+use starfoundry_lib_industry::tag::TagDryRun;
+
+use crate::{AppState, eve_gateway_api_client};
+use crate::api_docs::{BadRequest, InternalServerError, Unauthorized, UnsupportedMediaType};
+use crate::tag::error::Result;
+use crate::tag::service::dry_run;
+
+/// Dry Run
+/// 
+/// - Alternative route: `/latest/tags/dry-run`
+/// - Alternative route: `/v1/tags/dry-run`
+/// 
+/// ---
+/// 
+/// Lists all projects the given automatic tag conditions would match,
+/// without changing any tags
+/// 
+/// ## Security
+/// - authenticated
+/// - project_group:read
+/// 
+#[utoipa::path(
+    post,
+    path = "/dry-run",
+    tag = "tags",
+    request_body = TagDryRun,
+    responses(
+        (
+            body = Vec<ProjectMinimal>,
+            description = "All projects the conditions match",
+            status = OK,
+        ),
+        (
+            description = "The conditions don't match any project",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        UnsupportedMediaType,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:       ExtractIdentity,
+    State(state):   State<AppState>,
+    Json(info):     Json<TagDryRun>,
+) -> Result<impl IntoResponse> {
+    let data = dry_run(
+            &state.postgres,
+            &eve_gateway_api_client()?,
+            (&identity).into(),
+            info,
+        )
+        .await?;
+
+    if data.is_empty() {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(data),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(data),
+            )
+            .into_response()
+        )
+    }
+}
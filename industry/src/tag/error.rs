@@ -36,9 +36,13 @@ pub enum TagError {
     #[error(transparent)]
     JsonExtractorRejection(#[from] JsonRejection),
     #[error(transparent)]
+    EveGatewayLibError(#[from] starfoundry_lib_eve_gateway::Error),
+    #[error(transparent)]
     ProjectLibError(Box<starfoundry_lib_industry::Error>),
     #[error(transparent)]
     ProjectError(Box<crate::project::error::ProjectError>),
+    #[error(transparent)]
+    ProjectGroupError(Box<crate::project_group::ProjectGroupError>),
 }
 
 impl IntoResponse for TagError {
@@ -48,6 +52,10 @@ impl IntoResponse for TagError {
                 format_json_errors(x).into_response()
             },
 
+            Self::ProjectLibError(e) => {
+                starfoundry_lib_industry::Error::into_response(*e)
+            },
+
             _ => {
                 tracing::error!("{}", self.to_string());
                 (
@@ -66,3 +74,4 @@ impl IntoResponse for TagError {
 
 boxed_from!(TagError::ProjectLibError, starfoundry_lib_industry::Error);
 boxed_from!(TagError::ProjectError, crate::project::error::ProjectError);
+boxed_from!(TagError::ProjectGroupError, crate::project_group::ProjectGroupError);
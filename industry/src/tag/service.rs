@@ -1,13 +1,19 @@
+mod apply;
 mod create;
 mod delete;
+mod dry_run;
 mod fetch;
 mod list;
+mod subject;
 mod trigger;
 mod update;
 
+pub use self::apply::*;
 pub use self::create::*;
 pub use self::delete::*;
+pub use self::dry_run::*;
 pub use self::fetch::*;
 pub use self::list::*;
+pub use self::subject::*;
 pub use self::trigger::*;
 pub use self::update::*;
@@ -0,0 +1,188 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::{ProjectGroupUuid, ProjectUuid};
+use starfoundry_lib_industry::project_group::ProjectGroupPermissionCode;
+use starfoundry_lib_types::CharacterId;
+
+use crate::project_group::permission::{fetch_permission, MemberIdentity};
+use crate::tag::error::{Result, TagError};
+use crate::tag::service::{load_subjects, replace_auto_tags};
+
+/// Re-evaluates the automatic tags of a single project.
+///
+/// Called after a project was created or updated, including status changes.
+/// The tags of every character that can read the project are evaluated,
+/// either as a member of the project group, through a corporation or
+/// alliance grant, or as owner of the project.
+///
+pub async fn apply_auto_tags(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_id:             ProjectUuid,
+) -> Result<()> {
+    let subjects = load_subjects(
+            pool,
+            eve_gateway_api_client,
+            vec![*project_id],
+        )
+        .await?;
+
+    let mut owners = sqlx::query!(r#"
+            SELECT DISTINCT character_id AS "character_id!"
+            FROM (
+                SELECT pgm.character_id
+                FROM project_group_member pgm
+                JOIN project p ON p.project_group_id = pgm.project_group_id
+                WHERE p.id = $1
+                UNION
+                SELECT owner
+                FROM project
+                WHERE id = $1
+            ) AS owners
+        "#,
+            *project_id,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(TagError::SqlxError)?
+        .into_iter()
+        .map(|x| CharacterId(x.character_id))
+        .collect::<Vec<_>>();
+
+    // grants are given to corporations and alliances, only the owners of
+    // automatic tags whose corporation or alliance has a grant are checked
+    let grants = sqlx::query!(r#"
+            SELECT
+                p.project_group_id,
+                COALESCE(
+                    ARRAY_AGG(pgg.entity_id) FILTER (WHERE pgg.entity_type = 'CORPORATION'),
+                    '{}'
+                ) AS "corporation_ids!",
+                COALESCE(
+                    ARRAY_AGG(pgg.entity_id) FILTER (WHERE pgg.entity_type = 'ALLIANCE'),
+                    '{}'
+                ) AS "alliance_ids!"
+            FROM project p
+            JOIN project_group_grant pgg ON pgg.project_group_id = p.project_group_id
+            WHERE p.id = $1
+            GROUP BY p.project_group_id
+        "#,
+            *project_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(TagError::SqlxError)?;
+
+    if let Some(grants) = grants {
+        let candidates = sqlx::query!(r#"
+                SELECT DISTINCT t.owner_id
+                FROM tag t
+                JOIN tag_auto ta ON ta.tag_id = t.id
+                WHERE NOT t.owner_id = ANY($1)
+            "#,
+                &owners.iter().map(|x| **x).collect::<Vec<_>>(),
+            )
+            .fetch_all(pool)
+            .await
+            .map_err(TagError::SqlxError)?
+            .into_iter()
+            .map(|x| CharacterId(x.owner_id))
+            .collect::<Vec<_>>();
+
+        let identities = if candidates.is_empty() {
+            Vec::new()
+        } else {
+            eve_gateway_api_client
+                .fetch_character_bulk(candidates)
+                .await?
+                .into_iter()
+                .filter(|x| {
+                    grants.corporation_ids.contains(&*x.corporation_id) ||
+                    x.alliance_id
+                        .map(|x| grants.alliance_ids.contains(&*x))
+                        .unwrap_or_default()
+                })
+                .map(|x| MemberIdentity::new(
+                    x.character_id,
+                    x.corporation_id,
+                    x.alliance_id,
+                ))
+                .collect::<Vec<_>>()
+        };
+
+        owners.extend(
+            readable_owners(
+                pool,
+                grants.project_group_id.into(),
+                identities,
+            )
+            .await?
+        );
+    }
+
+    replace_auto_tags(
+            pool,
+            owners,
+            &[*project_id],
+            &subjects,
+        )
+        .await
+}
+
+/// Keeps the characters that can read the project group
+async fn readable_owners(
+    pool:             &PgPool,
+    project_group_id: ProjectGroupUuid,
+    identities:       Vec<MemberIdentity>,
+) -> Result<Vec<CharacterId>> {
+    let mut owners = Vec::new();
+    for identity in identities {
+        let permission = fetch_permission(
+                pool,
+                project_group_id,
+                identity,
+            )
+            .await?;
+
+        if permission.has(ProjectGroupPermissionCode::Read) {
+            owners.push(identity.character_id);
+        }
+    }
+
+    Ok(owners)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use sqlx::PgPool;
+    use starfoundry_lib_types::{AllianceId, CharacterId, CorporationId};
+    use uuid::Uuid;
+
+    use crate::project_group::permission::MemberIdentity;
+
+    #[sqlx::test(
+        fixtures(
+            path = "../../project_group/fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn owners_through_grants(
+        pool: PgPool,
+    ) {
+        let owners = super::readable_owners(
+                &pool,
+                Uuid::from_str("00000000-0000-0000-0000-000000000005").unwrap().into(),
+                vec![
+                    MemberIdentity::new(CharacterId(100), CorporationId(1000), None),
+                    MemberIdentity::new(CharacterId(101), CorporationId(1001), Some(AllianceId(2000))),
+                    MemberIdentity::new(CharacterId(102), CorporationId(1002), None),
+                ],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(owners, vec![CharacterId(100), CharacterId(101)]);
+    }
+}
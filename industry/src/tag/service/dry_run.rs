@@ -0,0 +1,45 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::project::{ProjectFilter, ProjectMinimal};
+use starfoundry_lib_industry::tag::TagDryRun;
+
+use crate::project_group::permission::MemberIdentity;
+use crate::tag::error::Result;
+use crate::tag::service::{is_valid, load_subjects};
+
+/// Lists all projects the character has access to that the conditions
+/// would match
+pub async fn dry_run(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    identity:               MemberIdentity,
+    dry_run:                TagDryRun,
+) -> Result<Vec<ProjectMinimal>> {
+    dry_run.validate()?;
+
+    let projects = crate::project::service::list(
+            pool,
+            identity,
+            ProjectFilter::default(),
+        )
+        .await?;
+    let subjects = load_subjects(
+            pool,
+            eve_gateway_api_client,
+            projects.iter().map(|x| *x.id).collect::<Vec<_>>(),
+        )
+        .await?;
+
+    let matching = subjects
+        .into_iter()
+        .filter(|x| is_valid(&dry_run.auto, x))
+        .map(|x| x.id)
+        .collect::<Vec<_>>();
+
+    Ok(
+        projects
+            .into_iter()
+            .filter(|x| matching.contains(&x.id))
+            .collect::<Vec<_>>()
+    )
+}
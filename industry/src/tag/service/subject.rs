@@ -0,0 +1,120 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::project::ProjectStatus;
+use starfoundry_lib_industry::ProjectUuid;
+use starfoundry_lib_types::TypeId;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::tag::error::{Result, TagError};
+
+/// All values of a project automatic tags can match on
+#[derive(Clone, Debug)]
+pub struct TagSubject {
+    pub id:                 ProjectUuid,
+    pub name:               String,
+    pub orderer:            String,
+    pub status:             ProjectStatus,
+    pub previous_status:    Option<ProjectStatus>,
+    pub project_group_name: String,
+    pub cost:               f64,
+    pub created_at:         DateTime<Utc>,
+    pub product_categories: Vec<String>,
+}
+
+impl TagSubject {
+    /// Status change written as `<previous>-><current>`, the previous status
+    /// is empty if the status never changed
+    pub fn status_transition(&self) -> String {
+        format!(
+            "{}->{}",
+            self.previous_status.map(|x| x.as_str().to_string()).unwrap_or_default(),
+            self.status.as_str(),
+        )
+    }
+
+    /// Full days since the project was created
+    pub fn age(&self) -> i64 {
+        (Utc::now() - self.created_at).num_days()
+    }
+}
+
+/// Loads everything automatic tags need of the given projects
+pub async fn load_subjects(
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    project_ids:            Vec<Uuid>,
+) -> Result<Vec<TagSubject>> {
+    let entries = sqlx::query!(r#"
+            SELECT
+                p.id,
+                p.name,
+                p.orderer,
+                p.status AS "status!: ProjectStatus",
+                p.previous_status AS "previous_status: ProjectStatus",
+                p.created_at,
+                pg.name AS project_group_name,
+                (
+                    COALESCE((SELECT SUM(cost) FROM project_job WHERE project_id = p.id), 0) +
+                    COALESCE((SELECT SUM(cost) FROM project_market WHERE project_id = p.id), 0) +
+                    COALESCE((SELECT SUM(cost) FROM project_misc WHERE project_id = p.id), 0)
+                ) AS "cost!",
+                ARRAY(
+                    SELECT DISTINCT type_id
+                    FROM project_product
+                    WHERE project_id = p.id
+                ) AS "product_type_ids!"
+            FROM project p
+            JOIN project_group pg ON pg.id = p.project_group_id
+            WHERE p.id = ANY($1)
+        "#,
+            &project_ids,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(TagError::SqlxError)?;
+
+    let mut type_ids = entries
+        .iter()
+        .flat_map(|x| x.product_type_ids.clone())
+        .map(TypeId::from)
+        .collect::<Vec<_>>();
+    type_ids.sort();
+    type_ids.dedup();
+    let categories = if type_ids.is_empty() {
+        HashMap::new()
+    } else {
+        eve_gateway_api_client
+            .fetch_item_bulk(type_ids)
+            .await?
+            .into_iter()
+            .map(|x| (x.type_id, x.category.name))
+            .collect::<HashMap<_, _>>()
+    };
+
+    let subjects = entries
+        .into_iter()
+        .map(|x| {
+            let mut product_categories = x.product_type_ids
+                .into_iter()
+                .filter_map(|x| categories.get(&x.into()).cloned())
+                .collect::<Vec<_>>();
+            product_categories.sort();
+            product_categories.dedup();
+
+            TagSubject {
+                id:                 x.id.into(),
+                name:               x.name,
+                orderer:            x.orderer,
+                status:             x.status,
+                previous_status:    x.previous_status,
+                project_group_name: x.project_group_name,
+                cost:               x.cost,
+                created_at:         x.created_at,
+                product_categories,
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(subjects)
+}
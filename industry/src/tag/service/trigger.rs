@@ -1,111 +1,200 @@
 use regex::Regex;
 use sqlx::PgPool;
-use starfoundry_lib_industry::project::{ProjectFilter, ProjectMinimal};
-use starfoundry_lib_industry::tag::{Tag, TagAutoCompare, TagAutoSelect};
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_industry::project::ProjectFilter;
+use starfoundry_lib_industry::tag::{TagAuto, TagAutoCompare, TagAutoSelect};
 use starfoundry_lib_types::CharacterId;
+use uuid::Uuid;
 
+use crate::project_group::permission::MemberIdentity;
 use crate::tag::error::{Result, TagError};
-use crate::tag::service::list;
+use crate::tag::service::{list, load_subjects, TagSubject};
 use crate::tag::list::TagFilter;
 
+/// Re-evaluates all automatic tags of the character for all projects the
+/// character has access to.
+///
+/// Only the automatic tags of the character are replaced, tags of other
+/// characters are not touched.
+///
 pub async fn trigger(
-    pool:           &PgPool,
-    character_id:   CharacterId,
+    pool:                   &PgPool,
+    eve_gateway_api_client: &impl EveGatewayApiClient,
+    identity:               MemberIdentity,
 ) -> Result<()> {
-    let tags = list(pool, character_id, TagFilter {
-        auto:   Some(true),
-        manual: Some(false),
-    }).await?;
-
-    let projects = crate::project::service::list(
-        pool,
-        character_id,
-        ProjectFilter::default(),
-    )
-    .await?;
+    let project_ids = crate::project::service::list(
+            pool,
+            identity,
+            ProjectFilter::default(),
+        )
+        .await?
+        .into_iter()
+        .map(|x| *x.id)
+        .collect::<Vec<_>>();
+    let subjects = load_subjects(
+            pool,
+            eve_gateway_api_client,
+            project_ids.clone(),
+        )
+        .await?;
 
+    replace_auto_tags(
+            pool,
+            vec![identity.character_id],
+            &project_ids,
+            &subjects,
+        )
+        .await
+}
+
+/// Replaces the automatic tags of the given characters on the given
+/// projects with the tags that match.
+///
+/// # Params
+///
+/// - `owners` - Characters whose automatic tags are evaluated
+/// - `project_ids` - Projects whose automatic tags are replaced
+/// - `subjects` - Projects the tags are applied to, a character only gets
+///   tags on projects it has access to
+///
+pub async fn replace_auto_tags(
+    pool:        &PgPool,
+    owners:      Vec<CharacterId>,
+    project_ids: &[Uuid],
+    subjects:    &[TagSubject],
+) -> Result<()> {
     let mut tag_ids = Vec::new();
-    let mut project_ids = Vec::new();
+    let mut matched_tag_ids = Vec::new();
+    let mut matched_project_ids = Vec::new();
+
+    for owner in owners {
+        let tags = list(pool, owner, TagFilter {
+            auto:   Some(true),
+            manual: Some(false),
+        }).await?;
+
+        for tag in tags.iter() {
+            tag_ids.push(*tag.id);
 
-    for tag in tags.iter() {
-        for project in projects.iter() {
-            if is_valid(tag, project) {
-                tag_ids.push(*tag.id);
-                project_ids.push(*project.id);
+            for subject in subjects.iter() {
+                if is_valid(&tag.auto, subject) {
+                    matched_tag_ids.push(*tag.id);
+                    matched_project_ids.push(*subject.id);
+                }
             }
         }
     }
 
-    if !tag_ids.is_empty() {
-        sqlx::query!("
-                DELETE FROM project_tag
-            ")
-            .execute(pool)
-            .await
-            .map_err(TagError::SqlxError)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(TagError::TransactionError)?;
 
-        sqlx::query!("
-                INSERT INTO project_tag
-                (
-                    project_id,
-                    tag_id
-                )
-                SELECT * FROM UNNEST(
-                    $1::UUID[],
-                    $2::UUID[]
-                )
-            ",
-                &project_ids,
-                &tag_ids,
+    sqlx::query!("
+            DELETE FROM project_tag
+            WHERE tag_id = ANY($1)
+            AND project_id = ANY($2)
+        ",
+            &tag_ids,
+            project_ids,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(TagError::SqlxError)?;
+
+    sqlx::query!("
+            INSERT INTO project_tag
+            (
+                project_id,
+                tag_id
             )
-            .execute(pool)
-            .await
-            .map_err(TagError::SqlxError)?;
-    }
+            SELECT * FROM UNNEST(
+                $1::UUID[],
+                $2::UUID[]
+            )
+        ",
+            &matched_project_ids,
+            &matched_tag_ids,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(TagError::SqlxError)?;
 
-    Ok(())
+    transaction
+        .commit()
+        .await
+        .map_err(TagError::TransactionError)
 }
 
-fn is_valid(
-    tag:        &Tag,
-    project:    &ProjectMinimal,
+/// Checks if all conditions match the project
+pub fn is_valid(
+    conditions: &[TagAuto],
+    subject:    &TagSubject,
 ) -> bool {
-    let mut valid = true;
+    conditions
+        .iter()
+        .all(|condition| {
+            let value = match condition.select {
+                TagAutoSelect::ProjectName              => TagValue::Text(subject.name.clone()),
+                TagAutoSelect::ProjectOrderer           => TagValue::Text(subject.orderer.clone()),
+                TagAutoSelect::ProjectStatus            => TagValue::Text(subject.status.as_str().into()),
+                TagAutoSelect::ProjectStatusTransition  => TagValue::Text(subject.status_transition()),
+                TagAutoSelect::ProjectGroup             => TagValue::Text(subject.project_group_name.clone()),
+                TagAutoSelect::ProjectCost              => TagValue::Number(subject.cost),
+                TagAutoSelect::ProjectAge               => TagValue::Number(subject.age() as f64),
+                TagAutoSelect::ProductCategory          => TagValue::List(subject.product_categories.clone()),
+            };
 
-    for auto_tag in tag.auto.iter() {
-        if !valid {
-            break;
-        }
-
-        valid = match auto_tag.select {
-            TagAutoSelect::ProjectName      => {
-                match_compare(
-                    &auto_tag.compare,
-                    &project.name,
-                    &auto_tag.value,
-                )
-            },
-            TagAutoSelect::ProjectOrderer   => {
-                match_compare(
-                    &auto_tag.compare,
-                    &project.orderer,
-                    &auto_tag.value,
-                )
-            },
-            TagAutoSelect::ProjectStatus    => {
-                match_compare(
-                    &auto_tag.compare,
-                    project.status.as_str(),
-                    &auto_tag.value,
-                )
-            },
-        }
-    }
+            match_compare(
+                &condition.compare,
+                value,
+                &condition.value,
+            )
+        })
+}
 
-    valid
+enum TagValue {
+    Text(String),
+    Number(f64),
+    /// Matches if any of the entries matches, [TagAutoCompare::IsNot] matches
+    /// if none of the entries is equal
+    List(Vec<String>),
 }
 
 fn match_compare(
+    compare:        &TagAutoCompare,
+    actual_value:   TagValue,
+    target_value:   &str,
+) -> bool {
+    match actual_value {
+        TagValue::Text(x) => match_compare_text(compare, &x, target_value),
+        TagValue::Number(x) => {
+            let target_value = if let Ok(x) = target_value.trim().parse::<f64>() {
+                x
+            } else {
+                return false;
+            };
+
+            match compare {
+                TagAutoCompare::Is          => x == target_value,
+                TagAutoCompare::IsNot       => x != target_value,
+                TagAutoCompare::GreaterThan => x > target_value,
+                TagAutoCompare::LessThan    => x < target_value,
+                TagAutoCompare::Contains    |
+                TagAutoCompare::Pattern     => false,
+            }
+        },
+        TagValue::List(x) => {
+            if *compare == TagAutoCompare::IsNot {
+                x.iter().all(|x| x != target_value)
+            } else {
+                x.iter().any(|x| match_compare_text(compare, x, target_value))
+            }
+        },
+    }
+}
+
+fn match_compare_text(
     compare:        &TagAutoCompare,
     actual_value:   &str,
     target_value:   &str,
@@ -127,5 +216,155 @@ fn match_compare(
                 false
             }
         },
+        TagAutoCompare::GreaterThan |
+        TagAutoCompare::LessThan    => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sqlx::PgPool;
+    use starfoundry_lib_industry::project::ProjectStatus;
+    use starfoundry_lib_industry::tag::{TagAuto, TagAutoCompare, TagAutoSelect};
+    use starfoundry_lib_types::{CharacterId, CorporationId};
+    use uuid::Uuid;
+
+    use super::is_valid;
+    use crate::project_group::permission::MemberIdentity;
+    use crate::tag::service::TagSubject;
+    use crate::test_util::EveGatewayTestApiClient;
+
+    fn subject() -> TagSubject {
+        TagSubject {
+            id:                 Uuid::default().into(),
+            name:               "Capital Cargo".into(),
+            orderer:            "Someone".into(),
+            status:             ProjectStatus::Done,
+            previous_status:    Some(ProjectStatus::InProgress),
+            project_group_name: "First".into(),
+            cost:               1_500_000f64,
+            created_at:         Utc::now() - Duration::days(10),
+            product_categories: vec!["Ship".into(), "Module".into()],
+        }
+    }
+
+    fn condition(
+        select:  TagAutoSelect,
+        compare: TagAutoCompare,
+        value:   &str,
+    ) -> TagAuto {
+        TagAuto {
+            select,
+            compare,
+            value: value.into(),
+        }
+    }
+
+    #[test]
+    fn cost_range() {
+        let conditions = vec![
+            condition(TagAutoSelect::ProjectCost, TagAutoCompare::GreaterThan, "1000000"),
+            condition(TagAutoSelect::ProjectCost, TagAutoCompare::LessThan, "2000000"),
+        ];
+        assert!(is_valid(&conditions, &subject()));
+
+        let conditions = vec![
+            condition(TagAutoSelect::ProjectCost, TagAutoCompare::GreaterThan, "2000000"),
+        ];
+        assert!(!is_valid(&conditions, &subject()));
+    }
+
+    #[test]
+    fn age_and_group() {
+        let conditions = vec![
+            condition(TagAutoSelect::ProjectAge, TagAutoCompare::GreaterThan, "7"),
+            condition(TagAutoSelect::ProjectGroup, TagAutoCompare::Is, "First"),
+        ];
+        assert!(is_valid(&conditions, &subject()));
+
+        let conditions = vec![
+            condition(TagAutoSelect::ProjectAge, TagAutoCompare::LessThan, "7"),
+        ];
+        assert!(!is_valid(&conditions, &subject()));
+    }
+
+    #[test]
+    fn product_category() {
+        let conditions = vec![
+            condition(TagAutoSelect::ProductCategory, TagAutoCompare::Is, "Ship"),
+        ];
+        assert!(is_valid(&conditions, &subject()));
+
+        let conditions = vec![
+            condition(TagAutoSelect::ProductCategory, TagAutoCompare::IsNot, "Module"),
+        ];
+        assert!(!is_valid(&conditions, &subject()));
+    }
+
+    #[test]
+    fn status_transition() {
+        let conditions = vec![
+            condition(TagAutoSelect::ProjectStatusTransition, TagAutoCompare::Is, "InProgress->Done"),
+        ];
+        assert!(is_valid(&conditions, &subject()));
+
+        let conditions = vec![
+            condition(TagAutoSelect::ProjectStatusTransition, TagAutoCompare::Pattern, "^Paused->"),
+        ];
+        assert!(!is_valid(&conditions, &subject()));
+    }
+
+    #[sqlx::test(
+        fixtures(
+            path = "../../project/fixtures",
+            scripts("base"),
+        ),
+    )]
+    async fn keeps_tags_of_other_characters(
+        pool: PgPool,
+    ) {
+        sqlx::query!("
+                INSERT INTO tag (id, owner_id, content, color, typ) VALUES
+                ('00000000-0000-0000-0000-000000001001', 1, 'Filter', '#fff', 'AUTO'),
+                ('00000000-0000-0000-0000-000000001002', 2, 'Other', '#fff', 'MANUAL')
+            ")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query!("
+                INSERT INTO tag_auto (tag_id, option, compare, value) VALUES
+                ('00000000-0000-0000-0000-000000001001', 'PROJECT_NAME', 'IS', 'Filter')
+            ")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query!("
+                INSERT INTO project_tag (project_id, tag_id) VALUES
+                ('00000000-0000-0000-0000-000000000101', '00000000-0000-0000-0000-000000001002')
+            ")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        super::trigger(
+                &pool,
+                &EveGatewayTestApiClient::new(),
+                MemberIdentity::new(CharacterId(1), CorporationId(1), None),
+            )
+            .await
+            .unwrap();
+
+        let entries = sqlx::query!("
+                SELECT project_id, tag_id
+                FROM project_tag
+                ORDER BY tag_id
+            ")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries.first().unwrap().project_id, Uuid::from_u128(0x102));
+        assert_eq!(entries.last().unwrap().project_id, Uuid::from_u128(0x101));
     }
 }
@@ -6,7 +6,7 @@ use starfoundry_lib_industry::tag::UpdateTag;
 use starfoundry_lib_industry::TagUuid;
 
 use crate::api_docs::{Forbidden, InternalServerError, NotFound, Unauthorized, UnprocessableEntity, UnsupportedMediaType};
-use crate::AppState;
+use crate::{AppState, eve_gateway_api_client};
 use crate::tag::error::Result;
 use crate::tag::service::trigger;
 
@@ -17,7 +17,10 @@ use crate::tag::service::trigger;
 /// 
 /// ---
 /// 
-/// Triggers the auto tag generation
+/// Re-evaluates all automatic tags of the character for all projects the
+/// character has access to.
+/// Automatic tags are also re-evaluated whenever a project is created or
+/// updated.
 /// 
 /// ## Security
 /// - authenticated
@@ -53,7 +56,8 @@ pub async fn api(
 ) -> Result<impl IntoResponse> {
     trigger(
         &state.postgres,
-        identity.character_id,
+        &eve_gateway_api_client()?,
+        (&identity).into(),
     ).await?;
 
     Ok((
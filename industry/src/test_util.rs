@@ -1,9 +1,9 @@
 use serde::Serialize;
-use starfoundry_lib_eve_gateway::{ApiClientExtended, EveGatewayApiClient, EveGatewayApiClientAsset, EveGatewayApiClientEveAsset, EveGatewayApiClientFitting, EveGatewayApiClientIndustry, EveGatewayApiClientItem, EveGatewayApiClientMarket, EveGatewayApiClientSearch, EveGatewayApiClientStanding, EveGatewayApiClientSystem};
+use starfoundry_lib_eve_gateway::{ApiClientExtended, AssetStockFilter, EveGatewayApiClient, EveGatewayApiClientAsset, EveGatewayApiClientEveAsset, EveGatewayApiClientFitting, EveGatewayApiClientIndustry, EveGatewayApiClientItem, EveGatewayApiClientMarket, EveGatewayApiClientSearch, EveGatewayApiClientStanding, EveGatewayApiClientSystem, EveGatewayApiClientWallet};
 use starfoundry_lib_eve_gateway::contract::EveGatewayApiClientContract;
 use starfoundry_lib_gateway::ApiClient;
 use starfoundry_lib_market::{MarketApiClient, MarketApiClientOrder, MarketApiClientPrice};
-use starfoundry_lib_types::{SystemId, TypeId};
+use starfoundry_lib_types::{CharacterId, SystemId, TypeId};
 
 #[derive(Clone)]
 pub struct EveGatewayTestApiClient;
@@ -25,6 +25,7 @@ impl EveGatewayApiClientItem for EveGatewayTestApiClient {}
 impl EveGatewayApiClientSearch for EveGatewayTestApiClient {}
 impl EveGatewayApiClientStanding for EveGatewayTestApiClient {}
 impl EveGatewayApiClientSystem for EveGatewayTestApiClient {}
+impl EveGatewayApiClientWallet for EveGatewayTestApiClient {}
 
 #[derive(Clone)]
 pub struct MarketTestApiClient;
@@ -168,6 +169,22 @@ impl ApiClient for EveGatewayTestApiClient {
         let path = path.into();
         dbg!(&path);
         let response = match path.as_ref() {
+            "characters/bulk" => {
+                let character_ids: Vec<CharacterId> = serde_json::from_value(serde_json::to_value(&data).unwrap()).unwrap();
+                let result = character_ids
+                    .into_iter()
+                    .filter(|x| **x == 1 || **x == 2)
+                    .map(|x| serde_json::json!({
+                        "alliance_id": 1,
+                        "alliance_name": "SomeAlliance",
+                        "character_id": *x,
+                        "character_name": "SomeCharacter",
+                        "corporation_id": 1,
+                        "corporation_name": "SomeCorporation",
+                    }))
+                    .collect::<Vec<_>>();
+                serde_json::json!(result)
+            },
             "items" => {
                 let mut result = Vec::new();
 
@@ -304,6 +321,149 @@ impl ApiClient for EveGatewayTestApiClient {
 
                 serde_json::json!(result)
             },
+            "items/parse/fit" => {
+                serde_json::json!([{
+                    "name": "Hauler",
+                    "ship": {
+                        "item_name": "Charon",
+                        "quantity": 1,
+                        "type_id": 20185,
+                        "material_efficiency": null,
+                        "raw": {
+                            "meta_group": null,
+                            "name": "Charon",
+                            "repackaged": 10000000,
+                            "type_id": 20185,
+                            "volume": 100000000,
+                            "category": {
+                                "category_id": 0,
+                                "name": "#System"
+                            },
+                            "group": {
+                                "group_id": 0,
+                                "category_id": 0,
+                                "name": "#System"
+                            }
+                        }
+                    },
+                    "items": [{
+                        "item_name": "Capital Cargo Bay",
+                        "quantity": 2,
+                        "type_id": 21027,
+                        "material_efficiency": null,
+                        "raw": {
+                            "meta_group": 4,
+                            "name": "Capital Cargo Bay",
+                            "repackaged": 10000000,
+                            "type_id": 21027,
+                            "volume": 100000000,
+                            "category": {
+                                "category_id": 0,
+                                "name": "#System"
+                            },
+                            "group": {
+                                "group_id": 0,
+                                "category_id": 0,
+                                "name": "#System"
+                            }
+                        }
+                    }],
+                    "invalid": []
+                }])
+            },
+            "eve/assets/stock" => {
+                let filter: AssetStockFilter = serde_json::from_value(serde_json::to_value(&data).unwrap()).unwrap();
+                let mut stock = vec![serde_json::json!({
+                    "type_id": 4051,
+                    "quantity": 1000,
+                    "location_id": 1046664001931_i64,
+                    "location_flag": "CorpSAG1",
+                    "container_name": null
+                })];
+                // assembled ship in the hangar
+                if filter.include_assembled {
+                    stock.push(serde_json::json!({
+                        "type_id": 20185,
+                        "quantity": 1,
+                        "location_id": 1046664001931_i64,
+                        "location_flag": "CorpSAG1",
+                        "container_name": null
+                    }));
+                }
+                serde_json::json!(stock)
+            },
+            "eve/wallet/journal" => {
+                serde_json::json!([{
+                    "owner_id": 1,
+                    "division": 1,
+                    "id": 1001,
+                    "date": "2026-10-01T12:00:00Z",
+                    "ref_type": "industry_job_tax",
+                    "amount": -50000.0,
+                    "context_id": 5001,
+                    "context_id_type": "industry_job_id"
+                }, {
+                    "owner_id": 1,
+                    "division": 1,
+                    "id": 1002,
+                    "date": "2026-10-01T13:00:00Z",
+                    "ref_type": "industry_job_tax",
+                    "amount": -20000.0,
+                    "context_id": 9999,
+                    "context_id_type": "industry_job_id"
+                }])
+            },
+            "eve/wallet/transactions" => {
+                serde_json::json!([{
+                    "owner_id": 1,
+                    "division": 1,
+                    "transaction_id": 2001,
+                    "date": "2026-10-01T10:00:00Z",
+                    "type_id": 4051,
+                    "quantity": 400,
+                    "unit_price": 100.0,
+                    "client_id": 2,
+                    "location_id": 60003760,
+                    "is_buy": true
+                }, {
+                    "owner_id": 1,
+                    "division": 1,
+                    "transaction_id": 2002,
+                    "date": "2026-10-01T11:00:00Z",
+                    "type_id": 4051,
+                    "quantity": 400,
+                    "unit_price": 110.0,
+                    "client_id": 2,
+                    "location_id": 60003760,
+                    "is_buy": true
+                }, {
+                    "owner_id": 1,
+                    "division": 1,
+                    "transaction_id": 2003,
+                    "date": "2026-10-01T11:30:00Z",
+                    "type_id": 34,
+                    "quantity": 1000,
+                    "unit_price": 5.0,
+                    "client_id": 2,
+                    "location_id": 60003760,
+                    "is_buy": true
+                }])
+            },
+            "industry/blueprints/dependencies/bulk" => {
+                serde_json::json!([])
+            },
+            "structures/services/fuel" => {
+                let data: Vec<TypeId> = serde_json::from_value(serde_json::to_value(&data).unwrap()).unwrap();
+                if data.contains(&TypeId(35892)) {
+                    serde_json::json!([{
+                        "type_id": 35892,
+                        "fuel_per_hour": 40,
+                        "fuel_online": 0
+                    }])
+                } else {
+                    serde_json::json!([])
+                }
+            },
             "systems" => {
                 let data: Vec<SystemId> = serde_json::from_value(serde_json::to_value(&data).unwrap()).unwrap();
                 if data.is_empty() {
@@ -367,12 +527,29 @@ impl ApiClientExtended for EveGatewayTestApiClient {
 impl ApiClient for MarketTestApiClient {
     async fn fetch<Q: Serialize, T>(
             &self,
-            _path:  impl Into<String>,
+            path:   impl Into<String>,
             _query: &Q,
         ) -> starfoundry_lib_gateway::Result<T>
         where
             T: serde::de::DeserializeOwned {
-        unimplemented!()
+
+        let path = path.into();
+        let response = match path.as_ref() {
+            "prices" => {
+                serde_json::json!([
+                    { "type_id": 4051, "adjusted_price": 11_000f64, "average_price": 12_000f64 },
+                    { "type_id": 4246, "adjusted_price": 9_000f64,  "average_price": 10_000f64 },
+                    { "type_id": 4247, "adjusted_price": 10_000f64, "average_price": 11_000f64 },
+                    { "type_id": 4312, "adjusted_price": 12_000f64, "average_price": 13_000f64 },
+                    { "type_id": 81143, "adjusted_price": 900f64,   "average_price": 1_000f64 },
+                ])
+            },
+            _ => unimplemented!(),
+        };
+
+        Ok(
+            serde_json::from_value(response).unwrap(),
+        )
     }
 
     async fn post<D, T>(
@@ -0,0 +1,162 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::CharacterId;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{AuditEventUuid, ProjectGroupUuid};
+
+/// Single change to a project, project group, structure or industry hub
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "id": "019a0b7e-5a3f-7c41-a7e0-0c5f0c9d9a10",
+        "character_id": 2117441999,
+        "entity_type": "PROJECT",
+        "entity_id": "019a0b7e-1c2d-7e2f-9d1b-2a7d5b6e4f01",
+        "project_group_id": "019a0b7d-8f1e-7a3c-b2d4-6e5f4a3b2c10",
+        "scope": "PROJECT_JOB",
+        "action": "UPDATE",
+        "before": [{
+            "id": "019a0b7e-2b3c-7d4e-8f5a-6b7c8d9e0f11",
+            "status": "WAITING_FOR_MATERIALS"
+        }],
+        "after": [{
+            "id": "019a0b7e-2b3c-7d4e-8f5a-6b7c8d9e0f11",
+            "status": "BUILDING"
+        }],
+        "created_at": "2026-10-19T12:00:00Z"
+    })
+)]
+pub struct AuditEvent {
+    pub id:               AuditEventUuid,
+    /// Character that did the change
+    pub character_id:     CharacterId,
+    pub entity_type:      AuditEntity,
+    pub entity_id:        Uuid,
+    /// Group the entity belongs to, only set for projects and project groups
+    pub project_group_id: Option<ProjectGroupUuid>,
+    /// Part of the entity that was changed
+    pub scope:            AuditScope,
+    pub action:           AuditAction,
+    /// Values that were removed or replaced by the change
+    pub before:           serde_json::Value,
+    /// Values that were added by the change
+    pub after:            serde_json::Value,
+    pub created_at:       DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditFilter {
+    /// Only include events after the given time
+    #[serde(default)]
+    #[param(
+        example = json!("2026-10-01T00:00:00Z"),
+        required = false,
+    )]
+    pub from:      Option<DateTime<Utc>>,
+
+    /// Only include events before the given time
+    #[serde(default)]
+    #[param(
+        example = json!("2026-10-19T00:00:00Z"),
+        required = false,
+    )]
+    pub to:        Option<DateTime<Utc>>,
+
+    /// Only include events that changed the given part of the entity
+    #[serde(default)]
+    #[param(
+        example = json!("PROJECT_JOB"),
+        required = false,
+    )]
+    pub scope:     Option<AuditScope>,
+
+    /// Only include events of the given entity, only relevant for project
+    /// groups, as they include the events of all their projects
+    #[serde(default)]
+    #[param(
+        example = json!("019a0b7e-1c2d-7e2f-9d1b-2a7d5b6e4f01"),
+        required = false,
+    )]
+    pub entity_id: Option<Uuid>,
+
+    /// Maximum number of events, defaults to 250
+    #[serde(default)]
+    #[param(
+        example = json!(250),
+        required = false,
+    )]
+    pub limit:     Option<i64>,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "AUDIT_ENTITY")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AuditEntity {
+    Project,
+    ProjectGroup,
+    Structure,
+    IndustryHub,
+    Store,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "AUDIT_ACTION")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    Archive,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "AUDIT_SCOPE")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AuditScope {
+    Project,
+    ProjectJob,
+    ProjectMarket,
+    ProjectExcess,
+    ProjectMisc,
+    ProjectReservation,
+    ProjectAssetStock,
+    ProjectWallet,
+
+    ProjectGroup,
+    ProjectGroupDefaultBlacklist,
+    ProjectGroupDefaultBlueprintOverwrite,
+    ProjectGroupDefaultFreight,
+    ProjectGroupDefaultJobSplitting,
+    ProjectGroupDefaultMarket,
+    ProjectGroupIndustryHub,
+    ProjectGroupMember,
+    ProjectGroupRole,
+    ProjectGroupGrant,
+    ProjectGroupStockTarget,
+    ProjectGroupTemplate,
+
+    Structure,
+    StructureFuel,
+    IndustryHub,
+
+    Store,
+    StoreOrder,
+}
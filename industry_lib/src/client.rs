@@ -8,6 +8,8 @@ use url::Url;
 use crate::error::{Error, Result};
 use crate::industry::IndustryApiClientIndustry;
 use crate::project::IndustryApiClientProject;
+use crate::project_template::IndustryApiClientProjectTemplate;
+use crate::stock_target::IndustryApiClientStockTarget;
 use crate::tag::IndustryApiClientTag;
 
 pub const ENV_INDUSTRY_API: &str = "STARFOUNDRY_INDUSTRY_API_URL";
@@ -121,6 +123,8 @@ impl ApiClient for IndustryClient {
 impl IndustryApiClient for IndustryClient {}
 impl IndustryApiClientIndustry for IndustryClient {}
 impl IndustryApiClientProject for IndustryClient {}
+impl IndustryApiClientProjectTemplate for IndustryClient {}
+impl IndustryApiClientStockTarget for IndustryClient {}
 impl IndustryApiClientTag for IndustryClient {}
 
 /// Trait that should be implemented on all clients
@@ -130,4 +134,6 @@ pub trait IndustryApiClient:
     ApiClient +
     IndustryApiClientIndustry +
     IndustryApiClientProject +
+    IndustryApiClientProjectTemplate +
+    IndustryApiClientStockTarget +
     IndustryApiClientTag {}
@@ -1,9 +1,13 @@
 mod calculation;
+mod fitting;
+mod sensitivity;
 mod stock;
 
 use crate::Result;
 
 pub use self::calculation::*;
+pub use self::fitting::*;
+pub use self::sensitivity::*;
 pub use self::stock::*;
 
 use starfoundry_lib_gateway::ApiClient;
@@ -22,4 +26,18 @@ pub trait IndustryApiClientIndustry: ApiClient {
             .await
             .map_err(Into::into)
     }
+
+    #[allow(async_fn_in_trait)]
+    async fn sensitivity(
+        &self,
+        request: &SensitivityRequest,
+    ) -> Result<SensitivityResponse> {
+        self
+            .post(
+                "industry/calculation/sensitivity",
+                request,
+            )
+            .await
+            .map_err(Into::into)
+    }
 }
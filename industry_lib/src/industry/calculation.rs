@@ -1,11 +1,11 @@
 use serde::{Deserialize, Serialize};
-use starfoundry_lib_eve_gateway::Item;
-use starfoundry_lib_types::{StructureId, TypeId};
+use starfoundry_lib_eve_gateway::{AssetStockFilter, Item};
+use starfoundry_lib_types::{LocationId, StructureId, TypeId};
 use utoipa::ToSchema;
 
-use crate::{ProjectGroupUuid, ProjectJobUuid, SolutionUuid};
+use crate::{IndustryHubUuid, ProjectGroupUuid, ProjectJobUuid, ProjectUuid, SolutionUuid};
 use crate::industry_hub::IndustryHub;
-use crate::industry::StockMinimal;
+use crate::industry::{FittingImport, StockMinimal};
 use crate::structure::Structure;
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
@@ -15,15 +15,52 @@ pub struct BuildEngine {
     pub products_str:               Option<String>,
     pub additional_products:        Option<Vec<BuildEngineAdditionalProduct>>,
     pub additional_products_str:    Option<String>,
+    /// Fits that are expanded into products, modules that are bought are
+    /// added as additional products
+    #[serde(default)]
+    pub fittings:                   Option<Vec<FittingImport>>,
 
     pub stocks:                     Option<Vec<StockMinimal>>,
     pub stocks_str:                 Option<String>,
+    /// Adds the synced assets matching the filter to the stock
+    #[serde(default)]
+    pub stocks_from_assets:         Option<AssetStockFilter>,
+    /// Location of the stock, quantities that projects of the group reserved
+    /// there are removed from the stock.
+    /// If not set, the reservations of all locations are removed
+    #[serde(default)]
+    pub stock_location_id:          Option<LocationId>,
+    /// Project the calculation is for, its own reservations are not removed
+    /// from the stock
+    #[serde(default)]
+    pub project_id:                 Option<ProjectUuid>,
 
     pub blacklist:                  Option<Vec<TypeId>>,
     pub blueprint_overwrite:        Option<Vec<TmpBlueprintOverwrite>>,
     pub job_splitting:              Option<Vec<TmpJobSplitting>>,
     pub markets:                    Option<Vec<StructureId>>,
     pub calculate_market_cost:      Option<bool>,
+
+    /// Combines the structures of all industry hubs into a single solution,
+    /// instead of one solution per hub.
+    /// Intermediates that are build in one hub and used in another are
+    /// reported as transfers and priced with the freight rate of the group
+    #[serde(default)]
+    pub combine_industry_hubs:      Option<bool>,
+    /// Overwrites the freight rate of the project group, ISK per m3
+    #[serde(default)]
+    pub freight_rate:               Option<f64>,
+    /// Limits the calculation to the given industry hubs of the project group.
+    /// If not set, all hubs of the group are used
+    #[serde(default)]
+    pub industry_hubs:              Option<Vec<IndustryHubUuid>>,
+
+    /// Plans planetary products as factory cycles instead of buying them
+    #[serde(default)]
+    pub expand_planetary:           Option<bool>,
+    /// Tax of the customs offices in percent, defaults to 10%
+    #[serde(default)]
+    pub customs_office_tax:         Option<f64>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
@@ -48,9 +85,54 @@ pub struct TmpJobSplitting {
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct BuildEngineResponse {
     pub solution_id:    SolutionUuid,
+    /// Hub the products are build in
     pub industry_hub:   IndustryHub,
+    /// All hubs the solution uses, only contains more than one hub if the
+    /// hubs were combined
+    #[serde(default)]
+    pub industry_hubs:  Vec<IndustryHub>,
     pub material:       Vec<BuildEngineMaterialResponse>,
     pub manufacturing:  Vec<BuildEngineManufacturingResponse>,
+    /// Intermediates that must be moved between the hubs
+    #[serde(default)]
+    pub transfers:      Vec<BuildEngineTransferResponse>,
+    /// Planetary products that are produced instead of bought, only set if
+    /// `expand_planetary` was requested
+    #[serde(default)]
+    pub planetary:      Vec<BuildEnginePlanetaryResponse>,
+}
+
+/// Intermediate that is build in one industry hub and used in another
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct BuildEngineTransferResponse {
+    pub item:        Item,
+    pub quantity:    u32,
+    /// Total volume in m3
+    pub volume:      f64,
+    /// Freight cost for moving the volume
+    pub cost:        f64,
+    pub source:      IndustryHubUuid,
+    pub destination: IndustryHubUuid,
+}
+
+/// Single planetary product, either extracted (tier 0) or produced in
+/// planetary factories
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct BuildEnginePlanetaryResponse {
+    pub item:            Item,
+    /// 0 for raw resources, 1 to 4 for factory products
+    pub tier:            u8,
+    pub needed:          f32,
+    /// Quantity that is extracted or produced by all cycles
+    pub quantity:        u32,
+    /// Number of factory cycles, 0 for raw resources
+    pub cycles:          u32,
+    /// Duration of a single cycle in seconds
+    pub cycle_time:      i32,
+    /// Planet types the raw resource can be extracted from
+    pub planet_type_ids: Vec<TypeId>,
+    /// Customs office tax for exporting the product and importing its inputs
+    pub customs_tax:     f64,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::{FittingId, TypeId};
+use utoipa::ToSchema;
+
+/// Fit that is expanded into its hull and modules
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "fit": "[Nyx, Ratting]\nCapital Armor Repairer II",
+        "multiplier": 2,
+        "buy": [2048]
+    })
+)]
+pub struct FittingImport {
+    /// EFT, DNA or the XML export of the in-game fitting window, may contain
+    /// multiple fits
+    #[serde(default)]
+    pub fit:        Option<String>,
+    /// Fitting of the character that is stored in game, only used if `fit`
+    /// is not set
+    #[serde(default)]
+    pub fitting_id: Option<FittingId>,
+    /// Number of ships that are build, defaults to 1
+    #[serde(default = "default_multiplier")]
+    pub multiplier: u32,
+    /// Hulls and modules that are bought instead of build
+    #[serde(default)]
+    pub buy:        Vec<TypeId>,
+    /// Modules that are build, even though they are bought by default
+    #[serde(default)]
+    pub build:      Vec<TypeId>,
+}
+
+fn default_multiplier() -> u32 {
+    1
+}
@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::{StructureId, TypeId};
+use utoipa::ToSchema;
+
+use crate::{IndustryHubUuid, ProjectGroupUuid};
+use crate::industry::{BuildEngineProduct, StockMinimal, TmpBlueprintOverwrite, TmpJobSplitting};
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct SensitivityRequest {
+    pub project_group_id:       ProjectGroupUuid,
+    /// Industry hub of the project group the products are build in
+    pub industry_hub_id:        IndustryHubUuid,
+    pub products:               Vec<BuildEngineProduct>,
+    #[serde(default)]
+    pub stocks:                 Vec<StockMinimal>,
+
+    pub blacklist:              Option<Vec<TypeId>>,
+    pub blueprint_overwrite:    Option<Vec<TmpBlueprintOverwrite>>,
+    pub job_splitting:          Option<Vec<TmpJobSplitting>>,
+    /// Markets the materials are bought from, if not set the adjusted prices
+    /// are used
+    pub markets:                Option<Vec<StructureId>>,
+
+    /// Every variation is calculated on its own against the baseline
+    pub variations:             Vec<SensitivityVariation>,
+}
+
+/// Single input that is changed compared to the baseline
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SensitivityVariation {
+    /// Material efficiency of the blueprint that builds the given product
+    BlueprintMaterialEfficiency {
+        type_id:             TypeId,
+        material_efficiency: u32,
+    },
+    /// Replaces all rigs of the industry hub with rigs of the given tier
+    RigTier {
+        tier: RigTier,
+    },
+    /// Changes the cost index of all systems by the given percent
+    SystemCostIndex {
+        percent: f32,
+    },
+    /// Changes the price of the given material by the given percent, if no
+    /// material is given, the price of all materials is changed
+    MaterialPrice {
+        type_id: Option<TypeId>,
+        percent: f64,
+    },
+    /// Buys the given intermediate instead of building it
+    Blacklist {
+        type_id: TypeId,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RigTier {
+    /// No rigs at all
+    None,
+    Tech1,
+    Tech2,
+}
+
+impl RigTier {
+    /// Material and time bonus of a rig of the tier in percent, before the
+    /// security multiplier of the structure is applied
+    #[must_use]
+    pub fn bonus(&self) -> Option<(f32, f32)> {
+        match self {
+            Self::None  => None,
+            Self::Tech1 => Some((2.0f32, 20f32)),
+            Self::Tech2 => Some((2.4f32, 24f32)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct SensitivityResponse {
+    pub baseline:   SensitivityResult,
+    pub variations: Vec<SensitivityVariationResult>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct SensitivityVariationResult {
+    pub variation:  SensitivityVariation,
+    pub result:     SensitivityResult,
+    /// Calculated as `result - baseline`
+    pub difference: SensitivityResult,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct SensitivityResult {
+    /// Cost of all materials that need to be bought
+    pub material_cost: f64,
+    pub build_tax:     f64,
+    pub total_cost:    f64,
+    /// Duration of all jobs together in seconds
+    pub time:          f64,
+    /// Duration of the longest job in seconds
+    pub longest_job:   f64,
+    /// Number of jobs that could not be placed in a structure of the hub
+    pub missing_jobs:  i64,
+}
+
+impl SensitivityResult {
+    /// Difference of all values, calculated as `self - baseline`
+    #[must_use]
+    pub fn difference(
+        &self,
+        baseline: &Self,
+    ) -> Self {
+        Self {
+            material_cost: self.material_cost - baseline.material_cost,
+            build_tax:     self.build_tax - baseline.build_tax,
+            total_cost:    self.total_cost - baseline.total_cost,
+            time:          self.time - baseline.time,
+            longest_job:   self.longest_job - baseline.longest_job,
+            missing_jobs:  self.missing_jobs - baseline.missing_jobs,
+        }
+    }
+}
@@ -1,10 +1,13 @@
 mod client;
 mod error;
 
+pub mod audit;
 pub mod industry_hub;
 pub mod industry;
 pub mod project;
 pub mod project_group;
+pub mod project_template;
+pub mod stock_target;
 pub mod structure;
 pub mod tag;
 
@@ -19,11 +22,15 @@ pub use starfoundry_lib_market::OreReprocessingEfficiency;
 
 pub const ENV_INDUSTRY_API: &str = "STARFOUNDRY_INDUSTRY_API_URL";
 
+starfoundry_uuid!(AuditEventUuid, "AuditEventUuid");
 starfoundry_uuid!(IndustryHubUuid, "IndustryHubUuid");
 starfoundry_uuid!(MarketUuid, "MarketUuid");
+starfoundry_uuid!(ProjectGroupRoleUuid, "ProjectGroupRoleUuid");
 starfoundry_uuid!(ProjectGroupUuid, "ProjectGroupUuid");
 starfoundry_uuid!(ProjectJobUuid, "ProjectJobUuid");
+starfoundry_uuid!(ProjectTemplateUuid, "ProjectTemplateUuid");
 starfoundry_uuid!(ProjectUuid, "ProjectUuid");
 starfoundry_uuid!(SolutionUuid, "SolutionUuid");
+starfoundry_uuid!(StockTargetUuid, "StockTargetUuid");
 starfoundry_uuid!(StructureUuid, "StructureUuid");
 starfoundry_uuid!(TagUuid, "TagUuid");
@@ -1,18 +1,28 @@
 mod add;
+mod asset_stock;
 mod check;
 mod create;
+mod delivery;
+mod export;
 mod fetch;
 mod list;
+mod reservation;
 mod update;
 mod status;
+mod wallet;
 
 pub use self::add::*;
+pub use self::asset_stock::*;
 pub use self::create::*;
+pub use self::delivery::*;
+pub use self::export::*;
 pub use self::check::*;
 pub use self::fetch::*;
 pub use self::list::*;
+pub use self::reservation::*;
 pub use self::update::*;
 pub use self::status::*;
+pub use self::wallet::*;
 
 use starfoundry_lib_gateway::ApiClient;
 
@@ -253,6 +263,20 @@ pub trait IndustryApiClientProject: ApiClient {
             .map_err(Into::into)
     }
 
+    #[allow(async_fn_in_trait)]
+    async fn project_list_reservations(
+        &self,
+        project_id: &ProjectUuid,
+    ) -> Result<Vec<ProjectReservation>> {
+        self
+            .fetch(
+                format!("projects/{project_id}/reservations"),
+                &(),
+            )
+            .await
+            .map_err(Into::into)
+    }
+
     #[allow(async_fn_in_trait)]
     async fn project_list_stock(
         &self,
@@ -267,6 +291,34 @@ pub trait IndustryApiClientProject: ApiClient {
             .map_err(Into::into)
     }
 
+    #[allow(async_fn_in_trait)]
+    async fn project_refresh_asset_stock(
+        &self,
+        project_id: &ProjectUuid,
+    ) -> Result<()> {
+        self
+            .post(
+                format!("projects/{project_id}/asset-stock/refresh"),
+                (),
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    #[allow(async_fn_in_trait)]
+    async fn project_refresh_wallet(
+        &self,
+        project_id: &ProjectUuid,
+    ) -> Result<()> {
+        self
+            .post(
+                format!("projects/{project_id}/wallet/refresh"),
+                (),
+            )
+            .await
+            .map_err(Into::into)
+    }
+
     #[allow(async_fn_in_trait)]
     async fn project_update(
         &self,
@@ -282,6 +334,21 @@ pub trait IndustryApiClientProject: ApiClient {
             .map_err(Into::into)
     }
 
+    #[allow(async_fn_in_trait)]
+    async fn project_update_reservations(
+        &self,
+        project_id: &ProjectUuid,
+        request:    &Vec<ProjectReservationEntry>,
+    ) -> Result<()> {
+        self
+            .put(
+                format!("projects/{project_id}/reservations"),
+                request,
+            )
+            .await
+            .map_err(Into::into)
+    }
+
     #[allow(async_fn_in_trait)]
     async fn project_update_job(
         &self,
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_eve_gateway::{AssetStockFilter, Item};
+use utoipa::ToSchema;
+
+/// Stock of a project that is taken from the synced assets
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectAssetStock {
+    /// Filter that selects the assets that are used as stock
+    pub filter:       AssetStockFilter,
+    /// Last time the assets were taken over, `None` if they were never
+    /// refreshed
+    pub refreshed_at: Option<DateTime<Utc>>,
+    pub materials:    Vec<ProjectAssetStockMaterial>,
+}
+
+/// Coverage of a single material the project still needs.
+///
+/// The reservations of the other projects in the project group are taken
+/// from the assets first.
+///
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectAssetStockMaterial {
+    pub item:     Item,
+    /// Quantity the project needs
+    pub needed:   i32,
+    /// Quantity that is covered by the assets
+    pub covered:  i32,
+    /// Quantity that would be in the assets, but is reserved by other projects
+    pub reserved: i32,
+    /// Quantity that is neither covered nor reserved
+    pub missing:  i32,
+    pub status:   ProjectAssetStockStatus,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProjectAssetStockStatus {
+    /// The assets cover the full quantity
+    Covered,
+    /// At least a part of the quantity is reserved by another project
+    Reserved,
+    /// At least a part of the quantity is not in the assets
+    Missing,
+}
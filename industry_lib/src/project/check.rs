@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use starfoundry_lib_eve_gateway::Item;
-use starfoundry_lib_types::TypeId;
+use starfoundry_lib_types::{LocationId, TypeId};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -10,11 +10,18 @@ use crate::{ProjectJobUuid, StructureUuid};
 /// Either `materials` or `materials_str` is required
 /// If `materials_str` is given, they will be resolved to their type_id and quantity
 /// 
+/// Quantities reserved by other projects of the same group are removed from
+/// the materials.
+/// 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct CheckMaterialsRequest {
     pub job_ids:        Vec<ProjectJobUuid>,
     pub materials:      Option<Vec<Material>>,
     pub materials_str:  Option<String>,
+    /// Location the materials are in, if not set the reservations of all
+    /// locations are removed
+    #[serde(default)]
+    pub location_id:    Option<LocationId>,
 }
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize, ToSchema)]
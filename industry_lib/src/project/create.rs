@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::{Error, ProjectGroupUuid, ProjectUuid, Result};
+use crate::industry::FittingImport;
+use crate::{Error, ProjectGroupUuid, ProjectUuid, Result, SolutionUuid};
 use uuid::Uuid;
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
@@ -15,6 +16,15 @@ pub struct CreateProject {
 
     pub pre_products:       Option<String>,
     pub pre_additional:     Option<String>,
+    /// Fits that are added to the products, modules that are bought are
+    /// added to the additional products
+    #[serde(default)]
+    pub fittings:           Option<Vec<FittingImport>>,
+
+    /// Initializes the project with the jobs, materials and stock of the
+    /// solution, the solution must belong to the same project group
+    #[serde(default)]
+    pub solution_id:        Option<SolutionUuid>,
 }
 
 impl CreateProject {
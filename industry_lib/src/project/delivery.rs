@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_eve_gateway::Item;
+use starfoundry_lib_types::{StructureId, TypeId};
+use utoipa::ToSchema;
+
+/// Part of the shopping list that can be pasted into the in-game MultiBuy
+/// window
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "source": 1046664001931_i64,
+        "volume": 12500,
+        "cost": 2500000,
+        "multibuy": "Tritanium 1000000\nPyerite 250000"
+    })
+)]
+pub struct ProjectMultiBuy {
+    /// Market the items are bought in
+    pub source:   StructureId,
+    /// Total volume in m3
+    pub volume:   f64,
+    /// Total cost with the selected prices
+    pub cost:     f64,
+    /// One line per item, in the format of the MultiBuy window
+    pub multibuy: String,
+}
+
+/// Items that should be contracted, if no items are given the products of
+/// the project are used
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct DeliveryManifestRequest {
+    #[serde(default)]
+    pub items: Vec<DeliveryManifestEntry>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct DeliveryManifestEntry {
+    pub type_id:  TypeId,
+    pub quantity: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct DeliveryManifest {
+    pub items:      Vec<DeliveryManifestItem>,
+    /// Total volume in m3, ships are counted with their repackaged volume
+    pub volume:     f64,
+    /// Suggested collateral based on the average prices
+    pub collateral: f64,
+    /// One line per item, in the format of the in-game contract window
+    pub contract:   String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct DeliveryManifestItem {
+    pub item:     Item,
+    pub quantity: i64,
+    /// Volume of all units in m3
+    pub volume:   f64,
+    /// Average price of a single unit
+    pub price:    f64,
+}
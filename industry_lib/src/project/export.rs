@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::TypeId;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::{ProjectGroupUuid, StructureUuid};
+use crate::project::{ProjectJobStatus, ProjectStatus};
+use crate::structure::ExportStructure;
+
+/// Version of the export format, increased on breaking changes
+pub const PROJECT_EXPORT_VERSION: u32 = 1;
+
+/// Portable copy of a project.
+///
+/// ESI job ids and the characters that started the jobs are not exported,
+/// as they are bound to the instance the project was exported from.
+///
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectExport {
+    pub version:           u32,
+    pub exported_at:       DateTime<Utc>,
+
+    pub name:              String,
+    pub orderer:           String,
+    pub status:            ProjectStatus,
+    pub sell_price:        Option<f64>,
+    pub notes:             Option<String>,
+
+    pub products:          Vec<ProjectExportProduct>,
+    pub jobs:              Vec<ProjectExportJob>,
+    pub market:            Vec<ProjectExportMarket>,
+    pub misc:              Vec<ProjectExportMisc>,
+    pub excess:            Vec<ProjectExportStock>,
+    pub stock:             Vec<ProjectExportStock>,
+    /// Structures the market entries are bought in
+    pub market_structures: Vec<StructureUuid>,
+
+    /// All structures that are referenced by the export
+    pub structures:        Vec<ExportStructure>,
+}
+
+impl ProjectExport {
+    /// All type ids that are referenced by the export
+    #[must_use]
+    pub fn type_ids(&self) -> Vec<TypeId> {
+        let mut type_ids = self
+            .products
+            .iter()
+            .map(|x| x.type_id)
+            .chain(self.jobs.iter().map(|x| x.type_id))
+            .chain(self.market.iter().map(|x| x.type_id))
+            .chain(self.excess.iter().map(|x| x.type_id))
+            .chain(self.stock.iter().map(|x| x.type_id))
+            .collect::<Vec<_>>();
+        type_ids.sort();
+        type_ids.dedup();
+        type_ids
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectExportProduct {
+    pub type_id:             TypeId,
+    pub quantity:            i32,
+    pub material_efficiency: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectExportJob {
+    pub type_id:      TypeId,
+    pub runs:         i32,
+    pub status:       ProjectJobStatus,
+    pub cost:         Option<f64>,
+    /// References an entry of `structures`
+    pub structure_id: StructureUuid,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectExportMarket {
+    pub type_id:  TypeId,
+    pub quantity: i32,
+    pub cost:     Option<f64>,
+    pub source:   Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectExportMisc {
+    pub item:        String,
+    pub cost:        f64,
+    pub quantity:    Option<i32>,
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectExportStock {
+    pub type_id:  TypeId,
+    pub quantity: i32,
+    pub cost:     Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ImportProject {
+    /// Group the project is imported into
+    pub project_group_id:  ProjectGroupUuid,
+    /// Maps the structures of the export to structures of the character,
+    /// structures that are not mapped are matched by their in-game id
+    #[serde(default)]
+    pub structure_mapping: HashMap<StructureUuid, StructureUuid>,
+    pub project:           ProjectExport,
+}
@@ -57,6 +57,9 @@ pub struct ProjectCost {
     pub misc_cost:      f64,
     pub excess_cost:    f64,
     pub stock_cost:     f64,
+    /// Structure fuel that is amortised into the project, see
+    /// `/structures/{StructureUuid}/fuel`
+    pub upkeep_cost:    f64,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
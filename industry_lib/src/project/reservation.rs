@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_eve_gateway::Item;
+use starfoundry_lib_types::{LocationId, TypeId};
+use utoipa::ToSchema;
+
+/// Quantity of a material a project holds back in a location.
+///
+/// Other projects of the same group don't see reserved quantities, neither
+/// when checking resources nor when planning a new solution.
+/// Reservations are released when the project is done or deleted.
+///
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectReservation {
+    pub item:        Item,
+    pub source:      ProjectReservationSource,
+    pub location_id: LocationId,
+    pub quantity:    i64,
+}
+
+/// Single entry of the reservation ledger, all entries of a project are
+/// replaced on every update
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectReservationEntry {
+    pub type_id:     TypeId,
+    pub source:      ProjectReservationSource,
+    pub location_id: LocationId,
+    pub quantity:    i64,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq, PartialOrd, Ord,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "PROJECT_RESERVATION_SOURCE")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProjectReservationSource {
+    /// the quantity is taken from the stock
+    Stock,
+    /// the quantity is taken from the excess of other projects
+    Excess,
+}
@@ -0,0 +1,210 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_eve_gateway::{WalletJournalEntry, WalletTransactionEntry};
+use starfoundry_lib_types::{JobId, TypeId};
+use std::collections::{HashMap, HashSet};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{MarketUuid, ProjectJobUuid};
+
+/// Journal ref types that are paid when installing an industry job
+pub const JOB_REF_TYPES: &[&str] = &["industry_job_tax"];
+
+/// Result of the last wallet reconciliation of a project
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectWallet {
+    /// Last time the wallets were reconciled, `None` if they never were
+    pub reconciled_at: Option<DateTime<Utc>>,
+    pub matches:       Vec<ProjectWalletMatch>,
+    /// Spending during the project that could not be matched to an entry
+    pub unmatched:     Vec<ProjectWalletUnmatched>,
+}
+
+/// Wallet entry that was matched to a market or job entry of the project
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct ProjectWalletMatch {
+    pub kind:         ProjectWalletKind,
+    /// Id of the market or job entry
+    pub entry_id:     Uuid,
+    /// Transaction id for market entries, journal id for jobs
+    pub reference_id: i64,
+    pub quantity:     i32,
+    pub cost:         f64,
+}
+
+/// Spending that belongs to the project window, but could not be matched
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct ProjectWalletUnmatched {
+    pub kind:         ProjectWalletKind,
+    /// Transaction id for market entries, journal id for jobs
+    pub reference_id: i64,
+    pub type_id:      Option<TypeId>,
+    pub quantity:     Option<i32>,
+    pub amount:       f64,
+    pub date:         DateTime<Utc>,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "PROJECT_WALLET_KIND")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProjectWalletKind {
+    Market,
+    Job,
+}
+
+/// Market entry of a project that should be matched against transactions
+#[derive(Clone, Debug)]
+pub struct WalletMarketEntry {
+    pub id:       MarketUuid,
+    pub type_id:  TypeId,
+    pub quantity: i32,
+}
+
+/// Job of a project that should be matched against the journal
+#[derive(Clone, Debug)]
+pub struct WalletJobEntry {
+    pub id:     ProjectJobUuid,
+    pub job_id: JobId,
+}
+
+/// Outcome of [reconcile_wallet]
+#[derive(Clone, Debug, Default)]
+pub struct WalletReconciliation {
+    pub matches:      Vec<ProjectWalletMatch>,
+    pub unmatched:    Vec<ProjectWalletUnmatched>,
+    /// Market entries that are fully covered by transactions, with their cost
+    pub market_costs: Vec<(MarketUuid, f64)>,
+    /// Jobs with at least one journal entry, with the total fee
+    pub job_costs:    Vec<(ProjectJobUuid, f64)>,
+}
+
+/// Matches the wallet entries against the market and job entries of a
+/// project.
+///
+/// Market entries are filled in order from the oldest buy transactions of
+/// the same type, transactions can be split between entries.
+/// Jobs are matched by the job id the journal entry references.
+/// Buy transactions of a type the project needs and job fees that are left
+/// over are reported as unmatched.
+///
+/// Transactions and journal entries that are already used by other projects
+/// must be removed by the caller.
+///
+#[must_use]
+pub fn reconcile_wallet(
+    market:       &[WalletMarketEntry],
+    jobs:         &[WalletJobEntry],
+    transactions: Vec<WalletTransactionEntry>,
+    journal:      Vec<WalletJournalEntry>,
+) -> WalletReconciliation {
+    let mut result = WalletReconciliation::default();
+
+    let mut transactions = transactions
+        .into_iter()
+        .filter(|x| x.is_buy && x.quantity > 0)
+        .collect::<Vec<_>>();
+    transactions.sort_by_key(|x| (x.date, x.transaction_id));
+    let mut remaining = transactions
+        .iter()
+        .map(|x| x.quantity)
+        .collect::<Vec<_>>();
+
+    for entry in market {
+        let mut needed = entry.quantity;
+        let mut cost = 0f64;
+
+        for (transaction, left) in transactions.iter().zip(remaining.iter_mut()) {
+            if needed == 0 {
+                break;
+            }
+            if transaction.type_id != entry.type_id || *left == 0 {
+                continue;
+            }
+
+            let quantity = needed.min(*left);
+            let transaction_cost = quantity as f64 * transaction.unit_price;
+            needed -= quantity;
+            *left -= quantity;
+            cost += transaction_cost;
+
+            result.matches.push(ProjectWalletMatch {
+                kind:         ProjectWalletKind::Market,
+                entry_id:     *entry.id,
+                reference_id: *transaction.transaction_id,
+                quantity,
+                cost:         transaction_cost,
+            });
+        }
+
+        if needed == 0 && entry.quantity > 0 {
+            result.market_costs.push((entry.id, cost));
+        }
+    }
+
+    let project_types = market
+        .iter()
+        .map(|x| x.type_id)
+        .collect::<HashSet<_>>();
+    for (transaction, left) in transactions.iter().zip(remaining) {
+        if left == 0 || !project_types.contains(&transaction.type_id) {
+            continue;
+        }
+
+        result.unmatched.push(ProjectWalletUnmatched {
+            kind:         ProjectWalletKind::Market,
+            reference_id: *transaction.transaction_id,
+            type_id:      Some(transaction.type_id),
+            quantity:     Some(left),
+            amount:       left as f64 * transaction.unit_price,
+            date:         transaction.date,
+        });
+    }
+
+    let project_jobs = jobs
+        .iter()
+        .map(|x| (*x.job_id as i64, x.id))
+        .collect::<HashMap<_, _>>();
+    let mut job_costs: HashMap<ProjectJobUuid, f64> = HashMap::new();
+    for entry in journal {
+        if !JOB_REF_TYPES.contains(&entry.ref_type.as_str()) || entry.amount >= 0f64 {
+            continue;
+        }
+
+        let job = entry
+            .context_id
+            .and_then(|x| project_jobs.get(&x));
+        if let Some(job) = job {
+            *job_costs.entry(*job).or_default() += entry.amount.abs();
+            result.matches.push(ProjectWalletMatch {
+                kind:         ProjectWalletKind::Job,
+                entry_id:     **job,
+                reference_id: *entry.id,
+                quantity:     1,
+                cost:         entry.amount.abs(),
+            });
+        } else {
+            result.unmatched.push(ProjectWalletUnmatched {
+                kind:         ProjectWalletKind::Job,
+                reference_id: *entry.id,
+                type_id:      None,
+                quantity:     None,
+                amount:       entry.amount.abs(),
+                date:         entry.date,
+            });
+        }
+    }
+
+    let mut job_costs = job_costs
+        .into_iter()
+        .collect::<Vec<_>>();
+    job_costs.sort_by_key(|(x, _)| *x);
+    result.job_costs = job_costs;
+
+    result
+}
@@ -1,7 +1,11 @@
+mod export;
 mod fetch;
 mod list;
 mod permission;
+mod role;
 
+pub use self::export::*;
 pub use self::fetch::*;
 pub use self::list::*;
 pub use self::permission::*;
+pub use self::role::*;
@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::TypeId;
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::StructureUuid;
+use crate::structure::ExportStructure;
+
+/// Version of the export format, increased on breaking changes
+pub const PROJECT_GROUP_EXPORT_VERSION: u32 = 1;
+
+/// Portable copy of a project group with its defaults and industry hubs.
+///
+/// Members, roles and grants are not exported, as the characters differ
+/// between instances.
+///
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectGroupExport {
+    pub version:              u32,
+    pub exported_at:          DateTime<Utc>,
+
+    pub name:                 String,
+    pub description:          Option<String>,
+
+    pub blacklist:            Vec<TypeId>,
+    pub blueprint_overwrites: Vec<ProjectGroupExportBlueprintOverwrite>,
+    pub job_splitting:        Vec<ProjectGroupExportJobSplitting>,
+    pub freight_rate:         Option<f64>,
+    pub markets:              Vec<StructureUuid>,
+    pub industry_hubs:        Vec<ProjectGroupExportIndustryHub>,
+
+    /// All structures that are referenced by the export
+    pub structures:           Vec<ExportStructure>,
+}
+
+impl ProjectGroupExport {
+    /// All type ids that are referenced by the export
+    #[must_use]
+    pub fn type_ids(&self) -> Vec<TypeId> {
+        let mut type_ids = self
+            .blacklist
+            .iter()
+            .copied()
+            .chain(self.blueprint_overwrites.iter().map(|x| x.type_id))
+            .chain(self.job_splitting.iter().map(|x| x.type_id))
+            .collect::<Vec<_>>();
+        type_ids.sort();
+        type_ids.dedup();
+        type_ids
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectGroupExportBlueprintOverwrite {
+    pub type_id:             TypeId,
+    pub material_efficiency: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectGroupExportJobSplitting {
+    pub type_id:  TypeId,
+    pub max_runs: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectGroupExportIndustryHub {
+    pub name:        String,
+    pub description: Option<String>,
+    /// References entries of `structures`
+    pub structures:  Vec<StructureUuid>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ImportProjectGroup {
+    /// Maps the structures of the export to structures of the character,
+    /// structures that are not mapped are matched by their in-game id
+    #[serde(default)]
+    pub structure_mapping: HashMap<StructureUuid, StructureUuid>,
+    pub project_group:     ProjectGroupExport,
+}
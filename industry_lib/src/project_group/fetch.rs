@@ -3,7 +3,7 @@ use starfoundry_lib_eve_gateway::CharacterInfo;
 use utoipa::ToSchema;
 
 use crate::project_group::ProjectGroupPermission;
-use crate::ProjectGroupUuid;
+use crate::{ProjectGroupRoleUuid, ProjectGroupUuid};
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[schema(
@@ -51,11 +51,14 @@ pub struct ProjectGroup {
             "READ",
             "WRITE_GROUP"
         ],
+        "role_id": "019a0a3b-6a3c-7e43-9b7c-3bd1e0d1c6a1",
         "is_owner": false
     })
 )]
 pub struct ProjectGroupMember {
     pub character:      CharacterInfo,
+    /// Combined permissions of the member and its role
     pub permissions:    ProjectGroupPermission,
+    pub role_id:        Option<ProjectGroupRoleUuid>,
     pub is_owner:       bool,
 }
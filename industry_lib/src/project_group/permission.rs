@@ -12,7 +12,7 @@ const BIT_WRITE_DEFAULT:    i32 = 16i32;
 const BIT_WRITE_MEMBER:     i32 = 32i32;
 const BIT_WRITE_GROUP:      i32 = 64i32;
 
-#[derive(Clone, Debug, ToSchema)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ToSchema)]
 pub struct ProjectGroupPermission(i32);
 
 impl ProjectGroupPermission {
@@ -21,10 +21,17 @@ impl ProjectGroupPermission {
     }
 
     pub fn add(&mut self, permission: ProjectGroupPermissionCode) -> &mut Self {
-        self.0 += *permission;
+        self.0 |= *permission;
         self
     }
 
+    /// Checks if the permission is set, the owner has every permission
+    pub fn has(&self, permission: ProjectGroupPermissionCode) -> bool {
+        let owner = *ProjectGroupPermissionCode::Owner;
+        self.0 & owner == owner ||
+        self.0 & *permission == *permission
+    }
+
     pub fn as_permissions(
         &self,
     ) -> Vec<ProjectGroupPermissionCode> {
@@ -111,7 +118,7 @@ impl Serialize for ProjectGroupPermission {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ProjectGroupPermissionCode {
     Owner,
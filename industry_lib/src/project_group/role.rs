@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::project_group::ProjectGroupPermission;
+use crate::ProjectGroupRoleUuid;
+
+/// Named set of permissions that can be assigned to members, corporations
+/// and alliances of a group
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "id": "019a0a3b-6a3c-7e43-9b7c-3bd1e0d1c6a1",
+        "name": "Builder",
+        "permissions": [
+            "READ",
+            "WRITE_PROJECT"
+        ]
+    })
+)]
+pub struct ProjectGroupRole {
+    pub id:          ProjectGroupRoleUuid,
+    pub name:        String,
+    pub permissions: ProjectGroupPermission,
+}
+
+/// Grants a role to every character of a corporation or alliance
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "entity_type": "CORPORATION",
+        "entity_id": 98024275,
+        "role_id": "019a0a3b-6a3c-7e43-9b7c-3bd1e0d1c6a1"
+    })
+)]
+pub struct ProjectGroupGrant {
+    pub entity_type: ProjectGroupGrantEntity,
+    pub entity_id:   i32,
+    pub role_id:     ProjectGroupRoleUuid,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "PROJECT_GROUP_GRANT_ENTITY")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProjectGroupGrantEntity {
+    Corporation,
+    Alliance,
+}
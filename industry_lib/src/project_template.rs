@@ -0,0 +1,43 @@
+mod fetch;
+mod instantiate;
+mod update;
+
+pub use self::fetch::*;
+pub use self::instantiate::*;
+pub use self::update::*;
+
+use starfoundry_lib_gateway::ApiClient;
+
+use crate::{ProjectTemplateUuid, Result};
+use crate::project::CreateProjectResponse;
+
+pub trait IndustryApiClientProjectTemplate: ApiClient {
+    #[allow(async_fn_in_trait)]
+    async fn project_template_fetch(
+        &self,
+        template_id: &ProjectTemplateUuid,
+    ) -> Result<Option<ProjectTemplate>> {
+        self
+            .fetch(
+                format!("project-templates/{template_id}"),
+                &(),
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    #[allow(async_fn_in_trait)]
+    async fn project_template_instantiate(
+        &self,
+        template_id: &ProjectTemplateUuid,
+        request:     &InstantiateProjectTemplate,
+    ) -> Result<CreateProjectResponse> {
+        self
+            .post(
+                format!("project-templates/{template_id}/instantiate"),
+                request,
+            )
+            .await
+            .map_err(Into::into)
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_eve_gateway::Item;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{ProjectGroupUuid, ProjectTemplateUuid, TagUuid};
+
+/// Template projects of a project group can be created from
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectTemplate {
+    pub id:               ProjectTemplateUuid,
+    pub project_group_id: ProjectGroupUuid,
+
+    pub name:             String,
+    pub orderer:          String,
+    pub notes:            Option<String>,
+
+    pub products:         Vec<ProjectTemplateProduct>,
+    /// Tags that are added to the created projects
+    pub tags:             Vec<TagUuid>,
+
+    /// Days after which the template creates a new project, `None` if the
+    /// template is not scheduled
+    pub interval_days:    Option<i32>,
+    /// Next time the template creates a project
+    pub next_run_at:      Option<DateTime<Utc>>,
+    /// Failed scheduled runs since the last created project, every failure
+    /// delays the next run
+    pub failed_attempts:  i32,
+    /// Error of the last failed scheduled run
+    pub last_error:       Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectTemplateProduct {
+    pub item:     Item,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ProjectTemplateFilter {
+    #[serde(default)]
+    #[param(
+        example = json!("019b5d76-0ebd-77f4-80b0-12daf86501b6"),
+        required = false,
+    )]
+    pub project_group_id: Option<ProjectGroupUuid>,
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{Error, Result};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "multiplier": 2
+    })
+)]
+pub struct InstantiateProjectTemplate {
+    /// Multiplies the quantity of all products, defaults to 1
+    #[serde(default = "default_multiplier")]
+    pub multiplier: i32,
+}
+
+impl InstantiateProjectTemplate {
+    pub fn validate(&self) -> Result<bool> {
+        if self.multiplier <= 0 {
+            return Err(Error::ValidationError("Field 'multiplier' must be greater than 0".into()));
+        }
+
+        Ok(true)
+    }
+}
+
+impl Default for InstantiateProjectTemplate {
+    fn default() -> Self {
+        Self {
+            multiplier: default_multiplier(),
+        }
+    }
+}
+
+fn default_multiplier() -> i32 {
+    1
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::TypeId;
+use utoipa::ToSchema;
+
+use crate::{Error, ProjectGroupUuid, Result, TagUuid};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "project_group_id": "019a0e27-5e9c-7a53-8c52-e4ecb1ec0a77",
+        "name": "Fuel Blocks",
+        "orderer": "Alliance",
+        "products": [{
+            "type_id": 4051,
+            "quantity": 40000
+        }],
+        "interval_days": 7
+    })
+)]
+pub struct CreateProjectTemplate {
+    pub project_group_id: ProjectGroupUuid,
+    #[serde(flatten)]
+    pub template:         UpdateProjectTemplate,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "name": "Fuel Blocks",
+        "orderer": "Alliance",
+        "products": [{
+            "type_id": 4051,
+            "quantity": 40000
+        }],
+        "interval_days": 7
+    })
+)]
+pub struct UpdateProjectTemplate {
+    pub name:          String,
+    pub orderer:       String,
+    #[serde(default)]
+    pub notes:         Option<String>,
+
+    pub products:      Vec<ProjectTemplateProductEntry>,
+    /// Manual tags of the character that are added to the created projects
+    #[serde(default)]
+    pub tags:          Vec<TagUuid>,
+
+    /// Creates a new project every given days, in the name of the character
+    /// that last updated the template
+    #[serde(default)]
+    pub interval_days: Option<i32>,
+}
+
+impl UpdateProjectTemplate {
+    pub fn validate(&self) -> Result<bool> {
+        if self.name.len() <= 100 {
+            if self.name.trim().is_empty() {
+                return Err(Error::ValidationError("Field 'name' must be set".into()));
+            }
+        } else {
+            return Err(Error::ValidationError("Field 'name' is too long, max length: 100".into()));
+        };
+
+        if self.products.is_empty() {
+            return Err(Error::ValidationError("Field 'products' must not be empty".into()));
+        }
+
+        if self.products.iter().any(|x| x.quantity <= 0) {
+            return Err(Error::ValidationError("Field 'quantity' must be greater than 0".into()));
+        }
+
+        if self.interval_days.map(|x| x <= 0).unwrap_or_default() {
+            return Err(Error::ValidationError("Field 'interval_days' must be greater than 0".into()));
+        }
+
+        Ok(true)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "type_id": 4051,
+        "quantity": 40000
+    })
+)]
+pub struct ProjectTemplateProductEntry {
+    pub type_id:  TypeId,
+    pub quantity: i32,
+}
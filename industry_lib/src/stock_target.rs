@@ -0,0 +1,70 @@
+mod fetch;
+mod restock;
+mod update;
+
+pub use self::fetch::*;
+pub use self::restock::*;
+pub use self::update::*;
+
+use starfoundry_lib_gateway::ApiClient;
+
+use crate::{ProjectGroupUuid, Result};
+
+pub trait IndustryApiClientStockTarget: ApiClient {
+    #[allow(async_fn_in_trait)]
+    async fn stock_target_fetch(
+        &self,
+        project_group_id: &ProjectGroupUuid,
+    ) -> Result<Option<StockTargets>> {
+        self
+            .fetch(
+                format!("stock-targets/{project_group_id}"),
+                &(),
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    #[allow(async_fn_in_trait)]
+    async fn stock_target_update(
+        &self,
+        project_group_id: &ProjectGroupUuid,
+        request:          &UpdateStockTargets,
+    ) -> Result<()> {
+        self
+            .put(
+                format!("stock-targets/{project_group_id}"),
+                request,
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    #[allow(async_fn_in_trait)]
+    async fn stock_target_check(
+        &self,
+        project_group_id: &ProjectGroupUuid,
+    ) -> Result<Option<StockTargets>> {
+        self
+            .post(
+                format!("stock-targets/{project_group_id}/check"),
+                (),
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    #[allow(async_fn_in_trait)]
+    async fn stock_target_restock(
+        &self,
+        project_group_id: &ProjectGroupUuid,
+    ) -> Result<Option<StockTargetRestock>> {
+        self
+            .post(
+                format!("stock-targets/{project_group_id}/restock"),
+                (),
+            )
+            .await
+            .map_err(Into::into)
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_eve_gateway::Item;
+use starfoundry_lib_types::LocationId;
+use utoipa::ToSchema;
+
+use crate::StockTargetUuid;
+use crate::stock_target::StockTargetRestockMode;
+
+/// Stock targets of a project group compared against the synced assets
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct StockTargets {
+    pub restock:    StockTargetRestockMode,
+    /// Last time the targets were compared against the assets, `None` if
+    /// they were never checked
+    pub checked_at: Option<DateTime<Utc>>,
+    pub targets:    Vec<StockTarget>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct StockTarget {
+    pub id:          StockTargetUuid,
+    pub item:        Item,
+    pub location_id: LocationId,
+    /// Quantity that should be in stock
+    pub quantity:    i32,
+    /// Quantity that was in the assets during the last check
+    pub available:   i64,
+    /// Quantity that is build by restock projects that are not done yet
+    pub incoming:    i64,
+    /// Quantity that is neither available nor incoming
+    pub shortfall:   i64,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::ProjectUuid;
+use crate::stock_target::StockTarget;
+
+/// Project that was created for the shortfall of the stock targets
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct StockTargetRestock {
+    pub project_id: ProjectUuid,
+    /// Targets that are restocked by the project, `shortfall` is the quantity
+    /// the project builds
+    pub targets:    Vec<StockTarget>,
+}
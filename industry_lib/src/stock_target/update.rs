@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::{LocationId, TypeId};
+use utoipa::ToSchema;
+
+use crate::{Error, Result};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "restock": "PROPOSE",
+        "targets": [{
+            "type_id": 4051,
+            "location_id": 1046664001931_i64,
+            "quantity": 20000
+        }]
+    })
+)]
+pub struct UpdateStockTargets {
+    pub restock: StockTargetRestockMode,
+    pub targets: Vec<StockTargetEntry>,
+}
+
+impl UpdateStockTargets {
+    pub fn validate(&self) -> Result<bool> {
+        if self.targets.iter().any(|x| x.quantity <= 0) {
+            return Err(Error::ValidationError("Field 'quantity' must be greater than 0".into()));
+        }
+
+        Ok(true)
+    }
+}
+
+/// Quantity of an item that should be in stock at a location
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "type_id": 4051,
+        "location_id": 1046664001931_i64,
+        "quantity": 20000
+    })
+)]
+pub struct StockTargetEntry {
+    pub type_id:     TypeId,
+    pub location_id: LocationId,
+    pub quantity:    i32,
+}
+
+/// What the worker does with the shortfall of the stock targets
+#[derive(
+    Clone, Copy, Debug,
+    PartialEq, Eq,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "STOCK_TARGET_RESTOCK")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StockTargetRestockMode {
+    /// Only the shortfall is updated, projects are created manually
+    Propose,
+    /// A restock project is created for the shortfall
+    Create,
+}
@@ -1,3 +1,7 @@
+mod export;
+mod fuel;
 mod structure;
 
+pub use self::export::*;
+pub use self::fuel::*;
 pub use self::structure::*;
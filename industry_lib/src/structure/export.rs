@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::{StructureId, SystemId, TypeId};
+use utoipa::ToSchema;
+
+use crate::StructureUuid;
+
+/// Structure that is referenced by an export.
+///
+/// The `id` is only valid on the instance the export was created on, on
+/// import the structure is mapped to a structure of the importing character.
+///
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(
+    example = json!({
+        "id": "019a462f-dd0a-7d07-9516-df377ae11395",
+        "structure_id": 1046664001931_i64,
+        "name": "1DQ1-A - 1-st Imperial Palace",
+        "system_id": 30004759,
+        "type_id": 35834
+    })
+)]
+pub struct ExportStructure {
+    pub id:           StructureUuid,
+    pub structure_id: StructureId,
+    pub name:         String,
+    pub system_id:    SystemId,
+    pub type_id:      TypeId,
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_eve_gateway::Item;
+use starfoundry_lib_types::TypeId;
+use utoipa::ToSchema;
+
+use crate::StructureUuid;
+
+/// Fuel consumption, stock and upkeep of a structure
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct StructureFuel {
+    /// Internal id of the structure
+    pub structure_id:          StructureUuid,
+    /// Fuel consumption of every online service
+    pub services:              Vec<StructureServiceFuelUsage>,
+    /// Fuel blocks consumed per hour by all services and the structure
+    /// itself
+    pub fuel_per_hour:         f32,
+    /// Magmatic gas consumed per hour, only Metenox Moon Drills consume
+    /// magmatic gas
+    pub magmatic_gas_per_hour: f32,
+
+    /// Fuel block type of the last recorded stock, if no stock was recorded
+    /// the cheapest fuel block is used for the upkeep
+    pub fuel_type_id:          TypeId,
+    /// Fuel blocks that are estimated to be left in the structure
+    pub fuel_blocks:           Option<i32>,
+    /// Time the fuel stock was recorded
+    pub recorded_at:           Option<DateTime<Utc>>,
+    /// Time the fuel blocks run out, if the structure keeps consuming them at
+    /// the current rate
+    pub runs_out_at:           Option<DateTime<Utc>>,
+
+    /// Price of a single fuel block
+    pub fuel_block_price:      f64,
+    /// Price of a single unit of magmatic gas
+    pub magmatic_gas_price:    f64,
+    /// Cost of the fuel blocks and magmatic gas that are consumed in 30 days
+    pub monthly_upkeep:        f64,
+}
+
+/// Fuel consumption of a single service module
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct StructureServiceFuelUsage {
+    /// Service module
+    pub item:          Item,
+    /// Fuel blocks consumed per hour, including the structure bonus
+    pub fuel_per_hour: f32,
+    /// Fuel reduction by the structure in percent
+    pub bonus:         f32,
+}
+
+/// Records the fuel that is currently in a structure
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct UpdateStructureFuel {
+    /// Fuel block type that is in the fuel bay
+    pub fuel_type_id: TypeId,
+    /// Number of fuel blocks in the fuel bay
+    pub fuel_blocks:  i32,
+}
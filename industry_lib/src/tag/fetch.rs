@@ -48,20 +48,72 @@ impl TryFrom<String> for TagType {
     }
 }
 
+impl TagAuto {
+    /// Checks that the compare can be used with the select, and that the
+    /// value of numeric selects is a number
+    pub fn validate(&self) -> Result<bool, Error> {
+        if self.compare.is_numeric() && !self.select.is_numeric() {
+            return Err(Error::ValidationError(
+                format!("'{}' can only be used with numeric fields", self.compare.as_str())
+            ));
+        }
+
+        if self.select.is_numeric() && self.value.trim().parse::<f64>().is_err() {
+            return Err(Error::ValidationError(
+                format!("the value of '{}' must be a number", self.select.as_str())
+            ));
+        }
+
+        Ok(true)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, ToSchema, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TagAutoSelect {
     ProjectName,
     ProjectOrderer,
     ProjectStatus,
+    /// Change of the status, written as `<previous>-><current>`, for example
+    /// `InProgress->Done`
+    ProjectStatusTransition,
+    /// Sum of the job, market and misc costs
+    ProjectCost,
+    /// Days since the project was created
+    ProjectAge,
+    /// Name of the project group
+    ProjectGroup,
+    /// Category names of the products, matches if any product matches
+    ProductCategory,
 }
 
 impl TagAutoSelect {
     pub fn as_str(&self) -> &str {
         match self {
-            Self::ProjectName       => "PROJECT_NAME",
-            Self::ProjectOrderer    => "PROJECT_ORDER",
-            Self::ProjectStatus     => "PROJECT_STATUS",
+            Self::ProjectName               => "PROJECT_NAME",
+            Self::ProjectOrderer            => "PROJECT_ORDERER",
+            Self::ProjectStatus             => "PROJECT_STATUS",
+            Self::ProjectStatusTransition   => "PROJECT_STATUS_TRANSITION",
+            Self::ProjectCost               => "PROJECT_COST",
+            Self::ProjectAge                => "PROJECT_AGE",
+            Self::ProjectGroup              => "PROJECT_GROUP",
+            Self::ProductCategory           => "PRODUCT_CATEGORY",
+        }
+    }
+
+    /// Selects that are compared as numbers
+    #[must_use]
+    pub fn is_numeric(&self) -> bool {
+        match self {
+            Self::ProjectCost               |
+            Self::ProjectAge                => true,
+
+            Self::ProjectName               |
+            Self::ProjectOrderer            |
+            Self::ProjectStatus             |
+            Self::ProjectStatusTransition   |
+            Self::ProjectGroup              |
+            Self::ProductCategory           => false,
         }
     }
 }
@@ -71,10 +123,15 @@ impl TryFrom<String> for TagAutoSelect {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         match value.as_ref() {
-            "PROJECT_NAME"      => Ok(Self::ProjectName),
-            "PROJECT_ORDERER"   => Ok(Self::ProjectOrderer),
-            "PROJECT_STATUS"    => Ok(Self::ProjectStatus),
-            _                   => Err(Self::Error::EnumParseError(value, "TagAutoSelect")),
+            "PROJECT_NAME"              => Ok(Self::ProjectName),
+            "PROJECT_ORDERER"           => Ok(Self::ProjectOrderer),
+            "PROJECT_STATUS"            => Ok(Self::ProjectStatus),
+            "PROJECT_STATUS_TRANSITION" => Ok(Self::ProjectStatusTransition),
+            "PROJECT_COST"              => Ok(Self::ProjectCost),
+            "PROJECT_AGE"               => Ok(Self::ProjectAge),
+            "PROJECT_GROUP"             => Ok(Self::ProjectGroup),
+            "PRODUCT_CATEGORY"          => Ok(Self::ProductCategory),
+            _                           => Err(Self::Error::EnumParseError(value, "TagAutoSelect")),
         }
     }
 }
@@ -86,15 +143,35 @@ pub enum TagAutoCompare {
     IsNot,
     Contains,
     Pattern,
+    /// Only for numeric selects
+    GreaterThan,
+    /// Only for numeric selects
+    LessThan,
 }
 
 impl TagAutoCompare {
     pub fn as_str(&self) -> &str {
         match self {
-            Self::Is        => "IS",
-            Self::IsNot     => "IS_NOT",
-            Self::Contains  => "CONTAINS",
-            Self::Pattern   => "PATTERN",
+            Self::Is            => "IS",
+            Self::IsNot         => "IS_NOT",
+            Self::Contains      => "CONTAINS",
+            Self::Pattern       => "PATTERN",
+            Self::GreaterThan   => "GREATER_THAN",
+            Self::LessThan      => "LESS_THAN",
+        }
+    }
+
+    /// Compares that only work with numbers
+    #[must_use]
+    pub fn is_numeric(&self) -> bool {
+        match self {
+            Self::GreaterThan   |
+            Self::LessThan      => true,
+
+            Self::Is            |
+            Self::IsNot         |
+            Self::Contains      |
+            Self::Pattern       => false,
         }
     }
 }
@@ -104,11 +181,34 @@ impl TryFrom<String> for TagAutoCompare {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         match value.as_ref() {
-            "IS"        => Ok(Self::Is),
-            "IS_NOT"    => Ok(Self::IsNot),
-            "CONTAINS"  => Ok(Self::Contains),
-            "PATTERN"   => Ok(Self::Pattern),
-            _           => Err(Self::Error::EnumParseError(value, "TagAutoCompare")),
+            "IS"            => Ok(Self::Is),
+            "IS_NOT"        => Ok(Self::IsNot),
+            "CONTAINS"      => Ok(Self::Contains),
+            "PATTERN"       => Ok(Self::Pattern),
+            "GREATER_THAN"  => Ok(Self::GreaterThan),
+            "LESS_THAN"     => Ok(Self::LessThan),
+            _               => Err(Self::Error::EnumParseError(value, "TagAutoCompare")),
         }
     }
 }
+
+/// Checks which projects the given conditions would match, without changing
+/// any tags
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct TagDryRun {
+    pub auto: Vec<TagAuto>,
+}
+
+impl TagDryRun {
+    pub fn validate(&self) -> Result<bool, Error> {
+        if self.auto.is_empty() {
+            return Err(Error::ValidationError("Field 'auto' must contain at least one value".into()));
+        }
+
+        for auto in self.auto.iter() {
+            auto.validate()?;
+        }
+
+        Ok(true)
+    }
+}
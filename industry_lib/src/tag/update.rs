@@ -20,6 +20,10 @@ impl UpdateTag {
             return Err(Error::ValidationError("Field 'auto' must contain at least one value, when 'typ' is 'AUTO'".into()));
         }
 
+        for auto in self.auto.iter() {
+            auto.validate()?;
+        }
+
         Ok(true)
     }
 }
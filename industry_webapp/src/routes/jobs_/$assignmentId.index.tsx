@@ -167,7 +167,7 @@ function JobAssignmentWrapper({
                     onClick={() => {
                         updateEntryMutation.mutate(row.original.id)
                     }}
-                    disabled={row.original.started}
+                    disabled={row.original.started || claimedByOther(row.original)}
                 >
                     Started
                 </Button>,
@@ -202,6 +202,11 @@ function JobAssignmentWrapper({
     </>
 }
 
+// jobs claimed by other builders can't be started
+const claimedByOther = (job: ProjectJobAssignment): boolean => {
+    return job.claimed_by !== undefined && job.claimed_by !== null && !job.claimed_by_me;
+}
+
 type JobAssignmentWrapperProps = {
     assignmentId:   Uuid,
     project:        string,
@@ -0,0 +1,58 @@
+use sqlx::PgPool;
+use starfoundry_lib_gateway::Identity;
+use starfoundry_lib_industry::IndustryClient;
+use starfoundry_lib_industry::project::IndustryApiClientProject;
+use starfoundry_lib_types::{CharacterId, CorporationId};
+use starfoundry_lib_worker::Task;
+
+use crate::error::{Error, Result};
+use crate::{SERVICE_NAME, WorkerIndustryTask};
+use crate::metric::WorkerMetric;
+
+/// Refreshes the asset stock of all projects that are not done yet.
+///
+/// The industry service is called in the name of the character that
+/// configured the asset stock, errors for a single project are logged and
+/// don't stop the refresh of the others.
+///
+pub async fn asset_stock(
+    pool: &PgPool,
+    task: &mut Task<WorkerMetric, WorkerIndustryTask>,
+) -> Result<()> {
+    let configs = sqlx::query!("
+            SELECT
+                pas.project_id,
+                pas.character_id,
+                pas.corporation_id,
+                pas.domain
+            FROM project_asset_stock pas
+            JOIN project p ON p.id = pas.project_id
+            WHERE p.status != 'DONE'
+        ")
+        .fetch_all(pool)
+        .await
+        .map_err(Error::ListAssetStock)?;
+    task.append_log(format!("Projects with asset stock: {}", configs.len()));
+
+    for config in configs {
+        let identity = Identity::new(
+            CharacterId(config.character_id),
+            CorporationId(config.corporation_id),
+            config.domain,
+        );
+
+        let result = match IndustryClient::new_with_identity(SERVICE_NAME, identity) {
+            Ok(client) => client
+                .project_refresh_asset_stock(&config.project_id.into())
+                .await,
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = result {
+            tracing::error!("Error while refreshing asset stock of project {}, {}", config.project_id, e);
+            task.append_error(format!("[{}] {}", config.project_id, e));
+        }
+    }
+
+    Ok(())
+}
@@ -17,6 +17,20 @@ pub enum Error {
     #[error("error while inserting job detection log, error: '{0}'")]
     InsertJobDetectionLog(sqlx::Error),
 
+    #[error("error while listing asset stock, error: '{0}'")]
+    ListAssetStock(sqlx::Error),
+
+    #[error("error while listing wallets, error: '{0}'")]
+    ListWallet(sqlx::Error),
+
+    #[error("error while listing stock targets, error: '{0}'")]
+    ListStockTarget(sqlx::Error),
+
+    #[error("error while listing project templates, error: '{0}'")]
+    ListProjectTemplate(sqlx::Error),
+    #[error("error while updating project template, error: '{0}'")]
+    UpdateProjectTemplate(sqlx::Error),
+
     #[error("error during transaction, error: '{0}'")]
     TransactionError(sqlx::Error),
 
@@ -32,4 +46,6 @@ pub enum Error {
     WorkerLibError(#[from] starfoundry_lib_worker::Error),
     #[error(transparent)]
     EveGatewayError(#[from] starfoundry_lib_eve_gateway::Error),
+    #[error(transparent)]
+    IndustryError(#[from] starfoundry_lib_industry::Error),
 }
@@ -9,7 +9,7 @@ use std::collections::HashMap;
 use crate::error::{Error, Result};
 use crate::{SERVICE_NAME, WorkerIndustryTask};
 use crate::metric::WorkerMetric;
-use crate::jobs::{cleanup_delivered_jobs, fetch_done_job_ids, fetch_startable_jobs, insert_job_detection_log, job_detection, resolve_corporation_asset_name, update_finished_jobs, update_industry_jobs};
+use crate::jobs::{book_job_assignment_payouts, cleanup_delivered_jobs, fetch_done_job_ids, fetch_startable_jobs, insert_job_detection_log, job_detection, resolve_corporation_asset_name, update_finished_jobs, update_industry_jobs};
 
 pub async fn corporation_jobs(
     pool:        &PgPool,
@@ -218,6 +218,7 @@ pub async fn corporation_jobs(
 
     update_industry_jobs(pool, &updates).await?;
     update_finished_jobs(pool).await?;
+    book_job_assignment_payouts(pool).await?;
     insert_job_detection_log(pool, &updates, &unmatched).await?;
 
     Ok(())
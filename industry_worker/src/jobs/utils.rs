@@ -204,6 +204,50 @@ pub async fn update_finished_jobs(
         .map_err(Error::UpdateJob)
 }
 
+/// Adds the agreed payment of claimed assignment jobs that are done to the
+/// misc costs of their project.
+///
+/// The payout is marked as pending, so that every payment is only added once.
+///
+pub async fn book_job_assignment_payouts(
+    pool: &PgPool,
+) -> Result<()> {
+    sqlx::query!("
+            WITH done AS (
+                UPDATE project_job_assignment pja
+                SET payout_status = 'PENDING'
+                FROM project_job pj
+                WHERE pj.id = pja.job_id
+                AND pj.status = 'DONE'
+                AND pja.payout_status = 'OPEN'
+                AND pja.claimed_by IS NOT NULL
+                AND pja.payment IS NOT NULL
+                RETURNING
+                    pja.id,
+                    pja.project_id,
+                    pja.claimed_by,
+                    pja.payment
+            )
+            INSERT INTO project_misc
+            (
+                project_id,
+                item,
+                cost,
+                description
+            )
+            SELECT
+                project_id,
+                'Job assignment payout',
+                payment,
+                'Builder ' || claimed_by || ', assignment ' || id
+            FROM done
+        ")
+        .execute(pool)
+        .await
+        .map(drop)
+        .map_err(Error::UpdateJob)
+}
+
 pub async fn insert_job_detection_log(
     pool:           &PgPool,
     updates:        &HashMap<Uuid, Vec<UpdateJobRequest>>,
@@ -1,9 +1,13 @@
+mod asset_stock;
 mod config;
 mod error;
 mod jobs;
 mod metric;
+mod project_template;
+mod stock_target;
 mod sync;
 mod tasks;
+mod wallet;
 
 use prometheus_client::registry::Registry;
 use sqlx::PgPool;
@@ -17,10 +21,14 @@ pub use self::tasks::*;
 
 use self::sync::{sync, sync_task};
 
+use crate::asset_stock::asset_stock;
 use crate::config::Config;
 use crate::error::Result;
 use crate::metric::WorkerMetric;
 use crate::jobs::corporation_jobs;
+use crate::project_template::project_template;
+use crate::stock_target::stock_target;
+use crate::wallet::wallet_reconciliation;
 
 pub const SERVICE_NAME: &str = "SF_INDUSTRY_WORKER";
 
@@ -141,5 +149,33 @@ async fn task_select(
                 )
                 .await
         },
+        WorkerIndustryTask::AssetStock      => {
+            asset_stock(
+                    pool,
+                    task,
+                )
+                .await
+        },
+        WorkerIndustryTask::WalletReconciliation => {
+            wallet_reconciliation(
+                    pool,
+                    task,
+                )
+                .await
+        },
+        WorkerIndustryTask::StockTarget => {
+            stock_target(
+                    pool,
+                    task,
+                )
+                .await
+        },
+        WorkerIndustryTask::ProjectTemplate => {
+            project_template(
+                    pool,
+                    task,
+                )
+                .await
+        },
     }
 }
@@ -0,0 +1,3 @@
+mod schedule;
+
+pub use self::schedule::*;
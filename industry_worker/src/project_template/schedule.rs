@@ -0,0 +1,100 @@
+use sqlx::PgPool;
+use starfoundry_lib_gateway::Identity;
+use starfoundry_lib_industry::IndustryClient;
+use starfoundry_lib_industry::project_template::{IndustryApiClientProjectTemplate, InstantiateProjectTemplate};
+use starfoundry_lib_types::{CharacterId, CorporationId};
+use starfoundry_lib_worker::Task;
+
+use crate::error::{Error, Result};
+use crate::{SERVICE_NAME, WorkerIndustryTask};
+use crate::metric::WorkerMetric;
+
+/// Creates the projects of all scheduled project templates that are due.
+///
+/// The industry service is called in the name of the character that last
+/// updated the template. Failures are stored with the template and delay its
+/// next run, starting with one hour and doubling with every failure, at most
+/// until the regular interval.
+///
+pub async fn project_template(
+    pool: &PgPool,
+    task: &mut Task<WorkerMetric, WorkerIndustryTask>,
+) -> Result<()> {
+    let templates = sqlx::query!("
+            SELECT
+                id,
+                character_id,
+                corporation_id,
+                domain
+            FROM project_template
+            WHERE next_run_at <= NOW()
+        ")
+        .fetch_all(pool)
+        .await
+        .map_err(Error::ListProjectTemplate)?;
+    task.append_log(format!("Due project templates: {}", templates.len()));
+
+    for template in templates {
+        let identity = Identity::new(
+            CharacterId(template.character_id),
+            CorporationId(template.corporation_id),
+            template.domain,
+        );
+
+        let result = match IndustryClient::new_with_identity(SERVICE_NAME, identity) {
+            Ok(client) => {
+                client
+                    .project_template_instantiate(
+                        &template.id.into(),
+                        &InstantiateProjectTemplate::default(),
+                    )
+                    .await
+            },
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(x) => {
+                task.append_log(format!("[{}] created project {}", template.id, x.id));
+
+                sqlx::query!("
+                        UPDATE project_template
+                        SET
+                            next_run_at     = NOW() + make_interval(days => interval_days),
+                            failed_attempts = 0,
+                            last_error      = NULL
+                        WHERE id = $1
+                    ",
+                        template.id,
+                    )
+                    .execute(pool)
+                    .await
+                    .map_err(Error::UpdateProjectTemplate)?;
+            },
+            Err(e) => {
+                tracing::error!("Error while creating project from template {}, {}", template.id, e);
+                task.append_error(format!("[{}] {}", template.id, e));
+
+                sqlx::query!("
+                        UPDATE project_template
+                        SET
+                            next_run_at     = NOW() + LEAST(
+                                make_interval(hours => POWER(2, LEAST(failed_attempts, 10))::INTEGER),
+                                make_interval(days => interval_days)
+                            ),
+                            failed_attempts = failed_attempts + 1,
+                            last_error      = $2
+                        WHERE id = $1
+                    ",
+                        template.id,
+                        e.to_string(),
+                    )
+                    .execute(pool)
+                    .await
+                    .map_err(Error::UpdateProjectTemplate)?;
+            },
+        }
+    }
+
+    Ok(())
+}
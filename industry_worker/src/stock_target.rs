@@ -0,0 +1,3 @@
+mod restock;
+
+pub use self::restock::*;
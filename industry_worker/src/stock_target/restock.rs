@@ -0,0 +1,74 @@
+use sqlx::PgPool;
+use starfoundry_lib_gateway::Identity;
+use starfoundry_lib_industry::IndustryClient;
+use starfoundry_lib_industry::stock_target::{IndustryApiClientStockTarget, StockTargetRestockMode};
+use starfoundry_lib_types::{CharacterId, CorporationId};
+use starfoundry_lib_worker::Task;
+
+use crate::error::{Error, Result};
+use crate::{SERVICE_NAME, WorkerIndustryTask};
+use crate::metric::WorkerMetric;
+
+/// Compares the stock targets of all project groups with the synced assets.
+///
+/// Depending on the settings of the project group, only the shortfall is
+/// updated or a restock project is created for it.
+/// The industry service is called in the name of the character that
+/// configured the stock targets, errors for a single project group are
+/// logged and don't stop the others.
+///
+pub async fn stock_target(
+    pool: &PgPool,
+    task: &mut Task<WorkerMetric, WorkerIndustryTask>,
+) -> Result<()> {
+    let configs = sqlx::query!(r#"
+            SELECT
+                project_group_id,
+                character_id,
+                corporation_id,
+                domain,
+                restock AS "restock: StockTargetRestockMode"
+            FROM stock_target_config
+        "#)
+        .fetch_all(pool)
+        .await
+        .map_err(Error::ListStockTarget)?;
+    task.append_log(format!("Project groups with stock targets: {}", configs.len()));
+
+    for config in configs {
+        let identity = Identity::new(
+            CharacterId(config.character_id),
+            CorporationId(config.corporation_id),
+            config.domain,
+        );
+
+        let result = match IndustryClient::new_with_identity(SERVICE_NAME, identity) {
+            Ok(client) => match config.restock {
+                StockTargetRestockMode::Propose => {
+                    client
+                        .stock_target_check(&config.project_group_id.into())
+                        .await
+                        .map(|_| ())
+                },
+                StockTargetRestockMode::Create => {
+                    client
+                        .stock_target_restock(&config.project_group_id.into())
+                        .await
+                        .map(|x| {
+                            if let Some(x) = x {
+                                task.append_log(format!("[{}] created restock project {}", config.project_group_id, x.project_id));
+                            }
+                        })
+                },
+            },
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = result {
+            tracing::error!("Error while checking stock targets of project group {}, {}", config.project_group_id, e);
+            task.append_error(format!("[{}] {}", config.project_group_id, e));
+        }
+    }
+
+    Ok(())
+}
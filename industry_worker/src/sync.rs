@@ -35,6 +35,50 @@ pub async fn sync_task(
         Err(e) => task.append_error(e.to_string()),
     };
 
+    match sync_asset_stock(
+        pool,
+    ).await {
+        Ok(new_entries) => {
+            if new_entries > 0 {
+                task.append_log("added asset stock task")
+            }
+        },
+        Err(e) => task.append_error(e.to_string()),
+    };
+
+    match sync_wallet_reconciliation(
+        pool,
+    ).await {
+        Ok(new_entries) => {
+            if new_entries > 0 {
+                task.append_log("added wallet reconciliation task")
+            }
+        },
+        Err(e) => task.append_error(e.to_string()),
+    };
+
+    match sync_stock_target(
+        pool,
+    ).await {
+        Ok(new_entries) => {
+            if new_entries > 0 {
+                task.append_log("added stock target task")
+            }
+        },
+        Err(e) => task.append_error(e.to_string()),
+    };
+
+    match sync_project_template(
+        pool,
+    ).await {
+        Ok(new_entries) => {
+            if new_entries > 0 {
+                task.append_log("added project template task")
+            }
+        },
+        Err(e) => task.append_error(e.to_string()),
+    };
+
     Ok(())
 }
 
@@ -51,6 +95,22 @@ pub async fn sync(
         pool,
     ).await?;
 
+    sync_asset_stock(
+        pool,
+    ).await?;
+
+    sync_wallet_reconciliation(
+        pool,
+    ).await?;
+
+    sync_stock_target(
+        pool,
+    ).await?;
+
+    sync_project_template(
+        pool,
+    ).await?;
+
     Ok(())
 }
 
@@ -174,3 +234,103 @@ async fn sync_corporation_jobs(
         .map(|_| new_entries.len())
         .map_err(Error::SyncError)
 }
+
+/// Ensures that there is exactly one task refreshing the asset stock, the
+/// task refreshes all projects at once
+async fn sync_asset_stock(
+    pool: &PgPool,
+) -> Result<usize> {
+    let task_name: String = WorkerIndustryTask::AssetStock.into();
+
+    sqlx::query!("
+            INSERT INTO worker_queue (task)
+            SELECT $1::VARCHAR
+            WHERE NOT EXISTS (
+                SELECT 1
+                FROM worker_queue
+                WHERE (status = 'WAITING' OR status = 'IN_PROGRESS')
+                AND task = $1::VARCHAR
+            )
+        ",
+            &task_name,
+        )
+        .execute(pool)
+        .await
+        .map(|x| x.rows_affected() as usize)
+        .map_err(Error::SyncError)
+}
+
+/// Ensures that there is exactly one task reconciling the wallets, the task
+/// reconciles all projects at once
+async fn sync_wallet_reconciliation(
+    pool: &PgPool,
+) -> Result<usize> {
+    let task_name: String = WorkerIndustryTask::WalletReconciliation.into();
+
+    sqlx::query!("
+            INSERT INTO worker_queue (task)
+            SELECT $1::VARCHAR
+            WHERE NOT EXISTS (
+                SELECT 1
+                FROM worker_queue
+                WHERE (status = 'WAITING' OR status = 'IN_PROGRESS')
+                AND task = $1::VARCHAR
+            )
+        ",
+            &task_name,
+        )
+        .execute(pool)
+        .await
+        .map(|x| x.rows_affected() as usize)
+        .map_err(Error::SyncError)
+}
+
+/// Ensures that there is exactly one task checking the stock targets, the
+/// task checks all project groups at once
+async fn sync_stock_target(
+    pool: &PgPool,
+) -> Result<usize> {
+    let task_name: String = WorkerIndustryTask::StockTarget.into();
+
+    sqlx::query!("
+            INSERT INTO worker_queue (task)
+            SELECT $1::VARCHAR
+            WHERE NOT EXISTS (
+                SELECT 1
+                FROM worker_queue
+                WHERE (status = 'WAITING' OR status = 'IN_PROGRESS')
+                AND task = $1::VARCHAR
+            )
+        ",
+            &task_name,
+        )
+        .execute(pool)
+        .await
+        .map(|x| x.rows_affected() as usize)
+        .map_err(Error::SyncError)
+}
+
+/// Ensures that there is exactly one task creating the projects of scheduled
+/// project templates
+async fn sync_project_template(
+    pool: &PgPool,
+) -> Result<usize> {
+    let task_name: String = WorkerIndustryTask::ProjectTemplate.into();
+
+    sqlx::query!("
+            INSERT INTO worker_queue (task)
+            SELECT $1::VARCHAR
+            WHERE NOT EXISTS (
+                SELECT 1
+                FROM worker_queue
+                WHERE (status = 'WAITING' OR status = 'IN_PROGRESS')
+                AND task = $1::VARCHAR
+            )
+        ",
+            &task_name,
+        )
+        .execute(pool)
+        .await
+        .map(|x| x.rows_affected() as usize)
+        .map_err(Error::SyncError)
+}
@@ -12,6 +12,15 @@ pub enum WorkerIndustryTask {
 
     JobCharacter,
     JobCorporation,
+
+    /// refreshes the stock of projects that use synced assets
+    AssetStock,
+    /// reconciles the costs of projects with the synced wallets
+    WalletReconciliation,
+    /// compares the stock targets of project groups with the synced assets
+    StockTarget,
+    /// creates the projects of scheduled project templates
+    ProjectTemplate,
 }
 
 impl WorkerTask for WorkerIndustryTask {
@@ -22,6 +31,13 @@ impl WorkerTask for WorkerIndustryTask {
             Self::Sync              => self.add_minutes(5),
             Self::JobCharacter      => self.add_minutes(5),
             Self::JobCorporation    => self.add_minutes(5),
+            // assets are synced once an hour
+            Self::AssetStock        => self.add_minutes(60),
+            // wallets are synced once an hour
+            Self::WalletReconciliation => self.add_minutes(60),
+            // assets are synced once an hour
+            Self::StockTarget       => self.add_minutes(60),
+            Self::ProjectTemplate   => self.add_minutes(60),
         }
     }
 }
@@ -34,6 +50,10 @@ impl TryFrom<String> for WorkerIndustryTask {
             "SYNC"                  => Ok(Self::Sync),
             "JOB_CHARACTER"         => Ok(Self::JobCharacter),
             "JOB_CORPORATION"       => Ok(Self::JobCorporation),
+            "ASSET_STOCK"           => Ok(Self::AssetStock),
+            "WALLET_RECONCILIATION" => Ok(Self::WalletReconciliation),
+            "STOCK_TARGET"          => Ok(Self::StockTarget),
+            "PROJECT_TEMPLATE"      => Ok(Self::ProjectTemplate),
             _                       => Err(Error::InvalidWorkerTask(value)),
         }
     }
@@ -45,6 +65,10 @@ impl Into<String> for WorkerIndustryTask {
             Self::Sync              => "SYNC",
             Self::JobCharacter      => "JOB_CHARACTER",
             Self::JobCorporation    => "JOB_CORPORATION",
+            Self::AssetStock        => "ASSET_STOCK",
+            Self::WalletReconciliation => "WALLET_RECONCILIATION",
+            Self::StockTarget       => "STOCK_TARGET",
+            Self::ProjectTemplate   => "PROJECT_TEMPLATE",
         }.into()
     }
 }
@@ -0,0 +1,3 @@
+mod reconcile;
+
+pub use self::reconcile::*;
@@ -0,0 +1,59 @@
+use sqlx::PgPool;
+use starfoundry_lib_gateway::Identity;
+use starfoundry_lib_industry::IndustryClient;
+use starfoundry_lib_industry::project::IndustryApiClientProject;
+use starfoundry_lib_types::{CharacterId, CorporationId};
+use starfoundry_lib_worker::Task;
+
+use crate::error::{Error, Result};
+use crate::{SERVICE_NAME, WorkerIndustryTask};
+use crate::metric::WorkerMetric;
+
+/// Reconciles the costs of all projects that are not done yet with the
+/// synced wallets.
+///
+/// The industry service is called in the name of the character that
+/// configured the reconciliation, errors for a single project are logged and
+/// don't stop the reconciliation of the others.
+///
+pub async fn wallet_reconciliation(
+    pool: &PgPool,
+    task: &mut Task<WorkerMetric, WorkerIndustryTask>,
+) -> Result<()> {
+    let configs = sqlx::query!("
+            SELECT
+                pw.project_id,
+                pw.character_id,
+                pw.corporation_id,
+                pw.domain
+            FROM project_wallet pw
+            JOIN project p ON p.id = pw.project_id
+            WHERE p.status != 'DONE'
+        ")
+        .fetch_all(pool)
+        .await
+        .map_err(Error::ListWallet)?;
+    task.append_log(format!("Projects with wallet: {}", configs.len()));
+
+    for config in configs {
+        let identity = Identity::new(
+            CharacterId(config.character_id),
+            CorporationId(config.corporation_id),
+            config.domain,
+        );
+
+        let result = match IndustryClient::new_with_identity(SERVICE_NAME, identity) {
+            Ok(client) => client
+                .project_refresh_wallet(&config.project_id.into())
+                .await,
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = result {
+            tracing::error!("Error while reconciling wallet of project {}, {}", config.project_id, e);
+            task.append_error(format!("[{}] {}", config.project_id, e));
+        }
+    }
+
+    Ok(())
+}
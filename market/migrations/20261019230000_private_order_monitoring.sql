@@ -0,0 +1,38 @@
+DO
+$$
+BEGIN
+    IF NOT EXISTS (
+        SELECT *
+        FROM pg_type typ
+        INNER JOIN pg_namespace nsp ON nsp.oid = typ.typnamespace
+        WHERE nsp.nspname = current_schema()
+        AND typ.typname = 'market_own_order_status'
+    ) THEN
+        CREATE TYPE MARKET_OWN_ORDER_STATUS AS ENUM (
+            'ACTIVE',
+            'UNDERCUT',
+            'OUTBID',
+            'FILLED',
+            'EXPIRED'
+        );
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+-- set as soon as the order is no longer returned by eve
+ALTER TABLE market_private_order ADD COLUMN IF NOT EXISTS closed_at TIMESTAMPTZ;
+-- last status a notification was sent for
+ALTER TABLE market_private_order ADD COLUMN IF NOT EXISTS notified_status MARKET_OWN_ORDER_STATUS;
+
+-- discord webhooks that are notified about changes of own orders
+CREATE TABLE IF NOT EXISTS market_order_notification (
+    -- either a character_id or a corporation_id
+    issuer_id   INTEGER     NOT NULL,
+    url         VARCHAR     NOT NULL,
+
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (issuer_id)
+);
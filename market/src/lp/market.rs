@@ -1,9 +1,9 @@
 use good_lp::{Constraint, Expression, ProblemVariables, Solution, SolverModel, Variable, constraint, default_solver, variable, variables};
 use std::collections::HashMap;
-use starfoundry_lib_types::StructureId;
+use starfoundry_lib_types::{StructureId, TypeId};
 
 use crate::lp::{LpError, Result};
-use crate::market::MarketEntry;
+use crate::market::{ContractEntry, MarketEntry};
 
 pub struct MarketProblem {
     vars:        ProblemVariables,
@@ -14,6 +14,8 @@ pub struct MarketProblem {
     constraints: Vec<Constraint>,
 
     mapping_order_id_market: HashMap<i64, MarketEntry>,
+    /// contracts can only be bought as a whole
+    contracts:               Vec<(Variable, ContractEntry)>,
 
     /// max recorded price per unit of the material
     max_per_unit_price: f64,
@@ -31,6 +33,7 @@ impl MarketProblem {
             constraints: Vec::new(),
 
             mapping_order_id_market: HashMap::new(),
+            contracts: Vec::new(),
 
             max_per_unit_price: 0f64,
             total_units: 0f64,
@@ -62,6 +65,27 @@ impl MarketProblem {
         }
     }
 
+    /// Adds public contracts as additional source, the full price of a
+    /// contract is counted against the given type
+    pub fn add_contracts(
+        &mut self,
+        type_id:   TypeId,
+        contracts: Vec<ContractEntry>,
+    ) {
+        for contract in contracts {
+            let quantity = contract.quantity(type_id);
+            if quantity <= 0 {
+                continue;
+            }
+
+            let variable = self.vars.add(variable().binary());
+
+            self.prices += contract.price * variable + self.hauling_cost(contract.structure_id, variable, contract.volume);
+            self.want += quantity as f64 * variable;
+            self.contracts.push((variable, contract));
+        }
+    }
+
     fn hauling_cost(
         &self,
         structure_id: StructureId,
@@ -101,8 +125,14 @@ impl MarketProblem {
     pub fn solve(
         mut self,
         want: i32,
-    ) -> Result<HashMap<StructureId, MarketProblemResult>> {
-        self.constraints.push(constraint!(self.want == want));
+    ) -> Result<MarketProblemSolution> {
+        // contracts can only be bought completely, which may exceed the
+        // wanted quantity
+        if self.contracts.is_empty() {
+            self.constraints.push(constraint!(self.want == want));
+        } else {
+            self.constraints.push(constraint!(self.want >= want));
+        }
 
         let mapping = self.variables
             .into_iter()
@@ -146,10 +176,27 @@ impl MarketProblem {
             }
         }
 
-        Ok(result)
+        let contracts = self.contracts
+            .into_iter()
+            .filter(|(var, _)| problem_result.value(*var) > 0.5f64)
+            .map(|(_, contract)| contract)
+            .collect::<Vec<_>>();
+
+        Ok(MarketProblemSolution {
+            markets: result,
+            contracts,
+        })
     }
 }
 
+#[derive(Debug)]
+pub struct MarketProblemSolution {
+    /// orders that should be bought, grouped by market
+    pub markets:   HashMap<StructureId, MarketProblemResult>,
+    /// contracts that should be bought
+    pub contracts: Vec<ContractEntry>,
+}
+
 #[derive(Debug)]
 pub struct MarketProblemResult {
     pub quantity: i32,
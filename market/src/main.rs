@@ -124,6 +124,13 @@ use starfoundry_lib_eve_gateway::EveGatewayClient;
 pub fn eve_gateway_api_client() -> Result<EveGatewayClient, starfoundry_lib_eve_gateway::Error> {
     EveGatewayClient::new(SERVICE_NAME)
 }
+/// Client that acts in the name of the given identity
+#[cfg(not(test))]
+pub fn eve_gateway_api_client_with_identity(
+    identity: starfoundry_lib_gateway::Identity,
+) -> Result<EveGatewayClient, starfoundry_lib_eve_gateway::Error> {
+    EveGatewayClient::new_with_identity(SERVICE_NAME, identity)
+}
 
 #[cfg(test)]
 use starfoundry_lib_eve_gateway::EveGatewayClient;
@@ -135,3 +142,9 @@ pub fn eve_gateway_api_client() -> Result<EveGatewayClient, starfoundry_lib_eve_
 
     EveGatewayClient::new("MARKET_TESTING")
 }
+#[cfg(test)]
+pub fn eve_gateway_api_client_with_identity(
+    identity: starfoundry_lib_gateway::Identity,
+) -> Result<EveGatewayClient, starfoundry_lib_eve_gateway::Error> {
+    EveGatewayClient::new_with_identity("MARKET_TESTING", identity)
+}
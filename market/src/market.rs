@@ -1,7 +1,10 @@
 mod error;
 mod bulk;
 mod last_fetch;
+mod list_own_orders;
+mod sell;
 mod service;
+mod update_order_notification;
 mod virtual_market;
 
 pub use self::service::*;
@@ -18,11 +21,23 @@ pub fn routes() -> OpenApiRouter<AppState> {
     let last_fetch = OpenApiRouter::new()
         .routes(routes!(last_fetch::api));
 
+    let list_own_orders = OpenApiRouter::new()
+        .routes(routes!(list_own_orders::api));
+
+    let sell = OpenApiRouter::new()
+        .routes(routes!(sell::api));
+
+    let update_order_notification = OpenApiRouter::new()
+        .routes(routes!(update_order_notification::api));
+
     let virtual_market = OpenApiRouter::new()
         .routes(routes!(virtual_market::api));
 
     OpenApiRouter::new()
         .merge(bulk)
         .merge(last_fetch)
+        .merge(list_own_orders)
+        .merge(sell)
+        .merge(update_order_notification)
         .merge(virtual_market)
 }
@@ -2,6 +2,7 @@ use axum::extract::rejection::JsonRejection;
 use axum::http::StatusCode;
 use axum::Json;
 use axum::response::{IntoResponse, Response};
+use starfoundry_lib_gateway::boxed_from;
 use starfoundry_lib_types::StructureId;
 use thiserror::Error;
 
@@ -17,9 +18,19 @@ pub enum MarketError {
     LatestFetch(sqlx::Error),
     #[error("structure not found, structureId '{0}'")]
     NotFound(StructureId),
+    #[error("not allowed to access the orders of issuer '{0}'")]
+    Forbidden(i32),
+    #[error("only discord webhooks are supported, url: '{0}'")]
+    InvalidWebhook(String),
 
     #[error("error while fetching market entries, error: '{0}'")]
     FetchMarketEntries(sqlx::Error),
+    #[error("error while fetching contract entries, error: '{0}'")]
+    FetchContractEntries(sqlx::Error),
+    #[error("error while fetching own orders, error: '{0}'")]
+    FetchOwnOrders(sqlx::Error),
+    #[error("error while updating order notification, error: '{0}'")]
+    UpdateOrderNotification(sqlx::Error),
 
     #[error(transparent)]
     JsonExtractorRejection(#[from] JsonRejection),
@@ -27,6 +38,8 @@ pub enum MarketError {
     LpError(#[from] LpError),
     #[error(transparent)]
     EveGatewayLibError(#[from] starfoundry_lib_eve_gateway::Error),
+    #[error(transparent)]
+    GatewayError(Box<starfoundry_lib_gateway::Error>),
 }
 
 impl IntoResponse for MarketError {
@@ -44,6 +57,34 @@ impl IntoResponse for MarketError {
                 ).into_response()
             }
 
+            Self::Forbidden(_) => {
+                (
+                    StatusCode::FORBIDDEN,
+                    Json(
+                        ErrorResponse {
+                            error: "FORBIDDEN".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            }
+
+            Self::InvalidWebhook(_) => {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(
+                        ErrorResponse {
+                            error: "INVALID_WEBHOOK".into(),
+                            description: self.to_string(),
+                        }
+                    )
+                ).into_response()
+            }
+
+            Self::GatewayError(e) => {
+                starfoundry_lib_gateway::Error::into_response(*e)
+            },
+
             Self::JsonExtractorRejection(x) => {
                 format_json_errors(x).into_response()
             },
@@ -64,3 +105,5 @@ impl IntoResponse for MarketError {
         .into_response()
     }
 }
+
+boxed_from!(MarketError::GatewayError, starfoundry_lib_gateway::Error);
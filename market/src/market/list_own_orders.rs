@@ -0,0 +1,93 @@
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_gateway::{ExtractIdentity, Identity};
+use starfoundry_lib_market::{MarketOwnOrder, MarketOwnOrderFilter};
+
+use crate::api_docs::{BadRequest, InternalServerError, Unauthorized};
+use crate::{AppState, eve_gateway_api_client_with_identity};
+use crate::market::error::Result;
+use crate::market::service::{issuer_ids, list_own_orders};
+
+/// List Own Orders
+/// 
+/// - Alternative route: `/latest/markets/orders`
+/// - Alternative route: `/v1/markets/orders`
+/// 
+/// ---
+/// 
+/// Lists the market orders of the character and its corporation, compared
+/// against the current order book.
+/// Corporation orders are only listed if the character granted the scope
+/// `esi-markets.read_corporation_orders.v1`.
+/// 
+/// Sell orders that are no longer the cheapest are flagged as `UNDERCUT`,
+/// buy orders that no longer pay the most as `OUTBID`.
+/// Orders that are no longer returned by EVE are either `FILLED` or
+/// `EXPIRED`, and are included until `closed_since`.
+/// 
+/// ## Security
+/// - authenticated
+/// 
+#[utoipa::path(
+    get,
+    path = "/orders",
+    tag = "Markets",
+    params(MarketOwnOrderFilter),
+    responses(
+        (
+            body = Vec<MarketOwnOrder>,
+            description = "List of own orders",
+            status = OK,
+        ),
+        (
+            description = "There are no orders",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:      ExtractIdentity,
+    State(state):  State<AppState>,
+    Query(filter): Query<MarketOwnOrderFilter>,
+) -> Result<impl IntoResponse> {
+    let scopes = eve_gateway_api_client_with_identity(Identity::new(
+            identity.character_id,
+            identity.corporation_id,
+            identity.host()?,
+        ))?
+        .list_scopes()
+        .await?;
+
+    let data = list_own_orders(
+            &state.postgres,
+            &issuer_ids(*identity.character_id, *identity.corporation_id, &scopes),
+            filter,
+        ).await?;
+
+    if data.is_empty() {
+        Ok(
+            (
+                StatusCode::NO_CONTENT,
+                Json(data),
+            )
+            .into_response()
+        )
+    } else {
+        Ok(
+            (
+                StatusCode::OK,
+                Json(data),
+            )
+            .into_response()
+        )
+    }
+}
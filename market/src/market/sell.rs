@@ -0,0 +1,65 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_market::{MarketSellRequest, MarketSellResponse};
+
+use crate::api_docs::{BadRequest, InternalServerError, Unauthorized};
+use crate::AppState;
+use crate::market::error::Result;
+use crate::market::service::sell;
+
+/// Sell Plan
+/// 
+/// - Alternative route: `/latest/markets/sell`
+/// - Alternative route: `/v1/markets/sell`
+/// 
+/// ---
+/// 
+/// Plans where and how the given items should be sold
+/// 
+/// For every item the units are either sold directly into buy orders, or a
+/// sell order is listed that undercuts the cheapest sell order.
+/// Units are sold into buy orders as long as they yield at least as much as
+/// listing them in the best market would, after sales tax and broker fee.
+/// The depth of the buy orders is respected, units that can't be sold into
+/// buy orders are listed.
+/// 
+/// The sales tax is calculated based on the `Accounting` skill, the broker
+/// fee based on `Broker Relations` and the standings, unless a fee for the
+/// structure is given.
+/// 
+#[utoipa::path(
+    post,
+    path = "/sell",
+    tag = "Markets",
+    request_body = MarketSellRequest,
+    responses(
+        (
+            body = MarketSellResponse,
+            description = "Recommendation where the items should be sold",
+            status = OK,
+        ),
+        BadRequest,
+        Unauthorized,
+        InternalServerError,
+    ),
+)]
+#[axum::debug_handler]
+pub async fn api(
+    State(state):  State<AppState>,
+    Json(request): Json<MarketSellRequest>,
+) -> Result<impl IntoResponse> {
+    let data = sell(
+            &state.postgres,
+            request,
+        ).await?;
+
+    Ok(
+        (
+            StatusCode::OK,
+            Json(data),
+        )
+        .into_response()
+    )
+}
@@ -1,7 +1,11 @@
 mod bulk;
 mod last_fetched;
+mod own_orders;
+mod sell;
 mod virtual_market;
 
 pub use self::bulk::*;
 pub use self::last_fetched::*;
+pub use self::own_orders::*;
+pub use self::sell::*;
 pub use self::virtual_market::*;
@@ -1,7 +1,10 @@
 mod appraisal;
+mod contract;
 mod multibuy;
 mod smartbuy;
 
+pub use self::contract::*;
+
 use sqlx::PgPool;
 use starfoundry_lib_eve_gateway::EveGatewayApiClient;
 use starfoundry_lib_market::{Asteroid, MarketStrategy, Gas, MarketBulkRequest, MarketBulkResponse, MarketItem};
@@ -182,11 +185,23 @@ pub async fn bulk(
                 return Ok(Vec::new());
             };
 
+            let contracts = if config.contracts {
+                contract_entries(
+                    pool,
+                    &market_items.iter().map(|x| x.type_id).collect::<Vec<_>>(),
+                    &request.markets,
+                )
+                .await?
+            } else {
+                Vec::new()
+            };
+
             self::smartbuy::smartbuy(
                 items,
                 market_items,
                 market_entries,
                 market_last_fetch,
+                contracts,
                 config,
             )
         }
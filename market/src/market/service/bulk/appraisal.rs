@@ -72,6 +72,7 @@ pub fn appraisal(
                                     total_orders: order_sell,
                                 }),
             last_fetch:         Some(*last_fetch),
+            contract_id:        None,
         });
     }
 
@@ -0,0 +1,97 @@
+use sqlx::PgPool;
+use starfoundry_lib_types::{ContractId, StructureId, TypeId};
+use std::collections::HashMap;
+
+use crate::market::error::{MarketError, Result};
+
+/// Public item exchange contract that can be used as a source for buying
+#[derive(Clone, Debug)]
+pub struct ContractEntry {
+    pub contract_id:    ContractId,
+    pub structure_id:   StructureId,
+    /// price of the whole contract
+    pub price:          f64,
+    /// volume of all items in the contract
+    pub volume:         f64,
+    pub items:          HashMap<TypeId, i64>,
+}
+
+impl ContractEntry {
+    /// Quantity of the given type in the contract
+    pub fn quantity(
+        &self,
+        type_id: TypeId,
+    ) -> i64 {
+        self.items
+            .get(&type_id)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Loads all active item exchange contracts in the given markets, that
+/// contain at least one of the given types.
+///
+/// Contracts that ask for items in return or contain blueprints are skipped.
+///
+pub async fn contract_entries(
+    pool:       &PgPool,
+    type_ids:   &[TypeId],
+    markets:    &[StructureId],
+) -> Result<Vec<ContractEntry>> {
+    let rows = sqlx::query!(r#"
+            SELECT
+                c.contract_id,
+                c.start_location_id AS "structure_id!",
+                c.price AS "price!",
+                COALESCE(c.volume, 0) AS "volume!",
+                ci.type_id,
+                ci.quantity
+            FROM contract c
+            JOIN contract_item ci ON ci.contract_id = c.contract_id
+            WHERE c.typ = 'item_exchange'
+            AND c.is_active
+            AND c.date_expired > NOW()
+            AND c.price IS NOT NULL
+            AND c.start_location_id = ANY($2)
+            AND c.contract_id IN (
+                SELECT contract_id
+                FROM contract_item
+                WHERE type_id = ANY($1)
+                AND is_included
+            )
+            AND NOT EXISTS (
+                SELECT 1
+                FROM contract_item x
+                WHERE x.contract_id = c.contract_id
+                AND (NOT x.is_included OR x.is_blueprint_copy IS NOT NULL)
+            )
+            ORDER BY c.contract_id
+        "#,
+            &type_ids.iter().map(|x| **x).collect::<Vec<_>>(),
+            &markets.iter().map(|x| **x).collect::<Vec<_>>(),
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(MarketError::FetchContractEntries)?;
+
+    let mut contracts: Vec<ContractEntry> = Vec::new();
+    for row in rows {
+        let contract_id = ContractId(row.contract_id);
+        if contracts.last().map(|x| x.contract_id) != Some(contract_id) {
+            contracts.push(ContractEntry {
+                contract_id,
+                structure_id: StructureId(row.structure_id),
+                price:        row.price,
+                volume:       row.volume,
+                items:        HashMap::new(),
+            });
+        }
+
+        if let Some(x) = contracts.last_mut() {
+            *x.items.entry(TypeId(row.type_id)).or_default() += row.quantity;
+        }
+    }
+
+    Ok(contracts)
+}
@@ -122,6 +122,7 @@ pub fn multibuy(
             price:              viable_market.price,
             buy_price:          None,
             sell_price:         None,
+            contract_id:        None,
             quantity:           viable_market.quantity,
             source:             viable_market.source.into(),
             item:               item.clone(),
@@ -2,16 +2,17 @@ use chrono::NaiveDateTime;
 use starfoundry_lib_eve_gateway::Item;
 use starfoundry_lib_market::{Asteroid, Gas, MarketBulkResponse, MarketItem, Mineral, SmartBuyConfig};
 use starfoundry_lib_types::{StructureId, TypeId};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::lp::{AsteroidCompressionProblem, MarketProblem};
-use crate::market::MarketEntry;
+use crate::market::{ContractEntry, MarketEntry};
 
 pub fn smartbuy(
     items:          HashMap<TypeId, Item>,
     wanted_items:   Vec<MarketItem>,
     market_entries: Vec<MarketEntry>,
     last_fetched:   HashMap<StructureId, NaiveDateTime>,
+    contracts:      Vec<ContractEntry>,
     config:         SmartBuyConfig,
 ) -> Vec<MarketBulkResponse> {
     let mut market_data: HashMap<TypeId, Vec<MarketEntry>> = HashMap::new();
@@ -69,6 +70,7 @@ pub fn smartbuy(
                     price:              market_result.price,
                     buy_price:          None,
                     sell_price:         None,
+                    contract_id:        None,
                     quantity:           market_result.quantity as u64,
                     source:             structure_id,
                     item:               item.clone(),
@@ -92,6 +94,7 @@ pub fn smartbuy(
                     price:              0f64,
                     buy_price:          None,
                     sell_price:         None,
+                    contract_id:        None,
                     quantity:           *quantity as u64,
                     source:             StructureId(0),
                     item:               item.clone(),
@@ -101,9 +104,22 @@ pub fn smartbuy(
         }
     }
 
+    // contracts that were bought, and the quantity they cover
+    let mut bought_contracts = HashSet::new();
+    let mut covered: HashMap<TypeId, i64> = HashMap::new();
+    // items that already have their purchases in the results
+    let mut processed: HashSet<TypeId> = HashSet::new();
+
     // go through all items and find the best matching prices
     for wanted_item in wanted_items.iter() {
-        if !market_data.contains_key(&wanted_item.type_id) {
+        let item_contracts = contracts
+            .iter()
+            .filter(|x| x.quantity(wanted_item.type_id) > 0)
+            .filter(|x| !bought_contracts.contains(&x.contract_id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !market_data.contains_key(&wanted_item.type_id) && item_contracts.is_empty() {
             continue;
         }
 
@@ -113,7 +129,20 @@ pub fn smartbuy(
             continue;
         }
 
-        let mut data = market_data.get(&wanted_item.type_id).unwrap().clone();
+        // a bundle that was bought for another item may already cover it
+        let quantity = wanted_item.quantity as i64 - covered
+            .get(&wanted_item.type_id)
+            .copied()
+            .unwrap_or_default();
+        if quantity <= 0 {
+            continue;
+        }
+        let quantity = quantity as i32;
+
+        let mut data = market_data
+            .get(&wanted_item.type_id)
+            .cloned()
+            .unwrap_or_default();
         if config.gas_decompression.is_some()
             && let Ok(gas) = Gas::try_from(wanted_item.type_id)
             && gas.is_uncompressed() {
@@ -129,12 +158,13 @@ pub fn smartbuy(
 
         let mut lp = MarketProblem::new();
         lp.calculate_market(data.clone());
+        lp.add_contracts(wanted_item.type_id, item_contracts);
 
         // increase required amount
         let result = if Gas::is_gas(wanted_item.type_id) && let Some(x) = config.gas_decompression {
-            lp.solve(x.compression_quantity(wanted_item.quantity))
+            lp.solve(x.compression_quantity(quantity))
         } else {
-            lp.solve(wanted_item.quantity)
+            lp.solve(quantity)
         };
 
         let item = if let Some(x) = items.get(&wanted_item.type_id) {
@@ -142,14 +172,16 @@ pub fn smartbuy(
         } else {
             continue;
         };
+        processed.insert(wanted_item.type_id);
 
         if let Ok(x) = result {
-            let result = x.into_iter()
+            let result = x.markets.into_iter()
                 .map(|(structure_id, x)| MarketBulkResponse {
                     insufficient_data:  false,
                     price:              x.price,
                     buy_price:          None,
                     sell_price:         None,
+                    contract_id:        None,
                     quantity:           x.quantity as u64,
                     source:             structure_id,
                     item:               item.clone(),
@@ -157,13 +189,56 @@ pub fn smartbuy(
                 })
                 .collect::<Vec<_>>();
             results.extend(result);
+
+            for contract in x.contracts {
+                bought_contracts.insert(contract.contract_id);
+
+                // other wanted items in the same contract come for free
+                for other_item in wanted_items.iter() {
+                    let contract_quantity = contract.quantity(other_item.type_id);
+                    if contract_quantity <= 0 {
+                        continue;
+                    }
+                    let other = if let Some(x) = items.get(&other_item.type_id) {
+                        x
+                    } else {
+                        continue;
+                    };
+
+                    let price = if other_item.type_id == wanted_item.type_id {
+                        contract.price / contract_quantity as f64
+                    } else {
+                        0f64
+                    };
+
+                    *covered.entry(other_item.type_id).or_default() += contract_quantity;
+                    // items that were handled before are already bought
+                    if other_item.type_id != wanted_item.type_id &&
+                        processed.contains(&other_item.type_id) {
+
+                        subtract_covered(&mut results, other_item.type_id, contract_quantity as u64);
+                    }
+                    results.push(MarketBulkResponse {
+                        insufficient_data:  false,
+                        price,
+                        buy_price:          None,
+                        sell_price:         None,
+                        contract_id:        Some(contract.contract_id),
+                        quantity:           contract_quantity as u64,
+                        source:             contract.structure_id,
+                        item:               other.clone(),
+                        last_fetch:         None,
+                    });
+                }
+            }
         } else {
             results.push(MarketBulkResponse {
                 insufficient_data:  true,
                 price:              0f64,
                 buy_price:          None,
                 sell_price:         None,
-                quantity:           wanted_item.quantity as u64,
+                contract_id:        None,
+                quantity:           quantity as u64,
                 source:             StructureId(0),
                 item:               item.clone(),
                 last_fetch:         None,
@@ -174,3 +249,224 @@ pub fn smartbuy(
     dbg!("smart", start.elapsed().as_millis());
     results
 }
+
+/// Reduces the purchases of the given type by the quantity a bought contract
+/// covers. Quantities without a market are reduced first, then the most
+/// expensive purchases
+fn subtract_covered(
+    results:  &mut Vec<MarketBulkResponse>,
+    type_id:  TypeId,
+    quantity: u64,
+) {
+    let mut remaining = quantity;
+
+    let mut entries = results
+        .iter_mut()
+        .filter(|x| x.item.type_id == type_id && x.contract_id.is_none())
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| {
+        b.insufficient_data
+            .cmp(&a.insufficient_data)
+            .then(b.price.total_cmp(&a.price))
+    });
+
+    for entry in entries {
+        let quantity = entry.quantity.min(remaining);
+        entry.quantity -= quantity;
+        remaining -= quantity;
+    }
+
+    results.retain(|x| {
+        x.item.type_id != type_id ||
+        x.contract_id.is_some() ||
+        x.quantity > 0
+    });
+}
+
+#[cfg(test)]
+mod bulk_smartbuy_tests {
+    use starfoundry_lib_eve_gateway::{Category, Group, Item};
+    use starfoundry_lib_market::{MarketBulkResponse, MarketItem, SmartBuyConfig};
+    use starfoundry_lib_types::{ContractId, StructureId, TypeId};
+    use std::collections::HashMap;
+
+    use crate::market::{ContractEntry, MarketEntry};
+
+    const JITA: StructureId = StructureId(60003760);
+
+    fn items() -> HashMap<TypeId, Item> {
+        let mut items = HashMap::new();
+        for type_id in [1, 2] {
+            items.insert(type_id.into(), Item {
+                category: Category {
+                    category_id: 1.into(),
+                    name: "Test Category".into(),
+                },
+                group: Group {
+                    category_id: 1.into(),
+                    group_id: 1.into(),
+                    name: "Test Group".into(),
+                },
+                name: format!("Test item {type_id}"),
+                type_id: type_id.into(),
+                volume: 0f32,
+                meta_group: None,
+                repackaged: None,
+            });
+        }
+        items
+    }
+
+    fn market_entry(
+        order_id: i64,
+        type_id:  i32,
+        price:    f64,
+        quantity: i32,
+    ) -> MarketEntry {
+        MarketEntry {
+            item_volume: 0f64,
+            order_id,
+            price,
+            quantity,
+            structure_id: JITA,
+            type_id: type_id.into(),
+            is_buy: false,
+        }
+    }
+
+    fn contract(
+        contract_id: i64,
+        price:       f64,
+        items:       Vec<(i32, i64)>,
+    ) -> ContractEntry {
+        ContractEntry {
+            contract_id:  ContractId(contract_id),
+            structure_id: JITA,
+            price,
+            volume:       0f64,
+            items:        items
+                .into_iter()
+                .map(|(type_id, quantity)| (type_id.into(), quantity))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    fn wanted(
+        type_id:  i32,
+        quantity: i32,
+    ) -> MarketItem {
+        MarketItem {
+            type_id: type_id.into(),
+            quantity,
+        }
+    }
+
+    fn config() -> SmartBuyConfig {
+        SmartBuyConfig {
+            gas_decompression:   None,
+            mineral_compression: None,
+            contracts:           true,
+        }
+    }
+
+    /// (type_id, contract_id, quantity, price) sorted by type and contract
+    fn summary(
+        result: Vec<MarketBulkResponse>,
+    ) -> Vec<(TypeId, Option<ContractId>, u64, f64)> {
+        let mut result = result
+            .into_iter()
+            .map(|x| (x.item.type_id, x.contract_id, x.quantity, x.price))
+            .collect::<Vec<_>>();
+        result.sort_by_key(|(type_id, contract_id, _, _)| (*type_id, *contract_id));
+        result
+    }
+
+    #[test]
+    fn single_item_contract() {
+        let result = super::smartbuy(
+            items(),
+            vec![wanted(1, 10)],
+            vec![market_entry(1, 1, 100f64, 10)],
+            HashMap::new(),
+            vec![contract(1, 500f64, vec![(1, 10)])],
+            config(),
+        );
+
+        assert_eq!(
+            summary(result),
+            vec![(TypeId(1), Some(ContractId(1)), 10, 50f64)],
+        );
+    }
+
+    #[test]
+    fn bundle_covers_later_item() {
+        let result = super::smartbuy(
+            items(),
+            vec![wanted(1, 10), wanted(2, 5)],
+            vec![
+                market_entry(1, 1, 100f64, 10),
+                market_entry(2, 2, 100f64, 5),
+            ],
+            HashMap::new(),
+            vec![contract(1, 600f64, vec![(1, 10), (2, 5)])],
+            config(),
+        );
+
+        assert_eq!(
+            summary(result),
+            vec![
+                (TypeId(1), Some(ContractId(1)), 10, 60f64),
+                (TypeId(2), Some(ContractId(1)), 5, 0f64),
+            ],
+        );
+    }
+
+    #[test]
+    fn bundle_covers_earlier_item() {
+        // the first item is cheaper on the market, the bundle is only bought
+        // for the second item and makes the market purchase obsolete
+        let result = super::smartbuy(
+            items(),
+            vec![wanted(1, 10), wanted(2, 5)],
+            vec![
+                market_entry(1, 1, 10f64, 10),
+                market_entry(2, 2, 1_000f64, 5),
+            ],
+            HashMap::new(),
+            vec![contract(1, 1_000f64, vec![(1, 10), (2, 5)])],
+            config(),
+        );
+
+        assert_eq!(
+            summary(result),
+            vec![
+                (TypeId(1), Some(ContractId(1)), 10, 0f64),
+                (TypeId(2), Some(ContractId(1)), 5, 200f64),
+            ],
+        );
+    }
+
+    #[test]
+    fn bundle_partially_covers_earlier_item() {
+        let result = super::smartbuy(
+            items(),
+            vec![wanted(1, 10), wanted(2, 5)],
+            vec![
+                market_entry(1, 1, 10f64, 10),
+                market_entry(2, 2, 1_000f64, 5),
+            ],
+            HashMap::new(),
+            vec![contract(1, 1_000f64, vec![(1, 4), (2, 5)])],
+            config(),
+        );
+
+        assert_eq!(
+            summary(result),
+            vec![
+                (TypeId(1), None, 6, 10f64),
+                (TypeId(1), Some(ContractId(1)), 4, 0f64),
+                (TypeId(2), Some(ContractId(1)), 5, 200f64),
+            ],
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use starfoundry_lib_market::{MarketOrderNotification, MarketOwnOrder, MarketOwnOrderFilter, MarketOwnOrderStatus};
+
+use crate::market::error::{MarketError, Result};
+
+const CORPORATION_SCOPE: &str = "esi-markets.read_corporation_orders.v1";
+const DISCORD_WEBHOOK: &str = "https://discord.com/api/webhooks/";
+
+/// Issuers whose orders the character can read, the orders of the
+/// corporation are only readable if the character granted the corporation
+/// market order scope
+pub fn issuer_ids(
+    character_id:   i32,
+    corporation_id: i32,
+    scopes:         &[String],
+) -> Vec<i32> {
+    let mut issuer_ids = vec![character_id];
+    if scopes.iter().any(|x| x == CORPORATION_SCOPE) {
+        issuer_ids.push(corporation_id);
+    }
+    issuer_ids
+}
+
+/// Lists the orders of the given issuers and compares them against the
+/// other orders in the same market.
+///
+/// Orders of all given issuers are not considered as competition.
+///
+pub async fn list_own_orders(
+    pool:       &PgPool,
+    issuer_ids: &[i32],
+    filter:     MarketOwnOrderFilter,
+) -> Result<Vec<MarketOwnOrder>> {
+    let closed_since = filter
+        .closed_since
+        .unwrap_or(Utc::now() - Duration::days(1));
+
+    let orders = sqlx::query!(r#"
+            SELECT
+                mpo.order_id,
+                mpo.issuer_id,
+                mpo.structure_id,
+                mpo.type_id,
+                mpo.is_buy,
+                mpo.price,
+                mpo.remaining,
+                mpo.expires,
+                mpo.closed_at,
+                (
+                    SELECT
+                        CASE
+                            WHEN mpo.is_buy
+                            THEN MAX(mol.price)
+                            ELSE MIN(mol.price)
+                        END
+                    FROM market_order_latest mol
+                    WHERE mol.type_id = mpo.type_id
+                    AND mol.structure_id = mpo.structure_id
+                    AND mol.is_buy = mpo.is_buy
+                    AND mol.order_id NOT IN (
+                        SELECT order_id
+                        FROM market_private_order
+                        WHERE issuer_id = ANY($1)
+                    )
+                ) AS competitor_price
+            FROM market_private_order mpo
+            WHERE mpo.issuer_id = ANY($1)
+            AND (mpo.closed_at IS NULL OR mpo.closed_at >= $2)
+            ORDER BY mpo.closed_at DESC NULLS FIRST, mpo.order_id
+        "#,
+            issuer_ids,
+            closed_since,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(MarketError::FetchOwnOrders)?
+        .into_iter()
+        .map(|x| MarketOwnOrder {
+            order_id:         x.order_id.into(),
+            issuer_id:        x.issuer_id,
+            structure_id:     x.structure_id.into(),
+            type_id:          x.type_id.into(),
+            is_buy:           x.is_buy,
+            price:            x.price,
+            remaining:        x.remaining,
+            expires:          x.expires,
+            closed_at:        x.closed_at,
+            status:           MarketOwnOrderStatus::evaluate(
+                x.is_buy,
+                x.price,
+                x.expires,
+                x.closed_at.map(|x| x.naive_utc()),
+                x.competitor_price,
+            ),
+            competitor_price: x.competitor_price,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(orders)
+}
+
+/// The worker posts the orders to the webhook, so it must point to discord
+fn validate_webhook(
+    url: &str,
+) -> Result<()> {
+    if url.starts_with(DISCORD_WEBHOOK) {
+        Ok(())
+    } else {
+        Err(MarketError::InvalidWebhook(url.into()))
+    }
+}
+
+/// Sets or removes the discord webhook that is notified about changes of
+/// the orders of an issuer.
+/// Only discord webhooks are accepted
+pub async fn update_order_notification(
+    pool:         &PgPool,
+    notification: MarketOrderNotification,
+) -> Result<()> {
+    if let Some(url) = notification.url {
+        validate_webhook(&url)?;
+
+        sqlx::query!("
+                INSERT INTO market_order_notification (issuer_id, url)
+                VALUES ($1, $2)
+                ON CONFLICT (issuer_id)
+                DO UPDATE SET
+                    url = EXCLUDED.url,
+                    updated_at = NOW()
+            ",
+                notification.issuer_id,
+                url,
+            )
+            .execute(pool)
+            .await
+            .map_err(MarketError::UpdateOrderNotification)?;
+    } else {
+        sqlx::query!("
+                DELETE FROM market_order_notification
+                WHERE issuer_id = $1
+            ",
+                notification.issuer_id,
+            )
+            .execute(pool)
+            .await
+            .map_err(MarketError::UpdateOrderNotification)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod own_order_access_tests {
+    use crate::market::error::MarketError;
+
+    #[test]
+    fn corporation_requires_scope() {
+        assert_eq!(super::issuer_ids(1, 2, &[]), vec![1]);
+        assert_eq!(
+            super::issuer_ids(1, 2, &["esi-markets.read_character_orders.v1".into()]),
+            vec![1],
+        );
+        assert_eq!(
+            super::issuer_ids(1, 2, &["esi-markets.read_corporation_orders.v1".into()]),
+            vec![1, 2],
+        );
+    }
+
+    #[test]
+    fn only_discord_webhooks() {
+        for url in [
+            "https://example.com/api/webhooks/1/token",
+            "http://discord.com/api/webhooks/1/token",
+            "https://discord.com.example.com/api/webhooks/1/token",
+        ] {
+            assert!(matches!(
+                super::validate_webhook(url),
+                Err(MarketError::InvalidWebhook(_)),
+            ));
+        }
+
+        assert!(super::validate_webhook("https://discord.com/api/webhooks/1/token").is_ok());
+    }
+}
+
+#[cfg(test)]
+mod own_order_status_tests {
+    use chrono::{Duration, Utc};
+    use starfoundry_lib_market::MarketOwnOrderStatus;
+
+    #[test]
+    fn undercut_and_outbid() {
+        let expires = Utc::now().naive_utc() + Duration::days(1);
+
+        assert_eq!(
+            MarketOwnOrderStatus::evaluate(false, 100f64, expires, None, Some(99f64)),
+            MarketOwnOrderStatus::Undercut,
+        );
+        assert_eq!(
+            MarketOwnOrderStatus::evaluate(false, 100f64, expires, None, Some(100f64)),
+            MarketOwnOrderStatus::Active,
+        );
+        assert_eq!(
+            MarketOwnOrderStatus::evaluate(true, 100f64, expires, None, Some(101f64)),
+            MarketOwnOrderStatus::Outbid,
+        );
+        assert_eq!(
+            MarketOwnOrderStatus::evaluate(true, 100f64, expires, None, None),
+            MarketOwnOrderStatus::Active,
+        );
+    }
+
+    #[test]
+    fn filled_and_expired() {
+        let now = Utc::now().naive_utc();
+
+        assert_eq!(
+            MarketOwnOrderStatus::evaluate(false, 100f64, now + Duration::days(1), Some(now), Some(1f64)),
+            MarketOwnOrderStatus::Filled,
+        );
+        assert_eq!(
+            MarketOwnOrderStatus::evaluate(false, 100f64, now - Duration::days(1), Some(now), None),
+            MarketOwnOrderStatus::Expired,
+        );
+        assert_eq!(
+            MarketOwnOrderStatus::evaluate(true, 100f64, now - Duration::days(1), None, None),
+            MarketOwnOrderStatus::Expired,
+        );
+    }
+}
@@ -0,0 +1,361 @@
+use sqlx::PgPool;
+use starfoundry_lib_market::{MarketItem, MarketSellEntry, MarketSellFees, MarketSellKind, MarketSellMarket, MarketSellRequest, MarketSellResponse};
+use starfoundry_lib_types::{StructureId, TypeId};
+use std::collections::BTreeMap;
+
+use crate::market::error::{MarketError, Result};
+use crate::market::MarketEntry;
+
+/// Amount a listed sell order undercuts the cheapest sell order
+const UNDERCUT: f64 = 0.01;
+
+pub async fn sell(
+    pool:    &PgPool,
+    request: MarketSellRequest,
+) -> Result<MarketSellResponse> {
+    let type_ids = request
+        .items
+        .iter()
+        .map(|x| *x.type_id)
+        .collect::<Vec<_>>();
+
+    let market_entries = sqlx::query!("
+            SELECT
+                order_id,
+                structure_id,
+                price,
+                remaining,
+                is_buy,
+                type_id
+            FROM market_order_latest mol
+            WHERE mol.type_id = ANY($1)
+            AND mol.structure_id = ANY($2)
+        ",
+            &type_ids,
+            &request.markets.iter().map(|x| **x).collect::<Vec<_>>(),
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(MarketError::FetchMarketEntries)?
+        .into_iter()
+        .map(|x| MarketEntry {
+            order_id:       x.order_id,
+            structure_id:   x.structure_id.into(),
+            price:          x.price,
+            quantity:       x.remaining,
+            // not relevant when selling
+            item_volume:    0f64,
+            type_id:        x.type_id.into(),
+            is_buy:         x.is_buy,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(sell_plan(
+        &request.fees,
+        &request.markets,
+        request.items,
+        market_entries,
+    ))
+}
+
+/// Listing option of an item in a single market
+struct Listing {
+    structure_id: StructureId,
+    price:        f64,
+    /// income per unit after all fees
+    net:          f64,
+}
+
+/// Plans the sale of the given items across the given markets.
+///
+/// Units are sold into the buy orders with the highest price first, as long
+/// as a unit yields at least as much as listing it in the best market.
+/// Everything that is left is listed as sell order in the market with the
+/// highest income after fees.
+/// Items that have neither buy orders that can take them, nor sell orders
+/// to price them against, are returned as unsold.
+///
+pub fn sell_plan(
+    fees:           &MarketSellFees,
+    markets:        &[StructureId],
+    items:          Vec<MarketItem>,
+    market_entries: Vec<MarketEntry>,
+) -> MarketSellResponse {
+    let sales_tax = fees.sales_tax();
+    let mut response = MarketSellResponse::default();
+
+    for item in items {
+        if item.quantity <= 0 {
+            continue;
+        }
+
+        let listing = best_listing(
+            fees,
+            item.type_id,
+            &market_entries,
+        );
+
+        let mut buy_orders = market_entries
+            .iter()
+            .filter(|x| x.is_buy && x.type_id == item.type_id && x.quantity > 0)
+            .collect::<Vec<_>>();
+        buy_orders.sort_by(|a, b| b.price.total_cmp(&a.price));
+
+        // (structure, kind) -> (quantity, gross income)
+        let mut sales: BTreeMap<(StructureId, MarketSellKind), (i32, f64)> = BTreeMap::new();
+        let mut remaining = item.quantity;
+
+        for order in buy_orders {
+            if remaining == 0 {
+                break;
+            }
+
+            if let Some(ref listing) = listing &&
+                order.price * (1f64 - sales_tax) < listing.net {
+                break;
+            }
+
+            let quantity = remaining.min(order.quantity);
+            remaining -= quantity;
+
+            let entry = sales
+                .entry((order.structure_id, MarketSellKind::BuyOrder))
+                .or_default();
+            entry.0 += quantity;
+            entry.1 += quantity as f64 * order.price;
+        }
+
+        if remaining > 0 {
+            if let Some(listing) = listing {
+                let entry = sales
+                    .entry((listing.structure_id, MarketSellKind::SellOrder))
+                    .or_default();
+                entry.0 += remaining;
+                entry.1 += remaining as f64 * listing.price;
+            } else {
+                response.unsold.push(MarketItem {
+                    type_id:  item.type_id,
+                    quantity: remaining,
+                });
+            }
+        }
+
+        for ((structure_id, kind), (quantity, gross)) in sales {
+            let fee_rate = match kind {
+                MarketSellKind::BuyOrder  => sales_tax,
+                MarketSellKind::SellOrder => sales_tax + fees.broker_fee(structure_id),
+            };
+            let fees = gross * fee_rate;
+
+            response.entries.push(MarketSellEntry {
+                type_id:      item.type_id,
+                structure_id,
+                kind,
+                quantity,
+                price:        gross / quantity as f64,
+                fees,
+                expected_isk: gross - fees,
+            });
+        }
+    }
+
+    response.markets = markets
+        .iter()
+        .map(|structure_id| MarketSellMarket {
+            structure_id: *structure_id,
+            expected_isk: response
+                .entries
+                .iter()
+                .filter(|x| x.structure_id == *structure_id)
+                .map(|x| x.expected_isk)
+                .sum(),
+        })
+        .collect::<Vec<_>>();
+
+    response
+}
+
+/// Finds the market where listing a sell order yields the most after fees
+fn best_listing(
+    fees:           &MarketSellFees,
+    type_id:        TypeId,
+    market_entries: &[MarketEntry],
+) -> Option<Listing> {
+    let sales_tax = fees.sales_tax();
+
+    let mut lowest: BTreeMap<StructureId, f64> = BTreeMap::new();
+    market_entries
+        .iter()
+        .filter(|x| !x.is_buy && x.type_id == type_id && x.quantity > 0)
+        .for_each(|x| {
+            lowest
+                .entry(x.structure_id)
+                .and_modify(|price| *price = price.min(x.price))
+                .or_insert(x.price);
+        });
+
+    lowest
+        .into_iter()
+        .map(|(structure_id, price)| {
+            let price = (price - UNDERCUT).max(UNDERCUT);
+            Listing {
+                structure_id,
+                price,
+                net: price * (1f64 - sales_tax - fees.broker_fee(structure_id)),
+            }
+        })
+        .max_by(|a, b| a.net.total_cmp(&b.net))
+}
+
+#[cfg(test)]
+mod sell_plan_tests {
+    use starfoundry_lib_market::{MarketItem, MarketSellBrokerFee, MarketSellFees, MarketSellKind};
+    use starfoundry_lib_types::{StructureId, TypeId};
+
+    use crate::market::MarketEntry;
+
+    fn order(
+        structure_id: i64,
+        price:        f64,
+        quantity:     i32,
+        is_buy:       bool,
+    ) -> MarketEntry {
+        MarketEntry {
+            item_volume:  0f64,
+            order_id:     0i64,
+            price,
+            quantity,
+            structure_id: structure_id.into(),
+            type_id:      TypeId(1),
+            is_buy,
+        }
+    }
+
+    fn wanted(quantity: i32) -> Vec<MarketItem> {
+        vec![
+            MarketItem {
+                quantity,
+                type_id: 1.into(),
+            }
+        ]
+    }
+
+    #[test]
+    fn fees_by_skills_and_standings() {
+        let fees = MarketSellFees::default();
+        assert!((fees.sales_tax() - 0.075).abs() < 1e-9);
+        assert!((fees.broker_fee(StructureId(1)) - 0.03).abs() < 1e-9);
+
+        let fees = MarketSellFees {
+            accounting:           5,
+            broker_relations:     5,
+            faction_standing:     10f64,
+            corporation_standing: 10f64,
+            broker_fees:          vec![
+                MarketSellBrokerFee {
+                    structure_id: StructureId(2),
+                    broker_fee:   0.005,
+                }
+            ],
+        };
+        assert!((fees.sales_tax() - 0.03375).abs() < 1e-9);
+        assert!((fees.broker_fee(StructureId(1)) - 0.01).abs() < 1e-9);
+        assert!((fees.broker_fee(StructureId(2)) - 0.005).abs() < 1e-9);
+    }
+
+    #[test]
+    fn dump_into_buy_orders_until_listing_is_better() {
+        let market_entries = vec![
+            order(1, 100f64, 10, true),
+            order(1, 50f64, 100, true),
+            order(1, 101f64, 10, false),
+        ];
+
+        let result = super::sell_plan(
+            &MarketSellFees::default(),
+            &[StructureId(1)],
+            wanted(30),
+            market_entries,
+        );
+
+        assert!(result.unsold.is_empty());
+        assert_eq!(result.entries.len(), 2);
+
+        let buy = result.entries.iter().find(|x| x.kind == MarketSellKind::BuyOrder).unwrap();
+        assert_eq!(buy.quantity, 10);
+        assert_eq!(buy.price, 100f64);
+        assert!((buy.expected_isk - 925f64).abs() < 1e-6);
+
+        let sell = result.entries.iter().find(|x| x.kind == MarketSellKind::SellOrder).unwrap();
+        assert_eq!(sell.quantity, 20);
+        assert_eq!(sell.price, 100.99);
+        assert!((sell.fees - 20f64 * 100.99 * 0.105).abs() < 1e-6);
+
+        let market = result.markets.first().unwrap();
+        assert!((market.expected_isk - buy.expected_isk - sell.expected_isk).abs() < 1e-6);
+    }
+
+    #[test]
+    fn respect_buy_order_depth() {
+        let market_entries = vec![
+            order(1, 100f64, 5, true),
+            order(2, 90f64, 5, true),
+        ];
+
+        let result = super::sell_plan(
+            &MarketSellFees::default(),
+            &[StructureId(1), StructureId(2)],
+            wanted(20),
+            market_entries,
+        );
+
+        assert_eq!(result.entries.len(), 2);
+        assert_eq!(result.entries.iter().map(|x| x.quantity).sum::<i32>(), 10);
+        assert_eq!(result.unsold, wanted(10));
+        assert_eq!(result.markets.len(), 2);
+    }
+
+    #[test]
+    fn list_in_market_with_lowest_broker_fee() {
+        let market_entries = vec![
+            order(1, 100f64, 10, false),
+            order(2, 100f64, 10, false),
+        ];
+        let fees = MarketSellFees {
+            broker_fees: vec![
+                MarketSellBrokerFee {
+                    structure_id: StructureId(2),
+                    broker_fee:   0.005,
+                }
+            ],
+            ..Default::default()
+        };
+
+        let result = super::sell_plan(
+            &fees,
+            &[StructureId(1), StructureId(2)],
+            wanted(100),
+            market_entries,
+        );
+
+        assert_eq!(result.entries.len(), 1);
+        let entry = result.entries.first().unwrap();
+        assert_eq!(entry.structure_id, StructureId(2));
+        assert_eq!(entry.kind, MarketSellKind::SellOrder);
+        assert_eq!(entry.quantity, 100);
+    }
+
+    #[test]
+    fn unsold_without_orders() {
+        let result = super::sell_plan(
+            &MarketSellFees::default(),
+            &[StructureId(1)],
+            wanted(10),
+            Vec::new(),
+        );
+
+        assert!(result.entries.is_empty());
+        assert_eq!(result.unsold, wanted(10));
+        assert_eq!(result.markets.first().unwrap().expected_isk, 0f64);
+    }
+}
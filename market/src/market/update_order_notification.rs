@@ -0,0 +1,80 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use axum::response::IntoResponse;
+use starfoundry_lib_eve_gateway::EveGatewayApiClient;
+use starfoundry_lib_gateway::{ExtractIdentity, Identity};
+use starfoundry_lib_market::MarketOrderNotification;
+
+use crate::api_docs::{BadRequest, Forbidden, InternalServerError, Unauthorized};
+use crate::{AppState, eve_gateway_api_client_with_identity};
+use crate::market::error::{MarketError, Result};
+use crate::market::service::{issuer_ids, update_order_notification};
+
+/// Update Order Notification
+/// 
+/// - Alternative route: `/latest/markets/orders/notification`
+/// - Alternative route: `/v1/markets/orders/notification`
+/// 
+/// ---
+/// 
+/// Sets the discord webhook that is notified when an order of the issuer
+/// is undercut, outbid, filled or expired.
+/// Only `https://discord.com/api/webhooks/` urls are accepted.
+/// If no url is given, the notification is removed.
+/// 
+/// ## Security
+/// - authenticated
+/// - the issuer must be the character, or its corporation if the character
+///   granted the scope `esi-markets.read_corporation_orders.v1`
+/// 
+#[utoipa::path(
+    put,
+    path = "/orders/notification",
+    tag = "Markets",
+    request_body = MarketOrderNotification,
+    responses(
+        (
+            description = "The notification was updated",
+            status = NO_CONTENT,
+        ),
+        BadRequest,
+        Unauthorized,
+        Forbidden,
+        InternalServerError,
+    ),
+    security(
+        ("api_key" = [])
+    ),
+)]
+pub async fn api(
+    identity:           ExtractIdentity,
+    State(state):       State<AppState>,
+    Json(notification): Json<MarketOrderNotification>,
+) -> Result<impl IntoResponse> {
+    let scopes = eve_gateway_api_client_with_identity(Identity::new(
+            identity.character_id,
+            identity.corporation_id,
+            identity.host()?,
+        ))?
+        .list_scopes()
+        .await?;
+
+    if !issuer_ids(*identity.character_id, *identity.corporation_id, &scopes)
+        .contains(&notification.issuer_id) {
+        return Err(MarketError::Forbidden(notification.issuer_id));
+    }
+
+    update_order_notification(
+            &state.postgres,
+            notification,
+        ).await?;
+
+    Ok(
+        (
+            StatusCode::NO_CONTENT,
+            Json(()),
+        )
+        .into_response()
+    )
+}
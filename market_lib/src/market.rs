@@ -2,13 +2,17 @@ mod asteroid_utils;
 mod bulk;
 mod gas_utils;
 mod mineral_utils;
+mod own_order;
+mod sell;
 mod virtual_market;
 use starfoundry_lib_gateway::ApiClient;
 
 pub use self::asteroid_utils::*;
 pub use self::bulk::*;
 pub use self::mineral_utils::*;
+pub use self::own_order::*;
 pub use self::gas_utils::*;
+pub use self::sell::*;
 pub use self::virtual_market::*;
 
 use crate::Result;
@@ -28,6 +32,34 @@ pub trait MarketApiClientOrder: ApiClient {
             .map_err(Into::into)
     }
 
+    #[allow(async_fn_in_trait)]
+    async fn list_own_orders(
+        &self,
+        filter: &MarketOwnOrderFilter,
+    ) -> Result<Vec<MarketOwnOrder>> {
+        self
+            .fetch(
+                "markets/orders",
+                filter,
+            )
+            .await
+            .map_err(Into::into)
+    }
+
+    #[allow(async_fn_in_trait)]
+    async fn sell_plan(
+        &self,
+        request: MarketSellRequest,
+    ) -> Result<MarketSellResponse> {
+        self
+            .post(
+                "markets/sell",
+                request,
+            )
+            .await
+            .map_err(Into::into)
+    }
+
     #[allow(async_fn_in_trait)]
     async fn update_virtual_market(
         &self,
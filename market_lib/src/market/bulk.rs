@@ -1,7 +1,7 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use starfoundry_lib_eve_gateway::Item;
-use starfoundry_lib_types::{StructureId, TypeId};
+use starfoundry_lib_types::{ContractId, StructureId, TypeId};
 use utoipa::{IntoParams, ToSchema};
 
 use crate::{GasDecompressionEfficiency, OreReprocessingEfficiency};
@@ -38,6 +38,10 @@ pub struct MarketBulkResponse {
     /// additional price information
     pub buy_price:          Option<MarketPrice>,
     pub sell_price:         Option<MarketPrice>,
+    /// set if the items are bought with a public item exchange contract,
+    /// the price of a contract is carried by a single item of it
+    #[serde(default)]
+    pub contract_id:        Option<ContractId>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
@@ -47,7 +51,7 @@ pub struct MarketPrice {
     pub total_orders:   i32,
 }
 
-#[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema, IntoParams)]
 pub struct MarketItem {
     pub type_id:    TypeId,
     pub quantity:   i32,
@@ -80,4 +84,7 @@ pub struct SmartBuyConfig {
     pub gas_decompression:      Option<GasDecompressionEfficiency>,
     // mineral compression is active
     pub mineral_compression:    Option<OreReprocessingEfficiency>,
+    // public item exchange contracts in the markets are used as source
+    #[serde(default)]
+    pub contracts:              bool,
 }
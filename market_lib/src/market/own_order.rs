@@ -0,0 +1,115 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::{OrderId, StructureId, TypeId};
+use utoipa::{IntoParams, ToSchema};
+
+/// Own market order compared against the current order book
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct MarketOwnOrder {
+    pub order_id:           OrderId,
+    /// either a character_id or a corporation_id
+    pub issuer_id:          i32,
+    pub structure_id:       StructureId,
+    pub type_id:            TypeId,
+    pub is_buy:             bool,
+    pub price:              f64,
+    pub remaining:          i32,
+    /// date the order expires naturally
+    pub expires:            NaiveDateTime,
+    /// set as soon as the order is no longer returned by eve
+    pub closed_at:          Option<DateTime<Utc>>,
+    pub status:             MarketOwnOrderStatus,
+    /// lowest price of other sell orders, or the highest price of other buy
+    /// orders in the same market
+    pub competitor_price:   Option<f64>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct MarketOwnOrderFilter {
+    /// orders that were closed after the given date are included,
+    /// defaults to the last 24 hours
+    pub closed_since:   Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct MarketOrderNotification {
+    /// either the character_id or the corporation_id of the requesting
+    /// character
+    pub issuer_id:  i32,
+    /// discord webhook, if not set, the notification is removed
+    pub url:        Option<String>,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq, PartialOrd, Ord,
+    sqlx::Type, Deserialize, Serialize, ToSchema,
+)]
+#[sqlx(type_name = "MARKET_OWN_ORDER_STATUS")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarketOwnOrderStatus {
+    /// The order has the best price in the market
+    ///
+    Active,
+    /// Another sell order is cheaper
+    ///
+    Undercut,
+    /// Another buy order pays more
+    ///
+    Outbid,
+    /// The order was closed before it expired.
+    /// Cancelled orders cannot be told apart from filled ones
+    ///
+    Filled,
+    /// The order reached its expiry date
+    ///
+    Expired,
+}
+
+impl MarketOwnOrderStatus {
+    /// Determines the status of an own order.
+    ///
+    /// `competitor_price` is the best price of all other orders of the same
+    /// side in the same market.
+    ///
+    #[must_use]
+    pub fn evaluate(
+        is_buy:           bool,
+        price:            f64,
+        expires:          NaiveDateTime,
+        closed_at:        Option<NaiveDateTime>,
+        competitor_price: Option<f64>,
+    ) -> Self {
+        if let Some(closed_at) = closed_at {
+            return if expires <= closed_at {
+                Self::Expired
+            } else {
+                Self::Filled
+            };
+        }
+
+        if expires <= Utc::now().naive_utc() {
+            return Self::Expired;
+        }
+
+        match competitor_price {
+            Some(x) if is_buy && x > price  => Self::Outbid,
+            Some(x) if !is_buy && x < price => Self::Undercut,
+            Some(_) | None                  => Self::Active,
+        }
+    }
+
+    /// Returns `true` if the status should be notified about
+    #[must_use]
+    pub fn is_alert(&self) -> bool {
+        match self {
+            Self::Active    => false,
+            Self::Undercut  |
+            Self::Outbid    |
+            Self::Filled    |
+            Self::Expired   => true,
+        }
+    }
+}
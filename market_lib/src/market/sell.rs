@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use starfoundry_lib_types::{StructureId, TypeId};
+use utoipa::ToSchema;
+
+use crate::MarketItem;
+
+/// Base sales tax without any skills
+pub const BASE_SALES_TAX: f64 = 0.075;
+/// Base broker fee in NPC stations without any skills or standings
+pub const BASE_BROKER_FEE: f64 = 0.03;
+/// The broker fee in NPC stations can never go below this
+pub const MIN_BROKER_FEE: f64 = 0.01;
+
+/// Request for planning the sale of items
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct MarketSellRequest {
+    pub markets:    Vec<StructureId>,
+    pub items:      Vec<MarketItem>,
+    #[serde(default)]
+    pub fees:       MarketSellFees,
+}
+
+/// Skills and standings of the selling character
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct MarketSellFees {
+    /// level of the skill `Accounting`
+    #[serde(default)]
+    pub accounting:             u8,
+    /// level of the skill `Broker Relations`
+    #[serde(default)]
+    pub broker_relations:       u8,
+    /// standing towards the faction owning the station
+    #[serde(default)]
+    pub faction_standing:       f64,
+    /// standing towards the corporation owning the station
+    #[serde(default)]
+    pub corporation_standing:   f64,
+    /// broker fees set by the owners of player structures, markets that are
+    /// not listed use the NPC station fee
+    #[serde(default)]
+    pub broker_fees:            Vec<MarketSellBrokerFee>,
+}
+
+impl MarketSellFees {
+    /// Sales tax that is paid on every sold unit, `0.075` equals 7.5%
+    #[must_use]
+    pub fn sales_tax(&self) -> f64 {
+        BASE_SALES_TAX * (1f64 - 0.11 * self.accounting.min(5) as f64)
+    }
+
+    /// Broker fee that is paid when listing a sell order in the given
+    /// market, `0.03` equals 3%
+    #[must_use]
+    pub fn broker_fee(
+        &self,
+        structure_id: StructureId,
+    ) -> f64 {
+        if let Some(x) = self
+            .broker_fees
+            .iter()
+            .find(|x| x.structure_id == structure_id) {
+
+            return x.broker_fee;
+        }
+
+        let fee = BASE_BROKER_FEE
+            - 0.003 * self.broker_relations.min(5) as f64
+            - 0.0003 * self.faction_standing
+            - 0.0002 * self.corporation_standing;
+        fee.max(MIN_BROKER_FEE)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct MarketSellBrokerFee {
+    pub structure_id:   StructureId,
+    /// `0.01` equals 1%
+    pub broker_fee:     f64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct MarketSellResponse {
+    pub entries:    Vec<MarketSellEntry>,
+    /// expected income per market after all fees
+    pub markets:    Vec<MarketSellMarket>,
+    /// items that cannot be sold in any of the markets
+    pub unsold:     Vec<MarketItem>,
+}
+
+/// Recommendation how many units of an item should be sold where
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct MarketSellEntry {
+    pub type_id:        TypeId,
+    pub structure_id:   StructureId,
+    pub kind:           MarketSellKind,
+    pub quantity:       i32,
+    /// average price per unit before fees
+    pub price:          f64,
+    /// sales tax and broker fee of all units
+    pub fees:           f64,
+    /// income after all fees
+    pub expected_isk:   f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct MarketSellMarket {
+    pub structure_id:   StructureId,
+    pub expected_isk:   f64,
+}
+
+#[derive(
+    Clone, Copy, Debug, Hash,
+    PartialEq, Eq, PartialOrd, Ord,
+    Deserialize, Serialize, ToSchema,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarketSellKind {
+    /// Sell directly into existing buy orders
+    ///
+    BuyOrder,
+    /// List a sell order that undercuts the cheapest sell order
+    ///
+    SellOrder,
+}
@@ -20,6 +20,12 @@ pub enum Error {
     InsertPrivateOrders(sqlx::Error),
     #[error("insert private order history, error: '{0}'")]
     InsertPrivateHistoryOrders(sqlx::Error),
+    #[error("close private orders, error: '{0}'")]
+    ClosePrivateOrders(sqlx::Error),
+    #[error("fetch private orders for notification, error: '{0}'")]
+    FetchOrderNotification(sqlx::Error),
+    #[error("update notified status of private orders, error: '{0}'")]
+    UpdateOrderNotification(sqlx::Error),
 
     #[error("insert orders for region '{1}', error: '{0}'")]
     InsertRegionOrders(sqlx::Error, RegionId),
@@ -44,4 +50,6 @@ pub enum Error {
     WorkerLibError(#[from] starfoundry_lib_worker::Error),
     #[error(transparent)]
     EveGatewayError(#[from] starfoundry_lib_eve_gateway::Error),
+    #[error(transparent)]
+    NotificationError(#[from] starfoundry_lib_notification::Error),
 }
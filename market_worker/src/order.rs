@@ -6,6 +6,7 @@ mod region;
 
 mod insert_private;
 mod insert_public;
+mod notify;
 
 pub use self::character::*;
 pub use self::corporation::*;
@@ -14,3 +15,4 @@ pub use self::player_station::*;
 pub use self::region::*;
 pub use self::insert_private::*;
 pub use self::insert_public::*;
+pub use self::notify::*;
@@ -8,7 +8,7 @@ use starfoundry_lib_worker::Task;
 use crate::{SERVICE_NAME, WorkerMarketTask};
 use crate::error::{Error, Result};
 use crate::metric::WorkerMetric;
-use crate::order::insert_private_orders;
+use crate::order::{insert_private_orders, notify_orders};
 
 pub async fn character_orders(
     pool: &PgPool,
@@ -54,6 +54,15 @@ pub async fn character_orders(
         *additional_data.character_id,
         entries
     )
+    .await?;
+
+    notify_orders(
+        pool,
+        task,
+        &client,
+        *additional_data.character_id,
+        &[*additional_data.character_id, *additional_data.corporation_id],
+    )
     .await
 }
 
@@ -8,7 +8,7 @@ use starfoundry_lib_worker::Task;
 use crate::{SERVICE_NAME, WorkerMarketTask};
 use crate::error::{Error, Result};
 use crate::metric::WorkerMetric;
-use crate::order::insert_private_orders;
+use crate::order::{insert_private_orders, notify_orders};
 
 pub async fn corporation_orders(
     pool: &PgPool,
@@ -48,12 +48,25 @@ pub async fn corporation_orders(
         }
     };
 
-    match insert_private_orders(
+    if let Err(e) = insert_private_orders(
             pool,
             task,
             *additional_data.corporation_id,
             entries
         )
+        .await {
+
+        task.append_error(e.to_string());
+        return Err(e);
+    }
+
+    match notify_orders(
+            pool,
+            task,
+            &client,
+            *additional_data.corporation_id,
+            &[*additional_data.character_id, *additional_data.corporation_id],
+        )
         .await {
             Ok(_)   => Ok(()),
             Err(e)  => {
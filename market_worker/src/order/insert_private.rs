@@ -13,10 +13,6 @@ pub async fn insert_private_orders(
     issuer_id:  i32,
     entries:    Vec<MarketOrder>,
 ) -> Result<()> {
-    if entries.is_empty() {
-        return Ok(());
-    }
-
     let mut entries = entries;
     entries.sort_by_key(|a| a.order_id);
     entries.dedup_by_key(|x| x.order_id);
@@ -124,6 +120,22 @@ pub async fn insert_private_orders(
         .await
         .map_err(Error::InsertPrivateHistoryOrders)?;
 
+    // orders that are no longer returned are either filled, cancelled or
+    // expired
+    sqlx::query!("
+            UPDATE market_private_order
+            SET closed_at = NOW()
+            WHERE issuer_id = $1
+            AND closed_at IS NULL
+            AND NOT (order_id = ANY($2))
+        ",
+            issuer_id,
+            &order_ids,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(Error::ClosePrivateOrders)?;
+
     transaction
         .commit()
         .await
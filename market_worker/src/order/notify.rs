@@ -0,0 +1,186 @@
+use sqlx::PgPool;
+use starfoundry_lib_eve_gateway::EveGatewayApiClientItem;
+use starfoundry_lib_market::MarketOwnOrderStatus;
+use starfoundry_lib_notification::{Discord, DiscordColor, DiscordEmbedding, DiscordField, send_discord};
+use starfoundry_lib_types::TypeId;
+use starfoundry_lib_worker::Task;
+use std::collections::HashMap;
+
+use crate::WorkerMarketTask;
+use crate::error::{Error, Result};
+use crate::metric::WorkerMetric;
+
+/// Max number of fields in a single discord embedding
+const MAX_FIELDS: usize = 25;
+
+/// Compares the orders of the issuer against the current order book and
+/// notifies the configured discord webhook about undercut, outbid, filled
+/// and expired orders.
+///
+/// Every status change is only notified once.
+/// Orders of all `issuer_ids` are not considered as competition.
+///
+pub async fn notify_orders(
+    pool:                   &PgPool,
+    task:                   &mut Task<WorkerMetric, WorkerMarketTask>,
+    eve_gateway_api_client: &impl EveGatewayApiClientItem,
+    issuer_id:              i32,
+    issuer_ids:             &[i32],
+) -> Result<()> {
+    let url = sqlx::query!("
+            SELECT url
+            FROM market_order_notification
+            WHERE issuer_id = $1
+        ",
+            issuer_id,
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(Error::FetchOrderNotification)?;
+
+    let url = if let Some(x) = url {
+        x.url
+    } else {
+        return Ok(());
+    };
+
+    let orders = sqlx::query!(r#"
+            SELECT
+                mpo.order_id,
+                mpo.structure_id,
+                mpo.type_id,
+                mpo.is_buy,
+                mpo.price,
+                mpo.expires,
+                mpo.closed_at,
+                mpo.notified_status AS "notified_status: MarketOwnOrderStatus",
+                (
+                    SELECT
+                        CASE
+                            WHEN mpo.is_buy
+                            THEN MAX(mol.price)
+                            ELSE MIN(mol.price)
+                        END
+                    FROM market_order_latest mol
+                    WHERE mol.type_id = mpo.type_id
+                    AND mol.structure_id = mpo.structure_id
+                    AND mol.is_buy = mpo.is_buy
+                    AND mol.order_id NOT IN (
+                        SELECT order_id
+                        FROM market_private_order
+                        WHERE issuer_id = ANY($2)
+                    )
+                ) AS competitor_price
+            FROM market_private_order mpo
+            WHERE mpo.issuer_id = $1
+            AND (mpo.closed_at IS NULL OR mpo.closed_at > NOW() - INTERVAL '1 day')
+            ORDER BY mpo.order_id
+        "#,
+            issuer_id,
+            issuer_ids,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(Error::FetchOrderNotification)?;
+
+    let mut changed_order_ids = Vec::new();
+    let mut changed_statuses  = Vec::new();
+    let mut alerts            = Vec::new();
+    for order in orders {
+        let status = MarketOwnOrderStatus::evaluate(
+            order.is_buy,
+            order.price,
+            order.expires,
+            order.closed_at.map(|x| x.naive_utc()),
+            order.competitor_price,
+        );
+
+        if order.notified_status == Some(status) {
+            continue;
+        }
+
+        changed_order_ids.push(order.order_id);
+        changed_statuses.push(status);
+        if status.is_alert() {
+            alerts.push((
+                status,
+                TypeId(order.type_id),
+                order.structure_id,
+                order.price,
+                order.competitor_price,
+            ));
+        }
+    }
+
+    if !alerts.is_empty() {
+        let type_ids = alerts
+            .iter()
+            .map(|(_, type_id, _, _, _)| *type_id)
+            .collect::<Vec<_>>();
+        let names = eve_gateway_api_client
+            .fetch_item_bulk(type_ids)
+            .await?
+            .into_iter()
+            .map(|x| (x.type_id, x.name))
+            .collect::<HashMap<_, _>>();
+
+        let mut messages = Vec::new();
+        for chunk in alerts.chunks(MAX_FIELDS) {
+            let mut embedding = DiscordEmbedding::new(
+                "Market orders",
+                "The following orders changed",
+                DiscordColor::Orange,
+            );
+
+            for (status, type_id, structure_id, price, competitor_price) in chunk {
+                let name = names
+                    .get(type_id)
+                    .cloned()
+                    .unwrap_or_else(|| type_id.to_string());
+                let value = match status {
+                    MarketOwnOrderStatus::Undercut |
+                    MarketOwnOrderStatus::Outbid   => format!(
+                        "{price:.2} ISK, competitor {:.2} ISK, market {structure_id}",
+                        competitor_price.unwrap_or_default(),
+                    ),
+                    MarketOwnOrderStatus::Active   |
+                    MarketOwnOrderStatus::Filled   |
+                    MarketOwnOrderStatus::Expired  => format!(
+                        "{price:.2} ISK, market {structure_id}",
+                    ),
+                };
+
+                embedding.add_field(DiscordField::new(
+                    format!("{status:?} - {name}"),
+                    value,
+                    false,
+                )?)?;
+            }
+
+            let mut message = Discord::new();
+            message.add_embedding(embedding);
+            messages.push(message);
+        }
+
+        send_discord(url, messages).await?;
+        task.append_log(format!("Notified {} orders", alerts.len()));
+    }
+
+    sqlx::query!("
+            UPDATE market_private_order
+            SET notified_status = data.status
+            FROM UNNEST(
+                $1::BIGINT[],
+                $2::MARKET_OWN_ORDER_STATUS[]
+            ) AS data(order_id, status)
+            WHERE market_private_order.order_id = data.order_id
+        ",
+            &changed_order_ids,
+            &changed_statuses as _,
+        )
+        .execute(pool)
+        .await
+        .map_err(Error::UpdateOrderNotification)?;
+
+    Ok(())
+}
@@ -1,13 +1,14 @@
 use serde::Serialize;
 use crate::error::{Error, Result};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Default, Serialize)]
 pub struct Discord {
     pub content:    String,
     pub embeds:     Vec<DiscordEmbedding>,
 }
 
 impl Discord {
+    #[must_use]
     pub fn new() -> Self {
         Self {
             content:    "".into(),
@@ -32,6 +33,7 @@ pub struct DiscordEmbedding {
 }
 
 impl DiscordEmbedding {
+    #[must_use]
     pub fn new<S: Into<String>>(
         title:       S,
         description: S,
@@ -123,6 +125,7 @@ pub enum DiscordColor {
 }
 
 impl DiscordColor {
+    #[must_use]
     pub fn as_code(&self) -> i32 {
         match self {
             Self::Default           => 0,
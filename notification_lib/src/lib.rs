@@ -1,9 +1,9 @@
-use crate::discord::Discord;
-use crate::error::{Error, Result};
-
 mod discord;
 mod error;
 
+pub use self::discord::*;
+pub use self::error::{Error, Result};
+
 pub async fn send_discord(
     url:      String,
     messages: Vec<Discord>,
@@ -51,7 +51,7 @@ pub async fn send_json(
 
 #[cfg(test)]
 mod tests {
-    use crate::discord::{Discord, DiscordColor, DiscordEmbedding, DiscordField};
+    use crate::{Discord, DiscordColor, DiscordEmbedding, DiscordField};
     use crate::send_discord;
 
     #[tokio::test]
@@ -36,7 +36,7 @@ export function CreateBuildOrderModal({
 
     const createJobOrderMutation = useMutation({
         mutationFn: async () => {
-            return await createJobAssignment(jobs);
+            return await createJobAssignment({ jobs });
         },
         onSuccess: (data) => {
             setHasError(false);
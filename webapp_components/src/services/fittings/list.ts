@@ -0,0 +1,26 @@
+import { axiosClient } from "@internal/services/client";
+import type { EveFitItem } from "@internal/services/fittings/create";
+
+export const listFittings = async (
+    characterId:    number,
+): Promise<EveFitting[]> => (await axiosClient())
+    .get(
+        `/api/eve/characters/${characterId}/fittings`,
+    )
+    .then(x => x.data || []);
+
+export type EveFitting = {
+    fitting_id:     number;
+    name:           string;
+    description:    string;
+    items:          EveFitItem[];
+    ship_type_id:   number;
+}
+
+export type FittingImport = {
+    fit?:           string;
+    fitting_id?:    number;
+    multiplier?:    number;
+    buy?:           number[];
+    build?:         number[];
+}
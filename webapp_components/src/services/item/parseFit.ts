@@ -0,0 +1,33 @@
+import { axiosClient } from "@internal/services/client";
+import type { Item } from "@internal/services/item/model";
+import type { GenericAbortSignal } from "axios";
+
+export const parseFit = async (
+    fit:     string,
+    signal?: GenericAbortSignal,
+): Promise<ParsedFit[]> => (await axiosClient())
+    .post(
+        `/api/eve/items/parse/fit`,
+        fit,
+        {
+            headers: {
+                'Content-Type': 'application/json'
+            },
+            signal,
+        },
+    )
+    .then(x => x.data);
+
+export type ParsedFit = {
+    name?:      string;
+    ship?:      ParsedFitItem;
+    items:      ParsedFitItem[];
+    invalid:    string[];
+}
+
+export type ParsedFitItem = {
+    item_name:  string;
+    quantity:   number;
+    type_id:    number;
+    raw:        Item;
+}
@@ -0,0 +1,20 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "../utils"
+
+export const claimJobAssignment = async (
+    assignmentId: Uuid,
+    jobId: Uuid,
+): Promise<void> => (await axiosClient())
+    .put(
+        `/api/job-assignments/${assignmentId}/${jobId}/claim`,
+    )
+    .then(x => x.data);
+
+export const releaseJobAssignment = async (
+    assignmentId: Uuid,
+    jobId: Uuid,
+): Promise<void> => (await axiosClient())
+    .delete(
+        `/api/job-assignments/${assignmentId}/${jobId}/claim`,
+    )
+    .then(x => x.data);
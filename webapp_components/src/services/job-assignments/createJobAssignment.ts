@@ -2,7 +2,7 @@ import { axiosClient } from "@internal/services/client";
 import type { Uuid } from "../utils"
 
 export const createJobAssignment = async (
-    data: ProjectJobMinimal[],
+    data: CreateJobAssignment,
 ): Promise<ProjectJobOrderResponse> => (await axiosClient())
     .post(
         '/api/job-assignments',
@@ -10,9 +10,15 @@ export const createJobAssignment = async (
     )
     .then(x => x.data);
 
+export type CreateJobAssignment = {
+    expires_at?: string,
+    jobs:        ProjectJobMinimal[],
+}
+
 export type ProjectJobMinimal = {
     project_id: Uuid,
     job_id:     Uuid,
+    payment?:   number,
 }
 
 export type ProjectJobOrderResponse = {
@@ -0,0 +1,44 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { GenericAbortSignal } from "axios";
+import type { Uuid } from "@internal/services/utils";
+
+export const LIST_CREATED_JOB_ASSIGNMENTS = 'listCreatedJobAssignments';
+
+export const listCreatedJobAssignments = async (
+    signal?: GenericAbortSignal,
+): Promise<JobAssignment[]> => (await axiosClient())
+    .get(
+        '/api/job-assignments',
+        {
+            signal,
+        }
+    )
+    .then(x => {
+        if (x.status === 204) {
+            return [];
+        }
+
+        return x.data
+    });
+
+export const useListCreatedJobAssignments = () => {
+    return useQuery({
+        queryKey: [LIST_CREATED_JOB_ASSIGNMENTS],
+        queryFn: async ({
+            signal
+        }: AbortSignal) => listCreatedJobAssignments(signal),
+    });
+}
+
+export type JobAssignment = {
+    id:             Uuid,
+    expires_at?:    string,
+    revoked_at?:    string,
+    created_at:     string,
+    jobs:           number,
+    claimed:        number,
+    started:        number,
+    payout_pending: number,
+    payout_paid:    number,
+}
@@ -1,6 +1,5 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
 import { useQuery } from "@tanstack/react-query";
-import axios from "axios";
-import type { AbortSignal } from "@internal/services/client";
 import type { GenericAbortSignal } from "axios";
 import type { Item } from "@internal/services/item/model";
 import type { Uuid } from "@internal/services/utils";
@@ -10,7 +9,7 @@ export const LIST_JOB_ASSIGNMENT = 'listJobAssignment';
 export const listJobAssignments = async (
     assignment_id:  Uuid,
     signal?:        GenericAbortSignal,
-): Promise<ProjectJobAssignmentGroup[]> => axios
+): Promise<ProjectJobAssignmentGroup[]> => (await axiosClient())
     .get(
         `/api/job-assignments/${assignment_id}`,
         {
@@ -51,4 +50,11 @@ export type ProjectJobAssignment = {
     started:        boolean,
     item:           Item,
     runs:           number,
+    claimed_by?:    number,
+    claimed_by_me:  boolean,
+    payment?:       number,
+    payout_status:  JobAssignmentPayoutStatus,
+    paid_at?:       string,
 }
+
+export type JobAssignmentPayoutStatus = 'OPEN' | 'PENDING' | 'PAID';
@@ -0,0 +1,11 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "../utils"
+
+export const payoutJobAssignment = async (
+    assignmentId: Uuid,
+    jobId: Uuid,
+): Promise<void> => (await axiosClient())
+    .put(
+        `/api/job-assignments/${assignmentId}/${jobId}/payout`,
+    )
+    .then(x => x.data);
@@ -0,0 +1,10 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "../utils"
+
+export const revokeJobAssignment = async (
+    assignmentId: Uuid,
+): Promise<void> => (await axiosClient())
+    .delete(
+        `/api/job-assignments/${assignmentId}`,
+    )
+    .then(x => x.data);
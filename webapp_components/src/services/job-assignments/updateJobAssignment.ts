@@ -1,10 +1,10 @@
-import axios from "axios";
+import { axiosClient } from "@internal/services/client";
 import type { Uuid } from "../utils"
 
 export const updateJobAssignment = async (
     assignmentId: Uuid,
     jobId: Uuid,
-): Promise<void> => axios
+): Promise<void> => (await axiosClient())
     .put(
         `/api/job-assignments/${assignmentId}/${jobId}`,
         {
@@ -0,0 +1,59 @@
+import { axiosClient } from "@internal/services/client";
+import type { Item } from "../item/model";
+import type { Uuid } from "@internal/services/utils";
+import type { SolutionSummary } from "./listSolutions";
+
+export const compareSolutions = async (
+    projectGroupUuid: Uuid,
+    base:             Uuid,
+    other:            Uuid,
+): Promise<SolutionComparison> => (await axiosClient())
+    .get(
+        `/api/project-groups/${projectGroupUuid}/solutions/compare`,
+        {
+            params: {
+                base,
+                other,
+            },
+        }
+    )
+    .then(x => x.data);
+
+export type SolutionComparison = {
+    base:       SolutionSummary,
+    other:      SolutionSummary,
+    difference: SolutionDifference,
+    materials:  SolutionMaterialDifference[],
+    jobs:       SolutionJobDifference[],
+}
+
+export type SolutionDifference = {
+    material:    number,
+    build_tax:   number,
+    transfer:    number,
+    total:       number,
+    jobs:        number,
+    time:        number,
+    longest_job: number,
+}
+
+export type SolutionMaterialDifference = {
+    item:           Item,
+    additional:     boolean,
+    base_quantity:  number,
+    other_quantity: number,
+    base_cost:      number,
+    other_cost:     number,
+}
+
+export type SolutionJobDifference = {
+    item:            Item,
+    base_runs:       number,
+    other_runs:      number,
+    base_jobs:       number,
+    other_jobs:      number,
+    base_build_tax:  number,
+    other_build_tax: number,
+    base_time:       number,
+    other_time:      number,
+}
@@ -0,0 +1,44 @@
+import { axiosClient } from "@internal/services/client";
+import type { ExportStructure } from "@internal/services/projects/export";
+import type { Uuid } from "@internal/services/utils";
+
+export const exportProjectGroup = async (
+    projectGroupUuid: Uuid,
+): Promise<ProjectGroupExport> => (await axiosClient())
+    .get(
+        `/api/project-groups/${projectGroupUuid}/export`,
+    )
+    .then(x => x.data);
+
+export type ProjectGroupExport = {
+    version:              number;
+    exported_at:          string;
+
+    name:                 string;
+    description?:         string;
+
+    blacklist:            number[];
+    blueprint_overwrites: ProjectGroupExportBlueprintOverwrite[];
+    job_splitting:        ProjectGroupExportJobSplitting[];
+    freight_rate?:        number;
+    markets:              Uuid[];
+    industry_hubs:        ProjectGroupExportIndustryHub[];
+
+    structures:           ExportStructure[];
+}
+
+export type ProjectGroupExportBlueprintOverwrite = {
+    type_id:             number;
+    material_efficiency: number;
+}
+
+export type ProjectGroupExportJobSplitting = {
+    type_id:  number;
+    max_runs: number;
+}
+
+export type ProjectGroupExportIndustryHub = {
+    name:         string;
+    description?: string;
+    structures:   Uuid[];
+}
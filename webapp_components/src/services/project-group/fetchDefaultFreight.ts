@@ -0,0 +1,47 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { Uuid } from "@internal/services/utils";
+import type { GenericAbortSignal } from "axios";
+
+export const FETCH_PROJECT_GROUP_DEFAULT_FREIGHT = 'fetchProjectGroupDefaultFreight';
+
+export const fetchProjectGroupDefaultFreight = async (
+    projectGroupUuid: Uuid,
+    signal?:          GenericAbortSignal,
+): Promise<Freight> => (await axiosClient())
+    .get(
+        `/api/project-groups/${projectGroupUuid}/defaults/freight`,
+        {
+            signal,
+        }
+    )
+    .then(x => x.data);
+
+export type Freight = {
+    // ISK per m3
+    rate: number,
+}
+
+// For general use
+export const useFetchProjectGroupDefaultFreight = (
+    projectGroupUuid:   Uuid,
+    options:            AdditionalProjectGroupDefaultFreightOptions = {},
+) => {
+    return useQuery(fetchProjectGroupDefaultFreightQuery(projectGroupUuid, options));
+}
+
+// For pre-fetching
+export const fetchProjectGroupDefaultFreightQuery = (
+    projectGroupUuid:   Uuid,
+    options:            AdditionalProjectGroupDefaultFreightOptions,
+) => ({
+    queryKey: [FETCH_PROJECT_GROUP_DEFAULT_FREIGHT, projectGroupUuid],
+    queryFn: async ({
+        signal
+    }: AbortSignal) => fetchProjectGroupDefaultFreight(projectGroupUuid, signal),
+    ...options,
+});
+
+export type AdditionalProjectGroupDefaultFreightOptions = {
+    enabled?: boolean,
+}
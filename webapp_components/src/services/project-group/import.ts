@@ -0,0 +1,20 @@
+import { axiosClient } from "@internal/services/client";
+import type { CreateProjectGroupResponse } from "@internal/services/project-group/create";
+import type { ProjectGroupExport } from "@internal/services/project-group/export";
+import type { Uuid } from "@internal/services/utils";
+
+export const importProjectGroup = async (
+    data: ImportProjectGroup,
+): Promise<CreateProjectGroupResponse> => (await axiosClient())
+    .post(
+        '/api/project-groups/import',
+        data,
+    )
+    .then(x => x.data);
+
+export type ImportProjectGroup = {
+    // maps exported structures to own structures, structures that are not
+    // mapped are matched by their in-game id
+    structure_mapping?: Record<Uuid, Uuid>;
+    project_group:      ProjectGroupExport;
+}
@@ -29,6 +29,7 @@ export type ProjectGroupMember = {
 
     is_owner:       boolean,
     permissions:    ProjectGroupPermission[],
+    role_id?:       Uuid,
 }
 
 // For general use
@@ -0,0 +1,76 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { Item } from "../item/model";
+import type { Uuid } from "@internal/services/utils";
+import type { GenericAbortSignal } from "axios";
+
+export const LIST_PROJECT_GROUP_SOLUTIONS = 'listProjectGroupSolutions';
+
+export const listProjectGroupSolutions = async (
+    projectGroupUuid: Uuid,
+    signal?:          GenericAbortSignal,
+): Promise<SolutionSummary[]> => (await axiosClient())
+    .get(
+        `/api/project-groups/${projectGroupUuid}/solutions`,
+        {
+            signal,
+        }
+    )
+    .then(x => {
+        if (x.status === 204) {
+            return [];
+        } else {
+            return x.data;
+        }
+    });
+
+export type SolutionSummary = {
+    id:              Uuid,
+    industry_hub_id: Uuid,
+    industry_hubs:   Uuid[],
+    rerun_of?:       Uuid,
+    projects:        Uuid[],
+    products:        SolutionProduct[],
+    cost:            SolutionCost,
+    jobs:            number,
+    time:            number,
+    longest_job:     number,
+    created_at:      string,
+}
+
+export type SolutionProduct = {
+    item:                Item,
+    quantity:            number,
+    material_efficiency: number,
+}
+
+export type SolutionCost = {
+    material:  number,
+    build_tax: number,
+    transfer:  number,
+    total:     number,
+}
+
+// For general use
+export const useListProjectGroupSolutions = (
+    projectGroupUuid:   Uuid,
+    options:            AdditionalProjectGroupSolutionsOptions = {},
+) => {
+    return useQuery(listProjectGroupSolutionsQuery(projectGroupUuid, options));
+}
+
+// For pre-fetching
+export const listProjectGroupSolutionsQuery = (
+    projectGroupUuid:   Uuid,
+    options:            AdditionalProjectGroupSolutionsOptions,
+) => ({
+    queryKey: [LIST_PROJECT_GROUP_SOLUTIONS, projectGroupUuid],
+    queryFn: async ({
+        signal
+    }: AbortSignal) => listProjectGroupSolutions(projectGroupUuid, signal),
+    ...options,
+});
+
+export type AdditionalProjectGroupSolutionsOptions = {
+    enabled?: boolean,
+}
@@ -0,0 +1,18 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+import type { GenerateSolutionResponse } from "../projects/generateSolution";
+
+export const rerunSolution = async (
+    projectGroupUuid: Uuid,
+    solutionUuid:     Uuid,
+): Promise<GenerateSolutionResponse | null> => (await axiosClient())
+    .post(
+        `/api/project-groups/${projectGroupUuid}/solutions/${solutionUuid}/rerun`,
+    )
+    .then(x => {
+        if (x.status === 204) {
+            return null;
+        } else {
+            return x.data;
+        }
+    });
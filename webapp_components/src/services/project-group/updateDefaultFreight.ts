@@ -0,0 +1,12 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+import type { Freight } from "./fetchDefaultFreight";
+
+export const updateDefaultFreight = async (
+    projectGroupUuid: Uuid,
+    freight:          Freight,
+): Promise<void> => (await axiosClient())
+    .put(
+        `/api/project-groups/${projectGroupUuid}/defaults/freight`,
+        freight,
+    );
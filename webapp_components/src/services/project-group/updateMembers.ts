@@ -12,4 +12,5 @@ export const updateMembers = async (
 
 export type UpdateMemberRequest = {
     character_id: number;
+    role_id?:     Uuid;
 }
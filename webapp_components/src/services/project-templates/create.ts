@@ -0,0 +1,20 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+import type { UpdateProjectTemplate } from "@internal/services/project-templates/update";
+
+export const createProjectTemplate = async (
+    data: CreateProjectTemplate,
+): Promise<CreateProjectTemplateResponse> => (await axiosClient())
+    .post(
+        '/api/project-templates',
+        data,
+    )
+    .then(x => x.data);
+
+export type CreateProjectTemplate = UpdateProjectTemplate & {
+    project_group_id: Uuid;
+}
+
+export type CreateProjectTemplateResponse = {
+    id: Uuid;
+}
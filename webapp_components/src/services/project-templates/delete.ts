@@ -0,0 +1,10 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+
+export const deleteProjectTemplate = async (
+    templateId: Uuid,
+): Promise<void> => (await axiosClient())
+    .delete(
+        `/api/project-templates/${templateId}`,
+    )
+    .then(x => x.data);
@@ -0,0 +1,20 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+
+export const instantiateProjectTemplate = async (
+    templateId: Uuid,
+    data:       InstantiateProjectTemplate,
+): Promise<InstantiateProjectTemplateResponse> => (await axiosClient())
+    .post(
+        `/api/project-templates/${templateId}/instantiate`,
+        data,
+    )
+    .then(x => x.data);
+
+export type InstantiateProjectTemplate = {
+    multiplier?: number;
+}
+
+export type InstantiateProjectTemplateResponse = {
+    id: Uuid;
+}
@@ -0,0 +1,68 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { GenericAbortSignal } from "axios";
+import type { Item } from "@internal/services/item/model";
+import type { Uuid } from "@internal/services/utils";
+
+export const LIST_PROJECT_TEMPLATES = 'listProjectTemplates';
+
+export const listProjectTemplates = async (
+    filter:  ProjectTemplateFilter,
+    signal?: GenericAbortSignal,
+): Promise<ProjectTemplate[]> => (await axiosClient())
+    .get(
+        '/api/project-templates',
+        {
+            params: filter,
+            signal,
+        }
+    )
+    .then(x => {
+        if (x.status === 204) {
+            return [];
+        }
+
+        return x.data;
+    });
+
+// For general use
+export const useListProjectTemplates = (
+    filter: ProjectTemplateFilter,
+) => {
+    return useQuery(listProjectTemplatesQuery(filter));
+}
+
+// For pre-fetching
+export const listProjectTemplatesQuery = (
+    filter: ProjectTemplateFilter,
+) => ({
+    queryKey: [LIST_PROJECT_TEMPLATES, filter],
+    queryFn: async ({
+        signal
+    }: AbortSignal) => listProjectTemplates(filter, signal),
+    // ms * s * m
+    staleTime: 1000 * 60 * 5,
+});
+
+export type ProjectTemplateFilter = {
+    project_group_id?: Uuid;
+}
+
+export type ProjectTemplate = {
+    id:                 Uuid;
+    project_group_id:   Uuid;
+    name:               string;
+    orderer:            string;
+    notes?:             string;
+    products:           ProjectTemplateProduct[];
+    tags:               Uuid[];
+    interval_days?:     number;
+    next_run_at?:       string;
+    failed_attempts:    number;
+    last_error?:        string;
+}
+
+export type ProjectTemplateProduct = {
+    item:       Item;
+    quantity:   number;
+}
@@ -0,0 +1,26 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+
+export const updateProjectTemplate = async (
+    templateId: Uuid,
+    data:       UpdateProjectTemplate,
+): Promise<void> => (await axiosClient())
+    .put(
+        `/api/project-templates/${templateId}`,
+        data,
+    )
+    .then(x => x.data);
+
+export type UpdateProjectTemplate = {
+    name:           string;
+    orderer:        string;
+    notes?:         string;
+    products:       ProjectTemplateProductEntry[];
+    tags?:          Uuid[];
+    interval_days?: number;
+}
+
+export type ProjectTemplateProductEntry = {
+    type_id:    number;
+    quantity:   number;
+}
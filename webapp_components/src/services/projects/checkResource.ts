@@ -19,6 +19,7 @@ export type CheckMaterialsRequest = {
     job_ids:        Uuid[];
     materials?:     Material[];
     materials_str?: string;
+    location_id?:   number;
 }
 
 export type Material = {
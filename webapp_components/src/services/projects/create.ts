@@ -1,4 +1,5 @@
 import { axiosClient } from "@internal/services/client";
+import type { FittingImport } from "@internal/services/fittings/list";
 import type { Uuid } from "@internal/services/utils";
 
 export const createProject = async (
@@ -15,6 +16,10 @@ export type CreateProject = {
     project_group_id: Uuid;
     orderer:          string;
     name:             string;
+    solution_id?:     Uuid;
+    pre_products?:    string;
+    pre_additional?:  string;
+    fittings?:        FittingImport[];
 }
 
 export type CreateProjectResponse = {
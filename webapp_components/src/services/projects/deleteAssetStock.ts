@@ -0,0 +1,10 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+
+export const deleteAssetStock = async (
+    projectId: Uuid,
+): Promise<void> => (await axiosClient())
+    .delete(
+        `/api/projects/${projectId}/asset-stock`,
+    )
+    .then(x => x.data);
@@ -0,0 +1,36 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+import type { Item } from "@internal/services/item/model";
+
+export const deliveryManifest = async (
+    projectId: Uuid,
+    request:   DeliveryManifestRequest,
+): Promise<DeliveryManifest> => (await axiosClient())
+    .post(
+        `/api/projects/${projectId}/delivery`,
+        request,
+    )
+    .then(x => x.data);
+
+export type DeliveryManifestRequest = {
+    items?: DeliveryManifestEntry[];
+}
+
+export type DeliveryManifestEntry = {
+    type_id:  number;
+    quantity: number;
+}
+
+export type DeliveryManifest = {
+    items:      DeliveryManifestItem[];
+    volume:     number;
+    collateral: number;
+    contract:   string;
+}
+
+export type DeliveryManifestItem = {
+    item:     Item;
+    quantity: number;
+    volume:   number;
+    price:    number;
+}
@@ -0,0 +1,75 @@
+import { axiosClient } from "@internal/services/client";
+import type { ProjectJobStatus } from "@internal/services/projects/listJobs";
+import type { ProjectStatus } from "@internal/services/projects/list";
+import type { Uuid } from "@internal/services/utils";
+
+export const exportProject = async (
+    projectUuid: Uuid,
+): Promise<ProjectExport> => (await axiosClient())
+    .get(
+        `/api/projects/${projectUuid}/export`,
+    )
+    .then(x => x.data);
+
+export type ProjectExport = {
+    version:           number;
+    exported_at:       string;
+
+    name:              string;
+    orderer:           string;
+    status:            ProjectStatus;
+    sell_price?:       number;
+    notes?:            string;
+
+    products:          ProjectExportProduct[];
+    jobs:              ProjectExportJob[];
+    market:            ProjectExportMarket[];
+    misc:              ProjectExportMisc[];
+    excess:            ProjectExportStock[];
+    stock:             ProjectExportStock[];
+    market_structures: Uuid[];
+
+    structures:        ExportStructure[];
+}
+
+export type ProjectExportProduct = {
+    type_id:             number;
+    quantity:            number;
+    material_efficiency: number;
+}
+
+export type ProjectExportJob = {
+    type_id:      number;
+    runs:         number;
+    status:       ProjectJobStatus;
+    cost?:        number;
+    structure_id: Uuid;
+}
+
+export type ProjectExportMarket = {
+    type_id:  number;
+    quantity: number;
+    cost?:    number;
+    source?:  string;
+}
+
+export type ProjectExportMisc = {
+    item:         string;
+    cost:         number;
+    quantity?:    number;
+    description?: string;
+}
+
+export type ProjectExportStock = {
+    type_id:  number;
+    quantity: number;
+    cost?:    number;
+}
+
+export type ExportStructure = {
+    id:           Uuid;
+    structure_id: number;
+    name:         string;
+    system_id:    number;
+    type_id:      number;
+}
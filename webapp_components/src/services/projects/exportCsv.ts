@@ -0,0 +1,25 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+
+export const exportProjectJobsCsv = async (
+    projectUuid: Uuid,
+): Promise<Blob> => exportCsv(`/api/projects/${projectUuid}/jobs/csv`);
+
+export const exportProjectMarketCsv = async (
+    projectUuid: Uuid,
+): Promise<Blob> => exportCsv(`/api/projects/${projectUuid}/market/csv`);
+
+export const exportProjectCostCsv = async (
+    projectUuid: Uuid,
+): Promise<Blob> => exportCsv(`/api/projects/${projectUuid}/cost/csv`);
+
+const exportCsv = async (
+    url: string,
+): Promise<Blob> => (await axiosClient())
+    .get(
+        url,
+        {
+            responseType: 'blob',
+        }
+    )
+    .then(x => x.data);
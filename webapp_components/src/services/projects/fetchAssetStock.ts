@@ -0,0 +1,62 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { GenericAbortSignal } from "axios";
+import type { Item } from "@internal/services/item/model";
+import type { Uuid } from "@internal/services/utils";
+
+export const FETCH_PROJECT_ASSET_STOCK = 'fetchProjectAssetStock';
+
+export const fetchProjectAssetStock = async (
+    projectId: Uuid,
+    signal?:   GenericAbortSignal,
+): Promise<ProjectAssetStock | undefined> => (await axiosClient())
+    .get(
+        `/api/projects/${projectId}/asset-stock`,
+        {
+            signal,
+        }
+    )
+    .then(x => x.status === 204 ? undefined : x.data);
+
+// For general use
+export const useFetchProjectAssetStock = (
+    projectId: Uuid,
+) => {
+    return useQuery(fetchProjectAssetStockQuery(projectId));
+}
+
+// For pre-fetching
+export const fetchProjectAssetStockQuery = (
+    projectId: Uuid,
+) => ({
+    queryKey: [FETCH_PROJECT_ASSET_STOCK, projectId],
+    queryFn: async ({
+        signal
+    }: AbortSignal) => fetchProjectAssetStock(projectId, signal),
+    // ms * s * m
+    staleTime: 1000 * 60 * 5,
+});
+
+export type AssetStockFilter = {
+    location_ids:       number[];
+    location_flags?:    string[];
+    container_names?:   string[];
+    include_assembled?: boolean;
+}
+
+export type ProjectAssetStock = {
+    filter:         AssetStockFilter;
+    refreshed_at?:  string;
+    materials:      ProjectAssetStockMaterial[];
+}
+
+export type ProjectAssetStockMaterial = {
+    item:       Item;
+    needed:     number;
+    covered:    number;
+    reserved:   number;
+    missing:    number;
+    status:     ProjectAssetStockStatus;
+}
+
+export type ProjectAssetStockStatus = 'COVERED' | 'RESERVED' | 'MISSING';
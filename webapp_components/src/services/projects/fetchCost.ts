@@ -44,4 +44,5 @@ export type ProjectCost = {
     misc_cost:      number;
     excess_cost:    number;
     stock_cost:     number;
+    upkeep_cost:    number;
 }
@@ -0,0 +1,62 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { GenericAbortSignal } from "axios";
+import type { TypeId, Uuid } from "@internal/services/utils";
+
+export const FETCH_PROJECT_WALLET = 'fetchProjectWallet';
+
+export const fetchProjectWallet = async (
+    projectId: Uuid,
+    signal?:   GenericAbortSignal,
+): Promise<ProjectWallet | undefined> => (await axiosClient())
+    .get(
+        `/api/projects/${projectId}/wallet`,
+        {
+            signal,
+        }
+    )
+    .then(x => x.status === 204 ? undefined : x.data);
+
+// For general use
+export const useFetchProjectWallet = (
+    projectId: Uuid,
+) => {
+    return useQuery(fetchProjectWalletQuery(projectId));
+}
+
+// For pre-fetching
+export const fetchProjectWalletQuery = (
+    projectId: Uuid,
+) => ({
+    queryKey: [FETCH_PROJECT_WALLET, projectId],
+    queryFn: async ({
+        signal
+    }: AbortSignal) => fetchProjectWallet(projectId, signal),
+    // ms * s * m
+    staleTime: 1000 * 60 * 5,
+});
+
+export type ProjectWallet = {
+    reconciled_at?: string;
+    matches:        ProjectWalletMatch[];
+    unmatched:      ProjectWalletUnmatched[];
+}
+
+export type ProjectWalletMatch = {
+    kind:           ProjectWalletKind;
+    entry_id:       Uuid;
+    reference_id:   number;
+    quantity:       number;
+    cost:           number;
+}
+
+export type ProjectWalletUnmatched = {
+    kind:           ProjectWalletKind;
+    reference_id:   number;
+    type_id?:       TypeId;
+    quantity?:      number;
+    amount:         number;
+    date:           string;
+}
+
+export type ProjectWalletKind = 'MARKET' | 'JOB';
@@ -3,6 +3,8 @@ import type { Item } from "@internal/services/item/model";
 import type { Structure } from "@internal/services/structure/list";
 import type { Uuid } from "../utils";
 import type { IndustryHub } from "../industry-hub/list";
+import type { AssetStockFilter } from "./fetchAssetStock";
+import type { FittingImport } from "@internal/services/fittings/list";
 
 export const generateSolution = async (
     config: GenerateSolutionRequest,
@@ -19,8 +21,17 @@ export type GenerateSolutionRequest = {
     products_str?:              string;
     additional_products?:       ProjectProducts[];
     additional_products_str?:   string;
+    fittings?:                  FittingImport[];
     stocks_str?:                string;
+    stocks_from_assets?:        AssetStockFilter;
+    stock_location_id?:         number;
+    project_id?:                Uuid;
     calculate_market_cost?:     boolean;
+    combine_industry_hubs?:     boolean;
+    freight_rate?:              number;
+    industry_hubs?:             Uuid[];
+    expand_planetary?:          boolean;
+    customs_office_tax?:        number;
 
     blacklist?:             number[];
     blueprint_overwrite?:   TmpBlueprintOverwrite[];
@@ -49,6 +60,29 @@ export type GenerateSolutionResponse = {
     industry_hub:   IndustryHub;
     material:       SolutionMaterial[];
     manufacturing:  SolutionManufacturing[];
+    industry_hubs:  IndustryHub[];
+    transfers:      SolutionTransfer[];
+    planetary:      SolutionPlanetary[];
+}
+
+export type SolutionPlanetary = {
+    item:            Item;
+    tier:            number;
+    needed:          number;
+    quantity:        number;
+    cycles:          number;
+    cycle_time:      number;
+    planet_type_ids: number[];
+    customs_tax:     number;
+}
+
+export type SolutionTransfer = {
+    item:        Item;
+    quantity:    number;
+    volume:      number;
+    cost:        number;
+    source:      Uuid;
+    destination: Uuid;
 }
 
 export type SolutionMaterial = {
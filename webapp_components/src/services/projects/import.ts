@@ -0,0 +1,21 @@
+import { axiosClient } from "@internal/services/client";
+import type { CreateProjectResponse } from "@internal/services/projects/create";
+import type { ProjectExport } from "@internal/services/projects/export";
+import type { Uuid } from "@internal/services/utils";
+
+export const importProject = async (
+    data: ImportProject,
+): Promise<CreateProjectResponse> => (await axiosClient())
+    .post(
+        '/api/projects/import',
+        data,
+    )
+    .then(x => x.data);
+
+export type ImportProject = {
+    project_group_id:   Uuid;
+    // maps exported structures to own structures, structures that are not
+    // mapped are matched by their in-game id
+    structure_mapping?: Record<Uuid, Uuid>;
+    project:            ProjectExport;
+}
@@ -40,6 +40,7 @@ export type ProjectMarketBuyQuery = {
 
     gas_decompression?: string;
     mineral_compression?: string;
+    contracts?: boolean;
 }
 
 export type ProjectMarketBuyEntry = {
@@ -60,4 +61,5 @@ export type MarketBulkResponse = {
     price:              number;
     insufficient_data:  boolean;
     last_fetch:         string;
+    contract_id?:       number;
 }
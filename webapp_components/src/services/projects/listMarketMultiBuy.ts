@@ -0,0 +1,45 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { GenericAbortSignal } from "axios";
+import type { Uuid } from "@internal/services/utils";
+import type { ProjectMarketBuyQuery } from "@internal/services/projects/listMarketBuy";
+
+export const LIST_PROJECT_MARKET_MULTIBUY = 'listProjectMarketMultiBuy';
+
+export const listProjectMarketMultiBuy = async (
+    projectId: Uuid,
+    config:    ProjectMarketMultiBuyQuery,
+    signal?:   GenericAbortSignal,
+): Promise<ProjectMultiBuy[]> => (await axiosClient())
+    .post(
+        `/api/projects/${projectId}/market/buy/multibuy`,
+        config,
+        {
+            signal,
+        }
+    )
+    .then(x => x.data);
+
+export const useListProjectMarketMultiBuy = (
+    projectId: Uuid,
+    config:    ProjectMarketMultiBuyQuery,
+) => {
+    return useQuery({
+        queryKey: [LIST_PROJECT_MARKET_MULTIBUY, projectId, config],
+        queryFn: async ({
+            signal
+        }: AbortSignal) => listProjectMarketMultiBuy(projectId, config, signal),
+        initialData: [],
+    })
+}
+
+export type ProjectMarketMultiBuyQuery = ProjectMarketBuyQuery & {
+    cargo_capacity?: number;
+}
+
+export type ProjectMultiBuy = {
+    source:   number;
+    volume:   number;
+    cost:     number;
+    multibuy: string;
+}
@@ -0,0 +1,71 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { GenericAbortSignal } from "axios";
+import type { Uuid } from "@internal/services/utils";
+
+export const LIST_PROJECT_MARKET_SELL = 'listProjectMarketSell';
+
+export const listProjectMarketSell = async (
+    projectId: Uuid,
+    config:    ProjectMarketSellQuery,
+    signal?:   GenericAbortSignal,
+): Promise<MarketSellResponse | undefined> => (await axiosClient())
+    .post(
+        `/api/projects/${projectId}/market/sell`,
+        config,
+        {
+            signal,
+        }
+    )
+    .then(x => x.status === 204 ? undefined : x.data);
+
+export const useListProjectMarketSell = (
+    projectId: Uuid,
+    config:    ProjectMarketSellQuery,
+) => {
+    return useQuery({
+        queryKey: [LIST_PROJECT_MARKET_SELL, projectId, config],
+        queryFn: async ({
+            signal
+        }: AbortSignal) => listProjectMarketSell(projectId, config, signal),
+    })
+}
+
+export type ProjectMarketSellQuery = {
+    structure_ids: number[];
+
+    fees?: MarketSellFees;
+}
+
+export type MarketSellFees = {
+    accounting?:            number;
+    broker_relations?:      number;
+    faction_standing?:      number;
+    corporation_standing?:  number;
+    broker_fees?:           {
+        structure_id:   number;
+        broker_fee:     number;
+    }[];
+}
+
+export type MarketSellResponse = {
+    entries:    MarketSellEntry[];
+    markets:    {
+        structure_id:   number;
+        expected_isk:   number;
+    }[];
+    unsold:     {
+        type_id:    number;
+        quantity:   number;
+    }[];
+}
+
+export type MarketSellEntry = {
+    type_id:        number;
+    structure_id:   number;
+    kind:           'BUY_ORDER' | 'SELL_ORDER';
+    quantity:       number;
+    price:          number;
+    fees:           number;
+    expected_isk:   number;
+}
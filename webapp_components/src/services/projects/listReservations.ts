@@ -0,0 +1,40 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { GenericAbortSignal } from "axios";
+import type { Item } from "@internal/services/item/model";
+import type { Uuid } from "@internal/services/utils";
+
+export const LIST_PROJECT_RESERVATIONS = 'listProjectReservations';
+
+export const listProjectReservations = async (
+    projectId: Uuid,
+    signal?:   GenericAbortSignal,
+): Promise<ProjectReservation[]> => (await axiosClient())
+    .get(
+        `/api/projects/${projectId}/reservations`,
+        {
+            signal,
+        }
+    )
+    .then(x => x.status === 204 ? [] : x.data);
+
+export const useListProjectReservations = (
+    projectId: Uuid,
+) => {
+    return useQuery({
+        queryKey: [LIST_PROJECT_RESERVATIONS, projectId],
+        queryFn: async ({
+            signal
+        }: AbortSignal) => listProjectReservations(projectId, signal),
+        initialData: [],
+    })
+}
+
+export type ProjectReservationSource = 'STOCK' | 'EXCESS';
+
+export type ProjectReservation = {
+    item:        Item;
+    source:      ProjectReservationSource;
+    location_id: number;
+    quantity:    number;
+}
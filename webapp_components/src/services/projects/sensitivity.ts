@@ -0,0 +1,60 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "../utils";
+import type { ProjectProducts, TmpBlueprintOverwrite, TmpJobSplitting } from "./generateSolution";
+
+export const sensitivity = async (
+    config: SensitivityRequest,
+): Promise<SensitivityResponse> => (await axiosClient())
+    .post(
+        '/api/industry/calculation/sensitivity',
+        config,
+    )
+    .then(x => x.data);
+
+export type SensitivityRequest = {
+    project_group_id:   Uuid;
+    industry_hub_id:    Uuid;
+    products:           ProjectProducts[];
+    stocks?:            SensitivityStock[];
+
+    blacklist?:             number[];
+    blueprint_overwrite?:   TmpBlueprintOverwrite[];
+    job_splitting?:         TmpJobSplitting[];
+    markets?:               number[];
+
+    variations:         SensitivityVariation[];
+}
+
+export type SensitivityStock = {
+    type_id:    number;
+    quantity:   number;
+}
+
+export type RigTier = 'NONE' | 'TECH1' | 'TECH2';
+
+export type SensitivityVariation =
+    { type: 'BLUEPRINT_MATERIAL_EFFICIENCY', type_id: number, material_efficiency: number } |
+    { type: 'RIG_TIER', tier: RigTier } |
+    { type: 'SYSTEM_COST_INDEX', percent: number } |
+    { type: 'MATERIAL_PRICE', type_id?: number, percent: number } |
+    { type: 'BLACKLIST', type_id: number };
+
+export type SensitivityResponse = {
+    baseline:   SensitivityResult;
+    variations: SensitivityVariationResult[];
+}
+
+export type SensitivityVariationResult = {
+    variation:  SensitivityVariation;
+    result:     SensitivityResult;
+    difference: SensitivityResult;
+}
+
+export type SensitivityResult = {
+    material_cost:  number;
+    build_tax:      number;
+    total_cost:     number;
+    time:           number;
+    longest_job:    number;
+    missing_jobs:   number;
+}
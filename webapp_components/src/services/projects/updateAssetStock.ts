@@ -0,0 +1,13 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+import type { AssetStockFilter } from "@internal/services/projects/fetchAssetStock";
+
+export const updateAssetStock = async (
+    projectId:  Uuid,
+    data:       AssetStockFilter,
+): Promise<void> => (await axiosClient())
+    .put(
+        `/api/projects/${projectId}/asset-stock`,
+        data,
+    )
+    .then(x => x.data);
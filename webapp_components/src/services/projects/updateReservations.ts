@@ -0,0 +1,20 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+import type { ProjectReservationSource } from "@internal/services/projects/listReservations";
+
+export const updateProjectReservations = async (
+    projectId:  Uuid,
+    data:       ProjectReservationEntry[],
+): Promise<void> => (await axiosClient())
+    .put(
+        `/api/projects/${projectId}/reservations`,
+        data,
+    )
+    .then(x => x.data);
+
+export type ProjectReservationEntry = {
+    type_id:     number;
+    source:      ProjectReservationSource;
+    location_id: number;
+    quantity:    number;
+}
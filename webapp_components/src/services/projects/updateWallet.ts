@@ -0,0 +1,10 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+
+export const updateWallet = async (
+    projectId:  Uuid,
+): Promise<void> => (await axiosClient())
+    .put(
+        `/api/projects/${projectId}/wallet`,
+    )
+    .then(x => x.data);
@@ -0,0 +1,11 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+import type { StockTargets } from "@internal/services/stock-targets/fetch";
+
+export const checkStockTargets = async (
+    projectGroupId: Uuid,
+): Promise<StockTargets | undefined> => (await axiosClient())
+    .post(
+        `/api/stock-targets/${projectGroupId}/check`,
+    )
+    .then(x => x.status === 204 ? undefined : x.data);
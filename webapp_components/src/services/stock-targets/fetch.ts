@@ -0,0 +1,56 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { GenericAbortSignal } from "axios";
+import type { Item } from "@internal/services/item/model";
+import type { Uuid } from "@internal/services/utils";
+
+export const FETCH_STOCK_TARGETS = 'fetchStockTargets';
+
+export const fetchStockTargets = async (
+    projectGroupId: Uuid,
+    signal?:        GenericAbortSignal,
+): Promise<StockTargets | undefined> => (await axiosClient())
+    .get(
+        `/api/stock-targets/${projectGroupId}`,
+        {
+            signal,
+        }
+    )
+    .then(x => x.status === 204 ? undefined : x.data);
+
+// For general use
+export const useFetchStockTargets = (
+    projectGroupId: Uuid,
+) => {
+    return useQuery(fetchStockTargetsQuery(projectGroupId));
+}
+
+// For pre-fetching
+export const fetchStockTargetsQuery = (
+    projectGroupId: Uuid,
+) => ({
+    queryKey: [FETCH_STOCK_TARGETS, projectGroupId],
+    queryFn: async ({
+        signal
+    }: AbortSignal) => fetchStockTargets(projectGroupId, signal),
+    // ms * s * m
+    staleTime: 1000 * 60 * 5,
+});
+
+export type StockTargetRestockMode = 'PROPOSE' | 'CREATE';
+
+export type StockTargets = {
+    restock:        StockTargetRestockMode;
+    checked_at?:    string;
+    targets:        StockTarget[];
+}
+
+export type StockTarget = {
+    id:             Uuid;
+    item:           Item;
+    location_id:    number;
+    quantity:       number;
+    available:      number;
+    incoming:       number;
+    shortfall:      number;
+}
@@ -0,0 +1,16 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+import type { StockTarget } from "@internal/services/stock-targets/fetch";
+
+export const restockStockTargets = async (
+    projectGroupId: Uuid,
+): Promise<StockTargetRestock | undefined> => (await axiosClient())
+    .post(
+        `/api/stock-targets/${projectGroupId}/restock`,
+    )
+    .then(x => x.status === 204 ? undefined : x.data);
+
+export type StockTargetRestock = {
+    project_id: Uuid;
+    targets:    StockTarget[];
+}
@@ -0,0 +1,24 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "@internal/services/utils";
+import type { StockTargetRestockMode } from "@internal/services/stock-targets/fetch";
+
+export const updateStockTargets = async (
+    projectGroupId: Uuid,
+    data:           UpdateStockTargets,
+): Promise<void> => (await axiosClient())
+    .put(
+        `/api/stock-targets/${projectGroupId}`,
+        data,
+    )
+    .then(x => x.data);
+
+export type UpdateStockTargets = {
+    restock:    StockTargetRestockMode;
+    targets:    StockTargetEntry[];
+}
+
+export type StockTargetEntry = {
+    type_id:        number;
+    location_id:    number;
+    quantity:       number;
+}
@@ -0,0 +1,23 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "../utils"
+import type { StoreOrder } from "./listOrders";
+
+export const createQuote = async (
+    storeId: Uuid,
+    data:    CreateQuote,
+): Promise<StoreOrder> => (await axiosClient())
+    .post(
+        `/api/stores/${storeId}/quotes`,
+        data,
+    )
+    .then(x => x.data);
+
+export type CreateQuote = {
+    products?:     {
+        type_id:             number,
+        material_efficiency: number,
+        quantity:            number,
+    }[],
+    // item list or a pasted fit
+    products_str?: string,
+}
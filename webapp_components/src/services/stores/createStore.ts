@@ -0,0 +1,23 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "../utils"
+
+export const createStore = async (
+    data: CreateStore,
+): Promise<CreateStoreResponse> => (await axiosClient())
+    .post(
+        '/api/stores',
+        data,
+    )
+    .then(x => x.data);
+
+export type CreateStore = {
+    project_group_id: Uuid,
+    name:             string,
+    description?:     string,
+    margin?:          number,
+    quote_validity?:  number,
+}
+
+export type CreateStoreResponse = {
+    id: Uuid;
+}
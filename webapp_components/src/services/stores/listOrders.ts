@@ -0,0 +1,81 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { GenericAbortSignal } from "axios";
+import type { ProjectStatus } from "@internal/services/projects/list";
+import type { SolutionProduct } from "@internal/services/project-group/listSolutions";
+import type { Uuid } from "@internal/services/utils";
+
+export const LIST_STORE_ORDERS = 'listStoreOrders';
+
+export const listOrders = async (
+    signal?: GenericAbortSignal,
+): Promise<StoreOrder[]> => (await axiosClient())
+    .get(
+        '/api/stores/orders',
+        {
+            signal,
+        }
+    )
+    .then(x => {
+        if (x.status === 204) {
+            return [];
+        }
+
+        return x.data
+    });
+
+export const listStoreOrders = async (
+    storeId: Uuid,
+    signal?: GenericAbortSignal,
+): Promise<StoreOrder[]> => (await axiosClient())
+    .get(
+        `/api/stores/${storeId}/orders`,
+        {
+            signal,
+        }
+    )
+    .then(x => {
+        if (x.status === 204) {
+            return [];
+        }
+
+        return x.data
+    });
+
+export const useListOrders = () => {
+    return useQuery({
+        queryKey: [LIST_STORE_ORDERS],
+        queryFn: async ({
+            signal
+        }: AbortSignal) => listOrders(signal),
+    });
+}
+
+export const useListStoreOrders = (
+    storeId: Uuid,
+) => {
+    return useQuery({
+        queryKey: [LIST_STORE_ORDERS, storeId],
+        queryFn: async ({
+            signal
+        }: AbortSignal) => listStoreOrders(storeId, signal),
+    });
+}
+
+export type StoreOrder = {
+    id:              Uuid,
+    store_id:        Uuid,
+    store_name:      string,
+    customer:        number,
+    status:          StoreOrderStatus,
+    products:        SolutionProduct[],
+    // only set for the store
+    build_cost?:     number,
+    price:           number,
+    valid_until:     string,
+    project_id?:     Uuid,
+    project_status?: ProjectStatus,
+    created_at:      string,
+}
+
+export type StoreOrderStatus = 'QUOTED' | 'ORDERED' | 'DELIVERED' | 'CANCELLED';
@@ -0,0 +1,42 @@
+import { axiosClient, type AbortSignal } from "@internal/services/client";
+import { useQuery } from "@tanstack/react-query";
+import type { GenericAbortSignal } from "axios";
+import type { Uuid } from "@internal/services/utils";
+
+export const LIST_STORES = 'listStores';
+
+export const listStores = async (
+    signal?: GenericAbortSignal,
+): Promise<Store[]> => (await axiosClient())
+    .get(
+        '/api/stores',
+        {
+            signal,
+        }
+    )
+    .then(x => {
+        if (x.status === 204) {
+            return [];
+        }
+
+        return x.data
+    });
+
+export const useListStores = () => {
+    return useQuery({
+        queryKey: [LIST_STORES],
+        queryFn: async ({
+            signal
+        }: AbortSignal) => listStores(signal),
+    });
+}
+
+export type Store = {
+    id:               Uuid,
+    owner:            number,
+    project_group_id: Uuid,
+    name:             string,
+    description?:     string,
+    margin:           number,
+    quote_validity:   number,
+}
@@ -0,0 +1,27 @@
+import { axiosClient } from "@internal/services/client";
+import type { Uuid } from "../utils"
+
+export const confirmOrder = async (
+    orderId: Uuid,
+): Promise<{ id: Uuid }> => (await axiosClient())
+    .put(
+        `/api/stores/orders/${orderId}/confirm`,
+    )
+    .then(x => x.data);
+
+export const cancelOrder = async (
+    orderId: Uuid,
+): Promise<void> => (await axiosClient())
+    .put(
+        `/api/stores/orders/${orderId}/cancel`,
+    )
+    .then(x => x.data);
+
+export const deliverOrder = async (
+    storeId: Uuid,
+    orderId: Uuid,
+): Promise<void> => (await axiosClient())
+    .put(
+        `/api/stores/${storeId}/orders/${orderId}/deliver`,
+    )
+    .then(x => x.data);
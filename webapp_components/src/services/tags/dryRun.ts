@@ -0,0 +1,22 @@
+import { axiosClient } from "@internal/services/client";
+import type { ProjectListMinimal } from "@internal/services/projects/list";
+import type { TagAuto } from "@internal/services/tags/list";
+
+export const dryRunTag = async (
+    data: TagDryRun,
+): Promise<ProjectListMinimal[]> => (await axiosClient())
+    .post(
+        '/api/tags/dry-run',
+        data,
+    )
+    .then(x => {
+        if (x.status === 204) {
+            return [];
+        }
+
+        return x.data;
+    });
+
+export type TagDryRun = {
+    auto: TagAuto[];
+}
@@ -34,8 +34,8 @@ export const useListTags = (filter: ListTagFilter) => {
 }
 
 export type TagType = 'MANUAL' | 'AUTO';
-export type AutoTagSelect = 'PROJECT_NAME' | 'PROJECT_ORDERER' | 'PROJECT_NOTE' | 'PROJECT_PRODUCT' | 'PROJECT_STATUS';
-export type AutoTagCompare = 'IS' | 'IS_NOT' | 'CONTAINS' | 'PATTERN';
+export type AutoTagSelect = 'PROJECT_NAME' | 'PROJECT_ORDERER' | 'PROJECT_STATUS' | 'PROJECT_STATUS_TRANSITION' | 'PROJECT_COST' | 'PROJECT_AGE' | 'PROJECT_GROUP' | 'PRODUCT_CATEGORY';
+export type AutoTagCompare = 'IS' | 'IS_NOT' | 'CONTAINS' | 'PATTERN' | 'GREATER_THAN' | 'LESS_THAN';
 
 export type Tag = {
     id:         Uuid;
@@ -16,6 +16,14 @@ const comparePattern = {
     label: 'pattern',
     value: 'PATTERN'
 };
+const compareGreaterThan = {
+    label: 'greater than',
+    value: 'GREATER_THAN'
+};
+const compareLessThan = {
+    label: 'less than',
+    value: 'LESS_THAN'
+};
 
 export const tagOptions: TagOption[] = [{
     label: 'project.name',
@@ -42,6 +50,46 @@ export const tagOptions: TagOption[] = [{
         compareIs,
         compareIsNot,
     ]
+}, {
+    label: 'project.status_transition',
+    value: 'PROJECT_STATUS_TRANSITION',
+    compare: [
+        compareIs,
+        compareIsNot,
+        comparePattern,
+    ]
+}, {
+    label: 'project.cost',
+    value: 'PROJECT_COST',
+    compare: [
+        compareGreaterThan,
+        compareLessThan,
+    ]
+}, {
+    label: 'project.age_days',
+    value: 'PROJECT_AGE',
+    compare: [
+        compareGreaterThan,
+        compareLessThan,
+    ]
+}, {
+    label: 'project.group',
+    value: 'PROJECT_GROUP',
+    compare: [
+        compareIs,
+        compareIsNot,
+        compareContains,
+        comparePattern,
+    ]
+}, {
+    label: 'product.category',
+    value: 'PRODUCT_CATEGORY',
+    compare: [
+        compareIs,
+        compareIsNot,
+        compareContains,
+        comparePattern,
+    ]
 }]
 
 type TagOption = {
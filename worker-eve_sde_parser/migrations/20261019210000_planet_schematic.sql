@@ -0,0 +1,33 @@
+CREATE TABLE IF NOT EXISTS planet_schematic (
+    schematic_id INTEGER NOT NULL,
+    name         VARCHAR NOT NULL,
+    -- duration of a single cycle in seconds
+    cycle_time   INTEGER NOT NULL,
+    -- product of the schematic
+    type_id      INTEGER NOT NULL,
+    -- quantity produced per cycle
+    quantity     INTEGER NOT NULL,
+
+    PRIMARY KEY (schematic_id)
+);
+CREATE INDEX IF NOT EXISTS planet_schematic_type_id ON planet_schematic (type_id);
+
+CREATE TABLE IF NOT EXISTS planet_schematic_material (
+    schematic_id INTEGER NOT NULL,
+    type_id      INTEGER NOT NULL,
+    -- quantity required per cycle
+    quantity     INTEGER NOT NULL,
+
+    PRIMARY KEY (schematic_id, type_id),
+    FOREIGN KEY (schematic_id)
+        REFERENCES planet_schematic (schematic_id)
+        ON DELETE CASCADE
+);
+
+-- raw resources and the planet types they can be extracted from
+CREATE TABLE IF NOT EXISTS planet_resource (
+    type_id        INTEGER NOT NULL,
+    planet_type_id INTEGER NOT NULL,
+
+    PRIMARY KEY (type_id, planet_type_id)
+);
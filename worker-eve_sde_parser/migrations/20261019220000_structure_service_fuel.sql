@@ -0,0 +1,10 @@
+-- fuel blocks consumed by a structure service module
+CREATE TABLE IF NOT EXISTS structure_service_fuel (
+    type_id       INTEGER NOT NULL,
+    -- fuel blocks per hour while the service is online
+    fuel_per_hour INTEGER NOT NULL,
+    -- fuel blocks required once to online the service
+    fuel_online   INTEGER NOT NULL,
+
+    PRIMARY KEY (type_id)
+);
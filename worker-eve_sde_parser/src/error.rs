@@ -67,6 +67,18 @@ pub enum Error {
     #[error("the file `repackagedvolumes.json` is not at the expected location, '{0}'")]
     CannotOpenRepackagedVolumes(std::io::Error, String),
 
+    #[error("the file `planetSchematics.yaml` couldn`t be parsed, '{0}'")]
+    ParsePlanetSchematics(serde_yaml::Error),
+    #[error("the file `planetSchematics.yaml` is not at the expected location, '{0}'")]
+    CannotOpenPlanetSchematicsFile(std::io::Error, String),
+    #[error("error while deleting the planet schematics database, '{0}'")]
+    DeletePlanetSchematics(sqlx::Error),
+    #[error("inserting the planet schematic data failed, '{0}'")]
+    InsertPlanetSchematics(sqlx::Error),
+
+    #[error("inserting the structure service fuel data failed, '{0}'")]
+    InsertStructureServiceFuel(sqlx::Error),
+
     #[error("the file `typeDogma` couldn`t be parsed, '{0}'")]
     ParseTypeDogma(serde_yaml::Error),
     #[error("the file `typeDogma` is not at the expected location, '{0}'")]
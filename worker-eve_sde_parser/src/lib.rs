@@ -6,6 +6,7 @@ pub mod downloads;
 pub mod items;
 pub mod map;
 pub mod parser;
+pub mod planet_schematics;
 pub mod reprocessing;
 pub mod structure;
 pub mod systems;
@@ -43,6 +44,7 @@ pub async fn import_sde(
     let group_ids                 = parser::groups::parse(&directory)?;
     let industry_modifier_sources = parser::industry_modifier_sources::parse(&directory)?;
     let industry_target_filters   = parser::industry_target_filters::parse(&directory)?;
+    let planet_schematics         = parser::planet_schematics::parse(&directory)?;
     let regions                   = parser::regions::parse(&directory)?;
     let repackaged                = parser::repackaged::parse(&directory)?;
     let systems                   = parser::systems::parse(&directory)?;
@@ -98,6 +100,11 @@ pub async fn import_sde(
             &repackaged,
         )
         .await?;
+    planet_schematics::run(
+            &pool,
+            &planet_schematics,
+        )
+        .await?;
     reprocessing::run(
             &pool,
             &type_material,
@@ -10,6 +10,7 @@ pub mod moon;
 pub mod npc_station;
 pub mod overwrite;
 pub mod planet;
+pub mod planet_schematics;
 pub mod regions;
 pub mod repackaged;
 pub mod stargate;
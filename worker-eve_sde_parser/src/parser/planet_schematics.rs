@@ -0,0 +1,125 @@
+use serde::Deserialize;
+use starfoundry_lib_types::TypeId;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::{FOLDER_INPUT, Error};
+
+pub fn parse(
+    directory: &str,
+) -> Result<HashMap<i32, PlanetSchematicEntry>, Error> {
+    tracing::info!("Parsing planetSchematics.yaml");
+    let start = Instant::now();
+
+    let path = format!(
+        "{}/{}/planetSchematics.yaml",
+        directory,
+        FOLDER_INPUT,
+    );
+
+    if !Path::new(&path).exists() {
+        return Err(Error::FileDoesNotExist(path));
+    }
+
+    let file = File::open(&path)
+        .map_err(|x| Error::CannotOpenPlanetSchematicsFile(x, path))?;
+
+    serde_yaml::from_reader(file)
+        .inspect(|_| {
+            tracing::info!(
+                "Finished parsing planetSchematics.yaml, task took {:.2}s",
+                start.elapsed().as_secs_f64()
+            );
+        })
+        .map_err(Error::ParsePlanetSchematics)
+}
+
+/// Represents a single schematic of a planetary factory
+#[derive(Clone, Debug, Deserialize)]
+pub struct PlanetSchematicEntry {
+    /// Duration of a single cycle in seconds
+    #[serde(rename = "cycleTime")]
+    pub cycle_time: i32,
+    /// Name of the schematic in different languages
+    #[serde(rename = "name")]
+    name:           HashMap<String, String>,
+    /// Inputs and output of the schematic
+    #[serde(rename = "types")]
+    types:          PlanetSchematicTypes,
+}
+
+impl PlanetSchematicEntry {
+    /// Gets the english name of the schematic
+    #[must_use]
+    pub fn name(&self) -> Option<String> {
+        self.name.get("en").cloned()
+    }
+
+    /// Gets the product of the schematic, together with the quantity that is
+    /// produced per cycle
+    #[must_use]
+    pub fn output(&self) -> Option<(TypeId, i32)> {
+        self.types
+            .entries()
+            .into_iter()
+            .find(|(_, x)| !x.is_input)
+            .map(|(type_id, x)| (type_id, x.quantity))
+    }
+
+    /// Gets all inputs of the schematic, together with the quantity that is
+    /// required per cycle
+    #[must_use]
+    pub fn inputs(&self) -> Vec<(TypeId, i32)> {
+        self.types
+            .entries()
+            .into_iter()
+            .filter(|(_, x)| x.is_input)
+            .map(|(type_id, x)| (type_id, x.quantity))
+            .collect::<Vec<_>>()
+    }
+}
+
+/// Depending on the version of the SDE the types are either a map by type id,
+/// or a list that contains the type id as `_key`
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum PlanetSchematicTypes {
+    Map(HashMap<TypeId, PlanetSchematicType>),
+    List(Vec<PlanetSchematicKeyedType>),
+}
+
+impl PlanetSchematicTypes {
+    fn entries(&self) -> Vec<(TypeId, PlanetSchematicType)> {
+        match self {
+            Self::Map(x) => {
+                x
+                    .iter()
+                    .map(|(type_id, x)| (*type_id, x.clone()))
+                    .collect::<Vec<_>>()
+            },
+            Self::List(x) => {
+                x
+                    .iter()
+                    .map(|x| (x.type_id, x.entry.clone()))
+                    .collect::<Vec<_>>()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct PlanetSchematicKeyedType {
+    #[serde(rename = "_key")]
+    type_id: TypeId,
+    #[serde(flatten)]
+    entry:   PlanetSchematicType,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct PlanetSchematicType {
+    #[serde(rename = "isInput")]
+    is_input: bool,
+    quantity: i32,
+}
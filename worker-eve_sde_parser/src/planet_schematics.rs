@@ -0,0 +1,200 @@
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::Error;
+use crate::parser::planet_schematics::PlanetSchematicEntry;
+
+/// Planet types by type id
+const TEMPERATE: i32 = 11;
+const ICE:       i32 = 12;
+const GAS:       i32 = 13;
+const OCEANIC:   i32 = 2014;
+const LAVA:      i32 = 2015;
+const BARREN:    i32 = 2016;
+const STORM:     i32 = 2017;
+const PLASMA:    i32 = 2063;
+
+/// Raw resources and the planet types they can be extracted from.
+/// The SDE does not contain the mapping in a usable way, so it is maintained
+/// by hand
+const PLANET_RESOURCES: &[(i32, &[i32])] = &[
+    // Aqueous Liquids
+    (2268, &[BARREN, GAS, ICE, OCEANIC, STORM, TEMPERATE]),
+    // Autotrophs
+    (2305, &[TEMPERATE]),
+    // Base Metals
+    (2267, &[BARREN, GAS, LAVA, PLASMA, STORM]),
+    // Carbon Compounds
+    (2288, &[BARREN, OCEANIC, TEMPERATE]),
+    // Complex Organisms
+    (2287, &[OCEANIC, TEMPERATE]),
+    // Felsic Magma
+    (2307, &[LAVA]),
+    // Heavy Metals
+    (2272, &[ICE, LAVA, PLASMA]),
+    // Ionic Solutions
+    (2309, &[GAS, STORM]),
+    // Microorganisms
+    (2073, &[BARREN, ICE, OCEANIC, TEMPERATE]),
+    // Noble Gas
+    (2310, &[GAS, ICE, STORM]),
+    // Noble Metals
+    (2270, &[BARREN, PLASMA]),
+    // Non-CS Crystals
+    (2306, &[LAVA, PLASMA]),
+    // Planktic Colonies
+    (2286, &[ICE, OCEANIC]),
+    // Reactive Gas
+    (2311, &[GAS]),
+    // Suspended Plasma
+    (2308, &[LAVA, PLASMA, STORM]),
+];
+
+pub async fn run(
+    pool:              &PgPool,
+    planet_schematics: &HashMap<i32, PlanetSchematicEntry>,
+) -> Result<(), Error> {
+    tracing::info!("Processing planet schematics");
+    let start = Instant::now();
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .map_err(Error::TransactionError)?;
+
+    tracing::debug!("Clearing database");
+    sqlx::query!("
+            DELETE FROM planet_schematic
+        ")
+        .execute(&mut *transaction)
+        .await
+        .map_err(Error::DeletePlanetSchematics)?;
+    sqlx::query!("
+            DELETE FROM planet_resource
+        ")
+        .execute(&mut *transaction)
+        .await
+        .map_err(Error::DeletePlanetSchematics)?;
+    tracing::debug!("Clearing database done");
+
+    let mut schematic_ids = Vec::new();
+    let mut names         = Vec::new();
+    let mut cycle_times   = Vec::new();
+    let mut type_ids      = Vec::new();
+    let mut quantities    = Vec::new();
+
+    let mut material_schematic_ids = Vec::new();
+    let mut material_type_ids      = Vec::new();
+    let mut material_quantities    = Vec::new();
+
+    for (schematic_id, schematic) in planet_schematics {
+        let (type_id, quantity) = if let Some(x) = schematic.output() {
+            x
+        } else {
+            tracing::warn!("Planet schematic {schematic_id} has no output");
+            continue;
+        };
+
+        schematic_ids.push(*schematic_id);
+        names.push(schematic.name().unwrap_or_default());
+        cycle_times.push(schematic.cycle_time);
+        type_ids.push(*type_id);
+        quantities.push(quantity);
+
+        for (type_id, quantity) in schematic.inputs() {
+            material_schematic_ids.push(*schematic_id);
+            material_type_ids.push(*type_id);
+            material_quantities.push(quantity);
+        }
+    }
+
+    tracing::debug!("Inserting data");
+    sqlx::query!("
+            INSERT INTO planet_schematic
+            (
+                schematic_id,
+                name,
+                cycle_time,
+                type_id,
+                quantity
+            )
+            SELECT * FROM UNNEST(
+                $1::INTEGER[],
+                $2::VARCHAR[],
+                $3::INTEGER[],
+                $4::INTEGER[],
+                $5::INTEGER[]
+            )
+        ",
+            &schematic_ids,
+            &names,
+            &cycle_times,
+            &type_ids,
+            &quantities,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(Error::InsertPlanetSchematics)?;
+
+    sqlx::query!("
+            INSERT INTO planet_schematic_material
+            (
+                schematic_id,
+                type_id,
+                quantity
+            )
+            SELECT * FROM UNNEST(
+                $1::INTEGER[],
+                $2::INTEGER[],
+                $3::INTEGER[]
+            )
+        ",
+            &material_schematic_ids,
+            &material_type_ids,
+            &material_quantities,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(Error::InsertPlanetSchematics)?;
+
+    let (resource_type_ids, planet_type_ids): (Vec<i32>, Vec<i32>) = PLANET_RESOURCES
+        .iter()
+        .flat_map(|(type_id, planet_types)| {
+            planet_types
+                .iter()
+                .map(|x| (*type_id, *x))
+        })
+        .unzip();
+    sqlx::query!("
+            INSERT INTO planet_resource
+            (
+                type_id,
+                planet_type_id
+            )
+            SELECT * FROM UNNEST(
+                $1::INTEGER[],
+                $2::INTEGER[]
+            )
+        ",
+            &resource_type_ids,
+            &planet_type_ids,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(Error::InsertPlanetSchematics)?;
+    tracing::debug!("Inserting data done");
+
+    transaction
+        .commit()
+        .await
+        .map_err(Error::TransactionError)?;
+    tracing::debug!("Transaction commited");
+
+    tracing::info!(
+        "Finished processing planet schematics, task took {:.2}s",
+        start.elapsed().as_secs_f64()
+    );
+
+    Ok(())
+}
@@ -39,6 +39,12 @@ pub async fn run(
         .execute(&mut *transaction)
         .await
         .map_err(Error::DeleteItems)?;
+    sqlx::query!("
+            DELETE FROM structure_service_fuel
+        ")
+        .execute(&mut *transaction)
+        .await
+        .map_err(Error::DeleteItems)?;
     tracing::debug!("Clearing database done");
 
     tracing::debug!("Inserting data starting");
@@ -63,8 +69,10 @@ pub async fn run(
             .unwrap();
     }
 
+    let mut service_type_ids = Vec::new();
     for structure in vec![35825, 35826, 35827, 35835, 35836, 35832, 35833, 47516, 47512, 47515, 47514, 47513, 35834, 40340] {
         let services = services(structure.into());
+        service_type_ids.extend(services.clone());
         sqlx::query!("
                 INSERT INTO structure_service
                 (
@@ -82,6 +90,33 @@ pub async fn run(
             .await
             .unwrap();
     }
+
+    service_type_ids.sort();
+    service_type_ids.dedup();
+    let (fuel_type_ids, fuel_per_hour, fuel_online) = service_fuel(
+        &service_type_ids,
+        &type_dogma,
+    );
+    sqlx::query!("
+            INSERT INTO structure_service_fuel
+            (
+                type_id,
+                fuel_per_hour,
+                fuel_online
+            )
+            SELECT * FROM UNNEST(
+                $1::INTEGER[],
+                $2::INTEGER[],
+                $3::INTEGER[]
+            )
+        ",
+            &fuel_type_ids,
+            &fuel_per_hour,
+            &fuel_online,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(Error::InsertStructureServiceFuel)?;
     tracing::debug!("Inserting data done");
 
     transaction
@@ -435,6 +470,43 @@ fn service_slots(
         .unwrap_or_default()
 }
 
+/// Fuel consumption of the given services, as type ids, fuel blocks per hour
+/// and fuel blocks required to online the service
+fn service_fuel(
+    service_type_ids: &[TypeId],
+    type_dogma:       &HashMap<usize, TypeDogma>,
+) -> (Vec<i32>, Vec<i32>, Vec<i32>) {
+    let mut type_ids      = Vec::new();
+    let mut fuel_per_hour = Vec::new();
+    let mut fuel_online   = Vec::new();
+
+    for service_type_id in service_type_ids {
+        let dogma = if let Some(x) = type_dogma.get(&(**service_type_id as usize)) {
+            x
+        } else {
+            tracing::warn!("No dogma for service {service_type_id}");
+            continue;
+        };
+
+        let attribute = |attribute_id: usize| {
+            dogma
+                .attributes
+                .iter()
+                .find(|x| x.attribute_id == attribute_id)
+                .map(|x| x.value as i32)
+                .unwrap_or_default()
+        };
+
+        type_ids.push(**service_type_id);
+        // serviceModuleFuelAmount
+        fuel_per_hour.push(attribute(2109));
+        // serviceModuleFuelOnlineAmount
+        fuel_online.push(attribute(2110));
+    }
+
+    (type_ids, fuel_per_hour, fuel_online)
+}
+
 #[derive(Debug)]
 struct Structure {
     type_id:            TypeId,